@@ -1153,9 +1153,14 @@ impl PythonRunner {
 
         let _permit = self.control.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
+        // Run the hook as the selected slice of a macOS universal binary, if any, such that
+        // extension modules are built for the right architecture.
+        let (program, arch_args) = venv.interpreter().arch_command();
+        let mut child = Command::new(program)
+            .args(arch_args)
             .args(["-c", script])
             .current_dir(source_tree.simplified())
+            .envs(venv.interpreter().query_env())
             .envs(environment_variables)
             .env(EnvVars::PATH, modified_path)
             .env(EnvVars::VIRTUAL_ENV, venv.root())
@@ -0,0 +1,190 @@
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+/// User-configured settings that affect how Python interpreters are discovered and queried.
+///
+/// The settings are carried by the [`Cache`](crate::Cache), which is threaded through interpreter
+/// discovery, as they change the interpreter metadata that is cached.
+#[derive(Debug, Clone, Default)]
+pub struct InterpreterSettings {
+    /// Glob patterns for Python executables that should never be discovered.
+    discovery_exclude: Vec<String>,
+    /// Glob patterns for the only Python executables that may be discovered, if any.
+    discovery_include_only: Option<Vec<String>>,
+    /// The environment variables to apply when invoking specific Python executables.
+    query_env: BTreeMap<PathBuf, BTreeMap<String, String>>,
+    /// The Python snippets to run as part of each interpreter query, keyed by name.
+    query_probes: BTreeMap<String, String>,
+    /// The time after which an interpreter query is killed, if any.
+    query_timeout: Option<Duration>,
+    /// The maximum size of the virtual memory of an interpreter query, in bytes, if any.
+    query_memory_limit: Option<u64>,
+    /// The maximum CPU time of an interpreter query, in seconds, if any.
+    query_cpu_limit: Option<u64>,
+    /// The slots for concurrent interpreter queries.
+    query_slots: Arc<QuerySlots>,
+}
+
+impl InterpreterSettings {
+    /// Set the glob patterns for the Python executables that may be discovered.
+    #[must_use]
+    pub fn with_discovery_filter(
+        self,
+        exclude: Vec<String>,
+        include_only: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            discovery_exclude: exclude,
+            discovery_include_only: include_only,
+            ..self
+        }
+    }
+
+    /// Return the glob patterns for Python executables that should never be discovered.
+    pub fn discovery_exclude(&self) -> &[String] {
+        &self.discovery_exclude
+    }
+
+    /// Return the glob patterns for the only Python executables that may be discovered, if any.
+    pub fn discovery_include_only(&self) -> Option<&[String]> {
+        self.discovery_include_only.as_deref()
+    }
+
+    /// Set the environment variables to apply when invoking the given Python executables.
+    #[must_use]
+    pub fn with_query_env(self, query_env: BTreeMap<PathBuf, BTreeMap<String, String>>) -> Self {
+        Self { query_env, ..self }
+    }
+
+    /// Return the environment variables to apply when invoking each Python executable.
+    pub fn query_env(&self) -> &BTreeMap<PathBuf, BTreeMap<String, String>> {
+        &self.query_env
+    }
+
+    /// Set the Python snippets to run as part of each interpreter query, as pairs of name and
+    /// source.
+    ///
+    /// If multiple probes share a name, the last one is used.
+    #[must_use]
+    pub fn with_query_probes(self, probes: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            query_probes: probes.into_iter().collect(),
+            ..self
+        }
+    }
+
+    /// Return the Python snippets to run as part of each interpreter query, keyed by name.
+    pub fn query_probes(&self) -> &BTreeMap<String, String> {
+        &self.query_probes
+    }
+
+    /// Set the time after which an interpreter query is killed, if any.
+    #[must_use]
+    pub fn with_query_timeout(self, query_timeout: Option<Duration>) -> Self {
+        Self {
+            query_timeout,
+            ..self
+        }
+    }
+
+    /// Return the time after which an interpreter query is killed, if any.
+    pub fn query_timeout(&self) -> Option<Duration> {
+        self.query_timeout
+    }
+
+    /// Set the maximum virtual memory (in bytes) and CPU time (in seconds) of an interpreter
+    /// query.
+    #[must_use]
+    pub fn with_query_resource_limits(self, memory: Option<u64>, cpu: Option<u64>) -> Self {
+        Self {
+            query_memory_limit: memory,
+            query_cpu_limit: cpu,
+            ..self
+        }
+    }
+
+    /// Return the maximum size of the virtual memory of an interpreter query, in bytes, if any.
+    pub fn query_memory_limit(&self) -> Option<u64> {
+        self.query_memory_limit
+    }
+
+    /// Return the maximum CPU time of an interpreter query, in seconds, if any.
+    pub fn query_cpu_limit(&self) -> Option<u64> {
+        self.query_cpu_limit
+    }
+
+    /// Set the maximum number of concurrent interpreter queries.
+    #[must_use]
+    pub fn with_query_concurrency(self, concurrency: NonZeroUsize) -> Self {
+        Self {
+            query_slots: Arc::new(QuerySlots::new(concurrency)),
+            ..self
+        }
+    }
+
+    /// Return the slots for concurrent interpreter queries.
+    pub fn query_slots(&self) -> &QuerySlots {
+        &self.query_slots
+    }
+}
+
+/// A counting semaphore capping the number of concurrent interpreter queries.
+#[derive(Debug)]
+pub struct QuerySlots {
+    available: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Default for QuerySlots {
+    /// One slot per available CPU.
+    fn default() -> Self {
+        Self::new(thread::available_parallelism().unwrap_or(NonZeroUsize::MIN))
+    }
+}
+
+impl QuerySlots {
+    fn new(concurrency: NonZeroUsize) -> Self {
+        Self {
+            available: Mutex::new(concurrency.get()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Block until a query slot is available.
+    pub fn acquire(&self) -> QueryPermit<'_> {
+        let mut available = self
+            .available
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        while *available == 0 {
+            available = self
+                .released
+                .wait(available)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *available -= 1;
+        QueryPermit { slots: self }
+    }
+}
+
+/// A held query slot, released on drop.
+#[derive(Debug)]
+pub struct QueryPermit<'a> {
+    slots: &'a QuerySlots,
+}
+
+impl Drop for QueryPermit<'_> {
+    fn drop(&mut self) {
+        let mut available = self
+            .slots
+            .available
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *available += 1;
+        self.slots.released.notify_one();
+    }
+}
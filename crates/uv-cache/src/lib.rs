@@ -18,6 +18,7 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::interpreter::{InterpreterSettings, QueryPermit, QuerySlots};
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
 pub use crate::wheel::WheelCache;
@@ -27,6 +28,7 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod interpreter;
 mod removal;
 mod wheel;
 
@@ -146,6 +148,8 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The settings that affect how Python interpreters are queried.
+    interpreter: Arc<InterpreterSettings>,
 }
 
 impl Cache {
@@ -155,6 +159,7 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            interpreter: Arc::default(),
         }
     }
 
@@ -165,6 +170,7 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            interpreter: Arc::default(),
         })
     }
 
@@ -174,6 +180,15 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`InterpreterSettings`] for the cache.
+    #[must_use]
+    pub fn with_interpreter_settings(self, interpreter: InterpreterSettings) -> Self {
+        Self {
+            interpreter: Arc::new(interpreter),
+            ..self
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -184,6 +199,11 @@ impl Cache {
         &self.refresh
     }
 
+    /// Return the [`InterpreterSettings`] for the cache.
+    pub fn interpreter_settings(&self) -> &InterpreterSettings {
+        &self.interpreter
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
@@ -999,7 +1019,7 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v9",
             Self::FlatIndex => "flat-index-v2",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v4",
+            Self::Interpreter => "interpreter-v8",
             // Note that when bumping this, you'll also need to bump it
             // in `crates/uv/tests/it/cache_clean.rs`.
             Self::Simple => "simple-v16",
@@ -14,6 +14,13 @@ impl IntoIterator for CommaSeparatedRequirements {
     }
 }
 
+impl From<String> for CommaSeparatedRequirements {
+    /// A single requirement, which is not split on commas.
+    fn from(requirement: String) -> Self {
+        Self(vec![requirement])
+    }
+}
+
 impl FromStr for CommaSeparatedRequirements {
     type Err = String;
 
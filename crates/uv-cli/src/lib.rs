@@ -10,8 +10,9 @@ use clap::{Args, Parser, Subcommand};
 
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier, PreviewFeatures,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    ActivationShell, ExportFormat, IndexStrategy, KeyringProviderType, NetworkAccess,
+    PackageNameSpecifier, PathPolicy, PreviewFeatures, ProjectBuildBackend, TargetTriple,
+    TrustedHost, TrustedPublishing, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettingEntry, ConfigSettingPackageEntry, Index, IndexUrl, Origin, PipExtraIndex,
@@ -20,7 +21,10 @@ use uv_distribution_types::{
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, Requirement};
 use uv_pypi_types::VerbatimParsedUrl;
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{
+    DownloadRate, HostCertificate, ImplementationName, PythonChannel, PythonDownloads,
+    PythonPreference, PythonVersion,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewerPackageEntry, ExcludeNewerTimestamp, ForkStrategy, PrereleaseMode,
@@ -52,6 +56,78 @@ pub enum PythonListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonDuFormat {
+    /// A table of the installations and their disk usage (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonResolveFormat {
+    /// The details of the download, one per line (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonValidateDownloadsFormat {
+    /// The problems with the downloads, one per line (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonInspectFormat {
+    /// The details of the interpreter, one per line (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonWhichFormat {
+    /// The details of the executable, one per line (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonMarkersFormat {
+    /// One marker per line (for humans).
+    #[default]
+    Text,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonFindFormat {
+    /// A table of the matching interpreters (for humans).
+    #[default]
+    Table,
+    /// JSON (for computers).
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonEnvVarsFormat {
+    /// Commands to set the variables in the current shell.
+    #[default]
+    Shell,
+    /// JSON (for computers).
+    Json,
+}
+
 #[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
 pub enum SyncFormat {
     /// Display the result in a human-readable format.
@@ -61,6 +137,59 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiagnosticsFormat {
+    /// Report errors in a human-readable format.
+    #[default]
+    Text,
+    /// Report errors as JSON, with a stable diagnostic code for each error.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SignalForwarding {
+    /// Forward signals to the child process.
+    #[default]
+    Child,
+    /// Run the child in a new process group and forward signals to the entire group.
+    ///
+    /// Useful when the child spawns processes of its own, e.g., under a process supervisor.
+    Group,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OverlayMode {
+    /// Reference the site packages of the project environment and the `--with` requirements in
+    /// place.
+    ///
+    /// Changes to the project environment, e.g., from a concurrent `uv sync`, are visible to the
+    /// running command.
+    #[default]
+    Link,
+    /// Use copy-on-write clones when supported by the filesystem, and links otherwise.
+    Auto,
+    /// Clone the site packages of the project environment and the `--with` requirements into the
+    /// ephemeral environment via copy-on-write.
+    ///
+    /// The running command is isolated from changes to the project environment. Fails if the
+    /// filesystem does not support copy-on-write clones.
+    Clone,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExitCodeMap {
+    /// Exit with `1` or `2` if uv fails, which can't be distinguished from the exit code of a
+    /// command that fails.
+    #[default]
+    Default,
+    /// Exit with a code of `200` or greater if uv fails, identifying the kind of failure.
+    ///
+    /// `201` for a resolution failure, `202` if no suitable Python interpreter was found, `203` if
+    /// the lockfile is missing or out of date, `204` if the environment could not be prepared, and
+    /// `200` for any other failure.
+    Reserved,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -73,6 +202,48 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigKey {
+    /// Whether to prefer uv-managed or system Python installations.
+    PythonPreference,
+    /// Whether to allow Python downloads.
+    PythonDownloads,
+    /// The mirror URL used to download managed Python installations.
+    PythonInstallMirror,
+    /// The mirror URL used to download managed PyPy installations.
+    PypyInstallMirror,
+    /// The URL of a JSON file describing custom Python installations.
+    PythonDownloadsJsonUrl,
+    /// The keyring provider used to download managed Python installations.
+    PythonInstallKeyringProvider,
+    /// The certificate authorities trusted for downloads of managed Python installations.
+    PythonInstallCaCerts,
+    /// The directory in which managed Python installations are stored.
+    PythonInstallDir,
+    /// The directory in which managed Python distributions are extracted before installation.
+    InstallStagingDir,
+    /// The Python executables that are never discovered.
+    #[value(name = "python-discovery.exclude")]
+    PythonDiscoveryExclude,
+    /// The only Python executables that may be discovered.
+    #[value(name = "python-discovery.include-only")]
+    PythonDiscoveryIncludeOnly,
+    /// The environment variables applied when invoking specific Python executables.
+    PythonQueryEnv,
+    /// The time after which an interpreter query is terminated.
+    #[value(name = "python-query-limits.timeout")]
+    PythonQueryTimeout,
+    /// The maximum virtual memory of an interpreter query.
+    #[value(name = "python-query-limits.memory-limit")]
+    PythonQueryMemoryLimit,
+    /// The maximum CPU time of an interpreter query.
+    #[value(name = "python-query-limits.cpu-limit")]
+    PythonQueryCpuLimit,
+    /// The maximum number of concurrent interpreter queries.
+    #[value(name = "python-query-limits.concurrency")]
+    PythonQueryConcurrency,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -321,6 +492,14 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// The format in which to report errors.
+    ///
+    /// With `json`, errors are written to stderr as a single line of JSON, including a stable
+    /// diagnostic code and, where available, the declarations that caused the error (e.g., the
+    /// package and dependency group). This option is intended for editors and other tools.
+    #[arg(global = true, long, value_enum)]
+    pub diagnostics: Option<DiagnosticsFormat>,
+
     /// Hide all progress outputs.
     ///
     /// For example, spinners or progress bars.
@@ -353,6 +532,20 @@ pub struct GlobalArgs {
     /// This setting has no effect when used in the `uv pip` interface.
     #[arg(global = true, long, env = EnvVars::UV_PROJECT)]
     pub project: Option<PathBuf>,
+
+    /// The IDs of the options whose values were read from their environment variable, rather than
+    /// passed on the command line.
+    ///
+    /// Populated after parsing, from the value sources recorded by `clap`.
+    #[arg(skip)]
+    pub env_sourced: Vec<String>,
+}
+
+impl GlobalArgs {
+    /// Whether the value of the option with the given ID was read from its environment variable.
+    pub fn is_env_sourced(&self, id: &str) -> bool {
+        self.env_sourced.iter().any(|sourced| sourced == id)
+    }
 }
 
 #[derive(Debug, Copy, Clone, clap::ValueEnum)]
@@ -520,6 +713,12 @@ pub enum Commands {
         after_long_help = ""
     )]
     Cache(CacheNamespace),
+    /// Inspect uv's configuration.
+    #[command(
+        after_help = "Use `uv help config` for more details.",
+        after_long_help = ""
+    )]
+    Config(ConfigNamespace),
     /// Manage the uv executable.
     #[command(name = "self")]
     Self_(SelfNamespace),
@@ -617,6 +816,12 @@ pub struct VersionArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -760,6 +965,34 @@ pub enum CacheCommand {
     Dir,
 }
 
+#[derive(Args)]
+pub struct ConfigNamespace {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Show where the value of a setting comes from.
+    ///
+    /// Lists every layer that can provide a value for the setting, in order of precedence: the
+    /// command line, environment variables, the `--config-file`, project, user, and system
+    /// configuration files, and the default. The layer whose value is used is marked as such. For
+    /// settings whose values are merged across layers, e.g., `python-discovery.exclude`, every
+    /// layer that contributes to the value is marked.
+    ///
+    /// Global options, e.g., `--managed-python` or `--no-config`, are taken into account, so
+    /// `uv config where` can be run with the same options as the command being debugged.
+    Where(ConfigWhereArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct ConfigWhereArgs {
+    /// The setting to inspect.
+    #[arg(long, value_enum)]
+    pub key: ConfigKey,
+}
+
 #[derive(Args, Debug)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
@@ -783,6 +1016,17 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Only remove ephemeral environments left behind by uv processes that were killed.
+    ///
+    /// Ephemeral environments (e.g., those created by `uv run --with` or `uv run --isolated`) are
+    /// removed when the owning process exits. If the process is killed, the environment is left in
+    /// the cache; such environments are also removed by a default `uv cache prune` and,
+    /// opportunistically, the next time uv creates an ephemeral environment.
+    ///
+    /// Environments kept by `uv run --keep-env-on-failure` are removed too.
+    #[arg(long, conflicts_with = "ci")]
+    pub ephemeral: bool,
 }
 
 #[derive(Args)]
@@ -883,6 +1127,12 @@ pub enum ProjectCommand {
     /// options to uv must be provided before the command, e.g., `uv run --verbose foo`. A `--` can
     /// be used to separate the command from uv options for clarity, e.g., `uv run --python 3.12 --
     /// python`.
+    ///
+    /// To run a command in another directory, use `--directory`, e.g., `uv run --directory
+    /// path/to/project pytest`. The command is run in the given directory, and the project,
+    /// `.python-version` files, `--env-file` paths, and relative `--with` requirements are all
+    /// resolved relative to it. To change the project root while resolving relative paths from the
+    /// current directory, use `--project` instead.
     #[command(
         after_help = "Use `uv help run` for more details.",
         after_long_help = ""
@@ -1180,6 +1430,43 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a platform for `uv python markers`, as either a target triple (e.g.,
+/// `aarch64-unknown-linux-gnu`) or a platform in the form `<os>-<arch>[-<libc>]` (e.g.,
+/// `linux-aarch64`).
+fn parse_markers_platform(input: &str) -> Result<TargetTriple, String> {
+    if let Ok(triple) = <TargetTriple as clap::ValueEnum>::from_str(input, true) {
+        return Ok(triple);
+    }
+
+    let mut parts = input.split('-');
+    let (Some(os), Some(arch), libc, None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(format!(
+            "`{input}` is not a target triple or a platform in the form `<os>-<arch>[-<libc>]`"
+        ));
+    };
+    let arch = match arch {
+        "amd64" => "x86_64",
+        "arm64" => "aarch64",
+        "x86" => "i686",
+        arch => arch,
+    };
+    let triple = match (os, libc) {
+        ("linux", None) => format!("{arch}-unknown-linux-gnu"),
+        ("linux", Some(libc)) => format!("{arch}-unknown-linux-{libc}"),
+        ("macos" | "darwin", None) => format!("{arch}-apple-darwin"),
+        ("windows", None) => format!("{arch}-pc-windows-msvc"),
+        _ => {
+            return Err(format!(
+                "`{input}` is not a target triple or a platform in the form `<os>-<arch>[-<libc>]`"
+            ));
+        }
+    };
+    <TargetTriple as clap::ValueEnum>::from_str(&triple, true)
+        .map_err(|_| format!("The platform `{input}` is not supported"))
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipCompileArgs {
@@ -1462,6 +1749,26 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("universal"), hide = true)]
     pub no_universal: bool,
 
+    /// Resolve for the Python interpreter in the Windows Subsystem for Linux (WSL).
+    ///
+    /// uv queries the `python3` executable in WSL for its version and platform, which are used in
+    /// place of `--python-version` and `--python-platform`, e.g., to resolve requirements for a
+    /// Linux deployment from Windows. The interpreter in WSL is only used to determine the target
+    /// of the resolution; source distributions are built with a local interpreter.
+    ///
+    /// Only supported on Windows.
+    #[arg(long, conflicts_with_all = ["python_version", "python_platform", "universal"], help_heading = "Python options")]
+    pub wsl: bool,
+
+    /// The WSL distribution to resolve for with `--wsl`, rather than the default distribution.
+    #[arg(
+        long,
+        requires = "wsl",
+        value_name = "DISTRIBUTION",
+        help_heading = "Python options"
+    )]
+    pub wsl_distribution: Option<String>,
+
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[arg(long, alias = "unsafe-package")]
@@ -1787,6 +2094,12 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2102,6 +2415,12 @@ pub struct PipInstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`)
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -2206,6 +2525,12 @@ pub struct PipUninstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     #[command(flatten)]
     pub compat_args: compat::PipGlobalCompatArgs,
 }
@@ -2300,6 +2625,15 @@ pub struct PipListArgs {
     #[arg(long, overrides_with("strict"), hide = true)]
     pub no_strict: bool,
 
+    /// Include packages from the environments that the target environment extends.
+    ///
+    /// Ephemeral environments (e.g., those created by `uv run --with`) record the environment
+    /// they layer on top of with the `extends-environment` key in `pyvenv.cfg`. With this flag,
+    /// packages from each environment in that chain are listed as well, unless shadowed by a
+    /// package of the same name in a nearer environment.
+    #[arg(long)]
+    pub include_parent: bool,
+
     #[command(flatten)]
     pub fetch: FetchArgs,
 
@@ -2674,6 +3008,78 @@ pub struct VenvArgs {
     #[arg(long, alias = "no-workspace")]
     pub no_project: bool,
 
+    /// Show where the interpreter of an existing virtual environment came from, rather than
+    /// creating a virtual environment.
+    ///
+    /// Displays the provenance recorded in the `uv-environment.toml` file when uv created the
+    /// environment, including the interpreter's installation key, whether it is managed by uv, and
+    /// the URL and hash of its download, if any.
+    #[arg(long, conflicts_with_all = ["python", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub show: bool,
+
+    /// Recreate an existing virtual environment in place with a different Python interpreter.
+    ///
+    /// If the environment is the project environment and the project has a lockfile, the new
+    /// environment is synced from the lockfile. Otherwise, the packages installed in the existing
+    /// environment are reinstalled into the new environment at the same versions, except for
+    /// editable installations. If the new environment cannot be created, the existing environment
+    /// is restored.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(long, value_name = "PYTHON", conflicts_with_all = ["python", "show", "clear", "allow_existing", "relocatable", "python_platform"])]
+    pub rebase: Option<String>,
+
+    /// Update the prompt of an existing virtual environment in place, rather than creating a
+    /// virtual environment.
+    ///
+    /// Rewrites the `pyvenv.cfg` file and the activation scripts of the environment, e.g., after
+    /// renaming a project, such that shells and editors display the new name.
+    ///
+    /// If "." is provided, the current directory name will be used.
+    #[arg(long, value_name = "PROMPT", conflicts_with_all = ["python", "show", "rebase", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub rename_prompt: Option<String>,
+
+    /// Adopt an existing virtual environment created by another tool, e.g., `virtualenv`, `venv`,
+    /// or Poetry, rather than creating a virtual environment.
+    ///
+    /// The environment at the given path is moved to the virtual environment path (e.g., the
+    /// project's `.venv`), unless it's already there. Entry points that refer to the previous
+    /// location are re-linked, uv's activation scripts are written with the environment's existing
+    /// prompt, and the interpreter is recorded as with any environment created by uv. The installed
+    /// packages are retained, such that a subsequent `uv sync` only installs the differences.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["python", "show", "rebase", "rename_prompt", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub adopt: Option<PathBuf>,
+
+    /// Lock an existing virtual environment against modification, rather than creating a virtual
+    /// environment.
+    ///
+    /// Commands that would install or uninstall packages in, recreate, or reconfigure a locked
+    /// environment, e.g., `uv sync`, `uv pip install`, or `uv venv --clear`, fail instead, unless
+    /// `--unlock` is provided. The lock is recorded in the environment's `pyvenv.cfg` file.
+    #[arg(long, conflicts_with_all = ["python", "show", "rebase", "rename_prompt", "adopt", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub lock_environment: bool,
+
+    /// Unlock an existing virtual environment that was locked with `--lock-environment`, rather
+    /// than creating a virtual environment.
+    #[arg(long, conflicts_with_all = ["python", "show", "rebase", "rename_prompt", "adopt", "lock_environment", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub unlock_environment: bool,
+
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Applies when recreating an existing environment, e.g., with `--clear` or `--rebase`, or when
+    /// updating its `pyvenv.cfg` file, e.g., with `--rename-prompt`.
+    #[arg(long, conflicts_with_all = ["show", "lock_environment", "unlock_environment"])]
+    pub unlock: bool,
+
+    /// Update whether an existing virtual environment has access to the system site packages
+    /// directory, rather than creating a virtual environment.
+    ///
+    /// Rewrites the `include-system-site-packages` key in the environment's `pyvenv.cfg` file, then
+    /// checks that the environment's interpreter includes (or excludes) the system site packages
+    /// directory on `sys.path` accordingly. If it doesn't, the previous value is restored.
+    #[arg(long, value_name = "BOOL", conflicts_with_all = ["python", "show", "rebase", "rename_prompt", "adopt", "lock_environment", "unlock_environment", "seed", "clear", "allow_existing", "prompt", "system_site_packages", "relocatable", "python_platform"])]
+    pub set_system_site_packages: Option<bool>,
+
     /// Install seed packages (one or more of: `pip`, `setuptools`, and `wheel`) into the virtual environment.
     ///
     /// Note that `setuptools` and `wheel` are not included in Python 3.12+ environments.
@@ -2727,6 +3133,15 @@ pub struct VenvArgs {
     #[arg(long)]
     pub system_site_packages: bool,
 
+    /// Give the virtual environment access to the user site packages directory.
+    ///
+    /// By default, virtual environments exclude the user site packages directory (e.g., where
+    /// `pip install --user` installs packages). With `--allow-user-site`, it's added to the
+    /// module search path at runtime, after the environment's own site packages. The setting is
+    /// recorded in the environment's `pyvenv.cfg`.
+    #[arg(long)]
+    pub allow_user_site: bool,
+
     /// Make the virtual environment relocatable.
     ///
     /// A relocatable virtual environment can be moved around and redistributed without invalidating
@@ -2743,6 +3158,20 @@ pub struct VenvArgs {
     #[arg(long)]
     pub relocatable: bool,
 
+    /// Create a resolution-only environment for the given platform.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// The platform is recorded in the environment's `pyvenv.cfg`, and commands that resolve or
+    /// install packages for the environment, like `uv pip install` and `uv pip compile`, will
+    /// target it as if `--python-platform` was provided. Since the installed packages may not be
+    /// compatible with the current machine, project commands, like `uv sync` and `uv run`, will
+    /// refuse to use the environment.
+    #[arg(long, conflicts_with = "seed", help_heading = "Python options")]
+    pub python_platform: Option<TargetTriple>,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -2782,7 +3211,11 @@ pub struct VenvArgs {
 
     /// The method to use when installing packages from the global cache.
     ///
-    /// This option is only used for installing seed packages.
+    /// The link mode is recorded in the environment's `pyvenv.cfg`, and used for subsequent
+    /// installations into the environment, e.g., with `uv sync` or `uv pip install`, unless
+    /// another link mode is requested. This is useful when the environment and the cache are on
+    /// different filesystems, where hard links are impossible, or on network filesystems, where
+    /// they're undesirable.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
     /// Windows.
@@ -3159,6 +3592,145 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Run with the packages in the given dependency group installed, at their locked versions.
+    ///
+    /// Rather than syncing the group into the project environment, its packages are installed
+    /// from the lockfile into a separate, cached environment that is layered on top of the
+    /// project environment, as with `--with`. The project environment is left unchanged.
+    ///
+    /// May be provided multiple times.
+    #[arg(
+        long,
+        value_name = "GROUP",
+        conflicts_with_all = ["with", "with_editable", "with_requirements", "no_sync", "no_project", "script", "gui_script"]
+    )]
+    pub with_requirements_from: Vec<GroupName>,
+
+    /// How to layer the `--with` requirements on top of the project environment.
+    ///
+    /// By default, uv references the site packages of the project environment and the `--with`
+    /// requirements in place. Cloning them into the ephemeral environment isolates the command
+    /// from concurrent changes to the project environment.
+    #[arg(long, value_enum, default_value_t = OverlayMode::default())]
+    pub overlay_mode: OverlayMode,
+
+    /// Require a matching hash for each `--with` requirement.
+    ///
+    /// Without a lockfile, the hashes are sourced from the index during resolution, and each
+    /// distribution is verified against them during installation. If the index doesn't provide
+    /// hashes for a distribution, e.g., for Git or local directory requirements, uv will exit with
+    /// an error.
+    ///
+    /// The project environment is unaffected.
+    #[arg(long, overrides_with("no_require_hashes"))]
+    pub require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub no_require_hashes: bool,
+
+    /// Allow the command to import packages from the user site packages directory.
+    ///
+    /// By default, the user site packages directory (e.g., where `pip install --user` installs
+    /// packages) is excluded from virtual environments, and `PYTHONNOUSERSITE` is set when the
+    /// command runs in an ephemeral environment (e.g., with `--with`), such that packages outside
+    /// of the environment can't leak into the command.
+    ///
+    /// With `--allow-user-site`, the user site packages are added to the command's module search
+    /// path with the lowest precedence. Environments created with `uv venv --allow-user-site`
+    /// include them already.
+    #[arg(long)]
+    pub allow_user_site: bool,
+
+    /// Replace the uv process with the command, rather than running it as a child process.
+    ///
+    /// The command inherits uv's process ID, such that it receives signals directly from, e.g., a
+    /// service manager, and uv doesn't remain in the process tree. As uv doesn't wait for the
+    /// command to exit, `--summary`, `--keep-env-on-failure`, `--exit-code-map`,
+    /// `--shutdown-timeout`, and `--fs-trace` are not supported, and temporary environments aren't
+    /// removed when the command exits; they're removed by a later `uv run` or
+    /// `uv cache prune --ephemeral`.
+    ///
+    /// Only supported on Unix; on Windows, the command is run as a child process.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "summary",
+            "summary_json",
+            "check_only",
+            "keep_env_on_failure",
+            "exit_code_map",
+            "shutdown_timeout",
+            "fs_trace",
+        ]
+    )]
+    pub exec: bool,
+
+    /// Keep the ephemeral environment in which the command ran if it fails.
+    ///
+    /// By default, ephemeral environments, e.g., for `--with` requirements, `--isolated`, or
+    /// scripts, are removed when the command exits. With this flag, if the command exits with a
+    /// non-zero status, the environment is kept in the cache and its path is printed, such that it
+    /// can be inspected. Kept environments are removed by `uv cache prune`.
+    #[arg(long, conflicts_with = "exec")]
+    pub keep_env_on_failure: bool,
+
+    /// Write a trace of the file system operations performed while composing the environment to
+    /// the given JSON file.
+    ///
+    /// The trace lists the lockfiles read and written, the files created in ephemeral virtual
+    /// environments, and the entrypoints, site packages, and directories copied or linked into
+    /// them. Useful for debugging what `uv run` changed on disk. Packages installed into the
+    /// project environment are not traced.
+    ///
+    /// When running with multiple Python versions, a trace is written for each version, with the
+    /// Python request appended to the path, e.g., `trace.json.3.12`.
+    #[arg(long, value_name = "PATH")]
+    pub fs_trace: Option<PathBuf>,
+
+    /// Print a summary of the work performed to compose the environment after the command exits.
+    ///
+    /// The summary includes the interpreter used and where it came from, whether the lockfile was
+    /// reused or updated, the number of packages installed and uninstalled, whether the
+    /// environment for `--with` requirements was found in the cache, and the time spent locking,
+    /// syncing, and running the command.
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Write a summary of the work performed to compose the environment to the given JSON file
+    /// after the command exits.
+    ///
+    /// See `--summary` for the contents of the summary.
+    #[arg(long, value_name = "PATH")]
+    pub summary_json: Option<PathBuf>,
+
+    /// Re-run the most recent command run in the project.
+    ///
+    /// The command, `--python` request, and `--with`, `--with-editable`, and `--with-requirements`
+    /// arguments of the previous invocation are reused. Other options, e.g., `--locked`, are taken
+    /// from the current invocation, and the project is locked and synced as usual, such that the
+    /// command runs against the current state of the lockfile.
+    ///
+    /// Invocations in a project are recorded in the project environment.
+    #[arg(
+        long,
+        conflicts_with_all = ["with", "with_editable", "with_requirements", "module", "script", "gui_script"]
+    )]
+    pub last: bool,
+
+    /// Show the recent commands run in the project, along with their exit codes and durations.
+    #[arg(long, conflicts_with = "last")]
+    pub history: bool,
+
+    /// Avoid recording the command in the project's history.
+    ///
+    /// By default, the command and its arguments are recorded in the project environment, for use
+    /// with `--last` and `--history`. Commands whose arguments include secrets should not be
+    /// recorded.
+    ///
+    /// May also be set with the `run.history` setting.
+    #[arg(long, env = EnvVars::UV_NO_RUN_HISTORY, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "history")]
+    pub no_history: bool,
+
     /// Run the command in an isolated virtual environment.
     ///
     /// Usually, the project environment is reused for performance. This option forces a fresh
@@ -3172,6 +3744,30 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_ISOLATED, value_parser = clap::builder::BoolishValueParser::new())]
     pub isolated: bool,
 
+    /// Whether the command may access the network, when run in an isolated environment.
+    ///
+    /// With `off`, the command is run without network access. On Linux, the command is run in a
+    /// new network namespace, with only a loopback interface. On other platforms, the standard
+    /// proxy environment variables are pointed at an unreachable address instead, which is
+    /// advisory rather than isolation: programs that ignore them can still access the network.
+    ///
+    /// uv may still access the network to prepare the environment.
+    ///
+    /// May also be set with the `run.network` setting.
+    #[arg(long, value_enum, requires = "isolated")]
+    pub network: Option<NetworkAccess>,
+
+    /// The sources of the `PATH` entries for the command, in order of precedence.
+    ///
+    /// Accepts a comma-separated list of `overlay` (the scripts directories of the `--with`
+    /// environments), `base` (the scripts directory of the project environment), and `inherit` (the
+    /// `PATH` in which uv was invoked). Omitted sources are excluded, e.g., `--path-policy base`
+    /// exposes only the project's scripts. Use `none` to clear `PATH` entirely.
+    ///
+    /// Defaults to `overlay,base,inherit`. May also be set with the `run.path-policy` setting.
+    #[arg(long, value_name = "SOURCES")]
+    pub path_policy: Option<PathPolicy>,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -3192,6 +3788,55 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_sync: bool,
 
+    /// Check that the environment is ready to run the command, without running it.
+    ///
+    /// The Python interpreter is discovered, the lockfile is checked as with `--locked`, and the
+    /// changes that a sync would make to the environment are reported. If the lockfile or the
+    /// environment would need to be updated, uv will exit with an error.
+    ///
+    /// Any `--with` requirements are not checked.
+    #[arg(long, conflicts_with_all = ["no_sync", "isolated"])]
+    pub check_only: bool,
+
+    /// Raise warnings about the environment as errors.
+    ///
+    /// By default, uv falls back to a usable environment with a warning when (e.g.) `VIRTUAL_ENV`
+    /// doesn't match the project environment and is ignored, an incompatible environment is used
+    /// due to `--no-sync`, or a script has no lockfile despite `--locked` or `--frozen`. With
+    /// `--fail-fast-env`, these fallbacks are errors instead, with a stable code identifying each,
+    /// e.g., for continuous integration.
+    ///
+    /// May also be set with the `fail-fast-env` setting.
+    #[arg(
+        long,
+        overrides_with("no_fail_fast_env"),
+        env = EnvVars::UV_FAIL_FAST_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub fail_fast_env: bool,
+
+    #[arg(long, overrides_with("fail_fast_env"), hide = true)]
+    pub no_fail_fast_env: bool,
+
+    /// The scheme used to distinguish uv's own failures from the exit code of the command.
+    ///
+    /// By default, uv exits with `1` or `2` if it fails, e.g., to resolve the dependencies or to
+    /// find a Python interpreter, while the exit code of the command is propagated as-is. With
+    /// `--exit-code-map reserved`, uv instead exits with a code of `200` or greater that
+    /// identifies the kind of failure, such that continuous integration can tell a command that
+    /// failed apart from an environment that could not be prepared.
+    #[arg(long, env = EnvVars::UV_EXIT_CODE_MAP, value_enum)]
+    pub exit_code_map: Option<ExitCodeMap>,
+
+    /// Raise an error if the Python interpreter differs from the one recorded in the lockfile.
+    ///
+    /// When `lock-interpreter` is enabled, uv records the implementation, minor version, and
+    /// variant of the interpreter used to lock the project, along with the range of Python versions
+    /// requested by the project, and warns if the project is used with a different interpreter.
+    /// With `--strict-python`, the difference is an error instead.
+    #[arg(long)]
+    pub strict_python: bool,
+
     /// Assert that the `uv.lock` will remain unchanged.
     ///
     /// Requires that the lockfile is up-to-date. If the lockfile is missing or
@@ -3208,6 +3853,12 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     /// Run the given path as a Python script.
     ///
     /// Using `--script` will attempt to parse the path as a PEP 723 script,
@@ -3222,6 +3873,36 @@ pub struct RunArgs {
     #[arg(long, conflicts_with_all = ["script", "module"])]
     pub gui_script: bool,
 
+    /// Launch an IPython REPL with access to the project environment.
+    ///
+    /// IPython is installed into a separate, cached environment that is layered on top of the
+    /// project environment, as with `--with`, and is not added to the project's dependencies.
+    #[arg(long, conflicts_with_all = ["ptpython", "module", "script", "gui_script"])]
+    pub ipython: bool,
+
+    /// Launch a ptpython REPL with access to the project environment.
+    ///
+    /// ptpython is installed into a separate, cached environment that is layered on top of the
+    /// project environment, as with `--with`, and is not added to the project's dependencies.
+    #[arg(long, conflicts_with_all = ["ipython", "module", "script", "gui_script"])]
+    pub ptpython: bool,
+
+    /// Register a Jupyter kernel with the given name that runs in the project environment.
+    ///
+    /// The kernel spec is written to the user's Jupyter data directory and invokes `uv run` when
+    /// the kernel is launched, such that the environment, including any `--with` requirements, is
+    /// kept in sync with the project. `ipykernel` is installed into a separate, cached environment
+    /// that is layered on top of the project environment, as with `--with`.
+    ///
+    /// The kernel is launched with the other arguments of this invocation, from the current
+    /// directory, such that relative paths are resolved as they are when the kernel is registered.
+    #[arg(
+        long,
+        value_name = "NAME",
+        conflicts_with_all = ["ipython", "ptpython", "module", "script", "gui_script", "check_only"]
+    )]
+    pub kernel: Option<String>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3261,6 +3942,10 @@ pub struct RunArgs {
     /// If the interpreter request is satisfied by a discovered environment, the environment will be
     /// used.
     ///
+    /// Multiple Python versions may be provided as a comma-separated list (e.g.,
+    /// `--python 3.10,3.11,3.12`), in which case the command will be run once for each version in
+    /// an isolated environment.
+    ///
     /// See `uv help python` to view supported request formats.
     #[arg(
         long,
@@ -3272,12 +3957,40 @@ pub struct RunArgs {
     )]
     pub python: Option<Maybe<String>>,
 
+    /// When multiple Python versions are requested, run the command for each version
+    /// concurrently.
+    ///
+    /// The output of each run is buffered and shown once the run completes, such that the output
+    /// of concurrent runs is not interleaved.
+    ///
+    /// By default, the command is run for each version sequentially.
+    #[arg(long, requires = "python", help_heading = "Python options")]
+    pub parallel: bool,
+
     /// Whether to show resolver and installer output from any environment modifications.
     ///
     /// By default, environment modifications are omitted, but enabled under `--verbose`.
     #[arg(long, env = EnvVars::UV_SHOW_RESOLUTION, value_parser = clap::builder::BoolishValueParser::new(), hide = true)]
     pub show_resolution: bool,
 
+    /// Where to forward signals received by uv while the command is running.
+    ///
+    /// By default, signals are forwarded to the child process. With `group`, the command is run
+    /// in a new process group and signals are forwarded to every process in that group. Since the
+    /// group is not in the foreground of the terminal, `group` is intended for non-interactive use,
+    /// e.g., under systemd or Kubernetes.
+    ///
+    /// Only supported on Unix.
+    #[arg(long, value_enum, default_value_t = SignalForwarding::default())]
+    pub signal_forwarding: SignalForwarding,
+
+    /// The number of seconds to wait for the command to exit after an interrupt or termination
+    /// signal, before killing it.
+    ///
+    /// By default, uv waits for the command to exit indefinitely.
+    #[arg(long, value_name = "SECONDS")]
+    pub shutdown_timeout: Option<u64>,
+
     /// Number of times that `uv run` will allow recursive invocations.
     ///
     /// The current recursion depth is tracked by environment variable. If environment variables are
@@ -3466,20 +4179,62 @@ pub struct SyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
-    #[command(flatten)]
-    pub installer: ResolverInstallerArgs,
-
-    #[command(flatten)]
-    pub build: BuildOptionsArgs,
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
 
-    #[command(flatten)]
-    pub refresh: RefreshArgs,
+    /// Raise an error if the Python interpreter differs from the one recorded in the lockfile.
+    ///
+    /// When `lock-interpreter` is enabled, uv records the implementation, minor version, and
+    /// variant of the interpreter used to lock the project, along with the range of Python versions
+    /// requested by the project, and warns if the project is used with a different interpreter.
+    /// With `--strict-python`, the difference is an error instead.
+    #[arg(long)]
+    pub strict_python: bool,
 
-    /// Sync all packages in the workspace.
+    /// Raise warnings about the environment as errors.
     ///
-    /// The workspace's environment (`.venv`) is updated to include all workspace members.
+    /// By default, uv falls back to a usable environment with a warning when (e.g.) `VIRTUAL_ENV`
+    /// doesn't match the project environment and is ignored, or an incompatible environment is
+    /// used due to `--no-sync`. With `--fail-fast-env`, these fallbacks are errors instead, with a
+    /// stable code identifying each, e.g., for continuous integration.
     ///
-    /// Any extras or groups specified via `--extra`, `--group`, or related options will be applied
+    /// May also be set with the `fail-fast-env` setting.
+    #[arg(
+        long,
+        overrides_with("no_fail_fast_env"),
+        env = EnvVars::UV_FAIL_FAST_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub fail_fast_env: bool,
+
+    #[arg(long, overrides_with("fail_fast_env"), hide = true)]
+    pub no_fail_fast_env: bool,
+
+    /// The scheme used to distinguish the kinds of failure in uv's exit code.
+    ///
+    /// By default, uv exits with `1` or `2` if it fails. With `--exit-code-map reserved`, uv
+    /// instead exits with a code of `200` or greater that identifies the kind of failure, e.g.,
+    /// `203` if the lockfile is out of date under `--locked`, as with `uv run`.
+    #[arg(long, env = EnvVars::UV_EXIT_CODE_MAP, value_enum)]
+    pub exit_code_map: Option<ExitCodeMap>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Sync all packages in the workspace.
+    ///
+    /// The workspace's environment (`.venv`) is updated to include all workspace members.
+    ///
+    /// Any extras or groups specified via `--extra`, `--group`, or related options will be applied
     /// to all workspace members.
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
@@ -3555,6 +4310,18 @@ pub struct SyncArgs {
     #[arg(long)]
     pub python_platform: Option<TargetTriple>,
 
+    /// Install packages into the specified directory, rather than into the project environment.
+    ///
+    /// The packages will be installed at the top-level of the directory, as with `uv pip install
+    /// --target`. The project environment is neither created nor modified.
+    ///
+    /// Combine with `--python-platform` to populate the directory with packages for a different
+    /// platform, e.g., to build an AWS Lambda bundle. In that case, any package without a wheel
+    /// for the target platform is rejected instead of being built from source for the current
+    /// platform. Local projects, like workspace members, are still built.
+    #[arg(long, conflicts_with_all = ["active", "no_active", "check", "script"])]
+    pub target: Option<PathBuf>,
+
     /// Check if the Python environment is synchronized with the project.
     ///
     /// If the environment is not up to date, uv will exit with an error.
@@ -3563,6 +4330,38 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Verify that the Python environment matches the lockfile, without modifying it.
+    ///
+    /// Each installed package is compared against the lockfile, and the files of each package are
+    /// hashed and compared against its `RECORD`. Packages that are missing from the environment,
+    /// extraneous to the lockfile, or modified after installation are reported.
+    ///
+    /// The lockfile is used as-is, as with `--frozen`; pass `--locked` to additionally assert that
+    /// it is up-to-date. If the environment has drifted from the lockfile, uv will exit with an
+    /// error.
+    #[arg(long, conflicts_with_all = ["check", "dry_run", "target", "script"])]
+    pub verify: bool,
+
+    /// Export an activation script for the project environment after syncing.
+    ///
+    /// The script exports `VIRTUAL_ENV` and `UV_PROJECT_ENVIRONMENT`, and prepends the
+    /// environment's scripts directory to `PATH`. It's regenerated on every sync, such that it
+    /// tracks the project environment.
+    ///
+    /// By default, an `.envrc` file is written to the project root, for use with `direnv`. Other
+    /// shells write to the `.uv` directory in the project root, e.g., `.uv/activate.fish`.
+    ///
+    /// May be provided more than once.
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SHELL",
+        num_args = 0..=1,
+        default_missing_value = "direnv",
+        conflicts_with_all = ["target", "script"]
+    )]
+    pub export_activate: Vec<ActivationShell>,
 }
 
 #[derive(Args)]
@@ -3596,6 +4395,25 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Raise warnings about the environment as errors.
+    ///
+    /// By default, uv falls back to a usable environment with a warning when (e.g.) `VIRTUAL_ENV`
+    /// doesn't match the project environment and is ignored, or an incompatible environment is
+    /// used due to `--no-sync`. With `--fail-fast-env`, these fallbacks are errors instead, with a
+    /// stable code identifying each, e.g., for continuous integration.
+    ///
+    /// May also be set with the `fail-fast-env` setting.
+    #[arg(
+        long,
+        overrides_with("no_fail_fast_env"),
+        env = EnvVars::UV_FAIL_FAST_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub fail_fast_env: bool,
+
+    #[arg(long, overrides_with("fail_fast_env"), hide = true)]
+    pub no_fail_fast_env: bool,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3758,6 +4576,12 @@ pub struct AddArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     /// Prefer the active virtual environment over the project's virtual environment.
     ///
     /// If the project virtual environment is active or no virtual environment is active, this has
@@ -3909,6 +4733,12 @@ pub struct RemoveArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with_all = ["locked", "upgrade", "no_sources"])]
     pub frozen: bool,
 
+    /// Modify the environment even if it's locked against modification.
+    ///
+    /// Environments can be locked with `uv venv --lock-environment`.
+    #[arg(long)]
+    pub unlock: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -4276,6 +5106,14 @@ pub struct ExportArgs {
     #[arg(long, conflicts_with_all = ["all_packages", "package", "no_emit_project", "no_emit_workspace"])]
     pub script: Option<PathBuf>,
 
+    /// Write the locked dependencies to the inline metadata of the given Python script.
+    ///
+    /// The `dependencies` in the script's PEP 723 metadata table are replaced with the packages in
+    /// the lockfile, each pinned to its locked version. If the script doesn't have a metadata
+    /// table, one is created. Editable and virtual packages, like the project itself, are omitted.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["script", "format", "output_file"])]
+    pub to_script: Option<PathBuf>,
+
     /// The Python interpreter to use during resolution.
     ///
     /// A Python interpreter is required for building source distributions to determine package
@@ -4610,6 +5448,24 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Raise warnings about the environment as errors.
+    ///
+    /// By default, uv falls back to a usable environment with a warning when (e.g.) the existing
+    /// tool has an invalid receipt and is removed. With `--fail-fast-env`, these fallbacks are
+    /// errors instead, with a stable code identifying each, e.g., for continuous integration.
+    ///
+    /// May also be set with the `fail-fast-env` setting.
+    #[arg(
+        long,
+        overrides_with("no_fail_fast_env"),
+        env = EnvVars::UV_FAIL_FAST_ENV,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub fail_fast_env: bool,
+
+    #[arg(long, overrides_with("fail_fast_env"), hide = true)]
+    pub no_fail_fast_env: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported request formats.
@@ -4999,6 +5855,61 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats and details on discovery behavior.
     Find(PythonFindArgs),
 
+    /// Show details about a Python interpreter, including its optional standard library features.
+    ///
+    /// Reports whether the interpreter includes modules that some builds of Python omit, e.g.,
+    /// `ssl`, `sqlite3`, `lzma`, and `tkinter`. An interpreter can be required to include a feature
+    /// with a request like `3.12+ssl`.
+    ///
+    /// Also reports the interpreter's ABI, i.e., its `SOABI` and `EXT_SUFFIX`, and the libc it was
+    /// built against. An interpreter can be required to use a given libc with a request like
+    /// `cpython-3.12-linux-x86_64-musl`.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Inspect(PythonInspectArgs),
+
+    /// Show the environment markers for a Python interpreter.
+    ///
+    /// Displays the PEP 508 marker environment that uv uses to evaluate dependency markers. The
+    /// Python version, platform, and implementation can be overridden to show the markers that uv
+    /// would synthesize when resolving for a different target, e.g., with `--python-platform`.
+    /// Markers that aren't overridden are taken from the discovered Python interpreter.
+    Markers(PythonMarkersArgs),
+
+    /// Show which executable `uv run` would invoke for a command, and the interpreter it runs with.
+    ///
+    /// Resolves the command as `uv run` would, searching the scripts directory of the project
+    /// environment (or of the discovered interpreter, outside a project) and then the `PATH`, in
+    /// the order given by `--path-policy`. When invoked within `uv run --with`, the scripts of the
+    /// layered environment are searched first.
+    ///
+    /// Displays the path to the executable, where it was found, the Python interpreter its shebang
+    /// (or, on Windows, its launcher) refers to, and the kind of environment that interpreter
+    /// belongs to, e.g., the project environment or a system Python installation.
+    Which(PythonWhichArgs),
+
+    /// Show the Python download that would be used for a request, without downloading it.
+    ///
+    /// Displays the installation key, URL, size, and SHA-256 checksum of the distribution that uv
+    /// would fetch if no installed Python satisfied the request, using the same selection as other
+    /// commands that download Python automatically. Installed Python versions are not considered.
+    ///
+    /// The size is read from the cache or requested from the server, and is omitted if unknown.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Resolve(PythonResolveArgs),
+
+    /// Check that the Python downloads in the download manifest are available from their source.
+    ///
+    /// Requests the archive of every download, using the configured mirrors and download manifest
+    /// (`--python-downloads-json-url`), and reports any that are missing. Use `--full` to download
+    /// each archive, without installing it, and compare it against its expected checksum.
+    ///
+    /// Exits with an error if any download is missing or corrupted.
+    ///
+    /// See `uv help python` to view supported request formats.
+    ValidateDownloads(PythonValidateDownloadsArgs),
+
     /// Pin to a specific Python version.
     ///
     /// Writes the pinned Python version to a `.python-version` file, which is used by other uv
@@ -5024,9 +5935,36 @@ pub enum PythonCommand {
     /// Python executables are only installed when preview mode is enabled.
     Dir(PythonDirArgs),
 
+    /// Show the disk space used by uv-managed Python installations.
+    ///
+    /// Files that are hardlinked from elsewhere, e.g., from the uv cache, are only counted once
+    /// and are reported as shared, since removing the installation alone will not reclaim that
+    /// space.
+    Du(PythonDuArgs),
+
+    /// Show the environment variables needed to build against a Python installation.
+    ///
+    /// Prints `CPATH`, `LIBRARY_PATH`, and `PKG_CONFIG_PATH` (or `INCLUDE` and `LIB` on Windows)
+    /// entries pointing at the include and library directories of the installation, for use when
+    /// building C extensions or embedding Python. `PYTHONHOME` is not set.
+    ///
+    /// By default, the commands to prepend the entries in the current shell are printed.
+    ///
+    /// See `uv help python` to view supported request formats.
+    EnvVars(PythonEnvVarsArgs),
+
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 
+    /// Protect managed Python installations from uninstallation.
+    ///
+    /// Protected installations are skipped by `uv python uninstall`, including with `--all`,
+    /// unless `--force` is provided. Use `--remove` to lift the protection.
+    ///
+    /// The requests must match installed versions. See `uv help python` to view supported request
+    /// formats.
+    PinInstall(PythonPinInstallArgs),
+
     /// Ensure that the Python executable directory is on the `PATH`.
     ///
     /// If the Python executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -5088,6 +6026,17 @@ pub struct PythonListArgs {
     #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
     pub output_format: PythonListFormat,
 
+    /// Only show managed Python installations with a newer patch release available.
+    ///
+    /// The latest installed patch version of each minor version is compared against the latest
+    /// available download for the same implementation and platform.
+    #[arg(long, conflicts_with_all = ["only_downloads", "all_versions", "all_platforms", "all_arches", "show_urls"])]
+    pub outdated: bool,
+
+    /// Exit with a non-zero status if any managed Python installation is outdated.
+    #[arg(long, requires = "outdated")]
+    pub exit_code: bool,
+
     /// URL pointing to JSON of custom Python installations.
     ///
     /// Note that currently, only local paths are supported.
@@ -5112,6 +6061,32 @@ pub struct PythonDirArgs {
     pub bin: bool,
 }
 
+#[derive(Args)]
+pub struct PythonDuArgs {
+    /// The Python version(s) to show disk usage for.
+    ///
+    /// By default, all uv-managed Python installations are included.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonDuFormat::default())]
+    pub output_format: PythonDuFormat,
+}
+
+#[derive(Args)]
+pub struct PythonEnvVarsArgs {
+    /// The Python request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: Option<String>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonEnvVarsFormat::default())]
+    pub output_format: PythonEnvVarsFormat,
+}
+
 #[derive(Args)]
 pub struct PythonInstallArgs {
     /// The directory to store the Python installation in.
@@ -5192,6 +6167,9 @@ pub struct PythonInstallArgs {
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
 
+    #[command(flatten)]
+    pub download_client: PythonDownloadClientArgs,
+
     /// Reinstall the requested Python version, if it's already installed.
     ///
     /// By default, uv will exit successfully if the version is already
@@ -5220,6 +6198,83 @@ pub struct PythonInstallArgs {
     /// If multiple Python versions are requested, uv will exit with an error.
     #[arg(long, conflicts_with("no_bin"))]
     pub default: bool,
+
+    /// The release channel to install Python versions from.
+    ///
+    /// By default, pre-releases are only installed if explicitly requested (e.g., `3.14.0rc1`) or
+    /// if there are no stable releases of the requested version. When a channel is provided, only
+    /// releases in the channel are installed, e.g., `--channel rc` allows release candidates and
+    /// stable releases, while `--channel stable` never installs pre-releases.
+    ///
+    /// The channel is recorded with the installation, and followed by `uv python upgrade`.
+    #[arg(long, value_enum, env = EnvVars::UV_PYTHON_CHANNEL)]
+    pub channel: Option<PythonChannel>,
+
+    /// Include `pip` in the installation, omitting the optional components that aren't selected.
+    ///
+    /// By default, managed Python installations include all optional components: `pip`, Tcl/Tk
+    /// (see `--with-tk`), and the CPython test suite (see `--with-tests`). When any `--with-*`
+    /// option is provided, only the selected components are included, and the others are removed
+    /// after the installation is extracted. `pip` is always included in a selection, such that
+    /// `--with-pip` alone selects a minimal installation.
+    ///
+    /// The selection is recorded in the installation key, e.g.,
+    /// `cpython-3.12.11+pip-linux-x86_64-gnu`, and the components can be requested elsewhere, e.g.,
+    /// `--python 3.12+pip`.
+    #[arg(long, conflicts_with = "only_download")]
+    pub with_pip: bool,
+
+    /// Include Tcl/Tk in the installation, omitting the optional components that aren't selected.
+    ///
+    /// Tcl/Tk is required by the `tkinter`, `idlelib`, and `turtle` modules.
+    ///
+    /// See `--with-pip` for details.
+    #[arg(long, conflicts_with = "only_download")]
+    pub with_tk: bool,
+
+    /// Include the CPython test suite in the installation, omitting the optional components that
+    /// aren't selected.
+    ///
+    /// See `--with-pip` for details.
+    #[arg(long, conflicts_with = "only_download")]
+    pub with_tests: bool,
+
+    /// Download the requested Python versions into the cache, without installing them.
+    ///
+    /// Archives are downloaded into `UV_PYTHON_CACHE_DIR`, which must be set, and verified
+    /// against their expected checksums. The cached archives can later be installed without
+    /// network access with `--from-cache`.
+    #[arg(
+        long,
+        conflicts_with_all = ["from_cache", "reinstall", "force", "default", "bin", "no_bin"]
+    )]
+    pub only_download: bool,
+
+    /// Install the requested Python versions from the cache, without accessing the network.
+    ///
+    /// Archives are read from `UV_PYTHON_CACHE_DIR`, which must be set, e.g., after downloading
+    /// them with `--only-download`. uv will exit with an error if an archive is missing from the
+    /// cache or does not match its expected checksum.
+    #[arg(long)]
+    pub from_cache: bool,
+
+    /// Install all of the requested Python versions, or none of them.
+    ///
+    /// By default, if some of the requested versions fail to install, the versions that were
+    /// installed successfully are kept. With `--atomic`, any failure, including a failure to create
+    /// an executable or registry entry or a failing post-install hook, undoes the changes of this
+    /// invocation: the versions it installed are removed again, along with the executables and
+    /// registry entries it created.
+    #[arg(long, conflicts_with_all = ["reinstall", "force", "only_download"])]
+    pub atomic: bool,
+
+    /// Limit the combined bandwidth of the Python downloads, in bytes per second.
+    ///
+    /// Accepts an optional `K`, `M`, or `G` suffix for kibibytes, mebibytes, or gibibytes per
+    /// second, e.g., `10M`. The limit is shared by all concurrent downloads, the number of which
+    /// is limited by `UV_CONCURRENT_DOWNLOADS`.
+    #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOAD_RATE_LIMIT, value_name = "RATE")]
+    pub download_rate_limit: Option<DownloadRate>,
 }
 
 #[derive(Args)]
@@ -5271,6 +6326,41 @@ pub struct PythonUpgradeArgs {
     /// Note that currently, only local paths are supported.
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
+
+    #[command(flatten)]
+    pub download_client: PythonDownloadClientArgs,
+
+    /// The release channel to upgrade Python versions along.
+    ///
+    /// For example, `--channel rc` will upgrade a Python version to its latest release candidate,
+    /// and then to its stable releases once available. `--channel stable` never upgrades to a
+    /// pre-release.
+    ///
+    /// By default, the channel the installation was installed from is used, if any.
+    #[arg(long, value_enum, env = EnvVars::UV_PYTHON_CHANNEL)]
+    pub channel: Option<PythonChannel>,
+
+    /// Upgrade to the latest Python minor version, rather than the latest patch release.
+    ///
+    /// The environments that use one of the upgraded versions, i.e., the environment of the
+    /// current project, the active virtual environment, and tool environments, are checked
+    /// against the latest minor version. uv then displays a migration plan: whether the project
+    /// environment can be recreated with the new version, whether the project's `.python-version`
+    /// file must be updated first, or whether the project's `requires-python` excludes the new
+    /// version, which must be addressed manually, along with the commands to migrate the other
+    /// environments.
+    ///
+    /// The plan is only displayed, and nothing is installed; use `--apply` to apply it.
+    #[arg(long, conflicts_with = "reinstall")]
+    pub minor: bool,
+
+    /// Apply the migration plan of `--minor`.
+    ///
+    /// Installs the latest minor version, updates the project's `.python-version` file, if
+    /// necessary, and removes the project environment, such that it's recreated with the new minor
+    /// version on the next sync.
+    #[arg(long, requires = "minor")]
+    pub apply: bool,
 }
 
 #[derive(Args)]
@@ -5288,6 +6378,29 @@ pub struct PythonUninstallArgs {
     /// Uninstall all managed Python versions.
     #[arg(long, conflicts_with("targets"))]
     pub all: bool,
+
+    /// Uninstall protected Python versions too.
+    ///
+    /// By default, installations protected with `uv python pin-install` are skipped.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct PythonPinInstallArgs {
+    /// The directory where the Python was installed.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
+
+    /// The Python version(s) to protect.
+    ///
+    /// See `uv help python` to view supported request formats.
+    #[arg(required = true)]
+    pub targets: Vec<String>,
+
+    /// Remove the protection from the Python version(s) instead.
+    #[arg(long)]
+    pub remove: bool,
 }
 
 #[derive(Args)]
@@ -5336,6 +6449,282 @@ pub struct PythonFindArgs {
     /// Show the Python version that would be used instead of the path to the interpreter.
     #[arg(long)]
     pub show_version: bool,
+
+    /// Show the base interpreter, rather than a virtual environment.
+    ///
+    /// If the discovered interpreter is a virtual environment, the interpreter it was created from
+    /// is shown instead, following virtual environments that were created from other virtual
+    /// environments, e.g., to compile native code against the base installation's `libpython`.
+    #[arg(long, conflicts_with = "all")]
+    pub base: bool,
+
+    /// Show all Python interpreters that satisfy the request, in the order they would be selected.
+    ///
+    /// The first interpreter listed is the one that would be used; the others would be used if it
+    /// were unavailable.
+    #[arg(long, conflicts_with = "script", conflicts_with = "show_version")]
+    pub all: bool,
+
+    /// Select the output format for `--all`.
+    #[arg(
+        long,
+        alias = "format",
+        value_enum,
+        requires = "all",
+        default_value_t = PythonFindFormat::default()
+    )]
+    pub output_format: PythonFindFormat,
+
+    /// Show the recorded failures to query Python interpreters, instead of finding an interpreter.
+    ///
+    /// Interpreters that are skipped during discovery after repeated failed queries are shown
+    /// first, along with the time until they're queried again.
+    ///
+    /// The output of failed queries is only recorded when `UV_PYTHON_RECORD_QUERY_FAILURES` is
+    /// set. For each interpreter, the number of failed queries and the output of the most recent
+    /// failure are shown.
+    #[arg(
+        long,
+        conflicts_with = "request",
+        conflicts_with = "script",
+        conflicts_with = "show_version",
+        conflicts_with = "all"
+    )]
+    pub show_failures: bool,
+}
+
+#[derive(Args)]
+pub struct PythonInspectArgs {
+    /// The Python request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: Option<String>,
+
+    /// Only inspect system Python interpreters.
+    ///
+    /// By default, uv will inspect the first Python interpreter it would use, including those in
+    /// an active virtual environment or a virtual environment in the current working directory or
+    /// any parent directory.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonInspectFormat::default())]
+    pub output_format: PythonInspectFormat,
+}
+
+#[derive(Args)]
+pub struct PythonWhichArgs {
+    /// The name of the command, e.g., `pytest`.
+    pub command: String,
+
+    /// Resolve the command with the given packages installed, as in `uv run --with`.
+    ///
+    /// The packages are installed into a cached environment that is layered on top of the base
+    /// environment, as in `uv run`, and its scripts directory is searched first.
+    #[arg(short = 'w', long)]
+    pub with: Vec<comma::CommaSeparatedRequirements>,
+
+    /// Avoid discovering a project or workspace.
+    ///
+    /// Otherwise, the scripts directory of the project environment is searched, as in `uv run`.
+    #[arg(long, alias = "no_workspace")]
+    pub no_project: bool,
+
+    /// The sources of the `PATH` entries to search, in order of precedence.
+    ///
+    /// Accepts a comma-separated list of `overlay`, `base`, and `inherit`, as in
+    /// `uv run --path-policy`. Defaults to `overlay,base,inherit`. May also be set with the
+    /// `run.path-policy` setting.
+    #[arg(long, value_name = "SOURCES")]
+    pub path_policy: Option<PathPolicy>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonWhichFormat::default())]
+    pub output_format: PythonWhichFormat,
+}
+
+#[derive(Args)]
+pub struct PythonMarkersArgs {
+    /// The Python interpreter to use as the base for the markers.
+    ///
+    /// Markers that aren't overridden are taken from this interpreter. By default, uv uses the
+    /// first Python interpreter it would use, including those in an active virtual environment or
+    /// a virtual environment in the current working directory or any parent directory.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+
+    /// The Python version to show the markers for, e.g., `3.11`.
+    ///
+    /// Overrides the `python_version`, `python_full_version`, and, for CPython,
+    /// `implementation_version` markers. If a patch version is omitted, the minimum patch version
+    /// is assumed.
+    #[arg(long)]
+    pub python_version: Option<PythonVersion>,
+
+    /// The platform to show the markers for.
+    ///
+    /// Accepts a "target triple", a string that describes the target platform in terms of its
+    /// CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`, or a platform in the form `<os>-<arch>[-<libc>]`, like
+    /// `linux-aarch64` or `macos-x86_64`.
+    #[arg(long, alias = "platform", value_parser = parse_markers_platform)]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python implementation to show the markers for, e.g., `pypy`.
+    ///
+    /// Overrides the `implementation_name` and `platform_python_implementation` markers. If the
+    /// base interpreter is of a different implementation, an interpreter of the requested
+    /// implementation is used as the base instead, as the `implementation_version` of an
+    /// alternative implementation can't be inferred.
+    #[arg(long)]
+    pub implementation: Option<ImplementationName>,
+
+    /// Only use system Python interpreters as the base for the markers.
+    ///
+    /// By default, uv will use the first Python interpreter it would use, including those in an
+    /// active virtual environment or a virtual environment in the current working directory or
+    /// any parent directory.
+    #[arg(
+        long,
+        env = EnvVars::UV_SYSTEM_PYTHON,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub system: bool,
+
+    #[arg(long, overrides_with("system"), hide = true)]
+    pub no_system: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonMarkersFormat::default())]
+    pub output_format: PythonMarkersFormat,
+}
+
+#[derive(Args)]
+pub struct PythonResolveArgs {
+    /// The Python request.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub request: Option<String>,
+
+    /// The platform to resolve the download for, e.g., `linux-aarch64-gnu`.
+    ///
+    /// Takes the form `<os>-<arch>-<libc>`, and overrides any platform in the request. Defaults to
+    /// the current platform.
+    #[arg(long)]
+    pub platform: Option<String>,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonResolveFormat::default())]
+    pub output_format: PythonResolveFormat,
+
+    /// Set the URL to use as the source for downloading Python installations.
+    ///
+    /// The provided URL will replace
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download` in the download
+    /// URL.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
+    pub mirror: Option<String>,
+
+    /// Set the URL to use as the source for downloading PyPy installations.
+    ///
+    /// The provided URL will replace `https://downloads.python.org/pypy` in the download URL.
+    #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
+    pub pypy_mirror: Option<String>,
+
+    /// URL pointing to JSON of custom Python installations.
+    ///
+    /// Note that currently, only local paths are supported.
+    #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
+    pub python_downloads_json_url: Option<String>,
+
+    #[command(flatten)]
+    pub download_client: PythonDownloadClientArgs,
+}
+
+#[derive(Args)]
+pub struct PythonValidateDownloadsArgs {
+    /// The Python requests to validate the downloads of.
+    ///
+    /// Defaults to all downloads in the manifest, for every platform.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Download each archive in full and verify its checksum.
+    ///
+    /// By default, uv only checks that each archive exists, via a `HEAD` request (or a single-byte
+    /// range request, if the server rejects `HEAD` requests).
+    #[arg(long)]
+    pub full: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonValidateDownloadsFormat::default())]
+    pub output_format: PythonValidateDownloadsFormat,
+
+    /// Set the URL to use as the source for downloading Python installations.
+    ///
+    /// The provided URL will replace
+    /// `https://github.com/astral-sh/python-build-standalone/releases/download` in the download
+    /// URL.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_MIRROR)]
+    pub mirror: Option<String>,
+
+    /// Set the URL to use as the source for downloading PyPy installations.
+    ///
+    /// The provided URL will replace `https://downloads.python.org/pypy` in the download URL.
+    #[arg(long, env = EnvVars::UV_PYPY_INSTALL_MIRROR)]
+    pub pypy_mirror: Option<String>,
+
+    /// URL pointing to JSON of custom Python installations.
+    ///
+    /// Note that currently, only local paths are supported.
+    #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
+    pub python_downloads_json_url: Option<String>,
+
+    #[command(flatten)]
+    pub download_client: PythonDownloadClientArgs,
+}
+
+/// Client settings for downloads of managed Python installations.
+#[derive(Args)]
+pub struct PythonDownloadClientArgs {
+    /// The keyring provider to use when downloading managed Python installations.
+    ///
+    /// This is independent of `--keyring-provider`, which applies to package indexes. With
+    /// `subprocess`, uv uses the `keyring` CLI to look up credentials for a Python mirror.
+    ///
+    /// Credentials from a `.netrc` file or embedded in the mirror URL are used regardless.
+    #[arg(long, value_enum, env = EnvVars::UV_PYTHON_INSTALL_KEYRING_PROVIDER)]
+    pub python_install_keyring_provider: Option<KeyringProviderType>,
+
+    /// An additional certificate authority to trust when downloading managed Python installations
+    /// from a specific host, as `<host>=<path>`.
+    ///
+    /// The path must point to a PEM bundle, which is trusted in addition to the default roots for
+    /// requests to the given host, but not for requests to package indexes.
+    ///
+    /// May be provided multiple times. Expects a space-separated list of `<host>=<path>` entries
+    /// when provided via `UV_PYTHON_INSTALL_CA_CERTS`.
+    #[arg(long, env = EnvVars::UV_PYTHON_INSTALL_CA_CERTS, value_delimiter = ' ')]
+    pub python_install_ca_cert: Option<Vec<HostCertificate>>,
 }
 
 #[derive(Args)]
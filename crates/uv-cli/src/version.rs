@@ -17,6 +17,15 @@ pub(crate) struct CommitInfo {
     commits_since_last_tag: u32,
 }
 
+/// The capabilities of the running uv, for tools that need to probe for support.
+#[derive(Serialize)]
+pub(crate) struct Capabilities {
+    /// The lockfile format version written by this uv.
+    lock_version: u32,
+    /// The lockfile format revision written by this uv.
+    lock_revision: u32,
+}
+
 /// uv's version.
 #[derive(Serialize)]
 pub struct VersionInfo {
@@ -28,6 +37,11 @@ pub struct VersionInfo {
     ///
     /// `None` if not built from a git repo or if retrieval failed.
     commit_info: Option<CommitInfo>,
+    /// The capabilities of the running uv.
+    ///
+    /// Only populated when printing uv's own version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capabilities: Option<Capabilities>,
 }
 
 impl VersionInfo {
@@ -36,6 +50,7 @@ impl VersionInfo {
             package_name: package_name.map(ToString::to_string),
             version: version.to_string(),
             commit_info: None,
+            capabilities: None,
         }
     }
 }
@@ -97,6 +112,10 @@ pub fn uv_self_version() -> VersionInfo {
         package_name: Some("uv".to_owned()),
         version,
         commit_info,
+        capabilities: Some(Capabilities {
+            lock_version: uv_resolver::VERSION,
+            lock_revision: uv_resolver::REVISION,
+        }),
     }
 }
 
@@ -104,7 +123,7 @@ pub fn uv_self_version() -> VersionInfo {
 mod tests {
     use insta::{assert_json_snapshot, assert_snapshot};
 
-    use super::{CommitInfo, VersionInfo};
+    use super::{Capabilities, CommitInfo, VersionInfo};
 
     #[test]
     fn version_formatting() {
@@ -112,6 +131,7 @@ mod tests {
             package_name: Some("uv".to_string()),
             version: "0.0.0".to_string(),
             commit_info: None,
+            capabilities: None,
         };
         assert_snapshot!(version, @"0.0.0");
     }
@@ -128,6 +148,7 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 0,
             }),
+            capabilities: None,
         };
         assert_snapshot!(version, @"0.0.0 (53b0f5d92 2023-10-19)");
     }
@@ -144,6 +165,7 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 24,
             }),
+            capabilities: None,
         };
         assert_snapshot!(version, @"0.0.0+24 (53b0f5d92 2023-10-19)");
     }
@@ -160,6 +182,10 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 0,
             }),
+            capabilities: Some(Capabilities {
+                lock_version: 1,
+                lock_revision: 3,
+            }),
         };
         assert_json_snapshot!(version, @r#"
     {
@@ -171,6 +197,10 @@ mod tests {
         "commit_date": "2023-10-19",
         "last_tag": "v0.0.1",
         "commits_since_last_tag": 0
+      },
+      "capabilities": {
+        "lock_version": 1,
+        "lock_revision": 3
       }
     }
     "#);
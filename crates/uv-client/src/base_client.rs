@@ -2,7 +2,7 @@ use std::error::Error;
 use std::fmt::Debug;
 use std::fmt::Write;
 use std::num::ParseIntError;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use std::{env, io, iter};
@@ -16,7 +16,7 @@ use http::{
     },
 };
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, IntoUrl, Proxy, Request, Response, multipart};
+use reqwest::{Certificate, Client, ClientBuilder, IntoUrl, Proxy, Request, Response, multipart};
 use reqwest_middleware::{ClientWithMiddleware, Middleware};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
@@ -41,7 +41,7 @@ use uv_warnings::warn_user_once;
 use crate::Connectivity;
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::tls::{read_certificates, read_identity};
 
 /// Do not use this value directly outside tests, use [`retries_from_env`] instead.
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -87,6 +87,8 @@ pub struct BaseClientBuilder<'a> {
     cross_origin_credential_policy: CrossOriginCredentialsPolicy,
     /// Optional custom reqwest client to use instead of creating a new one.
     custom_client: Option<Client>,
+    /// Additional certificate authorities to trust for specific hosts, as paths to PEM bundles.
+    host_certificates: Vec<(TrustedHost, PathBuf)>,
 }
 
 /// The policy for handling HTTP redirects.
@@ -146,6 +148,7 @@ impl BaseClientBuilder<'_> {
             redirect_policy: RedirectPolicy::default(),
             cross_origin_credential_policy: CrossOriginCredentialsPolicy::Secure,
             custom_client: None,
+            host_certificates: vec![],
         }
     }
 }
@@ -249,6 +252,16 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Trust the certificate authorities in the PEM bundle at `path` for requests to `host`, in
+    /// addition to the default roots.
+    ///
+    /// Requests to hosts without an override are unaffected.
+    #[must_use]
+    pub fn host_certificates(mut self, host: TrustedHost, path: PathBuf) -> Self {
+        self.host_certificates.push((host, path));
+        self
+    }
+
     #[must_use]
     pub fn redirect(mut self, policy: RedirectPolicy) -> Self {
         self.redirect_policy = policy;
@@ -303,6 +316,7 @@ impl<'a> BaseClientBuilder<'a> {
             Some(client) => (client.clone(), client.clone()),
             None => self.create_secure_and_insecure_clients(timeout),
         };
+        let raw_host_clients = self.create_host_clients(timeout);
 
         // Wrap in any relevant middleware and handle connectivity.
         let client = RedirectClientWithMiddleware {
@@ -315,6 +329,7 @@ impl<'a> BaseClientBuilder<'a> {
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
+        let host_clients = self.wrap_host_clients(&raw_host_clients);
 
         BaseClient {
             connectivity: self.connectivity,
@@ -324,6 +339,8 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            host_clients,
+            raw_host_clients,
             timeout,
         }
     }
@@ -341,6 +358,7 @@ impl<'a> BaseClientBuilder<'a> {
             redirect_policy: self.redirect_policy,
             cross_origin_credentials_policy: self.cross_origin_credential_policy,
         };
+        let host_clients = self.wrap_host_clients(&existing.raw_host_clients);
 
         BaseClient {
             connectivity: self.connectivity,
@@ -350,12 +368,78 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            host_clients,
+            raw_host_clients: existing.raw_host_clients.clone(),
             timeout: existing.timeout,
         }
     }
 
-    fn create_secure_and_insecure_clients(&self, timeout: Duration) -> (Client, Client) {
-        // Create user agent.
+    /// Wrap the host-specific clients in any relevant middleware.
+    fn wrap_host_clients(
+        &self,
+        raw_host_clients: &[(TrustedHost, Client)],
+    ) -> Vec<(TrustedHost, RedirectClientWithMiddleware)> {
+        raw_host_clients
+            .iter()
+            .map(|(host, raw_client)| {
+                let client = RedirectClientWithMiddleware {
+                    client: self.apply_middleware(raw_client.clone()),
+                    redirect_policy: self.redirect_policy,
+                    cross_origin_credentials_policy: self.cross_origin_credential_policy,
+                };
+                (host.clone(), client)
+            })
+            .collect()
+    }
+
+    /// Create a secure client for each host with additional certificate authorities.
+    ///
+    /// Hosts with invalid certificate bundles are skipped with a warning, and use the default
+    /// client instead.
+    fn create_host_clients(&self, timeout: Duration) -> Vec<(TrustedHost, Client)> {
+        if self.host_certificates.is_empty() {
+            return vec![];
+        }
+        if self.custom_client.is_some() {
+            debug!("Ignoring host-specific certificates with a custom client");
+            return vec![];
+        }
+
+        let user_agent_string = self.user_agent();
+        let ssl_cert_file_exists = ssl_cert_file_exists();
+        self.host_certificates
+            .iter()
+            .filter_map(|(host, path)| {
+                let certificates = match read_certificates(path.as_os_str()) {
+                    Ok(certificates) => certificates,
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring invalid certificates for `{host}` at {}: {err}",
+                            path.simplified_display().cyan()
+                        );
+                        return None;
+                    }
+                };
+                debug!(
+                    "Using {} additional certificate(s) for `{host}` from: {}",
+                    certificates.len(),
+                    path.simplified_display()
+                );
+                let client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    self.redirect_policy,
+                    &certificates,
+                );
+                Some((host.clone(), client))
+            })
+            .collect()
+    }
+
+    /// Create the user agent, including linehaul metadata, if available.
+    fn user_agent(&self) -> String {
         let mut user_agent_string = format!("uv/{}", version());
 
         // Add linehaul metadata.
@@ -366,17 +450,15 @@ impl<'a> BaseClientBuilder<'a> {
             }
         }
 
+        user_agent_string
+    }
+
+    fn create_secure_and_insecure_clients(&self, timeout: Duration) -> (Client, Client) {
+        // Create user agent.
+        let user_agent_string = self.user_agent();
+
         // Check for the presence of an `SSL_CERT_FILE`.
-        let ssl_cert_file_exists = env::var_os(EnvVars::SSL_CERT_FILE).is_some_and(|path| {
-            let path_exists = Path::new(&path).exists();
-            if !path_exists {
-                warn_user_once!(
-                    "Ignoring invalid `SSL_CERT_FILE`. File does not exist: {}.",
-                    path.simplified_display().cyan()
-                );
-            }
-            path_exists
-        });
+        let ssl_cert_file_exists = ssl_cert_file_exists();
 
         // Create a secure client that validates certificates.
         let raw_client = self.create_client(
@@ -385,6 +467,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             Security::Secure,
             self.redirect_policy,
+            &[],
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -394,6 +477,7 @@ impl<'a> BaseClientBuilder<'a> {
             ssl_cert_file_exists,
             Security::Insecure,
             self.redirect_policy,
+            &[],
         );
 
         (raw_client, raw_dangerous_client)
@@ -406,6 +490,7 @@ impl<'a> BaseClientBuilder<'a> {
         ssl_cert_file_exists: bool,
         security: Security,
         redirect_policy: RedirectPolicy,
+        root_certificates: &[Certificate],
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -428,6 +513,13 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
+        // Trust any additional certificate authorities.
+        let client_builder = root_certificates
+            .iter()
+            .fold(client_builder, |client_builder, certificate| {
+                client_builder.add_root_certificate(certificate.clone())
+            });
+
         // Configure mTLS.
         let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
@@ -516,6 +608,11 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients that trust additional certificate authorities for specific hosts.
+    host_clients: Vec<(TrustedHost, RedirectClientWithMiddleware)>,
+    /// The HTTP clients that trust additional certificate authorities for specific hosts, without
+    /// middleware.
+    raw_host_clients: Vec<(TrustedHost, Client)>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -526,6 +623,20 @@ pub struct BaseClient {
     retries: u32,
 }
 
+/// Check for the presence of an `SSL_CERT_FILE`, warning if it does not exist.
+fn ssl_cert_file_exists() -> bool {
+    env::var_os(EnvVars::SSL_CERT_FILE).is_some_and(|path| {
+        let path_exists = Path::new(&path).exists();
+        if !path_exists {
+            warn_user_once!(
+                "Ignoring invalid `SSL_CERT_FILE`. File does not exist: {}.",
+                path.simplified_display().cyan()
+            );
+        }
+        path_exists
+    })
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -539,6 +650,10 @@ impl BaseClient {
     pub fn for_host(&self, url: &DisplaySafeUrl) -> &RedirectClientWithMiddleware {
         if self.disable_ssl(url) {
             &self.dangerous_client
+        } else if let Some((_, client)) =
+            self.host_clients.iter().find(|(host, _)| host.matches(url))
+        {
+            client
         } else {
             &self.client
         }
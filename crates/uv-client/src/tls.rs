@@ -1,4 +1,4 @@
-use reqwest::Identity;
+use reqwest::{Certificate, Identity};
 use std::ffi::OsStr;
 use std::io::Read;
 
@@ -19,3 +19,10 @@ pub(crate) fn read_identity(ssl_client_cert: &OsStr) -> Result<Identity, Certifi
         CertificateError::Reqwest(tls_err)
     })
 }
+
+/// Return the `Certificate`s in the PEM bundle at the provided path.
+pub(crate) fn read_certificates(path: &OsStr) -> Result<Vec<Certificate>, CertificateError> {
+    let mut buf = Vec::new();
+    fs_err::File::open(path)?.read_to_end(&mut buf)?;
+    Certificate::from_pem_bundle(&buf).map_err(CertificateError::Reqwest)
+}
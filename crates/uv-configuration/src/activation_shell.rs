@@ -0,0 +1,17 @@
+/// A shell for which to export an activation script for the project environment.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ActivationShell {
+    /// Write an `.envrc` file to the project root, for use with `direnv`.
+    Direnv,
+    /// Write a POSIX-compatible `.uv/activate.sh` script, e.g., for `bash` or `zsh`.
+    Sh,
+    /// Write a `.uv/activate.fish` script, for `fish`.
+    Fish,
+    /// Write a `.uv/activate.ps1` script, for PowerShell.
+    Powershell,
+}
@@ -0,0 +1,221 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// A size in bytes, parsed from a human-readable string, e.g., `2GB` or `500MiB`.
+///
+/// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, and binary units (`KiB`, `MiB`,
+/// `GiB`, `TiB`) are powers of 1024. Units are case-insensitive; a number without a unit is a
+/// size in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize {
+    bytes: u64,
+    /// The original representation, for display.
+    unit: ByteUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum ByteUnit {
+    Byte,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Kibi,
+    Mebi,
+    Gibi,
+    Tebi,
+}
+
+impl ByteUnit {
+    const ALL: [Self; 9] = [
+        Self::Byte,
+        Self::Kilo,
+        Self::Mega,
+        Self::Giga,
+        Self::Tera,
+        Self::Kibi,
+        Self::Mebi,
+        Self::Gibi,
+        Self::Tebi,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Byte => "B",
+            Self::Kilo => "kB",
+            Self::Mega => "MB",
+            Self::Giga => "GB",
+            Self::Tera => "TB",
+            Self::Kibi => "KiB",
+            Self::Mebi => "MiB",
+            Self::Gibi => "GiB",
+            Self::Tebi => "TiB",
+        }
+    }
+
+    fn multiplier(self) -> u64 {
+        match self {
+            Self::Byte => 1,
+            Self::Kilo => 1000,
+            Self::Mega => 1000_u64.pow(2),
+            Self::Giga => 1000_u64.pow(3),
+            Self::Tera => 1000_u64.pow(4),
+            Self::Kibi => 1024,
+            Self::Mebi => 1024_u64.pow(2),
+            Self::Gibi => 1024_u64.pow(3),
+            Self::Tebi => 1024_u64.pow(4),
+        }
+    }
+}
+
+impl ByteSize {
+    /// Return the size in bytes.
+    pub fn as_u64(self) -> u64 {
+        self.bytes
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ByteSizeError {
+    #[error("Expected a size, e.g., `2GB` or `500MiB`, but found: `{0}`")]
+    Invalid(String),
+    #[error("Size is too large: `{0}`")]
+    Overflow(String),
+}
+
+impl FromStr for ByteSize {
+    type Err = ByteSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let split = trimmed
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(trimmed.len());
+        let (quantity, unit) = trimmed.split_at(split);
+        let unit = unit.trim();
+
+        let unit = if unit.is_empty() {
+            ByteUnit::Byte
+        } else {
+            ByteUnit::ALL
+                .into_iter()
+                .find(|candidate| candidate.as_str().eq_ignore_ascii_case(unit))
+                .ok_or_else(|| ByteSizeError::Invalid(s.to_string()))?
+        };
+
+        // Allow fractional quantities, e.g., `1.5GB`, by scaling the integer and fractional parts
+        // separately to avoid floating-point rounding.
+        let (whole, fraction) = quantity.split_once('.').unwrap_or((quantity, ""));
+        if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+            return Err(ByteSizeError::Invalid(s.to_string()));
+        }
+        let parse = |digits: &str| -> Result<u64, ByteSizeError> {
+            if digits.is_empty() {
+                Ok(0)
+            } else {
+                digits
+                    .parse::<u64>()
+                    .map_err(|_| ByteSizeError::Overflow(s.to_string()))
+            }
+        };
+        let multiplier = unit.multiplier();
+        let whole = parse(whole)?
+            .checked_mul(multiplier)
+            .ok_or_else(|| ByteSizeError::Overflow(s.to_string()))?;
+        let fraction = if fraction.is_empty() {
+            0
+        } else {
+            let scale = u32::try_from(fraction.len())
+                .ok()
+                .and_then(|len| 10_u64.checked_pow(len))
+                .ok_or_else(|| ByteSizeError::Overflow(s.to_string()))?;
+            parse(fraction)?
+                .checked_mul(multiplier)
+                .ok_or_else(|| ByteSizeError::Overflow(s.to_string()))?
+                / scale
+        };
+        let bytes = whole
+            .checked_add(fraction)
+            .ok_or_else(|| ByteSizeError::Overflow(s.to_string()))?;
+
+        Ok(Self { bytes, unit })
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let multiplier = self.unit.multiplier();
+        if self.bytes % multiplier == 0 {
+            write!(f, "{}{}", self.bytes / multiplier, self.unit.as_str())
+        } else {
+            write!(f, "{}B", self.bytes)
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ByteSize {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ByteSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A size in bytes, e.g., `2GB` or `500MiB`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = ByteSize;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a size, e.g., `2GB` or `500MiB`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ByteSize::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::ByteSize;
+
+    #[test]
+    fn parse() {
+        let bytes = |s: &str| ByteSize::from_str(s).map(ByteSize::as_u64).ok();
+        assert_eq!(bytes("1024"), Some(1024));
+        assert_eq!(bytes("2GB"), Some(2_000_000_000));
+        assert_eq!(bytes("2 gb"), Some(2_000_000_000));
+        assert_eq!(bytes("500MiB"), Some(500 * 1024 * 1024));
+        assert_eq!(bytes("1.5kB"), Some(1500));
+        assert_eq!(bytes("0.5KiB"), Some(512));
+        assert_eq!(bytes("2XB"), None);
+        assert_eq!(bytes("GB"), None);
+        assert_eq!(bytes("1.2.3MB"), None);
+        assert_eq!(bytes("99999999999TB"), None);
+    }
+
+    #[test]
+    fn display() {
+        let display = |s: &str| ByteSize::from_str(s).unwrap().to_string();
+        assert_eq!(display("2GB"), "2GB");
+        assert_eq!(display("2gib"), "2GiB");
+        assert_eq!(display("1.5kB"), "1500B");
+        assert_eq!(display("1024"), "1024B");
+    }
+}
@@ -1,5 +1,7 @@
+pub use activation_shell::*;
 pub use authentication::*;
 pub use build_options::*;
+pub use byte_size::*;
 pub use concurrency::*;
 pub use constraints::*;
 pub use dependency_groups::*;
@@ -10,8 +12,10 @@ pub use extras::*;
 pub use hash::*;
 pub use install_options::*;
 pub use name_specifiers::*;
+pub use network_access::*;
 pub use overrides::*;
 pub use package_options::*;
+pub use path_policy::*;
 pub use preview::*;
 pub use project_build_backend::*;
 pub use required_version::*;
@@ -22,8 +26,10 @@ pub use trusted_host::*;
 pub use trusted_publishing::*;
 pub use vcs::*;
 
+mod activation_shell;
 mod authentication;
 mod build_options;
+mod byte_size;
 mod concurrency;
 mod constraints;
 mod dependency_groups;
@@ -34,8 +40,10 @@ mod extras;
 mod hash;
 mod install_options;
 mod name_specifiers;
+mod network_access;
 mod overrides;
 mod package_options;
+mod path_policy;
 mod preview;
 mod project_build_backend;
 mod required_version;
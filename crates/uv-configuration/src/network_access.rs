@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+/// Whether a command run by uv may access the network.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum NetworkAccess {
+    /// Allow the command to access the network.
+    #[default]
+    On,
+    /// Run the command without network access.
+    Off,
+}
+
+impl NetworkAccess {
+    /// Returns `true` if network access is disabled.
+    pub fn is_off(self) -> bool {
+        matches!(self, Self::Off)
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+/// A source of `PATH` entries for a command run by uv.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PathEntry {
+    /// The scripts directories of the environments layered atop the base environment, e.g., via
+    /// `--with`.
+    Overlay,
+    /// The scripts directory of the base environment, e.g., the project environment.
+    Base,
+    /// The `PATH` entries inherited from the environment in which uv was invoked.
+    Inherit,
+}
+
+impl PathEntry {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Overlay => "overlay",
+            Self::Base => "base",
+            Self::Inherit => "inherit",
+        }
+    }
+}
+
+/// The sources of the `PATH` entries for a command run by uv, in order of precedence.
+///
+/// An empty policy clears `PATH` entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPolicy(Vec<PathEntry>);
+
+impl Default for PathPolicy {
+    fn default() -> Self {
+        Self(vec![
+            PathEntry::Overlay,
+            PathEntry::Base,
+            PathEntry::Inherit,
+        ])
+    }
+}
+
+impl PathPolicy {
+    /// Return the sources of `PATH` entries, in order of precedence.
+    pub fn entries(&self) -> &[PathEntry] {
+        &self.0
+    }
+
+    /// Returns `true` if `PATH` should be cleared entirely.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PathPolicyError {
+    #[error(
+        "Expected a comma-separated list of `overlay`, `base`, and `inherit`, or `none`, but found: `{0}`"
+    )]
+    Invalid(String),
+    #[error("`{0}` was provided more than once")]
+    Duplicate(&'static str),
+}
+
+impl TryFrom<Vec<PathEntry>> for PathPolicy {
+    type Error = PathPolicyError;
+
+    fn try_from(entries: Vec<PathEntry>) -> Result<Self, Self::Error> {
+        for (index, entry) in entries.iter().enumerate() {
+            if entries[..index].contains(entry) {
+                return Err(PathPolicyError::Duplicate(entry.as_str()));
+            }
+        }
+        Ok(Self(entries))
+    }
+}
+
+impl FromStr for PathPolicy {
+    type Err = PathPolicyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == "none" {
+            return Ok(Self(Vec::new()));
+        }
+        let entries = s
+            .split(',')
+            .map(|entry| match entry.trim() {
+                "overlay" => Ok(PathEntry::Overlay),
+                "base" => Ok(PathEntry::Base),
+                "inherit" => Ok(PathEntry::Inherit),
+                _ => Err(PathPolicyError::Invalid(s.to_string())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::try_from(entries)
+    }
+}
+
+impl std::fmt::Display for PathPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("none");
+        }
+        for (index, entry) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(entry.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for PathPolicy {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("PathPolicy")
+    }
+
+    fn json_schema(generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        let items = generator.subschema_for::<PathEntry>();
+        schemars::json_schema!({
+            "type": "array",
+            "items": items,
+            "uniqueItems": true,
+            "description": "The sources of the `PATH` entries for a command, in order of precedence. An empty list clears `PATH` entirely."
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PathPolicy {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Vec::<PathEntry>::deserialize(deserializer)?;
+        Self::try_from(entries).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{PathEntry, PathPolicy};
+
+    #[test]
+    fn parse() {
+        let entries = |s: &str| PathPolicy::from_str(s).map(|policy| policy.entries().to_vec());
+        assert_eq!(
+            entries("base,inherit").ok(),
+            Some(vec![PathEntry::Base, PathEntry::Inherit])
+        );
+        assert_eq!(
+            entries("inherit, overlay").ok(),
+            Some(vec![PathEntry::Inherit, PathEntry::Overlay])
+        );
+        assert_eq!(entries("none").ok(), Some(vec![]));
+        assert!(entries("base,base").is_err());
+        assert!(entries("scripts").is_err());
+        assert!(entries("").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(PathPolicy::default().to_string(), "overlay,base,inherit");
+        assert_eq!(PathPolicy::from_str("none").unwrap().to_string(), "none");
+    }
+}
@@ -27,6 +27,7 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
             EnvironmentPreference::OnlyVirtual,
             PythonPreference::default(),
             &cache,
+            None,
             Preview::default(),
         )?
         .into_interpreter();
@@ -83,6 +83,35 @@ pub fn legacy_user_state_dir() -> Option<PathBuf> {
         .map(|dir| if cfg!(windows) { dir.join("data") } else { dir })
 }
 
+/// Returns the user-level Jupyter data directory, in which kernel specs are registered.
+///
+/// This follows the conventions of `jupyter_core`, in order:
+///
+/// - `$JUPYTER_DATA_DIR`
+/// - `$HOME/Library/Jupyter` on macOS
+/// - `%APPDATA%\jupyter` on Windows
+/// - `$XDG_DATA_HOME/jupyter` or `$HOME/.local/share/jupyter` on other platforms
+///
+/// Returns `None` if a directory cannot be found. Does not check if the directory exists.
+pub fn jupyter_data_dir() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(EnvVars::JUPYTER_DATA_DIR).and_then(parse_path) {
+        return Some(path);
+    }
+    if cfg!(target_os = "macos") {
+        etcetera::home_dir()
+            .ok()
+            .map(|home| home.join("Library").join("Jupyter"))
+    } else if cfg!(windows) {
+        etcetera::base_strategy::choose_native_strategy()
+            .ok()
+            .map(|dirs| dirs.data_dir().join("jupyter"))
+    } else {
+        etcetera::base_strategy::choose_base_strategy()
+            .ok()
+            .map(|dirs| dirs.data_dir().join("jupyter"))
+    }
+}
+
 /// Return a [`PathBuf`] if the given [`OsString`] is an absolute path.
 fn parse_path(path: OsString) -> Option<PathBuf> {
     let path = PathBuf::from(path);
@@ -697,6 +697,75 @@ impl LockedFile {
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
 
+    /// Acquire a cross-process lock for a resource using a file at the provided path, without
+    /// waiting.
+    ///
+    /// Returns `None` if the lock is held by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = Self::create(path)?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err)
+                if err.kind() == std::io::ErrorKind::WouldBlock
+                    || err.raw_os_error() == fs2::lock_contended_error().raw_os_error() =>
+            {
+                trace!("Lock for `{resource}` is held by another process");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Create a new file at the provided path, holding a cross-process lock on it.
+    ///
+    /// The file is created and locked under a temporary name, then moved into place, such that
+    /// other processes never observe the file at `path` without the lock held. Returns an error if
+    /// a file already exists at `path`.
+    pub fn create_locked(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        let path = path.as_ref();
+        let file = if let Some(parent) = path.parent() {
+            NamedTempFile::new_in(parent)?
+        } else {
+            NamedTempFile::new()?
+        };
+        file.as_file().try_lock_exclusive()?;
+        let file = file.persist_noclobber(path).map_err(|err| err.error)?;
+        debug!("Acquired lock for `{resource}`");
+        Ok(Self(fs_err::File::from_parts(file, path)))
+    }
+
+    /// Allow the lock to be inherited by a program that replaces the current process, e.g., with
+    /// `exec`, such that the lock is held until that program exits.
+    #[cfg(unix)]
+    pub fn inherit_on_exec(&self) -> Result<(), std::io::Error> {
+        let file = self.0.file();
+        let flags = rustix::io::fcntl_getfd(file)?;
+        rustix::io::fcntl_setfd(file, flags.difference(rustix::io::FdFlags::CLOEXEC))?;
+        Ok(())
+    }
+
+    /// Replace the contents of the locked file.
+    ///
+    /// Writes go through the locked handle, since other handles may be denied access to the file
+    /// while it's locked (e.g., on Windows).
+    pub fn write_contents(&self, contents: impl AsRef<[u8]>) -> Result<(), std::io::Error> {
+        use std::io::{Seek, Write};
+
+        let mut file = self.0.file();
+        file.set_len(0)?;
+        file.rewind()?;
+        file.write_all(contents.as_ref())
+    }
+
     #[cfg(unix)]
     fn create(path: impl AsRef<Path>) -> Result<fs_err::File, std::io::Error> {
         use std::os::unix::fs::PermissionsExt;
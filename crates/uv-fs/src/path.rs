@@ -277,6 +277,67 @@ fn normalized(path: &Path) -> PathBuf {
     normalized
 }
 
+/// The maximum length of a directory path on Windows without the extended-length prefix, i.e.,
+/// `MAX_PATH` less room for an 8.3 file name.
+#[cfg(windows)]
+const MAX_DIRECTORY_PATH: usize = 248;
+
+/// Convert a [`Path`] to its extended-length form (e.g., `\\?\C:\...`) on Windows, if it's too
+/// long to be used without one.
+///
+/// Without the prefix, paths longer than `MAX_PATH` (260 characters) can only be used if long
+/// paths are enabled in the registry, which requires administrator privileges. The standard
+/// library adds the prefix in its own filesystem operations, but other APIs that receive the path
+/// (e.g., junctions and reflinks) don't. Extended-length paths are passed to the filesystem as-is,
+/// so the path is made absolute and normalized first.
+///
+/// The result should only be used for filesystem operations: paths written to files or displayed
+/// to the user should retain their original form. On other platforms, this is a no-op.
+pub fn long_path(path: &Path) -> Cow<'_, Path> {
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::{OsStrExt, OsStringExt};
+        use std::path::Prefix;
+
+        // Relative paths are resolved against the working directory, so the limit applies to the
+        // absolute path.
+        let Ok(absolute) = std::path::absolute(path) else {
+            return Cow::Borrowed(path);
+        };
+        if absolute.as_os_str().len() < MAX_DIRECTORY_PATH {
+            return Cow::Borrowed(path);
+        }
+        let Some(Component::Prefix(prefix)) = absolute.components().next() else {
+            return Cow::Borrowed(path);
+        };
+        let extended = match prefix.kind() {
+            Prefix::Disk(_) => {
+                let mut extended = OsString::from(r"\\?\");
+                extended.push(absolute.as_os_str());
+                extended
+            }
+            // Replace the leading `\\` of `\\server\share` with `\\?\UNC\`.
+            Prefix::UNC(..) => OsString::from_wide(
+                &r"\\?\UNC"
+                    .encode_utf16()
+                    .chain(absolute.as_os_str().encode_wide().skip(1))
+                    .collect::<Vec<_>>(),
+            ),
+            // Verbatim and device paths are passed to the filesystem as-is already.
+            Prefix::Verbatim(_)
+            | Prefix::VerbatimUNC(..)
+            | Prefix::VerbatimDisk(_)
+            | Prefix::DeviceNS(_) => return Cow::Borrowed(path),
+        };
+        Cow::Owned(PathBuf::from(extended))
+    }
+    #[cfg(not(windows))]
+    {
+        Cow::Borrowed(path)
+    }
+}
+
 /// Compute a path describing `path` relative to `base`.
 ///
 /// `lib/python/site-packages/foo/__init__.py` and `lib/python/site-packages` -> `foo/__init__.py`
@@ -532,6 +593,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_long_path() {
+        let short = Path::new("venv");
+        assert_eq!(long_path(short), short);
+
+        if cfg!(windows) {
+            let component = "a".repeat(100);
+            let path = PathBuf::from(format!(r"C:\{component}\{component}\{component}\.venv"));
+            assert_eq!(
+                long_path(&path),
+                PathBuf::from(format!(r"\\?\C:\{component}\{component}\{component}\.venv"))
+            );
+
+            let path = PathBuf::from(format!(
+                r"\\server\share\{component}\{component}\{component}"
+            ));
+            assert_eq!(
+                long_path(&path),
+                PathBuf::from(format!(
+                    r"\\?\UNC\server\share\{component}\{component}\{component}"
+                ))
+            );
+
+            // Paths that are already extended-length are unchanged.
+            let path = PathBuf::from(format!(r"\\?\C:\{component}\{component}\{component}"));
+            assert_eq!(long_path(&path), path);
+        } else {
+            let path = PathBuf::from(format!("/{}", "a/".repeat(200)));
+            assert_eq!(long_path(&path), path);
+        }
+    }
+
     #[test]
     fn test_normalize_relative() {
         let cases = [
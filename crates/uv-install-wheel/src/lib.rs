@@ -13,13 +13,17 @@ use uv_pypi_types::Scheme;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
-pub use wheel::{LibKind, parse_wheel_file, read_record_file};
+pub use verify::{RecordMismatch, RecordMismatchKind, verify_wheel};
+pub use wheel::{
+    LibKind, ShebangExecutable, format_shebang, parse_shebang, parse_wheel_file, read_record_file,
+};
 
 mod install;
 mod linker;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
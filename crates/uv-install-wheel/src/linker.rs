@@ -98,6 +98,31 @@ impl Default for LinkMode {
     }
 }
 
+impl std::fmt::Display for LinkMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clone => write!(f, "clone"),
+            Self::Copy => write!(f, "copy"),
+            Self::Hardlink => write!(f, "hardlink"),
+            Self::Symlink => write!(f, "symlink"),
+        }
+    }
+}
+
+impl std::str::FromStr for LinkMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "clone" => Ok(Self::Clone),
+            "copy" => Ok(Self::Copy),
+            "hardlink" => Ok(Self::Hardlink),
+            "symlink" => Ok(Self::Symlink),
+            _ => Err(format!("Unknown link mode: `{s}`")),
+        }
+    }
+}
+
 impl LinkMode {
     /// Extract a wheel by linking all of its files into site packages.
     #[instrument(skip_all)]
@@ -0,0 +1,130 @@
+use std::io;
+use std::path::Path;
+
+use fs_err as fs;
+use serde::Serialize;
+
+use crate::Error;
+use crate::wheel::{copy_and_hash, read_record_file};
+
+/// A file of an installed wheel that doesn't match its `RECORD` entry.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct RecordMismatch {
+    /// The path of the file, as listed in the `RECORD`, relative to the `site-packages` directory.
+    pub path: String,
+    /// How the file differs from its `RECORD` entry.
+    pub kind: RecordMismatchKind,
+}
+
+/// How an installed file differs from its `RECORD` entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordMismatchKind {
+    /// The file does not exist.
+    Missing,
+    /// The contents of the file don't match the hash in the `RECORD`.
+    Modified,
+}
+
+/// Verify the files of the wheel represented by the given `.dist-info` directory against the
+/// hashes in its `RECORD`, without modifying anything.
+///
+/// Entries without a hash (like the `RECORD` itself) and entries hashed with an algorithm other
+/// than SHA-256 are skipped. A missing `RECORD` is reported as a missing file.
+pub fn verify_wheel(dist_info: &Path) -> Result<Vec<RecordMismatch>, Error> {
+    let Some(site_packages) = dist_info.parent() else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record_path = dist_info.join("RECORD");
+    let record = match fs::File::open(&record_path) {
+        Ok(mut record_file) => read_record_file(&mut record_file)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let path = record_path
+                .strip_prefix(site_packages)
+                .unwrap_or(&record_path);
+            return Ok(vec![RecordMismatch {
+                path: path.to_string_lossy().replace('\\', "/"),
+                kind: RecordMismatchKind::Missing,
+            }]);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut mismatches = Vec::new();
+    for entry in record {
+        let Some(expected) = entry
+            .hash
+            .as_deref()
+            .filter(|hash| hash.starts_with("sha256="))
+        else {
+            continue;
+        };
+
+        let mut file = match fs::File::open(site_packages.join(&entry.path)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                mismatches.push(RecordMismatch {
+                    path: entry.path,
+                    kind: RecordMismatchKind::Missing,
+                });
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let (_, actual) = copy_and_hash(&mut file, &mut io::sink())?;
+        if actual != expected {
+            mismatches.push(RecordMismatch {
+                path: entry.path,
+                kind: RecordMismatchKind::Modified,
+            });
+        }
+    }
+
+    mismatches.sort();
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use assert_fs::prelude::*;
+
+    use super::{RecordMismatch, RecordMismatchKind, verify_wheel};
+
+    #[test]
+    fn verify_record() -> Result<()> {
+        let site_packages = assert_fs::TempDir::new()?;
+        site_packages
+            .child("foo/__init__.py")
+            .write_str("print('hello')\n")?;
+        site_packages.child("foo/bar.py").write_str("modified\n")?;
+        site_packages.child("foo-1.0.dist-info/RECORD").write_str(
+            "foo/__init__.py,sha256=A-aT2fL2h-D0Djao33_LTRwil0ASt8KlXAAOsw8wWCQ,15\n\
+             foo/bar.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0\n\
+             foo/baz.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0\n\
+             foo-1.0.dist-info/RECORD,,\n",
+        )?;
+
+        let mismatches = verify_wheel(&site_packages.child("foo-1.0.dist-info"))?;
+        assert_eq!(
+            mismatches,
+            vec![
+                RecordMismatch {
+                    path: "foo/bar.py".to_string(),
+                    kind: RecordMismatchKind::Modified,
+                },
+                RecordMismatch {
+                    path: "foo/baz.py".to_string(),
+                    kind: RecordMismatchKind::Missing,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -78,7 +78,10 @@ pub(crate) fn read_scripts_from_section(
 /// <https://github.com/richo/hashing-copy/blob/d8dd2fdb63c6faf198de0c9e5713d6249cbb5323/src/lib.rs#L10-L52>
 /// which in turn got it from std
 /// <https://doc.rust-lang.org/1.58.0/src/std/io/copy.rs.html#128-156>
-fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<(u64, String)> {
+pub(crate) fn copy_and_hash(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+) -> io::Result<(u64, String)> {
     // TODO: Do we need to support anything besides sha256?
     let mut hasher = Sha256::new();
     // Same buf size as std. Note that this number is important for performance
@@ -102,41 +105,153 @@ fn copy_and_hash(reader: &mut impl Read, writer: &mut impl Write) -> io::Result<
     ))
 }
 
+/// The prefix of the executable in a `/bin/sh` trampoline for relocatable scripts, which resolves
+/// the executable relative to the directory of the script.
+const RELOCATABLE_PREFIX: &str = r#""$(dirname -- "$(realpath -- "$0")")"/"#;
+
 /// Format the shebang for a given Python executable.
 ///
-/// Like pip, if a shebang is non-simple (too long or contains spaces), we use `/bin/sh` as the
+/// Like pip, if a shebang is non-simple (too long or contains whitespace), we use `/bin/sh` as the
 /// executable.
 ///
 /// See: <https://github.com/pypa/pip/blob/0ad4c94be74cc24874c6feb5bb3c2152c398a18e/src/pip/_vendor/distlib/scripts.py#L136-L165>
-fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool) -> String {
+pub fn format_shebang(executable: impl AsRef<Path>, os_name: &str, relocatable: bool) -> String {
     // Convert the executable to a simplified path.
-    let executable = executable.as_ref().simplified_display().to_string();
+    let executable = executable.as_ref().simplified();
 
     // Validate the shebang.
     if os_name == "posix" {
         // The length of the full line: the shebang, plus the leading `#` and `!`, and a trailing
         // newline.
-        let shebang_length = 2 + executable.len() + 1;
+        let shebang_length = 2 + executable.as_os_str().len() + 1;
 
-        // If the shebang is too long, or contains spaces, wrap it in `/bin/sh`.
+        // If the shebang is too long, contains whitespace, or isn't valid UTF-8, wrap it in
+        // `/bin/sh`.
         // Same applies for relocatable scripts (executable is relative to script dir, hence `dirname` trick)
         // (note: the Windows trampoline binaries natively support relative paths to executable)
-        if shebang_length > 127 || executable.contains(' ') || relocatable {
-            let prefix = if relocatable {
-                r#""$(dirname -- "$(realpath -- "$0")")"/"#
-            } else {
-                ""
-            };
-            let executable = format!(
-                "{}'{}'",
-                prefix,
-                escape_posix_for_single_quotes(&executable)
-            );
+        let simple = executable
+            .to_str()
+            .is_some_and(|executable| !executable.contains([' ', '\t', '\n']));
+        if shebang_length > 127 || !simple || relocatable {
+            let prefix = if relocatable { RELOCATABLE_PREFIX } else { "" };
+            let executable = format!("{prefix}{}", quote_posix_executable(executable));
             return format!("#!/bin/sh\n'''exec' {executable} \"$0\" \"$@\"\n' '''");
         }
     }
 
-    format!("#!{executable}")
+    format!("#!{}", executable.simplified_display())
+}
+
+/// Quote an executable for the `/bin/sh` trampoline in [`format_shebang`].
+///
+/// Python decodes scripts as UTF-8, so a path that isn't valid UTF-8 can't be written to the script
+/// verbatim. Instead, the path is spelled out with `printf` octal escapes.
+fn quote_posix_executable(executable: &Path) -> String {
+    if let Some(executable) = executable.to_str() {
+        return format!("'{}'", escape_posix_for_single_quotes(executable));
+    }
+
+    #[cfg(unix)]
+    let bytes = std::os::unix::ffi::OsStrExt::as_bytes(executable.as_os_str()).to_vec();
+    #[cfg(not(unix))]
+    let bytes = executable.to_string_lossy().into_owned().into_bytes();
+
+    let mut format = String::with_capacity(bytes.len());
+    for byte in bytes {
+        match byte {
+            b'%' => format.push_str("%%"),
+            b'\\' => format.push_str("\\\\"),
+            b'\'' => format.push_str("'\\''"),
+            b' '..=b'~' => format.push(char::from(byte)),
+            _ => format.push_str(&format!("\\{byte:03o}")),
+        }
+    }
+    format!("\"$(printf '{format}')\"")
+}
+
+/// The Python executable that a script runs with, as named by its shebang.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShebangExecutable {
+    /// The path to the executable.
+    Path(PathBuf),
+    /// The path to the executable, relative to the directory of the script, as written for
+    /// relocatable scripts.
+    Relative(PathBuf),
+    /// The name of an executable to search for on the `PATH`, via `/usr/bin/env`.
+    Env(String),
+}
+
+/// Parse the Python executable from the shebang of a script, i.e., the inverse of
+/// [`format_shebang`].
+///
+/// Returns `None` if the script doesn't start with a shebang, or if the shebang is a `/bin/sh`
+/// trampoline that wasn't written by [`format_shebang`].
+pub fn parse_shebang(contents: &str) -> Option<ShebangExecutable> {
+    let mut lines = contents.lines();
+    let shebang = lines.next()?.strip_prefix("#!")?.trim();
+
+    if shebang == "/bin/sh" {
+        let executable = lines
+            .next()?
+            .strip_prefix("'''exec' ")?
+            .strip_suffix(r#" "$0" "$@""#)?;
+        if let Some(executable) = executable.strip_prefix(RELOCATABLE_PREFIX) {
+            return unquote_posix_executable(executable).map(ShebangExecutable::Relative);
+        }
+        return unquote_posix_executable(executable).map(ShebangExecutable::Path);
+    }
+
+    let mut words = shebang.split_whitespace();
+    let program = words.next()?;
+    if Path::new(program)
+        .file_name()
+        .is_some_and(|name| name == "env")
+    {
+        let name = words.find(|word| !word.starts_with('-'))?;
+        return Some(ShebangExecutable::Env(name.to_string()));
+    }
+
+    Some(ShebangExecutable::Path(PathBuf::from(shebang)))
+}
+
+/// Unquote an executable from a `/bin/sh` trampoline, i.e., the inverse of
+/// [`quote_posix_executable`].
+fn unquote_posix_executable(executable: &str) -> Option<PathBuf> {
+    let Some(format) = executable
+        .strip_prefix("\"$(printf '")
+        .and_then(|format| format.strip_suffix("')\""))
+    else {
+        let executable = executable.strip_prefix('\'')?.strip_suffix('\'')?;
+        return Some(PathBuf::from(executable.replace(r"'\''", "'")));
+    };
+
+    let format = format.replace(r"'\''", "'");
+    let mut bytes = Vec::with_capacity(format.len());
+    let mut rest = format.as_str();
+    while let Some(char) = rest.chars().next() {
+        if let Some(escaped) = rest.strip_prefix("%%") {
+            bytes.push(b'%');
+            rest = escaped;
+        } else if let Some(escaped) = rest.strip_prefix(r"\\") {
+            bytes.push(b'\\');
+            rest = escaped;
+        } else if let Some(escaped) = rest.strip_prefix('\\') {
+            bytes.push(u8::from_str_radix(escaped.get(..3)?, 8).ok()?);
+            rest = &escaped[3..];
+        } else {
+            bytes.extend_from_slice(&rest.as_bytes()[..char.len_utf8()]);
+            rest = &rest[char.len_utf8()..];
+        }
+    }
+
+    #[cfg(unix)]
+    let executable = {
+        use std::os::unix::ffi::OsStringExt;
+        std::ffi::OsString::from_vec(bytes)
+    };
+    #[cfg(not(unix))]
+    let executable = String::from_utf8_lossy(&bytes).into_owned();
+    Some(PathBuf::from(executable))
 }
 
 /// Returns a [`PathBuf`] to `python[w].exe` for script execution.
@@ -928,14 +1043,14 @@ impl RenameOrCopy {
 #[cfg(test)]
 mod test {
     use std::io::Cursor;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     use anyhow::Result;
     use assert_fs::prelude::*;
     use indoc::{formatdoc, indoc};
 
     use crate::Error;
-    use crate::wheel::format_shebang;
+    use crate::wheel::{ShebangExecutable, format_shebang, parse_shebang};
 
     use super::{
         RecordEntry, Script, get_script_executable, parse_email_message_file, parse_wheel_file,
@@ -1135,6 +1250,87 @@ mod test {
             format_shebang(executable, os_name, false),
             "#!/bin/sh\n'''exec' '/usr/bin/path/to/a/very/long/executable/executable/executable/executable/executable/executable/executable/executable/name/python3' \"$0\" \"$@\"\n' '''"
         );
+
+        // Non-ASCII paths are fine, as long as they're valid UTF-8.
+        let executable = Path::new("/home/jürgen/.venv/bin/python3");
+        let os_name = "posix";
+        assert_eq!(
+            format_shebang(executable, os_name, false),
+            "#!/home/jürgen/.venv/bin/python3"
+        );
+
+        // Tabs are treated like spaces.
+        let executable = Path::new("/home/user/my\tproject/.venv/bin/python3");
+        let os_name = "posix";
+        assert_eq!(
+            format_shebang(executable, os_name, false),
+            "#!/bin/sh\n'''exec' '/home/user/my\tproject/.venv/bin/python3' \"$0\" \"$@\"\n' '''"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_shebang_non_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        // If the path isn't valid UTF-8, it's spelled out with `printf` escapes.
+        let executable = Path::new(OsStr::from_bytes(b"/home/j\xfcrgen's 100%/bin/python3"));
+        let os_name = "posix";
+        assert_eq!(
+            format_shebang(executable, os_name, false),
+            "#!/bin/sh\n'''exec' \"$(printf '/home/j\\374rgen'\\''s 100%%/bin/python3')\" \"$0\" \"$@\"\n' '''"
+        );
+    }
+
+    #[test]
+    fn test_parse_shebang() {
+        // A simple shebang names the executable directly.
+        let shebang = format_shebang(Path::new("/usr/bin/python3"), "posix", false);
+        assert_eq!(
+            parse_shebang(&shebang),
+            Some(ShebangExecutable::Path(PathBuf::from("/usr/bin/python3")))
+        );
+
+        // As does the `exec` trick, for paths that contain spaces.
+        let shebang = format_shebang(Path::new("/usr/bin/it's python3"), "posix", false);
+        assert_eq!(
+            parse_shebang(&shebang),
+            Some(ShebangExecutable::Path(PathBuf::from(
+                "/usr/bin/it's python3"
+            )))
+        );
+
+        // Relocatable scripts name the executable relative to the script.
+        let shebang = format_shebang(Path::new("python3"), "posix", true);
+        assert_eq!(
+            parse_shebang(&shebang),
+            Some(ShebangExecutable::Relative(PathBuf::from("python3")))
+        );
+
+        // Other installers may resolve the executable from the `PATH`.
+        assert_eq!(
+            parse_shebang("#!/usr/bin/env -S python3 -u\n"),
+            Some(ShebangExecutable::Env("python3".to_string()))
+        );
+
+        // Other `/bin/sh` scripts and binaries don't name an executable.
+        assert_eq!(parse_shebang("#!/bin/sh\nexec python3 \"$@\"\n"), None);
+        assert_eq!(parse_shebang("\x7fELF"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_parse_shebang_non_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let executable = Path::new(OsStr::from_bytes(b"/home/j\xfcrgen's 100%\\/bin/python3"));
+        let shebang = format_shebang(executable, "posix", false);
+        assert_eq!(
+            parse_shebang(&shebang),
+            Some(ShebangExecutable::Path(executable.to_path_buf()))
+        );
     }
 
     #[test]
@@ -42,8 +42,8 @@ pub use crate::origin::RequirementOrigin;
 #[cfg(feature = "non-pep508-extensions")]
 pub use crate::unnamed::{UnnamedRequirement, UnnamedRequirementUrl};
 pub use crate::verbatim_url::{
-    Scheme, VerbatimUrl, VerbatimUrlError, expand_env_vars, looks_like_git_repository,
-    split_scheme, strip_host,
+    Scheme, UnsetEnvVarError, VerbatimUrl, VerbatimUrlError, expand_env_vars,
+    looks_like_git_repository, split_scheme, strip_host, try_expand_env_vars,
 };
 /// Version and version specifiers used in requirements (reexport).
 // https://github.com/konstin/pep508_rs/issues/19
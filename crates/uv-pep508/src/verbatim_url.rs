@@ -454,6 +454,37 @@ pub fn expand_env_vars(s: &str) -> Cow<'_, str> {
     })
 }
 
+/// An environment variable referenced in a string was not set.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Environment variable `{0}` is not set; use `$${{{0}}}` to include `${{{0}}}` literally")]
+pub struct UnsetEnvVarError(pub String);
+
+/// Expand all environment variables, like [`expand_env_vars`], but fail on unset variables rather
+/// than retaining them verbatim.
+///
+/// A reference can be escaped with a second `$`, such that `$${MY_VARIABLE}` expands to the
+/// literal `${MY_VARIABLE}`.
+pub fn try_expand_env_vars(s: &str) -> Result<Cow<'_, str>, UnsetEnvVarError> {
+    static RE: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\$(?P<escape>\$)?\{(?P<name>[A-Z0-9_]+)}").unwrap());
+
+    let mut unset = None;
+    let expanded = RE.replace_all(s, |caps: &regex::Captures<'_>| {
+        let name = &caps["name"];
+        if caps.name("escape").is_some() {
+            return format!("${{{name}}}");
+        }
+        std::env::var(name).unwrap_or_else(|_| {
+            unset.get_or_insert_with(|| name.to_string());
+            String::new()
+        })
+    });
+    match unset {
+        Some(name) => Err(UnsetEnvVarError(name)),
+        None => Ok(expanded),
+    }
+}
+
 /// Like [`Url::parse`], but only splits the scheme. Derived from the `url` crate.
 pub fn split_scheme(s: &str) -> Option<(&str, &str)> {
     /// <https://url.spec.whatwg.org/#c0-controls-and-space>
@@ -669,6 +700,24 @@ mod tests {
         assert_eq!(split_scheme("https:"), Some(("https", "")));
     }
 
+    #[test]
+    fn try_expand() {
+        assert_eq!(
+            try_expand_env_vars("https://example.com/$${UV_TEST_UNSET_VARIABLE}/python"),
+            Ok(Cow::Borrowed(
+                "https://example.com/${UV_TEST_UNSET_VARIABLE}/python"
+            ))
+        );
+        assert_eq!(
+            try_expand_env_vars("https://example.com/${UV_TEST_UNSET_VARIABLE}/python"),
+            Err(UnsetEnvVarError("UV_TEST_UNSET_VARIABLE".to_string()))
+        );
+        assert_eq!(
+            try_expand_env_vars("https://example.com/$HOME/python"),
+            Ok(Cow::Borrowed("https://example.com/$HOME/python"))
+        );
+    }
+
     #[test]
     fn fragment() {
         assert_eq!(
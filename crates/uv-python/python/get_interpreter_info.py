@@ -556,6 +556,38 @@ def get_operating_system_and_architecture():
     return {"os": operating_system, "arch": architecture}
 
 
+def get_extensions() -> dict:
+    """Run the probes registered by the embedding application, if any.
+
+    Each probe defines a `probe()` function, the result of which is reported under the probe's
+    name. Probes that fail are omitted, and any output is redirected to `stderr`, such that it
+    doesn't interfere with the JSON on `stdout`.
+    """
+    path = os.path.join(os.path.dirname(__file__), "probes.json")
+    if not os.path.exists(path):
+        return {}
+    with open(path, encoding="utf-8") as f:
+        probes = json.load(f)
+
+    extensions = {}
+    stdout = sys.stdout
+    sys.stdout = sys.stderr
+    try:
+        for name, source in probes.items():
+            namespace = {"__name__": "__uv_probe__"}
+            try:
+                exec(source, namespace)
+                result = namespace["probe"]()
+                # Ensure the result can be serialized before including it.
+                json.dumps(result)
+            except Exception:
+                continue
+            extensions[name] = result
+    finally:
+        sys.stdout = stdout
+    return extensions
+
+
 def main() -> None:
     markers = {
         "implementation_name": implementation_name,
@@ -650,6 +682,9 @@ def main() -> None:
         "scheme": get_scheme(use_sysconfig_scheme),
         "virtualenv": get_virtualenv(),
         "platform": os_and_arch,
+        # Whether the interpreter is a macOS universal binary with both `arm64` and `x86_64`
+        # slices, e.g., a python.org framework build or the system Python.
+        "macos_universal2": sysconfig.get_platform().endswith("-universal2"),
         "manylinux_compatible": manylinux_compatible,
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
@@ -657,6 +692,15 @@ def main() -> None:
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
+        # The ABI tag and extension module suffix, e.g., `cpython-312-x86_64-linux-gnu` and
+        # `.cpython-312-x86_64-linux-gnu.so`, which identify the libc the interpreter was built
+        # against.
+        "soabi": sysconfig.get_config_var("SOABI"),
+        "ext_suffix": sysconfig.get_config_var("EXT_SUFFIX"),
+        # The directory containing `libpython`, e.g., `/usr/lib/x86_64-linux-gnu` on Debian, which
+        # isn't necessarily within the prefix.
+        "libdir": sysconfig.get_config_var("LIBDIR"),
+        "extensions": get_extensions(),
     }
     print(json.dumps(interpreter_info))
 
@@ -0,0 +1,463 @@
+//! Optional components of managed Python installations.
+//!
+//! The python-build-standalone distributions include a few components that many installations
+//! don't need, e.g., Tcl/Tk and the CPython test suite. These components can be deselected at
+//! install time, in which case they're removed from the installation after it is extracted, and the
+//! selection is recorded in the installation key, e.g., `cpython-3.12.11+tk-linux-x86_64-gnu`.
+//!
+//! Other modules, like `lzma` and `sqlite3`, are statically linked into the interpreter and are
+//! always included.
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use tracing::debug;
+
+use crate::Interpreter;
+use crate::installation::PythonInstallationKey;
+
+/// An optional component of a managed Python installation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PythonComponent {
+    /// The bundled `pip` installation.
+    Pip,
+    /// Tcl/Tk, along with the `tkinter`, `idlelib`, and `turtledemo` modules.
+    Tk,
+    /// The CPython test suite.
+    Tests,
+}
+
+impl PythonComponent {
+    const ALL: [Self; 3] = [Self::Pip, Self::Tk, Self::Tests];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Pip => "pip",
+            Self::Tk => "tk",
+            Self::Tests => "tests",
+        }
+    }
+}
+
+impl Display for PythonComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PythonComponent {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|component| component.as_str() == s)
+            .ok_or(())
+    }
+}
+
+/// The optional components included in a managed Python installation.
+///
+/// By default, all components are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PythonComponents {
+    pip: bool,
+    tk: bool,
+    tests: bool,
+}
+
+impl Default for PythonComponents {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl PythonComponents {
+    /// All components, i.e., a full installation.
+    pub const fn all() -> Self {
+        Self {
+            pip: true,
+            tk: true,
+            tests: true,
+        }
+    }
+
+    /// Only the given components.
+    pub fn only(components: impl IntoIterator<Item = PythonComponent>) -> Self {
+        let mut selection = Self {
+            pip: false,
+            tk: false,
+            tests: false,
+        };
+        for component in components {
+            match component {
+                PythonComponent::Pip => selection.pip = true,
+                PythonComponent::Tk => selection.tk = true,
+                PythonComponent::Tests => selection.tests = true,
+            }
+        }
+        selection
+    }
+
+    /// Include `pip`, in addition to the selected components.
+    #[must_use]
+    pub fn with_pip(self) -> Self {
+        Self { pip: true, ..self }
+    }
+
+    /// Whether the given component is included.
+    pub fn contains(self, component: PythonComponent) -> bool {
+        match component {
+            PythonComponent::Pip => self.pip,
+            PythonComponent::Tk => self.tk,
+            PythonComponent::Tests => self.tests,
+        }
+    }
+
+    /// Whether all components are included.
+    pub fn is_all(self) -> bool {
+        self == Self::all()
+    }
+
+    /// Whether all of the components in `other` are included.
+    pub fn is_superset(self, other: Self) -> bool {
+        other.iter().all(|component| self.contains(component))
+    }
+
+    /// Iterate over the included components.
+    pub fn iter(self) -> impl Iterator<Item = PythonComponent> {
+        PythonComponent::ALL
+            .into_iter()
+            .filter(move |component| self.contains(*component))
+    }
+
+    /// Split trailing components from a version, e.g., `3.12+pip+tk` into `3.12` and `pip` and
+    /// `tk`.
+    ///
+    /// Other suffixes, e.g., `+freethreaded`, are left on the version.
+    pub(crate) fn split_suffix(version: &str) -> (&str, Option<Self>) {
+        let mut rest = version;
+        let mut components = Vec::new();
+        while let Some((prefix, suffix)) = rest.rsplit_once('+') {
+            let Ok(component) = PythonComponent::from_str(suffix) else {
+                break;
+            };
+            components.push(component);
+            rest = prefix;
+        }
+        if components.is_empty() {
+            (version, None)
+        } else {
+            (rest, Some(Self::only(components)))
+        }
+    }
+
+    /// Whether an interpreter includes the components.
+    ///
+    /// Unlike managed installations, the components of an arbitrary interpreter aren't recorded,
+    /// so we check for them in the standard library and on the `sys.path` of the interpreter.
+    pub(crate) fn satisfied_by_interpreter(self, interpreter: &Interpreter) -> bool {
+        self.satisfied_by(interpreter.stdlib(), interpreter.sys_path())
+    }
+
+    /// Whether the components are present in the given standard library and `sys.path`.
+    ///
+    /// `pip` may be installed in any `sys.path` entry, e.g., in `/usr/lib/python3/dist-packages`
+    /// for Debian's `python3-pip`, rather than in the `site-packages` directory of the standard
+    /// library.
+    fn satisfied_by(self, stdlib: &Path, sys_path: &[PathBuf]) -> bool {
+        self.iter().all(|component| match component {
+            PythonComponent::Pip => std::iter::once(stdlib.join("site-packages"))
+                .chain(sys_path.iter().cloned())
+                .any(|dir| dir.join("pip").is_dir()),
+            PythonComponent::Tk => stdlib.join("tkinter").is_dir(),
+            PythonComponent::Tests => stdlib.join("test").is_dir(),
+        })
+    }
+
+    /// Remove the components that aren't included from an extracted installation.
+    pub(crate) fn trim(self, root: &Path, key: &PythonInstallationKey) -> io::Result<()> {
+        let stdlib = if key.os().is_windows() {
+            root.join("Lib")
+        } else {
+            root.join("lib").join(format!(
+                "python{}.{}{}",
+                key.major(),
+                key.minor(),
+                key.variant().suffix()
+            ))
+        };
+        let bin = if key.os().is_windows() {
+            root.join("Scripts")
+        } else {
+            root.join("bin")
+        };
+
+        for component in PythonComponent::ALL {
+            if self.contains(component) {
+                continue;
+            }
+            debug!("Removing `{component}` from the installation of {key}");
+            match component {
+                PythonComponent::Pip => {
+                    let site_packages = stdlib.join("site-packages");
+                    remove_matching(&site_packages, |name| {
+                        name == "pip" || (name.starts_with("pip-") && name.ends_with(".dist-info"))
+                    })?;
+                    remove_matching(&bin, |name| name.starts_with("pip"))?;
+                }
+                PythonComponent::Tk => {
+                    for module in ["tkinter", "idlelib", "turtledemo", "turtle.py"] {
+                        remove(&stdlib.join(module))?;
+                    }
+                    remove_matching(&bin, |name| name.starts_with("idle"))?;
+                    if key.os().is_windows() {
+                        remove(&root.join("tcl"))?;
+                        remove_matching(&root.join("DLLs"), |name| {
+                            name.starts_with("_tkinter")
+                                || name.starts_with("tcl")
+                                || name.starts_with("tk")
+                        })?;
+                    } else {
+                        remove_matching(&stdlib.join("lib-dynload"), |name| {
+                            name.starts_with("_tkinter")
+                        })?;
+                        // The Tcl/Tk script libraries, e.g., `lib/tcl8.6` and `lib/tk8.6`.
+                        remove_matching(&root.join("lib"), |name| {
+                            ["tcl", "tk", "itcl", "thread"]
+                                .iter()
+                                .any(|prefix| name.starts_with(prefix))
+                        })?;
+                    }
+                }
+                PythonComponent::Tests => {
+                    for package in [
+                        "test",
+                        "ctypes/test",
+                        "distutils/tests",
+                        "idlelib/idle_test",
+                        "lib2to3/tests",
+                        "sqlite3/test",
+                        "tkinter/test",
+                        "unittest/test",
+                    ] {
+                        remove(&stdlib.join(package))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for PythonComponents {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PythonComponents {
+    /// Selections with more components are greater, i.e., a full installation is preferred.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iter()
+            .count()
+            .cmp(&other.iter().count())
+            .then_with(|| (self.pip, self.tk, self.tests).cmp(&(other.pip, other.tk, other.tests)))
+    }
+}
+
+impl Display for PythonComponents {
+    /// Display the included components, separated by `+`, e.g., `pip+tk`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, component) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("+")?;
+            }
+            write!(f, "{component}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Remove a file or directory, if it exists.
+fn remove(path: &Path) -> io::Result<()> {
+    let result = if path.is_dir() {
+        fs_err::remove_dir_all(path)
+    } else {
+        fs_err::remove_file(path)
+    };
+    match result {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Remove the entries of a directory with names matching the predicate.
+fn remove_matching(dir: &Path, predicate: impl Fn(&str) -> bool) -> io::Result<()> {
+    let entries = match fs_err::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_name().to_str().is_some_and(&predicate) {
+            remove(&entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+
+    use super::{PythonComponent, PythonComponents};
+    use crate::installation::PythonInstallationKey;
+
+    #[test]
+    fn split_suffix() {
+        assert_eq!(PythonComponents::split_suffix("3.12"), ("3.12", None));
+        assert_eq!(
+            PythonComponents::split_suffix("3.12+tk"),
+            ("3.12", Some(PythonComponents::only([PythonComponent::Tk])))
+        );
+        assert_eq!(
+            PythonComponents::split_suffix("3.13+freethreaded+tk+pip"),
+            (
+                "3.13+freethreaded",
+                Some(PythonComponents::only([
+                    PythonComponent::Pip,
+                    PythonComponent::Tk
+                ]))
+            )
+        );
+        assert_eq!(
+            PythonComponents::split_suffix("3.13+freethreaded"),
+            ("3.13+freethreaded", None)
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let tk = PythonComponents::only([PythonComponent::Tk]);
+        let pip_tk = PythonComponents::only([PythonComponent::Pip, PythonComponent::Tk]);
+        assert!(PythonComponents::all() > pip_tk);
+        assert!(pip_tk > tk);
+        assert!(PythonComponents::all().is_superset(tk));
+        assert!(!tk.is_superset(pip_tk));
+        assert_eq!(pip_tk.to_string(), "pip+tk");
+    }
+
+    #[test]
+    fn trim() -> Result<()> {
+        let root = tempfile::tempdir()?;
+        let root = root.path();
+        let key = PythonInstallationKey::from_str("cpython-3.12.0-linux-x86_64-gnu")?;
+
+        // A minimal layout of a python-build-standalone distribution.
+        let stdlib = root.join("lib").join("python3.12");
+        for dir in [
+            stdlib.join("site-packages").join("pip"),
+            stdlib.join("site-packages").join("pip-24.0.dist-info"),
+            stdlib.join("tkinter"),
+            stdlib.join("idlelib"),
+            stdlib.join("test"),
+            stdlib.join("unittest").join("test"),
+            stdlib.join("lib-dynload"),
+            root.join("lib").join("tcl8.6"),
+        ] {
+            fs_err::create_dir_all(dir)?;
+        }
+        for file in [
+            stdlib.join("turtle.py"),
+            stdlib
+                .join("lib-dynload")
+                .join("_tkinter.cpython-312-x86_64-linux-gnu.so"),
+            stdlib
+                .join("lib-dynload")
+                .join("_ssl.cpython-312-x86_64-linux-gnu.so"),
+            root.join("bin").join("pip3.12"),
+            root.join("bin").join("idle3.12"),
+            root.join("bin").join("python3.12"),
+        ] {
+            fs_err::create_dir_all(file.parent().unwrap())?;
+            fs_err::write(file, "")?;
+        }
+
+        PythonComponents::only([PythonComponent::Pip]).trim(root, &key)?;
+
+        // The selected components are kept, along with the rest of the installation.
+        assert!(stdlib.join("site-packages").join("pip").is_dir());
+        assert!(
+            stdlib
+                .join("site-packages")
+                .join("pip-24.0.dist-info")
+                .is_dir()
+        );
+        assert!(root.join("bin").join("pip3.12").is_file());
+        assert!(root.join("bin").join("python3.12").is_file());
+        assert!(
+            stdlib
+                .join("lib-dynload")
+                .join("_ssl.cpython-312-x86_64-linux-gnu.so")
+                .is_file()
+        );
+
+        // The others are removed.
+        for path in [
+            stdlib.join("tkinter"),
+            stdlib.join("idlelib"),
+            stdlib.join("turtle.py"),
+            stdlib.join("test"),
+            stdlib.join("unittest").join("test"),
+            stdlib
+                .join("lib-dynload")
+                .join("_tkinter.cpython-312-x86_64-linux-gnu.so"),
+            root.join("lib").join("tcl8.6"),
+            root.join("bin").join("idle3.12"),
+        ] {
+            assert!(!path.exists(), "`{}` should be removed", path.display());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn satisfied_by() -> Result<()> {
+        let root = tempfile::tempdir()?;
+        let stdlib = root.path().join("lib").join("python3.12");
+        let dist_packages = root
+            .path()
+            .join("lib")
+            .join("python3")
+            .join("dist-packages");
+        fs_err::create_dir_all(stdlib.join("tkinter"))?;
+
+        let pip = PythonComponents::only([PythonComponent::Pip]);
+        let tk = PythonComponents::only([PythonComponent::Tk]);
+        assert!(tk.satisfied_by(&stdlib, &[]));
+        assert!(!pip.satisfied_by(&stdlib, std::slice::from_ref(&dist_packages)));
+
+        // `pip` may be installed outside of the standard library, e.g., by Debian's `python3-pip`.
+        fs_err::create_dir_all(dist_packages.join("pip"))?;
+        assert!(pip.satisfied_by(&stdlib, std::slice::from_ref(&dist_packages)));
+        assert!(!PythonComponents::all().satisfied_by(&stdlib, &[dist_packages]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_pip() {
+        let tk = PythonComponents::only([PythonComponent::Tk]);
+        assert_eq!(
+            tk.with_pip(),
+            PythonComponents::only([PythonComponent::Pip, PythonComponent::Tk])
+        );
+        assert_eq!(PythonComponents::all().with_pip(), PythonComponents::all());
+    }
+}
@@ -1,9 +1,11 @@
 use itertools::{Either, Itertools};
 use regex::Regex;
-use rustc_hash::{FxBuildHasher, FxHashSet};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 use same_file::is_same_file;
+use std::borrow::Cow;
 use std::env::consts::EXE_SUFFIX;
 use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
 use std::{env, io, iter};
 use std::{path::Path, path::PathBuf, str::FromStr};
 use thiserror::Error;
@@ -13,7 +15,6 @@ use which::{which, which_all};
 
 use uv_cache::Cache;
 use uv_fs::Simplified;
-use uv_fs::which::is_executable;
 use uv_pep440::{
     LowerBound, Prerelease, UpperBound, Version, VersionSpecifier, VersionSpecifiers,
     release_specifiers_to_ranges,
@@ -21,14 +22,22 @@ use uv_pep440::{
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
+use crate::discovery_filter::PythonDiscoveryFilter;
 use crate::downloads::{PlatformRequest, PythonDownloadRequest};
+use crate::features::PythonFeatures;
+use crate::host;
 use crate::implementation::ImplementationName;
 use crate::installation::PythonInstallation;
 use crate::interpreter::Error as InterpreterError;
-use crate::interpreter::{StatusCodeError, UnexpectedResponseError};
+use crate::interpreter::{StatusCodeError, UnexpectedResponseError, canonicalize_executable};
 use crate::managed::{ManagedPythonInstallations, PythonMinorVersionLink};
 #[cfg(windows)]
 use crate::microsoft_store::find_microsoft_store_pythons;
+use crate::provider::{self, ProviderPrecedence};
+#[cfg(windows)]
+use crate::py_launcher::py_launcher_pythons;
+use crate::query_probes::QueryProbe;
+use crate::shim::Shim;
 use crate::virtualenv::Error as VirtualEnvError;
 use crate::virtualenv::{
     CondaEnvironmentKind, conda_environment_from_env, virtualenv_from_env,
@@ -143,6 +152,21 @@ impl From<bool> for PythonDownloads {
     }
 }
 
+/// The policy for selecting the managed Python version to download when no installed interpreter
+/// satisfies a version range, e.g., a project's `requires-python`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonResolution {
+    /// Download the latest Python version in the range.
+    #[default]
+    Highest,
+    /// Download the lowest Python minor version in the range, i.e., the version at its lower
+    /// bound.
+    LowestCompatible,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum EnvironmentPreference {
     /// Only use virtual environments, never allow a system environment.
@@ -216,14 +240,24 @@ pub enum PythonSource {
     SearchPath,
     /// The first executable found in the search path i.e. `PATH`
     SearchPathFirst,
+    /// A pyenv shim was found in the search path i.e. `PATH`, and resolved to the executable it
+    /// runs
+    PyenvShim,
+    /// An asdf shim was found in the search path i.e. `PATH`, and resolved to the executable it
+    /// runs
+    AsdfShim,
     /// An executable was found in the Windows registry via PEP 514
     Registry,
+    /// An executable was reported by the Windows `py` launcher, e.g., a custom command in `py.ini`
+    PyLauncher,
     /// An executable was found in the known Microsoft Store locations
     MicrosoftStore,
     /// The Python installation was found in the uv managed Python directory
     Managed,
     /// The Python installation was found via the invoking interpreter i.e. via `python -m uv ...`
     ParentInterpreter,
+    /// An executable was reported by a custom [`PythonProvider`](crate::provider::PythonProvider)
+    Provider(&'static str),
 }
 
 #[derive(Error, Debug)]
@@ -263,6 +297,61 @@ pub enum Error {
     // TODO(zanieb): Is this error case necessary still? We should probably drop it.
     #[error("Interpreter discovery for `{0}` requires `{1}` but only `{2}` is allowed")]
     SourceNotAllowed(PythonRequest, PythonSource, PythonPreference),
+
+    /// The user-configured discovery filter is invalid.
+    #[error(transparent)]
+    DiscoveryFilter(#[from] crate::discovery_filter::Error),
+}
+
+/// An active virtual environment that was skipped during Python discovery, as its interpreter
+/// does not satisfy the request.
+///
+/// See [`find_active_environment_skip`].
+#[derive(Debug, Clone)]
+pub struct ActiveEnvironmentSkip {
+    root: PathBuf,
+    version: Version,
+    request: PythonRequest,
+}
+
+impl ActiveEnvironmentSkip {
+    /// The root of the skipped environment.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl fmt::Display for ActiveEnvironmentSkip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ignoring the active virtual environment at `{}`: Python {} does not satisfy the request for {}",
+            self.root.user_display(),
+            self.version,
+            self.request
+        )
+    }
+}
+
+/// Determine whether the active virtual environment, if any, would be skipped when discovering a
+/// Python installation for the given request.
+///
+/// Returns `None` if there is no active virtual environment, or if its interpreter satisfies the
+/// request.
+pub fn find_active_environment_skip(
+    request: &PythonRequest,
+    cache: &Cache,
+) -> Option<ActiveEnvironmentSkip> {
+    let root = virtualenv_from_env()?;
+    let interpreter = Interpreter::query(virtualenv_python_executable(&root), cache).ok()?;
+    if request.satisfied(&interpreter, cache) {
+        return None;
+    }
+    Some(ActiveEnvironmentSkip {
+        root,
+        version: interpreter.python_version().clone(),
+        request: request.clone(),
+    })
 }
 
 /// Lazily iterate over Python executables in mutable virtual environments.
@@ -331,17 +420,29 @@ fn python_executables_from_installed<'a>(
     implementation: Option<&'a ImplementationName>,
     platform: PlatformRequest,
     preference: PythonPreference,
+    cache: &'a Cache,
+    python_install_dir: Option<&'a Path>,
     preview: Preview,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
+    // Skip executables excluded by the user, before they're ever queried.
+    let filter = match PythonDiscoveryFilter::from_settings(cache.interpreter_settings()) {
+        Ok(filter) => Arc::new(filter),
+        Err(err) => return Box::new(iter::once(Err(Error::from(err)))),
+    };
+
     let from_managed_installations = iter::once_with(move || {
-        ManagedPythonInstallations::from_settings(None)
+        ManagedPythonInstallations::discoverable(python_install_dir)
             .map_err(Error::from)
-            .and_then(|installed_installations| {
-                debug!(
-                    "Searching for managed installations at `{}`",
-                    installed_installations.root().user_display()
-                );
-                let installations = installed_installations.find_matching_current_platform()?;
+            .and_then(|directories| {
+                // Search the project-level directory, if any, before the user-level directory.
+                let mut installations = Vec::new();
+                for installed_installations in directories {
+                    debug!(
+                        "Searching for managed installations at `{}`",
+                        installed_installations.root().user_display()
+                    );
+                    installations.extend(installed_installations.find_matching_current_platform()?);
+                }
                 // Check that the Python version and platform satisfy the request to avoid
                 // unnecessary interpreter queries later
                 Ok(installations
@@ -385,63 +486,104 @@ fn python_executables_from_installed<'a>(
     })
     .flatten_ok();
 
-    let from_search_path = iter::once_with(move || {
-        python_executables_from_search_path(version, implementation)
-            .enumerate()
-            .map(|(i, path)| {
-                if i == 0 {
-                    Ok((PythonSource::SearchPathFirst, path))
-                } else {
-                    Ok((PythonSource::SearchPath, path))
-                }
-            })
+    let from_search_path = iter::once_with({
+        let filter = filter.clone();
+        move || {
+            python_executables_from_search_path(version, implementation)
+                .filter(move |path| filter.allows(path))
+                .enumerate()
+                .filter_map(move |(i, path)| {
+                    if let Some(shim) = Shim::from_executable(&path) {
+                        return shim
+                            .resolve(&path, cache)
+                            .map(|executable| Ok((shim.source(), executable)));
+                    }
+                    if i == 0 {
+                        Some(Ok((PythonSource::SearchPathFirst, path)))
+                    } else {
+                        Some(Ok((PythonSource::SearchPath, path)))
+                    }
+                })
+        }
     })
     .flatten();
 
-    let from_windows_registry = iter::once_with(move || {
-        #[cfg(windows)]
-        {
-            // Skip interpreter probing if we already know the version doesn't match.
-            let version_filter = move |entry: &WindowsPython| {
-                if let Some(found) = &entry.version {
-                    // Some distributions emit the patch version (example: `SysVersion: 3.9`)
-                    if found.string.chars().filter(|c| *c == '.').count() == 1 {
-                        version.matches_major_minor(found.major(), found.minor())
+    let from_windows_registry = iter::once_with({
+        let filter = filter.clone();
+        move || {
+            #[cfg(windows)]
+            {
+                // Skip interpreter probing if we already know the version doesn't match.
+                let version_filter = move |entry: &WindowsPython| {
+                    if let Some(found) = &entry.version {
+                        // Some distributions emit the patch version (example: `SysVersion: 3.9`)
+                        if found.string.chars().filter(|c| *c == '.').count() == 1 {
+                            version.matches_major_minor(found.major(), found.minor())
+                        } else {
+                            version.matches_version(found)
+                        }
                     } else {
-                        version.matches_version(found)
+                        true
                     }
-                } else {
-                    true
-                }
-            };
+                };
 
-            env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
-                .is_none()
-                .then(|| {
-                    registry_pythons()
-                        .map(|entries| {
-                            entries
-                                .into_iter()
-                                .filter(version_filter)
-                                .map(|entry| (PythonSource::Registry, entry.path))
-                                .chain(
-                                    find_microsoft_store_pythons()
-                                        .filter(version_filter)
-                                        .map(|entry| (PythonSource::MicrosoftStore, entry.path)),
-                                )
-                        })
-                        .map_err(Error::from)
-                })
-                .into_iter()
-                .flatten_ok()
-        }
-        #[cfg(not(windows))]
-        {
-            Vec::new()
+                env::var_os(EnvVars::UV_TEST_PYTHON_PATH)
+                    .is_none()
+                    .then(|| {
+                        registry_pythons()
+                            .map(|entries| {
+                                let store = find_microsoft_store_pythons().collect::<Vec<_>>();
+                                // The `py` launcher also reports the registry and Microsoft Store
+                                // installations, which we already discovered above.
+                                let known = entries
+                                    .iter()
+                                    .chain(&store)
+                                    .map(|entry| entry.path.clone())
+                                    .collect::<FxHashSet<_>>();
+                                entries
+                                    .into_iter()
+                                    .filter(version_filter)
+                                    .map(|entry| (PythonSource::Registry, entry.path))
+                                    .chain(
+                                        store.into_iter().filter(version_filter).map(|entry| {
+                                            (PythonSource::MicrosoftStore, entry.path)
+                                        }),
+                                    )
+                                    .chain(
+                                        iter::once_with(py_launcher_pythons)
+                                            .flatten()
+                                            .filter(move |entry| !known.contains(&entry.path))
+                                            .filter(version_filter)
+                                            .map(|entry| (PythonSource::PyLauncher, entry.path)),
+                                    )
+                            })
+                            .map_err(Error::from)
+                    })
+                    .into_iter()
+                    .flatten_ok()
+                    .filter_ok(move |(_, path)| filter.allows(path))
+            }
+            #[cfg(not(windows))]
+            {
+                let _ = filter;
+                Vec::new()
+            }
         }
     })
     .flatten();
 
+    let from_providers = move |precedence| {
+        let filter = filter.clone();
+        iter::once_with(move || {
+            provider::python_executables(precedence, version, implementation)
+                .filter(move |(_, path)| filter.allows(path))
+                .map(Ok)
+        })
+        .flatten()
+    };
+    let from_search_path =
+        from_providers(ProviderPrecedence::BeforeSearchPath).chain(from_search_path);
+
     match preference {
         PythonPreference::OnlyManaged => {
             // TODO(zanieb): Ideally, we'd create "fake" managed installation directories for tests,
@@ -454,16 +596,25 @@ fn python_executables_from_installed<'a>(
             }
         }
         PythonPreference::Managed => Box::new(
-            from_managed_installations
+            from_providers(ProviderPrecedence::First)
+                .chain(from_managed_installations)
                 .chain(from_search_path)
-                .chain(from_windows_registry),
+                .chain(from_windows_registry)
+                .chain(from_providers(ProviderPrecedence::Last)),
         ),
         PythonPreference::System => Box::new(
-            from_search_path
+            from_providers(ProviderPrecedence::First)
+                .chain(from_search_path)
                 .chain(from_windows_registry)
-                .chain(from_managed_installations),
+                .chain(from_managed_installations)
+                .chain(from_providers(ProviderPrecedence::Last)),
+        ),
+        PythonPreference::OnlySystem => Box::new(
+            from_providers(ProviderPrecedence::First)
+                .chain(from_search_path)
+                .chain(from_windows_registry)
+                .chain(from_providers(ProviderPrecedence::Last)),
         ),
-        PythonPreference::OnlySystem => Box::new(from_search_path.chain(from_windows_registry)),
     }
 }
 
@@ -482,6 +633,8 @@ fn python_executables<'a>(
     platform: PlatformRequest,
     environments: EnvironmentPreference,
     preference: PythonPreference,
+    cache: &'a Cache,
+    python_install_dir: Option<&'a Path>,
     preview: Preview,
 ) -> Box<dyn Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a> {
     // Always read from `UV_INTERNAL__PARENT_INTERPRETER` — it could be a system interpreter
@@ -502,8 +655,15 @@ fn python_executables<'a>(
     .flatten();
 
     let from_virtual_environments = python_executables_from_virtual_environments();
-    let from_installed =
-        python_executables_from_installed(version, implementation, platform, preference, preview);
+    let from_installed = python_executables_from_installed(
+        version,
+        implementation,
+        platform,
+        preference,
+        cache,
+        python_install_dir,
+        preview,
+    );
 
     // Limit the search to the relevant environment preference; this avoids unnecessary work like
     // traversal of the file system. Subsequent filtering should be done by the caller with
@@ -561,10 +721,15 @@ fn python_executables_from_search_path<'a>(
     // precedence.
     let search_dirs: Vec<_> = env::split_paths(&search_path).collect();
     let mut seen_dirs = FxHashSet::with_capacity_and_hasher(search_dirs.len(), FxBuildHasher);
+    let fs = host::file_system();
     search_dirs
         .into_iter()
-        .filter(|dir| dir.is_dir())
+        .filter({
+            let fs = fs.clone();
+            move |dir| fs.is_dir(dir)
+        })
         .flat_map(move |dir| {
+            let fs = fs.clone();
             // Clone the directory for second closure
             let dir_clone = dir.clone();
             trace!(
@@ -583,18 +748,13 @@ fn python_executables_from_search_path<'a>(
                 // If we cannot determine if the directory is unique, we'll assume it is
                 .unwrap_or(true)
                 .then(|| {
+                    let fs_clone = fs.clone();
                     possible_names
                         .clone()
                         .into_iter()
-                        .flat_map(move |name| {
-                            // Since we're just working with a single directory at a time, we collect to simplify ownership
-                            which::which_in_global(&*name, Some(&dir))
-                                .into_iter()
-                                .flatten()
-                                // We have to collect since `which` requires that the regex outlives its
-                                // parameters, and the dir is local while we return the iterator.
-                                .collect::<Vec<_>>()
-                        })
+                        // Since we're just working with a single directory at a time, we collect to
+                        // simplify ownership.
+                        .flat_map(move |name| fs.find_executables(&dir, &name))
                         .chain(find_all_minor(implementation, version, &dir_clone))
                         .filter(|path| !is_windows_store_shim(path))
                         .inspect(|path| {
@@ -603,12 +763,7 @@ fn python_executables_from_search_path<'a>(
                         .chain(
                             // TODO(zanieb): Consider moving `python.bat` into `possible_names` to avoid a chain
                             cfg!(windows)
-                                .then(move || {
-                                    which::which_in_global("python.bat", Some(&dir_clone))
-                                        .into_iter()
-                                        .flatten()
-                                        .collect::<Vec<_>>()
-                                })
+                                .then(move || fs_clone.find_executables(&dir_clone, "python.bat"))
                                 .into_iter()
                                 .flatten(),
                         )
@@ -646,11 +801,11 @@ fn find_all_minor(
                 ))
                 .unwrap()
             };
-            let all_minors = fs_err::read_dir(dir)
+            let fs = host::file_system();
+            let all_minors = fs
+                .read_dir(dir)
                 .into_iter()
                 .flatten()
-                .flatten()
-                .map(|entry| entry.path())
                 .filter(move |path| {
                     let Some(filename) = path.file_name() else {
                         return false;
@@ -676,7 +831,7 @@ fn find_all_minor(
                     }
                     true
                 })
-                .filter(|path| is_executable(path))
+                .filter(move |path| fs.is_executable(path))
                 .collect::<Vec<_>>();
             Either::Left(all_minors.into_iter())
         }
@@ -702,6 +857,7 @@ fn python_interpreters<'a>(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &'a Cache,
+    python_install_dir: Option<&'a Path>,
     preview: Preview,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
     python_interpreters_from_executables(
@@ -714,6 +870,8 @@ fn python_interpreters<'a>(
             platform,
             environments,
             preference,
+            cache,
+            python_install_dir,
             preview,
         )
         .filter_ok(move |(source, path)| {
@@ -737,31 +895,81 @@ fn python_interpreters<'a>(
         }
     })
     .filter_ok(move |(source, interpreter)| {
-        satisfies_python_preference(*source, interpreter, preference)
+        satisfies_python_preference(*source, interpreter, preference, python_install_dir)
     })
 }
 
 /// Lazily convert Python executables into interpreters.
+///
+/// Executables that are aliases of an executable that was already queried, e.g., `python3` and
+/// `python3.12` linking to the same file, reuse the existing interpreter rather than querying the
+/// same binary again.
 fn python_interpreters_from_executables<'a>(
     executables: impl Iterator<Item = Result<(PythonSource, PathBuf), Error>> + 'a,
     cache: &'a Cache,
 ) -> impl Iterator<Item = Result<(PythonSource, Interpreter), Error>> + 'a {
-    executables.map(|result| match result {
-        Ok((source, path)) => Interpreter::query(&path, cache)
-            .map(|interpreter| (source, interpreter))
-            .inspect(|(source, interpreter)| {
+    let mut seen = FxHashMap::<AliasKey, Interpreter>::default();
+    executables.map(move |result| match result {
+        Ok((source, path)) => {
+            let key = AliasKey::from_executable(&path);
+            if let Some(interpreter) = key.as_ref().and_then(|key| seen.get(key)) {
                 debug!(
-                    "Found `{}` at `{}` ({source})",
+                    "Found `{}` at `{}` ({source}), an alias of `{}`",
                     interpreter.key(),
-                    path.display()
+                    path.display(),
+                    interpreter.real_executable().display()
                 );
-            })
-            .map_err(|err| Error::Query(Box::new(err), path, source))
-            .inspect_err(|err| debug!("{err}")),
+                return Ok((source, interpreter.clone().with_real_executable(path)));
+            }
+            Interpreter::query(&path, cache)
+                .map(|interpreter| (source, interpreter))
+                .inspect(|(source, interpreter)| {
+                    debug!(
+                        "Found `{}` at `{}` ({source})",
+                        interpreter.key(),
+                        path.display()
+                    );
+                    if let Some(key) = key {
+                        seen.insert(key, interpreter.clone());
+                    }
+                })
+                .map_err(|err| Error::Query(Box::new(err), path, source))
+                .inspect_err(|err| debug!("{err}"))
+        }
         Err(err) => Err(err),
     })
 }
 
+/// A key that identifies the interpreter an executable will run, without querying it.
+///
+/// Python derives `sys.prefix` from the resolved executable, unless a `pyvenv.cfg` is found
+/// alongside the invoked path. As such, executables that resolve to the same file and share the
+/// same (or no) `pyvenv.cfg` are aliases of the same interpreter.
+///
+/// Hard links can't be resolved to a single path, so they're queried separately; see
+/// [`Interpreter::identity`] to detect them after the fact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct AliasKey {
+    canonical: PathBuf,
+    pyvenv_cfg: Option<PathBuf>,
+}
+
+impl AliasKey {
+    fn from_executable(path: &Path) -> Option<Self> {
+        let canonical = canonicalize_executable(path).ok()?;
+        let parent = path.parent()?;
+        let pyvenv_cfg = [Some(parent), parent.parent()]
+            .into_iter()
+            .flatten()
+            .map(|dir| dir.join("pyvenv.cfg"))
+            .find(|cfg| cfg.is_file());
+        Some(Self {
+            canonical,
+            pyvenv_cfg,
+        })
+    }
+}
+
 /// Whether a [`Interpreter`] matches the [`EnvironmentPreference`].
 ///
 /// This is the correct way to determine if an interpreter matches the preference. In contrast,
@@ -867,10 +1075,14 @@ fn source_satisfies_environment_preference(
 }
 
 /// Returns true if a Python interpreter matches the [`PythonPreference`].
+///
+/// Interpreters in the project-level directory for managed Python installations, if any, are
+/// considered managed.
 pub fn satisfies_python_preference(
     source: PythonSource,
     interpreter: &Interpreter,
     preference: PythonPreference,
+    python_install_dir: Option<&Path>,
 ) -> bool {
     // If the source is "explicit", we will not apply the Python preference, e.g., if the user has
     // activated a virtual environment, we should always allow it. We may want to invalidate the
@@ -886,15 +1098,20 @@ pub fn satisfies_python_preference(
         | PythonSource::DiscoveredEnvironment
         | PythonSource::SearchPath
         | PythonSource::SearchPathFirst
+        | PythonSource::PyenvShim
+        | PythonSource::AsdfShim
         | PythonSource::Registry
+        | PythonSource::PyLauncher
         | PythonSource::MicrosoftStore
+        | PythonSource::Provider(_)
         | PythonSource::BaseCondaPrefix => false,
     };
 
     match preference {
         PythonPreference::OnlyManaged => {
             // Perform a fast check using the source before querying the interpreter
-            if matches!(source, PythonSource::Managed) || interpreter.is_managed() {
+            if matches!(source, PythonSource::Managed) || interpreter.is_managed(python_install_dir)
+            {
                 true
             } else {
                 if is_explicit {
@@ -915,7 +1132,7 @@ pub fn satisfies_python_preference(
         // If not "only" a kind, any interpreter is okay
         PythonPreference::Managed | PythonPreference::System => true,
         PythonPreference::OnlySystem => {
-            if is_system_interpreter(source, interpreter) {
+            if is_system_interpreter(source, interpreter, python_install_dir) {
                 true
             } else {
                 if is_explicit {
@@ -936,7 +1153,11 @@ pub fn satisfies_python_preference(
     }
 }
 
-pub(crate) fn is_system_interpreter(source: PythonSource, interpreter: &Interpreter) -> bool {
+pub(crate) fn is_system_interpreter(
+    source: PythonSource,
+    interpreter: &Interpreter,
+    python_install_dir: Option<&Path>,
+) -> bool {
     match source {
         // A managed interpreter is never a system interpreter
         PythonSource::Managed => false,
@@ -948,8 +1169,12 @@ pub(crate) fn is_system_interpreter(source: PythonSource, interpreter: &Interpre
         | PythonSource::DiscoveredEnvironment
         | PythonSource::SearchPath
         | PythonSource::SearchPathFirst
+        | PythonSource::PyenvShim
+        | PythonSource::AsdfShim
         | PythonSource::Registry
-        | PythonSource::BaseCondaPrefix => !interpreter.is_managed(),
+        | PythonSource::PyLauncher
+        | PythonSource::Provider(_)
+        | PythonSource::BaseCondaPrefix => !interpreter.is_managed(python_install_dir),
         // Managed interpreters should never be found in the store
         PythonSource::MicrosoftStore => true,
     }
@@ -966,7 +1191,13 @@ impl Error {
             Self::Query(err, _, source) => match &**err {
                 InterpreterError::Encode(_)
                 | InterpreterError::Io(_)
-                | InterpreterError::SpawnFailed { .. } => true,
+                | InterpreterError::SpawnFailed { .. }
+                | InterpreterError::MockInterpreter { .. }
+                | InterpreterError::BaseInterpreterCycle(_) => true,
+                #[cfg(any(test, feature = "embedded"))]
+                InterpreterError::EmbeddedNotInitialized
+                | InterpreterError::EmbeddedQuery(_)
+                | InterpreterError::EmbeddedQueryScript(_) => true,
                 InterpreterError::UnexpectedResponse(UnexpectedResponseError { path, .. })
                 | InterpreterError::StatusCode(StatusCodeError { path, .. }) => {
                     debug!(
@@ -975,6 +1206,20 @@ impl Error {
                     );
                     false
                 }
+                InterpreterError::QueryTimeout { path, .. } => {
+                    debug!(
+                        "Skipping unresponsive interpreter at {} from {source}: {err}",
+                        path.display()
+                    );
+                    false
+                }
+                InterpreterError::Quarantined { path, reason, .. } => {
+                    debug!(
+                        "Skipping quarantined interpreter at {} from {source}: {reason}",
+                        path.display()
+                    );
+                    false
+                }
                 InterpreterError::QueryScript { path, err } => {
                     debug!(
                         "Skipping bad interpreter at {} from {source}: {err}",
@@ -1041,7 +1286,15 @@ fn python_interpreters_with_executable_name<'a>(
     python_interpreters_from_executables(
         which_all(name)
             .into_iter()
-            .flat_map(|inner| inner.map(|path| Ok((PythonSource::SearchPath, path)))),
+            .flatten()
+            .filter_map(move |path| {
+                if let Some(shim) = Shim::from_executable(&path) {
+                    return shim
+                        .resolve(&path, cache)
+                        .map(|executable| Ok((shim.source(), executable)));
+                }
+                Some(Ok((PythonSource::SearchPath, path)))
+            }),
         cache,
     )
 }
@@ -1052,6 +1305,7 @@ pub fn find_python_installations<'a>(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &'a Cache,
+    python_install_dir: Option<&'a Path>,
     preview: Preview,
 ) -> Box<dyn Iterator<Item = Result<FindPythonResult, Error>> + 'a> {
     let sources = DiscoveryPreferences {
@@ -1144,6 +1398,7 @@ pub fn find_python_installations<'a>(
                 environments,
                 preference,
                 cache,
+                python_install_dir,
                 preview,
             )
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
@@ -1157,6 +1412,7 @@ pub fn find_python_installations<'a>(
                 environments,
                 preference,
                 cache,
+                python_install_dir,
                 preview,
             )
             .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
@@ -1174,6 +1430,7 @@ pub fn find_python_installations<'a>(
                     environments,
                     preference,
                     cache,
+                    python_install_dir,
                     preview,
                 )
                 .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
@@ -1188,6 +1445,7 @@ pub fn find_python_installations<'a>(
                 environments,
                 preference,
                 cache,
+                python_install_dir,
                 preview,
             )
             .filter_ok(|(_source, interpreter)| implementation.matches_interpreter(interpreter))
@@ -1206,6 +1464,7 @@ pub fn find_python_installations<'a>(
                     environments,
                     preference,
                     cache,
+                    python_install_dir,
                     preview,
                 )
                 .filter_ok(|(_source, interpreter)| implementation.matches_interpreter(interpreter))
@@ -1227,48 +1486,38 @@ pub fn find_python_installations<'a>(
                     environments,
                     preference,
                     cache,
+                    python_install_dir,
                     preview,
                 )
                 .filter_ok(|(_source, interpreter)| request.satisfied_by_interpreter(interpreter))
+                .map_ok(|(source, interpreter)| (source, request.select_arch_slice(interpreter)))
                 .map_ok(|tuple| Ok(PythonInstallation::from_tuple(tuple)))
             })
         }
     }
 }
 
-/// Find a Python installation that satisfies the given request.
-///
-/// If an error is encountered while locating or inspecting a candidate installation,
-/// the error will raised instead of attempting further candidates.
-pub(crate) fn find_python_installation(
-    request: &PythonRequest,
-    environments: EnvironmentPreference,
-    preference: PythonPreference,
-    cache: &Cache,
-    preview: Preview,
-) -> Result<FindPythonResult, Error> {
-    let installations =
-        find_python_installations(request, environments, preference, cache, preview);
-    let mut first_prerelease = None;
-    let mut first_managed = None;
-    let mut first_error = None;
-    for result in installations {
-        // Iterate until the first critical error or happy result
-        if !result.as_ref().err().is_none_or(Error::is_critical) {
-            // Track the first non-critical error
-            if first_error.is_none() {
-                if let Err(err) = result {
-                    first_error = Some(err);
-                }
-            }
-            continue;
-        }
-
-        // If it's an error, we're done.
-        let Ok(Ok(ref installation)) = result else {
-            return result;
-        };
+/// Whether a discovered installation that satisfies a request should be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    /// Use the installation.
+    Use,
+    /// Skip the installation, it requires opt-in.
+    Skip,
+    /// Skip the installation since it's a pre-release, unless no stable installations are found.
+    DeferPrerelease,
+    /// Skip the installation since system installations are preferred, unless no system
+    /// installations are found.
+    DeferManaged,
+}
 
+impl Selection {
+    fn of(
+        installation: &PythonInstallation,
+        request: &PythonRequest,
+        preference: PythonPreference,
+        python_install_dir: Option<&Path>,
+    ) -> Self {
         // Check if we need to skip the interpreter because it is "not allowed", e.g., if it is a
         // pre-release version or an alternative implementation, using it requires opt-in.
 
@@ -1277,7 +1526,10 @@ pub(crate) fn find_python_installation(
         let has_default_executable_name = installation.interpreter.has_default_executable_name()
             && matches!(
                 installation.source,
-                PythonSource::SearchPath | PythonSource::SearchPathFirst
+                PythonSource::SearchPath
+                    | PythonSource::SearchPathFirst
+                    | PythonSource::PyenvShim
+                    | PythonSource::AsdfShim
             );
 
         // If it's a pre-release and pre-releases aren't allowed, skip it — but store it for later
@@ -1288,10 +1540,7 @@ pub(crate) fn find_python_installation(
             && !has_default_executable_name
         {
             debug!("Skipping pre-release installation {}", installation.key());
-            if first_prerelease.is_none() {
-                first_prerelease = Some(installation.clone());
-            }
-            continue;
+            return Self::DeferPrerelease;
         }
 
         // If it's an alternative implementation and alternative implementations aren't allowed,
@@ -1304,24 +1553,141 @@ pub(crate) fn find_python_installation(
             && !has_default_executable_name
         {
             debug!("Skipping alternative implementation {}", installation.key());
-            continue;
+            return Self::Skip;
         }
 
         // If it's a managed Python installation, and system interpreters are preferred, skip it
         // for now.
         if matches!(preference, PythonPreference::System)
-            && !is_system_interpreter(installation.source, installation.interpreter())
+            && !is_system_interpreter(
+                installation.source,
+                installation.interpreter(),
+                python_install_dir,
+            )
         {
             debug!(
                 "Skipping managed installation {}: system installation preferred",
                 installation.key()
             );
-            if first_managed.is_none() {
-                first_managed = Some(installation.clone());
+            return Self::DeferManaged;
+        }
+
+        Self::Use
+    }
+}
+
+/// Find all Python installations that satisfy the given request, in the order they would be
+/// selected by [`find_python_installation`].
+///
+/// Installations that require opt-in are excluded, and installations which are only used as a
+/// fallback, e.g., pre-releases, are ordered after the others. The same interpreter is only
+/// reported once, even if it is found via multiple sources.
+pub fn find_all_python_installations(
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
+    python_install_dir: Option<&Path>,
+    preview: Preview,
+) -> Result<Vec<PythonInstallation>, Error> {
+    let mut selected = Vec::new();
+    let mut managed = Vec::new();
+    let mut prereleases = Vec::new();
+    let cache = request.query_cache(cache);
+    for result in find_python_installations(
+        request,
+        environments,
+        preference,
+        &cache,
+        python_install_dir,
+        preview,
+    ) {
+        let installation = match result {
+            Ok(Ok(installation)) => installation,
+            Ok(Err(_)) => continue,
+            Err(err) if err.is_critical() => return Err(err),
+            Err(err) => {
+                debug!("Skipping unusable installation: {err}");
+                continue;
+            }
+        };
+        match Selection::of(&installation, request, preference, python_install_dir) {
+            Selection::Use => selected.push(installation),
+            Selection::Skip => {}
+            Selection::DeferPrerelease => prereleases.push(installation),
+            Selection::DeferManaged => managed.push(installation),
+        }
+    }
+
+    let mut seen = FxHashSet::default();
+    Ok(selected
+        .into_iter()
+        .chain(managed)
+        .chain(prereleases)
+        .filter(|installation| {
+            seen.insert(installation.interpreter().sys_executable().to_path_buf())
+        })
+        .collect())
+}
+
+/// Find a Python installation that satisfies the given request.
+///
+/// If an error is encountered while locating or inspecting a candidate installation,
+/// the error will raised instead of attempting further candidates.
+pub(crate) fn find_python_installation(
+    request: &PythonRequest,
+    environments: EnvironmentPreference,
+    preference: PythonPreference,
+    cache: &Cache,
+    python_install_dir: Option<&Path>,
+    preview: Preview,
+) -> Result<FindPythonResult, Error> {
+    let cache = request.query_cache(cache);
+    let installations = find_python_installations(
+        request,
+        environments,
+        preference,
+        &cache,
+        python_install_dir,
+        preview,
+    );
+    let mut first_prerelease = None;
+    let mut first_managed = None;
+    let mut first_error = None;
+    for result in installations {
+        // Iterate until the first critical error or happy result
+        if !result.as_ref().err().is_none_or(Error::is_critical) {
+            // Track the first non-critical error
+            if first_error.is_none() {
+                if let Err(err) = result {
+                    first_error = Some(err);
+                }
             }
             continue;
         }
 
+        // If it's an error, we're done.
+        let Ok(Ok(ref installation)) = result else {
+            return result;
+        };
+
+        match Selection::of(installation, request, preference, python_install_dir) {
+            Selection::Use => {}
+            Selection::Skip => continue,
+            Selection::DeferPrerelease => {
+                if first_prerelease.is_none() {
+                    first_prerelease = Some(installation.clone());
+                }
+                continue;
+            }
+            Selection::DeferManaged => {
+                if first_managed.is_none() {
+                    first_managed = Some(installation.clone());
+                }
+                continue;
+            }
+        }
+
         // If we didn't skip it, this is the installation to use
         return result;
     }
@@ -1374,13 +1740,21 @@ pub(crate) fn find_best_python_installation(
     environments: EnvironmentPreference,
     preference: PythonPreference,
     cache: &Cache,
+    python_install_dir: Option<&Path>,
     preview: Preview,
 ) -> Result<FindPythonResult, Error> {
     debug!("Starting Python discovery for {}", request);
 
     // First, check for an exact match (or the first available version if no Python version was provided)
     debug!("Looking for exact match for request {request}");
-    let result = find_python_installation(request, environments, preference, cache, preview);
+    let result = find_python_installation(
+        request,
+        environments,
+        preference,
+        cache,
+        python_install_dir,
+        preview,
+    );
     match result {
         Ok(Ok(installation)) => {
             warn_on_unsupported_python(installation.interpreter());
@@ -1408,7 +1782,14 @@ pub(crate) fn find_best_python_installation(
         _ => None,
     } {
         debug!("Looking for relaxed patch version {request}");
-        let result = find_python_installation(&request, environments, preference, cache, preview);
+        let result = find_python_installation(
+            &request,
+            environments,
+            preference,
+            cache,
+            python_install_dir,
+            preview,
+        );
         match result {
             Ok(Ok(installation)) => {
                 warn_on_unsupported_python(installation.interpreter());
@@ -1424,18 +1805,22 @@ pub(crate) fn find_best_python_installation(
     // If a Python version was requested but cannot be fulfilled, just take any version
     debug!("Looking for a default Python installation");
     let request = PythonRequest::Default;
-    Ok(
-        find_python_installation(&request, environments, preference, cache, preview)?.map_err(
-            |err| {
-                // Use a more general error in this case since we looked for multiple versions
-                PythonNotFound {
-                    request,
-                    python_preference: err.python_preference,
-                    environment_preference: err.environment_preference,
-                }
-            },
-        ),
-    )
+    Ok(find_python_installation(
+        &request,
+        environments,
+        preference,
+        cache,
+        python_install_dir,
+        preview,
+    )?
+    .map_err(|err| {
+        // Use a more general error in this case since we looked for multiple versions
+        PythonNotFound {
+            request,
+            python_preference: err.python_preference,
+            environment_preference: err.environment_preference,
+        }
+    }))
 }
 
 /// Display a warning if the Python version of the [`Interpreter`] is unsupported by uv.
@@ -1640,13 +2025,14 @@ impl PythonRequest {
             return request;
         }
 
+        let fs = host::file_system();
         let value_as_path = PathBuf::from(value);
         // e.g. /path/to/.venv
-        if value_as_path.is_dir() {
+        if fs.is_dir(&value_as_path) {
             return Self::Directory(value_as_path);
         }
         // e.g. /path/to/python
-        if value_as_path.is_file() {
+        if fs.is_file(&value_as_path) {
             return Self::File(value_as_path);
         }
 
@@ -1654,7 +2040,7 @@ impl PythonRequest {
         #[cfg(windows)]
         if value_as_path.extension().is_none() {
             let value_as_path = value_as_path.with_extension(EXE_SUFFIX);
-            if value_as_path.is_file() {
+            if fs.is_file(&value_as_path) {
                 return Self::File(value_as_path);
             }
         }
@@ -1667,10 +2053,10 @@ impl PythonRequest {
         if value_as_path.is_relative() {
             if let Ok(current_dir) = crate::current_dir() {
                 let relative = current_dir.join(&value_as_path);
-                if relative.is_dir() {
+                if fs.is_dir(&relative) {
                     return Self::Directory(relative);
                 }
-                if relative.is_file() {
+                if fs.is_file(&relative) {
                     return Self::File(relative);
                 }
             }
@@ -1830,6 +2216,37 @@ impl PythonRequest {
         }
     }
 
+    /// Return the [`PythonVersion`] identified by the request, if it requests a specific CPython
+    /// minor or patch version.
+    pub fn as_python_version(&self) -> Option<PythonVersion> {
+        match self {
+            Self::Version(version_request) => version_request.as_python_version(),
+            Self::ImplementationVersion(ImplementationName::CPython, version_request) => {
+                version_request.as_python_version()
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the free-threaded variant of the request.
+    ///
+    /// Returns `None` if the request does not include a version to attach the variant to, or if
+    /// it refers to a specific interpreter, e.g., by path or executable name.
+    #[must_use]
+    pub fn into_freethreaded(self) -> Option<Self> {
+        match self {
+            Self::Version(version_request) => {
+                version_request.into_freethreaded().map(Self::Version)
+            }
+            Self::ImplementationVersion(ImplementationName::CPython, version_request) => {
+                version_request.into_freethreaded().map(|version_request| {
+                    Self::ImplementationVersion(ImplementationName::CPython, version_request)
+                })
+            }
+            _ => None,
+        }
+    }
+
     /// Check if a given interpreter satisfies the interpreter request.
     pub fn satisfied(&self, interpreter: &Interpreter, cache: &Cache) -> bool {
         /// Returns `true` if the two paths refer to the same interpreter executable.
@@ -1918,7 +2335,40 @@ impl PythonRequest {
                         .implementation_name()
                         .eq_ignore_ascii_case(implementation.into())
             }
-            Self::Key(request) => request.satisfied_by_interpreter(interpreter),
+            Self::Key(request) => {
+                if request.features().is_some() && interpreter.features().is_none() {
+                    // The optional features are only reported if the probe was registered when the
+                    // interpreter was queried, so query it again with the probe.
+                    return Interpreter::query(
+                        interpreter.sys_executable(),
+                        &QueryProbe::with_features(cache),
+                    )
+                    .is_ok_and(|interpreter| request.satisfied_by_interpreter(&interpreter));
+                }
+                request.satisfied_by_interpreter(interpreter)
+            }
+        }
+    }
+
+    /// Return the optional standard library features required by the request, if any, e.g.,
+    /// `ssl` for `3.12+ssl`.
+    pub fn features(&self) -> Option<PythonFeatures> {
+        match self {
+            Self::Key(request) => request.features(),
+            _ => None,
+        }
+    }
+
+    /// Return the [`Cache`] to use when searching for interpreters that satisfy this request.
+    ///
+    /// Interpreters only report their optional standard library features if they're queried with
+    /// the [`QueryProbe::features`] probe, which is only registered if the request requires a
+    /// feature, as the probe slows down every query.
+    pub fn query_cache<'a>(&self, cache: &'a Cache) -> Cow<'a, Cache> {
+        if self.features().is_some() {
+            Cow::Owned(QueryProbe::with_features(cache))
+        } else {
+            Cow::Borrowed(cache)
         }
     }
 
@@ -1958,18 +2408,35 @@ impl PythonRequest {
     ///
     /// [`Self::parse`] should always return the same request when given the output of this method.
     pub fn to_canonical_string(&self) -> String {
-        match self {
-            Self::Any => "any".to_string(),
-            Self::Default => "default".to_string(),
-            Self::Version(version) => version.to_string(),
-            Self::Directory(path) => path.display().to_string(),
-            Self::File(path) => path.display().to_string(),
-            Self::ExecutableName(name) => name.clone(),
-            Self::Implementation(implementation) => implementation.to_string(),
-            Self::ImplementationVersion(implementation, version) => {
-                format!("{implementation}@{version}")
+        self.canonical().to_string()
+    }
+
+    /// Display the request in its canonical representation, as in [`Self::to_canonical_string`].
+    ///
+    /// Unlike the [`fmt::Display`] implementation of the request itself, which is intended for
+    /// messages, the output can be passed back to [`Self::parse`].
+    pub fn canonical(&self) -> impl fmt::Display + '_ {
+        CanonicalPythonRequest(self)
+    }
+}
+
+/// The canonical representation of a [`PythonRequest`], see [`PythonRequest::canonical`].
+struct CanonicalPythonRequest<'a>(&'a PythonRequest);
+
+impl fmt::Display for CanonicalPythonRequest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            PythonRequest::Any => f.write_str("any"),
+            PythonRequest::Default => f.write_str("default"),
+            PythonRequest::Version(version) => write!(f, "{version}"),
+            PythonRequest::Directory(path) => write!(f, "{}", path.display()),
+            PythonRequest::File(path) => write!(f, "{}", path.display()),
+            PythonRequest::ExecutableName(name) => f.write_str(name),
+            PythonRequest::Implementation(implementation) => write!(f, "{implementation}"),
+            PythonRequest::ImplementationVersion(implementation, version) => {
+                write!(f, "{implementation}@{version}")
             }
-            Self::Key(request) => request.to_string(),
+            PythonRequest::Key(request) => write!(f, "{request}"),
         }
     }
 }
@@ -1982,9 +2449,15 @@ impl PythonSource {
     /// Whether a pre-release Python installation from this source can be used without opt-in.
     pub(crate) fn allows_prereleases(self) -> bool {
         match self {
-            Self::Managed | Self::Registry | Self::MicrosoftStore => false,
+            Self::Managed
+            | Self::Registry
+            | Self::PyLauncher
+            | Self::MicrosoftStore
+            | Self::Provider(_) => false,
             Self::SearchPath
             | Self::SearchPathFirst
+            | Self::PyenvShim
+            | Self::AsdfShim
             | Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
@@ -1999,11 +2472,15 @@ impl PythonSource {
         match self {
             Self::Managed
             | Self::Registry
+            | Self::PyLauncher
             | Self::SearchPath
             // TODO(zanieb): We may want to allow this at some point, but when adding this variant
             // we want compatibility with existing behavior
             | Self::SearchPathFirst
-            | Self::MicrosoftStore => false,
+            | Self::PyenvShim
+            | Self::AsdfShim
+            | Self::MicrosoftStore
+            | Self::Provider(_) => false,
             Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ProvidedPath
@@ -2024,6 +2501,9 @@ impl PythonSource {
     /// This enables targeting the virtual environment with uv by putting its `bin/` on the `PATH`
     /// without setting `VIRTUAL_ENV` — but if there's another interpreter before it we will ignore
     /// it.
+    ///
+    /// A [`PythonSource::PyenvShim`] could be a virtual environment too, since pyenv can select
+    /// virtual environments created with `pyenv-virtualenv`, as could a [`PythonSource::AsdfShim`].
     pub(crate) fn is_maybe_virtualenv(self) -> bool {
         match self {
             Self::ProvidedPath
@@ -2032,8 +2512,15 @@ impl PythonSource {
             | Self::CondaPrefix
             | Self::BaseCondaPrefix
             | Self::ParentInterpreter
-            | Self::SearchPathFirst => true,
-            Self::Managed | Self::SearchPath | Self::Registry | Self::MicrosoftStore => false,
+            | Self::SearchPathFirst
+            | Self::PyenvShim
+            | Self::AsdfShim => true,
+            Self::Managed
+            | Self::SearchPath
+            | Self::Registry
+            | Self::PyLauncher
+            | Self::MicrosoftStore
+            | Self::Provider(_) => false,
         }
     }
 
@@ -2047,8 +2534,12 @@ impl PythonSource {
             | Self::Managed
             | Self::SearchPath
             | Self::SearchPathFirst
+            | Self::PyenvShim
+            | Self::AsdfShim
             | Self::Registry
-            | Self::MicrosoftStore => true,
+            | Self::PyLauncher
+            | Self::MicrosoftStore
+            | Self::Provider(_) => true,
             Self::ActiveEnvironment | Self::DiscoveredEnvironment => false,
         }
     }
@@ -2059,7 +2550,13 @@ impl PythonPreference {
         // If not dealing with a system interpreter source, we don't care about the preference
         if !matches!(
             source,
-            PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+            PythonSource::Managed
+                | PythonSource::SearchPath
+                | PythonSource::PyenvShim
+                | PythonSource::AsdfShim
+                | PythonSource::Registry
+                | PythonSource::PyLauncher
+                | PythonSource::Provider(_)
         ) {
             return true;
         }
@@ -2068,10 +2565,24 @@ impl PythonPreference {
             Self::OnlyManaged => matches!(source, PythonSource::Managed),
             Self::Managed | Self::System => matches!(
                 source,
-                PythonSource::Managed | PythonSource::SearchPath | PythonSource::Registry
+                PythonSource::Managed
+                    | PythonSource::SearchPath
+                    | PythonSource::PyenvShim
+                    | PythonSource::AsdfShim
+                    | PythonSource::Registry
+                    | PythonSource::PyLauncher
+                    | PythonSource::Provider(_)
             ),
             Self::OnlySystem => {
-                matches!(source, PythonSource::SearchPath | PythonSource::Registry)
+                matches!(
+                    source,
+                    PythonSource::SearchPath
+                        | PythonSource::PyenvShim
+                        | PythonSource::AsdfShim
+                        | PythonSource::Registry
+                        | PythonSource::PyLauncher
+                        | PythonSource::Provider(_)
+                )
             }
         }
     }
@@ -2420,6 +2931,75 @@ impl VersionRequest {
         }
     }
 
+    /// Return the [`PythonVersion`] identified by the request, if it's a minor or patch version.
+    ///
+    /// Used to synthesize markers for a version that is not available.
+    pub fn as_python_version(&self) -> Option<PythonVersion> {
+        match self {
+            Self::MajorMinor(major, minor, _) => {
+                PythonVersion::from_str(&format!("{major}.{minor}")).ok()
+            }
+            Self::MajorMinorPatch(major, minor, patch, _) => {
+                PythonVersion::from_str(&format!("{major}.{minor}.{patch}")).ok()
+            }
+            Self::Any
+            | Self::Default
+            | Self::Major(..)
+            | Self::MajorMinorPrerelease(..)
+            | Self::Range(..) => None,
+        }
+    }
+
+    /// Return the range of Python versions that satisfy the request, e.g., `==3.12.*` for
+    /// `3.12`, or `None` if the request doesn't include a version.
+    pub fn as_specifiers(&self) -> Option<VersionSpecifiers> {
+        let specifier = match self {
+            Self::Any | Self::Default => return None,
+            Self::Major(major, _) => {
+                VersionSpecifier::equals_star_version(Version::new([u64::from(*major)]))
+            }
+            Self::MajorMinor(major, minor, _) => {
+                VersionSpecifier::equals_star_version(Version::new([
+                    u64::from(*major),
+                    u64::from(*minor),
+                ]))
+            }
+            Self::MajorMinorPatch(major, minor, patch, _) => {
+                VersionSpecifier::equals_version(Version::new([
+                    u64::from(*major),
+                    u64::from(*minor),
+                    u64::from(*patch),
+                ]))
+            }
+            Self::MajorMinorPrerelease(major, minor, prerelease, _) => {
+                VersionSpecifier::equals_version(
+                    Version::new([u64::from(*major), u64::from(*minor), 0])
+                        .with_pre(Some(*prerelease)),
+                )
+            }
+            Self::Range(specifiers, _) => return Some(specifiers.clone()),
+        };
+        Some(VersionSpecifiers::from(specifier))
+    }
+
+    /// Return the free-threaded variant of the request, if the request includes a version.
+    #[must_use]
+    pub fn into_freethreaded(self) -> Option<Self> {
+        let variant = PythonVariant::Freethreaded;
+        match self {
+            Self::Any | Self::Default => None,
+            Self::Major(major, _) => Some(Self::Major(major, variant)),
+            Self::MajorMinor(major, minor, _) => Some(Self::MajorMinor(major, minor, variant)),
+            Self::MajorMinorPatch(major, minor, patch, _) => {
+                Some(Self::MajorMinorPatch(major, minor, patch, variant))
+            }
+            Self::MajorMinorPrerelease(major, minor, prerelease, _) => Some(
+                Self::MajorMinorPrerelease(major, minor, prerelease, variant),
+            ),
+            Self::Range(specifiers, _) => Some(Self::Range(specifiers, variant)),
+        }
+    }
+
     /// Check if the request is for a version supported by uv.
     ///
     /// If not, an `Err` is returned with an explanatory message.
@@ -2488,8 +3068,12 @@ impl VersionRequest {
                 | PythonSource::ActiveEnvironment => Self::Any,
                 PythonSource::SearchPath
                 | PythonSource::SearchPathFirst
+                | PythonSource::PyenvShim
+                | PythonSource::AsdfShim
                 | PythonSource::Registry
+                | PythonSource::PyLauncher
                 | PythonSource::MicrosoftStore
+                | PythonSource::Provider(_)
                 | PythonSource::Managed => Self::Default,
             },
             _ => self,
@@ -2540,7 +3124,7 @@ impl VersionRequest {
     ///
     /// WARNING: Use [`VersionRequest::matches_interpreter`] too. This method is only suitable to
     /// avoid querying interpreters if it's clear it cannot fulfill the request.
-    pub(crate) fn matches_version(&self, version: &PythonVersion) -> bool {
+    pub fn matches_version(&self, version: &PythonVersion) -> bool {
         match self {
             Self::Any | Self::Default => true,
             Self::Major(major, _) => version.major() == *major,
@@ -2647,7 +3231,7 @@ impl VersionRequest {
     ///
     /// If the patch version is not present, the request is returned unchanged.
     #[must_use]
-    fn without_patch(self) -> Self {
+    pub(crate) fn without_patch(self) -> Self {
         match self {
             Self::Default => Self::Default,
             Self::Any => Self::Any,
@@ -2888,7 +3472,8 @@ impl fmt::Display for VersionRequest {
             Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
                 write!(f, "{major}.{minor}{prerelease}t")
             }
-            Self::Range(specifiers, _) => write!(f, "{specifiers}"),
+            Self::Range(specifiers, PythonVariant::Default) => write!(f, "{specifiers}"),
+            Self::Range(specifiers, PythonVariant::Freethreaded) => write!(f, "{specifiers}t"),
         }
     }
 }
@@ -2922,10 +3507,14 @@ impl fmt::Display for PythonSource {
             Self::DiscoveredEnvironment => f.write_str("virtual environment"),
             Self::SearchPath => f.write_str("search path"),
             Self::SearchPathFirst => f.write_str("first executable in the search path"),
+            Self::PyenvShim => f.write_str("pyenv shim"),
+            Self::AsdfShim => f.write_str("asdf shim"),
             Self::Registry => f.write_str("registry"),
+            Self::PyLauncher => f.write_str("`py` launcher"),
             Self::MicrosoftStore => f.write_str("Microsoft Store"),
             Self::Managed => f.write_str("managed installations"),
             Self::ParentInterpreter => f.write_str("parent interpreter"),
+            Self::Provider(name) => write!(f, "provider `{name}`"),
         }
     }
 }
@@ -3108,13 +3697,102 @@ mod tests {
     use uv_pep440::{Prerelease, PrereleaseKind, VersionSpecifiers};
 
     use crate::{
+        components::{PythonComponent, PythonComponents},
         discovery::{PythonRequest, VersionRequest},
         downloads::{ArchRequest, PythonDownloadRequest},
+        features::{PythonFeature, PythonFeatures},
         implementation::ImplementationName,
     };
     use uv_platform::{Arch, Libc, Os};
 
-    use super::{Error, PythonVariant};
+    use super::{Error, PythonVariant, find_all_minor};
+
+    /// An in-memory filesystem, for testing discovery without creating interpreters on disk.
+    #[derive(Default)]
+    struct MemoryFileSystem {
+        dirs: Vec<PathBuf>,
+        files: Vec<PathBuf>,
+        executables: Vec<PathBuf>,
+    }
+
+    impl crate::host::FileSystem for MemoryFileSystem {
+        fn is_dir(&self, path: &std::path::Path) -> bool {
+            self.dirs.iter().any(|dir| dir == path)
+        }
+
+        fn is_file(&self, path: &std::path::Path) -> bool {
+            self.files
+                .iter()
+                .chain(&self.executables)
+                .any(|file| file == path)
+        }
+
+        fn is_executable(&self, path: &std::path::Path) -> bool {
+            self.executables.iter().any(|file| file == path)
+        }
+
+        fn read_dir(&self, path: &std::path::Path) -> std::io::Result<Vec<PathBuf>> {
+            Ok(self
+                .files
+                .iter()
+                .chain(&self.executables)
+                .filter(|file| file.parent() == Some(path))
+                .cloned()
+                .collect())
+        }
+
+        fn read_to_string(&self, path: &std::path::Path) -> std::io::Result<String> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                path.display().to_string(),
+            ))
+        }
+
+        fn find_executables(&self, dir: &std::path::Path, name: &str) -> Vec<PathBuf> {
+            let path = dir.join(name);
+            if self.is_executable(&path) {
+                vec![path]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    #[test]
+    fn interpreter_request_from_str_mocked_file_system() {
+        let fs = MemoryFileSystem {
+            dirs: vec![PathBuf::from("/mock/.venv")],
+            executables: vec![PathBuf::from("/mock/bin/python")],
+            ..MemoryFileSystem::default()
+        };
+        crate::host::with_file_system(fs, || {
+            assert_eq!(
+                PythonRequest::parse("/mock/.venv"),
+                PythonRequest::Directory(PathBuf::from("/mock/.venv"))
+            );
+            assert_eq!(
+                PythonRequest::parse("/mock/bin/python"),
+                PythonRequest::File(PathBuf::from("/mock/bin/python"))
+            );
+        });
+    }
+
+    #[test]
+    fn find_all_minor_mocked_file_system() {
+        let dir = PathBuf::from("/mock/bin");
+        let exe = |name: &str| dir.join(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+        let fs = MemoryFileSystem {
+            dirs: vec![dir.clone()],
+            // Not executable, so it's skipped.
+            files: vec![exe("python3.13")],
+            // Python 3.6 is unsupported, so it's skipped without querying.
+            executables: vec![exe("python3.12"), exe("python3.6"), exe("python3")],
+        };
+        let found = crate::host::with_file_system(fs, || {
+            find_all_minor(None, &VersionRequest::Any, &dir).collect::<Vec<_>>()
+        });
+        assert_eq!(found, vec![exe("python3.12")]);
+    }
 
     #[test]
     fn interpreter_request_from_str() {
@@ -3186,7 +3864,10 @@ mod tests {
                 arch: None,
                 os: None,
                 libc: None,
-                prereleases: None
+                prereleases: None,
+                channel: None,
+                components: None,
+                features: None
             })
         );
         assert_eq!(
@@ -3205,7 +3886,10 @@ mod tests {
                 ))),
                 os: Some(Os::new(target_lexicon::OperatingSystem::Darwin(None))),
                 libc: Some(Libc::None),
-                prereleases: None
+                prereleases: None,
+                channel: None,
+                components: None,
+                features: None
             })
         );
         assert_eq!(
@@ -3221,7 +3905,41 @@ mod tests {
                 arch: None,
                 os: None,
                 libc: None,
-                prereleases: None
+                prereleases: None,
+                channel: None,
+                components: None,
+                features: None
+            })
+        );
+        assert_eq!(
+            PythonRequest::parse("3.12+tk"),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: Some(VersionRequest::MajorMinor(3, 12, PythonVariant::Default)),
+                implementation: None,
+                arch: None,
+                os: None,
+                libc: None,
+                prereleases: None,
+                channel: None,
+                components: Some(PythonComponents::only([PythonComponent::Tk])),
+                features: None
+            })
+        );
+        assert_eq!(
+            PythonRequest::parse("3.12+ssl+tk+sqlite3"),
+            PythonRequest::Key(PythonDownloadRequest {
+                version: Some(VersionRequest::MajorMinor(3, 12, PythonVariant::Default)),
+                implementation: None,
+                arch: None,
+                os: None,
+                libc: None,
+                prereleases: None,
+                channel: None,
+                components: Some(PythonComponents::only([PythonComponent::Tk])),
+                features: Some(PythonFeatures::only([
+                    PythonFeature::Ssl,
+                    PythonFeature::Sqlite3
+                ]))
             })
         );
         assert_eq!(
@@ -3240,7 +3958,10 @@ mod tests {
                 ))),
                 os: None,
                 libc: None,
-                prereleases: None
+                prereleases: None,
+                channel: None,
+                components: None,
+                features: None
             })
         );
 
@@ -3464,6 +4185,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn interpreter_request_into_freethreaded() {
+        assert_eq!(
+            PythonRequest::parse("3.13").into_freethreaded(),
+            Some(PythonRequest::parse("3.13t"))
+        );
+        assert_eq!(
+            PythonRequest::parse("cpython@3.13.1").into_freethreaded(),
+            Some(PythonRequest::parse("cpython@3.13.1t"))
+        );
+        assert_eq!(
+            PythonRequest::parse(">=3.13").into_freethreaded(),
+            Some(PythonRequest::Version(VersionRequest::Range(
+                VersionSpecifiers::from_str(">=3.13").unwrap(),
+                PythonVariant::Freethreaded
+            )))
+        );
+        assert_eq!(PythonRequest::Default.into_freethreaded(), None);
+        assert_eq!(PythonRequest::parse("pypy@3.10").into_freethreaded(), None);
+        assert_eq!(PythonRequest::parse("./foo").into_freethreaded(), None);
+    }
+
     #[test]
     fn version_request_from_str() {
         assert_eq!(
@@ -0,0 +1,180 @@
+//! User-configured filters that exclude Python executables from discovery.
+//!
+//! The filter is configured via the cache's [`InterpreterSettings`] and consulted when enumerating
+//! the search path and the Windows registry, before any interpreter is queried.
+
+use std::path::Path;
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use thiserror::Error;
+use tracing::debug;
+
+use uv_cache::InterpreterSettings;
+
+#[derive(Debug, Error)]
+#[error("Invalid pattern in `python-discovery.{setting}`: `{pattern}`")]
+pub struct Error {
+    setting: &'static str,
+    pattern: String,
+    #[source]
+    err: globset::Error,
+}
+
+/// A set of glob patterns that determine which Python executables may be discovered.
+#[derive(Debug, Clone, Default)]
+pub struct PythonDiscoveryFilter {
+    /// Executables matching any of these patterns are never discovered.
+    exclude: Option<GlobSet>,
+    /// If set, only executables matching any of these patterns are discovered.
+    include_only: Option<GlobSet>,
+}
+
+impl PythonDiscoveryFilter {
+    /// Create a [`PythonDiscoveryFilter`] from the `exclude` and `include-only` patterns.
+    ///
+    /// A leading `~` in a pattern is expanded to the user's home directory.
+    pub fn from_patterns(
+        exclude: &[String],
+        include_only: Option<&[String]>,
+    ) -> Result<Self, Error> {
+        let exclude = if exclude.is_empty() {
+            None
+        } else {
+            Some(glob_set(exclude, "exclude")?)
+        };
+        let include_only = include_only
+            .filter(|patterns| !patterns.is_empty())
+            .map(|patterns| glob_set(patterns, "include-only"))
+            .transpose()?;
+        Ok(Self {
+            exclude,
+            include_only,
+        })
+    }
+
+    /// Create a [`PythonDiscoveryFilter`] from the patterns in the [`InterpreterSettings`].
+    pub fn from_settings(settings: &InterpreterSettings) -> Result<Self, Error> {
+        Self::from_patterns(
+            settings.discovery_exclude(),
+            settings.discovery_include_only(),
+        )
+    }
+
+    /// Returns `true` if the filter does not exclude any executables.
+    pub fn is_empty(&self) -> bool {
+        self.exclude.is_none() && self.include_only.is_none()
+    }
+
+    /// Returns `true` if the Python executable at `path` may be discovered.
+    pub fn allows(&self, path: &Path) -> bool {
+        if self
+            .exclude
+            .as_ref()
+            .is_some_and(|exclude| exclude.is_match(path))
+        {
+            debug!(
+                "Skipping Python executable excluded by `python-discovery.exclude`: {}",
+                path.display()
+            );
+            return false;
+        }
+        if self
+            .include_only
+            .as_ref()
+            .is_some_and(|include_only| !include_only.is_match(path))
+        {
+            debug!(
+                "Skipping Python executable not matched by `python-discovery.include-only`: {}",
+                path.display()
+            );
+            return false;
+        }
+        true
+    }
+}
+
+/// Compile a set of patterns, expanding a leading `~` to the user's home directory.
+fn glob_set(patterns: &[String], setting: &'static str) -> Result<GlobSet, Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = compile(&expand_home(pattern)).map_err(|err| Error {
+            setting,
+            pattern: pattern.clone(),
+            err,
+        })?;
+        builder.add(glob);
+    }
+    builder.build().map_err(|err| Error {
+        setting,
+        pattern: patterns.join(", "),
+        err,
+    })
+}
+
+fn compile(pattern: &str) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern)
+        // Require `**` to match across directories, e.g., `/opt/*/python3` should not match
+        // `/opt/a/bin/python3`.
+        .literal_separator(true)
+        .case_insensitive(cfg!(windows))
+        .build()
+}
+
+fn expand_home(pattern: &str) -> String {
+    let Some(rest) = pattern
+        .strip_prefix("~/")
+        .or_else(|| pattern.strip_prefix("~\\"))
+        .or_else(|| (pattern == "~").then_some(""))
+    else {
+        return pattern.to_string();
+    };
+    let Ok(home) = etcetera::home_dir() else {
+        return pattern.to_string();
+    };
+    home.join(rest).to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::PythonDiscoveryFilter;
+
+    #[test]
+    fn exclude() {
+        let filter = PythonDiscoveryFilter::from_patterns(
+            &[
+                "/usr/bin/python3".to_string(),
+                "/opt/*/bin/python*".to_string(),
+            ],
+            None,
+        )
+        .unwrap();
+        assert!(!filter.allows(Path::new("/usr/bin/python3")));
+        assert!(!filter.allows(Path::new("/opt/corp/bin/python3.12")));
+        assert!(filter.allows(Path::new("/usr/bin/python3.12")));
+        assert!(filter.allows(Path::new("/opt/corp/nested/bin/python3")));
+    }
+
+    #[test]
+    fn include_only() {
+        let filter = PythonDiscoveryFilter::from_patterns(
+            &["/usr/local/bin/python3.8".to_string()],
+            Some(&["/usr/local/bin/*".to_string()]),
+        )
+        .unwrap();
+        assert!(filter.allows(Path::new("/usr/local/bin/python3")));
+        assert!(!filter.allows(Path::new("/usr/local/bin/python3.8")));
+        assert!(!filter.allows(Path::new("/usr/bin/python3")));
+    }
+
+    #[test]
+    fn invalid() {
+        let err = PythonDiscoveryFilter::from_patterns(&["/usr/bin/[python".to_string()], None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid pattern in `python-discovery.exclude`: `/usr/bin/[python`"
+        );
+    }
+}
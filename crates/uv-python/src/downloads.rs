@@ -1,11 +1,15 @@
 use std::borrow::Cow;
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::future::Future;
+use std::num::NonZeroU64;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::{Mutex, PoisonError};
 use std::task::{Context, Poll};
-use std::time::{Duration, SystemTime};
+use std::time::Duration;
 use std::{env, io};
 
 use futures::TryStreamExt;
@@ -18,25 +22,30 @@ use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncWriteExt, BufWriter, ReadBuf};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tokio_util::either::Either;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 use url::Url;
 
-use uv_client::{BaseClient, WrappedReqwestError, is_extended_transient_error};
+use uv_client::{BaseClient, BaseClientBuilder, WrappedReqwestError, is_extended_transient_error};
+use uv_configuration::{KeyringProviderType, TrustedHost};
 use uv_distribution_filename::{ExtensionError, SourceDistExtension};
 use uv_extract::hash::Hasher;
-use uv_fs::{Simplified, rename_with_retry};
+use uv_fs::{Simplified, long_path, rename_with_retry};
+use uv_pep440::{Prerelease, PrereleaseKind};
 use uv_platform::{self as platform, Arch, Libc, Os, Platform};
 use uv_pypi_types::{HashAlgorithm, HashDigest};
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 
 use crate::PythonVariant;
+use crate::components::{PythonComponent, PythonComponents};
+use crate::features::{PythonFeature, PythonFeatures};
+use crate::host;
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
 use crate::installation::PythonInstallationKey;
-use crate::managed::ManagedPythonInstallation;
-use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
+use crate::managed::{ManagedPythonInstallation, dedupe_installation, find_sibling_installation};
+use crate::{Interpreter, PythonRequest, PythonResolution, PythonVersion, VersionRequest};
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -86,6 +95,8 @@ pub enum Error {
         #[source]
         err: io::Error,
     },
+    #[error("Failed to verify copy of Python installation from {} to {}", from.user_display(), to.user_display())]
+    CopyVerification { from: PathBuf, to: PathBuf },
     #[error("Failed to read managed Python installation directory: {0}", dir.user_display())]
     ReadError {
         dir: PathBuf,
@@ -110,6 +121,13 @@ pub enum Error {
         url: Box<Url>,
         python_builds_dir: PathBuf,
     },
+    #[error("The cached archive for {file} at {} does not match the expected checksum\n\nExpected:\n{expected}\n\nComputed:\n{actual}", path.user_display())]
+    CachedArchiveHashMismatch {
+        file: Box<PythonInstallationKey>,
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
 }
 
 impl Error {
@@ -157,6 +175,75 @@ pub struct PythonDownloadRequest {
     /// Whether to allow pre-releases or not. If not set, defaults to true if [`Self::version`] is
     /// not None, and false otherwise.
     pub(crate) prereleases: Option<bool>,
+
+    /// The release channel to select downloads from, if any.
+    ///
+    /// If set, pre-releases are only selected if they are included in the channel, and there is no
+    /// implicit fallback to pre-releases when no stable release is available.
+    pub(crate) channel: Option<PythonChannel>,
+
+    /// The optional components that must be included in the installation, if any.
+    ///
+    /// If not set, any installation matches, and downloads are installed with all components.
+    pub(crate) components: Option<PythonComponents>,
+
+    /// The optional standard library features that the interpreter must include, if any.
+    pub(crate) features: Option<PythonFeatures>,
+}
+
+/// A release channel for managed Python downloads.
+///
+/// Each channel includes the releases of the more stable channels, e.g., the `beta` channel
+/// includes beta releases, release candidates, and stable releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum PythonChannel {
+    /// Include alpha releases and all more stable releases.
+    Alpha,
+    /// Include beta releases, release candidates, and stable releases.
+    Beta,
+    /// Include release candidates and stable releases.
+    Rc,
+    /// Only include stable releases.
+    Stable,
+}
+
+impl PythonChannel {
+    /// Whether a release with the given pre-release tag is included in the channel.
+    pub fn includes(self, prerelease: Option<Prerelease>) -> bool {
+        let channel = match prerelease.map(|prerelease| prerelease.kind) {
+            None => Self::Stable,
+            Some(PrereleaseKind::Alpha) => Self::Alpha,
+            Some(PrereleaseKind::Beta) => Self::Beta,
+            Some(PrereleaseKind::Rc) => Self::Rc,
+        };
+        channel >= self
+    }
+}
+
+impl FromStr for PythonChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alpha" => Ok(Self::Alpha),
+            "beta" => Ok(Self::Beta),
+            "rc" => Ok(Self::Rc),
+            "stable" => Ok(Self::Stable),
+            _ => Err(format!("Unknown release channel: `{s}`")),
+        }
+    }
+}
+
+impl Display for PythonChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alpha => f.write_str("alpha"),
+            Self::Beta => f.write_str("beta"),
+            Self::Rc => f.write_str("rc"),
+            Self::Stable => f.write_str("stable"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -256,6 +343,9 @@ impl PythonDownloadRequest {
             os,
             libc,
             prereleases,
+            channel: None,
+            components: None,
+            features: None,
         }
     }
 
@@ -311,6 +401,24 @@ impl PythonDownloadRequest {
         self
     }
 
+    #[must_use]
+    pub fn with_channel(mut self, channel: PythonChannel) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    #[must_use]
+    pub fn with_components(mut self, components: PythonComponents) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    #[must_use]
+    pub fn with_features(mut self, features: PythonFeatures) -> Self {
+        self.features = Some(features);
+        self
+    }
+
     /// Construct a new [`PythonDownloadRequest`] from a [`PythonRequest`] if possible.
     ///
     /// Returns [`None`] if the request kind is not compatible with a download, e.g., it is
@@ -384,6 +492,18 @@ impl PythonDownloadRequest {
         self.libc.as_ref()
     }
 
+    pub fn channel(&self) -> Option<PythonChannel> {
+        self.channel
+    }
+
+    pub fn components(&self) -> Option<PythonComponents> {
+        self.components
+    }
+
+    pub fn features(&self) -> Option<PythonFeatures> {
+        self.features
+    }
+
     /// Iterate over all [`PythonDownload`]'s that match this request.
     pub fn iter_downloads<'a>(
         &'a self,
@@ -414,6 +534,12 @@ impl PythonDownloadRequest {
         if !self.allows_prereleases() && key.prerelease.is_some() {
             return false;
         }
+        // If a channel was requested, only match releases in the channel
+        if let Some(channel) = self.channel {
+            if !channel.includes(key.prerelease) {
+                return false;
+            }
+        }
         if let Some(version) = &self.version {
             if !version.matches_major_minor_patch_prerelease(
                 key.major,
@@ -429,6 +555,20 @@ impl PythonDownloadRequest {
                 }
             }
         }
+        if let Some(components) = self.components {
+            if !key.components.is_superset(components) {
+                return false;
+            }
+        }
+        // Managed installations include all of the features, but `tkinter` is only included
+        // with the Tcl/Tk component.
+        if let Some(features) = self.features {
+            if features.contains(PythonFeature::Tkinter)
+                && !key.components.contains(PythonComponent::Tk)
+            {
+                return false;
+            }
+        }
         true
     }
 
@@ -439,6 +579,9 @@ impl PythonDownloadRequest {
 
     /// Whether this download request opts-in to pre-release Python versions.
     pub fn allows_prereleases(&self) -> bool {
+        if let Some(channel) = self.channel {
+            return channel != PythonChannel::Stable;
+        }
         self.prereleases.unwrap_or_else(|| {
             self.version
                 .as_ref()
@@ -465,8 +608,22 @@ impl PythonDownloadRequest {
             }
         }
         let platform = self.platform();
-        let interpreter_platform = Platform::from(interpreter.platform());
-        if !platform.matches(&interpreter_platform) {
+        let mut interpreter_platform = Platform::from(interpreter.platform());
+        // Prefer the libc encoded in the interpreter's ABI, which is more precise than the one
+        // derived from its platform tags, e.g., it distinguishes `gnueabi` from `gnueabihf`.
+        if let Some(libc) = interpreter.abi_libc() {
+            interpreter_platform.libc = libc;
+        }
+        // A macOS universal binary satisfies the request if any of its slices does.
+        if !platform.matches(&interpreter_platform)
+            && !interpreter.architectures().into_iter().any(|arch| {
+                platform.matches(&Platform::new(
+                    interpreter_platform.os,
+                    arch,
+                    interpreter_platform.libc,
+                ))
+            })
+        {
             debug!(
                 "Skipping interpreter at `{executable}`: platform `{interpreter_platform}` does not match request `{platform}`",
             );
@@ -481,9 +638,39 @@ impl PythonDownloadRequest {
                 return false;
             }
         }
+        if let Some(components) = self.components {
+            if !components.satisfied_by_interpreter(interpreter) {
+                debug!(
+                    "Skipping interpreter at `{executable}`: missing components requested by `+{components}`"
+                );
+                return false;
+            }
+        }
+        if let Some(features) = self.features {
+            let missing = features.difference(interpreter.features().unwrap_or_default());
+            if !missing.is_empty() {
+                debug!(
+                    "Skipping interpreter at `{executable}`: missing features requested by `+{features}`: {}",
+                    missing.iter().join(", ")
+                );
+                return false;
+            }
+        }
         true
     }
 
+    /// Select the slice of a macOS universal interpreter matching an explicitly requested
+    /// architecture, e.g., the `x86_64` slice for `cpython-3.12-macos-x86_64` on Apple Silicon.
+    ///
+    /// Other interpreters are returned unchanged.
+    #[must_use]
+    pub fn select_arch_slice(&self, interpreter: Interpreter) -> Interpreter {
+        match self.arch {
+            Some(ArchRequest::Explicit(arch)) => interpreter.with_arch_slice(arch),
+            _ => interpreter,
+        }
+    }
+
     /// Extract the platform components of this request.
     pub fn platform(&self) -> PlatformRequest {
         PlatformRequest {
@@ -497,7 +684,7 @@ impl PythonDownloadRequest {
 impl From<&ManagedPythonInstallation> for PythonDownloadRequest {
     fn from(installation: &ManagedPythonInstallation) -> Self {
         let key = installation.key();
-        Self::new(
+        let request = Self::new(
             Some(VersionRequest::from(&key.version())),
             match &key.implementation {
                 LenientImplementationName::Known(implementation) => Some(*implementation),
@@ -509,7 +696,12 @@ impl From<&ManagedPythonInstallation> for PythonDownloadRequest {
             Some(*key.os()),
             Some(*key.libc()),
             Some(key.prerelease.is_some()),
-        )
+        );
+        if key.components.is_all() {
+            request
+        } else {
+            request.with_components(key.components)
+        }
     }
 }
 
@@ -521,10 +713,16 @@ impl Display for PythonDownloadRequest {
         } else {
             parts.push("any".to_string());
         }
+        let components = self
+            .components
+            .map(|components| format!("+{components}"))
+            .into_iter()
+            .chain(self.features.map(|features| format!("+{features}")))
+            .collect::<String>();
         if let Some(version) = &self.version {
-            parts.push(version.to_string());
+            parts.push(format!("{version}{components}"));
         } else {
-            parts.push("any".to_string());
+            parts.push(format!("any{components}"));
         }
         if let Some(os) = &self.os {
             parts.push(os.to_string());
@@ -619,6 +817,8 @@ impl FromStr for PythonDownloadRequest {
 
         let mut implementation = None;
         let mut version = None;
+        let mut components = None;
+        let mut features = None;
         let mut os = None;
         let mut arch = None;
         let mut libc = None;
@@ -647,7 +847,10 @@ impl FromStr for PythonDownloadRequest {
                     }
                 }
                 Position::Version => {
+                    let (part, part_components, part_features) = split_version_suffixes(part);
                     if part.eq_ignore_ascii_case("any") {
+                        components = part_components;
+                        features = part_features;
                         state.next_part();
                         continue;
                     }
@@ -657,6 +860,8 @@ impl FromStr for PythonDownloadRequest {
                         // Err(err) if !first_part => return Err(err),
                         Ok(val) => {
                             version = Some(val);
+                            components = part_components;
+                            features = part_features;
                             state.next_part();
                         }
                         Err(err) => {
@@ -732,8 +937,66 @@ impl FromStr for PythonDownloadRequest {
             }
         }
 
-        Ok(Self::new(version, implementation, arch, os, libc, None))
+        let mut request = Self::new(version, implementation, arch, os, libc, None);
+        request.components = components;
+        request.features = features;
+        Ok(request)
+    }
+}
+
+/// Split trailing components and features from a version, e.g., `3.12+tk+ssl` into `3.12`, `tk`,
+/// and `ssl`.
+///
+/// Other suffixes, e.g., `+freethreaded`, are left on the version.
+fn split_version_suffixes(
+    version: &str,
+) -> (&str, Option<PythonComponents>, Option<PythonFeatures>) {
+    let mut rest = version;
+    let mut components = Vec::new();
+    let mut features = Vec::new();
+    while let Some((prefix, suffix)) = rest.rsplit_once('+') {
+        if let Ok(component) = PythonComponent::from_str(suffix) {
+            components.push(component);
+        } else if let Ok(feature) = PythonFeature::from_str(suffix) {
+            features.push(feature);
+        } else {
+            break;
+        }
+        rest = prefix;
     }
+    (
+        rest,
+        (!components.is_empty()).then(|| PythonComponents::only(components)),
+        (!features.is_empty()).then(|| PythonFeatures::only(features)),
+    )
+}
+
+/// Return the directory in which downloaded Python archives are cached, if any.
+///
+/// Archives are only cached when `UV_PYTHON_CACHE_DIR` is set.
+pub fn python_cache_dir() -> Option<PathBuf> {
+    env::var_os(EnvVars::UV_PYTHON_CACHE_DIR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Return the filename of the archive at the given download URL.
+fn archive_filename(url: &Url) -> Result<String, Error> {
+    // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
+    // decodes to.
+    let filename = url
+        .path_segments()
+        .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+        .next_back()
+        .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
+        .replace("%2B", "-");
+    debug_assert!(
+        filename
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'),
+        "Unexpected char in filename: {filename}"
+    );
+    Ok(filename)
 }
 
 const BUILTIN_PYTHON_DOWNLOADS_JSON: &str = include_str!("download-metadata-minified.json");
@@ -767,6 +1030,20 @@ pub enum DownloadResult {
     Fetched(PathBuf),
 }
 
+/// The state of a Python distribution at its source, as determined by
+/// [`ManagedPythonDownload::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DownloadValidation {
+    /// The archive exists, with the given size, if it was reported.
+    Available { size: Option<u64> },
+    /// The archive was read in full and matches its expected checksum.
+    Verified { size: u64 },
+    /// The archive does not exist.
+    Missing,
+    /// The archive was read in full and does not match its expected checksum.
+    Corrupted { expected: String, actual: String },
+}
+
 impl ManagedPythonDownload {
     /// Return the first [`ManagedPythonDownload`] matching a request, if any.
     ///
@@ -780,7 +1057,9 @@ impl ManagedPythonDownload {
             return Ok(download);
         }
 
-        if !request.allows_prereleases() {
+        // If no channel was requested, fall back to pre-releases when no stable release is
+        // available
+        if !request.allows_prereleases() && request.channel.is_none() {
             if let Some(download) = request
                 .clone()
                 .with_prereleases(true)
@@ -793,6 +1072,70 @@ impl ManagedPythonDownload {
 
         Err(Error::NoDownloadFound(request.clone()))
     }
+
+    /// Return the [`ManagedPythonDownload`] that would be fetched for a request under the given
+    /// [`PythonResolution`].
+    ///
+    /// The resolution policy only applies to version ranges, e.g., `requires-python`; for other
+    /// requests, the lowest compatible version is rarely the one the user wants.
+    pub fn from_request_with_resolution(
+        request: &PythonDownloadRequest,
+        python_resolution: PythonResolution,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<&'static Self, Error> {
+        match (python_resolution, request.version()) {
+            (PythonResolution::LowestCompatible, Some(VersionRequest::Range(..))) => {
+                debug!("Selecting the lowest compatible Python download for {request}");
+                Self::from_request_lowest(request, python_downloads_json_url)
+            }
+            _ => Self::from_request(request, python_downloads_json_url),
+        }
+    }
+
+    /// Return the [`ManagedPythonDownload`] with the lowest minor version matching a request, if
+    /// any, preferring the latest patch release of that minor version.
+    ///
+    /// As in [`ManagedPythonDownload::from_request`], pre-release versions are only considered if
+    /// there is no stable version matching the request.
+    pub fn from_request_lowest(
+        request: &PythonDownloadRequest,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<&'static Self, Error> {
+        if let Some(download) = Self::lowest(request.iter_downloads(python_downloads_json_url)?) {
+            return Ok(download);
+        }
+
+        if !request.allows_prereleases() && request.channel.is_none() {
+            if let Some(download) = Self::lowest(
+                request
+                    .clone()
+                    .with_prereleases(true)
+                    .iter_downloads(python_downloads_json_url)?,
+            ) {
+                return Ok(download);
+            }
+        }
+
+        Err(Error::NoDownloadFound(request.clone()))
+    }
+
+    /// Select the download with the lowest minor version and the highest patch version from a set
+    /// of candidates, considering only the implementation of the first (i.e., preferred)
+    /// candidate.
+    fn lowest(mut downloads: impl Iterator<Item = &'static Self>) -> Option<&'static Self> {
+        let first = downloads.next()?;
+        let implementation = first.key().implementation.clone();
+        std::iter::once(first)
+            .chain(downloads.filter(|download| download.key().implementation == implementation))
+            .min_by_key(|download| {
+                let key = download.key();
+                (
+                    key.major,
+                    key.minor,
+                    Reverse((key.patch, key.prerelease.is_none())),
+                )
+            })
+    }
     //noinspection RsUnresolvedPath - RustRover can't see through the `include!`
 
     /// Iterate over all [`ManagedPythonDownload`]s.
@@ -844,6 +1187,17 @@ impl ManagedPythonDownload {
         &self.key
     }
 
+    /// Return the download with only the given optional components, which are removed from the
+    /// installation after it is extracted.
+    #[must_use]
+    pub fn with_components(&self, components: PythonComponents) -> Self {
+        Self {
+            key: self.key.clone().with_components(components),
+            url: self.url.clone(),
+            sha256: self.sha256.clone(),
+        }
+    }
+
     pub fn os(&self) -> &Os {
         self.key.os()
     }
@@ -852,6 +1206,76 @@ impl ManagedPythonDownload {
         self.sha256.as_ref()
     }
 
+    /// Return the size of the distribution archive, without downloading it.
+    ///
+    /// If the archive is already in the cache, its size is read from disk; otherwise, the size is
+    /// requested from the server, if it's reported. Returns `None` if the size is unknown, e.g.,
+    /// when offline.
+    pub async fn archive_size(
+        &self,
+        client: &BaseClient,
+        python_install_mirror: Option<&str>,
+        pypy_install_mirror: Option<&str>,
+    ) -> Result<Option<u64>, Error> {
+        let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
+
+        if let Some(python_builds_dir) = python_cache_dir() {
+            let filename = archive_filename(&url)?;
+            let target_cache_file = self.cache_file(&python_builds_dir, &filename);
+            match fs_err::tokio::metadata(&target_cache_file).await {
+                Ok(metadata) => return Ok(Some(metadata.len())),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        if url.scheme() != "file" && client.connectivity().is_offline() {
+            return Ok(None);
+        }
+        read_url_size(&url, client).await
+    }
+
+    /// Check that the archive for this distribution exists at its source, without installing it.
+    ///
+    /// The archive is requested with a `HEAD` request (or a single-byte range request, if the
+    /// server rejects `HEAD` requests). With `full`, the archive is read in its entirety, without
+    /// being written to disk, and compared against its expected checksum, if any.
+    pub async fn validate(
+        &self,
+        client: &BaseClient,
+        python_install_mirror: Option<&str>,
+        pypy_install_mirror: Option<&str>,
+        full: bool,
+    ) -> Result<DownloadValidation, Error> {
+        let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
+
+        let Some(size) = probe_url(&url, client).await? else {
+            return Ok(DownloadValidation::Missing);
+        };
+        if !full {
+            return Ok(DownloadValidation::Available { size });
+        }
+
+        let (reader, _) = read_url(&url, client).await?;
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
+        let size = tokio::io::copy(&mut hasher, &mut tokio::io::sink()).await?;
+        hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+        let Some(expected) = self.sha256.as_deref() else {
+            return Ok(DownloadValidation::Available { size: Some(size) });
+        };
+        let actual = HashDigest::from(hashers.pop().unwrap()).digest;
+        if actual.eq_ignore_ascii_case(expected) {
+            Ok(DownloadValidation::Verified { size })
+        } else {
+            Ok(DownloadValidation::Corrupted {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            })
+        }
+    }
+
     /// Download and extract a Python distribution, retrying on failure.
     #[instrument(skip(client, installation_dir, scratch_dir, reporter), fields(download = % self.key()))]
     pub async fn fetch_with_retry(
@@ -862,11 +1286,13 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        rate_limit: Option<&DownloadRateLimit>,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let mut total_attempts = 0;
         let mut retried_here = false;
-        let start_time = SystemTime::now();
+        let clock = host::clock();
+        let start_time = clock.now();
         let retry_policy = client.retry_policy();
         loop {
             let result = self
@@ -877,6 +1303,7 @@ impl ManagedPythonDownload {
                     reinstall,
                     python_install_mirror,
                     pypy_install_mirror,
+                    rate_limit,
                     reporter,
                 )
                 .await;
@@ -898,7 +1325,7 @@ impl ManagedPythonDownload {
                                 self.key()
                             );
                             let duration = execute_after
-                                .duration_since(SystemTime::now())
+                                .duration_since(clock.now())
                                 .unwrap_or_else(|_| Duration::default());
                             tokio::time::sleep(duration).await;
                             retried_here = true;
@@ -929,6 +1356,7 @@ impl ManagedPythonDownload {
         reinstall: bool,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        rate_limit: Option<&DownloadRateLimit>,
         reporter: Option<&dyn Reporter>,
     ) -> Result<DownloadResult, Error> {
         let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
@@ -939,38 +1367,19 @@ impl ManagedPythonDownload {
             return Ok(DownloadResult::AlreadyAvailable(path));
         }
 
-        // We improve filesystem compatibility by using neither the URL-encoded `%2B` nor the `+` it
-        // decodes to.
-        let filename = url
-            .path_segments()
-            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
-            .next_back()
-            .ok_or_else(|| Error::InvalidUrlFormat(url.clone()))?
-            .replace("%2B", "-");
-        debug_assert!(
-            filename
-                .chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'),
-            "Unexpected char in filename: {filename}"
-        );
+        let filename = archive_filename(&url)?;
         let ext = SourceDistExtension::from_path(&filename)
             .map_err(|err| Error::MissingExtension(url.to_string(), err))?;
 
         let temp_dir = tempfile::tempdir_in(scratch_dir).map_err(Error::DownloadDirError)?;
 
-        if let Some(python_builds_dir) =
-            env::var_os(EnvVars::UV_PYTHON_CACHE_DIR).filter(|s| !s.is_empty())
-        {
-            let python_builds_dir = PathBuf::from(python_builds_dir);
+        // Extract via an extended-length path on Windows, as the files in a distribution can
+        // exceed `MAX_PATH` when the scratch directory is deeply nested.
+        let scratch = long_path(temp_dir.path()).into_owned();
+
+        if let Some(python_builds_dir) = python_cache_dir() {
             fs_err::create_dir_all(&python_builds_dir)?;
-            let hash_prefix = match self.sha256.as_deref() {
-                Some(sha) => {
-                    // Shorten the hash to avoid too-long-filename errors
-                    &sha[..9]
-                }
-                None => "none",
-            };
-            let target_cache_file = python_builds_dir.join(format!("{hash_prefix}-{filename}"));
+            let target_cache_file = self.cache_file(&python_builds_dir, &filename);
 
             // Download the archive to the cache, or return a reader if we have it in cache.
             // TODO(konsti): We should "tee" the write so we can do the download-to-cache and unpacking
@@ -978,6 +1387,11 @@ impl ManagedPythonDownload {
             let (reader, size): (Box<dyn AsyncRead + Unpin>, Option<u64>) =
                 match fs_err::tokio::File::open(&target_cache_file).await {
                     Ok(file) => {
+                        // Without network access, the archive can't be replaced, so verify it
+                        // up-front to report a corrupted archive rather than an extraction failure.
+                        if client.connectivity().is_offline() {
+                            self.verify_cached_archive(&target_cache_file).await?;
+                        }
                         debug!(
                             "Extracting existing `{}`",
                             target_cache_file.simplified_display()
@@ -999,6 +1413,7 @@ impl ManagedPythonDownload {
                         self.download_archive(
                             &url,
                             client,
+                            rate_limit,
                             reporter,
                             &python_builds_dir,
                             &target_cache_file,
@@ -1017,14 +1432,25 @@ impl ManagedPythonDownload {
             // Extract the downloaded archive into a temporary directory.
             self.extract_reader(
                 reader,
-                temp_dir.path(),
+                &scratch,
                 &filename,
                 ext,
                 size,
                 reporter,
                 Direction::Extract,
             )
-            .await?;
+            .await
+            .map_err(|err| match err {
+                Error::HashMismatch {
+                    expected, actual, ..
+                } => Error::CachedArchiveHashMismatch {
+                    file: Box::new(self.key().clone()),
+                    path: target_cache_file.clone(),
+                    expected,
+                    actual,
+                },
+                err => err,
+            })?;
         } else {
             // Avoid overlong log lines
             debug!("Downloading {url}");
@@ -1035,8 +1461,8 @@ impl ManagedPythonDownload {
 
             let (reader, size) = read_url(&url, client).await?;
             self.extract_reader(
-                reader,
-                temp_dir.path(),
+                RateLimitedReader::new(reader, rate_limit),
+                &scratch,
                 &filename,
                 ext,
                 size,
@@ -1047,9 +1473,12 @@ impl ManagedPythonDownload {
         }
 
         // Extract the top-level directory.
-        let mut extracted = match uv_extract::strip_component(temp_dir.path()) {
+        let mut extracted = match uv_extract::strip_component(&scratch) {
             Ok(top_level) => top_level,
-            Err(uv_extract::Error::NonSingularArchive(_)) => temp_dir.keep(),
+            Err(uv_extract::Error::NonSingularArchive(_)) => {
+                let _ = temp_dir.keep();
+                scratch
+            }
             Err(err) => return Err(Error::ExtractError(filename.to_string(), err)),
         };
 
@@ -1092,29 +1521,124 @@ impl ManagedPythonDownload {
             }
         }
 
-        // Remove the target if it already exists.
-        if path.is_dir() {
-            debug!("Removing existing directory: {}", path.user_display());
-            fs_err::tokio::remove_dir_all(&path).await?;
+        // Remove any optional components that weren't selected.
+        if !self.key.components.is_all() {
+            self.key.components.trim(&extracted, &self.key)?;
         }
 
-        // Persist it to the target.
-        debug!("Moving {} to {}", extracted.display(), path.user_display());
-        rename_with_retry(extracted, &path)
-            .await
-            .map_err(|err| Error::CopyError {
-                to: path.clone(),
-                err,
-            })?;
+        // Persist it to the target, sharing unchanged files with another patch version, if any.
+        let sibling = find_sibling_installation(installation_dir, &self.key);
+        persist_installation(&extracted, installation_dir, &path, sibling.as_deref()).await?;
 
         Ok(DownloadResult::Fetched(path))
     }
 
+    /// Download the archive for a Python distribution into the cache directory, without extracting
+    /// it.
+    ///
+    /// If the archive is already cached and matches the expected checksum, it is not downloaded
+    /// again. The cached archive is used by [`ManagedPythonDownload::fetch`] when
+    /// `UV_PYTHON_CACHE_DIR` points to `python_builds_dir`, e.g., to install without network
+    /// access.
+    #[instrument(skip(client, python_builds_dir, reporter), fields(download = % self.key()))]
+    pub async fn download_to_cache(
+        &self,
+        client: &BaseClient,
+        python_builds_dir: &Path,
+        python_install_mirror: Option<&str>,
+        pypy_install_mirror: Option<&str>,
+        rate_limit: Option<&DownloadRateLimit>,
+        reporter: Option<&dyn Reporter>,
+    ) -> Result<DownloadResult, Error> {
+        let url = self.download_url(python_install_mirror, pypy_install_mirror)?;
+        let filename = archive_filename(&url)?;
+        fs_err::create_dir_all(python_builds_dir)?;
+        let target_cache_file = self.cache_file(python_builds_dir, &filename);
+
+        if target_cache_file.is_file() {
+            match self.verify_cached_archive(&target_cache_file).await {
+                Ok(()) => return Ok(DownloadResult::AlreadyAvailable(target_cache_file)),
+                Err(Error::CachedArchiveHashMismatch { .. }) => {
+                    debug!(
+                        "Removing cached archive with mismatched checksum: `{}`",
+                        target_cache_file.simplified_display()
+                    );
+                    fs_err::tokio::remove_file(&target_cache_file).await?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if client.connectivity().is_offline() {
+            return Err(Error::OfflinePythonMissing {
+                file: Box::new(self.key().clone()),
+                url: Box::new(url),
+                python_builds_dir: python_builds_dir.to_path_buf(),
+            });
+        }
+
+        self.download_archive(
+            &url,
+            client,
+            rate_limit,
+            reporter,
+            python_builds_dir,
+            &target_cache_file,
+        )
+        .await?;
+
+        // Verify the archive now, rather than when it's installed, which may be on another host.
+        if let Err(err) = self.verify_cached_archive(&target_cache_file).await {
+            fs_err::tokio::remove_file(&target_cache_file).await?;
+            return Err(err);
+        }
+
+        Ok(DownloadResult::Fetched(target_cache_file))
+    }
+
+    /// Return the path to the archive for this distribution in the cache directory.
+    fn cache_file(&self, python_builds_dir: &Path, filename: &str) -> PathBuf {
+        let hash_prefix = match self.sha256.as_deref() {
+            Some(sha) => {
+                // Shorten the hash to avoid too-long-filename errors
+                &sha[..9]
+            }
+            None => "none",
+        };
+        python_builds_dir.join(format!("{hash_prefix}-{filename}"))
+    }
+
+    /// Verify that a cached archive matches the expected checksum, if any.
+    async fn verify_cached_archive(&self, path: &Path) -> Result<(), Error> {
+        let Some(expected) = self.sha256.as_deref() else {
+            return Ok(());
+        };
+
+        let file = fs_err::tokio::File::open(path).await?;
+        let mut hashers = vec![Hasher::from(HashAlgorithm::Sha256)];
+        let mut hasher =
+            uv_extract::hash::HashReader::new(tokio::io::BufReader::new(file), &mut hashers);
+        hasher.finish().await.map_err(Error::HashExhaustion)?;
+
+        let actual = HashDigest::from(hashers.pop().unwrap()).digest;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(Error::CachedArchiveHashMismatch {
+                file: Box::new(self.key().clone()),
+                path: path.to_path_buf(),
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Download the managed Python archive into the cache directory.
     async fn download_archive(
         &self,
         url: &Url,
         client: &BaseClient,
+        rate_limit: Option<&DownloadRateLimit>,
         reporter: Option<&dyn Reporter>,
         python_builds_dir: &Path,
         target_cache_file: &Path,
@@ -1125,7 +1649,8 @@ impl ManagedPythonDownload {
             target_cache_file.simplified_display()
         );
 
-        let (mut reader, size) = read_url(url, client).await?;
+        let (reader, size) = read_url(url, client).await?;
+        let mut reader = RateLimitedReader::new(reader, rate_limit);
         let temp_dir = tempfile::tempdir_in(python_builds_dir)?;
         let temp_file = temp_dir.path().join("download");
 
@@ -1211,7 +1736,8 @@ impl ManagedPythonDownload {
 
     /// Return the [`Url`] to use when downloading the distribution. If a mirror is set via the
     /// appropriate environment variable, use it instead.
-    fn download_url(
+    /// Return the URL from which the distribution would be fetched, accounting for any mirrors.
+    pub fn download_url(
         &self,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
@@ -1467,6 +1993,233 @@ where
     }
 }
 
+/// A download rate, in bytes per second.
+///
+/// Parsed from a number of bytes, with an optional `K`, `M`, or `G` suffix for kibibytes,
+/// mebibytes, or gibibytes, e.g., `500K` or `10M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadRate(NonZeroU64);
+
+impl DownloadRate {
+    /// Return the number of bytes per second.
+    pub fn bytes_per_second(self) -> NonZeroU64 {
+        self.0
+    }
+}
+
+impl FromStr for DownloadRate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (number, multiplier) = match s.char_indices().last() {
+            Some((index, 'K' | 'k')) => (&s[..index], 1024),
+            Some((index, 'M' | 'm')) => (&s[..index], 1024 * 1024),
+            Some((index, 'G' | 'g')) => (&s[..index], 1024 * 1024 * 1024),
+            _ => (s, 1),
+        };
+        number
+            .parse::<u64>()
+            .ok()
+            .and_then(|number| number.checked_mul(multiplier))
+            .and_then(NonZeroU64::new)
+            .map(Self)
+            .ok_or_else(|| {
+                format!(
+                    "expected a positive number of bytes per second, optionally followed by `K`, `M`, or `G`, e.g., `10M`; found `{s}`"
+                )
+            })
+    }
+}
+
+/// A limit on the combined bandwidth of Python downloads, shared across concurrent downloads.
+#[derive(Debug)]
+pub struct DownloadRateLimit {
+    rate: DownloadRate,
+    /// The time until which the bandwidth is reserved by previous reads.
+    reserved_until: Mutex<tokio::time::Instant>,
+}
+
+impl DownloadRateLimit {
+    /// Create a limit of the given rate.
+    pub fn new(rate: DownloadRate) -> Self {
+        Self {
+            rate,
+            reserved_until: Mutex::new(tokio::time::Instant::now()),
+        }
+    }
+
+    /// Reserve bandwidth for the given number of bytes, returning the time until which the reader
+    /// must wait before reading again.
+    fn reserve(&self, bytes: u64) -> tokio::time::Instant {
+        let mut reserved_until = self
+            .reserved_until
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        #[allow(clippy::cast_precision_loss)]
+        let duration =
+            Duration::from_secs_f64(bytes as f64 / self.rate.bytes_per_second().get() as f64);
+        *reserved_until = (*reserved_until).max(tokio::time::Instant::now()) + duration;
+        *reserved_until
+    }
+}
+
+/// An asynchronous reader that limits the rate at which bytes are read.
+struct RateLimitedReader<'a, R> {
+    reader: R,
+    limit: Option<&'a DownloadRateLimit>,
+    delay: Option<Pin<Box<tokio::time::Sleep>>>,
+}
+
+impl<'a, R> RateLimitedReader<'a, R> {
+    /// Create a new [`RateLimitedReader`] that wraps another reader, if a limit is given.
+    fn new(reader: R, limit: Option<&'a DownloadRateLimit>) -> Self {
+        Self {
+            reader,
+            limit,
+            delay: None,
+        }
+    }
+}
+
+impl<R> AsyncRead for RateLimitedReader<'_, R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        // Wait out the bandwidth reserved by the previous read.
+        if let Some(delay) = self.delay.as_mut() {
+            if delay.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            self.delay = None;
+        }
+
+        let filled = buf.filled().len();
+        let result = Pin::new(&mut self.reader).poll_read(cx, buf);
+        if let (Poll::Ready(Ok(())), Some(limit)) = (&result, self.limit) {
+            let read = (buf.filled().len() - filled) as u64;
+            if read > 0 {
+                let until = limit.reserve(read);
+                self.delay = Some(Box::pin(tokio::time::sleep_until(until)));
+            }
+        }
+        result
+    }
+}
+
+/// Client settings that apply only to downloads of managed Python installations.
+///
+/// Python distributions are often served from different infrastructure than package indexes,
+/// e.g., an internal mirror that requires its own credentials or certificate authority, so these
+/// settings are not applied to index requests.
+///
+/// Credentials from a `.netrc` file or embedded in the mirror URL are always used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PythonDownloadClientSettings {
+    /// The keyring provider to use for Python downloads, if overridden.
+    keyring: Option<KeyringProviderType>,
+    /// Additional certificate authorities to trust for specific hosts.
+    host_certificates: Vec<HostCertificate>,
+}
+
+impl PythonDownloadClientSettings {
+    /// Create the settings from the keyring provider override and the per-host certificate
+    /// authorities.
+    pub fn new(
+        keyring: Option<KeyringProviderType>,
+        host_certificates: Vec<HostCertificate>,
+    ) -> Self {
+        Self {
+            keyring,
+            host_certificates,
+        }
+    }
+
+    /// Apply the settings to a [`BaseClientBuilder`] used for Python downloads.
+    #[must_use]
+    pub fn apply<'a>(&self, builder: BaseClientBuilder<'a>) -> BaseClientBuilder<'a> {
+        let builder = match self.keyring {
+            Some(keyring) => builder.keyring(keyring),
+            None => builder,
+        };
+        self.host_certificates
+            .iter()
+            .fold(builder, |builder, certificate| {
+                builder.host_certificates(certificate.host.clone(), certificate.path.clone())
+            })
+    }
+}
+
+/// A certificate authority to trust for requests to a specific host, as a path to a PEM bundle.
+///
+/// Parsed from `<host>=<path>`, e.g., `python.mirror.example.com=/etc/ssl/certs/corporate.pem`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostCertificate {
+    host: TrustedHost,
+    path: PathBuf,
+}
+
+impl HostCertificate {
+    /// Resolve a relative certificate path against the given root directory.
+    #[must_use]
+    pub fn relative_to(self, root_dir: &Path) -> Self {
+        Self {
+            path: root_dir.join(self.path),
+            ..self
+        }
+    }
+}
+
+impl FromStr for HostCertificate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((host, path)) = s
+            .split_once('=')
+            .filter(|(host, path)| !host.is_empty() && !path.is_empty())
+        else {
+            return Err(format!("expected `<host>=<path>`, found `{s}`"));
+        };
+        let host = TrustedHost::from_str(host).map_err(|err| err.to_string())?;
+        Ok(Self {
+            host,
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+impl Display for HostCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.host, self.path.display())
+    }
+}
+
+impl<'de> Deserialize<'de> for HostCertificate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for HostCertificate {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("HostCertificate")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A host and the path to a PEM bundle of certificate authorities to trust for it, as `<host>=<path>`."
+        })
+    }
+}
+
 /// Convert a [`Url`] into an [`AsyncRead`] stream.
 async fn read_url(
     url: &Url,
@@ -1511,10 +2264,293 @@ async fn read_url(
     }
 }
 
+/// Determine the size of the file at a URL without reading it, if the server reports it.
+async fn read_url_size(url: &Url, client: &BaseClient) -> Result<Option<u64>, Error> {
+    let url = DisplaySafeUrl::from(url.clone());
+    if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|()| Error::InvalidFileUrl(url.to_string()))?;
+
+        Ok(Some(fs_err::tokio::metadata(&path).await?.len()))
+    } else {
+        let response = client
+            .for_host(&url)
+            .head(Url::from(url.clone()))
+            .send()
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+
+        let retry_count = response
+            .extensions()
+            .get::<reqwest_retry::RetryCount>()
+            .map(|retries| retries.value());
+
+        let response = response
+            .error_for_status()
+            .map_err(|err| Error::from_reqwest(url, err, retry_count))?;
+
+        Ok(response.content_length())
+    }
+}
+
+/// Check whether the file at a URL exists, without reading it.
+///
+/// Returns `None` if the file is missing, or else its size, if the server reports it. Servers that
+/// reject `HEAD` requests are probed with a single-byte range request instead.
+async fn probe_url(url: &Url, client: &BaseClient) -> Result<Option<Option<u64>>, Error> {
+    let url = DisplaySafeUrl::from(url.clone());
+    if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|()| Error::InvalidFileUrl(url.to_string()))?;
+
+        return match fs_err::tokio::metadata(&path).await {
+            Ok(metadata) => Ok(Some(Some(metadata.len()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        };
+    }
+
+    let mut response = client
+        .for_host(&url)
+        .head(Url::from(url.clone()))
+        .send()
+        .await
+        .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+    if matches!(
+        response.status(),
+        reqwest::StatusCode::METHOD_NOT_ALLOWED | reqwest::StatusCode::NOT_IMPLEMENTED
+    ) {
+        debug!("Server rejected `HEAD` request for {url}; retrying with a range request");
+        response = client
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .header(reqwest::header::RANGE, "bytes=0-0")
+            .send()
+            .await
+            .map_err(|err| Error::from_reqwest_middleware(url.clone(), err))?;
+    }
+
+    if matches!(
+        response.status(),
+        reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::GONE
+    ) {
+        return Ok(None);
+    }
+
+    let retry_count = response
+        .extensions()
+        .get::<reqwest_retry::RetryCount>()
+        .map(|retries| retries.value());
+
+    let response = response
+        .error_for_status()
+        .map_err(|err| Error::from_reqwest(url, err, retry_count))?;
+
+    // For a range request, the total size is reported in the `Content-Range` header, e.g.,
+    // `bytes 0-0/1234`.
+    let size = if response.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+        response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|range| range.to_str().ok())
+            .and_then(|range| range.rsplit_once('/'))
+            .and_then(|(_, total)| total.parse().ok())
+    } else {
+        response.content_length()
+    };
+
+    Ok(Some(size))
+}
+
+/// Move an extracted Python installation into place at `path`.
+///
+/// The installation is first moved to a temporary sibling of `path`, such that the final move is
+/// an atomic rename on the same filesystem. If the extraction directory is on a different
+/// filesystem, the installation is copied instead, and the copy is verified before it's moved
+/// into place. Any existing installation at `path` is only removed once the new installation is in
+/// place, and is restored if the move fails.
+///
+/// If a `sibling` installation is provided, files that are identical in both installations are
+/// replaced with hardlinks once staged, such that they only use disk space once.
+async fn persist_installation(
+    extracted: &Path,
+    installation_dir: &Path,
+    path: &Path,
+    sibling: Option<&Path>,
+) -> Result<(), Error> {
+    // Stage the installation via extended-length paths on Windows, as with the extraction.
+    let installation_dir = long_path(installation_dir);
+    let path = long_path(path);
+    let path = path.as_ref();
+
+    let staging = tempfile::tempdir_in(installation_dir).map_err(Error::DownloadDirError)?;
+    let staged = staging.path().join("install");
+
+    match rename_with_retry(extracted, &staged).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+            debug!(
+                "Copying {} to {} across filesystems",
+                extracted.user_display(),
+                staged.user_display()
+            );
+            copy_installation(extracted, &staged).map_err(|err| Error::CopyError {
+                to: staged.clone(),
+                err,
+            })?;
+            if !is_complete_copy(extracted, &staged)? {
+                return Err(Error::CopyVerification {
+                    from: extracted.to_path_buf(),
+                    to: staged,
+                });
+            }
+            fs_err::tokio::remove_dir_all(extracted).await?;
+        }
+        Err(err) => return Err(Error::CopyError { to: staged, err }),
+    }
+
+    // Deduplicate on the staged installation, which is on the same filesystem as the sibling.
+    if let Some(sibling) = sibling {
+        match dedupe_installation(&staged, sibling) {
+            Ok(shared) => debug!(
+                "Shared {shared} bytes with the existing installation at: {}",
+                sibling.user_display()
+            ),
+            Err(err) => debug!(
+                "Failed to share files with the existing installation at {}: {err}",
+                sibling.user_display()
+            ),
+        }
+    }
+
+    // Move the existing installation aside, rather than removing it, such that it can be restored.
+    let previous = if path.is_dir() {
+        let previous = staging.path().join("previous");
+        debug!("Moving existing directory aside: {}", path.user_display());
+        rename_with_retry(path, &previous)
+            .await
+            .map_err(|err| Error::CopyError {
+                to: previous.clone(),
+                err,
+            })?;
+        Some(previous)
+    } else {
+        None
+    };
+
+    debug!("Moving {} to {}", staged.display(), path.user_display());
+    if let Err(err) = rename_with_retry(&staged, path).await {
+        if let Some(previous) = previous {
+            if let Err(err) = rename_with_retry(&previous, path).await {
+                warn!(
+                    "Failed to restore previous installation at {}: {err}",
+                    path.user_display()
+                );
+            }
+        }
+        return Err(Error::CopyError {
+            to: path.to_path_buf(),
+            err,
+        });
+    }
+
+    // Dropping the staging directory removes the previous installation, if any.
+    Ok(())
+}
+
+/// Recursively copy a Python installation, preserving symlinks.
+fn copy_installation(from: &Path, to: &Path) -> io::Result<()> {
+    fs_err::create_dir_all(to)?;
+    for entry in fs_err::read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_installation(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            fs_err::os::unix::fs::symlink(fs_err::read_link(entry.path())?, &target)?;
+            #[cfg(not(unix))]
+            fs_err::copy(entry.path(), &target)?;
+        } else {
+            fs_err::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if `to` contains every file, directory, and symlink in `from`, with matching
+/// file sizes.
+fn is_complete_copy(from: &Path, to: &Path) -> io::Result<bool> {
+    for entry in fs_err::read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        let Ok(metadata) = fs_err::symlink_metadata(&target) else {
+            return Ok(false);
+        };
+        if file_type.is_dir() {
+            if !metadata.is_dir() || !is_complete_copy(&entry.path(), &target)? {
+                return Ok(false);
+            }
+        } else if file_type.is_symlink() && cfg!(unix) {
+            if !metadata.is_symlink() {
+                return Ok(false);
+            }
+        } else if metadata.len() != fs_err::metadata(entry.path())?.len() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Parse per-host certificate overrides.
+    #[test]
+    fn test_parse_host_certificate() {
+        let certificate =
+            HostCertificate::from_str("https://proxy.example.com:8443=certs/ca.pem").unwrap();
+        assert_eq!(
+            certificate.host,
+            TrustedHost::from_str("https://proxy.example.com:8443").unwrap()
+        );
+        assert_eq!(certificate.path, PathBuf::from("certs/ca.pem"));
+        assert_eq!(
+            certificate.relative_to(Path::new("/etc/uv")).path,
+            PathBuf::from("/etc/uv/certs/ca.pem")
+        );
+        for value in ["invalid", "=/a.pem", "host=", "host:port=/a.pem"] {
+            assert!(HostCertificate::from_str(value).is_err(), "{value}");
+        }
+    }
+
+    /// Parse download rates with and without a unit suffix.
+    #[test]
+    fn test_parse_download_rate() {
+        for (value, expected) in [
+            ("1024", 1024),
+            ("500K", 500 * 1024),
+            ("10m", 10 * 1024 * 1024),
+            ("1G", 1024 * 1024 * 1024),
+        ] {
+            assert_eq!(
+                DownloadRate::from_str(value)
+                    .unwrap()
+                    .bytes_per_second()
+                    .get(),
+                expected
+            );
+        }
+        for value in ["", "0", "-1", "K", "1.5M", "10MB"] {
+            assert!(DownloadRate::from_str(value).is_err(), "{value}");
+        }
+    }
+
     /// Parse a request with all of its fields.
     #[test]
     fn test_python_download_request_from_str_complete() {
@@ -1596,6 +2632,68 @@ mod tests {
         assert_eq!(request.libc, None);
     }
 
+    #[test]
+    fn test_split_version_suffixes() {
+        assert_eq!(split_version_suffixes("3.12"), ("3.12", None, None));
+        assert_eq!(
+            split_version_suffixes("3.12+tk"),
+            (
+                "3.12",
+                Some(PythonComponents::only([PythonComponent::Tk])),
+                None
+            )
+        );
+        assert_eq!(
+            split_version_suffixes("3.13+freethreaded+ssl+tk+pip"),
+            (
+                "3.13+freethreaded",
+                Some(PythonComponents::only([
+                    PythonComponent::Pip,
+                    PythonComponent::Tk
+                ])),
+                Some(PythonFeatures::only([PythonFeature::Ssl]))
+            )
+        );
+        assert_eq!(
+            split_version_suffixes("3.13+freethreaded"),
+            ("3.13+freethreaded", None, None)
+        );
+    }
+
+    #[test]
+    fn test_python_download_request_features() {
+        let request = PythonDownloadRequest::from_str("cpython-3.12+sqlite3+tkinter")
+            .expect("Test request should be parsed");
+        assert_eq!(
+            request.features,
+            Some(PythonFeatures::only([
+                PythonFeature::Sqlite3,
+                PythonFeature::Tkinter
+            ]))
+        );
+        assert_eq!(
+            request.to_string(),
+            "cpython-3.12+sqlite3+tkinter-any-any-any"
+        );
+
+        // `tkinter` is only included in managed installations with Tcl/Tk.
+        let request = request.with_components(PythonComponents::only([PythonComponent::Pip]));
+        assert!(
+            !request.satisfied_by_key(
+                &PythonInstallationKey::new(
+                    LenientImplementationName::Known(ImplementationName::CPython),
+                    3,
+                    12,
+                    0,
+                    None,
+                    Platform::from_env().unwrap(),
+                    PythonVariant::Default,
+                )
+                .with_components(PythonComponents::only([PythonComponent::Pip]))
+            )
+        );
+    }
+
     #[test]
     fn test_python_download_request_from_str_implementation_only() {
         let request =
@@ -1726,4 +2824,98 @@ mod tests {
 
         assert!(matches!(result, Err(Error::TooManyParts(_))));
     }
+
+    /// Each channel includes the releases of the more stable channels.
+    #[test]
+    fn test_python_channel_includes() {
+        let rc = Some(Prerelease {
+            kind: PrereleaseKind::Rc,
+            number: 1,
+        });
+        let alpha = Some(Prerelease {
+            kind: PrereleaseKind::Alpha,
+            number: 2,
+        });
+
+        assert!(PythonChannel::Stable.includes(None));
+        assert!(!PythonChannel::Stable.includes(rc));
+        assert!(PythonChannel::Rc.includes(None));
+        assert!(PythonChannel::Rc.includes(rc));
+        assert!(!PythonChannel::Rc.includes(alpha));
+        assert!(PythonChannel::Alpha.includes(alpha));
+        assert!(PythonChannel::Alpha.includes(rc));
+    }
+
+    /// A channel disables the implicit fallback to pre-releases.
+    #[test]
+    fn test_python_download_request_channel_prereleases() {
+        let request = PythonDownloadRequest::from_str("3.14").unwrap();
+        assert!(!request.allows_prereleases());
+        assert!(
+            request
+                .clone()
+                .with_channel(PythonChannel::Rc)
+                .allows_prereleases()
+        );
+        assert!(
+            !request
+                .with_channel(PythonChannel::Stable)
+                .allows_prereleases()
+        );
+    }
+
+    /// Select the latest patch release of the lowest compatible minor version.
+    #[test]
+    fn test_from_request_lowest() {
+        let request = PythonDownloadRequest::from_str("cpython-any-linux-x86_64-gnu")
+            .unwrap()
+            .with_version(VersionRequest::from_str(">=3.10").unwrap());
+
+        let lowest = ManagedPythonDownload::from_request_lowest(&request, None).unwrap();
+        assert_eq!((lowest.key().major, lowest.key().minor), (3, 10));
+        assert!(lowest.key().prerelease.is_none());
+
+        let latest_patch = request
+            .iter_downloads(None)
+            .unwrap()
+            .filter(|download| download.key().minor == 10)
+            .map(|download| download.key().patch)
+            .max()
+            .unwrap();
+        assert_eq!(lowest.key().patch, latest_patch);
+
+        // The highest policy selects a newer minor version.
+        let highest = ManagedPythonDownload::from_request(&request, None).unwrap();
+        assert!(highest.key().minor > 10);
+    }
+
+    /// Copy an installation tree and verify the copy.
+    #[test]
+    fn test_copy_installation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let from = temp_dir.path().join("from");
+        fs_err::create_dir_all(from.join("bin")).unwrap();
+        fs_err::create_dir_all(from.join("lib").join("python3.12")).unwrap();
+        fs_err::write(from.join("bin").join("python3.12"), "python").unwrap();
+        fs_err::write(from.join("lib").join("python3.12").join("os.py"), "os").unwrap();
+        #[cfg(unix)]
+        fs_err::os::unix::fs::symlink("python3.12", from.join("bin").join("python")).unwrap();
+
+        let to = temp_dir.path().join("to");
+        copy_installation(&from, &to).unwrap();
+        assert!(is_complete_copy(&from, &to).unwrap());
+        #[cfg(unix)]
+        assert_eq!(
+            fs_err::read_link(to.join("bin").join("python")).unwrap(),
+            PathBuf::from("python3.12")
+        );
+
+        // A truncated file should fail verification.
+        fs_err::write(to.join("lib").join("python3.12").join("os.py"), "").unwrap();
+        assert!(!is_complete_copy(&from, &to).unwrap());
+
+        // As should a missing file.
+        fs_err::remove_file(to.join("lib").join("python3.12").join("os.py")).unwrap();
+        assert!(!is_complete_copy(&from, &to).unwrap());
+    }
 }
@@ -10,6 +10,7 @@ use uv_cache::Cache;
 use uv_configuration::Preview;
 use uv_fs::{LockedFile, Simplified};
 use uv_pep440::Version;
+use uv_warnings::warn_user;
 
 use crate::discovery::find_python_installation;
 use crate::installation::PythonInstallation;
@@ -154,14 +155,20 @@ impl PythonEnvironment {
         preference: EnvironmentPreference,
         python_preference: PythonPreference,
         cache: &Cache,
+        python_install_dir: Option<&Path>,
         preview: Preview,
     ) -> Result<Self, Error> {
-        let installation =
-            match find_python_installation(request, preference, python_preference, cache, preview)?
-            {
-                Ok(installation) => installation,
-                Err(err) => return Err(EnvironmentNotFound::from(err).into()),
-            };
+        let installation = match find_python_installation(
+            request,
+            preference,
+            python_preference,
+            cache,
+            python_install_dir,
+            preview,
+        )? {
+            Ok(installation) => installation,
+            Err(err) => return Err(EnvironmentNotFound::from(err).into()),
+        };
         Ok(Self::from_installation(installation))
     }
 
@@ -275,6 +282,47 @@ impl PythonEnvironment {
         Ok(PyVenvConfiguration::parse(self.0.root.join("pyvenv.cfg"))?)
     }
 
+    /// Return the environments extended by this environment, nearest first.
+    ///
+    /// The chain is read from the `extends-environment` key of each `pyvenv.cfg`, as written for
+    /// ephemeral environments layered on top of a project environment. The chain ends at the first
+    /// environment without the key, or with a warning at a parent that no longer exists.
+    pub fn parent_environments(&self, cache: &Cache) -> Result<Vec<Self>, Error> {
+        let mut parents = Vec::new();
+        let mut seen = vec![self.root().to_path_buf()];
+        let mut current = self.clone();
+        while let Some(parent) = current
+            .cfg()
+            .ok()
+            .and_then(|cfg| cfg.extends_environment().map(Path::to_path_buf))
+        {
+            if seen.contains(&parent) {
+                warn_user!(
+                    "Ignoring cyclic `extends-environment` in `{}`",
+                    current.root().join("pyvenv.cfg").user_display()
+                );
+                break;
+            }
+            if !parent.is_dir() {
+                warn_user!(
+                    "Ignoring missing parent environment `{}` (from `extends-environment` in `{}`)",
+                    parent.user_display(),
+                    current.root().join("pyvenv.cfg").user_display()
+                );
+                break;
+            }
+            debug!(
+                "Found parent environment via `extends-environment`: `{}`",
+                parent.user_display()
+            );
+            let environment = Self::from_root(&parent, cache)?;
+            seen.push(parent);
+            parents.push(environment.clone());
+            current = environment;
+        }
+        Ok(parents)
+    }
+
     /// Set a key-value pair in the `pyvenv.cfg` file.
     pub fn set_pyvenv_cfg(&self, key: &str, value: &str) -> Result<(), Error> {
         let content = fs_err::read_to_string(self.0.root.join("pyvenv.cfg"))?;
@@ -290,6 +338,17 @@ impl PythonEnvironment {
         self.cfg().is_ok_and(|cfg| cfg.is_relocatable())
     }
 
+    /// Returns `true` if the environment is locked against modification.
+    pub fn is_read_only(&self) -> bool {
+        self.cfg().is_ok_and(|cfg| cfg.is_read_only())
+    }
+
+    /// Returns the target platform if the environment is a resolution-only environment, i.e., it
+    /// was created with `uv venv --python-platform`.
+    pub fn python_platform(&self) -> Option<String> {
+        self.cfg().ok()?.python_platform().map(ToString::to_string)
+    }
+
     /// Returns the location of the Python executable.
     pub fn python_executable(&self) -> &Path {
         self.0.interpreter.sys_executable()
@@ -316,6 +375,14 @@ impl PythonEnvironment {
         self.0.interpreter.lock().await
     }
 
+    /// Return the path to the file lock for the environment, along with a description of the
+    /// locked resource.
+    ///
+    /// See [`Interpreter::lock_path`].
+    pub fn lock_path(&self) -> (PathBuf, String) {
+        self.0.interpreter.lock_path()
+    }
+
     /// Return the [`Interpreter`] for this environment.
     ///
     /// See also [`PythonEnvironment::interpreter`].
@@ -365,4 +432,32 @@ impl PythonEnvironment {
 
         (cfg_version != exe_version).then_some((cfg_version, exe_version))
     }
+
+    /// Update the Python version in the `pyvenv.cfg` file to match the interpreter's Python
+    /// version, e.g., after the base installation was upgraded in-place.
+    ///
+    /// Returns the previous version if it was updated, or [`None`] if the versions were already
+    /// consistent.
+    pub fn update_pyvenv_version(&self) -> Result<Option<Version>, Error> {
+        let Some((cfg_version, _)) = self.get_pyvenv_version_conflict() else {
+            return Ok(None);
+        };
+
+        // Both `venv` (`version`) and `virtualenv` (`version_info`) keys may be present.
+        let path = self.0.root.join("pyvenv.cfg");
+        let mut content = fs_err::read_to_string(&path)?;
+        let version = self.interpreter().python_full_version().to_string();
+        for key in ["version", "version_info"] {
+            let present = content.lines().any(|line| {
+                line.split_once('=')
+                    .is_some_and(|(lhs, _)| lhs.trim() == key)
+            });
+            if present {
+                content = PyVenvConfiguration::set(&content, key, &version);
+            }
+        }
+        fs_err::write(&path, content)?;
+
+        Ok(Some(cfg_version))
+    }
 }
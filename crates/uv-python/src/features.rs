@@ -0,0 +1,179 @@
+//! Optional standard library modules that an interpreter may have been built without.
+//!
+//! Distributions of Python, e.g., Debian's or builds from pyenv, frequently omit extension modules
+//! like `_ssl` or `_sqlite3` when the corresponding system libraries were missing at build time.
+//! Such interpreters work until a package needs the module, so the availability of each feature
+//! is reported when the interpreter is queried, and interpreters can be required to include a
+//! feature with a request like `3.12+ssl`.
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An optional standard library feature of an interpreter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PythonFeature {
+    /// The `ssl` module.
+    Ssl,
+    /// The `sqlite3` module.
+    Sqlite3,
+    /// The `lzma` module.
+    Lzma,
+    /// The `bz2` module.
+    Bz2,
+    /// The `zlib` module.
+    Zlib,
+    /// The `ctypes` module.
+    Ctypes,
+    /// The `tkinter` module.
+    Tkinter,
+}
+
+impl PythonFeature {
+    pub const ALL: [Self; 7] = [
+        Self::Ssl,
+        Self::Sqlite3,
+        Self::Lzma,
+        Self::Bz2,
+        Self::Zlib,
+        Self::Ctypes,
+        Self::Tkinter,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ssl => "ssl",
+            Self::Sqlite3 => "sqlite3",
+            Self::Lzma => "lzma",
+            Self::Bz2 => "bz2",
+            Self::Zlib => "zlib",
+            Self::Ctypes => "ctypes",
+            Self::Tkinter => "tkinter",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl Display for PythonFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PythonFeature {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|feature| feature.as_str() == s)
+            .ok_or(())
+    }
+}
+
+/// A set of [`PythonFeature`]s, e.g., the features available in an interpreter or the features
+/// required by a request.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "Vec<String>", into = "Vec<String>")]
+pub struct PythonFeatures(u8);
+
+impl PythonFeatures {
+    /// Only the given features.
+    pub fn only(features: impl IntoIterator<Item = PythonFeature>) -> Self {
+        Self(
+            features
+                .into_iter()
+                .fold(0, |bits, feature| bits | feature.bit()),
+        )
+    }
+
+    /// Whether the given feature is included.
+    pub fn contains(self, feature: PythonFeature) -> bool {
+        self.0 & feature.bit() != 0
+    }
+
+    /// Whether all of the features in `other` are included.
+    pub fn is_superset(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The features that are included, but aren't included in `other`.
+    #[must_use]
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether no features are included.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the included features.
+    pub fn iter(self) -> impl Iterator<Item = PythonFeature> {
+        PythonFeature::ALL
+            .into_iter()
+            .filter(move |feature| self.contains(*feature))
+    }
+}
+
+impl From<Vec<String>> for PythonFeatures {
+    /// Collect the features reported by the interpreter query, ignoring unknown features.
+    fn from(features: Vec<String>) -> Self {
+        Self::only(
+            features
+                .iter()
+                .filter_map(|feature| PythonFeature::from_str(feature).ok()),
+        )
+    }
+}
+
+impl From<PythonFeatures> for Vec<String> {
+    fn from(features: PythonFeatures) -> Self {
+        features.iter().map(|feature| feature.to_string()).collect()
+    }
+}
+
+impl Display for PythonFeatures {
+    /// Display the included features, separated by `+`, e.g., `ssl+sqlite3`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, feature) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str("+")?;
+            }
+            write!(f, "{feature}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PythonFeature, PythonFeatures};
+
+    #[test]
+    fn set_operations() {
+        let available =
+            PythonFeatures::only([PythonFeature::Ssl, PythonFeature::Lzma, PythonFeature::Zlib]);
+        let required = PythonFeatures::only([PythonFeature::Ssl, PythonFeature::Sqlite3]);
+        assert!(!available.is_superset(required));
+        assert_eq!(
+            required.difference(available),
+            PythonFeatures::only([PythonFeature::Sqlite3])
+        );
+        assert!(available.is_superset(PythonFeatures::only([PythonFeature::Ssl])));
+        assert_eq!(available.to_string(), "ssl+lzma+zlib");
+    }
+
+    #[test]
+    fn deserialize() {
+        let features: PythonFeatures =
+            serde_json::from_str(r#"["ssl", "tkinter", "unknown"]"#).unwrap();
+        assert_eq!(
+            features,
+            PythonFeatures::only([PythonFeature::Ssl, PythonFeature::Tkinter])
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Access to the clock and filesystem of the host, as used by Python discovery.
+//!
+//! By default, the system clock and the real filesystem are used. Embedders can substitute their
+//! own [`Clock`] or [`FileSystem`] for the duration of a closure (on the current thread) with
+//! [`with_clock`] and [`with_file_system`], e.g., to test discovery hermetically, without
+//! creating interpreters on disk. Combined with `UV_MOCK_INTERPRETER_JSON`, which mocks the
+//! interpreter query itself, this allows discovery to be tested on all platforms.
+//!
+//! Only the discovery hot paths are routed through the [`FileSystem`], i.e., the search of the
+//! `PATH`, the resolution of path requests, and the detection of virtual environments. Managed
+//! installations and the interpreter cache always use the real filesystem.
+use std::cell::RefCell;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::SystemTime;
+
+/// A source of the current time.
+pub trait Clock: Send + Sync {
+    /// Return the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// The system clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// The filesystem operations used during Python discovery.
+pub trait FileSystem: Send + Sync {
+    /// Returns `true` if the path exists and is a directory, following symbolic links.
+    fn is_dir(&self, path: &Path) -> bool;
+
+    /// Returns `true` if the path exists and is a file, following symbolic links.
+    fn is_file(&self, path: &Path) -> bool;
+
+    /// Returns `true` if the path exists, following symbolic links.
+    fn exists(&self, path: &Path) -> bool {
+        self.is_file(path) || self.is_dir(path)
+    }
+
+    /// Returns `true` if the path is an executable file.
+    fn is_executable(&self, path: &Path) -> bool;
+
+    /// Return the paths of the entries in a directory, in no particular order.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// Read the contents of a file as a string.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Return the executables with the given name in a directory, as found by a `PATH` search.
+    ///
+    /// On Windows, this includes executables matching the name with any of the `PATHEXT`
+    /// extensions.
+    fn find_executables(&self, dir: &Path, name: &str) -> Vec<PathBuf>;
+}
+
+/// The real filesystem.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn is_executable(&self, path: &Path) -> bool {
+        uv_fs::which::is_executable(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        fs_err::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        fs_err::read_to_string(path)
+    }
+
+    fn find_executables(&self, dir: &Path, name: &str) -> Vec<PathBuf> {
+        which::which_in_global(name, Some(dir))
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+static SYSTEM_CLOCK: LazyLock<Arc<dyn Clock>> = LazyLock::new(|| Arc::new(SystemClock));
+static OS_FILE_SYSTEM: LazyLock<Arc<dyn FileSystem>> = LazyLock::new(|| Arc::new(OsFileSystem));
+
+thread_local! {
+    static CLOCK: RefCell<Option<Arc<dyn Clock>>> = const { RefCell::new(None) };
+    static FILE_SYSTEM: RefCell<Option<Arc<dyn FileSystem>>> = const { RefCell::new(None) };
+}
+
+/// Return the [`Clock`] in use on the current thread.
+pub fn clock() -> Arc<dyn Clock> {
+    CLOCK
+        .with_borrow(Clone::clone)
+        .unwrap_or_else(|| SYSTEM_CLOCK.clone())
+}
+
+/// Return the [`FileSystem`] in use on the current thread.
+pub fn file_system() -> Arc<dyn FileSystem> {
+    FILE_SYSTEM
+        .with_borrow(Clone::clone)
+        .unwrap_or_else(|| OS_FILE_SYSTEM.clone())
+}
+
+/// Run `f` with `clock` as the [`Clock`] of the current thread.
+///
+/// The previous clock is restored when `f` returns or panics. Work spawned onto other threads
+/// within `f` uses the system clock.
+pub fn with_clock<T>(clock: impl Clock + 'static, f: impl FnOnce() -> T) -> T {
+    let previous = CLOCK.replace(Some(Arc::new(clock)));
+    let _guard = Restore {
+        key: &CLOCK,
+        previous,
+    };
+    f()
+}
+
+/// Run `f` with `file_system` as the [`FileSystem`] of the current thread.
+///
+/// The previous filesystem is restored when `f` returns or panics. Work spawned onto other threads
+/// within `f` uses the real filesystem.
+pub fn with_file_system<T>(file_system: impl FileSystem + 'static, f: impl FnOnce() -> T) -> T {
+    let previous = FILE_SYSTEM.replace(Some(Arc::new(file_system)));
+    let _guard = Restore {
+        key: &FILE_SYSTEM,
+        previous,
+    };
+    f()
+}
+
+/// Restores the previous value of a thread-local override on drop.
+struct Restore<T: ?Sized + 'static> {
+    key: &'static std::thread::LocalKey<RefCell<Option<Arc<T>>>>,
+    previous: Option<Arc<T>>,
+}
+
+impl<T: ?Sized + 'static> Drop for Restore<T> {
+    fn drop(&mut self) {
+        self.key.set(self.previous.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
+
+    use super::{Clock, FileSystem, clock, file_system, with_clock, with_file_system};
+
+    struct FixedClock(SystemTime);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    struct EmptyFileSystem;
+
+    impl FileSystem for EmptyFileSystem {
+        fn is_dir(&self, _path: &Path) -> bool {
+            false
+        }
+
+        fn is_file(&self, _path: &Path) -> bool {
+            false
+        }
+
+        fn is_executable(&self, _path: &Path) -> bool {
+            false
+        }
+
+        fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+            Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} does not exist", path.display()),
+            ))
+        }
+
+        fn read_to_string(&self, path: &Path) -> io::Result<String> {
+            Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} does not exist", path.display()),
+            ))
+        }
+
+        fn find_executables(&self, _dir: &Path, _name: &str) -> Vec<PathBuf> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn overrides_are_scoped() {
+        let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+        with_clock(FixedClock(epoch), || {
+            assert_eq!(clock().now(), epoch);
+        });
+        assert_ne!(clock().now(), epoch);
+
+        let current_dir = std::env::current_dir().unwrap();
+        with_file_system(EmptyFileSystem, || {
+            assert!(!file_system().is_dir(&current_dir));
+        });
+        assert!(file_system().is_dir(&current_dir));
+    }
+
+    #[test]
+    fn overrides_are_restored_on_panic() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let result = std::panic::catch_unwind(|| {
+            with_clock(FixedClock(epoch), || panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert_ne!(clock().now(), epoch);
+    }
+}
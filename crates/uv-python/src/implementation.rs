@@ -4,6 +4,8 @@ use std::{
 };
 use thiserror::Error;
 
+use uv_pep508::MarkerEnvironment;
+
 use crate::Interpreter;
 
 #[derive(Error, Debug)]
@@ -64,6 +66,24 @@ impl ImplementationName {
                 .eq_ignore_ascii_case(self.into()),
         }
     }
+
+    /// Return a [`MarkerEnvironment`] compatible with the given [`ImplementationName`], based on
+    /// a base [`MarkerEnvironment`].
+    ///
+    /// The returned [`MarkerEnvironment`] will preserve the base environment's platform and Python
+    /// version markers, but override its implementation markers. The version of an alternative
+    /// implementation can't be inferred, so `implementation_version` is retained.
+    pub fn markers(self, base: &MarkerEnvironment) -> MarkerEnvironment {
+        // Ex) `implementation_name == "pypy"` and `platform_python_implementation == "PyPy"`
+        let (implementation_name, platform_python_implementation) = match self {
+            Self::CPython | Self::Pyodide => ("cpython", "CPython"),
+            Self::PyPy => ("pypy", "PyPy"),
+            Self::GraalPy => ("graalpy", "GraalVM"),
+        };
+        base.clone()
+            .with_implementation_name(implementation_name)
+            .with_platform_python_implementation(platform_python_implementation)
+    }
 }
 
 impl LenientImplementationName {
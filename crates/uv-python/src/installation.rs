@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 use std::str::FromStr;
 
 use indexmap::IndexMap;
@@ -13,15 +14,19 @@ use uv_configuration::Preview;
 use uv_pep440::{Prerelease, Version};
 use uv_platform::{Arch, Libc, Os, Platform};
 
+use crate::components::PythonComponents;
 use crate::discovery::{
     EnvironmentPreference, PythonRequest, find_best_python_installation, find_python_installation,
 };
-use crate::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest, Reporter};
+use crate::downloads::{
+    DownloadResult, ManagedPythonDownload, PythonDownloadClientSettings, PythonDownloadRequest,
+    Reporter,
+};
 use crate::implementation::LenientImplementationName;
 use crate::managed::{ManagedPythonInstallation, ManagedPythonInstallations};
 use crate::{
-    Error, ImplementationName, Interpreter, PythonDownloads, PythonPreference, PythonSource,
-    PythonVariant, PythonVersion, downloads,
+    Error, ImplementationName, Interpreter, PythonDownloads, PythonPreference, PythonResolution,
+    PythonSource, PythonVariant, PythonVersion, Remediation, downloads,
 };
 
 /// A Python interpreter and accompanying tools.
@@ -59,10 +64,17 @@ impl PythonInstallation {
         environments: EnvironmentPreference,
         preference: PythonPreference,
         cache: &Cache,
+        python_install_dir: Option<&Path>,
         preview: Preview,
     ) -> Result<Self, Error> {
-        let installation =
-            find_python_installation(request, environments, preference, cache, preview)??;
+        let installation = find_python_installation(
+            request,
+            environments,
+            preference,
+            cache,
+            python_install_dir,
+            preview,
+        )??;
         Ok(installation)
     }
 
@@ -73,6 +85,7 @@ impl PythonInstallation {
         environments: EnvironmentPreference,
         preference: PythonPreference,
         cache: &Cache,
+        python_install_dir: Option<&Path>,
         preview: Preview,
     ) -> Result<Self, Error> {
         Ok(find_best_python_installation(
@@ -80,6 +93,7 @@ impl PythonInstallation {
             environments,
             preference,
             cache,
+            python_install_dir,
             preview,
         )??)
     }
@@ -87,6 +101,10 @@ impl PythonInstallation {
     /// Find or fetch a [`PythonInstallation`].
     ///
     /// Unlike [`PythonInstallation::find`], if the required Python is not installed it will be installed automatically.
+    ///
+    /// Python versions are installed into the project-level directory for managed Python
+    /// installations, if given, and discovery searches it before the user-level directory.
+    /// Downloaded distributions are extracted into the staging directory, if given.
     pub async fn find_or_download(
         request: Option<&PythonRequest>,
         environments: EnvironmentPreference,
@@ -98,12 +116,23 @@ impl PythonInstallation {
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
         python_downloads_json_url: Option<&str>,
+        python_install_dir: Option<&Path>,
+        install_staging_dir: Option<&Path>,
+        download_client: &PythonDownloadClientSettings,
+        python_resolution: PythonResolution,
         preview: Preview,
     ) -> Result<Self, Error> {
         let request = request.unwrap_or(&PythonRequest::Default);
 
         // Search for the installation
-        let err = match Self::find(request, environments, preference, cache, preview) {
+        let err = match Self::find(
+            request,
+            environments,
+            preference,
+            cache,
+            python_install_dir,
+            preview,
+        ) {
             Ok(installation) => return Ok(installation),
             Err(err) => err,
         };
@@ -127,7 +156,11 @@ impl PythonInstallation {
             && client_builder.connectivity.is_online();
 
         let download = download_request.clone().fill().map(|request| {
-            ManagedPythonDownload::from_request(&request, python_downloads_json_url)
+            ManagedPythonDownload::from_request_with_resolution(
+                &request,
+                python_resolution,
+                python_downloads_json_url,
+            )
         });
 
         // Regardless of whether downloads are enabled, we want to determine if the download is
@@ -137,11 +170,19 @@ impl PythonInstallation {
             Ok(Ok(download)) => Some(download),
             // If the download cannot be found, return the _original_ discovery error
             Ok(Err(downloads::Error::NoDownloadFound(_))) => {
+                // If the request includes a patch version that doesn't exist, suggest the
+                // available patch versions instead
+                let err = if let Some(remediation) =
+                    unknown_patch_remediation(request, &download_request, python_downloads_json_url)
+                {
+                    err.with_remediation(remediation)
+                } else {
+                    err
+                };
                 if downloads_enabled {
                     debug!("No downloads are available for {request}");
-                    return Err(err);
                 }
-                None
+                return Err(err);
             }
             Err(err) | Ok(Err(err)) => {
                 if downloads_enabled {
@@ -161,30 +202,23 @@ impl PythonInstallation {
 
         // If the download is available, but not usable, we attach a hint to the original error.
         if !downloads_enabled {
-            let for_request = match request {
-                PythonRequest::Default | PythonRequest::Any => String::new(),
-                _ => format!(" for {request}"),
-            };
-
             match python_downloads {
                 PythonDownloads::Automatic => {}
                 PythonDownloads::Manual => {
-                    return Err(err.with_missing_python_hint(format!(
-                        "A managed Python download is available{for_request}, but Python downloads are set to 'manual', use `uv python install {}` to install the required version",
-                        request.to_canonical_string(),
-                    )));
+                    return Err(err.with_remediation(Remediation::InstallManually(request.clone())));
                 }
                 PythonDownloads::Never => {
-                    return Err(err.with_missing_python_hint(format!(
-                        "A managed Python download is available{for_request}, but Python downloads are set to 'never'"
-                    )));
+                    return Err(
+                        err.with_remediation(Remediation::DownloadsDisabled(request.clone()))
+                    );
                 }
             }
 
             match preference {
                 PythonPreference::OnlySystem => {
-                    return Err(err.with_missing_python_hint(format!(
-                        "A managed Python download is available{for_request}, but the Python preference is set to 'only system'"
+                    return Err(err.with_remediation(Remediation::PreferenceExcludesManaged(
+                        request.clone(),
+                        preference,
                     )));
                 }
                 PythonPreference::Managed
@@ -193,9 +227,7 @@ impl PythonInstallation {
             }
 
             if !client_builder.connectivity.is_online() {
-                return Err(err.with_missing_python_hint(format!(
-                    "A managed Python download is available{for_request}, but uv is set to offline mode"
-                )));
+                return Err(err.with_remediation(Remediation::Offline(request.clone())));
             }
 
             return Err(err);
@@ -208,6 +240,9 @@ impl PythonInstallation {
             reporter,
             python_install_mirror,
             pypy_install_mirror,
+            python_install_dir,
+            install_staging_dir,
+            download_client,
             preview,
         )
         .await
@@ -221,14 +256,20 @@ impl PythonInstallation {
         reporter: Option<&dyn Reporter>,
         python_install_mirror: Option<&str>,
         pypy_install_mirror: Option<&str>,
+        python_install_dir: Option<&Path>,
+        install_staging_dir: Option<&Path>,
+        download_client: &PythonDownloadClientSettings,
         preview: Preview,
     ) -> Result<Self, Error> {
-        let installations = ManagedPythonInstallations::from_settings(None)?.init()?;
+        let installations =
+            ManagedPythonInstallations::from_settings(python_install_dir.map(Path::to_path_buf))?
+                .with_staging_dir(install_staging_dir.map(Path::to_path_buf))
+                .init()?;
         let installations_dir = installations.root();
         let scratch_dir = installations.scratch();
         let _lock = installations.lock().await?;
 
-        let client = client_builder.build();
+        let client = download_client.apply(client_builder.clone()).build();
 
         info!("Fetching requested Python...");
         let result = download
@@ -239,6 +280,7 @@ impl PythonInstallation {
                 false,
                 python_install_mirror,
                 pypy_install_mirror,
+                None,
                 reporter,
             )
             .await?;
@@ -340,6 +382,41 @@ impl PythonInstallation {
     }
 }
 
+/// If a request includes a patch version that has no managed download, return a [`Remediation`]
+/// pointing to the latest available patch version of the same minor version.
+fn unknown_patch_remediation(
+    request: &PythonRequest,
+    download_request: &PythonDownloadRequest,
+    python_downloads_json_url: Option<&str>,
+) -> Option<Remediation> {
+    let version = download_request.version()?;
+    version.patch()?;
+    let version = version.clone().without_patch();
+
+    let alternative = match request {
+        PythonRequest::Version(_) => PythonRequest::Version(version.clone()),
+        PythonRequest::ImplementationVersion(implementation, _) => {
+            PythonRequest::ImplementationVersion(*implementation, version.clone())
+        }
+        _ => return None,
+    };
+
+    let download = download_request
+        .clone()
+        .with_version(version)
+        .fill()
+        .ok()
+        .and_then(|request| {
+            ManagedPythonDownload::from_request(&request, python_downloads_json_url).ok()
+        })?;
+
+    Some(Remediation::UnknownPatch {
+        request: request.clone(),
+        alternative,
+        latest: download.python_version().to_string(),
+    })
+}
+
 #[derive(Error, Debug)]
 pub enum PythonInstallationKeyError {
     #[error("Failed to parse Python installation key `{0}`: {1}")]
@@ -355,6 +432,7 @@ pub struct PythonInstallationKey {
     pub(crate) prerelease: Option<Prerelease>,
     pub(crate) platform: Platform,
     pub(crate) variant: PythonVariant,
+    pub(crate) components: PythonComponents,
 }
 
 impl PythonInstallationKey {
@@ -375,6 +453,7 @@ impl PythonInstallationKey {
             prerelease,
             platform,
             variant,
+            components: PythonComponents::all(),
         }
     }
 
@@ -392,9 +471,17 @@ impl PythonInstallationKey {
             prerelease: version.pre(),
             platform,
             variant,
+            components: PythonComponents::all(),
         }
     }
 
+    /// Return the key for an installation with only the given optional components.
+    #[must_use]
+    pub fn with_components(mut self, components: PythonComponents) -> Self {
+        self.components = components;
+        self
+    }
+
     pub fn implementation(&self) -> Cow<'_, LenientImplementationName> {
         if self.os().is_emscripten() {
             Cow::Owned(LenientImplementationName::from(ImplementationName::Pyodide))
@@ -449,6 +536,10 @@ impl PythonInstallationKey {
         &self.variant
     }
 
+    pub fn components(&self) -> PythonComponents {
+        self.components
+    }
+
     /// Return a canonical name for a minor versioned executable.
     pub fn executable_name_minor(&self) -> String {
         format!(
@@ -486,9 +577,14 @@ impl fmt::Display for PythonInstallationKey {
             PythonVariant::Default => String::new(),
             PythonVariant::Freethreaded => format!("+{}", self.variant),
         };
+        let components = if self.components.is_all() {
+            String::new()
+        } else {
+            format!("+{}", self.components)
+        };
         write!(
             f,
-            "{}-{}.{}.{}{}{}-{}",
+            "{}-{}.{}.{}{}{}{}-{}",
             self.implementation(),
             self.major,
             self.minor,
@@ -497,6 +593,7 @@ impl fmt::Display for PythonInstallationKey {
                 .map(|pre| pre.to_string())
                 .unwrap_or_default(),
             variant,
+            components,
             self.platform
         )
     }
@@ -525,6 +622,9 @@ impl FromStr for PythonInstallationKey {
 
         let implementation = LenientImplementationName::from(*implementation_str);
 
+        let (version_str, components) = PythonComponents::split_suffix(version_str);
+        let components = components.unwrap_or_default();
+
         let (version, variant) = match version_str.split_once('+') {
             Some((version, variant)) => {
                 let variant = PythonVariant::from_str(variant).map_err(|()| {
@@ -535,7 +635,7 @@ impl FromStr for PythonInstallationKey {
                 })?;
                 (version, variant)
             }
-            None => (*version_str, PythonVariant::Default),
+            None => (version_str, PythonVariant::Default),
         };
 
         let version = PythonVersion::from_str(version).map_err(|err| {
@@ -560,6 +660,7 @@ impl FromStr for PythonInstallationKey {
             prerelease: version.pre(),
             platform,
             variant,
+            components,
         })
     }
 }
@@ -579,6 +680,8 @@ impl Ord for PythonInstallationKey {
             .then_with(|| self.platform.cmp(&other.platform).reverse())
             // Python variants are sorted in preferred order, with `Default` first
             .then_with(|| self.variant.cmp(&other.variant).reverse())
+            // Installations with more components are preferred
+            .then_with(|| self.components.cmp(&other.components))
     }
 }
 
@@ -627,10 +730,15 @@ impl fmt::Display for PythonInstallationMinorVersionKey {
             PythonVariant::Default => String::new(),
             PythonVariant::Freethreaded => format!("+{}", self.0.variant),
         };
+        let components = if self.0.components.is_all() {
+            String::new()
+        } else {
+            format!("+{}", self.0.components)
+        };
         write!(
             f,
-            "{}-{}.{}{}-{}",
-            self.0.implementation, self.0.major, self.0.minor, variant, self.0.platform,
+            "{}-{}.{}{}{}-{}",
+            self.0.implementation, self.0.major, self.0.minor, variant, components, self.0.platform,
         )
     }
 }
@@ -644,6 +752,7 @@ impl fmt::Debug for PythonInstallationMinorVersionKey {
             .field("major", &self.0.major)
             .field("minor", &self.0.minor)
             .field("variant", &self.0.variant)
+            .field("components", &self.0.components)
             .field("os", &self.0.platform.os)
             .field("arch", &self.0.platform.arch)
             .field("libc", &self.0.platform.libc)
@@ -660,6 +769,7 @@ impl PartialEq for PythonInstallationMinorVersionKey {
             && self.0.minor == other.0.minor
             && self.0.platform == other.0.platform
             && self.0.variant == other.0.variant
+            && self.0.components == other.0.components
     }
 }
 
@@ -672,6 +782,7 @@ impl Hash for PythonInstallationMinorVersionKey {
         self.0.minor.hash(state);
         self.0.platform.hash(state);
         self.0.variant.hash(state);
+        self.0.components.hash(state);
     }
 }
 
@@ -684,6 +795,7 @@ impl From<PythonInstallationKey> for PythonInstallationMinorVersionKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::PythonComponent;
     use uv_platform::ArchVariant;
 
     #[test]
@@ -772,6 +884,7 @@ mod tests {
             prerelease: None,
             platform: Platform::from_str("linux-x86_64-gnu").unwrap(),
             variant: PythonVariant::Default,
+            components: PythonComponents::all(),
         };
         assert_eq!(key.to_string(), "cpython-3.12.0-linux-x86_64-gnu");
 
@@ -783,10 +896,23 @@ mod tests {
             prerelease: None,
             platform: Platform::from_str("macos-aarch64-none").unwrap(),
             variant: PythonVariant::Freethreaded,
+            components: PythonComponents::all(),
         };
         assert_eq!(
             key_with_variant.to_string(),
             "cpython-3.13.0+freethreaded-macos-aarch64-none"
         );
+
+        let key_with_components =
+            key_with_variant.with_components(PythonComponents::only([PythonComponent::Tk]));
+        assert_eq!(
+            key_with_components.to_string(),
+            "cpython-3.13.0+freethreaded+tk-macos-aarch64-none"
+        );
+        assert_eq!(
+            PythonInstallationKey::from_str("cpython-3.13.0+freethreaded+tk-macos-aarch64-none")
+                .unwrap(),
+            key_with_components
+        );
     }
 }
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::env::consts::ARCH;
+use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
 use std::sync::OnceLock;
+use std::time::Duration;
 use std::{env, io};
 
 use configparser::ini::Ini;
@@ -17,7 +20,7 @@ use tracing::{debug, trace, warn};
 use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness};
 use uv_cache_info::Timestamp;
 use uv_cache_key::cache_digest;
-use uv_fs::{LockedFile, PythonExt, Simplified, write_atomic_sync};
+use uv_fs::{LockedFile, Simplified, write_atomic_sync};
 use uv_install_wheel::Layout;
 use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, StringVersion};
@@ -25,17 +28,40 @@ use uv_platform::{Arch, Libc, Os};
 use uv_platform_tags::{Platform, Tags, TagsError};
 use uv_pypi_types::{ResolverMarkerEnvironment, Scheme};
 
+use crate::features::PythonFeatures;
 use crate::implementation::LenientImplementationName;
 use crate::managed::ManagedPythonInstallations;
 use crate::pointer_size::PointerSize;
+use crate::quarantine::Quarantine;
+use crate::query_env::query_env;
+use crate::query_failures::QueryFailure;
+use crate::query_limits::QueryLimits;
+use crate::query_probes::QueryProbe;
 use crate::{
-    Prefix, PythonInstallationKey, PythonVariant, PythonVersion, Target, VersionRequest,
-    VirtualEnvironment,
+    Prefix, PyVenvConfiguration, PythonInstallationKey, PythonVariant, PythonVersion, Target,
+    VersionRequest, VirtualEnvironment,
 };
 
 #[cfg(windows)]
 use windows_sys::Win32::Foundation::{APPMODEL_ERROR_NO_PACKAGE, ERROR_CANT_ACCESS_FILE};
 
+#[cfg(any(test, feature = "embedded"))]
+mod embedded;
+
+/// The architectures contained in a macOS `universal2` binary.
+const UNIVERSAL2_SLICES: [uv_platform_tags::Arch; 2] = [
+    uv_platform_tags::Arch::Aarch64,
+    uv_platform_tags::Arch::X86_64,
+];
+
+/// Return the name macOS uses for a slice of a universal binary, e.g., in `platform.machine()`.
+fn macos_arch_name(slice: uv_platform_tags::Arch) -> &'static str {
+    match slice {
+        uv_platform_tags::Arch::Aarch64 => "arm64",
+        _ => "x86_64",
+    }
+}
+
 /// A Python executable and its associated platform markers.
 #[derive(Debug, Clone)]
 pub struct Interpreter {
@@ -58,7 +84,14 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    soabi: Option<String>,
+    ext_suffix: Option<String>,
+    libdir: Option<PathBuf>,
+    macos_universal2: bool,
+    arch_slice: Option<Arch>,
     real_executable: PathBuf,
+    query_env: BTreeMap<String, String>,
+    extensions: BTreeMap<String, serde_json::Value>,
 }
 
 impl Interpreter {
@@ -72,7 +105,26 @@ impl Interpreter {
             info.sys_executable.display()
         );
 
-        Ok(Self {
+        let interpreter = Self::from_info(info, executable.as_ref().to_path_buf());
+
+        // A virtual environment created from a non-native slice of a macOS universal binary runs
+        // as that slice.
+        if interpreter.macos_universal2 && interpreter.is_virtualenv() {
+            if let Some(arch) =
+                PyVenvConfiguration::parse(interpreter.sys_prefix.join("pyvenv.cfg"))
+                    .ok()
+                    .and_then(|cfg| cfg.arch())
+            {
+                return Ok(interpreter.with_arch_slice(arch));
+            }
+        }
+
+        Ok(interpreter)
+    }
+
+    /// Create an [`Interpreter`] from the output of the query script.
+    fn from_info(info: InterpreterInfo, real_executable: PathBuf) -> Self {
+        Self {
             platform: info.platform,
             markers: Box::new(info.markers),
             scheme: info.scheme,
@@ -82,6 +134,11 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            soabi: info.soabi,
+            ext_suffix: info.ext_suffix,
+            libdir: info.libdir,
+            macos_universal2: info.macos_universal2,
+            arch_slice: None,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -92,8 +149,10 @@ impl Interpreter {
             tags: OnceLock::new(),
             target: None,
             prefix: None,
-            real_executable: executable.as_ref().to_path_buf(),
-        })
+            real_executable,
+            query_env: info.query_env,
+            extensions: info.extensions,
+        }
     }
 
     /// Return a new [`Interpreter`] with the given virtual environment root.
@@ -179,6 +238,34 @@ impl Interpreter {
         Ok(base_python)
     }
 
+    /// Resolve the base interpreter; that is, the interpreter that isn't a virtual environment,
+    /// and from which this interpreter's virtual environment was (transitively) created.
+    ///
+    /// Unlike [`Interpreter::find_base_python`], which returns the executable of the immediate
+    /// base, this routine follows chains of virtual environments created from other virtual
+    /// environments, and queries the resulting interpreter, such that its metadata (e.g., its
+    /// `sys.prefix` and include directories) describes the base installation itself.
+    ///
+    /// Returns a clone of this interpreter if it isn't a virtual environment.
+    pub fn resolved_base(&self, cache: &Cache) -> Result<Self, Error> {
+        let mut interpreter = self.clone();
+        let mut visited = vec![interpreter.sys_executable().to_path_buf()];
+        while interpreter.is_virtualenv() {
+            let base = interpreter.find_base_python()?;
+            if visited.contains(&base) {
+                return Err(Error::BaseInterpreterCycle(base));
+            }
+            debug!(
+                "Resolving base interpreter of `{}` to: {}",
+                interpreter.sys_executable().user_display(),
+                base.user_display()
+            );
+            interpreter = Self::query(&base, cache)?;
+            visited.push(base);
+        }
+        Ok(interpreter)
+    }
+
     /// Returns the path to the Python virtual environment.
     #[inline]
     pub fn platform(&self) -> &Platform {
@@ -222,6 +309,100 @@ impl Interpreter {
         Arch::from(&self.platform().arch())
     }
 
+    /// Returns `true` if the interpreter is a macOS universal binary, with both an `aarch64` and
+    /// an `x86_64` slice.
+    pub fn is_macos_universal2(&self) -> bool {
+        self.macos_universal2
+    }
+
+    /// Return the architectures the interpreter can run as.
+    ///
+    /// A macOS universal binary can run as either of its slices; the native slice is listed
+    /// first. Any other interpreter, including a virtual environment, which runs as the slice it
+    /// was created with, can only run as the architecture reported by its platform tags.
+    pub fn architectures(&self) -> Vec<Arch> {
+        let native = self.platform.arch();
+        if !self.macos_universal2 || self.is_virtualenv() {
+            return vec![Arch::from(&native)];
+        }
+        std::iter::once(native)
+            .chain(
+                UNIVERSAL2_SLICES
+                    .into_iter()
+                    .filter(|slice| *slice != native),
+            )
+            .map(|slice| Arch::from(&slice))
+            .collect()
+    }
+
+    /// Return a new [`Interpreter`] that runs as the given slice of a macOS universal binary,
+    /// e.g., `x86_64` under Rosetta on Apple Silicon.
+    ///
+    /// The platform tags and markers are adjusted to match the slice. If the interpreter is not
+    /// a universal binary, or `arch` is already the architecture of the interpreter, the
+    /// interpreter is returned unchanged.
+    #[must_use]
+    pub fn with_arch_slice(self, arch: Arch) -> Self {
+        if !self.macos_universal2 || arch == self.arch() {
+            return self;
+        }
+        let Some(slice) = UNIVERSAL2_SLICES
+            .into_iter()
+            .find(|slice| Arch::from(slice) == arch)
+        else {
+            return self;
+        };
+        debug!(
+            "Using the `{arch}` slice of the universal Python executable at `{}`",
+            self.sys_executable.user_display()
+        );
+        Self {
+            platform: Platform::new(self.platform.os().clone(), slice),
+            markers: Box::new(
+                (*self.markers)
+                    .clone()
+                    .with_platform_machine(macos_arch_name(slice)),
+            ),
+            tags: OnceLock::new(),
+            arch_slice: Some(arch),
+            ..self
+        }
+    }
+
+    /// Return the slice of a macOS universal binary that the interpreter was selected to run as,
+    /// if it differs from the native slice.
+    ///
+    /// Such an interpreter must be run with `arch`, e.g., `arch -x86_64 python`; see
+    /// [`Interpreter::arch_command`].
+    pub fn arch_slice(&self) -> Option<Arch> {
+        self.arch_slice
+    }
+
+    /// Return the program and leading arguments with which to run the Python executable, i.e.,
+    /// `arch -x86_64 python` if a non-native slice of a macOS universal binary was selected, and
+    /// `python` otherwise.
+    pub fn arch_command(&self) -> (PathBuf, Vec<OsString>) {
+        self.arch_command_for(&self.sys_executable)
+    }
+
+    /// Return the program and leading arguments with which to run the given program, e.g., an
+    /// entrypoint of the environment, as the selected slice of a macOS universal binary.
+    ///
+    /// Processes run under `arch` prefer the same slice when launching universal binaries, so
+    /// the interpreter of a script is run as the selected slice, too.
+    pub fn arch_command_for(&self, program: &Path) -> (PathBuf, Vec<OsString>) {
+        match self.arch_slice {
+            Some(_) => (
+                PathBuf::from("/usr/bin/arch"),
+                vec![
+                    OsString::from(format!("-{}", macos_arch_name(self.platform.arch()))),
+                    program.as_os_str().to_os_string(),
+                ],
+            ),
+            None => (program.to_path_buf(), Vec::new()),
+        }
+    }
+
     /// Return the [`Libc`] reported by the interpreter platform tags.
     pub fn libc(&self) -> Libc {
         Libc::from(self.platform().os())
@@ -266,10 +447,11 @@ impl Interpreter {
         self.prefix.is_some()
     }
 
-    /// Returns `true` if this interpreter is managed by uv.
+    /// Returns `true` if this interpreter is managed by uv, i.e., if it's in the user-level
+    /// directory for managed Python installations or in the given project-level directory.
     ///
     /// Returns `false` if we cannot determine the path of the uv managed Python interpreters.
-    pub fn is_managed(&self) -> bool {
+    pub fn is_managed(&self, python_install_dir: Option<&Path>) -> bool {
         if let Ok(test_managed) =
             std::env::var(uv_static::EnvVars::UV_INTERNAL__TEST_PYTHON_MANAGED)
         {
@@ -287,11 +469,13 @@ impl Interpreter {
             });
         }
 
-        let Ok(installations) = ManagedPythonInstallations::from_settings(None) else {
+        let Ok(installations) = ManagedPythonInstallations::discoverable(python_install_dir) else {
             return false;
         };
 
-        self.sys_base_prefix.starts_with(installations.root())
+        installations
+            .iter()
+            .any(|installations| self.sys_base_prefix.starts_with(installations.root()))
     }
 
     /// Returns `Some` if the environment is externally managed, optionally including an error
@@ -437,6 +621,45 @@ impl Interpreter {
         &self.real_executable
     }
 
+    /// Return a new [`Interpreter`] for an alias of the queried executable, e.g., a symbolic link
+    /// to it, that is known to resolve to the same interpreter.
+    #[must_use]
+    pub(crate) fn with_real_executable(self, real_executable: PathBuf) -> Self {
+        Self {
+            real_executable,
+            ..self
+        }
+    }
+
+    /// Return the [`InterpreterIdentity`] of this interpreter, to detect aliases of the same
+    /// interpreter, e.g., symbolic links or hard links to the same executable.
+    pub fn identity(&self) -> io::Result<InterpreterIdentity> {
+        Ok(InterpreterIdentity {
+            file: same_file::Handle::from_path(&self.real_executable)?,
+            sys_prefix: self.sys_prefix.clone(),
+        })
+    }
+
+    /// Return the environment variables from `python-query-env` that were applied when querying
+    /// the interpreter, and should be applied whenever it's invoked.
+    pub fn query_env(&self) -> &BTreeMap<String, String> {
+        &self.query_env
+    }
+
+    /// Return the results of the probes registered via
+    /// [`InterpreterSettings::with_query_probes`](uv_cache::InterpreterSettings::with_query_probes),
+    /// keyed by probe name.
+    ///
+    /// Probes that failed are absent.
+    pub fn extensions(&self) -> &BTreeMap<String, serde_json::Value> {
+        &self.extensions
+    }
+
+    /// Return the result of the probe with the given name, if any.
+    pub fn extension(&self, name: &str) -> Option<&serde_json::Value> {
+        self.extensions.get(name)
+    }
+
     /// Return the `sys.path` for this Python interpreter.
     pub fn sys_path(&self) -> &[PathBuf] {
         &self.sys_path
@@ -507,6 +730,51 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return the ABI tag of the interpreter, as specified by the sysconfig var `SOABI`, e.g.,
+    /// `cpython-312-x86_64-linux-gnu`.
+    pub fn soabi(&self) -> Option<&str> {
+        self.soabi.as_deref()
+    }
+
+    /// Return the suffix of extension modules built for the interpreter, as specified by the
+    /// sysconfig var `EXT_SUFFIX`, e.g., `.cpython-312-x86_64-linux-gnu.so`.
+    pub fn ext_suffix(&self) -> Option<&str> {
+        self.ext_suffix.as_deref()
+    }
+
+    /// Return the directory containing the interpreter's shared library and static archive, as
+    /// specified by the sysconfig var `LIBDIR`, e.g., `/usr/lib` or `<prefix>/lib`.
+    ///
+    /// Not set on Windows, where the import libraries are stored in `<prefix>/libs` instead.
+    pub fn libdir(&self) -> Option<&Path> {
+        self.libdir.as_deref()
+    }
+
+    /// Return the [`Libc`] the interpreter was built against, as encoded in its ABI tag or
+    /// extension module suffix, e.g., `musl` for `cpython-312-x86_64-linux-musl`.
+    ///
+    /// Unlike [`Interpreter::libc`], which is derived from the platform tags, this distinguishes
+    /// between, e.g., `gnueabi` and `gnueabihf`. Returns `None` if the ABI doesn't encode a libc,
+    /// as on macOS and Windows.
+    pub fn abi_libc(&self) -> Option<Libc> {
+        self.soabi
+            .as_deref()
+            .and_then(libc_from_abi)
+            .or_else(|| self.ext_suffix.as_deref().and_then(libc_from_abi))
+    }
+
+    /// Return the optional standard library features that are available in the interpreter, e.g.,
+    /// `ssl` if the `_ssl` extension module can be imported.
+    ///
+    /// Returns `None` unless the interpreter was queried with the [`QueryProbe::features`] probe
+    /// registered, e.g., via [`QueryProbe::with_features`].
+    pub fn features(&self) -> Option<PythonFeatures> {
+        let features = self.extension(QueryProbe::FEATURES)?;
+        serde_json::from_value::<Vec<String>>(features.clone())
+            .ok()
+            .map(PythonFeatures::from)
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -535,7 +803,8 @@ impl Interpreter {
     // set `PYTHON_BUILD_STANDALONE=1`.`
     #[cfg(windows)]
     pub fn is_standalone(&self) -> bool {
-        self.standalone || (self.is_managed() && self.markers().implementation_name() == "cpython")
+        self.standalone
+            || (self.is_managed(None) && self.markers().implementation_name() == "cpython")
     }
 
     /// Return the [`Layout`] environment used to install wheels into this interpreter.
@@ -638,26 +907,37 @@ impl Interpreter {
 
     /// Grab a file lock for the environment to prevent concurrent writes across processes.
     pub async fn lock(&self) -> Result<LockedFile, io::Error> {
+        let (path, resource) = self.lock_path();
+        LockedFile::acquire(path, resource).await
+    }
+
+    /// Return the path to the file lock for the environment, along with a description of the
+    /// locked resource.
+    pub fn lock_path(&self) -> (PathBuf, String) {
         if let Some(target) = self.target() {
             // If we're installing into a `--target`, use a target-specific lockfile.
-            LockedFile::acquire(target.root().join(".lock"), target.root().user_display()).await
+            (
+                target.root().join(".lock"),
+                target.root().user_display().to_string(),
+            )
         } else if let Some(prefix) = self.prefix() {
             // Likewise, if we're installing into a `--prefix`, use a prefix-specific lockfile.
-            LockedFile::acquire(prefix.root().join(".lock"), prefix.root().user_display()).await
+            (
+                prefix.root().join(".lock"),
+                prefix.root().user_display().to_string(),
+            )
         } else if self.is_virtualenv() {
             // If the environment a virtualenv, use a virtualenv-specific lockfile.
-            LockedFile::acquire(
+            (
                 self.sys_prefix.join(".lock"),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         } else {
             // Otherwise, use a global lockfile.
-            LockedFile::acquire(
+            (
                 env::temp_dir().join(format!("uv-{}.lock", cache_digest(&self.sys_executable))),
-                self.sys_prefix.user_display(),
+                self.sys_prefix.user_display().to_string(),
             )
-            .await
         }
     }
 }
@@ -707,6 +987,8 @@ pub struct UnexpectedResponseError {
     pub(super) stdout: String,
     pub(super) stderr: String,
     pub(super) path: PathBuf,
+    /// The path to the recorded query failure, if any.
+    pub(super) transcript: Option<PathBuf>,
 }
 
 impl Display for UnexpectedResponseError {
@@ -734,16 +1016,42 @@ impl Display for UnexpectedResponseError {
             writeln!(f)?;
         }
 
+        write_transcript_hint(f, self.transcript.as_deref(), non_empty)?;
+
         Ok(())
     }
 }
 
+/// Reference the recorded query failure, if any, in an error message.
+///
+/// If the output streams were written, the message already ends with a newline.
+fn write_transcript_hint(
+    f: &mut Formatter<'_>,
+    transcript: Option<&Path>,
+    trailing_newline: bool,
+) -> std::fmt::Result {
+    if let Some(transcript) = transcript {
+        write!(
+            f,
+            "{}{}{} The query output was recorded at `{}`; use `{}` to review failed queries",
+            if trailing_newline { "\n" } else { "\n\n" },
+            "hint".bold().cyan(),
+            ":".bold(),
+            transcript.user_display(),
+            "uv python find --show-failures".green(),
+        )?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Error)]
 pub struct StatusCodeError {
     pub(super) code: ExitStatus,
     pub(super) stdout: String,
     pub(super) stderr: String,
     pub(super) path: PathBuf,
+    /// The path to the recorded query failure, if any.
+    pub(super) transcript: Option<PathBuf>,
 }
 
 impl Display for StatusCodeError {
@@ -771,6 +1079,8 @@ impl Display for StatusCodeError {
             writeln!(f)?;
         }
 
+        write_transcript_hint(f, self.transcript.as_deref(), non_empty)?;
+
         Ok(())
     }
 }
@@ -800,6 +1110,25 @@ pub enum Error {
     UnexpectedResponse(UnexpectedResponseError),
     #[error("{0}")]
     StatusCode(StatusCodeError),
+    #[error(
+        "Querying Python at `{}` did not complete within {}s (set `python-query-limits.timeout` or `{}` to change the timeout)",
+        path.user_display(),
+        timeout.as_secs(),
+        uv_static::EnvVars::UV_PYTHON_QUERY_TIMEOUT
+    )]
+    QueryTimeout { path: PathBuf, timeout: Duration },
+    #[error(
+        "Skipping Python at `{}` for {}s after {failures} consecutive failed queries ({reason}); set `{}=0` to query it anyway",
+        path.user_display(),
+        retry.as_secs(),
+        uv_static::EnvVars::UV_PYTHON_QUARANTINE_THRESHOLD
+    )]
+    Quarantined {
+        path: PathBuf,
+        failures: u64,
+        retry: Duration,
+        reason: String,
+    },
     #[error("Can't use Python at `{path}`")]
     QueryScript {
         #[source]
@@ -808,6 +1137,50 @@ pub enum Error {
     },
     #[error("Failed to write to cache")]
     Encode(#[from] rmp_serde::encode::Error),
+    #[error("Failed to read mocked interpreter metadata from `{}`", path.user_display())]
+    MockInterpreter {
+        path: PathBuf,
+        #[source]
+        err: serde_json::Error,
+    },
+    #[error("Failed to resolve the base interpreter: `{}` is a virtual environment with itself as its base", _0.user_display())]
+    BaseInterpreterCycle(PathBuf),
+    #[cfg(any(test, feature = "embedded"))]
+    #[error("The embedded Python runtime is not initialized")]
+    EmbeddedNotInitialized,
+    #[cfg(any(test, feature = "embedded"))]
+    #[error("Failed to query the embedded Python runtime: {0}")]
+    EmbeddedQuery(String),
+    #[cfg(any(test, feature = "embedded"))]
+    #[error("Can't use the embedded Python runtime")]
+    EmbeddedQueryScript(#[source] InterpreterInfoError),
+}
+
+impl Error {
+    /// The reason to record if the error counts towards quarantining the interpreter, i.e., if the
+    /// interpreter could be run but failed to report its metadata.
+    fn quarantine_reason(&self) -> Option<String> {
+        match self {
+            Self::UnexpectedResponse(err) => {
+                Some(format!("Returned an invalid response: {}", err.err))
+            }
+            Self::StatusCode(err) => Some(format!("Exited with {}", err.code)),
+            Self::QueryScript { err, .. } => Some(err.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The identity of a Python interpreter, shared by all the paths that invoke it.
+///
+/// Two executables are the same interpreter if they're the same file, i.e., the same device and
+/// inode on Unix or the same volume and file index on Windows, and report the same `sys.prefix`.
+/// The latter distinguishes (e.g.) a virtual environment from its base interpreter, as the
+/// environment's executable is often a link to the base executable.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct InterpreterIdentity {
+    file: same_file::Handle,
+    sys_prefix: PathBuf,
 }
 
 #[derive(Debug, Error)]
@@ -870,6 +1243,14 @@ pub enum InterpreterInfoError {
     EmscriptenNotPyodide,
 }
 
+/// Parse the [`Libc`] from the multiarch tuple in an ABI tag or extension module suffix, e.g.,
+/// `musl` from `cpython-312-x86_64-linux-musl` or `.pypy310-pp73-x86_64-linux-gnu.so`.
+fn libc_from_abi(abi: &str) -> Option<Libc> {
+    let (_, libc) = abi.rsplit_once("-linux-")?;
+    let libc = libc.split('.').next().unwrap_or(libc);
+    libc.parse().ok()
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct InterpreterInfo {
     platform: Platform,
@@ -888,27 +1269,53 @@ struct InterpreterInfo {
     standalone: bool,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    #[serde(default)]
+    soabi: Option<String>,
+    #[serde(default)]
+    ext_suffix: Option<String>,
+    #[serde(default)]
+    libdir: Option<PathBuf>,
+    #[serde(default)]
+    macos_universal2: bool,
+    /// The environment variables from `python-query-env` that were applied to the query.
+    #[serde(default)]
+    query_env: BTreeMap<String, String>,
+    /// The results of the probes registered on the [`uv_cache::InterpreterSettings`].
+    #[serde(default)]
+    extensions: BTreeMap<String, serde_json::Value>,
 }
 
 impl InterpreterInfo {
     /// Return the resolved [`InterpreterInfo`] for the given Python executable.
-    pub(crate) fn query(interpreter: &Path, cache: &Cache) -> Result<Self, Error> {
+    pub(crate) fn query(
+        interpreter: &Path,
+        query_env: &BTreeMap<String, String>,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
         let tempdir = tempfile::tempdir_in(cache.root())?;
-        Self::setup_python_query_files(tempdir.path())?;
+        Self::setup_python_query_files(
+            tempdir.path(),
+            cache.interpreter_settings().query_probes(),
+        )?;
 
         // Sanitize the path by (1) running under isolated mode (`-I`) to ignore any site packages
         // modifications, and then (2) adding the path containing our query script to the front of
         // `sys.path` so that we can import it.
-        let script = format!(
-            r#"import sys; sys.path = ["{}"] + sys.path; from python.get_interpreter_info import main; main()"#,
-            tempdir.path().escape_for_python()
-        );
-        let output = Command::new(interpreter)
-            .arg("-I") // Isolated mode.
-            .arg("-B") // Don't write bytecode.
-            .arg("-c")
-            .arg(script)
-            .output()
+        //
+        // The path is passed as an argument, rather than embedded in the script, such that Python
+        // decodes it like any other path, even if it contains bytes that aren't valid UTF-8.
+        let script = "import sys; sys.path = [sys.argv[1]] + sys.path; from python.get_interpreter_info import main; main()";
+        let limits = QueryLimits::from_settings(cache.interpreter_settings());
+        let output = limits
+            .output(
+                Command::new(interpreter)
+                    .arg("-I") // Isolated mode.
+                    .arg("-B") // Don't write bytecode.
+                    .arg("-c")
+                    .arg(script)
+                    .arg(tempdir.path())
+                    .envs(query_env),
+            )
             .map_err(|err| {
                 if err.kind() == io::ErrorKind::NotFound {
                     return Error::NotFound(interpreter.to_path_buf());
@@ -930,57 +1337,122 @@ impl InterpreterInfo {
                 }
             })?;
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let Some(output) = output else {
+            let timeout = limits.timeout().unwrap_or_default();
+            QueryFailure::record(
+                cache,
+                interpreter,
+                format!("Timed out after {}s", timeout.as_secs()),
+                "",
+                "",
+            );
+            return Err(Error::QueryTimeout {
+                path: interpreter.to_path_buf(),
+                timeout,
+            });
+        };
+
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        let record =
+            |reason: String| QueryFailure::record(cache, interpreter, reason, &stdout, &stderr);
 
+        if !output.status.success() {
             // If the Python version is too old, we may not even be able to invoke the query script
             if stderr.contains("Unknown option: -I") {
+                record(InterpreterInfoError::UnsupportedPython.to_string());
                 return Err(Error::QueryScript {
                     err: InterpreterInfoError::UnsupportedPython,
                     path: interpreter.to_path_buf(),
                 });
             }
 
+            let transcript = record(format!("Exited with {}", output.status));
             return Err(Error::StatusCode(StatusCodeError {
                 code: output.status,
+                stdout,
                 stderr,
-                stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
                 path: interpreter.to_path_buf(),
+                transcript,
             }));
         }
 
-        let result: InterpreterInfoResult =
-            serde_json::from_slice(&output.stdout).map_err(|err| {
-                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-
+        let result: InterpreterInfoResult = match serde_json::from_slice(&output.stdout) {
+            Ok(result) => result,
+            Err(err) => {
                 // If the Python version is too old, we may not even be able to invoke the query script
                 if stderr.contains("Unknown option: -I") {
-                    Error::QueryScript {
+                    record(InterpreterInfoError::UnsupportedPython.to_string());
+                    return Err(Error::QueryScript {
                         err: InterpreterInfoError::UnsupportedPython,
                         path: interpreter.to_path_buf(),
-                    }
-                } else {
-                    Error::UnexpectedResponse(UnexpectedResponseError {
-                        err,
-                        stdout: String::from_utf8_lossy(&output.stdout).trim().to_string(),
-                        stderr,
-                        path: interpreter.to_path_buf(),
-                    })
+                    });
                 }
-            })?;
+
+                let transcript = record(format!("Returned an invalid response: {err}"));
+                return Err(Error::UnexpectedResponse(UnexpectedResponseError {
+                    err,
+                    stdout,
+                    stderr,
+                    path: interpreter.to_path_buf(),
+                    transcript,
+                }));
+            }
+        };
 
         match result {
+            InterpreterInfoResult::Error(err) => {
+                record(err.to_string());
+                Err(Error::QueryScript {
+                    err,
+                    path: interpreter.to_path_buf(),
+                })
+            }
+            InterpreterInfoResult::Success(data) => Ok(*data),
+        }
+    }
+
+    /// Return the mocked [`InterpreterInfo`] for the given (absolute) Python executable, if
+    /// `UV_MOCK_INTERPRETER_JSON` is set and includes an entry for it.
+    ///
+    /// The file maps executable paths to the output of the interpreter query script, e.g.,
+    /// `{"/usr/bin/python3": {"result": "success", ...}}`, such that tests can simulate arbitrary
+    /// interpreters and platforms without running them. Executables without an entry are queried
+    /// as usual.
+    fn query_mocked(executable: &Path) -> Result<Option<Result<Self, Error>>, Error> {
+        let Some(path) = env::var_os(uv_static::EnvVars::UV_MOCK_INTERPRETER_JSON) else {
+            return Ok(None);
+        };
+        let path = PathBuf::from(path);
+        let contents = fs::read(&path)?;
+        let mut mocks: BTreeMap<PathBuf, InterpreterInfoResult> = serde_json::from_slice(&contents)
+            .map_err(|err| Error::MockInterpreter {
+                path: path.clone(),
+                err,
+            })?;
+        let Some(result) = mocks.remove(executable) else {
+            return Ok(None);
+        };
+        trace!(
+            "Using mocked interpreter info from `{}` for: {}",
+            path.user_display(),
+            executable.user_display()
+        );
+        Ok(Some(match result {
             InterpreterInfoResult::Error(err) => Err(Error::QueryScript {
                 err,
-                path: interpreter.to_path_buf(),
+                path: executable.to_path_buf(),
             }),
             InterpreterInfoResult::Success(data) => Ok(*data),
-        }
+        }))
     }
 
     /// Duplicate the directory structure we have in `../python` into a tempdir, so we can run
     /// the Python probing scripts with `python -m python.get_interpreter_info` from that tempdir.
-    fn setup_python_query_files(root: &Path) -> Result<(), Error> {
+    fn setup_python_query_files(
+        root: &Path,
+        probes: &BTreeMap<String, String>,
+    ) -> Result<(), Error> {
         let python_dir = root.join("python");
         fs_err::create_dir(&python_dir)?;
         fs_err::write(
@@ -991,6 +1463,12 @@ impl InterpreterInfo {
             python_dir.join("__init__.py"),
             include_str!("../python/__init__.py"),
         )?;
+        if !probes.is_empty() {
+            fs_err::write(
+                python_dir.join("probes.json"),
+                serde_json::to_vec(probes).map_err(io::Error::other)?,
+            )?;
+        }
         let packaging_dir = python_dir.join("packaging");
         fs_err::create_dir(&packaging_dir)?;
         fs_err::write(
@@ -1020,6 +1498,11 @@ impl InterpreterInfo {
     pub(crate) fn query_cached(executable: &Path, cache: &Cache) -> Result<Self, Error> {
         let absolute = std::path::absolute(executable)?;
 
+        // If the interpreter is mocked, skip the query (and the cache) entirely.
+        if let Some(result) = Self::query_mocked(&absolute)? {
+            return result;
+        }
+
         // Provide a better error message if the link is broken or the file does not exist. Since
         // `canonicalize_executable` does not resolve the file on Windows, we must re-use this logic
         // for the subsequent metadata read as we may not have actually resolved the path.
@@ -1045,6 +1528,11 @@ impl InterpreterInfo {
 
         let canonical = canonicalize_executable(&absolute).map_err(handle_io_error)?;
 
+        // Apply any user-configured environment variables for the executable, e.g., for
+        // interpreters that can't start without an `LD_LIBRARY_PATH`.
+        let query_env = query_env(cache.interpreter_settings(), &absolute, &canonical);
+        let probes = cache.interpreter_settings().query_probes();
+
         let cache_entry = cache.entry(
             CacheBucket::Interpreter,
             // Shard interpreter metadata by host architecture, operating system, and version, to
@@ -1060,8 +1548,18 @@ impl InterpreterInfo {
             // path in the cache entry as well, otherwise we can have cache collisions if an
             // absolute path refers to different interpreters with matching ctimes, e.g., if you
             // have a `.venv/bin/python` pointing to both Python 3.12 and Python 3.13 that were
-            // modified at the same time.
-            format!("{}.msgpack", cache_digest(&(&absolute, &canonical))),
+            // modified at the same time. The configured environment variables may also change the
+            // reported values, so they're included too, if any, as are any registered probes.
+            format!(
+                "{}.msgpack",
+                if !probes.is_empty() {
+                    cache_digest(&(&absolute, &canonical, &query_env, probes))
+                } else if query_env.is_empty() {
+                    cache_digest(&(&absolute, &canonical))
+                } else {
+                    cache_digest(&(&absolute, &canonical, &query_env))
+                }
+            ),
         );
 
         // We check the timestamp of the canonicalized executable to check if an underlying
@@ -1101,12 +1599,33 @@ impl InterpreterInfo {
             }
         }
 
+        // Skip the interpreter if its recent queries failed, rather than failing again.
+        if let Some(quarantine) = Quarantine::check(cache, &absolute, &canonical, modified) {
+            return Err(Error::Quarantined {
+                path: executable.to_path_buf(),
+                failures: quarantine.failures,
+                retry: quarantine.remaining().unwrap_or_default(),
+                reason: quarantine.reason,
+            });
+        }
+
         // Otherwise, run the Python script.
         trace!(
             "Querying interpreter executable at {}",
             executable.display()
         );
-        let info = Self::query(executable, cache)?;
+        let info = match Self::query(executable, &query_env, cache) {
+            Ok(info) => {
+                Quarantine::clear(cache, &absolute, &canonical);
+                info
+            }
+            Err(err) => {
+                if let Some(reason) = err.quarantine_reason() {
+                    Quarantine::record_failure(cache, &absolute, &canonical, modified, reason);
+                }
+                return Err(err);
+            }
+        };
 
         // If `executable` is a pyenv shim, a bash script that redirects to the activated
         // python executable at another path, we're not allowed to cache the interpreter info.
@@ -1215,16 +1734,19 @@ fn find_base_python(
 #[cfg(unix)]
 #[cfg(test)]
 mod tests {
+    use std::ffi::OsString;
+    use std::path::Path;
     use std::str::FromStr;
 
     use fs_err as fs;
     use indoc::{formatdoc, indoc};
     use tempfile::tempdir;
 
-    use uv_cache::Cache;
+    use uv_cache::{Cache, InterpreterSettings};
     use uv_pep440::Version;
 
-    use crate::Interpreter;
+    use crate::features::{PythonFeature, PythonFeatures};
+    use crate::{Interpreter, QueryProbe};
 
     #[test]
     fn test_cache_invalidation() {
@@ -1322,4 +1844,408 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn test_extensions() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = indoc! {r##"
+        {
+            "result": "success",
+            "platform": {
+                "os": {
+                    "name": "manylinux",
+                    "major": 2,
+                    "minor": 38
+                },
+                "arch": "x86_64"
+            },
+            "manylinux_compatible": false,
+            "standalone": false,
+            "markers": {
+                "implementation_name": "cpython",
+                "implementation_version": "3.12.0",
+                "os_name": "posix",
+                "platform_machine": "x86_64",
+                "platform_python_implementation": "CPython",
+                "platform_release": "6.5.0-13-generic",
+                "platform_system": "Linux",
+                "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                "python_full_version": "3.12.0",
+                "python_version": "3.12",
+                "sys_platform": "linux"
+            },
+            "sys_base_exec_prefix": "/home/ferris/.pyenv/versions/3.12.0",
+            "sys_base_prefix": "/home/ferris/.pyenv/versions/3.12.0",
+            "sys_prefix": "/home/ferris/projects/uv/.venv",
+            "sys_executable": "/home/ferris/projects/uv/.venv/bin/python",
+            "sys_path": [
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/lib/python3.12",
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages"
+            ],
+            "site_packages": [
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages"
+            ],
+            "stdlib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12",
+            "scheme": {
+                "data": "/home/ferris/.pyenv/versions/3.12.0",
+                "include": "/home/ferris/.pyenv/versions/3.12.0/include",
+                "platlib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages",
+                "purelib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages",
+                "scripts": "/home/ferris/.pyenv/versions/3.12.0/bin"
+            },
+            "virtualenv": {
+                "data": "",
+                "include": "include",
+                "platlib": "lib/python3.12/site-packages",
+                "purelib": "lib/python3.12/site-packages",
+                "scripts": "bin"
+            },
+            "pointer_size": "64",
+            "gil_disabled": true,
+            "extensions": {
+                "glibc_version": "2.38",
+                "cuda_driver_version": null,
+                "features": ["ssl", "zlib"]
+            }
+        }
+    "##};
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo '{json}'
+        "},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(
+            interpreter.extension("glibc_version"),
+            Some(&serde_json::Value::from("2.38"))
+        );
+        assert_eq!(
+            interpreter.extension("cuda_driver_version"),
+            Some(&serde_json::Value::Null)
+        );
+        assert_eq!(interpreter.extension("missing"), None);
+        assert_eq!(
+            interpreter.features(),
+            Some(PythonFeatures::only([
+                PythonFeature::Ssl,
+                PythonFeature::Zlib
+            ]))
+        );
+
+        // The results are cached alongside the standard metadata.
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(interpreter.extensions().len(), 3);
+    }
+
+    #[test]
+    fn test_extensions_cache_key() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let queries = mock_dir.path().join("queries");
+        let json = indoc! {r##"
+        {
+            "result": "success",
+            "platform": {
+                "os": {
+                    "name": "manylinux",
+                    "major": 2,
+                    "minor": 38
+                },
+                "arch": "x86_64"
+            },
+            "manylinux_compatible": false,
+            "standalone": false,
+            "markers": {
+                "implementation_name": "cpython",
+                "implementation_version": "3.12.0",
+                "os_name": "posix",
+                "platform_machine": "x86_64",
+                "platform_python_implementation": "CPython",
+                "platform_release": "6.5.0-13-generic",
+                "platform_system": "Linux",
+                "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                "python_full_version": "3.12.0",
+                "python_version": "3.12",
+                "sys_platform": "linux"
+            },
+            "sys_base_exec_prefix": "/home/ferris/.pyenv/versions/3.12.0",
+            "sys_base_prefix": "/home/ferris/.pyenv/versions/3.12.0",
+            "sys_prefix": "/home/ferris/.pyenv/versions/3.12.0",
+            "sys_executable": "[EXECUTABLE]",
+            "sys_path": [
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/lib/python3.12",
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages"
+            ],
+            "site_packages": [
+                "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages"
+            ],
+            "stdlib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12",
+            "scheme": {
+                "data": "/home/ferris/.pyenv/versions/3.12.0",
+                "include": "/home/ferris/.pyenv/versions/3.12.0/include",
+                "platlib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages",
+                "purelib": "/home/ferris/.pyenv/versions/3.12.0/lib/python3.12/site-packages",
+                "scripts": "/home/ferris/.pyenv/versions/3.12.0/bin"
+            },
+            "virtualenv": {
+                "data": "",
+                "include": "include",
+                "platlib": "lib/python3.12/site-packages",
+                "purelib": "lib/python3.12/site-packages",
+                "scripts": "bin"
+            },
+            "pointer_size": "64",
+            "gil_disabled": false
+        }
+    "##};
+
+        // The metadata is only cached if the executable reports itself as `sys.executable`.
+        let json = json.replace("[EXECUTABLE]", &mocked_interpreter.display().to_string());
+
+        // Record each query, such that cache hits can be told apart from queries.
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo query >> '{queries}'
+        echo '{json}'
+        ", queries = queries.display()},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+        let count = || fs::read_to_string(&queries).unwrap().lines().count();
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(count(), 1);
+
+        // Registering probes on the cache invalidates the cached metadata.
+        let cache = cache.with_interpreter_settings(
+            InterpreterSettings::default().with_query_probes([QueryProbe::glibc_version().into()]),
+        );
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(count(), 2);
+
+        // As does changing the set of probes.
+        let cache = cache.with_interpreter_settings(
+            InterpreterSettings::default()
+                .with_query_probes([QueryProbe::cuda_driver_version().into()]),
+        );
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(count(), 3);
+
+        // Requesting the optional features adds a probe, without dropping the existing ones.
+        let cache = QueryProbe::with_features(&cache);
+        let probes = cache.interpreter_settings().query_probes();
+        assert!(probes.contains_key("cuda_driver_version"));
+        assert!(probes.contains_key(QueryProbe::FEATURES));
+        Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(count(), 4);
+    }
+
+    /// The query output of a macOS universal2 framework build of CPython on Apple Silicon.
+    const MACOS_UNIVERSAL2_INFO: &str = indoc! {r##"
+        {
+            "result": "success",
+            "platform": {
+                "os": {
+                    "name": "macos",
+                    "major": 14,
+                    "minor": 0
+                },
+                "arch": "aarch64"
+            },
+            "macos_universal2": true,
+            "manylinux_compatible": false,
+            "standalone": false,
+            "markers": {
+                "implementation_name": "cpython",
+                "implementation_version": "3.12.0",
+                "os_name": "posix",
+                "platform_machine": "arm64",
+                "platform_python_implementation": "CPython",
+                "platform_release": "23.0.0",
+                "platform_system": "Darwin",
+                "platform_version": "Darwin Kernel Version 23.0.0",
+                "python_full_version": "3.12.0",
+                "python_version": "3.12",
+                "sys_platform": "darwin"
+            },
+            "sys_base_exec_prefix": "/Library/Frameworks/Python.framework/Versions/3.12",
+            "sys_base_prefix": "/Library/Frameworks/Python.framework/Versions/3.12",
+            "sys_prefix": "/Library/Frameworks/Python.framework/Versions/3.12",
+            "sys_executable": "/Library/Frameworks/Python.framework/Versions/3.12/bin/python3",
+            "sys_path": [
+                "/Library/Frameworks/Python.framework/Versions/3.12/lib/python3.12"
+            ],
+            "site_packages": [
+                "/Library/Frameworks/Python.framework/Versions/3.12/lib/python3.12/site-packages"
+            ],
+            "stdlib": "/Library/Frameworks/Python.framework/Versions/3.12/lib/python3.12",
+            "scheme": {
+                "data": "/Library/Frameworks/Python.framework/Versions/3.12",
+                "include": "/Library/Frameworks/Python.framework/Versions/3.12/include",
+                "platlib": "/Library/Frameworks/Python.framework/Versions/3.12/lib/python3.12/site-packages",
+                "purelib": "/Library/Frameworks/Python.framework/Versions/3.12/lib/python3.12/site-packages",
+                "scripts": "/Library/Frameworks/Python.framework/Versions/3.12/bin"
+            },
+            "virtualenv": {
+                "data": "",
+                "include": "include",
+                "platlib": "lib/python3.12/site-packages",
+                "purelib": "lib/python3.12/site-packages",
+                "scripts": "bin"
+            },
+            "pointer_size": "64",
+            "gil_disabled": false
+        }
+    "##};
+
+    #[test]
+    fn test_macos_universal2_slice() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = MACOS_UNIVERSAL2_INFO;
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo '{json}'
+        "},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        let aarch64 = uv_platform::Arch::from(&uv_platform_tags::Arch::Aarch64);
+        let x86_64 = uv_platform::Arch::from(&uv_platform_tags::Arch::X86_64);
+        assert!(interpreter.is_macos_universal2());
+        assert_eq!(interpreter.architectures(), vec![aarch64, x86_64]);
+        assert_eq!(interpreter.arch_slice(), None);
+
+        // Selecting the native slice is a no-op.
+        let interpreter = interpreter.with_arch_slice(aarch64);
+        assert_eq!(interpreter.arch_slice(), None);
+
+        // Selecting the `x86_64` slice adjusts the markers and tags.
+        let interpreter = interpreter.with_arch_slice(x86_64);
+        assert_eq!(interpreter.arch(), x86_64);
+        assert_eq!(interpreter.arch_slice(), Some(x86_64));
+        assert_eq!(interpreter.markers().platform_machine(), "x86_64");
+        assert!(
+            interpreter
+                .tags()
+                .unwrap()
+                .platform_tag()
+                .is_some_and(|platform| platform.to_string().ends_with("_x86_64"))
+        );
+        let (program, args) = interpreter.arch_command();
+        assert_eq!(program, std::path::Path::new("/usr/bin/arch"));
+        assert_eq!(args[0], "-x86_64");
+    }
+
+    #[test]
+    fn test_macos_universal2_virtualenv_slice() {
+        let mock_dir = tempdir().unwrap();
+        let mocked_interpreter = mock_dir.path().join("python");
+        let venv = mock_dir.path().join("venv");
+        fs::create_dir_all(&venv).unwrap();
+        let json = MACOS_UNIVERSAL2_INFO.replace(
+            r#""sys_prefix": "/Library/Frameworks/Python.framework/Versions/3.12""#,
+            &format!(r#""sys_prefix": "{}""#, venv.display()),
+        );
+
+        let cache = Cache::temp().unwrap().init().unwrap();
+
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r"
+        #!/bin/sh
+        echo '{json}'
+        "},
+        )
+        .unwrap();
+
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+        let x86_64 = uv_platform::Arch::from(&uv_platform_tags::Arch::X86_64);
+
+        // Without a recorded slice, the environment runs as the native slice, only.
+        fs::write(venv.join("pyvenv.cfg"), "home = /usr/bin\n").unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert!(interpreter.is_virtualenv());
+        assert_eq!(interpreter.arch_slice(), None);
+        assert_eq!(interpreter.architectures(), vec![interpreter.arch()]);
+
+        // With a recorded slice, the environment runs as that slice.
+        fs::write(
+            venv.join("pyvenv.cfg"),
+            "home = /usr/bin\nuv-arch = x86_64\n",
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert_eq!(interpreter.arch_slice(), Some(x86_64));
+        assert_eq!(interpreter.architectures(), vec![x86_64]);
+        assert_eq!(interpreter.markers().platform_machine(), "x86_64");
+
+        // Entrypoints of the environment are run as the slice, too.
+        let (program, args) = interpreter.arch_command_for(Path::new("pytest"));
+        assert_eq!(program, Path::new("/usr/bin/arch"));
+        assert_eq!(
+            args,
+            vec![OsString::from("-x86_64"), OsString::from("pytest")]
+        );
+    }
+
+    #[test]
+    fn test_libc_from_abi() {
+        use uv_platform::Libc;
+
+        use super::libc_from_abi;
+
+        assert_eq!(
+            libc_from_abi("cpython-312-x86_64-linux-musl"),
+            Some(Libc::from_str("musl").unwrap())
+        );
+        assert_eq!(
+            libc_from_abi("cpython-313t-arm-linux-gnueabihf"),
+            Some(Libc::from_str("gnueabihf").unwrap())
+        );
+        assert_eq!(
+            libc_from_abi(".pypy310-pp73-x86_64-linux-gnu.so"),
+            Some(Libc::from_str("gnu").unwrap())
+        );
+        assert_eq!(libc_from_abi("cpython-312-darwin"), None);
+        assert_eq!(libc_from_abi(".cp312-win_amd64.pyd"), None);
+    }
 }
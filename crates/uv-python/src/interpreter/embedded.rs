@@ -0,0 +1,258 @@
+//! Query the Python runtime embedded in the current process via `libpython`.
+//!
+//! Applications that embed CPython (i.e., link against `libpython`) alongside uv can resolve
+//! against their own runtime, rather than a standalone executable. Instead of spawning a
+//! subprocess, the interpreter query script is run in-process via the C API, and its output is
+//! parsed exactly as if it had been printed by a subprocess.
+//!
+//! The `libpython` symbols are resolved when the embedding application is linked, so enabling the
+//! `embedded` feature requires the final binary to link against `libpython`.
+#![allow(unsafe_code)]
+
+use std::ffi::CString;
+#[cfg(feature = "embedded")]
+use std::ffi::{c_char, c_int, c_void};
+use std::path::Path;
+
+use serde::Deserialize;
+#[cfg(feature = "embedded")]
+use tracing::debug;
+
+#[cfg(feature = "embedded")]
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+#[cfg(feature = "embedded")]
+use crate::interpreter::Interpreter;
+use crate::interpreter::{Error, InterpreterInfo, InterpreterInfoResult};
+
+// SAFETY: These signatures match the declarations in CPython's public headers (`pylifecycle.h`,
+// `pystate.h`, and `pythonrun.h`), which are part of the stable ABI. `PyGILState_STATE` is a C enum,
+// which is represented as an `int`, and `PyCompilerFlags *` may be null, so it's declared as an
+// opaque pointer.
+#[cfg(feature = "embedded")]
+unsafe extern "C" {
+    fn Py_IsInitialized() -> c_int;
+    fn PyGILState_Ensure() -> c_int;
+    fn PyGILState_Release(state: c_int);
+    fn PyRun_SimpleStringFlags(command: *const c_char, flags: *mut c_void) -> c_int;
+}
+
+/// A Python snippet that runs the query script and writes its output to a file.
+///
+/// The snippet must never raise: an uncaught `SystemExit` would terminate the host process. The
+/// query script itself calls `sys.exit(0)` on known errors, after printing the error as JSON. Any
+/// modifications to `sys.path` and `sys.modules` are reverted, such that the host's runtime is
+/// left as it was found.
+const QUERY_SNIPPET: &str = r#"
+def __uv_query(root, output):
+    import contextlib
+    import io
+    import json
+    import sys
+    import traceback
+
+    path = list(sys.path)
+    modules = set(sys.modules)
+    stdout = io.StringIO()
+    error = None
+    try:
+        sys.path.insert(0, root)
+        with contextlib.redirect_stdout(stdout):
+            try:
+                from python.get_interpreter_info import main
+
+                main()
+            except SystemExit:
+                pass
+    except BaseException:
+        error = traceback.format_exc()
+    finally:
+        sys.path[:] = path
+        for name in set(sys.modules) - modules:
+            if name == "python" or name.startswith("python."):
+                del sys.modules[name]
+
+    with open(output, "w", encoding="utf-8") as f:
+        json.dump({"stdout": stdout.getvalue(), "error": error}, f)
+
+
+try:
+    __uv_query({root}, {output})
+finally:
+    del __uv_query
+"#;
+
+/// The output of [`QUERY_SNIPPET`].
+#[derive(Debug, Deserialize)]
+struct EmbeddedOutput {
+    stdout: String,
+    error: Option<String>,
+}
+
+#[cfg(feature = "embedded")]
+impl Interpreter {
+    /// Detect the interpreter info for the Python runtime embedded in the current process.
+    ///
+    /// The host application must have initialized the runtime (e.g., via `Py_Initialize`) before
+    /// calling this method. The global interpreter lock is acquired for the duration of the query.
+    pub fn query_embedded(cache: &Cache) -> Result<Self, Error> {
+        // SAFETY: `Py_IsInitialized` may be called at any time, even before initialization.
+        if unsafe { Py_IsInitialized() } == 0 {
+            return Err(Error::EmbeddedNotInitialized);
+        }
+
+        let tempdir = tempfile::tempdir_in(cache.root())?;
+        InterpreterInfo::setup_python_query_files(
+            tempdir.path(),
+            cache.interpreter_settings().query_probes(),
+        )?;
+        let output = tempdir.path().join("output.json");
+
+        let snippet = query_snippet(tempdir.path(), &output)?;
+
+        debug!("Querying the embedded Python runtime");
+
+        // SAFETY: The runtime is initialized, and we hold the GIL while running the snippet, which
+        // catches all exceptions. The GIL state is released on the same thread that acquired it.
+        let status = unsafe {
+            let gil = PyGILState_Ensure();
+            let status = PyRun_SimpleStringFlags(snippet.as_ptr(), std::ptr::null_mut());
+            PyGILState_Release(gil);
+            status
+        };
+        if status != 0 {
+            return Err(Error::EmbeddedQuery(
+                "The query script could not be run; see the host's standard error for details"
+                    .to_string(),
+            ));
+        }
+
+        let info = parse_output(&fs_err::read(&output)?)?;
+
+        debug!(
+            "Found embedded Python with `sys.prefix` at: {}",
+            info.sys_prefix.user_display()
+        );
+
+        let executable = info.sys_executable.clone();
+        Ok(Self::from_info(info, executable))
+    }
+}
+
+/// Render the [`QUERY_SNIPPET`] for the query files in `root`, writing its output to `output`.
+fn query_snippet(root: &Path, output: &Path) -> Result<CString, Error> {
+    let snippet = QUERY_SNIPPET
+        .replace("{root}", &python_string(root)?)
+        .replace("{output}", &python_string(output)?);
+    CString::new(snippet).map_err(|err| Error::EmbeddedQuery(err.to_string()))
+}
+
+/// Parse the output written by the [`QUERY_SNIPPET`].
+fn parse_output(contents: &[u8]) -> Result<InterpreterInfo, Error> {
+    let output: EmbeddedOutput =
+        serde_json::from_slice(contents).map_err(|err| Error::EmbeddedQuery(err.to_string()))?;
+    if let Some(error) = output.error {
+        return Err(Error::EmbeddedQuery(error.trim().to_string()));
+    }
+
+    let result: InterpreterInfoResult = serde_json::from_str(output.stdout.trim())
+        .map_err(|err| Error::EmbeddedQuery(err.to_string()))?;
+    match result {
+        InterpreterInfoResult::Error(err) => Err(Error::EmbeddedQueryScript(err)),
+        InterpreterInfoResult::Success(info) => Ok(*info),
+    }
+}
+
+/// Render a path as a Python string literal.
+///
+/// JSON strings are valid Python string literals, so the path is serialized as JSON.
+fn python_string(path: &Path) -> Result<String, Error> {
+    let path = path.to_str().ok_or_else(|| {
+        Error::EmbeddedQuery(format!(
+            "The cache path is not valid UTF-8: {}",
+            path.user_display()
+        ))
+    })?;
+    serde_json::to_string(path).map_err(|err| Error::EmbeddedQuery(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+    use std::process::Command;
+
+    use tempfile::tempdir;
+
+    use crate::interpreter::{Error, InterpreterInfo};
+
+    use super::{parse_output, python_string, query_snippet};
+
+    /// Run the rendered query snippet with a standalone interpreter, as the C API would.
+    ///
+    /// The snippet doesn't depend on being run in-process, so running it in a subprocess exercises
+    /// everything but the FFI calls. Returns `None` if no `python3` is available.
+    fn run_snippet(root: &Path) -> Option<Vec<u8>> {
+        let python = which::which("python3").ok()?;
+        let output = root.join("output.json");
+        let snippet = query_snippet(root, &output).unwrap();
+        let status = Command::new(python)
+            .arg("-c")
+            .arg(snippet.to_str().unwrap())
+            .status()
+            .unwrap();
+        assert!(status.success());
+        Some(fs_err::read(output).unwrap())
+    }
+
+    #[test]
+    fn python_string_escapes() {
+        assert_eq!(
+            python_string(Path::new(r#"/a "quoted" \path"#)).unwrap(),
+            r#""/a \"quoted\" \\path""#
+        );
+    }
+
+    #[test]
+    fn query_snippet_runs_query_script() {
+        let root = tempdir().unwrap();
+        InterpreterInfo::setup_python_query_files(root.path(), &BTreeMap::new()).unwrap();
+        let Some(output) = run_snippet(root.path()) else {
+            return;
+        };
+
+        let info = parse_output(&output).unwrap();
+        assert!(info.sys_executable.is_absolute());
+        assert_eq!(info.markers.python_full_version().version.release()[0], 3);
+    }
+
+    #[test]
+    fn query_snippet_catches_exceptions() {
+        let root = tempdir().unwrap();
+        fs_err::create_dir_all(root.path().join("python")).unwrap();
+        fs_err::write(root.path().join("python").join("__init__.py"), "").unwrap();
+        fs_err::write(
+            root.path().join("python").join("get_interpreter_info.py"),
+            "def main():\n    raise RuntimeError('boom')\n",
+        )
+        .unwrap();
+        let Some(output) = run_snippet(root.path()) else {
+            return;
+        };
+
+        let err = parse_output(&output).unwrap_err();
+        let Error::EmbeddedQuery(message) = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert!(message.ends_with("RuntimeError: boom"), "{message}");
+    }
+
+    #[test]
+    fn parse_output_invalid() {
+        assert!(matches!(
+            parse_output(br#"{"stdout": "not json", "error": null}"#),
+            Err(Error::EmbeddedQuery(_))
+        ));
+    }
+}
@@ -1,27 +1,37 @@
 //! Find requested Python interpreters and query interpreters for information.
-use owo_colors::OwoColorize;
 use thiserror::Error;
 
 #[cfg(test)]
 use uv_static::EnvVars;
 
 pub use crate::discovery::{
-    EnvironmentPreference, Error as DiscoveryError, PythonDownloads, PythonNotFound,
-    PythonPreference, PythonRequest, PythonSource, PythonVariant, VersionRequest,
+    ActiveEnvironmentSkip, EnvironmentPreference, Error as DiscoveryError, PythonDownloads,
+    PythonNotFound, PythonPreference, PythonRequest, PythonResolution, PythonSource, PythonVariant,
+    VersionRequest, find_active_environment_skip, find_all_python_installations,
     find_python_installations, satisfies_python_preference,
 };
-pub use crate::downloads::PlatformRequest;
+pub use crate::discovery_filter::{Error as DiscoveryFilterError, PythonDiscoveryFilter};
+pub use crate::downloads::{DownloadRate, HostCertificate, PlatformRequest, PythonChannel};
 pub use crate::environment::{InvalidEnvironmentKind, PythonEnvironment};
 pub use crate::implementation::{ImplementationName, LenientImplementationName};
 pub use crate::installation::{
     PythonInstallation, PythonInstallationKey, PythonInstallationMinorVersionKey,
 };
 pub use crate::interpreter::{
-    BrokenSymlink, Error as InterpreterError, Interpreter, canonicalize_executable,
+    BrokenSymlink, Error as InterpreterError, Interpreter, InterpreterIdentity,
+    canonicalize_executable,
 };
 pub use crate::pointer_size::PointerSize;
 pub use crate::prefix::Prefix;
+pub use crate::provenance::{
+    EnvironmentProvenance, Error as ProvenanceError, InterpreterProvenance, ProvenanceSource,
+};
 pub use crate::python_version::PythonVersion;
+pub use crate::quarantine::Quarantine;
+pub use crate::query_failures::QueryFailure;
+pub use crate::query_probes::QueryProbe;
+pub use crate::remediation::Remediation;
+pub use crate::request_builder::{Error as PythonRequestBuilderError, PythonRequestBuilder};
 pub use crate::target::Target;
 pub use crate::version_files::{
     DiscoveryOptions as VersionFileDiscoveryOptions, FilePreference as VersionFilePreference,
@@ -29,9 +39,13 @@ pub use crate::version_files::{
 };
 pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, VirtualEnvironment};
 
+pub mod components;
 mod discovery;
+mod discovery_filter;
 pub mod downloads;
 mod environment;
+pub mod features;
+pub mod host;
 mod implementation;
 mod installation;
 mod interpreter;
@@ -41,13 +55,26 @@ pub mod managed;
 mod microsoft_store;
 mod pointer_size;
 mod prefix;
+mod provenance;
+pub mod provider;
+#[cfg(windows)]
+mod py_launcher;
 mod python_version;
+mod quarantine;
+mod query_env;
+mod query_failures;
+mod query_limits;
+mod query_probes;
+mod remediation;
+mod request_builder;
+mod shim;
 mod sysconfig;
 mod target;
 mod version_files;
 mod virtualenv;
 #[cfg(windows)]
 pub mod windows_registry;
+pub mod wsl;
 
 #[cfg(windows)]
 pub(crate) const COMPANY_KEY: &str = "Astral";
@@ -91,8 +118,8 @@ pub enum Error {
     #[error(transparent)]
     KeyError(#[from] installation::PythonInstallationKeyError),
 
-    #[error("{}{}", .0, if let Some(hint) = .1 { format!("\n\n{}{} {hint}", "hint".bold().cyan(), ":".bold()) } else { String::new() })]
-    MissingPython(PythonNotFound, Option<String>),
+    #[error("{}{}", .0, remediation::format_hints(.1))]
+    MissingPython(PythonNotFound, Vec<Remediation>),
 
     #[error(transparent)]
     MissingEnvironment(#[from] environment::EnvironmentNotFound),
@@ -102,9 +129,16 @@ pub enum Error {
 }
 
 impl Error {
-    pub(crate) fn with_missing_python_hint(self, hint: String) -> Self {
+    /// Attach a [`Remediation`] to a [`Error::MissingPython`] error.
+    ///
+    /// Other errors are returned unchanged.
+    #[must_use]
+    pub fn with_remediation(self, remediation: Remediation) -> Self {
         match self {
-            Self::MissingPython(err, _) => Self::MissingPython(err, Some(hint)),
+            Self::MissingPython(err, mut remediations) => {
+                remediations.push(remediation);
+                Self::MissingPython(err, remediations)
+            }
             _ => self,
         }
     }
@@ -112,7 +146,7 @@ impl Error {
 
 impl From<PythonNotFound> for Error {
     fn from(err: PythonNotFound) -> Self {
-        Self::MissingPython(err, None)
+        Self::MissingPython(err, Vec::new())
     }
 }
 
@@ -125,6 +159,7 @@ mod tests {
         ffi::{OsStr, OsString},
         path::{Path, PathBuf},
         str::FromStr,
+        time::{Duration, SystemTime},
     };
 
     use anyhow::Result;
@@ -135,10 +170,10 @@ mod tests {
     use uv_configuration::Preview;
     use uv_static::EnvVars;
 
-    use uv_cache::Cache;
+    use uv_cache::{Cache, InterpreterSettings};
 
     use crate::{
-        PythonNotFound, PythonRequest, PythonSource, PythonVersion,
+        PythonNotFound, PythonRequest, PythonSource, PythonVersion, Quarantine, QueryFailure,
         implementation::ImplementationName, installation::PythonInstallation,
         managed::ManagedPythonInstallations, virtualenv::virtualenv_python_executable,
     };
@@ -147,6 +182,7 @@ mod tests {
         discovery::{
             self, EnvironmentPreference, find_best_python_installation, find_python_installation,
         },
+        host::{Clock, with_clock},
     };
 
     struct TestContext {
@@ -238,6 +274,32 @@ mod tests {
             system: bool,
             free_threaded: bool,
         ) -> Result<()> {
+            let json =
+                Self::mock_interpreter_json(path, version, implementation, system, free_threaded);
+
+            fs_err::create_dir_all(path.parent().unwrap())?;
+            fs_err::write(
+                path,
+                formatdoc! {r"
+                #!/bin/sh
+                echo '{json}'
+                "},
+            )?;
+
+            fs_err::set_permissions(path, std::os::unix::fs::PermissionsExt::from_mode(0o770))?;
+
+            Ok(())
+        }
+
+        /// Return the fixed metadata mocking our interpreter query script output for a fake Python
+        /// interpreter at `path`.
+        fn mock_interpreter_json(
+            path: &Path,
+            version: &PythonVersion,
+            implementation: ImplementationName,
+            system: bool,
+            free_threaded: bool,
+        ) -> String {
             let json = indoc! {r##"
                 {
                     "result": "success",
@@ -301,28 +363,14 @@ mod tests {
                 json.replace("{PREFIX}", "/home/ferris/projects/uv/.venv")
             };
 
-            let json = json
-                .replace(
-                    "{PATH}",
-                    path.to_str().expect("Path can be represented as string"),
-                )
-                .replace("{FULL_VERSION}", &version.to_string())
-                .replace("{VERSION}", &version.without_patch().to_string())
-                .replace("{FREE_THREADED}", &free_threaded.to_string())
-                .replace("{IMPLEMENTATION}", (&implementation).into());
-
-            fs_err::create_dir_all(path.parent().unwrap())?;
-            fs_err::write(
-                path,
-                formatdoc! {r"
-                #!/bin/sh
-                echo '{json}'
-                "},
-            )?;
-
-            fs_err::set_permissions(path, std::os::unix::fs::PermissionsExt::from_mode(0o770))?;
-
-            Ok(())
+            json.replace(
+                "{PATH}",
+                path.to_str().expect("Path can be represented as string"),
+            )
+            .replace("{FULL_VERSION}", &version.to_string())
+            .replace("{VERSION}", &version.without_patch().to_string())
+            .replace("{FREE_THREADED}", &free_threaded.to_string())
+            .replace("{IMPLEMENTATION}", (&implementation).into())
         }
 
         fn create_mock_pyodide_interpreter(path: &Path, version: &PythonVersion) -> Result<()> {
@@ -561,6 +609,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         });
@@ -576,6 +625,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         });
@@ -601,6 +651,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         });
@@ -623,6 +674,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -685,6 +737,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -717,6 +770,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         });
@@ -728,6 +782,260 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_records_query_failure() -> Result<()> {
+        let mut context = TestContext::new()?;
+        let python = context
+            .new_search_path_directory("python2")?
+            .child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python)?;
+
+        for _ in 0..2 {
+            let result = context.run_with_vars(
+                &[(
+                    EnvVars::UV_PYTHON_RECORD_QUERY_FAILURES,
+                    Some(OsStr::new("1")),
+                )],
+                || {
+                    find_python_installation(
+                        &PythonRequest::Default,
+                        EnvironmentPreference::OnlySystem,
+                        PythonPreference::default(),
+                        &context.cache,
+                        None,
+                        Preview::default(),
+                    )
+                },
+            );
+            assert!(
+                matches!(result, Err(discovery::Error::Query(..))),
+                "The Python 2 query should fail; got {result:?}"
+            );
+        }
+
+        // Failed queries aren't cached, so each discovery attempt is recorded.
+        let failures = QueryFailure::read_all(&context.cache)?;
+        assert_eq!(failures.len(), 1);
+        let (_, failure) = &failures[0];
+        assert_eq!(failure.path, python.path());
+        assert_eq!(failure.count, 2);
+        assert!(failure.stderr.contains("Unknown option: -I"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_quarantines_failing_interpreter() -> Result<()> {
+        let mut context = TestContext::new()?;
+        let python = context
+            .new_search_path_directory("python2")?
+            .child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python)?;
+
+        let find = |context: &TestContext| {
+            context.run_with_vars(
+                &[(
+                    EnvVars::UV_PYTHON_QUARANTINE_THRESHOLD,
+                    Some(OsStr::new("2")),
+                )],
+                || {
+                    find_python_installation(
+                        &PythonRequest::Default,
+                        EnvironmentPreference::OnlySystem,
+                        PythonPreference::default(),
+                        &context.cache,
+                        None,
+                        Preview::default(),
+                    )
+                },
+            )
+        };
+
+        // The interpreter is queried until it has failed twice in a row.
+        for _ in 0..2 {
+            let result = find(&context);
+            assert!(
+                matches!(
+                    &result,
+                    Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::QueryScript { .. })
+                ),
+                "The Python 2 query should fail; got {result:?}"
+            );
+        }
+
+        // Afterwards, it's skipped without being queried.
+        let result = find(&context);
+        assert!(
+            matches!(
+                &result,
+                Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::Quarantined { failures: 2, .. })
+            ),
+            "The Python 2 interpreter should be quarantined; got {result:?}"
+        );
+
+        let quarantined = Quarantine::read_all(&context.cache)?;
+        assert_eq!(quarantined.len(), 1);
+        assert_eq!(quarantined[0].path, python.path());
+        assert_eq!(quarantined[0].failures, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_quarantine_disabled_by_default() -> Result<()> {
+        let mut context = TestContext::new()?;
+        let python = context
+            .new_search_path_directory("python2")?
+            .child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python)?;
+
+        // Without a threshold, the interpreter is queried again after every failure.
+        for _ in 0..4 {
+            let result = context.run(|| {
+                find_python_installation(
+                    &PythonRequest::Default,
+                    EnvironmentPreference::OnlySystem,
+                    PythonPreference::default(),
+                    &context.cache,
+                    None,
+                    Preview::default(),
+                )
+            });
+            assert!(
+                matches!(
+                    &result,
+                    Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::QueryScript { .. })
+                ),
+                "The Python 2 query should fail; got {result:?}"
+            );
+        }
+
+        assert!(Quarantine::read_all(&context.cache)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_quarantine_cooldown() -> Result<()> {
+        struct FixedClock(SystemTime);
+
+        impl Clock for FixedClock {
+            fn now(&self) -> SystemTime {
+                self.0
+            }
+        }
+
+        let mut context = TestContext::new()?;
+        let python = context
+            .new_search_path_directory("python2")?
+            .child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python)?;
+
+        let find = |context: &TestContext| {
+            context.run_with_vars(
+                &[
+                    (
+                        EnvVars::UV_PYTHON_QUARANTINE_THRESHOLD,
+                        Some(OsStr::new("1")),
+                    ),
+                    (
+                        EnvVars::UV_PYTHON_QUARANTINE_COOLDOWN,
+                        Some(OsStr::new("60")),
+                    ),
+                ],
+                || {
+                    find_python_installation(
+                        &PythonRequest::Default,
+                        EnvironmentPreference::OnlySystem,
+                        PythonPreference::default(),
+                        &context.cache,
+                        None,
+                        Preview::default(),
+                    )
+                },
+            )
+        };
+
+        // The first failure quarantines the interpreter.
+        let now = SystemTime::now();
+        let result = with_clock(FixedClock(now), || find(&context));
+        assert!(
+            matches!(
+                &result,
+                Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::QueryScript { .. })
+            ),
+            "The Python 2 query should fail; got {result:?}"
+        );
+
+        let result = with_clock(FixedClock(now + Duration::from_secs(30)), || find(&context));
+        assert!(
+            matches!(
+                &result,
+                Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::Quarantined { failures: 1, .. })
+            ),
+            "The Python 2 interpreter should be quarantined; got {result:?}"
+        );
+
+        // Once the cooldown has elapsed, the interpreter is queried again.
+        let result = with_clock(FixedClock(now + Duration::from_secs(90)), || find(&context));
+        assert!(
+            matches!(
+                &result,
+                Err(discovery::Error::Query(err, ..)) if matches!(**err, crate::interpreter::Error::QueryScript { .. })
+            ),
+            "The Python 2 query should be retried; got {result:?}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn find_python_mocked_interpreter() -> Result<()> {
+        let mut context = TestContext::new()?;
+        let python = context
+            .new_search_path_directory("python2")?
+            .child(format!("python{}", env::consts::EXE_SUFFIX));
+        TestContext::create_mock_python2_interpreter(&python)?;
+
+        // The executable can't be queried, but its metadata is mocked.
+        let mocks = context.tempdir.child("mocks.json");
+        mocks.write_str(&format!(
+            "{{{}: {}}}",
+            serde_json::to_string(python.path())?,
+            TestContext::mock_interpreter_json(
+                python.path(),
+                &PythonVersion::from_str("3.12.1")?,
+                ImplementationName::CPython,
+                true,
+                false,
+            )
+        ))?;
+
+        let python = context.run_with_vars(
+            &[(
+                EnvVars::UV_MOCK_INTERPRETER_JSON,
+                Some(mocks.path().as_os_str()),
+            )],
+            || {
+                find_python_installation(
+                    &PythonRequest::Default,
+                    EnvironmentPreference::OnlySystem,
+                    PythonPreference::default(),
+                    &context.cache,
+                    None,
+                    Preview::default(),
+                )
+            },
+        )??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.12.1",
+            "The mocked interpreter metadata should be used"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_skip_python2_executable() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -754,6 +1062,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::default(),
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -786,6 +1095,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -808,6 +1118,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -834,6 +1145,7 @@ mod tests {
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -846,6 +1158,76 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn find_python_discovery_filter() -> Result<()> {
+        let mut context = TestContext::new()?;
+        context.add_python_interpreters(&[
+            (true, ImplementationName::CPython, "python", "3.10.0"),
+            (true, ImplementationName::CPython, "python", "3.10.1"),
+        ])?;
+
+        // Excluded executables on the search path are skipped.
+        let cache = context.cache.clone().with_interpreter_settings(
+            InterpreterSettings::default()
+                .with_discovery_filter(vec!["**/*-3.10.0/*".to_string()], None),
+        );
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::Default,
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &cache,
+                None,
+                Preview::default(),
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.10.1",
+            "Should skip the excluded interpreter"
+        );
+
+        // Executables that don't match any of the `include-only` patterns are skipped too.
+        let cache = context.cache.clone().with_interpreter_settings(
+            InterpreterSettings::default()
+                .with_discovery_filter(Vec::new(), Some(vec!["**/*-3.10.1/*".to_string()])),
+        );
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::Default,
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &cache,
+                None,
+                Preview::default(),
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.10.1",
+            "Should skip the interpreter that isn't included"
+        );
+
+        // Without a filter, the first interpreter is found.
+        let python = context.run(|| {
+            find_python_installation(
+                &PythonRequest::Default,
+                EnvironmentPreference::Any,
+                PythonPreference::OnlySystem,
+                &context.cache,
+                None,
+                Preview::default(),
+            )
+        })??;
+        assert_eq!(
+            python.interpreter().python_full_version().to_string(),
+            "3.10.0",
+            "Should find the first interpreter"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn find_python_system_python_disallowed() -> Result<()> {
         let mut context = TestContext::new()?;
@@ -860,6 +1242,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -883,6 +1266,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -917,6 +1301,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -951,6 +1336,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -973,6 +1359,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -995,6 +1382,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1029,6 +1417,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1066,6 +1455,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1097,6 +1487,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1132,6 +1523,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1158,6 +1550,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1185,6 +1578,7 @@ mod tests {
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1210,6 +1604,7 @@ mod tests {
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1232,6 +1627,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1255,6 +1651,7 @@ mod tests {
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1288,6 +1685,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1309,6 +1707,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1336,6 +1735,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1354,6 +1754,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1383,6 +1784,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1421,6 +1823,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1449,6 +1852,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1474,6 +1878,7 @@ mod tests {
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1499,6 +1904,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1524,6 +1930,7 @@ mod tests {
                     EnvironmentPreference::OnlyVirtual,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1562,6 +1969,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1590,6 +1998,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1607,6 +2016,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })??;
@@ -1624,6 +2034,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })?;
@@ -1646,6 +2057,7 @@ mod tests {
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -1663,6 +2075,7 @@ mod tests {
                     EnvironmentPreference::OnlySystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1685,6 +2098,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1700,6 +2114,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -1714,6 +2129,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -1743,6 +2159,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1759,6 +2176,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1789,6 +2207,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1805,6 +2224,7 @@ mod tests {
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1821,6 +2241,7 @@ mod tests {
                 EnvironmentPreference::OnlyVirtual,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1837,6 +2258,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1861,6 +2283,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1876,6 +2299,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1900,6 +2324,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1920,6 +2345,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             },
@@ -1949,6 +2375,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -1971,6 +2398,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -2001,6 +2429,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2017,6 +2446,7 @@ mod tests {
                 EnvironmentPreference::ExplicitSystem,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -2044,6 +2474,7 @@ mod tests {
                     EnvironmentPreference::ExplicitSystem,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2069,6 +2500,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -2086,6 +2518,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2101,6 +2534,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2127,6 +2561,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2142,6 +2577,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2168,6 +2604,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2195,6 +2632,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2222,6 +2660,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2249,6 +2688,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2276,6 +2716,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2304,6 +2745,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -2326,6 +2768,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2341,6 +2784,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2367,6 +2811,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2382,6 +2827,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2420,6 +2866,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2438,6 +2885,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2480,6 +2928,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2498,6 +2947,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2535,6 +2985,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2558,6 +3009,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2581,6 +3033,7 @@ mod tests {
                     EnvironmentPreference::Any,
                     PythonPreference::OnlySystem,
                     &context.cache,
+                    None,
                     Preview::default(),
                 )
             })
@@ -2620,6 +3073,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2673,6 +3127,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2713,6 +3168,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })?;
@@ -2728,6 +3184,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
@@ -2745,6 +3202,7 @@ mod tests {
                 EnvironmentPreference::Any,
                 PythonPreference::OnlySystem,
                 &context.cache,
+                None,
                 Preview::default(),
             )
         })??;
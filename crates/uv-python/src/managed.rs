@@ -1,6 +1,7 @@
 use core::fmt;
 use std::borrow::Cow;
 use std::cmp::Reverse;
+use std::collections::BTreeSet;
 use std::ffi::OsStr;
 use std::io::{self, Write};
 #[cfg(windows)]
@@ -10,6 +11,7 @@ use std::str::FromStr;
 
 use fs_err as fs;
 use itertools::Itertools;
+use rustc_hash::FxHashSet;
 use same_file::is_same_file;
 use thiserror::Error;
 use tracing::{debug, warn};
@@ -24,7 +26,7 @@ use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 use uv_trampoline_builder::{Launcher, windows_python_launcher};
 
-use crate::downloads::{Error as DownloadError, ManagedPythonDownload};
+use crate::downloads::{Error as DownloadError, ManagedPythonDownload, PythonChannel};
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
@@ -108,17 +110,31 @@ pub enum Error {
     #[error(transparent)]
     MacOsDylib(#[from] macos_dylib::Error),
 }
+
 /// A collection of uv-managed Python installations installed on the current system.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ManagedPythonInstallations {
     /// The path to the top-level directory of the installed Python versions.
     root: PathBuf,
+    /// The path to the directory in which Python distributions are extracted before they're moved
+    /// into `root`, if it differs from the default scratch directory.
+    staging: Option<PathBuf>,
 }
 
 impl ManagedPythonInstallations {
     /// A directory for Python installations at `root`.
     fn from_path(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self {
+            root: root.into(),
+            staging: None,
+        }
+    }
+
+    /// Extract Python distributions into the given staging directory, e.g., from the
+    /// `install-staging-dir` setting, rather than the default scratch directory.
+    #[must_use]
+    pub fn with_staging_dir(self, staging: Option<PathBuf>) -> Self {
+        Self { staging, ..self }
     }
 
     /// Grab a file lock for the managed Python distribution directory to prevent concurrent access
@@ -129,14 +145,24 @@ impl ManagedPythonInstallations {
 
     /// Prefer, in order:
     ///
-    /// 1. The specific Python directory passed via the `install_dir` argument.
+    /// 1. The specific Python directory passed via the `install_dir` argument, e.g., the
+    ///    project-level directory from the `python-install-dir` setting.
     /// 2. The specific Python directory specified with the `UV_PYTHON_INSTALL_DIR` environment variable.
     /// 3. A directory in the system-appropriate user-level data directory, e.g., `~/.local/uv/python`.
     /// 4. A directory in the local data directory, e.g., `./.uv/python`.
     pub fn from_settings(install_dir: Option<PathBuf>) -> Result<Self, Error> {
         if let Some(install_dir) = install_dir {
             Ok(Self::from_path(install_dir))
-        } else if let Some(install_dir) =
+        } else {
+            Self::user_from_settings()
+        }
+    }
+
+    /// The user-level directory for Python installations, ignoring any project-level directory.
+    ///
+    /// See [`ManagedPythonInstallations::from_settings`].
+    pub fn user_from_settings() -> Result<Self, Error> {
+        if let Some(install_dir) =
             std::env::var_os(EnvVars::UV_PYTHON_INSTALL_DIR).filter(|s| !s.is_empty())
         {
             Ok(Self::from_path(install_dir))
@@ -147,6 +173,16 @@ impl ManagedPythonInstallations {
         }
     }
 
+    /// The directories to search for Python installations, in order of precedence: the
+    /// project-level directory, if any, then the user-level directory.
+    pub fn discoverable(project: Option<&Path>) -> Result<Vec<Self>, Error> {
+        let user = Self::user_from_settings()?;
+        match project {
+            Some(project) if project != user.root() => Ok(vec![Self::from_path(project), user]),
+            _ => Ok(vec![user]),
+        }
+    }
+
     /// Create a temporary Python installation directory.
     pub fn temp() -> Result<Self, Error> {
         Ok(Self::from_path(
@@ -154,9 +190,15 @@ impl ManagedPythonInstallations {
         ))
     }
 
-    /// Return the location of the scratch directory for managed Python installations.
+    /// Return the location of the scratch directory for managed Python installations, in which
+    /// distributions are extracted before they're moved into place.
+    ///
+    /// Defaults to a directory within the installation directory, such that extracted
+    /// distributions can be moved into place with an atomic rename.
     pub fn scratch(&self) -> PathBuf {
-        self.root.join(".temp")
+        self.staging
+            .clone()
+            .unwrap_or_else(|| self.root.join(".temp"))
     }
 
     /// Initialize the Python installation directory.
@@ -262,15 +304,13 @@ impl ManagedPythonInstallations {
     ) -> Result<impl DoubleEndedIterator<Item = ManagedPythonInstallation> + use<>, Error> {
         let platform = Platform::from_env()?;
 
-        let iter = Self::from_settings(None)?
-            .find_all()?
-            .filter(move |installation| {
-                if !platform.supports(installation.platform()) {
-                    debug!("Skipping managed installation `{installation}`: not supported by current platform `{platform}`");
-                    return false;
-                }
-                true
-            });
+        let iter = self.find_all()?.filter(move |installation| {
+            if !platform.supports(installation.platform()) {
+                debug!("Skipping managed installation `{installation}`: not supported by current platform `{platform}`");
+                return false;
+            }
+            true
+        });
 
         Ok(iter)
     }
@@ -296,11 +336,61 @@ impl ManagedPythonInstallations {
             }))
     }
 
+    /// Return the path to the manifest of protected installations.
+    fn protected_manifest(&self) -> PathBuf {
+        self.root.join(".protected")
+    }
+
+    /// Read the keys of the protected installations.
+    ///
+    /// Protected installations are skipped by `uv python uninstall` unless `--force` is provided.
+    /// The manifest lists one installation key per line.
+    pub fn protected(&self) -> Result<BTreeSet<PythonInstallationKey>, Error> {
+        let contents = match fs::read_to_string(self.protected_manifest()) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                PythonInstallationKey::from_str(line)
+                    .inspect_err(|err| {
+                        warn!("Ignoring malformed protected installation key `{line}`: {err}");
+                    })
+                    .ok()
+            })
+            .collect())
+    }
+
+    /// Write the keys of the protected installations, removing the manifest if there are none.
+    pub fn set_protected(&self, keys: &BTreeSet<PythonInstallationKey>) -> Result<(), Error> {
+        let manifest = self.protected_manifest();
+        if keys.is_empty() {
+            return match fs::remove_file(&manifest) {
+                Ok(()) => Ok(()),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+                Err(err) => Err(err.into()),
+            };
+        }
+        let contents = keys
+            .iter()
+            .map(|key| format!("{key}\n"))
+            .collect::<String>();
+        uv_fs::write_atomic_sync(&manifest, contents)?;
+        Ok(())
+    }
+
     pub fn root(&self) -> &Path {
         &self.root
     }
 }
 
+/// The name of the file recording the release channel of an installation, in its root directory.
+const CHANNEL_FILE: &str = "uv-channel";
+
 static EXTERNALLY_MANAGED: &str = "[externally-managed]
 Error=This Python installation is managed by uv and should not be modified.
 ";
@@ -676,6 +766,224 @@ impl ManagedPythonInstallation {
     pub fn sha256(&self) -> Option<&str> {
         self.sha256.as_deref()
     }
+
+    /// Return the path to the file recording the release channel of the installation.
+    fn channel_file(&self) -> PathBuf {
+        self.path.join(CHANNEL_FILE)
+    }
+
+    /// Return the release channel the installation was installed from, if one was requested.
+    ///
+    /// Upgrades of the installation follow the channel, unless another channel is requested.
+    pub fn channel(&self) -> Option<PythonChannel> {
+        let contents = fs::read_to_string(self.channel_file()).ok()?;
+        PythonChannel::from_str(contents.trim())
+            .inspect_err(|err| warn!("Ignoring the release channel of `{}`: {err}", self.key))
+            .ok()
+    }
+
+    /// Record the release channel the installation was installed from.
+    pub fn set_channel(&self, channel: PythonChannel) -> Result<(), Error> {
+        if self.channel() == Some(channel) {
+            return Ok(());
+        }
+        uv_fs::write_atomic_sync(self.channel_file(), format!("{channel}\n"))?;
+        Ok(())
+    }
+}
+
+/// The disk space used by a managed Python installation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiskUsage {
+    /// The total size of the files in the installation, in bytes.
+    ///
+    /// Files that are hardlinked multiple times are only counted once, in the first installation
+    /// in which they are encountered.
+    pub total: u64,
+    /// The size of the files in the installation that have other hardlinks, e.g., into the uv
+    /// cache, in bytes.
+    ///
+    /// This space is not reclaimed by removing the installation alone.
+    pub shared: u64,
+}
+
+impl DiskUsage {
+    /// The number of bytes that would be freed by removing the installation.
+    pub fn reclaimable(&self) -> u64 {
+        self.total - self.shared
+    }
+}
+
+/// Compute the [`DiskUsage`] of each of the given installations.
+///
+/// Hardlinked files are attributed to the first installation in which they are encountered, so
+/// the sum of the returned usages reflects the space actually used on disk.
+pub fn disk_usage<'a>(
+    installations: impl IntoIterator<Item = &'a ManagedPythonInstallation>,
+) -> Result<Vec<(&'a ManagedPythonInstallation, DiskUsage)>, Error> {
+    let mut seen = FxHashSet::default();
+    installations
+        .into_iter()
+        .map(|installation| {
+            let mut usage = DiskUsage::default();
+            accumulate_disk_usage(installation.path(), &mut seen, &mut usage).map_err(|err| {
+                Error::ReadError {
+                    dir: installation.path().to_path_buf(),
+                    err,
+                }
+            })?;
+            Ok((installation, usage))
+        })
+        .collect()
+}
+
+/// Add the size of each file under `dir` to `usage`, skipping hardlinks that were already `seen`.
+///
+/// Symbolic links are not followed.
+fn accumulate_disk_usage(
+    dir: &Path,
+    seen: &mut FxHashSet<(u64, u64)>,
+    usage: &mut DiskUsage,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = fs::symlink_metadata(entry.path())?;
+        if metadata.is_dir() {
+            accumulate_disk_usage(&entry.path(), seen, usage)?;
+        } else if metadata.is_file() {
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::MetadataExt;
+
+                if metadata.nlink() > 1 {
+                    if !seen.insert((metadata.dev(), metadata.ino())) {
+                        continue;
+                    }
+                    usage.shared += metadata.len();
+                }
+            }
+            usage.total += metadata.len();
+        }
+    }
+    Ok(())
+}
+
+/// Find the installation in `installations_dir` that an installation of `key` can share files
+/// with, i.e., the latest other patch version of the same implementation, minor version,
+/// platform, and variant.
+pub(crate) fn find_sibling_installation(
+    installations_dir: &Path,
+    key: &PythonInstallationKey,
+) -> Option<PathBuf> {
+    let entries = match fs::read_dir(installations_dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            debug!(
+                "Failed to read installations directory `{}`: {err}",
+                installations_dir.user_display()
+            );
+            return None;
+        }
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .filter_map(|entry| {
+            let sibling = PythonInstallationKey::from_str(entry.file_name().to_str()?).ok()?;
+            (sibling.implementation == key.implementation
+                && sibling.major == key.major
+                && sibling.minor == key.minor
+                && sibling.platform == key.platform
+                && sibling.variant == key.variant
+                && sibling.version() != key.version())
+            .then(|| (sibling.version(), entry.path()))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, path)| path)
+}
+
+/// Replace the files under `dir` with hardlinks to identical files at the same relative path under
+/// `sibling`, an installation of another patch version of the same minor version.
+///
+/// Most of an installation, e.g., the standard library, is unchanged across patch versions, so a
+/// patch upgrade only requires disk space for the files that changed. Files that are modified
+/// after installation, i.e., those in `site-packages` and those patched by uv (see
+/// [`is_patched`]), are never shared, as writing to a shared file would modify the sibling too.
+///
+/// Returns the number of bytes that are shared with the sibling installation.
+pub(crate) fn dedupe_installation(dir: &Path, sibling: &Path) -> io::Result<u64> {
+    let mut shared = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if is_patched(&entry.file_name()) {
+            continue;
+        }
+        let path = entry.path();
+        let target = sibling.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            if target.is_dir() {
+                shared += dedupe_installation(&path, &target)?;
+            }
+        } else if file_type.is_file() {
+            let Ok(target_metadata) = fs::symlink_metadata(&target) else {
+                continue;
+            };
+            let metadata = entry.metadata()?;
+            if !target_metadata.is_file()
+                || target_metadata.len() != metadata.len()
+                || target_metadata.permissions() != metadata.permissions()
+                || !is_same_contents(&path, &target)?
+            {
+                continue;
+            }
+            // Link to a temporary path first, such that the file is replaced atomically.
+            let mut temp = entry.file_name();
+            temp.push(".uv-dedupe");
+            let temp = dir.join(temp);
+            fs::hard_link(&target, &temp)?;
+            if let Err(err) = fs::rename(&temp, &path) {
+                let _ = fs::remove_file(&temp);
+                return Err(err);
+            }
+            shared += metadata.len();
+        }
+    }
+    Ok(shared)
+}
+
+/// Returns `true` if the file or directory is modified after installation, either by uv, e.g.,
+/// [`ManagedPythonInstallation::ensure_sysconfig_patched`], or by the user, e.g., `site-packages`.
+fn is_patched(name: &OsStr) -> bool {
+    let Some(name) = name.to_str() else {
+        return false;
+    };
+    matches!(
+        name,
+        "site-packages" | "EXTERNALLY-MANAGED" | "pkgconfig" | "sysconfig" | "sysconfig.py"
+    ) || name.starts_with("_sysconfigdata")
+        || (name.starts_with("libpython") && name.ends_with(".dylib"))
+}
+
+/// Returns `true` if the files at `a` and `b` have the same contents.
+fn is_same_contents(a: &Path, b: &Path) -> io::Result<bool> {
+    use std::io::Read;
+
+    let mut a = io::BufReader::new(fs::File::open(a)?);
+    let mut b = io::BufReader::new(fs::File::open(b)?);
+    let mut buf_a = [0u8; 8192];
+    let mut buf_b = [0u8; 8192];
+    loop {
+        let len = a.read(&mut buf_a)?;
+        if len == 0 {
+            // The files have the same length, so `b` is exhausted too.
+            return Ok(true);
+        }
+        b.read_exact(&mut buf_b[..len])?;
+        if buf_a[..len] != buf_b[..len] {
+            return Ok(false);
+        }
+    }
 }
 
 /// A representation of a minor version symlink directory (or junction on Windows)
@@ -936,3 +1244,119 @@ pub fn python_executable_dir() -> Result<PathBuf, Error> {
     uv_dirs::user_executable_directory(Some(EnvVars::UV_PYTHON_BIN_DIR))
         .ok_or(Error::NoExecutableDirectory)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Identical files are shared with the sibling installation, except in `site-packages`.
+    #[test]
+    fn test_dedupe_installation() -> io::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let sibling = temp_dir.path().join("cpython-3.12.4-linux-x86_64-gnu");
+        let dir = temp_dir.path().join("cpython-3.12.5-linux-x86_64-gnu");
+        for root in [&sibling, &dir] {
+            fs::create_dir_all(root.join("lib").join("python3.12").join("site-packages"))?;
+            fs::write(
+                root.join("lib").join("python3.12").join("os.py"),
+                "import abc",
+            )?;
+            fs::write(
+                root.join("lib")
+                    .join("python3.12")
+                    .join("site-packages")
+                    .join("README.txt"),
+                "This directory exists so that 3rd party packages can be installed here.",
+            )?;
+        }
+        fs::write(sibling.join("lib").join("python3.12").join("ssl.py"), "4")?;
+        fs::write(dir.join("lib").join("python3.12").join("ssl.py"), "5")?;
+
+        let key = PythonInstallationKey::from_str("cpython-3.12.5-linux-x86_64-gnu").unwrap();
+        assert_eq!(
+            find_sibling_installation(temp_dir.path(), &key).as_deref(),
+            Some(sibling.as_path())
+        );
+
+        let shared = dedupe_installation(&dir, &sibling)?;
+        assert_eq!(shared, "import abc".len() as u64);
+
+        let lib = Path::new("lib").join("python3.12");
+        assert!(is_same_file(
+            dir.join(&lib).join("os.py"),
+            sibling.join(&lib).join("os.py")
+        )?);
+        assert!(!is_same_file(
+            dir.join(&lib).join("ssl.py"),
+            sibling.join(&lib).join("ssl.py")
+        )?);
+        assert!(!is_same_file(
+            dir.join(&lib).join("site-packages").join("README.txt"),
+            sibling.join(&lib).join("site-packages").join("README.txt")
+        )?);
+        assert_eq!(fs::read_to_string(dir.join(&lib).join("ssl.py"))?, "5");
+
+        Ok(())
+    }
+
+    /// Patching an installation after it's deduplicated must leave the sibling installation
+    /// unchanged.
+    #[test]
+    fn test_dedupe_installation_patched() -> Result<(), Error> {
+        let sysconfigdata = indoc::indoc! {r#"
+            # system configuration generated and used by the sysconfig module
+            build_time_vars = {
+                "BINDIR": "/install/bin",
+                "PYTHON_BUILD_STANDALONE": 1
+            }
+        "#};
+
+        let temp_dir = tempfile::tempdir()?;
+        let sibling = temp_dir.path().join("cpython-3.12.4-linux-x86_64-gnu");
+        let dir = temp_dir.path().join("cpython-3.12.5-linux-x86_64-gnu");
+        let lib = Path::new("lib").join("python3.12");
+        for root in [&sibling, &dir] {
+            fs::create_dir_all(root.join(&lib))?;
+            fs::write(
+                root.join(&lib)
+                    .join("_sysconfigdata__linux_x86_64-linux-gnu.py"),
+                sysconfigdata,
+            )?;
+            fs::write(
+                root.join(&lib).join("EXTERNALLY-MANAGED"),
+                "[externally-managed]",
+            )?;
+        }
+
+        dedupe_installation(&dir, &sibling)?;
+
+        // Patch the installation, as after `uv python install`.
+        sysconfig::update_sysconfig(&dir, 3, 12, "")?;
+        fs::write(
+            dir.join(&lib).join("EXTERNALLY-MANAGED"),
+            EXTERNALLY_MANAGED,
+        )?;
+
+        assert_ne!(
+            fs::read_to_string(
+                dir.join(&lib)
+                    .join("_sysconfigdata__linux_x86_64-linux-gnu.py")
+            )?,
+            sysconfigdata
+        );
+        assert_eq!(
+            fs::read_to_string(
+                sibling
+                    .join(&lib)
+                    .join("_sysconfigdata__linux_x86_64-linux-gnu.py")
+            )?,
+            sysconfigdata
+        );
+        assert_eq!(
+            fs::read_to_string(sibling.join(&lib).join("EXTERNALLY-MANAGED"))?,
+            "[externally-managed]"
+        );
+
+        Ok(())
+    }
+}
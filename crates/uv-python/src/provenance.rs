@@ -0,0 +1,179 @@
+//! Records of the interpreter used to create a virtual environment.
+//!
+//! When uv creates a virtual environment, it writes a `uv-environment.toml` file to the root of the
+//! environment, recording where the base interpreter came from.
+use std::io;
+use std::path::{Path, PathBuf};
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+use crate::Interpreter;
+use crate::downloads::ManagedPythonDownload;
+
+/// The name of the provenance file in the root of a virtual environment.
+const PROVENANCE_FILE: &str = "uv-environment.toml";
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse: `{}`", _0.user_display())]
+    Parse(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to serialize environment provenance")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// The provenance of a virtual environment, as recorded in its `uv-environment.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EnvironmentProvenance {
+    /// The version of uv that created the environment.
+    pub uv_version: String,
+    /// The time at which the environment was created.
+    pub created: Timestamp,
+    /// The base interpreter of the environment.
+    pub interpreter: InterpreterProvenance,
+}
+
+/// The provenance of the base interpreter of a virtual environment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct InterpreterProvenance {
+    /// The installation key of the interpreter, e.g., `cpython-3.12.4-linux-x86_64-gnu`.
+    pub key: String,
+    /// Whether the interpreter is managed by uv.
+    pub source: ProvenanceSource,
+    /// The path to the interpreter executable.
+    pub executable: PathBuf,
+    /// The URL from which the interpreter was downloaded, for managed interpreters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The SHA256 of the downloaded archive, for managed interpreters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// Where the base interpreter of a virtual environment came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProvenanceSource {
+    /// A Python installation managed by uv.
+    Managed,
+    /// A Python installation that is not managed by uv.
+    System,
+}
+
+impl std::fmt::Display for ProvenanceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Managed => f.write_str("managed"),
+            Self::System => f.write_str("system"),
+        }
+    }
+}
+
+impl EnvironmentProvenance {
+    /// Determine the provenance of an environment created from the given base interpreter.
+    ///
+    /// For managed interpreters, the download URL and hash are read from the known Python
+    /// downloads (or those in `python_downloads_json_url`, if set), if the installation key is
+    /// found there.
+    pub fn new(
+        interpreter: &Interpreter,
+        python_downloads_json_url: Option<&str>,
+        python_install_dir: Option<&Path>,
+        uv_version: impl Into<String>,
+    ) -> Self {
+        let key = interpreter.key();
+        let managed = interpreter.is_managed(python_install_dir);
+        let download = if managed {
+            ManagedPythonDownload::iter_all(python_downloads_json_url)
+                .ok()
+                .and_then(|mut downloads| downloads.find(|download| download.key() == &key))
+        } else {
+            None
+        };
+
+        Self {
+            uv_version: uv_version.into(),
+            created: Timestamp::now(),
+            interpreter: InterpreterProvenance {
+                key: key.to_string(),
+                source: if managed {
+                    ProvenanceSource::Managed
+                } else {
+                    ProvenanceSource::System
+                },
+                executable: interpreter
+                    .sys_base_executable()
+                    .unwrap_or(interpreter.sys_executable())
+                    .to_path_buf(),
+                url: download.map(|download| download.url().to_string()),
+                sha256: download
+                    .and_then(ManagedPythonDownload::sha256)
+                    .map(ToString::to_string),
+            },
+        }
+    }
+
+    /// Read the provenance of the virtual environment at `root`.
+    ///
+    /// Returns `None` if the environment has no provenance file, e.g., if it was created by an
+    /// older version of uv or by another tool.
+    pub fn read(root: &Path) -> Result<Option<Self>, Error> {
+        let path = root.join(PROVENANCE_FILE);
+        let content = match fs_err::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let provenance =
+            toml::from_str(&content).map_err(|err| Error::Parse(path, Box::new(err)))?;
+        Ok(Some(provenance))
+    }
+
+    /// Write the provenance to the virtual environment at `root`.
+    pub fn write(&self, root: &Path) -> Result<(), Error> {
+        let path = root.join(PROVENANCE_FILE);
+        debug!("Writing environment provenance to: {}", path.user_display());
+        fs_err::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use jiff::Timestamp;
+
+    use super::{EnvironmentProvenance, InterpreterProvenance, ProvenanceSource};
+
+    #[test]
+    fn round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let provenance = EnvironmentProvenance {
+            uv_version: "0.8.0".to_string(),
+            created: "2025-07-01T12:00:00Z".parse::<Timestamp>().unwrap(),
+            interpreter: InterpreterProvenance {
+                key: "cpython-3.12.4-linux-x86_64-gnu".to_string(),
+                source: ProvenanceSource::Managed,
+                executable: PathBuf::from("/python/bin/python3.12"),
+                url: Some("https://example.com/cpython-3.12.4.tar.gz".to_string()),
+                sha256: Some("abc123".to_string()),
+            },
+        };
+
+        assert_eq!(EnvironmentProvenance::read(temp_dir.path()).unwrap(), None);
+        provenance.write(temp_dir.path()).unwrap();
+        assert_eq!(
+            EnvironmentProvenance::read(temp_dir.path()).unwrap(),
+            Some(provenance)
+        );
+    }
+}
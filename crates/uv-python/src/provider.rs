@@ -0,0 +1,213 @@
+//! Custom sources of Python interpreters for discovery.
+//!
+//! In addition to the built-in sources (see [`PythonSource`]), embedders can register a
+//! [`PythonProvider`] with [`register`] to yield candidate interpreters from elsewhere, e.g., the
+//! CLI of an internal toolchain manager or a network service that provisions interpreters.
+//!
+//! Candidates from providers are searched alongside the other installed interpreters, i.e., after
+//! virtual environments and explicitly requested interpreters, at the position given by the
+//! provider's [`ProviderPrecedence`]. Like interpreters found on the `PATH`, candidates are
+//! treated as system interpreters, so they are not considered with `--python-preference
+//! only-managed`, and they are queried to ensure they satisfy the request.
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use tracing::debug;
+
+use uv_warnings::warn_user_once;
+
+use crate::discovery::{PythonSource, VersionRequest};
+use crate::implementation::ImplementationName;
+
+/// The error type returned by a [`PythonProvider`].
+pub type ProviderError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A custom source of Python interpreters for discovery.
+pub trait PythonProvider: Send + Sync {
+    /// The name of the provider, e.g., `toolchain-manager`, used to identify the source of an
+    /// interpreter in messages.
+    fn name(&self) -> &'static str;
+
+    /// The position of the provider's candidates relative to the built-in sources.
+    fn precedence(&self) -> ProviderPrecedence {
+        ProviderPrecedence::default()
+    }
+
+    /// Return the paths of Python executables that may satisfy the request, in order of
+    /// preference.
+    ///
+    /// The request is provided to avoid returning candidates that are known not to match; the
+    /// returned executables are queried regardless. An error is reported as a warning and the
+    /// provider is skipped, rather than failing discovery.
+    fn find(
+        &self,
+        version: &VersionRequest,
+        implementation: Option<&ImplementationName>,
+    ) -> Result<Vec<PathBuf>, ProviderError>;
+}
+
+/// The position of the candidates from a [`PythonProvider`] relative to the built-in sources of
+/// installed interpreters.
+///
+/// Providers with the same precedence are searched in the order in which they were registered.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderPrecedence {
+    /// Before all built-in sources of installed interpreters, including managed installations.
+    First,
+    /// Immediately before the interpreters on the `PATH`.
+    BeforeSearchPath,
+    /// After all built-in sources of installed interpreters.
+    #[default]
+    Last,
+}
+
+static PROVIDERS: RwLock<Vec<Arc<dyn PythonProvider>>> = RwLock::new(Vec::new());
+
+/// Register a [`PythonProvider`] for the remainder of the process.
+pub fn register(provider: impl PythonProvider + 'static) {
+    debug!("Registering Python provider `{}`", provider.name());
+    PROVIDERS
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(Arc::new(provider));
+}
+
+/// Return the registered [`PythonProvider`]s, in the order in which they were registered.
+pub fn providers() -> Vec<Arc<dyn PythonProvider>> {
+    PROVIDERS
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Lazily iterate over the candidates of the registered providers with the given precedence.
+pub(crate) fn python_executables<'a>(
+    precedence: ProviderPrecedence,
+    version: &'a VersionRequest,
+    implementation: Option<&'a ImplementationName>,
+) -> impl Iterator<Item = (PythonSource, PathBuf)> + 'a {
+    candidates(providers(), precedence, version, implementation)
+}
+
+fn candidates<'a>(
+    providers: Vec<Arc<dyn PythonProvider>>,
+    precedence: ProviderPrecedence,
+    version: &'a VersionRequest,
+    implementation: Option<&'a ImplementationName>,
+) -> impl Iterator<Item = (PythonSource, PathBuf)> + 'a {
+    providers
+        .into_iter()
+        .filter(move |provider| provider.precedence() == precedence)
+        .flat_map(move |provider| {
+            let name = provider.name();
+            debug!("Searching for Python interpreters with provider `{name}`");
+            let paths = provider
+                .find(version, implementation)
+                .unwrap_or_else(|err| {
+                    warn_user_once!(
+                        "Failed to find Python interpreters with provider `{name}`: {err}"
+                    );
+                    Vec::new()
+                });
+            paths
+                .into_iter()
+                .map(move |path| (PythonSource::Provider(name), path))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use super::{ProviderError, ProviderPrecedence, PythonProvider, candidates};
+    use crate::discovery::{PythonSource, VersionRequest};
+    use crate::implementation::ImplementationName;
+
+    struct StaticProvider {
+        name: &'static str,
+        precedence: ProviderPrecedence,
+        paths: Vec<PathBuf>,
+    }
+
+    impl PythonProvider for StaticProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn precedence(&self) -> ProviderPrecedence {
+            self.precedence
+        }
+
+        fn find(
+            &self,
+            _version: &VersionRequest,
+            _implementation: Option<&ImplementationName>,
+        ) -> Result<Vec<PathBuf>, ProviderError> {
+            Ok(self.paths.clone())
+        }
+    }
+
+    struct FailingProvider;
+
+    impl PythonProvider for FailingProvider {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        fn find(
+            &self,
+            _version: &VersionRequest,
+            _implementation: Option<&ImplementationName>,
+        ) -> Result<Vec<PathBuf>, ProviderError> {
+            Err("service unavailable".into())
+        }
+    }
+
+    #[test]
+    fn candidates_by_precedence() {
+        let providers: Vec<Arc<dyn PythonProvider>> = vec![
+            Arc::new(StaticProvider {
+                name: "first",
+                precedence: ProviderPrecedence::First,
+                paths: vec![PathBuf::from("/opt/first/python3")],
+            }),
+            Arc::new(FailingProvider),
+            Arc::new(StaticProvider {
+                name: "last",
+                precedence: ProviderPrecedence::Last,
+                paths: vec![
+                    PathBuf::from("/opt/last/python3.12"),
+                    PathBuf::from("/opt/last/python3"),
+                ],
+            }),
+        ];
+
+        let version = VersionRequest::Default;
+        let found = |precedence| {
+            candidates(providers.clone(), precedence, &version, None).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            found(ProviderPrecedence::First),
+            vec![(
+                PythonSource::Provider("first"),
+                PathBuf::from("/opt/first/python3")
+            )]
+        );
+        assert_eq!(found(ProviderPrecedence::BeforeSearchPath), vec![]);
+        assert_eq!(
+            found(ProviderPrecedence::Last),
+            vec![
+                (
+                    PythonSource::Provider("last"),
+                    PathBuf::from("/opt/last/python3.12")
+                ),
+                (
+                    PythonSource::Provider("last"),
+                    PathBuf::from("/opt/last/python3")
+                ),
+            ]
+        );
+    }
+}
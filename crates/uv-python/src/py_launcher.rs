@@ -0,0 +1,241 @@
+//! Pythons known to the Windows `py` launcher.
+//!
+//! The launcher reads the PEP 514 registry entries and the Microsoft Store locations, which we
+//! search directly, but it also reports interpreters that are only known to the launcher, e.g.,
+//! custom commands configured in a `py.ini` file. We ask the launcher for the interpreters it
+//! knows about via `py --list-paths`, and read the `[commands]` sections of `py.ini` files, which
+//! older versions of the launcher do not include in their listing.
+//!
+//! See <https://docs.python.org/3/using/windows.html#python-launcher-for-windows>
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_static::EnvVars;
+
+use crate::PythonVersion;
+use crate::windows_registry::WindowsPython;
+
+/// Find all Pythons known to the `py` launcher, if it is installed.
+pub(crate) fn py_launcher_pythons() -> Vec<WindowsPython> {
+    let Ok(launcher) = which::which("py") else {
+        debug!("The `py` launcher was not found");
+        return Vec::new();
+    };
+
+    let mut pythons = list_paths(&launcher);
+
+    // The per-user configuration takes precedence over the configuration next to the launcher.
+    let configs = env::var_os(EnvVars::LOCALAPPDATA)
+        .map(|local_app_data| PathBuf::from(local_app_data).join("py.ini"))
+        .into_iter()
+        .chain(launcher.parent().map(|parent| parent.join("py.ini")));
+    for config in configs {
+        match fs_err::read_to_string(&config) {
+            Ok(contents) => pythons.extend(parse_py_ini(&contents)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => debug!("Failed to read `{}`: {err}", config.user_display()),
+        }
+    }
+
+    let mut seen = rustc_hash::FxHashSet::default();
+    pythons.retain(|python| seen.insert(python.path.clone()));
+    pythons
+}
+
+/// Run `py --list-paths` and parse its output.
+fn list_paths(launcher: &Path) -> Vec<WindowsPython> {
+    let output = match Command::new(launcher).arg("--list-paths").output() {
+        Ok(output) => output,
+        Err(err) => {
+            debug!(
+                "Failed to list Pythons with `{}`: {err}",
+                launcher.user_display()
+            );
+            return Vec::new();
+        }
+    };
+    if !output.status.success() {
+        debug!(
+            "Failed to list Pythons with `{}`: {}",
+            launcher.user_display(),
+            output.status
+        );
+        return Vec::new();
+    }
+    parse_list_paths(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the output of `py --list-paths`.
+///
+/// Each interpreter is listed on its own line, as a tag followed by the path to the executable,
+/// with the default interpreter marked with an asterisk, e.g.:
+///
+/// ```text
+///  -V:3.12 *        C:\Users\ferris\AppData\Local\Programs\Python\Python312\python.exe
+///  -V:Custom/3.13   C:\Custom\python.exe
+/// ```
+///
+/// Older versions of the launcher use the `-3.12-64` tag format instead, and place the asterisk
+/// after the path. Lines that don't start with a tag, e.g., the active virtual environment, are
+/// skipped.
+fn parse_list_paths(output: &str) -> Vec<WindowsPython> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let (tag, rest) = line.split_once(char::is_whitespace)?;
+            let tag = tag.strip_prefix('-')?;
+            let path = rest.trim();
+            let path = path.strip_prefix('*').unwrap_or(path).trim_start();
+            let path = path.strip_suffix('*').unwrap_or(path).trim_end();
+            if path.is_empty() {
+                return None;
+            }
+            Some(WindowsPython {
+                path: PathBuf::from(path),
+                version: tag_version(tag),
+            })
+        })
+        .collect()
+}
+
+/// Parse the Python version from a `py` launcher tag, e.g., `V:3.12`, `V:PythonCore/3.12`, or
+/// `3.12-64`.
+///
+/// Tags for other companies are free-form, so we don't read a version from them.
+fn tag_version(tag: &str) -> Option<PythonVersion> {
+    let tag = match tag.strip_prefix("V:") {
+        Some(tag) => match tag.split_once('/') {
+            Some(("PythonCore", tag)) => tag,
+            Some(_) => return None,
+            None => tag,
+        },
+        None => tag,
+    };
+    let end = tag
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(tag.len());
+    PythonVersion::from_str(&tag[..end]).ok()
+}
+
+/// Parse the executables of the custom commands in the `[commands]` section of a `py.ini` file.
+///
+/// Each command maps a name to a command line, e.g., `mypython=C:\Custom\python.exe -X utf8`. We
+/// only read the executable, which may be quoted.
+fn parse_py_ini(contents: &str) -> Vec<WindowsPython> {
+    let mut in_commands = false;
+    let mut pythons = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with([';', '#']) {
+            continue;
+        }
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            in_commands = section.trim().eq_ignore_ascii_case("commands");
+            continue;
+        }
+        if !in_commands {
+            continue;
+        }
+        let Some((_, command)) = line.split_once('=') else {
+            continue;
+        };
+        let command = command.trim();
+        let executable = if let Some(command) = command.strip_prefix('"') {
+            command.split_once('"').map(|(executable, _)| executable)
+        } else {
+            let lower = command.to_ascii_lowercase();
+            lower
+                .find(".exe")
+                .map(|index| &command[..index + ".exe".len()])
+        };
+        let Some(executable) = executable.filter(|executable| !executable.is_empty()) else {
+            debug!("Skipping `py.ini` command without an executable: `{line}`");
+            continue;
+        };
+        pythons.push(WindowsPython {
+            path: PathBuf::from(executable),
+            version: None,
+        });
+    }
+    pythons
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use indoc::indoc;
+
+    use crate::PythonVersion;
+
+    use super::{parse_list_paths, parse_py_ini, tag_version};
+
+    #[test]
+    fn list_paths() {
+        let output = indoc! {r"
+             -V:3.12 *        C:\Python312\python.exe
+             -V:Custom/3.13   C:\Custom Build\python.exe
+             -3.11-64         C:\Python311\python.exe *
+              *               C:\project\.venv\Scripts\python.exe
+            No installed Pythons found!
+        "};
+        let pythons = parse_list_paths(output);
+        assert_eq!(
+            pythons
+                .iter()
+                .map(|python| python.path.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PathBuf::from(r"C:\Python312\python.exe"),
+                PathBuf::from(r"C:\Custom Build\python.exe"),
+                PathBuf::from(r"C:\Python311\python.exe"),
+            ]
+        );
+    }
+
+    #[test]
+    fn tag_versions() {
+        assert_eq!(tag_version("V:3.12"), PythonVersion::from_str("3.12").ok());
+        assert_eq!(
+            tag_version("V:PythonCore/3.13t"),
+            PythonVersion::from_str("3.13").ok()
+        );
+        assert_eq!(tag_version("3.11-64"), PythonVersion::from_str("3.11").ok());
+        assert_eq!(tag_version("V:Custom/3.13"), None);
+    }
+
+    #[test]
+    fn py_ini() {
+        let contents = indoc! {r#"
+            [defaults]
+            python=3.12
+
+            [commands]
+            ; A comment
+            custom=C:\Custom\python.exe -X utf8
+            quoted="C:\Program Files\Custom\python.exe" -u
+            script=C:\Tools\run
+        "#};
+        assert_eq!(
+            parse_py_ini(contents)
+                .into_iter()
+                .map(|python| python.path)
+                .collect::<Vec<_>>(),
+            vec![
+                PathBuf::from(r"C:\Custom\python.exe"),
+                PathBuf::from(r"C:\Program Files\Custom\python.exe"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Quarantine of interpreters whose queries fail repeatedly.
+//!
+//! Failed queries aren't cached, so a broken interpreter on the search path (e.g., one with a
+//! missing shared library) would otherwise be spawned, and fail, on every invocation. When enabled
+//! with `UV_PYTHON_QUARANTINE_THRESHOLD`, the interpreter is skipped after that number of
+//! consecutive failures until a cooldown period has elapsed, after which it's queried again. A
+//! successful query, or a change to the executable, resets the count.
+//!
+//! Timeouts aren't counted as failures, since they're often caused by a busy system rather than a
+//! broken interpreter.
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_info::Timestamp;
+use uv_cache_key::cache_digest;
+use uv_fs::{Simplified, write_atomic_sync};
+use uv_static::EnvVars;
+
+use crate::host;
+use crate::query_limits::parse_env;
+
+/// The default time for which a quarantined interpreter is skipped.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60 * 60);
+
+/// The consecutive failed queries of a Python interpreter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quarantine {
+    /// The path to the interpreter.
+    pub path: PathBuf,
+    /// The modification time of the interpreter executable when the failures occurred.
+    modified: Timestamp,
+    /// The number of consecutive failed queries.
+    pub failures: u64,
+    /// The time of the most recent failure.
+    last_failure: SystemTime,
+    /// The reason for the most recent failure.
+    pub reason: String,
+}
+
+impl Quarantine {
+    /// The number of consecutive failures after which interpreters are quarantined, or `None` if
+    /// quarantine is disabled, which is the default.
+    fn threshold() -> Option<u64> {
+        parse_env::<u64>(EnvVars::UV_PYTHON_QUARANTINE_THRESHOLD).filter(|threshold| *threshold > 0)
+    }
+
+    /// The time for which a quarantined interpreter is skipped.
+    fn cooldown() -> Duration {
+        parse_env::<u64>(EnvVars::UV_PYTHON_QUARANTINE_COOLDOWN)
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_COOLDOWN)
+    }
+
+    /// The directory in which quarantine records are stored.
+    fn directory(cache: &Cache) -> PathBuf {
+        cache.bucket(CacheBucket::Interpreter).join("quarantine")
+    }
+
+    /// The stable path of the record for the interpreter with the given absolute and canonical
+    /// paths.
+    fn record_path(cache: &Cache, absolute: &Path, canonical: &Path) -> PathBuf {
+        Self::directory(cache).join(format!("{}.json", cache_digest(&(absolute, canonical))))
+    }
+
+    /// Read the record for the interpreter, if any.
+    fn read(record_path: &Path) -> Option<Self> {
+        let data = fs_err::read(record_path).ok()?;
+        serde_json::from_slice(&data).ok()
+    }
+
+    /// Returns the remaining time for which the interpreter is skipped, if it's quarantined.
+    ///
+    /// Returns `None` once the cooldown period has elapsed, such that the interpreter is queried
+    /// again.
+    pub fn remaining(&self) -> Option<Duration> {
+        let threshold = Self::threshold()?;
+        if self.failures < threshold {
+            return None;
+        }
+        let elapsed = host::clock()
+            .now()
+            .duration_since(self.last_failure)
+            .unwrap_or_default();
+        Self::cooldown()
+            .checked_sub(elapsed)
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Returns the record for the interpreter if it's quarantined, i.e., if its most recent queries
+    /// failed and it hasn't been modified since.
+    pub(crate) fn check(
+        cache: &Cache,
+        absolute: &Path,
+        canonical: &Path,
+        modified: Timestamp,
+    ) -> Option<Self> {
+        let quarantine = Self::read(&Self::record_path(cache, absolute, canonical))?;
+        if quarantine.modified != modified {
+            return None;
+        }
+        quarantine.remaining()?;
+        Some(quarantine)
+    }
+
+    /// Record a failed query of the interpreter.
+    ///
+    /// Failures to write the record are logged and ignored.
+    pub(crate) fn record_failure(
+        cache: &Cache,
+        absolute: &Path,
+        canonical: &Path,
+        modified: Timestamp,
+        reason: String,
+    ) {
+        if Self::threshold().is_none() {
+            return;
+        }
+
+        let record_path = Self::record_path(cache, absolute, canonical);
+        let failures = Self::read(&record_path)
+            .filter(|previous| previous.modified == modified)
+            .map_or(0, |previous| previous.failures);
+        let quarantine = Self {
+            path: absolute.to_path_buf(),
+            modified,
+            failures: failures + 1,
+            last_failure: host::clock().now(),
+            reason,
+        };
+
+        let result = serde_json::to_vec_pretty(&quarantine)
+            .map_err(io::Error::from)
+            .and_then(|data| {
+                fs_err::create_dir_all(Self::directory(cache))?;
+                write_atomic_sync(&record_path, data)
+            });
+        match result {
+            Ok(()) => {
+                if quarantine.remaining().is_some() {
+                    debug!(
+                        "Quarantining Python at {} after {} consecutive failed queries",
+                        absolute.user_display(),
+                        quarantine.failures
+                    );
+                }
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to record failed query of {}: {err}",
+                    absolute.user_display()
+                );
+            }
+        }
+    }
+
+    /// Clear the failures of the interpreter, after a successful query.
+    pub(crate) fn clear(cache: &Cache, absolute: &Path, canonical: &Path) {
+        let record_path = Self::record_path(cache, absolute, canonical);
+        match fs_err::remove_file(&record_path) {
+            Ok(()) => {
+                debug!(
+                    "Cleared failed queries of Python at {}",
+                    absolute.user_display()
+                );
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => {
+                warn!(
+                    "Failed to clear failed queries of {}: {err}",
+                    absolute.user_display()
+                );
+            }
+        }
+    }
+
+    /// Read all quarantined interpreters, sorted by path.
+    pub fn read_all(cache: &Cache) -> Result<Vec<Self>, io::Error> {
+        let entries = match fs_err::read_dir(Self::directory(cache)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut quarantined = Vec::new();
+        for entry in entries {
+            let record_path = entry?.path();
+            if record_path
+                .extension()
+                .is_none_or(|extension| extension != "json")
+            {
+                continue;
+            }
+            let Some(quarantine) = Self::read(&record_path) else {
+                warn!(
+                    "Ignoring malformed quarantine record at {}",
+                    record_path.user_display()
+                );
+                continue;
+            };
+            if quarantine.remaining().is_some() {
+                quarantined.push(quarantine);
+            }
+        }
+        quarantined.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(quarantined)
+    }
+}
@@ -0,0 +1,107 @@
+//! User-configured environment variables for specific Python executables.
+//!
+//! Some interpreters, e.g., those built into a nonstandard prefix, can't start without additional
+//! environment variables, like `LD_LIBRARY_PATH`. The variables are configured via the cache's
+//! [`InterpreterSettings`] and applied when querying the interpreter, and recorded on the
+//! [`Interpreter`](crate::Interpreter) such that subsequent invocations can apply them too.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tracing::debug;
+
+use uv_cache::InterpreterSettings;
+
+use crate::interpreter::canonicalize_executable;
+
+/// Return the environment variables configured for the Python executable at `absolute`, which
+/// resolves to `canonical`.
+///
+/// Variables configured for an executable that resolves to `canonical` (e.g., the base interpreter
+/// of a virtual environment) are inherited, but those configured for the executable itself take
+/// precedence.
+pub(crate) fn query_env(
+    settings: &InterpreterSettings,
+    absolute: &Path,
+    canonical: &Path,
+) -> BTreeMap<String, String> {
+    let env = settings.query_env();
+    if env.is_empty() {
+        return BTreeMap::new();
+    }
+    let mut vars = BTreeMap::new();
+    for (path, configured) in env {
+        if path != absolute
+            && (path == canonical
+                || (path.is_absolute()
+                    && canonicalize_executable(path).is_ok_and(|resolved| resolved == canonical)))
+        {
+            vars.extend(configured.clone());
+        }
+    }
+    if let Some(overrides) = env.get(absolute) {
+        vars.extend(overrides.clone());
+    }
+    if !vars.is_empty() {
+        debug!(
+            "Applying `python-query-env` for {}: {}",
+            absolute.display(),
+            vars.keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    vars
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use assert_fs::prelude::*;
+    use uv_cache::InterpreterSettings;
+
+    use super::query_env;
+
+    #[test]
+    fn query_env_precedence() {
+        let temp_dir = assert_fs::TempDir::new().unwrap();
+        let base = temp_dir.child("base").child("python");
+        let venv = temp_dir.child("venv").child("python");
+        let other = temp_dir.child("other").child("python");
+
+        let settings = InterpreterSettings::default().with_query_env(BTreeMap::from([
+            (
+                base.to_path_buf(),
+                BTreeMap::from([
+                    ("LD_LIBRARY_PATH".to_string(), "/opt/base/lib".to_string()),
+                    ("BASE".to_string(), "1".to_string()),
+                ]),
+            ),
+            (
+                venv.to_path_buf(),
+                BTreeMap::from([("LD_LIBRARY_PATH".to_string(), "/opt/venv/lib".to_string())]),
+            ),
+        ]));
+
+        // The variables configured for the executable itself take precedence over those
+        // configured for the executable it resolves to.
+        assert_eq!(
+            query_env(&settings, venv.path(), base.path()),
+            BTreeMap::from([
+                ("BASE".to_string(), "1".to_string()),
+                ("LD_LIBRARY_PATH".to_string(), "/opt/venv/lib".to_string()),
+            ])
+        );
+        assert_eq!(
+            query_env(&settings, base.path(), base.path()),
+            BTreeMap::from([
+                ("BASE".to_string(), "1".to_string()),
+                ("LD_LIBRARY_PATH".to_string(), "/opt/base/lib".to_string()),
+            ])
+        );
+        assert!(query_env(&settings, other.path(), other.path()).is_empty());
+        assert!(query_env(&InterpreterSettings::default(), base.path(), base.path()).is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+//! Records of failed interpreter queries.
+//!
+//! When `UV_PYTHON_RECORD_QUERY_FAILURES` is set, the output of each failed query of a candidate
+//! interpreter is persisted to the cache, along with the number of times the query has failed, so
+//! that it's possible to determine why an interpreter was skipped during discovery.
+use std::env;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_fs::{Simplified, write_atomic_sync};
+use uv_static::EnvVars;
+
+/// A record of the failed queries of a Python interpreter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryFailure {
+    /// The path to the interpreter.
+    pub path: PathBuf,
+    /// The number of times a query of the interpreter has failed.
+    pub count: u64,
+    /// The reason for the most recent failure, e.g., the exit status.
+    pub reason: String,
+    /// The standard output of the most recent failure.
+    pub stdout: String,
+    /// The standard error of the most recent failure.
+    pub stderr: String,
+}
+
+impl QueryFailure {
+    /// Returns `true` if query failures should be recorded.
+    pub fn enabled() -> bool {
+        env::var(EnvVars::UV_PYTHON_RECORD_QUERY_FAILURES).is_ok_and(|value| {
+            matches!(
+                value.to_lowercase().as_str(),
+                "y" | "yes" | "t" | "true" | "on" | "1"
+            )
+        })
+    }
+
+    /// The directory in which query failures are recorded.
+    fn directory(cache: &Cache) -> PathBuf {
+        cache.bucket(CacheBucket::Interpreter).join("failures")
+    }
+
+    /// The stable path of the record for the interpreter at `path`.
+    fn record_path(cache: &Cache, path: &Path) -> PathBuf {
+        Self::directory(cache).join(format!("{}.json", cache_digest(&path)))
+    }
+
+    /// Record a failed query of the interpreter at `path`, if recording is enabled.
+    ///
+    /// Returns the path to the record. Failures to write the record are logged and ignored.
+    pub(crate) fn record(
+        cache: &Cache,
+        path: &Path,
+        reason: String,
+        stdout: &str,
+        stderr: &str,
+    ) -> Option<PathBuf> {
+        if !Self::enabled() {
+            return None;
+        }
+
+        let record_path = Self::record_path(cache, path);
+        let count = fs_err::read(&record_path)
+            .ok()
+            .and_then(|data| serde_json::from_slice::<Self>(&data).ok())
+            .map_or(0, |previous| previous.count);
+        let failure = Self {
+            path: path.to_path_buf(),
+            count: count + 1,
+            reason,
+            stdout: stdout.to_string(),
+            stderr: stderr.to_string(),
+        };
+
+        let result = serde_json::to_vec_pretty(&failure)
+            .map_err(io::Error::from)
+            .and_then(|data| {
+                fs_err::create_dir_all(Self::directory(cache))?;
+                write_atomic_sync(&record_path, data)
+            });
+        match result {
+            Ok(()) => {
+                debug!(
+                    "Recorded failed query of {} at: {}",
+                    path.user_display(),
+                    record_path.user_display()
+                );
+                Some(record_path)
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to record failed query of {}: {err}",
+                    path.user_display()
+                );
+                None
+            }
+        }
+    }
+
+    /// Read all recorded query failures, along with the paths to their records.
+    ///
+    /// Records are sorted by interpreter path.
+    pub fn read_all(cache: &Cache) -> Result<Vec<(PathBuf, Self)>, io::Error> {
+        let entries = match fs_err::read_dir(Self::directory(cache)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut failures = Vec::new();
+        for entry in entries {
+            let record_path = entry?.path();
+            if record_path
+                .extension()
+                .is_none_or(|extension| extension != "json")
+            {
+                continue;
+            }
+            match serde_json::from_slice::<Self>(&fs_err::read(&record_path)?) {
+                Ok(failure) => failures.push((record_path, failure)),
+                Err(err) => {
+                    warn!(
+                        "Ignoring malformed query failure record at {}: {err}",
+                        record_path.user_display()
+                    );
+                }
+            }
+        }
+        failures.sort_by(|(_, a), (_, b)| a.path.cmp(&b.path));
+        Ok(failures)
+    }
+}
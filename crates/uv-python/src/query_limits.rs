@@ -0,0 +1,187 @@
+//! Limits on the subprocesses used to query Python interpreters.
+//!
+//! Querying a candidate interpreter runs it, so a hung interpreter (e.g., one on an unresponsive
+//! network mount) would otherwise hang discovery indefinitely. Each query is subject to an optional
+//! timeout, optional memory and CPU time limits (on Unix), and a cap on the number of concurrent
+//! queries, as configured via the cache's [`InterpreterSettings`].
+use std::env;
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::{debug, warn};
+
+use uv_cache::{InterpreterSettings, QuerySlots};
+
+/// The interval at which a running query is polled for completion.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The limits applied to interpreter query subprocesses.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QueryLimits<'a> {
+    /// The time after which a query is killed, if any.
+    timeout: Option<Duration>,
+    /// The maximum size of the virtual memory of a query, in bytes, if any.
+    memory: Option<u64>,
+    /// The maximum CPU time of a query, in seconds, if any.
+    cpu: Option<u64>,
+    /// The slots for concurrent queries.
+    slots: &'a QuerySlots,
+}
+
+impl<'a> QueryLimits<'a> {
+    /// Read the query limits from the [`InterpreterSettings`].
+    pub(crate) fn from_settings(settings: &'a InterpreterSettings) -> Self {
+        Self {
+            timeout: settings.query_timeout(),
+            memory: settings.query_memory_limit(),
+            cpu: settings.query_cpu_limit(),
+            slots: settings.query_slots(),
+        }
+    }
+
+    /// The time after which a query is killed, if any.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Run the query `command` to completion, subject to these limits.
+    ///
+    /// Returns `Ok(None)` if the query was killed after exceeding the timeout.
+    pub(crate) fn output(&self, command: &mut Command) -> io::Result<Option<Output>> {
+        let _permit = self.slots.acquire();
+
+        self.apply_resource_limits(command);
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        // Drain the pipes on separate threads, so that a query with a large output can't block
+        // on a full pipe while we wait for it to exit.
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+
+        let Some(status) = self.wait(&mut child)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Output {
+            status,
+            stdout: stdout.join().unwrap_or_default(),
+            stderr: stderr.join().unwrap_or_default(),
+        }))
+    }
+
+    /// Wait for `child` to exit, killing it if it exceeds the timeout.
+    fn wait(&self, child: &mut Child) -> io::Result<Option<ExitStatus>> {
+        let Some(timeout) = self.timeout else {
+            return child.wait().map(Some);
+        };
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
+            if Instant::now() >= deadline {
+                debug!("Killing interpreter query after {}s", timeout.as_secs_f32());
+                // The child may have exited in the meantime, in which case there's nothing to kill.
+                let _ = child.kill();
+                let _ = child.wait();
+                return Ok(None);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Apply the memory and CPU time limits to the query `command`.
+    #[cfg(unix)]
+    #[allow(unsafe_code)]
+    fn apply_resource_limits(&self, command: &mut Command) {
+        use std::os::unix::process::CommandExt;
+
+        use nix::sys::resource::{Resource, setrlimit};
+
+        let Self { memory, cpu, .. } = *self;
+        if memory.is_none() && cpu.is_none() {
+            return;
+        }
+
+        // SAFETY: `setrlimit` is async-signal-safe, and the closure does not allocate.
+        unsafe {
+            command.pre_exec(move || {
+                if let Some(memory) = memory {
+                    setrlimit(Resource::RLIMIT_AS, memory, memory)?;
+                }
+                if let Some(cpu) = cpu {
+                    setrlimit(Resource::RLIMIT_CPU, cpu, cpu)?;
+                }
+                Ok(())
+            });
+        }
+    }
+
+    /// Apply the memory and CPU time limits to the query `command`.
+    ///
+    /// Resource limits are not supported on this platform.
+    #[cfg(not(unix))]
+    fn apply_resource_limits(&self, _command: &mut Command) {
+        if self.memory.is_some() || self.cpu.is_some() {
+            debug!("Ignoring interpreter query resource limits, which are only supported on Unix");
+        }
+    }
+}
+
+/// Read the stream to completion on a separate thread.
+fn drain(stream: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut stream) = stream {
+            let _ = stream.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+/// Parse the environment variable `name`, warning if it is set to an invalid value.
+pub(crate) fn parse_env<T: std::str::FromStr>(name: &str) -> Option<T> {
+    let value = env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(value) => Some(value),
+        Err(_) => {
+            warn!("Ignoring invalid value for `{name}`: `{value}`");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+    use std::time::Duration;
+
+    use uv_cache::InterpreterSettings;
+
+    use super::QueryLimits;
+
+    #[test]
+    #[cfg(unix)]
+    fn query_timeout() {
+        let settings =
+            InterpreterSettings::default().with_query_timeout(Some(Duration::from_millis(100)));
+        let limits = QueryLimits::from_settings(&settings);
+        let output = limits
+            .output(Command::new("sleep").arg("10"))
+            .expect("spawning `sleep` should succeed");
+        assert!(output.is_none());
+
+        let output = limits
+            .output(Command::new("echo").arg("hello"))
+            .expect("spawning `echo` should succeed")
+            .expect("`echo` should complete within the timeout");
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"hello\n");
+    }
+}
@@ -0,0 +1,164 @@
+//! Additional probes to run when querying a Python interpreter.
+//!
+//! Applications that embed uv can register probes on the [`InterpreterSettings`] of the cache to
+//! collect extra information about each interpreter, e.g., whether CUDA is available, as part of
+//! the standard interpreter query, rather than spawning a second subprocess. The results are cached
+//! alongside the standard metadata and exposed via
+//! [`Interpreter::extensions`](crate::Interpreter::extensions).
+//!
+//! The probes are part of the cache key, such that interpreters are re-queried when the set of
+//! probes changes.
+//!
+//! [`InterpreterSettings`]: uv_cache::InterpreterSettings
+
+use uv_cache::Cache;
+
+/// A Python snippet to run as part of the interpreter query.
+///
+/// The source must define a `probe()` function that takes no arguments and returns a
+/// JSON-serializable value. The function is called in the interpreter being queried, in isolated
+/// mode, and its return value is stored under the probe's name. If the snippet raises an
+/// exception, the probe is omitted from the results. Anything the probe writes to `stdout` is
+/// redirected to `stderr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryProbe {
+    name: String,
+    source: String,
+}
+
+impl QueryProbe {
+    /// Create a [`QueryProbe`] with the given name and Python source.
+    pub fn new(name: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            source: source.into(),
+        }
+    }
+
+    /// The name of the probe returned by [`QueryProbe::features`].
+    pub const FEATURES: &str = "features";
+
+    /// A probe that reports the optional standard library features that can be imported, e.g.,
+    /// `["ssl", "sqlite3"]`.
+    ///
+    /// The features aren't part of the standard query, as importing the extension modules (e.g.,
+    /// `_tkinter`) is slow. The probe is registered when a request requires a feature, e.g.,
+    /// `3.12+ssl`, and reported as [`Interpreter::features`](crate::Interpreter::features).
+    pub fn features() -> Self {
+        Self::new(
+            Self::FEATURES,
+            r#"
+def probe():
+    # The extension module that each feature requires. Distributions frequently omit these modules
+    # when the corresponding system library was missing at build time.
+    modules = {
+        "ssl": "_ssl",
+        "sqlite3": "_sqlite3",
+        "lzma": "_lzma",
+        "bz2": "_bz2",
+        "zlib": "zlib",
+        "ctypes": "_ctypes",
+        "tkinter": "_tkinter",
+    }
+    features = []
+    for feature, module in modules.items():
+        try:
+            # Import the module, rather than searching for it, since the module may be present but
+            # fail to load its shared library.
+            __import__(module)
+        except ImportError:
+            continue
+        features.append(feature)
+    return features
+"#,
+        )
+    }
+
+    /// Return a [`Cache`] on which the [`QueryProbe::features`] probe is registered, in addition
+    /// to any probes registered on the given cache.
+    #[must_use]
+    pub fn with_features(cache: &Cache) -> Cache {
+        let settings = cache.interpreter_settings();
+        if settings.query_probes().contains_key(Self::FEATURES) {
+            return cache.clone();
+        }
+        let probes = settings
+            .query_probes()
+            .iter()
+            .map(|(name, source)| (name.clone(), source.clone()))
+            .chain([Self::features().into()])
+            .collect::<Vec<_>>();
+        cache
+            .clone()
+            .with_interpreter_settings(settings.clone().with_query_probes(probes))
+    }
+
+    /// A probe that reports the glibc version the interpreter is running against, e.g.,
+    /// `"2.39"`, or `None` on other platforms.
+    pub fn glibc_version() -> Self {
+        Self::new(
+            "glibc_version",
+            r#"
+def probe():
+    import os
+
+    try:
+        version = os.confstr("CS_GNU_LIBC_VERSION")
+    except (AttributeError, OSError, ValueError):
+        return None
+    if not version or not version.startswith("glibc "):
+        return None
+    return version.split(" ", 1)[1]
+"#,
+        )
+    }
+
+    /// A probe that reports the version of the CUDA driver available to the interpreter, e.g.,
+    /// `"12.4"`, or `None` if the driver can't be loaded.
+    pub fn cuda_driver_version() -> Self {
+        Self::new(
+            "cuda_driver_version",
+            r#"
+def probe():
+    import ctypes
+    import ctypes.util
+    import sys
+
+    if sys.platform == "win32":
+        names = ["nvcuda.dll"]
+    else:
+        names = ["libcuda.so.1", "libcuda.so", ctypes.util.find_library("cuda")]
+    for name in names:
+        if not name:
+            continue
+        try:
+            cuda = ctypes.CDLL(name)
+        except OSError:
+            continue
+        version = ctypes.c_int()
+        if cuda.cuDriverGetVersion(ctypes.byref(version)) != 0:
+            return None
+        return "%d.%d" % (version.value // 1000, (version.value % 1000) // 10)
+    return None
+"#,
+        )
+    }
+
+    /// The name under which the probe's result is stored.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Python source of the probe.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl From<QueryProbe> for (String, String) {
+    /// Return the pair of name and source, as registered with
+    /// [`InterpreterSettings::with_query_probes`](uv_cache::InterpreterSettings::with_query_probes).
+    fn from(probe: QueryProbe) -> Self {
+        (probe.name, probe.source)
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+
+use owo_colors::OwoColorize;
+use uv_fs::Simplified;
+
+use crate::{PythonPreference, PythonRequest};
+
+/// An actionable suggestion attached to a [`crate::Error::MissingPython`] error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Remediation {
+    /// A managed download is available, but Python downloads are set to `manual`.
+    InstallManually(PythonRequest),
+    /// A managed download is available, but Python downloads are set to `never`.
+    DownloadsDisabled(PythonRequest),
+    /// A managed download is available, but the Python preference excludes managed installations.
+    PreferenceExcludesManaged(PythonRequest, PythonPreference),
+    /// A managed download is available, but uv is in offline mode.
+    Offline(PythonRequest),
+    /// The requested patch version does not exist, but other patch versions of the same minor
+    /// version are available.
+    UnknownPatch {
+        /// The original request, including the unavailable patch version.
+        request: PythonRequest,
+        /// The same request without the patch version.
+        alternative: PythonRequest,
+        /// The latest available patch version, e.g., `3.12.11`.
+        latest: String,
+    },
+    /// The request was read from a version file outside of the project directory.
+    ParentVersionFile {
+        /// The request declared in the version file.
+        request: PythonRequest,
+        /// The path to the version file.
+        path: PathBuf,
+    },
+}
+
+impl Remediation {
+    /// Return ` for <request>`, or an empty string for requests that don't constrain the version.
+    fn for_request(request: &PythonRequest) -> String {
+        match request {
+            PythonRequest::Default | PythonRequest::Any => String::new(),
+            _ => format!(" for {request}"),
+        }
+    }
+}
+
+impl Display for Remediation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InstallManually(request) => write!(
+                f,
+                "A managed Python download is available{}, but Python downloads are set to 'manual', use `uv python install {}` to install the required version",
+                Self::for_request(request),
+                request.to_canonical_string(),
+            ),
+            Self::DownloadsDisabled(request) => write!(
+                f,
+                "A managed Python download is available{}, but Python downloads are set to 'never'",
+                Self::for_request(request),
+            ),
+            Self::PreferenceExcludesManaged(request, preference) => write!(
+                f,
+                "A managed Python download is available{}, but the Python preference is set to '{preference}'",
+                Self::for_request(request),
+            ),
+            Self::Offline(request) => write!(
+                f,
+                "A managed Python download is available{}, but uv is set to offline mode",
+                Self::for_request(request),
+            ),
+            Self::UnknownPatch {
+                request,
+                alternative,
+                latest,
+            } => write!(
+                f,
+                "No managed Python download is available for {request}, the latest available patch version is {latest}; request `{}` to use any patch version",
+                alternative.to_canonical_string(),
+            ),
+            Self::ParentVersionFile { request, path } => write!(
+                f,
+                "The request for {request} was read from `{}` in a parent directory, use `uv python pin` to set a version for this project",
+                path.user_display(),
+            ),
+        }
+    }
+}
+
+/// Render a list of [`Remediation`]s as `hint:` lines, for appending to an error message.
+pub(crate) fn format_hints(remediations: &[Remediation]) -> String {
+    remediations
+        .iter()
+        .map(|remediation| format!("\n\n{}{} {remediation}", "hint".bold().cyan(), ":".bold()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{PythonPreference, PythonRequest};
+
+    use super::Remediation;
+
+    #[test]
+    fn display() {
+        let request = PythonRequest::parse("3.12");
+        assert_eq!(
+            Remediation::InstallManually(request.clone()).to_string(),
+            "A managed Python download is available for Python 3.12, but Python downloads are set to 'manual', use `uv python install 3.12` to install the required version"
+        );
+        assert_eq!(
+            Remediation::DownloadsDisabled(PythonRequest::Default).to_string(),
+            "A managed Python download is available, but Python downloads are set to 'never'"
+        );
+        assert_eq!(
+            Remediation::PreferenceExcludesManaged(request.clone(), PythonPreference::OnlySystem)
+                .to_string(),
+            "A managed Python download is available for Python 3.12, but the Python preference is set to 'only system'"
+        );
+        assert_eq!(
+            Remediation::UnknownPatch {
+                request: PythonRequest::parse("3.12.99"),
+                alternative: request.clone(),
+                latest: "3.12.11".to_string(),
+            }
+            .to_string(),
+            "No managed Python download is available for Python 3.12.99, the latest available patch version is 3.12.11; request `3.12` to use any patch version"
+        );
+        assert_eq!(
+            Remediation::ParentVersionFile {
+                request,
+                path: PathBuf::from("../.python-version"),
+            }
+            .to_string(),
+            "The request for Python 3.12 was read from `../.python-version` in a parent directory, use `uv python pin` to set a version for this project"
+        );
+    }
+}
@@ -0,0 +1,254 @@
+//! Programmatic construction of [`PythonRequest`]s.
+//!
+//! Embedders that need to combine constraints, e.g., an implementation with a version range, can
+//! use a [`PythonRequestBuilder`] rather than formatting a string for [`PythonRequest::parse`].
+//! The canonical representation of the resulting request (see [`PythonRequest::canonical`])
+//! parses back to the same request.
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use uv_pep440::VersionSpecifiers;
+
+use crate::discovery::{PythonRequest, PythonVariant, VersionRequest};
+use crate::implementation::ImplementationName;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    #[error("A {0} cannot be combined with an implementation, version, or variant")]
+    ConflictingSource(&'static str),
+    #[error("A free-threaded Python request requires a version")]
+    FreethreadedWithoutVersion,
+}
+
+/// Where the requested interpreter comes from, if not discovered by implementation and version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    File(PathBuf),
+    Directory(PathBuf),
+    ExecutableName(String),
+}
+
+impl Source {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::File(_) => "file",
+            Self::Directory(_) => "directory",
+            Self::ExecutableName(_) => "executable name",
+        }
+    }
+}
+
+/// A builder for a [`PythonRequest`].
+///
+/// Without any constraints, the builder produces [`PythonRequest::Default`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PythonRequestBuilder {
+    implementation: Option<ImplementationName>,
+    version: Option<VersionRequest>,
+    variant: Option<PythonVariant>,
+    source: Option<Source>,
+}
+
+impl PythonRequestBuilder {
+    /// Create a builder without any constraints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require the given Python implementation, e.g., PyPy.
+    #[must_use]
+    pub fn implementation(mut self, implementation: ImplementationName) -> Self {
+        self.implementation = Some(implementation);
+        self
+    }
+
+    /// Require a Python version matching the given request, e.g., `3.12`.
+    #[must_use]
+    pub fn version(mut self, version: VersionRequest) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Require a Python version within the given range, e.g., `>=3.10,<3.13`.
+    #[must_use]
+    pub fn version_range(mut self, specifiers: VersionSpecifiers) -> Self {
+        self.version = Some(VersionRequest::Range(specifiers, PythonVariant::Default));
+        self
+    }
+
+    /// Require the given variant, e.g., a free-threaded build.
+    ///
+    /// Overrides any variant included in the version.
+    #[must_use]
+    pub fn variant(mut self, variant: PythonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Use the Python executable at the given path.
+    #[must_use]
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.source = Some(Source::File(path.into()));
+        self
+    }
+
+    /// Use the Python installation or environment in the given directory.
+    #[must_use]
+    pub fn directory(mut self, path: impl Into<PathBuf>) -> Self {
+        self.source = Some(Source::Directory(path.into()));
+        self
+    }
+
+    /// Use the Python executable with the given name, as found in the `PATH`.
+    #[must_use]
+    pub fn executable_name(mut self, name: impl Into<String>) -> Self {
+        self.source = Some(Source::ExecutableName(name.into()));
+        self
+    }
+
+    /// Build the [`PythonRequest`].
+    ///
+    /// Returns an error if the constraints can't be represented by a single request, e.g., a path
+    /// combined with a version.
+    pub fn build(self) -> Result<PythonRequest, Error> {
+        let Self {
+            implementation,
+            version,
+            variant,
+            source,
+        } = self;
+
+        if let Some(source) = source {
+            if implementation.is_some() || version.is_some() || variant.is_some() {
+                return Err(Error::ConflictingSource(source.kind()));
+            }
+            return Ok(match source {
+                Source::File(path) => PythonRequest::File(path),
+                Source::Directory(path) => PythonRequest::Directory(path),
+                Source::ExecutableName(name) => PythonRequest::ExecutableName(name),
+            });
+        }
+
+        let version = match variant {
+            None => version,
+            Some(PythonVariant::Default) => version.map(VersionRequest::without_python_variant),
+            Some(PythonVariant::Freethreaded) => Some(
+                version
+                    .and_then(VersionRequest::into_freethreaded)
+                    .ok_or(Error::FreethreadedWithoutVersion)?,
+            ),
+        };
+
+        // `any` and `default` versions are represented by dedicated requests, rather than as
+        // version requests, to match the output of `PythonRequest::parse`.
+        Ok(match (implementation, version) {
+            (None, None | Some(VersionRequest::Default)) => PythonRequest::Default,
+            (None, Some(VersionRequest::Any)) => PythonRequest::Any,
+            (None, Some(version)) => PythonRequest::Version(version),
+            (Some(implementation), None | Some(VersionRequest::Default | VersionRequest::Any)) => {
+                PythonRequest::Implementation(implementation)
+            }
+            (Some(implementation), Some(version)) => {
+                PythonRequest::ImplementationVersion(implementation, version)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::VersionSpecifiers;
+
+    use super::{Error, PythonRequestBuilder};
+    use crate::discovery::{PythonRequest, PythonVariant, VersionRequest};
+    use crate::implementation::ImplementationName;
+
+    #[test]
+    fn build() {
+        assert_eq!(
+            PythonRequestBuilder::new().build(),
+            Ok(PythonRequest::Default)
+        );
+        assert_eq!(
+            PythonRequestBuilder::new()
+                .version(VersionRequest::Any)
+                .build(),
+            Ok(PythonRequest::Any)
+        );
+        assert_eq!(
+            PythonRequestBuilder::new()
+                .implementation(ImplementationName::PyPy)
+                .version(VersionRequest::MajorMinor(3, 10, PythonVariant::Default))
+                .build(),
+            Ok(PythonRequest::ImplementationVersion(
+                ImplementationName::PyPy,
+                VersionRequest::MajorMinor(3, 10, PythonVariant::Default)
+            ))
+        );
+        assert_eq!(
+            PythonRequestBuilder::new()
+                .version_range(VersionSpecifiers::from_str(">=3.12").unwrap())
+                .variant(PythonVariant::Freethreaded)
+                .build(),
+            Ok(PythonRequest::Version(VersionRequest::Range(
+                VersionSpecifiers::from_str(">=3.12").unwrap(),
+                PythonVariant::Freethreaded
+            )))
+        );
+        assert_eq!(
+            PythonRequestBuilder::new()
+                .variant(PythonVariant::Freethreaded)
+                .build(),
+            Err(Error::FreethreadedWithoutVersion)
+        );
+        assert_eq!(
+            PythonRequestBuilder::new()
+                .file("/usr/bin/python3")
+                .version(VersionRequest::MajorMinor(3, 12, PythonVariant::Default))
+                .build(),
+            Err(Error::ConflictingSource("file"))
+        );
+    }
+
+    #[test]
+    fn canonical_round_trip() {
+        let builders = [
+            PythonRequestBuilder::new(),
+            PythonRequestBuilder::new().version(VersionRequest::Any),
+            PythonRequestBuilder::new().version(VersionRequest::Major(3, PythonVariant::Default)),
+            PythonRequestBuilder::new()
+                .version(VersionRequest::MajorMinor(3, 13, PythonVariant::Default))
+                .variant(PythonVariant::Freethreaded),
+            PythonRequestBuilder::new()
+                .version(VersionRequest::MajorMinorPatch(
+                    3,
+                    12,
+                    4,
+                    PythonVariant::Default,
+                ))
+                .implementation(ImplementationName::CPython),
+            PythonRequestBuilder::new()
+                .version_range(VersionSpecifiers::from_str(">=3.10,<3.13").unwrap()),
+            PythonRequestBuilder::new()
+                .version_range(VersionSpecifiers::from_str(">=3.13").unwrap())
+                .variant(PythonVariant::Freethreaded),
+            PythonRequestBuilder::new()
+                .implementation(ImplementationName::PyPy)
+                .version_range(VersionSpecifiers::from_str(">=3.9,<3.11").unwrap()),
+            PythonRequestBuilder::new().implementation(ImplementationName::GraalPy),
+            PythonRequestBuilder::new().executable_name("foo"),
+        ];
+        for builder in builders {
+            let request = builder.build().unwrap();
+            let canonical = request.canonical().to_string();
+            assert_eq!(
+                PythonRequest::parse(&canonical),
+                request,
+                "`{canonical}` should round-trip"
+            );
+        }
+    }
+}
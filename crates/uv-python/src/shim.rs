@@ -0,0 +1,467 @@
+//! Python executables that are shims of a version manager, i.e., pyenv or asdf.
+//!
+//! pyenv and asdf install a shim for each executable they manage, e.g., `python3.12`, in a `shims`
+//! directory. The shim is a shell script that runs the executable from the selected Python version,
+//! as determined by an environment variable (e.g., `PYENV_VERSION`), a version file in the working
+//! directory or one of its ancestors (e.g., `.python-version`), or the global version. Querying the
+//! shim spawns the version manager on every invocation, and the result can't be cached, since the
+//! selected version can change without the shim changing. Instead, uv asks the version manager
+//! which executable the shim runs, e.g., with `pyenv which`, and queries that executable directly.
+//!
+//! Resolutions are cached across invocations, keyed on the inputs to the version selection, i.e.,
+//! the environment variables, the version files, and the installed versions, such that the version
+//! manager is only spawned again once the selection may have changed.
+
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace, warn};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::cache_digest;
+use uv_fs::{CWD, Simplified, write_atomic_sync};
+use uv_static::EnvVars;
+
+use crate::discovery::PythonSource;
+
+/// The version manager that installed a shim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShimManager {
+    Pyenv,
+    Asdf,
+}
+
+impl ShimManager {
+    /// The name of the version manager, as used in the cache key and log messages.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Pyenv => "pyenv",
+            Self::Asdf => "asdf",
+        }
+    }
+}
+
+/// A version manager shim, as parsed from the shim script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Shim {
+    /// The version manager that installed the shim.
+    manager: ShimManager,
+    /// The version manager executable that the shim invokes.
+    program: PathBuf,
+    /// The `PYENV_ROOT` that a pyenv shim exports, if any.
+    root: Option<PathBuf>,
+}
+
+/// A cached resolution of a shim.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedResolution {
+    /// The path to the shim.
+    shim: PathBuf,
+    /// The executable that the shim runs, or `None` if the shim couldn't be resolved.
+    executable: Option<PathBuf>,
+}
+
+impl Shim {
+    /// Detect a pyenv or asdf shim at the given path.
+    ///
+    /// Returns `None` if the executable is not a shim.
+    pub(crate) fn from_executable(path: &Path) -> Option<Self> {
+        // pyenv and asdf always write their shims to a `shims` directory; avoid reading any other
+        // executable.
+        if path.parent()?.file_name()? != "shims" {
+            return None;
+        }
+
+        // Only the first lines are needed, and the executable may be a large binary.
+        let mut contents = Vec::new();
+        fs_err::File::open(path)
+            .ok()?
+            .take(1024)
+            .read_to_end(&mut contents)
+            .ok()?;
+        Self::parse(std::str::from_utf8(&contents).ok()?)
+    }
+
+    /// Parse the contents of a pyenv or asdf shim.
+    fn parse(contents: &str) -> Option<Self> {
+        Self::parse_pyenv(contents).or_else(|| Self::parse_asdf(contents))
+    }
+
+    /// Parse the contents of a pyenv shim, e.g.:
+    ///
+    /// ```sh
+    /// #!/usr/bin/env bash
+    /// set -e
+    /// [ -n "$PYENV_DEBUG" ] && set -x
+    ///
+    /// program="${0##*/}"
+    ///
+    /// export PYENV_ROOT="/home/ferris/.pyenv"
+    /// exec "/home/ferris/.pyenv/libexec/pyenv" exec "$program" "$@"
+    /// ```
+    fn parse_pyenv(contents: &str) -> Option<Self> {
+        let mut lines = contents.lines();
+        if !lines.next()?.starts_with("#!") {
+            return None;
+        }
+
+        let mut root = None;
+        for line in lines {
+            let line = line.trim();
+            if let Some(value) = line.strip_prefix("export PYENV_ROOT=") {
+                root = Some(PathBuf::from(unquote(value)?));
+            } else if let Some(rest) = line.strip_prefix("exec \"") {
+                let (pyenv, rest) = rest.split_once('"')?;
+                if !rest.trim_start().starts_with("exec ") {
+                    return None;
+                }
+                return Some(Self {
+                    manager: ShimManager::Pyenv,
+                    program: PathBuf::from(pyenv),
+                    root,
+                });
+            }
+        }
+        None
+    }
+
+    /// Parse the contents of an asdf shim for a Python executable, e.g.:
+    ///
+    /// ```sh
+    /// #!/usr/bin/env bash
+    /// # asdf-plugin: python 3.12.4
+    /// exec /home/ferris/.asdf/bin/asdf exec "python3" "$@" # asdf_allow: ' asdf '
+    /// ```
+    ///
+    /// Newer versions of asdf invoke `asdf` from the `PATH`, i.e., `exec asdf exec ...`.
+    fn parse_asdf(contents: &str) -> Option<Self> {
+        let mut lines = contents.lines();
+        if !lines.next()?.starts_with("#!") {
+            return None;
+        }
+
+        let mut python = false;
+        for line in lines {
+            let line = line.trim();
+            if let Some(plugin) = line.strip_prefix("# asdf-plugin:") {
+                python |= plugin.split_whitespace().next() == Some("python");
+            } else if let Some(rest) = line.strip_prefix("exec ") {
+                if !python {
+                    return None;
+                }
+                let (asdf, rest) = match rest.strip_prefix('"') {
+                    Some(rest) => rest.split_once('"')?,
+                    None => rest.split_once(' ')?,
+                };
+                if !rest.trim_start().starts_with("exec ") {
+                    return None;
+                }
+                return Some(Self {
+                    manager: ShimManager::Asdf,
+                    program: PathBuf::from(asdf),
+                    root: None,
+                });
+            }
+        }
+        None
+    }
+
+    /// The source to attribute to the executables that the shim resolves to.
+    pub(crate) fn source(&self) -> PythonSource {
+        match self.manager {
+            ShimManager::Pyenv => PythonSource::PyenvShim,
+            ShimManager::Asdf => PythonSource::AsdfShim,
+        }
+    }
+
+    /// Resolve the executable that the shim at `path` runs, e.g., with `pyenv which`.
+    ///
+    /// Returns `None` if the version manager fails to resolve the executable, e.g., if it isn't
+    /// provided by the selected Python version. Resolutions are cached, keyed on the inputs to the
+    /// version selection.
+    pub(crate) fn resolve(&self, path: &Path, cache: &Cache) -> Option<PathBuf> {
+        let entry = Self::directory(cache).join(format!("{}.json", self.selection_key(path)));
+
+        if let Some(cached) = fs_err::read(&entry)
+            .ok()
+            .and_then(|data| serde_json::from_slice::<CachedResolution>(&data).ok())
+        {
+            // Guard against an executable that was removed without changing the installed
+            // versions, e.g., a broken installation.
+            if cached
+                .executable
+                .as_ref()
+                .is_none_or(|executable| executable.is_file())
+            {
+                trace!(
+                    "Using cached resolution of {} shim at `{}`",
+                    self.manager.name(),
+                    path.display()
+                );
+                return cached.executable;
+            }
+        }
+
+        let executable = self.which(path);
+
+        let cached = CachedResolution {
+            shim: path.to_path_buf(),
+            executable,
+        };
+        let result = serde_json::to_vec_pretty(&cached)
+            .map_err(io::Error::from)
+            .and_then(|data| {
+                fs_err::create_dir_all(Self::directory(cache))?;
+                write_atomic_sync(&entry, data)
+            });
+        if let Err(err) = result {
+            warn!(
+                "Failed to cache resolution of {} shim at `{}`: {err}",
+                self.manager.name(),
+                path.user_display()
+            );
+        }
+        cached.executable
+    }
+
+    /// The directory in which shim resolutions are cached.
+    fn directory(cache: &Cache) -> PathBuf {
+        cache.bucket(CacheBucket::Interpreter).join("shims")
+    }
+
+    /// Compute a digest of the inputs that determine which executable the shim at `path` runs.
+    ///
+    /// Both pyenv and asdf select a version from (1) an environment variable, (2) the nearest
+    /// version file in the working directory or its ancestors, or (3) a global version file, and
+    /// run the executable from the matching installation. The installation directory is included
+    /// by modification time, such that installing or removing a version invalidates the key.
+    fn selection_key(&self, path: &Path) -> String {
+        let home = std::env::var_os(EnvVars::HOME).map(PathBuf::from);
+        let (variables, local, global, installs): (&[&str], Vec<String>, Option<PathBuf>, _) =
+            match self.manager {
+                ShimManager::Pyenv => {
+                    let root = self
+                        .root
+                        .clone()
+                        .or_else(|| std::env::var_os(EnvVars::PYENV_ROOT).map(PathBuf::from))
+                        .or_else(|| home.as_ref().map(|home| home.join(".pyenv")));
+                    (
+                        &[EnvVars::PYENV_VERSION, EnvVars::PYENV_ROOT],
+                        vec![".python-version".to_string()],
+                        root.as_ref().map(|root| root.join("version")),
+                        root.map(|root| root.join("versions")),
+                    )
+                }
+                ShimManager::Asdf => {
+                    let data_dir = std::env::var_os(EnvVars::ASDF_DATA_DIR)
+                        .map(PathBuf::from)
+                        .or_else(|| home.as_ref().map(|home| home.join(".asdf")));
+                    let tool_versions = std::env::var(EnvVars::ASDF_DEFAULT_TOOL_VERSIONS_FILENAME)
+                        .unwrap_or_else(|_| ".tool-versions".to_string());
+                    (
+                        &[
+                            EnvVars::ASDF_PYTHON_VERSION,
+                            EnvVars::ASDF_DATA_DIR,
+                            EnvVars::ASDF_DEFAULT_TOOL_VERSIONS_FILENAME,
+                        ],
+                        vec![tool_versions.clone(), ".python-version".to_string()],
+                        home.as_ref().map(|home| home.join(tool_versions)),
+                        data_dir.map(|data_dir| data_dir.join("installs").join("python")),
+                    )
+                }
+            };
+
+        let variables = variables
+            .iter()
+            .map(|name| (name.to_string(), std::env::var(name).ok()))
+            .collect::<Vec<_>>();
+        let local = CWD
+            .ancestors()
+            .flat_map(|directory| local.iter().map(move |name| directory.join(name)))
+            .filter_map(|file| {
+                let contents = fs_err::read_to_string(&file).ok()?;
+                Some((file, contents))
+            })
+            .collect::<Vec<_>>();
+        let global = global.and_then(|file| fs_err::read_to_string(file).ok());
+        let installs = installs.and_then(|installs| modified(&installs));
+
+        cache_digest(&(
+            path,
+            self.manager.name(),
+            &self.program,
+            &self.root,
+            variables,
+            local,
+            global,
+            installs,
+        ))
+    }
+
+    fn which(&self, path: &Path) -> Option<PathBuf> {
+        let name = path.file_name()?;
+        let mut command = Command::new(&self.program);
+        command.arg("which").arg(name);
+        if let Some(root) = &self.root {
+            command.env(EnvVars::PYENV_ROOT, root);
+        }
+
+        trace!(
+            "Resolving {} shim at `{}`",
+            self.manager.name(),
+            path.display()
+        );
+        let output = match command.output() {
+            Ok(output) => output,
+            Err(err) => {
+                debug!(
+                    "Failed to run `{}` to resolve {} shim at `{}`: {err}",
+                    self.program.display(),
+                    self.manager.name(),
+                    path.display()
+                );
+                return None;
+            }
+        };
+        if !output.status.success() {
+            debug!(
+                "Failed to resolve {} shim at `{}`: {}",
+                self.manager.name(),
+                path.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+
+        let executable = PathBuf::from(String::from_utf8(output.stdout).ok()?.trim());
+        // Guard against resolving to another shim, which would be queried through the version
+        // manager anyway.
+        if !executable.is_absolute()
+            || executable.parent() == path.parent()
+            || !executable.is_file()
+        {
+            debug!(
+                "Ignoring invalid resolution of {} shim at `{}`: `{}`",
+                self.manager.name(),
+                path.display(),
+                executable.display()
+            );
+            return None;
+        }
+        debug!(
+            "Resolved {} shim at `{}` to `{}`",
+            self.manager.name(),
+            path.display(),
+            executable.display()
+        );
+        Some(executable)
+    }
+}
+
+/// Return the modification time of the given path, in nanoseconds since the Unix epoch, if it
+/// exists.
+fn modified(path: &Path) -> Option<u128> {
+    let modified = fs_err::metadata(path).ok()?.modified().ok()?;
+    Some(
+        modified
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()?
+            .as_nanos(),
+    )
+}
+
+/// Remove the quotes around a shell word, e.g., `"/home/ferris/.pyenv"`.
+fn unquote(value: &str) -> Option<&str> {
+    let value = value.trim();
+    match value.strip_prefix('"') {
+        Some(value) => value.strip_suffix('"'),
+        None => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indoc::indoc;
+
+    use super::{Shim, ShimManager};
+
+    #[test]
+    fn parse_shim() {
+        let shim = Shim::parse(indoc! {r#"
+            #!/usr/bin/env bash
+            set -e
+            [ -n "$PYENV_DEBUG" ] && set -x
+
+            program="${0##*/}"
+
+            export PYENV_ROOT="/home/ferris/.pyenv"
+            exec "/home/ferris/.pyenv/libexec/pyenv" exec "$program" "$@"
+        "#});
+        assert_eq!(
+            shim,
+            Some(Shim {
+                manager: ShimManager::Pyenv,
+                program: PathBuf::from("/home/ferris/.pyenv/libexec/pyenv"),
+                root: Some(PathBuf::from("/home/ferris/.pyenv")),
+            })
+        );
+
+        // Other scripts in a `shims` directory aren't shims.
+        assert_eq!(
+            Shim::parse(indoc! {r#"
+                #!/bin/sh
+                exec "/usr/bin/python3" "$@"
+            "#}),
+            None
+        );
+        assert_eq!(Shim::parse("\x7fELF"), None);
+    }
+
+    #[test]
+    fn parse_asdf_shim() {
+        let shim = Shim::parse(indoc! {r#"
+            #!/usr/bin/env bash
+            # asdf-plugin: python 3.12.4
+            # asdf-plugin: python 3.11.9
+            exec /home/ferris/.asdf/bin/asdf exec "python3" "$@" # asdf_allow: ' asdf '
+        "#});
+        assert_eq!(
+            shim,
+            Some(Shim {
+                manager: ShimManager::Asdf,
+                program: PathBuf::from("/home/ferris/.asdf/bin/asdf"),
+                root: None,
+            })
+        );
+
+        // Newer versions of asdf invoke `asdf` from the `PATH`.
+        let shim = Shim::parse(indoc! {r#"
+            #!/usr/bin/env bash
+            # asdf-plugin: python 3.12.4
+            exec asdf exec "python3" "$@"
+        "#});
+        assert_eq!(
+            shim,
+            Some(Shim {
+                manager: ShimManager::Asdf,
+                program: PathBuf::from("asdf"),
+                root: None,
+            })
+        );
+
+        // Shims for other plugins aren't Python shims.
+        assert_eq!(
+            Shim::parse(indoc! {r#"
+                #!/usr/bin/env bash
+                # asdf-plugin: nodejs 20.11.0
+                exec /home/ferris/.asdf/bin/asdf exec "node" "$@"
+            "#}),
+            None
+        );
+    }
+}
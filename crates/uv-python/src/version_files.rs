@@ -2,12 +2,13 @@ use std::ops::Add;
 use std::path::{Path, PathBuf};
 
 use fs_err as fs;
-use itertools::Itertools;
 use tracing::debug;
 use uv_dirs::user_uv_config_dir;
 use uv_fs::Simplified;
 use uv_warnings::warn_user_once;
 
+use uv_pep508::try_expand_env_vars;
+
 use crate::PythonRequest;
 
 /// The file name for Python version pins.
@@ -23,6 +24,18 @@ pub struct PythonVersionFile {
     path: PathBuf,
     /// The Python version requests declared in the file.
     versions: Vec<PythonRequest>,
+    /// The lines of the file, as read from disk, used to preserve comments and formatting when
+    /// writing the file.
+    lines: Vec<Line>,
+}
+
+/// A line in a Python version file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Line {
+    /// A line declaring one of the Python version requests, in its original form.
+    Request(String),
+    /// A comment, an empty line, or an ignored request, which is preserved verbatim.
+    Verbatim(String),
 }
 
 /// Whether to prefer the `.python-version` or `.python-versions` file.
@@ -165,34 +178,55 @@ impl PythonVersionFile {
                     "Reading Python requests from version file at `{}`",
                     path.display()
                 );
-                let versions = content
-                    .lines()
-                    .filter(|line| {
-                        // Skip comments and empty lines.
-                        let trimmed = line.trim();
-                        !(trimmed.is_empty() || trimmed.starts_with('#'))
-                    })
-                    .map(ToString::to_string)
-                    .map(|version| PythonRequest::parse(&version))
-                    .filter(|request| {
-                        if let PythonRequest::ExecutableName(name) = request {
-                            warn_user_once!(
-                                "Ignoring unsupported Python request `{name}` in version file: {}",
-                                path.display()
-                            );
-                            false
-                        } else {
-                            true
-                        }
-                    })
-                    .collect();
-                Ok(Some(Self { path, versions }))
+                Self::parse(path, &content).map(Some)
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(err) => Err(err),
         }
     }
 
+    /// Parse the contents of a Python version file.
+    ///
+    /// Environment variables in requests, e.g., `${PYENV_ROOT}/versions/3.12/bin/python`, are
+    /// expanded; an error is returned if a referenced variable is unset.
+    fn parse(path: PathBuf, content: &str) -> Result<Self, std::io::Error> {
+        let mut versions = Vec::new();
+        let mut lines = Vec::new();
+        for line in content.lines() {
+            // Skip comments and empty lines.
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                lines.push(Line::Verbatim(line.to_string()));
+                continue;
+            }
+            let expanded = try_expand_env_vars(trimmed).map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "Failed to read Python version file `{}`: {err}",
+                        path.user_display()
+                    ),
+                )
+            })?;
+            let request = PythonRequest::parse(&expanded);
+            if let PythonRequest::ExecutableName(name) = &request {
+                warn_user_once!(
+                    "Ignoring unsupported Python request `{name}` in version file: {}",
+                    path.display()
+                );
+                lines.push(Line::Verbatim(line.to_string()));
+                continue;
+            }
+            versions.push(request);
+            lines.push(Line::Request(line.to_string()));
+        }
+        Ok(Self {
+            path,
+            versions,
+            lines,
+        })
+    }
+
     /// Read a Python version file at the given path.
     ///
     /// If the file does not exist, an error is returned.
@@ -214,6 +248,7 @@ impl PythonVersionFile {
         Self {
             path,
             versions: vec![],
+            lines: vec![],
         }
     }
 
@@ -262,29 +297,174 @@ impl PythonVersionFile {
     }
 
     /// Set the versions for the file.
+    ///
+    /// Comments and empty lines in the file are preserved when the file is written; the requests
+    /// are replaced in order.
     #[must_use]
     pub fn with_versions(self, versions: Vec<PythonRequest>) -> Self {
-        Self {
-            path: self.path,
-            versions,
+        Self { versions, ..self }
+    }
+
+    /// Append a version to the file.
+    pub fn push_version(&mut self, version: PythonRequest) {
+        self.versions.push(version);
+    }
+
+    /// Remove all occurrences of a version from the file, along with the lines that declare it.
+    ///
+    /// Returns `true` if the version was present.
+    pub fn remove_version(&mut self, version: &PythonRequest) -> bool {
+        let mut index = 0;
+        let mut removed = false;
+        let versions = std::mem::take(&mut self.versions);
+        self.lines.retain(|line| {
+            let Line::Request(..) = line else {
+                return true;
+            };
+            let matches = versions.get(index) == Some(version);
+            index += 1;
+            !matches
+        });
+        for existing in versions {
+            if existing == *version {
+                removed = true;
+            } else {
+                self.versions.push(existing);
+            }
+        }
+        removed
+    }
+
+    /// Render the contents of the version file.
+    ///
+    /// The original lines of the file are preserved, with the requests replaced by the current
+    /// versions in order. Any additional versions are appended to the end of the file.
+    fn render(&self) -> String {
+        let mut versions = self.versions.iter();
+        let mut lines = Vec::with_capacity(self.lines.len().max(self.versions.len()));
+        for line in &self.lines {
+            match line {
+                Line::Verbatim(line) => lines.push(line.clone()),
+                Line::Request(line) => {
+                    // Drop requests that are no longer present.
+                    let Some(version) = versions.next() else {
+                        continue;
+                    };
+                    // Retain the original formatting if the request is unchanged.
+                    if try_expand_env_vars(line.trim())
+                        .is_ok_and(|line| PythonRequest::parse(&line) == *version)
+                    {
+                        lines.push(line.clone());
+                    } else {
+                        lines.push(version.to_canonical_string());
+                    }
+                }
+            }
         }
+        lines.extend(versions.map(PythonRequest::to_canonical_string));
+        lines.join("\n").add("\n")
     }
 
     /// Update the version file on the file system.
+    ///
+    /// Comments and empty lines from the original file are preserved.
     pub async fn write(&self) -> Result<(), std::io::Error> {
         debug!("Writing Python versions to `{}`", self.path.display());
         if let Some(parent) = self.path.parent() {
             fs_err::tokio::create_dir_all(parent).await?;
         }
-        fs::tokio::write(
-            &self.path,
-            self.versions
-                .iter()
-                .map(PythonRequest::to_canonical_string)
-                .join("\n")
-                .add("\n")
-                .as_bytes(),
+        fs::tokio::write(&self.path, self.render().as_bytes()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indoc::indoc;
+
+    use crate::PythonRequest;
+
+    use super::PythonVersionFile;
+
+    #[test]
+    fn render_preserves_comments() {
+        let file = PythonVersionFile::parse(
+            PathBuf::from(".python-version"),
+            indoc! {"
+                # The version used in CI.
+                3.12
+
+                # Also supported.
+                3.11
+            "},
+        )
+        .unwrap();
+        assert_eq!(
+            file.versions().cloned().collect::<Vec<_>>(),
+            vec![PythonRequest::parse("3.12"), PythonRequest::parse("3.11")]
+        );
+
+        let file = file.with_versions(vec![PythonRequest::parse("3.13")]);
+        assert_eq!(
+            file.render(),
+            indoc! {"
+                # The version used in CI.
+                3.13
+
+                # Also supported.
+            "}
+        );
+    }
+
+    #[test]
+    fn push_and_remove_version() {
+        let mut file = PythonVersionFile::parse(
+            PathBuf::from(".python-versions"),
+            indoc! {"
+                3.10
+                # Unreleased.
+                3.14
+            "},
+        )
+        .unwrap();
+        assert!(file.remove_version(&PythonRequest::parse("3.10")));
+        assert!(!file.remove_version(&PythonRequest::parse("3.9")));
+        file.push_version(PythonRequest::parse("pypy@3.11"));
+        assert_eq!(
+            file.render(),
+            indoc! {"
+                # Unreleased.
+                3.14
+                pypy@3.11
+            "}
+        );
+    }
+
+    #[test]
+    fn expand_env_vars() {
+        let file = PythonVersionFile::parse(
+            PathBuf::from(".python-version"),
+            indoc! {"
+                $${UV_TEST_UNSET_VARIABLE}/bin/python
+            "},
+        )
+        .unwrap();
+        assert_eq!(
+            file.versions().cloned().collect::<Vec<_>>(),
+            vec![PythonRequest::parse("${UV_TEST_UNSET_VARIABLE}/bin/python")]
+        );
+
+        let err = PythonVersionFile::parse(
+            PathBuf::from(".python-version"),
+            indoc! {"
+                ${UV_TEST_UNSET_VARIABLE}/bin/python
+            "},
         )
-        .await
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to read Python version file `.python-version`: Environment variable `UV_TEST_UNSET_VARIABLE` is not set; use `$${UV_TEST_UNSET_VARIABLE}` to include `${UV_TEST_UNSET_VARIABLE}` literally"
+        );
     }
 }
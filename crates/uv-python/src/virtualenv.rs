@@ -5,13 +5,17 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use fs_err as fs;
 use thiserror::Error;
 
+use uv_fs::Simplified;
+use uv_install_wheel::LinkMode;
+use uv_platform::Arch;
 use uv_pypi_types::Scheme;
 use uv_static::EnvVars;
+use uv_warnings::warn_user_once;
 
 use crate::PythonVersion;
+use crate::host::{self, FileSystem};
 
 /// The layout of a virtual environment.
 #[derive(Debug)]
@@ -33,6 +37,8 @@ pub struct VirtualEnvironment {
 /// A parsed `pyvenv.cfg`
 #[derive(Debug, Clone)]
 pub struct PyVenvConfiguration {
+    /// The directory containing the base interpreter, i.e., the `home` key.
+    pub(crate) home: Option<PathBuf>,
     /// Was the virtual environment created with the `virtualenv` package?
     pub(crate) virtualenv: bool,
     /// Was the virtual environment created with the `uv` package?
@@ -45,6 +51,20 @@ pub struct PyVenvConfiguration {
     pub(crate) include_system_site_packages: bool,
     /// The Python version the virtual environment was created with
     pub(crate) version: Option<PythonVersion>,
+    /// The target platform of a resolution-only environment, if any.
+    pub(crate) python_platform: Option<String>,
+    /// The `sys.prefix` of the environment this environment extends, if any.
+    pub(crate) extends_environment: Option<PathBuf>,
+    /// The prompt prefix to display when the environment is activated, if any.
+    pub(crate) prompt: Option<String>,
+    /// The link mode to use when installing packages into the environment, if any.
+    pub(crate) link_mode: Option<LinkMode>,
+    /// Is the virtual environment locked against modification?
+    pub(crate) read_only: bool,
+    /// Does the virtual environment expose the user site packages directory?
+    pub(crate) user_site: bool,
+    /// The slice of a macOS universal binary the virtual environment runs as, if any.
+    pub(crate) arch: Option<Arch>,
 }
 
 #[derive(Debug, Error)]
@@ -55,6 +75,10 @@ pub enum Error {
     MissingPyVenvCfg(PathBuf),
     #[error("Broken virtual environment `{0}`: `pyvenv.cfg` could not be parsed")]
     ParsePyVenvCfg(PathBuf, #[source] io::Error),
+    #[error(
+        "Refusing to use virtual environment `{0}`: `{1}` is a symbolic link, junction, or other reparse point (`UV_VENV_REFUSE_LINKS` is set)"
+    )]
+    RefusedLink(PathBuf, PathBuf),
 }
 
 /// Locate an active virtual environment by inspecting environment variables.
@@ -62,12 +86,73 @@ pub enum Error {
 /// Supports `VIRTUAL_ENV`.
 pub(crate) fn virtualenv_from_env() -> Option<PathBuf> {
     if let Some(dir) = env::var_os(EnvVars::VIRTUAL_ENV).filter(|value| !value.is_empty()) {
-        return Some(PathBuf::from(dir));
+        return match resolve_virtualenv_root(PathBuf::from(dir), refuse_links()) {
+            Ok(root) => Some(root),
+            Err(err) => {
+                warn_user_once!("Ignoring `VIRTUAL_ENV`: {err}");
+                None
+            }
+        };
     }
 
     None
 }
 
+/// Resolve the root directory of a discovered virtual environment.
+///
+/// On Windows, directory junctions and symbolic links are resolved, so that an environment that
+/// is reachable via multiple paths (e.g., on a Dev Drive or a roaming profile) is always reported
+/// with the same path.
+///
+/// If `refuse_links` is set, an error is returned if any component of the path is a link.
+fn resolve_virtualenv_root(root: PathBuf, refuse_links: bool) -> Result<PathBuf, Error> {
+    if refuse_links {
+        if let Some(link) = root.ancestors().find(|path| is_link(path)) {
+            return Err(Error::RefusedLink(root.clone(), link.to_path_buf()));
+        }
+    }
+
+    if cfg!(windows) {
+        if let Ok(canonical) = dunce::canonicalize(&root) {
+            return Ok(canonical);
+        }
+    }
+
+    Ok(root)
+}
+
+/// Returns `true` if `UV_VENV_REFUSE_LINKS` is set, i.e., if virtual environments behind links
+/// should be refused.
+fn refuse_links() -> bool {
+    env::var(EnvVars::UV_VENV_REFUSE_LINKS).is_ok_and(|value| {
+        matches!(
+            value.to_lowercase().as_str(),
+            "y" | "yes" | "t" | "true" | "on" | "1"
+        )
+    })
+}
+
+/// Returns `true` if the path is a symbolic link or, on Windows, any other reparse point, e.g., a
+/// directory junction or an app execution alias.
+fn is_link(path: &Path) -> bool {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return false;
+    };
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        use windows_sys::Win32::Storage::FileSystem::FILE_ATTRIBUTE_REPARSE_POINT;
+
+        metadata.file_attributes() & FILE_ATTRIBUTE_REPARSE_POINT != 0
+    }
+
+    #[cfg(not(windows))]
+    {
+        metadata.file_type().is_symlink()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub(crate) enum CondaEnvironmentKind {
     /// The base Conda environment; treated like a system Python environment.
@@ -127,46 +212,56 @@ pub(crate) fn conda_environment_from_env(kind: CondaEnvironmentKind) -> Option<P
 /// containing virtual environment is returned.
 pub(crate) fn virtualenv_from_working_dir() -> Result<Option<PathBuf>, Error> {
     let current_dir = crate::current_dir()?;
+    let fs = host::file_system();
 
     for dir in current_dir.ancestors() {
         // If we're _within_ a virtualenv, return it.
-        if uv_fs::is_virtualenv_base(dir) {
-            return Ok(Some(dir.to_path_buf()));
+        if is_virtualenv_base(&*fs, dir) {
+            return resolve_virtualenv_root(dir.to_path_buf(), refuse_links()).map(Some);
         }
 
         // Otherwise, search for a `.venv` directory.
         let dot_venv = dir.join(".venv");
-        if dot_venv.is_dir() {
-            if !uv_fs::is_virtualenv_base(&dot_venv) {
+        if fs.is_dir(&dot_venv) {
+            if !is_virtualenv_base(&*fs, &dot_venv) {
                 return Err(Error::MissingPyVenvCfg(dot_venv));
             }
-            return Ok(Some(dot_venv));
+            return resolve_virtualenv_root(dot_venv, refuse_links()).map(Some);
         }
     }
 
     Ok(None)
 }
 
+/// Returns `true` if a path is the base path of a virtual environment, indicated by the presence
+/// of a `pyvenv.cfg` file.
+///
+/// Equivalent to [`uv_fs::is_virtualenv_base`], but uses the given [`FileSystem`].
+fn is_virtualenv_base(fs: &dyn FileSystem, path: &Path) -> bool {
+    fs.is_file(&path.join("pyvenv.cfg"))
+}
+
 /// Returns the path to the `python` executable inside a virtual environment.
 pub(crate) fn virtualenv_python_executable(venv: impl AsRef<Path>) -> PathBuf {
     let venv = venv.as_ref();
+    let fs = host::file_system();
     if cfg!(windows) {
         // Search for `python.exe` in the `Scripts` directory.
         let default_executable = venv.join("Scripts").join("python.exe");
-        if default_executable.exists() {
+        if fs.exists(&default_executable) {
             return default_executable;
         }
 
         // Apparently, Python installed via msys2 on Windows _might_ produce a POSIX-like layout.
         // See: https://github.com/PyO3/maturin/issues/1108
         let executable = venv.join("bin").join("python.exe");
-        if executable.exists() {
+        if fs.exists(&executable) {
             return executable;
         }
 
         // Fallback for Conda environments.
         let executable = venv.join("python.exe");
-        if executable.exists() {
+        if fs.exists(&executable) {
             return executable;
         }
 
@@ -175,12 +270,12 @@ pub(crate) fn virtualenv_python_executable(venv: impl AsRef<Path>) -> PathBuf {
     } else {
         // Check for both `python3` over `python`, preferring the more specific one
         let default_executable = venv.join("bin").join("python3");
-        if default_executable.exists() {
+        if fs.exists(&default_executable) {
             return default_executable;
         }
 
         let executable = venv.join("bin").join("python");
-        if executable.exists() {
+        if fs.exists(&executable) {
             return executable;
         }
 
@@ -192,23 +287,35 @@ pub(crate) fn virtualenv_python_executable(venv: impl AsRef<Path>) -> PathBuf {
 impl PyVenvConfiguration {
     /// Parse a `pyvenv.cfg` file into a [`PyVenvConfiguration`].
     pub fn parse(cfg: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut home = None;
         let mut virtualenv = false;
         let mut uv = false;
         let mut relocatable = false;
         let mut seed = false;
         let mut include_system_site_packages = true;
         let mut version = None;
+        let mut python_platform = None;
+        let mut extends_environment = None;
+        let mut prompt = None;
+        let mut link_mode = None;
+        let mut read_only = false;
+        let mut user_site = false;
+        let mut arch = None;
 
         // Per https://snarky.ca/how-virtual-environments-work/, the `pyvenv.cfg` file is not a
         // valid INI file, and is instead expected to be parsed by partitioning each line on the
         // first equals sign.
-        let content = fs::read_to_string(&cfg)
+        let content = host::file_system()
+            .read_to_string(cfg.as_ref())
             .map_err(|err| Error::ParsePyVenvCfg(cfg.as_ref().to_path_buf(), err))?;
         for line in content.lines() {
             let Some((key, value)) = line.split_once('=') else {
                 continue;
             };
             match key.trim() {
+                "home" => {
+                    home = Some(PathBuf::from(value.trim()));
+                }
                 "virtualenv" => {
                     virtualenv = true;
                 }
@@ -230,20 +337,68 @@ impl PyVenvConfiguration {
                             .map_err(|e| io::Error::new(std::io::ErrorKind::InvalidData, e))?,
                     );
                 }
+                "uv-python-platform" => {
+                    python_platform = Some(value.trim().to_string());
+                }
+                "extends-environment" => {
+                    extends_environment = Some(PathBuf::from(value.trim()));
+                }
+                "prompt" => {
+                    prompt = Some(value.trim().to_string());
+                }
+                "uv-link-mode" => match LinkMode::from_str(value.trim()) {
+                    Ok(mode) => link_mode = Some(mode),
+                    // The value may have been written by a newer version of uv; fall back to the
+                    // default link mode rather than rejecting the environment.
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring `uv-link-mode` in `{}`: {err}",
+                            cfg.as_ref().user_display()
+                        );
+                    }
+                },
+                "uv-read-only" => {
+                    read_only = value.trim().to_lowercase() == "true";
+                }
+                "uv-user-site" => {
+                    user_site = value.trim().to_lowercase() == "true";
+                }
+                "uv-arch" => match Arch::from_str(value.trim()) {
+                    Ok(value) => arch = Some(value),
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring `uv-arch` in `{}`: {err}",
+                            cfg.as_ref().user_display()
+                        );
+                    }
+                },
                 _ => {}
             }
         }
 
         Ok(Self {
+            home,
             virtualenv,
             uv,
             relocatable,
             seed,
             include_system_site_packages,
             version,
+            python_platform,
+            extends_environment,
+            prompt,
+            link_mode,
+            read_only,
+            user_site,
+            arch,
         })
     }
 
+    /// Returns the directory containing the base interpreter of the virtual environment, if any.
+    pub fn home(&self) -> Option<&Path> {
+        self.home.as_deref()
+    }
+
     /// Returns true if the virtual environment was created with the `virtualenv` package.
     pub fn is_virtualenv(&self) -> bool {
         self.virtualenv
@@ -264,11 +419,58 @@ impl PyVenvConfiguration {
         self.seed
     }
 
-    /// Returns true if the virtual environment should include system site packages.
+    /// Returns true if the virtual environment should include system site packages, i.e., if the
+    /// `include-system-site-packages` key is `true` or, as in Python's `site` module, missing.
     pub fn include_system_site_packages(&self) -> bool {
         self.include_system_site_packages
     }
 
+    /// Returns the target platform if the virtual environment is a resolution-only environment,
+    /// i.e., it was created with `uv venv --python-platform`.
+    pub fn python_platform(&self) -> Option<&str> {
+        self.python_platform.as_deref()
+    }
+
+    /// Returns the `sys.prefix` of the environment that this environment extends, if any, as
+    /// recorded by the `extends-environment` key.
+    pub fn extends_environment(&self) -> Option<&Path> {
+        self.extends_environment.as_deref()
+    }
+
+    /// Returns the prompt prefix of the virtual environment, if any.
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    /// Returns the link mode to use when installing packages into the virtual environment, if
+    /// recorded, i.e., if it was created with `uv venv --link-mode`.
+    pub fn link_mode(&self) -> Option<LinkMode> {
+        self.link_mode
+    }
+
+    /// Returns `true` if the virtual environment is locked against modification, i.e., if it was
+    /// locked with `uv venv --lock-environment`.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the virtual environment exposes the user site packages directory, i.e.,
+    /// if it was created with `uv venv --allow-user-site`.
+    pub fn allows_user_site(&self) -> bool {
+        self.user_site
+    }
+
+    /// Returns the slice of a macOS universal binary the virtual environment runs as, if it was
+    /// created from a non-native slice.
+    pub fn arch(&self) -> Option<Arch> {
+        self.arch
+    }
+
+    /// Returns the Python version the virtual environment was created with, if recorded.
+    pub fn version(&self) -> Option<&PythonVersion> {
+        self.version.as_ref()
+    }
+
     /// Set the key-value pair in the `pyvenv.cfg` file.
     pub fn set(content: &str, key: &str, value: &str) -> String {
         let mut lines = content.lines().map(Cow::Borrowed).collect::<Vec<_>>();
@@ -299,6 +501,91 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_parse_extends_environment() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+        fs_err::write(
+            &cfg,
+            indoc! {"
+                home = /path/to/python
+                extends-environment = /path/to/parent
+            "},
+        )
+        .unwrap();
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(
+            cfg.extends_environment(),
+            Some(Path::new("/path/to/parent"))
+        );
+    }
+
+    #[test]
+    fn test_parse_prompt() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+        fs_err::write(
+            &cfg,
+            indoc! {"
+                home = /path/to/python
+                prompt = my-project
+            "},
+        )
+        .unwrap();
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(cfg.prompt(), Some("my-project"));
+    }
+
+    #[test]
+    fn test_parse_link_mode() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+        fs_err::write(
+            &cfg,
+            indoc! {"
+                home = /path/to/python
+                uv-link-mode = symlink
+            "},
+        )
+        .unwrap();
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(cfg.link_mode(), Some(LinkMode::Symlink));
+    }
+
+    #[test]
+    fn test_parse_unknown_link_mode() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+        fs_err::write(
+            &cfg,
+            indoc! {"
+                home = /path/to/python
+                uv-link-mode = reflink
+                prompt = my-project
+            "},
+        )
+        .unwrap();
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(cfg.link_mode(), None);
+        assert_eq!(cfg.prompt(), Some("my-project"));
+    }
+
+    #[test]
+    fn test_parse_arch() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cfg = temp_dir.path().join("pyvenv.cfg");
+        fs_err::write(
+            &cfg,
+            indoc! {"
+                home = /path/to/python
+                uv-arch = x86_64
+            "},
+        )
+        .unwrap();
+        let cfg = PyVenvConfiguration::parse(&cfg).unwrap();
+        assert_eq!(cfg.arch(), Some(Arch::from_str("x86_64").unwrap()));
+    }
+
     #[test]
     fn test_set_existing_key() {
         let content = indoc! {"
@@ -378,4 +665,40 @@ mod tests {
             "}
         );
     }
+
+    #[test]
+    fn test_resolve_virtualenv_root_links() {
+        // Canonicalize the temporary directory, which may itself be behind a link, e.g., on macOS.
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = dunce::canonicalize(temp_dir.path()).unwrap();
+        let target = root.join("target");
+        fs_err::create_dir(&target).unwrap();
+        let venv = target.join(".venv");
+        fs_err::create_dir(&venv).unwrap();
+
+        // On Windows, a junction is created, rather than a symbolic link.
+        let link = root.join("link");
+        uv_fs::replace_symlink(&target, &link).unwrap();
+        let linked = link.join(".venv");
+
+        // Paths without links are used as-is.
+        assert_eq!(resolve_virtualenv_root(venv.clone(), true).unwrap(), venv);
+
+        // Links are resolved on Windows, so that every path to the environment is reported the
+        // same way.
+        let resolved = resolve_virtualenv_root(linked.clone(), false).unwrap();
+        if cfg!(windows) {
+            assert_eq!(resolved, venv);
+        } else {
+            assert_eq!(resolved, linked);
+        }
+
+        // If links are refused, the linked component is reported.
+        let err = resolve_virtualenv_root(linked.clone(), true).unwrap_err();
+        let Error::RefusedLink(root, component) = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(root, linked);
+        assert_eq!(component, link);
+    }
 }
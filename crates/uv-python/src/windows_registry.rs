@@ -148,7 +148,22 @@ pub fn create_registry_entry(
         }
     };
 
-    write_registry_entry(installation, pointer_width)?;
+    if let Err(err) = write_registry_entry(installation, pointer_width) {
+        // Don't leave a partially written entry behind, other tools may not be able to handle it.
+        let python_entry = format!(
+            "Software\\Python\\{COMPANY_KEY}\\{}",
+            registry_python_tag(installation.key())
+        );
+        debug!("Rolling back registry key HKCU:\\{}", python_entry);
+        if let Err(err) = CURRENT_USER.remove_tree(&python_entry) {
+            if err.code() != ERROR_NOT_FOUND {
+                warn_user!(
+                    "Failed to roll back registry entries under HKCU:\\{python_entry}: {err}"
+                );
+            }
+        }
+        return Err(err.into());
+    }
 
     Ok(())
 }
@@ -157,8 +172,6 @@ fn write_registry_entry(
     installation: &ManagedPythonInstallation,
     pointer_width: i32,
 ) -> windows_registry::Result<()> {
-    // We currently just overwrite all known keys, without removing prior entries first
-
     // Similar to using the bin directory in HOME on Unix, we only install for the current user
     // on Windows.
     let company = CURRENT_USER.create(format!("Software\\Python\\{COMPANY_KEY}"))?;
@@ -166,7 +179,17 @@ fn write_registry_entry(
     company.set_string("SupportUrl", "https://github.com/astral-sh/uv")?;
 
     // Ex) CPython3.13.1
-    let tag = company.create(registry_python_tag(installation.key()))?;
+    let python_tag = registry_python_tag(installation.key());
+
+    // Remove any prior entry, so that values from a previous installation (e.g., a download URL)
+    // don't linger.
+    if let Err(err) = company.remove_tree(&python_tag) {
+        if err.code() != ERROR_NOT_FOUND {
+            return Err(err);
+        }
+    }
+
+    let tag = company.create(&python_tag)?;
     let display_name = format!(
         "{} {} ({}-bit)",
         installation.key().implementation().pretty(),
@@ -207,13 +230,24 @@ fn registry_python_tag(key: &PythonInstallationKey) -> String {
 }
 
 /// Remove requested Python entries from the Windows Registry (PEP 514).
+///
+/// With `all`, the entire uv registry key is removed, including the entries of any installations
+/// not in `installations`, so it should only be set if every installation is removed.
+///
+/// Returns the installations whose entries were removed, so the entries can be restored with
+/// [`create_registry_entry`] if removing the installation itself fails.
 pub fn remove_registry_entry<'a>(
     installations: impl IntoIterator<Item = &'a ManagedPythonInstallation>,
     all: bool,
     errors: &mut Vec<(PythonInstallationKey, anyhow::Error)>,
-) {
+) -> Vec<&'a ManagedPythonInstallation> {
     let astral_key = format!("Software\\Python\\{COMPANY_KEY}");
     if all {
+        // Determine which of the installations are registered before removing the entire key.
+        let registered: HashSet<String> = CURRENT_USER
+            .open(&astral_key)
+            .and_then(|key| key.keys().map(Iterator::collect))
+            .unwrap_or_default();
         debug!("Removing registry key HKCU:\\{}", astral_key);
         if let Err(err) = CURRENT_USER.remove_tree(&astral_key) {
             if err.code() == ERROR_NOT_FOUND {
@@ -221,10 +255,15 @@ pub fn remove_registry_entry<'a>(
             } else {
                 warn_user!("Failed to clear registry entries under {astral_key}: {err}");
             }
+            return Vec::new();
         }
-        return;
+        return installations
+            .into_iter()
+            .filter(|installation| registered.contains(&registry_python_tag(installation.key())))
+            .collect();
     }
 
+    let mut removed = Vec::new();
     for installation in installations {
         let python_tag = registry_python_tag(installation.key());
         let python_entry = format!("{astral_key}\\{python_tag}");
@@ -242,8 +281,11 @@ pub fn remove_registry_entry<'a>(
                     anyhow!("Failed to clear registry entries under HKCU:\\{python_entry}: {err}"),
                 ));
             }
+        } else {
+            removed.push(installation);
         }
     }
+    removed
 }
 
 /// Remove Python entries from the Windows Registry (PEP 514) that are not matching any
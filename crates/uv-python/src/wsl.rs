@@ -0,0 +1,180 @@
+//! Python interpreters in the Windows Subsystem for Linux (WSL), queried via WSL interop.
+//!
+//! An interpreter in WSL runs in a separate Linux system, so uv can't use it to create an
+//! environment, run a command, or build a source distribution. It's never returned by interpreter
+//! discovery. Instead, its version and platform are queried with `wsl.exe`, such that requirements
+//! can be resolved for it from Windows, e.g., with `uv pip compile --wsl`.
+
+use std::io;
+use std::process::Command;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::debug;
+
+use crate::PythonVersion;
+
+/// Print the properties of the interpreter that determine its markers and platform tags.
+const QUERY_SCRIPT: &str = "\
+import json, platform, sys
+name, version = platform.libc_ver()
+print(json.dumps({
+    'implementation': sys.implementation.name,
+    'version': platform.python_version(),
+    'machine': platform.machine(),
+    'libc': name,
+    'libc_version': version,
+}))";
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Resolving for a Python interpreter in WSL is only supported on Windows")]
+    Unsupported,
+    #[error("Failed to run `wsl.exe`; is the Windows Subsystem for Linux installed?")]
+    Spawn(#[source] io::Error),
+    #[error("Failed to query the Python interpreter in WSL{}:\n{stderr}", .distribution.as_ref().map(|distribution| format!(" (`{distribution}`)")).unwrap_or_default())]
+    Query {
+        distribution: Option<String>,
+        stderr: String,
+    },
+    #[error("Failed to parse the response from the Python interpreter in WSL")]
+    Parse(#[source] serde_json::Error),
+    #[error("The Python interpreter in WSL reported an invalid version: `{0}`")]
+    Version(String),
+    #[error("The Python interpreter in WSL is `{0}`, but only CPython is supported for resolution")]
+    Implementation(String),
+}
+
+/// A request for the Python interpreter in WSL, i.e., `python3` in the given distribution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WslRequest {
+    /// The WSL distribution to query, or the default distribution if `None`.
+    pub distribution: Option<String>,
+}
+
+/// The properties of a Python interpreter in WSL, as reported by the interpreter itself.
+#[derive(Debug, Deserialize)]
+struct WslQuery {
+    implementation: String,
+    version: String,
+    machine: String,
+    libc: String,
+    libc_version: String,
+}
+
+/// A Python interpreter in WSL, usable for resolution but not for execution.
+#[derive(Debug, Clone)]
+pub struct WslPython {
+    version: PythonVersion,
+    machine: String,
+    /// The glibc version, as `(major, minor)`, or `None` for other C libraries, e.g., musl.
+    glibc: Option<(u32, u32)>,
+}
+
+impl WslPython {
+    /// Query the `python3` executable in WSL.
+    pub fn query(request: &WslRequest) -> Result<Self, Error> {
+        if !cfg!(windows) {
+            return Err(Error::Unsupported);
+        }
+
+        let mut command = Command::new("wsl.exe");
+        if let Some(distribution) = &request.distribution {
+            command.arg("--distribution").arg(distribution);
+        }
+        command
+            .arg("--exec")
+            .arg("python3")
+            .arg("-c")
+            .arg(QUERY_SCRIPT);
+        debug!("Querying the Python interpreter in WSL: {command:?}");
+
+        let output = command.output().map_err(Error::Spawn)?;
+        if !output.status.success() {
+            return Err(Error::Query {
+                distribution: request.distribution.clone(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+        let query: WslQuery = serde_json::from_slice(&output.stdout).map_err(Error::Parse)?;
+        Self::from_query(query)
+    }
+
+    fn from_query(query: WslQuery) -> Result<Self, Error> {
+        if query.implementation != "cpython" {
+            return Err(Error::Implementation(query.implementation));
+        }
+        let version =
+            PythonVersion::from_str(&query.version).map_err(|_| Error::Version(query.version))?;
+        let glibc = if query.libc == "glibc" {
+            query
+                .libc_version
+                .split_once('.')
+                .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        } else {
+            None
+        };
+        Ok(Self {
+            version,
+            machine: query.machine,
+            glibc,
+        })
+    }
+
+    /// The Python version of the interpreter, e.g., `3.12.3`.
+    pub fn python_version(&self) -> &PythonVersion {
+        &self.version
+    }
+
+    /// The names of the target triples that describe the interpreter's platform, most specific
+    /// first, e.g., `x86_64-manylinux_2_35` followed by `x86_64-unknown-linux-gnu`.
+    ///
+    /// Not every glibc version has a corresponding target, so callers should use the first name
+    /// that they support.
+    pub fn target_triples(&self) -> Vec<String> {
+        let machine = match self.machine.as_str() {
+            "arm64" => "aarch64",
+            machine => machine,
+        };
+        match self.glibc {
+            Some((major, minor)) => vec![
+                format!("{machine}-manylinux_{major}_{minor}"),
+                format!("{machine}-unknown-linux-gnu"),
+            ],
+            None => vec![format!("{machine}-unknown-linux-musl")],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WslPython, WslQuery};
+
+    #[test]
+    fn target_triples() {
+        let python = WslPython::from_query(WslQuery {
+            implementation: "cpython".to_string(),
+            version: "3.12.3".to_string(),
+            machine: "x86_64".to_string(),
+            libc: "glibc".to_string(),
+            libc_version: "2.39".to_string(),
+        })
+        .unwrap();
+        assert_eq!(python.python_version().to_string(), "3.12.3");
+        assert_eq!(
+            python.target_triples(),
+            ["x86_64-manylinux_2_39", "x86_64-unknown-linux-gnu"]
+        );
+
+        let python = WslPython::from_query(WslQuery {
+            implementation: "cpython".to_string(),
+            version: "3.12.3".to_string(),
+            machine: "aarch64".to_string(),
+            libc: String::new(),
+            libc_version: String::new(),
+        })
+        .unwrap();
+        assert_eq!(python.target_triples(), ["aarch64-unknown-linux-musl"]);
+    }
+}
@@ -7,9 +7,9 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
-    PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
-    VERSION,
+    Installable, Lock, LockError, LockVersion, LockedInterpreter, Package, PackageMap, PylockToml,
+    PylockTomlErrorKind, REVISION, RequirementsTxtExport, ResolverManifest, SatisfiesResult,
+    ScriptExport, TreeDisplay, VERSION,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
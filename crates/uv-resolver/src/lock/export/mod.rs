@@ -19,11 +19,13 @@ use crate::graph_ops::{Reachable, marker_reachability};
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::export::requirements_txt::RequirementsTxtExport;
+pub use crate::lock::export::script::ScriptExport;
 use crate::universal_marker::resolve_conflicts;
 use crate::{Installable, Package};
 
 mod pylock_toml;
 mod requirements_txt;
+mod script;
 
 /// A flat requirement, with its associated marker.
 #[derive(Debug, Clone, PartialEq, Eq)]
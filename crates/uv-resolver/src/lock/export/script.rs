@@ -0,0 +1,110 @@
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_distribution_filename::{DistExtension, SourceDistExtension};
+use uv_git_types::GitReference;
+use uv_normalize::PackageName;
+use uv_pep440::{VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{Requirement, VerbatimUrl, VersionOrUrl};
+use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
+use uv_redacted::DisplaySafeUrl;
+
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{LockErrorKind, Source};
+use crate::{Installable, LockError};
+
+/// An export of a [`Lock`] as the `dependencies` of a PEP 723 script.
+///
+/// Each package is pinned to its locked version or URL. Editable and virtual packages, like the
+/// workspace members themselves, are omitted, as they can't be referenced from a standalone
+/// script.
+#[derive(Debug)]
+pub struct ScriptExport(Vec<Requirement>);
+
+impl ScriptExport {
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        dev: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file.
+        let ExportableRequirements(nodes) =
+            ExportableRequirements::from_lock(target, prune, extras, dev, false, install_options);
+
+        let mut requirements = Vec::with_capacity(nodes.len());
+        for ExportableRequirement {
+            package, marker, ..
+        } in nodes
+        {
+            let version_or_url = match &package.id.source {
+                Source::Registry(_) => {
+                    let version = package
+                        .id
+                        .version
+                        .as_ref()
+                        .expect("registry package without version");
+                    VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
+                        VersionSpecifier::equals_version(version.clone()),
+                    ))
+                }
+                Source::Git(url, git) => {
+                    // Remove the fragment and query from the URL; they're already present in the
+                    // `GitSource`.
+                    let mut url = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
+                    url.set_fragment(None);
+                    url.set_query(None);
+
+                    // Reconstruct the `GitUrl` from the `GitSource`.
+                    let git_url = uv_git_types::GitUrl::from_commit(
+                        url,
+                        GitReference::from(git.kind.clone()),
+                        git.precise,
+                    )
+                    .expect("Internal Git URLs must have supported schemes");
+
+                    VersionOrUrl::Url(VerbatimUrl::from_url(DisplaySafeUrl::from(ParsedGitUrl {
+                        url: git_url,
+                        subdirectory: git.subdirectory.clone(),
+                    })))
+                }
+                Source::Direct(url, direct) => VersionOrUrl::Url(VerbatimUrl::from_url(
+                    DisplaySafeUrl::from(ParsedArchiveUrl {
+                        url: url.to_url().map_err(LockErrorKind::InvalidUrl)?,
+                        subdirectory: direct.subdirectory.clone(),
+                        ext: DistExtension::Source(SourceDistExtension::TarGz),
+                    }),
+                )),
+                Source::Path(path) | Source::Directory(path) => {
+                    // The script may live outside the workspace, so use an absolute path.
+                    let path = target.install_path().join(path);
+                    VersionOrUrl::Url(VerbatimUrl::from_absolute_path(&path).map_err(|err| {
+                        LockErrorKind::VerbatimUrl {
+                            id: package.id.clone(),
+                            err,
+                        }
+                    })?)
+                }
+                Source::Editable(_) | Source::Virtual(_) => continue,
+            };
+
+            requirements.push(Requirement {
+                name: package.id.name.clone(),
+                extras: Box::new([]),
+                version_or_url: Some(version_or_url),
+                marker,
+                origin: None,
+            });
+        }
+
+        requirements.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(Self(requirements))
+    }
+
+    /// Return the pinned requirements.
+    pub fn requirements(&self) -> &[Requirement] {
+        &self.0
+    }
+}
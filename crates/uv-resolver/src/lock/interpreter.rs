@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+use toml_edit::{Table, value};
+
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_python::{Interpreter, PythonVariant};
+
+/// The Python interpreter that was used to lock a project, as recorded with `lock-interpreter`.
+///
+/// Only the properties that affect the environment are recorded, i.e., the implementation, the
+/// minor version, and the variant, such that the same lockfile produces the same environment on
+/// every machine that uses a matching interpreter. The range of Python versions requested by the
+/// project is recorded alongside, such that an interpreter outside of the range is reported even
+/// if it shares the minor version.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LockedInterpreter {
+    /// The implementation name, e.g., `cpython`.
+    implementation: String,
+    /// The minor version, e.g., `3.12`.
+    version: Version,
+    /// The range of Python versions requested by the project, e.g., `==3.12.*`.
+    #[serde(default)]
+    requires_python: Option<VersionSpecifiers>,
+    /// The variant, e.g., `freethreaded`.
+    #[serde(default, deserialize_with = "deserialize_variant")]
+    variant: PythonVariant,
+}
+
+impl LockedInterpreter {
+    /// Return the [`LockedInterpreter`] for the given [`Interpreter`], along with the range of
+    /// Python versions requested by the project, if any.
+    pub fn from_interpreter(
+        interpreter: &Interpreter,
+        requires_python: Option<VersionSpecifiers>,
+    ) -> Self {
+        Self {
+            implementation: interpreter.implementation_name().to_string(),
+            version: interpreter.python_minor_version(),
+            requires_python,
+            variant: interpreter.variant(),
+        }
+    }
+
+    /// Set the range of Python versions requested by the project.
+    #[must_use]
+    pub fn with_requires_python(self, requires_python: Option<VersionSpecifiers>) -> Self {
+        Self {
+            requires_python,
+            ..self
+        }
+    }
+
+    /// Return the range of Python versions requested by the project, if recorded.
+    pub fn requires_python(&self) -> Option<&VersionSpecifiers> {
+        self.requires_python.as_ref()
+    }
+
+    /// Return a description of each property in which the given [`Interpreter`] differs from the
+    /// recorded interpreter, e.g., ``Python version `3.11` (locked with `3.12`)``.
+    pub fn differences(&self, interpreter: &Interpreter) -> Vec<String> {
+        let mut differences = Vec::new();
+        let implementation = interpreter.implementation_name();
+        if self.implementation != implementation {
+            differences.push(format!(
+                "implementation `{implementation}` (locked with `{}`)",
+                self.implementation
+            ));
+        }
+        let version = interpreter.python_minor_version();
+        if self.version != version {
+            differences.push(format!(
+                "Python version `{version}` (locked with `{}`)",
+                self.version
+            ));
+        } else if let Some(requires_python) = &self.requires_python {
+            let version = interpreter.python_version().only_release();
+            if !requires_python.contains(&version) {
+                differences.push(format!(
+                    "Python version `{version}` (locked with `{requires_python}`)"
+                ));
+            }
+        }
+        let variant = interpreter.variant();
+        if self.variant != variant {
+            differences.push(format!(
+                "variant `{variant}` (locked with `{}`)",
+                self.variant
+            ));
+        }
+        differences
+    }
+
+    /// Serialize the [`LockedInterpreter`] to a TOML table.
+    pub(crate) fn to_toml(&self) -> Table {
+        let mut table = Table::new();
+        table.insert("implementation", value(&self.implementation));
+        table.insert("version", value(self.version.to_string()));
+        if let Some(requires_python) = &self.requires_python {
+            table.insert("requires-python", value(requires_python.to_string()));
+        }
+        if self.variant != PythonVariant::Default {
+            table.insert("variant", value(self.variant.to_string()));
+        }
+        table
+    }
+}
+
+impl Display for LockedInterpreter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.implementation, self.version)?;
+        if self.variant != PythonVariant::Default {
+            write!(f, "+{}", self.variant)?;
+        }
+        Ok(())
+    }
+}
+
+fn deserialize_variant<'de, D>(deserializer: D) -> Result<PythonVariant, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let variant = String::deserialize(deserializer)?;
+    PythonVariant::from_str(&variant)
+        .map_err(|()| serde::de::Error::custom(format!("invalid Python variant: `{variant}`")))
+}
@@ -51,9 +51,10 @@ use uv_workspace::WorkspaceMember;
 
 use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
-pub use crate::lock::export::RequirementsTxtExport;
 pub use crate::lock::export::{PylockToml, PylockTomlErrorKind};
+pub use crate::lock::export::{RequirementsTxtExport, ScriptExport};
 pub use crate::lock::installable::Installable;
+pub use crate::lock::interpreter::LockedInterpreter;
 pub use crate::lock::map::PackageMap;
 pub use crate::lock::tree::TreeDisplay;
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
@@ -65,6 +66,7 @@ use crate::{
 
 mod export;
 mod installable;
+mod interpreter;
 mod map;
 mod tree;
 
@@ -72,7 +74,7 @@ mod tree;
 pub const VERSION: u32 = 1;
 
 /// The current revision of the lockfile format.
-const REVISION: u32 = 3;
+pub const REVISION: u32 = 4;
 
 static LINUX_MARKERS: LazyLock<UniversalMarker> = LazyLock::new(|| {
     let pep508 = MarkerTree::from_str("os_name == 'posix' and sys_platform == 'linux'").unwrap();
@@ -201,6 +203,8 @@ pub struct Lock {
     requires_python: RequiresPython,
     /// We discard the lockfile if these options don't match.
     options: ResolverOptions,
+    /// The Python interpreter used to generate the lock, if recorded.
+    interpreter: Option<LockedInterpreter>,
     /// The actual locked version and their metadata.
     packages: Vec<Package>,
     /// A map from package ID to index in `packages`.
@@ -708,6 +712,7 @@ impl Lock {
             required_environments,
             requires_python,
             options,
+            interpreter: None,
             packages,
             by_id,
             manifest,
@@ -758,6 +763,13 @@ impl Lock {
         self
     }
 
+    /// Record the Python interpreter that was used to generate this lock.
+    #[must_use]
+    pub fn with_interpreter(mut self, interpreter: Option<LockedInterpreter>) -> Self {
+        self.interpreter = interpreter;
+        self
+    }
+
     /// Returns `true` if this [`Lock`] includes `provides-extra` metadata.
     pub fn supports_provides_extra(&self) -> bool {
         // `provides-extra` was added in Version 1 Revision 1.
@@ -796,11 +808,25 @@ impl Lock {
         &self.packages
     }
 
+    /// Returns the first package that can only be installed on a free-threaded CPython
+    /// interpreter, i.e., a package without a source distribution whose wheels all target a
+    /// free-threaded ABI (like `cp313t`).
+    pub fn freethreaded_package(&self) -> Option<&Package> {
+        self.packages
+            .iter()
+            .find(|package| package.requires_freethreaded())
+    }
+
     /// Returns the supported Python version range for the lockfile, if present.
     pub fn requires_python(&self) -> &RequiresPython {
         &self.requires_python
     }
 
+    /// Returns the Python interpreter used to generate the lock, if recorded.
+    pub fn interpreter(&self) -> Option<&LockedInterpreter> {
+        self.interpreter.as_ref()
+    }
+
     /// Returns the resolution mode used to generate this lock.
     pub fn resolution_mode(&self) -> ResolutionMode {
         self.options.resolution_mode
@@ -1041,6 +1067,11 @@ impl Lock {
             doc.insert("conflicts", value(list));
         }
 
+        // Write the interpreter that was used to generate the resolution, if recorded.
+        if let Some(interpreter) = &self.interpreter {
+            doc.insert("interpreter", Item::Table(interpreter.to_toml()));
+        }
+
         // Write the settings that were used to generate the resolution.
         // This enables us to invalidate the lockfile if the user changes
         // their settings.
@@ -2200,6 +2231,8 @@ struct LockWire {
     #[serde(default)]
     options: ResolverOptions,
     #[serde(default)]
+    interpreter: Option<LockedInterpreter>,
+    #[serde(default)]
     manifest: ResolverManifest,
     #[serde(rename = "package", alias = "distribution", default)]
     packages: Vec<PackageWire>,
@@ -2258,7 +2291,8 @@ impl TryFrom<LockWire> for Lock {
             supported_environments,
             required_environments,
             fork_markers,
-        )?;
+        )?
+        .with_interpreter(wire.interpreter);
 
         Ok(lock)
     }
@@ -3024,6 +3058,23 @@ impl Package {
         }
     }
 
+    /// Returns `true` if the package can only be installed on a free-threaded CPython interpreter.
+    fn requires_freethreaded(&self) -> bool {
+        self.sdist.is_none()
+            && !self.wheels.is_empty()
+            && self.wheels.iter().all(|wheel| {
+                wheel.filename.abi_tags().iter().all(|tag| {
+                    matches!(
+                        tag,
+                        AbiTag::CPython {
+                            gil_disabled: true,
+                            ..
+                        }
+                    )
+                })
+            })
+    }
+
     /// Returns the [`PackageName`] of the package.
     pub fn name(&self) -> &PackageName {
         &self.id.name
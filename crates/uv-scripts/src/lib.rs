@@ -412,6 +412,93 @@ impl FromStr for Pep723Metadata {
     }
 }
 
+/// An interpreter hint embedded in a Python script without PEP 723 metadata.
+///
+/// Hints are read from a `# uv: python=<request>` comment in the script's leading comment block
+/// or, failing that, from a versioned interpreter in the shebang (e.g., `#!/usr/bin/env python3.11`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptPythonHint(String);
+
+impl ScriptPythonHint {
+    /// Parse the interpreter hint from the contents of a Python script, if any.
+    pub fn parse(contents: &[u8]) -> Option<Self> {
+        let contents = String::from_utf8_lossy(contents);
+        let mut lines = contents.lines().peekable();
+
+        // Extract the shebang, if present.
+        let shebang = lines
+            .next_if(|line| line.starts_with("#!"))
+            .and_then(|line| Self::from_shebang(&line[2..]));
+
+        // Search the leading comment block for an explicit `# uv: python=` directive, which takes
+        // precedence over the shebang.
+        for line in lines {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some(comment) = line.strip_prefix('#') else {
+                break;
+            };
+            let Some(directive) = comment.trim_start().strip_prefix("uv:") else {
+                continue;
+            };
+            let Some((key, value)) = directive.split_once('=') else {
+                continue;
+            };
+            if key.trim() != "python" {
+                continue;
+            }
+            let value = value.trim();
+            if !value.is_empty() {
+                return Some(Self(value.to_string()));
+            }
+        }
+
+        shebang
+    }
+
+    /// Read the interpreter hint from a Python script, if any.
+    pub async fn read(file: impl AsRef<Path>) -> Result<Option<Self>, io::Error> {
+        let contents = fs_err::tokio::read(&file).await?;
+        Ok(Self::parse(&contents))
+    }
+
+    /// Return the interpreter request, e.g., `3.11`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Extract an interpreter request from a shebang line (without the leading `#!`).
+    ///
+    /// Supports `uv run --python <request>` shebangs and versioned interpreter names like
+    /// `python3.11`; unversioned interpreters (e.g., `python3`) don't yield a hint.
+    fn from_shebang(shebang: &str) -> Option<Self> {
+        let mut tokens = shebang.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if token == "--python" || token == "-p" {
+                return tokens.next().map(|value| Self(value.to_string()));
+            }
+            if let Some(value) = token.strip_prefix("--python=") {
+                return Some(Self(value.to_string()));
+            }
+
+            let name = token.rsplit(['/', '\\']).next().unwrap_or(token);
+            let Some(version) = name.strip_prefix("python") else {
+                continue;
+            };
+            if version.contains('.')
+                && version
+                    .split('.')
+                    .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()))
+            {
+                return Some(Self(version.to_string()));
+            }
+        }
+        None
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct Tool {
@@ -430,6 +517,7 @@ pub struct ToolUv {
     pub build_constraint_dependencies: Option<Vec<uv_pep508::Requirement<VerbatimParsedUrl>>>,
     pub extra_build_dependencies: Option<BTreeMap<PackageName, Vec<ExtraBuildDependency>>>,
     pub sources: Option<BTreeMap<PackageName, Sources>>,
+    pub lock_url: Option<DisplaySafeUrl>,
 }
 
 #[derive(Debug, Error)]
@@ -651,7 +739,7 @@ fn serialize_metadata(metadata: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Pep723Error, Pep723Script, ScriptTag, serialize_metadata};
+    use crate::{Pep723Error, Pep723Script, ScriptPythonHint, ScriptTag, serialize_metadata};
     use std::str::FromStr;
 
     #[test]
@@ -1132,4 +1220,43 @@ mod tests {
             "#}
         );
     }
+
+    #[test]
+    fn python_hint_comment() {
+        let contents = indoc::indoc! {r"
+        #!/usr/bin/env python3.10
+        # A small utility.
+        # uv: python=3.11
+
+        print('hello')
+    "};
+
+        assert_eq!(
+            ScriptPythonHint::parse(contents.as_bytes()),
+            Some(ScriptPythonHint("3.11".to_string()))
+        );
+    }
+
+    #[test]
+    fn python_hint_shebang() {
+        assert_eq!(
+            ScriptPythonHint::parse(b"#!/usr/bin/env python3.12\nprint('hello')\n"),
+            Some(ScriptPythonHint("3.12".to_string()))
+        );
+        assert_eq!(
+            ScriptPythonHint::parse(b"#!/usr/bin/env -S uv run --python 3.13 --script\n"),
+            Some(ScriptPythonHint("3.13".to_string()))
+        );
+        assert_eq!(ScriptPythonHint::parse(b"#!/usr/bin/env python3\n"), None);
+    }
+
+    #[test]
+    fn python_hint_after_code() {
+        let contents = indoc::indoc! {r"
+        import sys
+        # uv: python=3.11
+    "};
+
+        assert_eq!(ScriptPythonHint::parse(contents.as_bytes()), None);
+    }
 }
@@ -4,8 +4,8 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 use url::Url;
 
 use uv_configuration::{
-    BuildIsolation, ExportFormat, IndexStrategy, KeyringProviderType, Reinstall, RequiredVersion,
-    TargetTriple, TrustedPublishing, Upgrade,
+    BuildIsolation, ByteSize, ExportFormat, IndexStrategy, KeyringProviderType, NetworkAccess,
+    PathPolicy, Reinstall, RequiredVersion, TargetTriple, TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, PackageConfigSettings, PipExtraIndex,
@@ -13,7 +13,7 @@ use uv_distribution_types::{
 };
 use uv_install_wheel::LinkMode;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::{PythonDownloads, PythonPreference, PythonResolution, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
@@ -23,7 +23,10 @@ use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::{FilesystemOptions, Options, PipOptions};
+use crate::{
+    FilesystemOptions, MaxEnvironmentSizeAction, Options, PipOptions, PythonDiscoveryOptions,
+    PythonInstallHookFailure, PythonInstallHookOptions, PythonQueryLimitsOptions, RunOptions,
+};
 
 pub trait Combine {
     /// Combine two values, preferring the values in `self`.
@@ -42,12 +45,17 @@ pub trait Combine {
 }
 
 impl Combine for Option<FilesystemOptions> {
-    /// Combine the options used in two [`FilesystemOptions`]s. Retains the root of `self`.
+    /// Combine the options used in two [`FilesystemOptions`]s, retaining the sources of both in
+    /// order of precedence. Retains the root of `self`.
     fn combine(self, other: Self) -> Self {
         match (self, other) {
-            (Some(a), Some(b)) => Some(FilesystemOptions(
-                a.into_options().combine(b.into_options()),
-            )),
+            (Some(mut a), Some(b)) => {
+                a.sources.extend(b.sources);
+                Some(FilesystemOptions {
+                    options: a.options.combine(b.options),
+                    sources: a.sources,
+                })
+            }
             (a, b) => a.or(b),
         }
     }
@@ -72,6 +80,42 @@ impl Combine for Option<PipOptions> {
     }
 }
 
+impl Combine for Option<RunOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PythonInstallHookOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PythonDiscoveryOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<PythonQueryLimitsOptions> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 macro_rules! impl_combine_or {
     ($name:ident) => {
         impl Combine for Option<$name> {
@@ -84,6 +128,7 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(ByteSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerTimestamp);
 impl_combine_or!(ExportFormat);
@@ -93,15 +138,20 @@ impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
 impl_combine_or!(KeyringProviderType);
 impl_combine_or!(LinkMode);
+impl_combine_or!(MaxEnvironmentSizeAction);
+impl_combine_or!(NetworkAccess);
 impl_combine_or!(DisplaySafeUrl);
 impl_combine_or!(NonZeroUsize);
 impl_combine_or!(PathBuf);
+impl_combine_or!(PathPolicy);
 impl_combine_or!(PipExtraIndex);
 impl_combine_or!(PipFindLinks);
 impl_combine_or!(PipIndex);
 impl_combine_or!(PrereleaseMode);
 impl_combine_or!(PythonDownloads);
+impl_combine_or!(PythonInstallHookFailure);
 impl_combine_or!(PythonPreference);
+impl_combine_or!(PythonResolution);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
@@ -113,6 +163,7 @@ impl_combine_or!(TorchMode);
 impl_combine_or!(TrustedPublishing);
 impl_combine_or!(Url);
 impl_combine_or!(bool);
+impl_combine_or!(u64);
 
 impl<T> Combine for Option<Vec<T>> {
     /// Combine two vectors by extending the vector in `self` with the vector in `other`, if they're
@@ -143,6 +194,25 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for Option<BTreeMap<PathBuf, BTreeMap<String, String>>> {
+    /// Combine two maps of environment variables by merging the variables for each path, with the
+    /// values in `self` taking precedence.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (path, vars) in b {
+                    let entry = a.entry(path).or_default();
+                    for (key, value) in vars {
+                        entry.entry(key).or_insert(value);
+                    }
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
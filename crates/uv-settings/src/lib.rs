@@ -12,14 +12,47 @@ pub use crate::settings::*;
 mod combine;
 mod settings;
 
-/// The [`Options`] as loaded from a configuration file on disk.
+/// The [`Options`] as loaded from the configuration files on disk.
+///
+/// Retains the options read from each file, in order of precedence, such that the origin of each
+/// setting can be reported.
 #[derive(Debug, Clone)]
-pub struct FilesystemOptions(Options);
+pub struct FilesystemOptions {
+    options: Options,
+    sources: Vec<ConfigSource>,
+}
 
 impl FilesystemOptions {
+    /// Create a [`FilesystemOptions`] from the [`Options`] in a single configuration file.
+    fn new(kind: ConfigSourceKind, path: Option<PathBuf>, options: Options) -> Self {
+        Self {
+            sources: vec![ConfigSource {
+                kind,
+                path,
+                options: options.clone(),
+            }],
+            options,
+        }
+    }
+
+    /// Create a [`FilesystemOptions`] from the inline metadata of a PEP 723 script.
+    pub fn from_script(path: Option<&Path>, options: Options) -> Self {
+        Self::new(
+            ConfigSourceKind::Script,
+            path.map(Path::to_path_buf),
+            options,
+        )
+    }
+
     /// Convert the [`FilesystemOptions`] into [`Options`].
     pub fn into_options(self) -> Options {
-        self.0
+        self.options
+    }
+
+    /// Return the configuration files that contributed to the [`FilesystemOptions`], in order of
+    /// precedence.
+    pub fn sources(&self) -> &[ConfigSource] {
+        &self.sources
     }
 }
 
@@ -27,7 +60,61 @@ impl Deref for FilesystemOptions {
     type Target = Options;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.options
+    }
+}
+
+/// A configuration file that contributed to a [`FilesystemOptions`].
+#[derive(Debug, Clone)]
+pub struct ConfigSource {
+    kind: ConfigSourceKind,
+    path: Option<PathBuf>,
+    options: Options,
+}
+
+impl ConfigSource {
+    /// Return the kind of configuration file.
+    pub fn kind(&self) -> ConfigSourceKind {
+        self.kind
+    }
+
+    /// Return the path to the configuration file, if any.
+    ///
+    /// Scripts read from `stdin` don't have a path.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Return the [`Options`] read from the file, before they were combined with any other file.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+}
+
+/// The kind of a [`ConfigSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSourceKind {
+    /// The file passed with `--config-file`.
+    ConfigFile,
+    /// The inline metadata of a PEP 723 script.
+    Script,
+    /// The nearest `uv.toml` or `pyproject.toml` to the project.
+    Project,
+    /// The user-level `uv.toml`.
+    User,
+    /// The system-level `uv.toml`.
+    System,
+}
+
+impl std::fmt::Display for ConfigSourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ConfigFile => f.write_str("`--config-file`"),
+            Self::Script => f.write_str("script"),
+            Self::Project => f.write_str("project"),
+            Self::User => f.write_str("user"),
+            Self::System => f.write_str("system"),
+        }
     }
 }
 
@@ -45,7 +132,8 @@ impl FilesystemOptions {
             Ok(options) => {
                 tracing::debug!("Found user configuration in: `{}`", file.display());
                 validate_uv_toml(&file, &options)?;
-                Ok(Some(Self(options)))
+                let options = strip_project_fields(&file, options);
+                Ok(Some(Self::new(ConfigSourceKind::User, Some(file), options)))
             }
             Err(Error::Io(err))
                 if matches!(
@@ -69,7 +157,12 @@ impl FilesystemOptions {
         tracing::debug!("Found system configuration in: `{}`", file.display());
         let options = read_file(&file)?;
         validate_uv_toml(&file, &options)?;
-        Ok(Some(Self(options)))
+        let options = strip_project_fields(&file, options);
+        Ok(Some(Self::new(
+            ConfigSourceKind::System,
+            Some(file),
+            options,
+        )))
     }
 
     /// Find the [`FilesystemOptions`] for the given path.
@@ -111,7 +204,9 @@ impl FilesystemOptions {
             Ok(content) => {
                 let options = toml::from_str::<Options>(&content)
                     .map_err(|err| Error::UvToml(path.clone(), Box::new(err)))?
-                    .relative_to(&std::path::absolute(dir)?)?;
+                    .relative_to(&std::path::absolute(dir)?)?
+                    .expand_env_vars()
+                    .map_err(|err| Error::ExpandEnvVars(path.clone(), err))?;
 
                 // If the directory also contains a `[tool.uv]` table in a `pyproject.toml` file,
                 // warn.
@@ -128,7 +223,11 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self::new(
+                    ConfigSourceKind::Project,
+                    Some(path),
+                    options,
+                )));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -156,10 +255,17 @@ impl FilesystemOptions {
                     return Ok(None);
                 };
 
-                let options = options.relative_to(&std::path::absolute(dir)?)?;
+                let options = options
+                    .relative_to(&std::path::absolute(dir)?)?
+                    .expand_env_vars()
+                    .map_err(|err| Error::ExpandEnvVars(path.clone(), err))?;
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self::new(
+                    ConfigSourceKind::Project,
+                    Some(path),
+                    options,
+                )));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -175,13 +281,11 @@ impl FilesystemOptions {
 
         let options = read_file(path)?;
         validate_uv_toml(path, &options)?;
-        Ok(Self(options))
-    }
-}
-
-impl From<Options> for FilesystemOptions {
-    fn from(options: Options) -> Self {
-        Self(options)
+        Ok(Self::new(
+            ConfigSourceKind::ConfigFile,
+            Some(path.to_path_buf()),
+            options,
+        ))
     }
 }
 
@@ -195,9 +299,24 @@ fn read_file(path: &Path) -> Result<Options, Error> {
     } else {
         options
     };
+    let options = options
+        .expand_env_vars()
+        .map_err(|err| Error::ExpandEnvVars(path.to_path_buf(), err))?;
     Ok(options)
 }
 
+/// Remove the settings that are only supported in project-level configuration from a user-level
+/// or system-level `uv.toml`, with a warning.
+fn strip_project_fields(path: &Path, mut options: Options) -> Options {
+    if options.install_mirrors.python_install_dir.take().is_some() {
+        warn_user!(
+            "Ignoring `python-install-dir` in `{}`: it is only supported in project-level configuration (use `UV_PYTHON_INSTALL_DIR` to change the user-level directory)",
+            path.user_display().cyan()
+        );
+    }
+    options
+}
+
 /// Validate that an [`Options`] schema is compatible with `uv.toml`.
 fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     let Options {
@@ -207,6 +326,15 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         publish: _,
         add: _,
         pip: _,
+        run: _,
+        python_install_hook: _,
+        python_discovery: _,
+        python_query_env: _,
+        python_query_limits: _,
+        max_environment_size: _,
+        max_environment_size_action: _,
+        export_activate: _,
+        fail_fast_env: _,
         cache_keys: _,
         override_dependencies: _,
         constraint_dependencies: _,
@@ -220,7 +348,10 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         default_groups,
         dependency_groups,
         managed,
+        lock_interpreter,
+        manage_ignore_files,
         package,
+        python,
         build_backend,
     } = options;
     // The `uv.toml` format is not allowed to include any of the following, which are
@@ -256,9 +387,24 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
+    if lock_interpreter.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "lock-interpreter",
+        ));
+    }
+    if manage_ignore_files.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "manage-ignore-files",
+        ));
+    }
     if package.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "package"));
     }
+    if python.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "python"));
+    }
     if build_backend.is_some() {
         return Err(Error::PyprojectOnlyField(
             path.to_path_buf(),
@@ -338,6 +484,11 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 python_install_mirror,
                 pypy_install_mirror,
                 python_downloads_json_url,
+                python_install_keyring_provider,
+                python_install_ca_certs,
+                python_resolution,
+                python_install_dir,
+                install_staging_dir,
             },
         publish:
             PublishOptions {
@@ -347,6 +498,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
             },
         add: AddOptions { add_bounds },
         pip,
+        run,
+        python_install_hook,
+        python_discovery,
+        python_query_env,
+        python_query_limits,
+        max_environment_size,
+        max_environment_size_action,
+        export_activate,
+        fail_fast_env,
         cache_keys,
         override_dependencies,
         constraint_dependencies,
@@ -360,7 +520,10 @@ fn warn_uv_toml_masked_fields(options: &Options) {
         default_groups: _,
         dependency_groups: _,
         managed: _,
+        lock_interpreter: _,
+        manage_ignore_files: _,
         package: _,
+        python: _,
         build_backend: _,
     } = options;
 
@@ -501,6 +664,15 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if python_downloads_json_url.is_some() {
         masked_fields.push("python-downloads-json-url");
     }
+    if python_install_keyring_provider.is_some() {
+        masked_fields.push("python-install-keyring-provider");
+    }
+    if python_install_ca_certs.is_some() {
+        masked_fields.push("python-install-ca-certs");
+    }
+    if python_resolution.is_some() {
+        masked_fields.push("python-resolution");
+    }
     if publish_url.is_some() {
         masked_fields.push("publish-url");
     }
@@ -516,6 +688,39 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if pip.is_some() {
         masked_fields.push("pip");
     }
+    if run.is_some() {
+        masked_fields.push("run");
+    }
+    if python_install_hook.is_some() {
+        masked_fields.push("python-install-hook");
+    }
+    if python_discovery.is_some() {
+        masked_fields.push("python-discovery");
+    }
+    if python_install_dir.is_some() {
+        masked_fields.push("python-install-dir");
+    }
+    if install_staging_dir.is_some() {
+        masked_fields.push("install-staging-dir");
+    }
+    if python_query_env.is_some() {
+        masked_fields.push("python-query-env");
+    }
+    if python_query_limits.is_some() {
+        masked_fields.push("python-query-limits");
+    }
+    if max_environment_size.is_some() {
+        masked_fields.push("max-environment-size");
+    }
+    if max_environment_size_action.is_some() {
+        masked_fields.push("max-environment-size-action");
+    }
+    if export_activate.is_some() {
+        masked_fields.push("export-activate");
+    }
+    if fail_fast_env.is_some() {
+        masked_fields.push("fail-fast-env");
+    }
     if cache_keys.is_some() {
         masked_fields.push("cache_keys");
     }
@@ -551,6 +756,9 @@ pub enum Error {
     #[error("Failed to parse: `{}`", _0.user_display())]
     UvToml(PathBuf, #[source] Box<toml::de::Error>),
 
+    #[error("Failed to expand environment variables in: `{}`", _0.user_display())]
+    ExpandEnvVars(PathBuf, #[source] uv_pep508::UnsetEnvVarError),
+
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1)]
     PyprojectOnlyField(PathBuf, &'static str),
 
@@ -570,6 +778,7 @@ pub enum Error {
 pub struct EnvironmentOptions {
     pub python_install_bin: Option<bool>,
     pub python_install_registry: Option<bool>,
+    pub python_query_limits: PythonQueryLimitsOptions,
 }
 
 impl EnvironmentOptions {
@@ -580,6 +789,16 @@ impl EnvironmentOptions {
             python_install_registry: parse_boolish_environment_variable(
                 EnvVars::UV_PYTHON_INSTALL_REGISTRY,
             )?,
+            python_query_limits: PythonQueryLimitsOptions {
+                timeout: parse_integer_environment_variable(EnvVars::UV_PYTHON_QUERY_TIMEOUT)?,
+                memory_limit: parse_integer_environment_variable(
+                    EnvVars::UV_PYTHON_QUERY_MEMORY_LIMIT,
+                )?,
+                cpu_limit: parse_integer_environment_variable(EnvVars::UV_PYTHON_QUERY_CPU_LIMIT)?,
+                concurrency: parse_integer_environment_variable(
+                    EnvVars::UV_PYTHON_QUERY_CONCURRENCY,
+                )?,
+            },
         })
     }
 }
@@ -635,3 +854,31 @@ fn parse_boolish_environment_variable(name: &'static str) -> Result<Option<bool>
 
     Ok(Some(value))
 }
+
+/// Parse an integer environment variable.
+fn parse_integer_environment_variable<T>(name: &'static str) -> Result<Option<T>, Error>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(value) = std::env::var_os(name) else {
+        return Ok(None);
+    };
+
+    let Some(value) = value.to_str() else {
+        return Err(Error::InvalidEnvironmentVariable {
+            name: name.to_string(),
+            value: value.to_string_lossy().to_string(),
+            err: "expected a valid UTF-8 string".to_string(),
+        });
+    };
+
+    match value.trim().parse::<T>() {
+        Ok(value) => Ok(Some(value)),
+        Err(err) => Err(Error::InvalidEnvironmentVariable {
+            name: name.to_string(),
+            value: value.to_string(),
+            err: err.to_string(),
+        }),
+    }
+}
@@ -1,11 +1,14 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{
+    borrow::Cow, collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf,
+};
 
 use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    BuildIsolation, IndexStrategy, KeyringProviderType, PackageNameSpecifier, Reinstall,
-    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
+    ActivationShell, BuildIsolation, ByteSize, IndexStrategy, KeyringProviderType, NetworkAccess,
+    PackageNameSpecifier, PathPolicy, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade,
 };
 use uv_distribution_types::{
     ConfigSettings, ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PackageConfigSettings,
@@ -14,15 +17,17 @@ use uv_distribution_types::{
 use uv_install_wheel::LinkMode;
 use uv_macros::{CombineOptions, OptionsMetadata};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_pep508::Requirement;
+use uv_pep508::{Requirement, UnsetEnvVarError, try_expand_env_vars};
 use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
-use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
+use uv_python::downloads::PythonDownloadClientSettings;
+use uv_python::{
+    HostCertificate, PythonDownloads, PythonPreference, PythonResolution, PythonVersion,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
     PrereleaseMode, ResolutionMode,
 };
-use uv_static::EnvVars;
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::pyproject_mut::AddBoundsKind;
@@ -66,6 +71,107 @@ pub struct Options {
     #[option_group]
     pub pip: Option<PipOptions>,
 
+    #[option_group]
+    pub run: Option<RunOptions>,
+
+    #[option_group]
+    pub python_install_hook: Option<PythonInstallHookOptions>,
+
+    #[option_group]
+    pub python_discovery: Option<PythonDiscoveryOptions>,
+
+    /// Environment variables to set when invoking specific Python executables, keyed by the path
+    /// to the executable.
+    ///
+    /// Some interpreters, e.g., those built into a nonstandard prefix, fail to start without
+    /// additional environment variables, like `LD_LIBRARY_PATH`. The variables are applied when uv
+    /// queries the interpreter during discovery, and whenever uv invokes the interpreter
+    /// thereafter, including from virtual environments that were created from it.
+    ///
+    /// Relative paths are resolved relative to the directory containing the configuration file.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [python-query-env."/opt/custom/bin/python3"]
+            LD_LIBRARY_PATH = "/opt/custom/lib"
+        "#
+    )]
+    pub python_query_env: Option<BTreeMap<PathBuf, BTreeMap<String, String>>>,
+
+    #[option_group]
+    pub python_query_limits: Option<PythonQueryLimitsOptions>,
+
+    /// The maximum size of the project environment, e.g., `2GB` or `500MiB`.
+    ///
+    /// Before installing packages into the environment, uv projects the size of the environment
+    /// after the sync, and exits with an error (or warns, per `max-environment-size-action`) if
+    /// it would exceed the limit. Packages that are not yet in the cache are counted by their
+    /// download size, so the projection may underestimate the final size; packages whose download
+    /// size is unknown are excluded from the projection, with a warning.
+    ///
+    /// When set, the size of the environment is also reported after each `uv sync`, and after
+    /// `uv run` modifies the environment.
+    ///
+    /// Decimal units (`kB`, `MB`, `GB`, `TB`) are powers of 1000, and binary units (`KiB`, `MiB`,
+    /// `GiB`, `TiB`) are powers of 1024.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-environment-size = "2GB"
+        "#
+    )]
+    pub max_environment_size: Option<ByteSize>,
+
+    /// The behavior when a sync would exceed the `max-environment-size`.
+    ///
+    /// By default, uv will exit with an error before modifying the environment (`fail`). When set
+    /// to `warn`, uv will warn and continue with the sync.
+    #[option(
+        default = "\"fail\"",
+        value_type = "str",
+        example = r#"
+            max-environment-size-action = "warn"
+        "#,
+        possible_values = true
+    )]
+    pub max_environment_size_action: Option<MaxEnvironmentSizeAction>,
+
+    /// The shells for which to export an activation script for the project environment on each
+    /// sync.
+    ///
+    /// Each script exports `VIRTUAL_ENV` and `UV_PROJECT_ENVIRONMENT`, and prepends the
+    /// environment's scripts directory to `PATH`. `direnv` writes an `.envrc` file to the project
+    /// root; `sh`, `fish`, and `powershell` write `.uv/activate.sh`, `.uv/activate.fish`, and
+    /// `.uv/activate.ps1`, respectively.
+    ///
+    /// Files that exist but weren't generated by uv are left untouched.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            export-activate = ["direnv", "fish"]
+        "#
+    )]
+    pub export_activate: Option<Vec<ActivationShell>>,
+
+    /// Raise warnings about the environment as errors.
+    ///
+    /// By default, project commands (e.g., `uv run`, `uv sync`, and `uv lock`) and `uv tool`
+    /// commands fall back to a usable environment with a warning when (e.g.) `VIRTUAL_ENV`
+    /// doesn't match the project environment and is ignored, or an incompatible environment is
+    /// used due to `--no-sync`. When enabled, these fallbacks are errors instead, with a stable
+    /// code identifying each, e.g., for continuous integration.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            fail-fast-env = true
+        "#
+    )]
+    pub fail_fast_env: Option<bool>,
+
     /// The keys to consider when caching builds for the project.
     ///
     /// Cache keys enable you to specify the files or directories that should trigger a rebuild when
@@ -152,9 +258,18 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub managed: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub lock_interpreter: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub manage_ignore_files: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub r#package: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub python: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_backend: Option<serde::de::IgnoredAny>,
 }
@@ -174,6 +289,20 @@ impl Options {
         Ok(Self {
             top_level: self.top_level.relative_to(root_dir)?,
             pip: self.pip.map(|pip| pip.relative_to(root_dir)).transpose()?,
+            install_mirrors: self.install_mirrors.relative_to(root_dir),
+            python_query_env: self.python_query_env.map(|env| {
+                env.into_iter()
+                    .map(|(path, vars)| (root_dir.join(path), vars))
+                    .collect()
+            }),
+            ..self
+        })
+    }
+
+    /// Expand environment variables in the settings that support them.
+    pub fn expand_env_vars(self) -> Result<Self, UnsetEnvVarError> {
+        Ok(Self {
+            install_mirrors: self.install_mirrors.expand_env_vars()?,
             ..self
         })
     }
@@ -960,7 +1089,7 @@ pub struct ResolverInstallerSchema {
 }
 
 /// Shared settings, relevant to all operations that might create managed python installations.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PythonInstallMirrors {
@@ -971,6 +1100,8 @@ pub struct PythonInstallMirrors {
     /// The provided URL will replace `https://github.com/astral-sh/python-build-standalone/releases/download` in, e.g., `https://github.com/astral-sh/python-build-standalone/releases/download/20240713/cpython-3.12.4%2B20240713-aarch64-apple-darwin-install_only.tar.gz`.
     ///
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
+    ///
+    /// Environment variables, e.g., `${CI_CACHE_DIR}`, are expanded. Use `$${VAR}` to include `${VAR}` literally.
     #[option(
         default = "None",
         value_type = "str",
@@ -987,6 +1118,8 @@ pub struct PythonInstallMirrors {
     ///
     /// Distributions can be read from a
     /// local directory by using the `file://` URL scheme.
+    ///
+    /// Environment variables, e.g., `${CI_CACHE_DIR}`, are expanded. Use `$${VAR}` to include `${VAR}` literally.
     #[option(
         default = "None",
         value_type = "str",
@@ -999,6 +1132,8 @@ pub struct PythonInstallMirrors {
     /// URL pointing to JSON of custom Python installations.
     ///
     /// Note that currently, only local paths are supported.
+    ///
+    /// Environment variables, e.g., `${CI_CACHE_DIR}`, are expanded. Use `$${VAR}` to include `${VAR}` literally.
     #[option(
         default = "None",
         value_type = "str",
@@ -1007,30 +1142,144 @@ pub struct PythonInstallMirrors {
         "#
     )]
     pub python_downloads_json_url: Option<String>,
-}
 
-impl Default for PythonInstallMirrors {
-    fn default() -> Self {
-        Self::resolve(None, None, None)
-    }
+    /// The keyring provider to use when downloading managed Python installations.
+    ///
+    /// Python downloads are often served from different infrastructure than package indexes, so
+    /// this setting is independent of `keyring-provider`. For example, with `subprocess`, uv uses
+    /// the `keyring` CLI to look up credentials for a Python mirror.
+    ///
+    /// Credentials from a `.netrc` file or embedded in the mirror URL are used regardless.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            python-install-keyring-provider = "subprocess"
+        "#,
+        possible_values = true
+    )]
+    pub python_install_keyring_provider: Option<KeyringProviderType>,
+
+    /// Additional certificate authorities to trust when downloading managed Python installations,
+    /// as `<host>=<path>` entries.
+    ///
+    /// Each path must point to a PEM bundle, which is trusted in addition to the default roots for
+    /// requests to the given host when downloading Python, but not for requests to package
+    /// indexes. Relative paths are resolved against the directory containing the configuration
+    /// file.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            python-install-ca-certs = ["python.mirror.example.com=/etc/ssl/certs/corporate.pem"]
+        "#
+    )]
+    pub python_install_ca_certs: Option<Vec<HostCertificate>>,
+
+    /// The Python version to download when no installed interpreter satisfies a version range,
+    /// e.g., a project's `requires-python`.
+    ///
+    /// By default, uv downloads the latest compatible Python version (`highest`). With
+    /// `lowest-compatible`, uv downloads the lowest compatible minor version instead, i.e., the
+    /// version at the lower bound of the range, which is most likely the version a lockfile was
+    /// generated with.
+    ///
+    /// Installed interpreters are always preferred over downloads, regardless of this setting.
+    #[option(
+        default = "\"highest\"",
+        value_type = "str",
+        example = r#"
+            python-resolution = "lowest-compatible"
+        "#,
+        possible_values = true
+    )]
+    pub python_resolution: Option<PythonResolution>,
+
+    /// The directory in which to store managed Python installations for the project, e.g., to
+    /// vendor Python toolchains in the repository or to restore them from a CI cache.
+    ///
+    /// Relative paths are resolved relative to the directory containing the configuration file.
+    ///
+    /// When set, `uv python install` installs Python versions into this directory, rather than
+    /// the user-level directory, and Python discovery searches this directory before the
+    /// user-level directory. Use `--project` to target the directory of a project other than the
+    /// current one.
+    ///
+    /// `UV_PYTHON_INSTALL_DIR` and `--install-dir` take precedence over this setting. The setting
+    /// is only read from project-level configuration; it's ignored in user-level and system-level
+    /// `uv.toml` files.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            python-install-dir = ".uv/pythons"
+        "#
+    )]
+    pub python_install_dir: Option<PathBuf>,
+
+    /// The directory in which to extract managed Python distributions before they're moved into
+    /// the installation directory.
+    ///
+    /// By default, distributions are extracted into a directory within the installation directory,
+    /// such that they can be moved into place with an atomic rename. If the staging directory is
+    /// on a different filesystem than the installation directory, the extracted distribution is
+    /// copied into place and verified instead.
+    ///
+    /// Relative paths are resolved relative to the directory containing the configuration file.
+    ///
+    /// `UV_PYTHON_INSTALL_STAGING_DIR` takes precedence over this setting.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            install-staging-dir = "/scratch/uv-python"
+        "#
+    )]
+    pub install_staging_dir: Option<PathBuf>,
 }
 
 impl PythonInstallMirrors {
-    pub fn resolve(
-        python_mirror: Option<String>,
-        pypy_mirror: Option<String>,
-        python_downloads_json_url: Option<String>,
-    ) -> Self {
-        let python_mirror_env = std::env::var(EnvVars::UV_PYTHON_INSTALL_MIRROR).ok();
-        let pypy_mirror_env = std::env::var(EnvVars::UV_PYPY_INSTALL_MIRROR).ok();
-        let python_downloads_json_url_env =
-            std::env::var(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL).ok();
+    /// Expand environment variables in the mirror URLs, e.g., `${CI_MIRROR}/python`.
+    ///
+    /// Returns an error if a referenced variable is unset.
+    pub fn expand_env_vars(self) -> Result<Self, UnsetEnvVarError> {
+        let expand = |value: Option<String>| {
+            value
+                .map(|value| try_expand_env_vars(&value).map(Cow::into_owned))
+                .transpose()
+        };
+        Ok(Self {
+            python_install_mirror: expand(self.python_install_mirror)?,
+            pypy_install_mirror: expand(self.pypy_install_mirror)?,
+            python_downloads_json_url: expand(self.python_downloads_json_url)?,
+            ..self
+        })
+    }
+
+    /// Resolve the relative certificate paths and installation directories against the given root
+    /// directory.
+    #[must_use]
+    pub fn relative_to(self, root_dir: &Path) -> Self {
         Self {
-            python_install_mirror: python_mirror_env.or(python_mirror),
-            pypy_install_mirror: pypy_mirror_env.or(pypy_mirror),
-            python_downloads_json_url: python_downloads_json_url_env.or(python_downloads_json_url),
+            python_install_ca_certs: self.python_install_ca_certs.map(|certificates| {
+                certificates
+                    .into_iter()
+                    .map(|certificate| certificate.relative_to(root_dir))
+                    .collect()
+            }),
+            python_install_dir: self.python_install_dir.map(|dir| root_dir.join(dir)),
+            install_staging_dir: self.install_staging_dir.map(|dir| root_dir.join(dir)),
+            ..self
         }
     }
+
+    /// Return the client settings to use for downloads of managed Python installations.
+    pub fn download_client(&self) -> PythonDownloadClientSettings {
+        PythonDownloadClientSettings::new(
+            self.python_install_keyring_provider,
+            self.python_install_ca_certs.clone().unwrap_or_default(),
+        )
+    }
 }
 
 /// Settings that are specific to the `uv pip` command-line interface.
@@ -2108,6 +2357,9 @@ pub struct OptionsWire {
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
     python_downloads_json_url: Option<String>,
+    python_install_keyring_provider: Option<KeyringProviderType>,
+    python_install_ca_certs: Option<Vec<HostCertificate>>,
+    python_resolution: Option<PythonResolution>,
 
     // #[serde(flatten)]
     // publish: PublishOptions
@@ -2120,6 +2372,17 @@ pub struct OptionsWire {
     add_bounds: Option<AddBoundsKind>,
 
     pip: Option<PipOptions>,
+    run: Option<RunOptions>,
+    python_install_hook: Option<PythonInstallHookOptions>,
+    python_discovery: Option<PythonDiscoveryOptions>,
+    python_install_dir: Option<PathBuf>,
+    install_staging_dir: Option<PathBuf>,
+    python_query_env: Option<BTreeMap<PathBuf, BTreeMap<String, String>>>,
+    python_query_limits: Option<PythonQueryLimitsOptions>,
+    max_environment_size: Option<ByteSize>,
+    max_environment_size_action: Option<MaxEnvironmentSizeAction>,
+    export_activate: Option<Vec<ActivationShell>>,
+    fail_fast_env: Option<bool>,
     cache_keys: Option<Vec<CacheKey>>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
@@ -2138,7 +2401,10 @@ pub struct OptionsWire {
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
+    lock_interpreter: Option<serde::de::IgnoredAny>,
+    manage_ignore_files: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
+    python: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
     dependency_groups: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
@@ -2161,6 +2427,9 @@ impl From<OptionsWire> for Options {
             python_install_mirror,
             pypy_install_mirror,
             python_downloads_json_url,
+            python_install_keyring_provider,
+            python_install_ca_certs,
+            python_resolution,
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
@@ -2194,6 +2463,17 @@ impl From<OptionsWire> for Options {
             no_binary,
             no_binary_package,
             pip,
+            run,
+            python_install_hook,
+            python_discovery,
+            python_install_dir,
+            install_staging_dir,
+            python_query_env,
+            python_query_limits,
+            max_environment_size,
+            max_environment_size_action,
+            export_activate,
+            fail_fast_env,
             cache_keys,
             override_dependencies,
             constraint_dependencies,
@@ -2212,7 +2492,10 @@ impl From<OptionsWire> for Options {
             extra_build_variables,
             dev_dependencies,
             managed,
+            lock_interpreter,
+            manage_ignore_files,
             package,
+            python,
             add_bounds: bounds,
             // Used by the build backend
             build_backend,
@@ -2267,6 +2550,15 @@ impl From<OptionsWire> for Options {
                 no_binary_package,
             },
             pip,
+            run,
+            python_install_hook,
+            python_discovery,
+            python_query_env,
+            python_query_limits,
+            max_environment_size,
+            max_environment_size_action,
+            export_activate,
+            fail_fast_env,
             cache_keys,
             build_backend,
             override_dependencies,
@@ -2274,11 +2566,16 @@ impl From<OptionsWire> for Options {
             build_constraint_dependencies,
             environments,
             required_environments,
-            install_mirrors: PythonInstallMirrors::resolve(
+            install_mirrors: PythonInstallMirrors {
                 python_install_mirror,
                 pypy_install_mirror,
                 python_downloads_json_url,
-            ),
+                python_install_keyring_provider,
+                python_install_ca_certs,
+                python_resolution,
+                python_install_dir,
+                install_staging_dir,
+            },
             conflicts,
             publish: PublishOptions {
                 publish_url,
@@ -2292,11 +2589,256 @@ impl From<OptionsWire> for Options {
             default_groups,
             dependency_groups,
             managed,
+            lock_interpreter,
+            manage_ignore_files,
             package,
+            python,
         }
     }
 }
 
+/// Settings that are specific to the `uv run` command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RunOptions {
+    /// Additional requirements to include in every `uv run` invocation, as with `--with`.
+    ///
+    /// The requirements are installed into an ephemeral overlay environment that is layered on top
+    /// of the project environment, and are not added to the project's dependencies or lockfile.
+    /// Requirements provided via `--with` on the command line are included in addition to these
+    /// requirements.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            with = ["ipython", "rich"]
+        "#
+    )]
+    pub with: Option<Vec<String>>,
+
+    /// Whether commands run in an isolated environment, i.e., with `uv run --isolated`, may access
+    /// the network.
+    ///
+    /// With `off`, the command is run without network access. On Linux, the command is run in a
+    /// new network namespace, which has no network interfaces other than loopback. On other
+    /// platforms, the standard proxy environment variables are pointed at an unreachable address
+    /// instead, which is advisory rather than isolation: programs that ignore them can still
+    /// access the network.
+    ///
+    /// Network access is only restricted for the command itself; uv may still access the network
+    /// to prepare the environment. Has no effect without `--isolated`.
+    #[option(
+        default = "\"on\"",
+        value_type = "str",
+        example = r#"
+            network = "off"
+        "#,
+        possible_values = true
+    )]
+    pub network: Option<NetworkAccess>,
+
+    /// The sources of the `PATH` entries for commands run with `uv run`, in order of precedence.
+    ///
+    /// `overlay` includes the scripts directories of the environments layered atop the project
+    /// environment (e.g., via `--with`), `base` includes the scripts directory of the project
+    /// environment (or of the interpreter, outside a project), and `inherit` includes the entries of
+    /// the `PATH` in which uv was invoked. Sources that are omitted are excluded, such that
+    /// `["base"]` exposes only the project's scripts. An empty list clears `PATH` entirely, for
+    /// hermetic runs.
+    #[option(
+        default = "[\"overlay\", \"base\", \"inherit\"]",
+        value_type = "list[str]",
+        example = r#"
+            path-policy = ["base", "inherit"]
+        "#
+    )]
+    pub path_policy: Option<PathPolicy>,
+
+    /// Whether to record `uv run` invocations in the project's history, for use with
+    /// `uv run --last` and `uv run --history`.
+    ///
+    /// The history includes the full command line of each invocation, so it should be disabled
+    /// for projects in which commands are passed secrets as arguments.
+    #[option(
+        default = "true",
+        value_type = "bool",
+        example = r#"
+            history = false
+        "#
+    )]
+    pub history: Option<bool>,
+}
+
+/// Settings for a hook that is executed after a managed Python installation is installed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PythonInstallHookOptions {
+    /// A command to execute after a managed Python installation is installed or reinstalled by
+    /// `uv python install` or `uv python upgrade`, e.g., to add a certificate bundle to the
+    /// installation or to register it with an inventory system.
+    ///
+    /// The command is provided as a list of arguments, and is not executed in a shell. If the first
+    /// argument is a Python script (i.e., it ends in `.py`), it is executed with the newly
+    /// installed interpreter.
+    ///
+    /// The hook receives the path to the installation via `UV_PYTHON_INSTALL_PATH`, the
+    /// installation key (e.g., `cpython-3.12.11-macos-aarch64-none`) via `UV_PYTHON_INSTALL_KEY`,
+    /// and the path to the installation's Python executable via `UV_PYTHON_INSTALL_EXECUTABLE`.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            command = ["/opt/acme/register-python.sh"]
+        "#
+    )]
+    pub command: Option<Vec<String>>,
+
+    /// The maximum time, in seconds, to wait for the hook to complete before it is terminated and
+    /// considered failed.
+    #[option(
+        default = "300",
+        value_type = "int",
+        example = r#"
+            timeout = 60
+        "#
+    )]
+    pub timeout: Option<u64>,
+
+    /// The behavior when the hook fails, i.e., when it exits with a non-zero status or exceeds
+    /// the timeout.
+    ///
+    /// By default, uv will warn and keep the installation (`warn`). When set to `fail`, uv will
+    /// exit with an error, leaving the installation in place for inspection.
+    #[option(
+        default = "\"warn\"",
+        value_type = "str",
+        example = r#"
+            on-failure = "fail"
+        "#
+    )]
+    pub on_failure: Option<PythonInstallHookFailure>,
+}
+
+/// Settings for the discovery of Python interpreters on the system.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PythonDiscoveryOptions {
+    /// Glob patterns for Python executables that should never be discovered, e.g., broken or
+    /// slow interpreters on the `PATH`.
+    ///
+    /// Patterns are matched against the path of each executable found on the `PATH` (and, on
+    /// Windows, in the registry) before it is queried. A leading `~` is expanded to the user's
+    /// home directory, `*` matches within a single path component, and `**` matches across
+    /// directories.
+    ///
+    /// Managed Python installations, virtual environments, and interpreters requested by path
+    /// (e.g., `--python /usr/bin/python3`) are not affected.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            exclude = ["/usr/bin/python3", "~/.pyenv/shims/*"]
+        "#
+    )]
+    pub exclude: Option<Vec<String>>,
+
+    /// Glob patterns for the only Python executables that may be discovered.
+    ///
+    /// If set, executables found on the `PATH` (and, on Windows, in the registry) that do not
+    /// match any of the patterns are skipped before they are queried. Patterns follow the same
+    /// syntax as `exclude`, which takes precedence.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            include-only = ["/usr/local/bin/*", "~/.local/bin/*"]
+        "#
+    )]
+    pub include_only: Option<Vec<String>>,
+}
+
+/// Limits on the subprocesses that uv runs to query Python interpreters, e.g., during discovery.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PythonQueryLimitsOptions {
+    /// The time (in seconds) after which an interpreter query is terminated, and the interpreter
+    /// is skipped during discovery, e.g., to skip interpreters on unresponsive network mounts.
+    ///
+    /// By default, or when set to `0`, queries are not subject to a timeout.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            timeout = 10
+        "#
+    )]
+    pub timeout: Option<u64>,
+
+    /// The maximum virtual memory (in MiB) of an interpreter query.
+    ///
+    /// Only supported on Unix.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            memory-limit = 512
+        "#
+    )]
+    pub memory_limit: Option<u64>,
+
+    /// The maximum CPU time (in seconds) of an interpreter query.
+    ///
+    /// Only supported on Unix.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            cpu-limit = 10
+        "#
+    )]
+    pub cpu_limit: Option<u64>,
+
+    /// The maximum number of interpreters to query concurrently.
+    ///
+    /// Defaults to the number of available CPUs.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrency = 4
+        "#
+    )]
+    pub concurrency: Option<NonZeroUsize>,
+}
+
+/// The behavior when a sync would exceed the maximum size of the environment.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum MaxEnvironmentSizeAction {
+    /// Exit with an error before modifying the environment.
+    #[default]
+    Fail,
+    /// Warn and continue.
+    Warn,
+}
+
+/// The behavior when a Python installation hook fails.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonInstallHookFailure {
+    /// Warn and continue.
+    #[default]
+    Warn,
+    /// Exit with an error.
+    Fail,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -280,6 +280,30 @@ impl Shell {
             )),
         }
     }
+
+    /// Returns the command necessary to prepend a directory to a search path variable, like
+    /// `CPATH`, in this shell.
+    pub fn prepend_search_path(self, name: &str, path: &Path) -> Option<String> {
+        match self {
+            Self::Nushell | Self::Csh => None,
+            Self::Bash | Self::Zsh | Self::Ksh => Some(format!(
+                "export {name}=\"{}${{{name}:+:${name}}}\"",
+                backslash_escape(&path.simplified_display().to_string()),
+            )),
+            Self::Fish => Some(format!(
+                "set -gx --prepend {name} \"{}\"",
+                backslash_escape(&path.simplified_display().to_string()),
+            )),
+            Self::Powershell => Some(format!(
+                "$env:{name} = \"{};$env:{name}\"",
+                backtick_escape(&path.simplified_display().to_string()),
+            )),
+            Self::Cmd => Some(format!(
+                "set {name}=\"{};%{name}%\"",
+                backslash_escape(&path.simplified_display().to_string()),
+            )),
+        }
+    }
 }
 
 impl std::fmt::Display for Shell {
@@ -147,6 +147,41 @@ impl EnvVars {
     /// Whether uv should prefer system or managed Python versions.
     pub const UV_PYTHON_PREFERENCE: &'static str = "UV_PYTHON_PREFERENCE";
 
+    /// Path to a JSON file mapping Python executable paths to mocked interpreter metadata, in the
+    /// format emitted by uv's interpreter query script. Interpreters with an entry are not run; intended
+    /// for simulating interpreters and platforms in tests and CI.
+    pub const UV_MOCK_INTERPRETER_JSON: &'static str = "UV_MOCK_INTERPRETER_JSON";
+
+    /// Record the output of failed Python interpreter queries in the cache, for review with
+    /// `uv python find --show-failures`.
+    pub const UV_PYTHON_RECORD_QUERY_FAILURES: &'static str = "UV_PYTHON_RECORD_QUERY_FAILURES";
+
+    /// Timeout (in seconds) for querying a Python interpreter, after which the interpreter is
+    /// skipped during discovery. Set to `0` to disable the timeout. Takes precedence over
+    /// `python-query-limits.timeout`. (default: no timeout)
+    pub const UV_PYTHON_QUERY_TIMEOUT: &'static str = "UV_PYTHON_QUERY_TIMEOUT";
+
+    /// The maximum virtual memory (in MiB) of a Python interpreter query. Only supported on Unix.
+    /// Takes precedence over `python-query-limits.memory-limit`.
+    pub const UV_PYTHON_QUERY_MEMORY_LIMIT: &'static str = "UV_PYTHON_QUERY_MEMORY_LIMIT";
+
+    /// The maximum CPU time (in seconds) of a Python interpreter query. Only supported on Unix.
+    /// Takes precedence over `python-query-limits.cpu-limit`.
+    pub const UV_PYTHON_QUERY_CPU_LIMIT: &'static str = "UV_PYTHON_QUERY_CPU_LIMIT";
+
+    /// The maximum number of Python interpreters to query concurrently. Takes precedence over
+    /// `python-query-limits.concurrency`. (default: the number of available CPUs)
+    pub const UV_PYTHON_QUERY_CONCURRENCY: &'static str = "UV_PYTHON_QUERY_CONCURRENCY";
+
+    /// The number of consecutive failed queries after which a Python interpreter is skipped during
+    /// discovery, until `UV_PYTHON_QUARANTINE_COOLDOWN` has elapsed. Timeouts aren't counted as
+    /// failures. (default: disabled)
+    pub const UV_PYTHON_QUARANTINE_THRESHOLD: &'static str = "UV_PYTHON_QUARANTINE_THRESHOLD";
+
+    /// The time (in seconds) for which a Python interpreter is skipped during discovery after
+    /// repeated failed queries, before it's queried again. (default: 3600 s)
+    pub const UV_PYTHON_QUARANTINE_COOLDOWN: &'static str = "UV_PYTHON_QUARANTINE_COOLDOWN";
+
     /// Require use of uv-managed Python versions.
     pub const UV_MANAGED_PYTHON: &'static str = "UV_MANAGED_PYTHON";
 
@@ -158,6 +193,10 @@ impl EnvVars {
     /// `--no-python-downloads` option. Whether uv should allow Python downloads.
     pub const UV_PYTHON_DOWNLOADS: &'static str = "UV_PYTHON_DOWNLOADS";
 
+    /// Equivalent to the `--download-rate-limit` command-line argument. Limits the combined
+    /// bandwidth of the downloads in `uv python install`, in bytes per second, e.g., `10M`.
+    pub const UV_PYTHON_DOWNLOAD_RATE_LIMIT: &'static str = "UV_PYTHON_DOWNLOAD_RATE_LIMIT";
+
     /// Overrides the environment-determined libc on linux systems when filling in the current platform
     /// within Python version requests. Options are: `gnu`, `gnueabi`, `gnueabihf`, `musl`, and `none`.
     pub const UV_LIBC: &'static str = "UV_LIBC";
@@ -226,6 +265,19 @@ impl EnvVars {
     /// the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
+    /// Equivalent to the `--no-history` command-line argument. If set, uv will not record
+    /// `uv run` invocations in the project's history.
+    pub const UV_NO_RUN_HISTORY: &'static str = "UV_NO_RUN_HISTORY";
+
+    /// Equivalent to the `--fail-fast-env` command-line argument. If set, uv will raise warnings
+    /// about the environment of `uv run`, `uv sync`, `uv lock`, and `uv tool install` as errors.
+    /// Takes precedence over the `fail-fast-env` setting.
+    pub const UV_FAIL_FAST_ENV: &'static str = "UV_FAIL_FAST_ENV";
+
+    /// Equivalent to the `--exit-code-map` command-line argument. If set to `reserved`, uv will
+    /// exit with a code of `200` or greater if `uv run` or `uv sync` fails.
+    pub const UV_EXIT_CODE_MAP: &'static str = "UV_EXIT_CODE_MAP";
+
     /// Equivalent to the `--locked` command-line argument. If set, uv will assert that the
     /// `uv.lock` remains unchanged.
     pub const UV_LOCKED: &'static str = "UV_LOCKED";
@@ -299,9 +351,33 @@ impl EnvVars {
     /// Whether to install the Python executable into the `UV_PYTHON_BIN_DIR` directory.
     pub const UV_PYTHON_INSTALL_BIN: &'static str = "UV_PYTHON_INSTALL_BIN";
 
+    /// The release channel to install and upgrade managed Python versions from, i.e., `alpha`,
+    /// `beta`, `rc`, or `stable`.
+    pub const UV_PYTHON_CHANNEL: &'static str = "UV_PYTHON_CHANNEL";
+
     /// Whether to install the Python executable into the Windows registry.
     pub const UV_PYTHON_INSTALL_REGISTRY: &'static str = "UV_PYTHON_INSTALL_REGISTRY";
 
+    /// Specifies the directory in which managed Python distributions are extracted before they're
+    /// moved into the Python installation directory.
+    ///
+    /// Defaults to a directory within the Python installation directory. If the staging directory
+    /// is on a different filesystem, the extracted distribution is copied and verified before it is
+    /// moved into place.
+    pub const UV_PYTHON_INSTALL_STAGING_DIR: &'static str = "UV_PYTHON_INSTALL_STAGING_DIR";
+
+    /// Set by uv for the `python-install-hook` command to the path of the managed Python
+    /// installation that was installed.
+    pub const UV_PYTHON_INSTALL_PATH: &'static str = "UV_PYTHON_INSTALL_PATH";
+
+    /// Set by uv for the `python-install-hook` command to the key of the managed Python
+    /// installation that was installed, e.g., `cpython-3.12.11-macos-aarch64-none`.
+    pub const UV_PYTHON_INSTALL_KEY: &'static str = "UV_PYTHON_INSTALL_KEY";
+
+    /// Set by uv for the `python-install-hook` command to the path of the Python executable of the
+    /// managed Python installation that was installed.
+    pub const UV_PYTHON_INSTALL_EXECUTABLE: &'static str = "UV_PYTHON_INSTALL_EXECUTABLE";
+
     /// Managed Python installations information is hardcoded in the `uv` binary.
     ///
     /// This variable can be set to a URL pointing to JSON to use as a list for Python installations.
@@ -332,10 +408,32 @@ impl EnvVars {
     /// Distributions can be read from a local directory by using the `file://` URL scheme.
     pub const UV_PYPY_INSTALL_MIRROR: &'static str = "UV_PYPY_INSTALL_MIRROR";
 
+    /// Additional certificate authorities to trust when downloading managed Python installations,
+    /// as a space-separated list of `<host>=<path>` entries, e.g.,
+    /// `mirror.example.com=/etc/ssl/certs/corporate.pem`.
+    ///
+    /// Each path must point to a PEM bundle, which is trusted in addition to the default roots for
+    /// requests to the given host, but not for requests to package indexes. The entries are
+    /// combined with those of the `python-install-ca-certs` setting.
+    pub const UV_PYTHON_INSTALL_CA_CERTS: &'static str = "UV_PYTHON_INSTALL_CA_CERTS";
+
+    /// Equivalent to the `--python-install-keyring-provider` command-line argument. The keyring
+    /// provider to use when downloading managed Python installations, e.g., `subprocess`.
+    ///
+    /// Python downloads use the keyring provider independently of `UV_KEYRING_PROVIDER`, which
+    /// applies to package indexes. Credentials from a `.netrc` file or embedded in the mirror URL
+    /// are used regardless.
+    pub const UV_PYTHON_INSTALL_KEYRING_PROVIDER: &'static str =
+        "UV_PYTHON_INSTALL_KEYRING_PROVIDER";
+
     /// Equivalent to the `--clear` command-line argument. If set, uv will remove any
     /// existing files or directories at the target path.
     pub const UV_VENV_CLEAR: &'static str = "UV_VENV_CLEAR";
 
+    /// Refuse to use virtual environments whose path traverses a symbolic link, directory
+    /// junction, or other reparse point, e.g., via `VIRTUAL_ENV` or a `.venv` directory.
+    pub const UV_VENV_REFUSE_LINKS: &'static str = "UV_VENV_REFUSE_LINKS";
+
     /// Install seed packages (one or more of: `pip`, `setuptools`, and `wheel`) into the virtual environment
     /// created by `uv venv`.
     ///
@@ -471,6 +569,24 @@ impl EnvVars {
     /// Used to detect an activated virtual environment.
     pub const VIRTUAL_ENV: &'static str = "VIRTUAL_ENV";
 
+    /// The root of a pyenv installation, passed to pyenv when resolving the interpreter that a
+    /// pyenv shim runs.
+    pub const PYENV_ROOT: &'static str = "PYENV_ROOT";
+
+    /// The Python version selected by pyenv, used to invalidate cached resolutions of pyenv shims.
+    pub const PYENV_VERSION: &'static str = "PYENV_VERSION";
+
+    /// The data directory of an asdf installation, used to invalidate cached resolutions of asdf
+    /// shims.
+    pub const ASDF_DATA_DIR: &'static str = "ASDF_DATA_DIR";
+
+    /// The name of asdf's version files, used to invalidate cached resolutions of asdf shims.
+    pub const ASDF_DEFAULT_TOOL_VERSIONS_FILENAME: &'static str =
+        "ASDF_DEFAULT_TOOL_VERSIONS_FILENAME";
+
+    /// The Python version selected by asdf, used to invalidate cached resolutions of asdf shims.
+    pub const ASDF_PYTHON_VERSION: &'static str = "ASDF_PYTHON_VERSION";
+
     /// Used to detect an activated Conda environment.
     pub const CONDA_PREFIX: &'static str = "CONDA_PREFIX";
 
@@ -610,6 +726,11 @@ impl EnvVars {
     /// Adds directories to Python module search path (e.g., `PYTHONPATH=/path/to/modules`).
     pub const PYTHONPATH: &'static str = "PYTHONPATH";
 
+    /// Excludes the user site packages directory from the module search path, equivalent to `-s`
+    /// in Python.
+    #[attr_hidden]
+    pub const PYTHONNOUSERSITE: &'static str = "PYTHONNOUSERSITE";
+
     /// Used in tests to enforce a consistent locale setting.
     #[attr_hidden]
     pub const LC_ALL: &'static str = "LC_ALL";
@@ -627,6 +748,9 @@ impl EnvVars {
     /// Used to detect when running inside a Jupyter notebook.
     pub const JPY_SESSION_NAME: &'static str = "JPY_SESSION_NAME";
 
+    /// Overrides the directory in which `uv run --kernel` registers Jupyter kernel specs.
+    pub const JUPYTER_DATA_DIR: &'static str = "JUPYTER_DATA_DIR";
+
     /// Use to create the tracing root directory via the `tracing-durations-export` feature.
     #[attr_hidden]
     pub const TRACING_DURATIONS_TEST_ROOT: &'static str = "TRACING_DURATIONS_TEST_ROOT";
@@ -795,6 +919,11 @@ impl EnvVars {
     /// Enables fetching files stored in Git LFS when installing a package from a Git repository.
     pub const UV_GIT_LFS: &'static str = "UV_GIT_LFS";
 
+    /// How to key the cached environments of PEP 723 scripts, either `path` (the default) to key
+    /// by the path of the script, or `content` to key by a hash of its inline metadata, such that
+    /// moved, renamed, or identical scripts share an environment.
+    pub const UV_SCRIPT_ENVIRONMENT_KEY: &'static str = "UV_SCRIPT_ENVIRONMENT_KEY";
+
     /// Number of times that `uv run` has been recursively invoked. Used to guard against infinite
     /// recursion, e.g., when `uv run`` is used in a script shebang.
     #[attr_hidden]
@@ -4,6 +4,7 @@ use std::path::Path;
 use thiserror::Error;
 
 use uv_configuration::Preview;
+use uv_fs::CWD;
 use uv_python::{Interpreter, PythonEnvironment};
 
 pub use virtualenv::{OnExisting, remove_virtualenv};
@@ -43,6 +44,17 @@ impl Prompt {
             None => Self::None,
         }
     }
+
+    /// Resolve the prompt to the value written to the environment, if any.
+    pub(crate) fn into_value(self) -> Option<String> {
+        match self {
+            Self::CurrentDirectoryName => CWD
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string()),
+            Self::Static(value) => Some(value),
+            Self::None => None,
+        }
+    }
 }
 
 /// Create a virtualenv.
@@ -60,6 +72,7 @@ pub fn create_venv(
 ) -> Result<PythonEnvironment, Error> {
     // Create the virtualenv at the given location.
     let virtualenv = virtualenv::create(
+        location,
         location,
         &interpreter,
         prompt,
@@ -75,3 +88,84 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Update the prompt of an existing virtualenv in place.
+///
+/// Rewrites the `pyvenv.cfg` file and the activation scripts, such that a renamed project doesn't
+/// retain a stale prompt.
+pub fn set_prompt(environment: &PythonEnvironment, prompt: Prompt) -> Result<(), Error> {
+    virtualenv::set_prompt(environment, prompt.into_value().as_deref())
+}
+
+/// Expose the user site packages directory in an existing virtualenv.
+///
+/// Virtual environments exclude the user site packages directory, as in Python's `site` module.
+/// This records the `uv-user-site` key in the `pyvenv.cfg` file and adds a `.pth` file that
+/// appends the user site packages to `sys.path`, with the lowest precedence, unless the user site
+/// is disabled at runtime (e.g., with `-s` or `PYTHONNOUSERSITE`).
+pub fn enable_user_site(environment: &PythonEnvironment) -> Result<(), Error> {
+    virtualenv::enable_user_site(environment)
+}
+
+/// Create a virtualenv, replacing any existing environment at the given location.
+///
+/// The new environment is built in a sibling staging directory and then moved into place, with
+/// the existing environment retained until the move succeeds. As a result, a crash at any point
+/// leaves either the previous or the new environment behind, rather than neither.
+#[allow(clippy::fn_params_excessive_bools)]
+pub fn replace_venv(
+    location: &Path,
+    interpreter: Interpreter,
+    prompt: Prompt,
+    system_site_packages: bool,
+    relocatable: bool,
+    seed: bool,
+    upgradeable: bool,
+    preview: Preview,
+) -> Result<PythonEnvironment, Error> {
+    // If the environment is symlinked, replace the target rather than the link itself.
+    let location = if location.is_symlink() {
+        fs_err::canonicalize(location)?
+    } else {
+        std::path::absolute(location)?
+    };
+    let staging = virtualenv::sibling(&location, "staging");
+
+    // Build the environment aside, discarding any staging directory left behind by a crash.
+    let virtualenv = virtualenv::create(
+        &staging,
+        &location,
+        &interpreter,
+        prompt,
+        system_site_packages,
+        OnExisting::Remove,
+        relocatable,
+        seed,
+        upgradeable,
+        preview,
+    )?;
+
+    if let Err(err) = virtualenv::swap_virtualenv(&staging, &location) {
+        if let Err(err) = remove_virtualenv(&staging) {
+            tracing::debug!(
+                "Failed to remove staged virtual environment at `{}`: {err}",
+                staging.display()
+            );
+        }
+        return Err(err);
+    }
+
+    // Create the corresponding `PythonEnvironment`.
+    let interpreter = interpreter.with_virtualenv(virtualenv);
+    Ok(PythonEnvironment::from_interpreter(interpreter))
+}
+
+/// Adopt a virtualenv created by another tool, e.g., `virtualenv`, `venv`, or Poetry.
+///
+/// The environment must already be at its final location. If it was moved there from `previous`,
+/// any entry points that refer to the previous location are re-linked. uv's activation scripts
+/// are written with the environment's existing prompt, and the `pyvenv.cfg` file is updated to
+/// match an environment created by uv.
+pub fn adopt_venv(environment: &PythonEnvironment, previous: Option<&Path>) -> Result<(), Error> {
+    virtualenv::adopt(environment, previous)
+}
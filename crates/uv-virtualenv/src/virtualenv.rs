@@ -3,7 +3,7 @@
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use console::Term;
 use fs_err as fs;
@@ -13,10 +13,10 @@ use owo_colors::OwoColorize;
 use tracing::{debug, trace};
 
 use uv_configuration::Preview;
-use uv_fs::{CWD, Simplified, cachedir};
+use uv_fs::{Simplified, cachedir};
 use uv_pypi_types::Scheme;
 use uv_python::managed::{PythonMinorVersionLink, create_link_to_executable};
-use uv_python::{Interpreter, VirtualEnvironment};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonEnvironment, VirtualEnvironment};
 use uv_shell::escape_posix_for_single_quotes;
 use uv_version::version;
 use uv_warnings::warn_user_once;
@@ -49,9 +49,14 @@ fn write_cfg(f: &mut impl Write, data: &[(String, String)]) -> io::Result<()> {
 }
 
 /// Create a [`VirtualEnvironment`] at the given location.
+///
+/// The environment is written to `location`, but any absolute paths embedded in the environment
+/// (e.g., in activation scripts) refer to `target`, which allows an environment to be built in a
+/// staging directory and moved into place afterwards.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(crate) fn create(
     location: &Path,
+    target: &Path,
     interpreter: &Interpreter,
     prompt: Prompt,
     system_site_packages: bool,
@@ -61,6 +66,11 @@ pub(crate) fn create(
     upgradeable: bool,
     preview: Preview,
 ) -> Result<VirtualEnvironment, Error> {
+    // Write the environment via an extended-length path on Windows, such that it can be created
+    // in a deeply nested directory. Any paths embedded in the environment refer to `target`.
+    let location = uv_fs::long_path(location);
+    let location = location.as_ref();
+
     // Determine the base Python executable; that is, the Python executable that should be
     // considered the "base" for the virtual environment.
     //
@@ -173,22 +183,10 @@ pub(crate) fn create(
     }
 
     let location = std::path::absolute(location)?;
+    let target = std::path::absolute(target)?;
 
-    let bin_name = if cfg!(unix) {
-        "bin"
-    } else if cfg!(windows) {
-        "Scripts"
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
     let scripts = location.join(&interpreter.virtualenv().scripts);
-    let prompt = match prompt {
-        Prompt::CurrentDirectoryName => CWD
-            .file_name()
-            .map(|name| name.to_string_lossy().to_string()),
-        Prompt::Static(value) => Some(value),
-        Prompt::None => None,
-    };
+    let prompt = prompt.into_value();
 
     // Add the CACHEDIR.TAG.
     cachedir::ensure_tag(&location)?;
@@ -429,47 +427,14 @@ pub(crate) fn create(
     }
 
     // Add all the activate scripts for different shells
-    for (name, template) in ACTIVATE_TEMPLATES {
-        let path_sep = if cfg!(windows) { ";" } else { ":" };
-
-        let relative_site_packages = [
-            interpreter.virtualenv().purelib.as_path(),
-            interpreter.virtualenv().platlib.as_path(),
-        ]
-        .iter()
-        .dedup()
-        .map(|path| {
-            pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
-                .expect("Failed to calculate relative path to site-packages")
-        })
-        .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
-        .join(path_sep);
-
-        let virtual_env_dir = match (relocatable, name.to_owned()) {
-            (true, "activate") => {
-                r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#.to_string()
-            }
-            (true, "activate.bat") => r"%~dp0..".to_string(),
-            (true, "activate.fish") => {
-                r#"'"$(dirname -- "$(cd "$(dirname -- "$(status -f)")"; and pwd)")"'"#.to_string()
-            }
-            // Note:
-            // * relocatable activate scripts appear not to be possible in csh and nu shell
-            // * `activate.ps1` is already relocatable by default.
-            _ => escape_posix_for_single_quotes(location.simplified().to_str().unwrap()),
-        };
-
-        let activator = template
-            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
-            .replace("{{ BIN_NAME }}", bin_name)
-            .replace(
-                "{{ VIRTUAL_PROMPT }}",
-                prompt.as_deref().unwrap_or_default(),
-            )
-            .replace("{{ PATH_SEP }}", path_sep)
-            .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
-        fs::write(scripts.join(name), activator)?;
-    }
+    write_activation_scripts(
+        &scripts,
+        &target,
+        interpreter,
+        relocatable,
+        prompt.as_deref(),
+        |_| true,
+    )?;
 
     let mut pyvenv_cfg_data: Vec<(String, String)> = vec![
         (
@@ -510,6 +475,12 @@ pub(crate) fn create(
         pyvenv_cfg_data.push(("prompt".to_string(), prompt));
     }
 
+    // Record the selected slice of a macOS universal binary, such that the environment keeps
+    // running as that slice.
+    if let Some(arch) = interpreter.arch_slice() {
+        pyvenv_cfg_data.push(("uv-arch".to_string(), arch.to_string()));
+    }
+
     if cfg!(windows) && interpreter.markers().implementation_name() == "graalpy" {
         pyvenv_cfg_data.push((
             "venvlauncher_command".to_string(),
@@ -548,20 +519,250 @@ pub(crate) fn create(
     fs::write(site_packages.join("_virtualenv.py"), VIRTUALENV_PATCH)?;
     fs::write(site_packages.join("_virtualenv.pth"), "import _virtualenv")?;
 
+    // If the environment is being staged, report the paths at which it will ultimately live.
+    let executable = target.join(
+        executable
+            .strip_prefix(&location)
+            .expect("Executable is within the virtual environment"),
+    );
+
     Ok(VirtualEnvironment {
         scheme: Scheme {
-            purelib: location.join(&interpreter.virtualenv().purelib),
-            platlib: location.join(&interpreter.virtualenv().platlib),
-            scripts: location.join(&interpreter.virtualenv().scripts),
-            data: location.join(&interpreter.virtualenv().data),
-            include: location.join(&interpreter.virtualenv().include),
+            purelib: target.join(&interpreter.virtualenv().purelib),
+            platlib: target.join(&interpreter.virtualenv().platlib),
+            scripts: target.join(&interpreter.virtualenv().scripts),
+            data: target.join(&interpreter.virtualenv().data),
+            include: target.join(&interpreter.virtualenv().include),
         },
-        root: location,
+        root: target,
         executable,
         base_executable: base_python,
     })
 }
 
+/// Write the activation scripts for the environment into the `scripts` directory.
+///
+/// Any absolute paths in the scripts refer to `target`. Only the scripts for which `filter`
+/// returns `true` are written.
+fn write_activation_scripts(
+    scripts: &Path,
+    target: &Path,
+    interpreter: &Interpreter,
+    relocatable: bool,
+    prompt: Option<&str>,
+    filter: impl Fn(&str) -> bool,
+) -> io::Result<()> {
+    let bin_name = if cfg!(unix) {
+        "bin"
+    } else if cfg!(windows) {
+        "Scripts"
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    };
+
+    for (name, template) in ACTIVATE_TEMPLATES {
+        if !filter(name) {
+            continue;
+        }
+
+        let path_sep = if cfg!(windows) { ";" } else { ":" };
+
+        let relative_site_packages = [
+            interpreter.virtualenv().purelib.as_path(),
+            interpreter.virtualenv().platlib.as_path(),
+        ]
+        .iter()
+        .dedup()
+        .map(|path| {
+            pathdiff::diff_paths(path, &interpreter.virtualenv().scripts)
+                .expect("Failed to calculate relative path to site-packages")
+        })
+        .map(|path| path.simplified().to_str().unwrap().replace('\\', "\\\\"))
+        .join(path_sep);
+
+        let virtual_env_dir = match (relocatable, name.to_owned()) {
+            (true, "activate") => {
+                r#"'"$(dirname -- "$(dirname -- "$(realpath -- "$SCRIPT_PATH")")")"'"#.to_string()
+            }
+            (true, "activate.bat") => r"%~dp0..".to_string(),
+            (true, "activate.fish") => {
+                r#"'"$(dirname -- "$(cd "$(dirname -- "$(status -f)")"; and pwd)")"'"#.to_string()
+            }
+            // Note:
+            // * relocatable activate scripts appear not to be possible in csh and nu shell
+            // * `activate.ps1` is already relocatable by default.
+            _ => escape_posix_for_single_quotes(target.simplified().to_str().unwrap()),
+        };
+
+        let activator = template
+            .replace("{{ VIRTUAL_ENV_DIR }}", &virtual_env_dir)
+            .replace("{{ BIN_NAME }}", bin_name)
+            .replace("{{ VIRTUAL_PROMPT }}", prompt.unwrap_or_default())
+            .replace("{{ PATH_SEP }}", path_sep)
+            .replace("{{ RELATIVE_SITE_PACKAGES }}", &relative_site_packages);
+        fs::write(scripts.join(name), activator)?;
+    }
+
+    Ok(())
+}
+
+/// Expose the user site packages directory in the environment, with the lowest precedence.
+///
+/// The `.pth` file sorts after any other path files written by uv (e.g., the ephemeral overlay).
+pub(crate) fn enable_user_site(environment: &PythonEnvironment) -> Result<(), Error> {
+    let site_packages = environment
+        .site_packages()
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No site packages directory"))?;
+    fs::write(
+        site_packages.join("_uv_user_site.pth"),
+        "import site, sys; sys.flags.no_user_site or site.addsitedir(site.getusersitepackages())",
+    )?;
+
+    let cfg = environment.root().join("pyvenv.cfg");
+    let content = fs::read_to_string(&cfg)?;
+    fs::write(
+        &cfg,
+        PyVenvConfiguration::set(&content, "uv-user-site", "true"),
+    )?;
+    Ok(())
+}
+
+/// Update the prompt of an existing virtual environment in place.
+///
+/// Rewrites the `prompt` key in the `pyvenv.cfg` file, along with any of uv's activation scripts
+/// that are present in the environment. If `prompt` is `None`, the `prompt` key is removed, such
+/// that the activation scripts fall back to the name of the environment directory.
+pub(crate) fn set_prompt(
+    environment: &PythonEnvironment,
+    prompt: Option<&str>,
+) -> Result<(), Error> {
+    let cfg = environment.root().join("pyvenv.cfg");
+    let content = fs::read_to_string(&cfg)?;
+    let content = if let Some(prompt) = prompt {
+        PyVenvConfiguration::set(&content, "prompt", prompt)
+    } else {
+        content
+            .lines()
+            .filter(|line| {
+                line.split_once('=')
+                    .is_none_or(|(key, _)| key.trim() != "prompt")
+            })
+            .map(|line| format!("{line}\n"))
+            .collect()
+    };
+    fs::write(&cfg, content)?;
+
+    // Only rewrite the activation scripts that exist, to avoid adding scripts to environments
+    // created by other tools.
+    let scripts = environment.scripts();
+    write_activation_scripts(
+        scripts,
+        environment.root(),
+        environment.interpreter(),
+        environment.relocatable(),
+        prompt,
+        |name| scripts.join(name).is_file(),
+    )?;
+
+    Ok(())
+}
+
+/// Adopt an existing virtual environment created by another tool.
+///
+/// See [`crate::adopt_venv`].
+pub(crate) fn adopt(environment: &PythonEnvironment, previous: Option<&Path>) -> Result<(), Error> {
+    let root = environment.root();
+    let scripts = environment.scripts();
+
+    // Re-link any entry points that refer to the interpreter at the previous location.
+    if let Some(previous) = previous {
+        relink_entry_points(scripts, previous, root)?;
+    }
+
+    // Other tools may quote the prompt (e.g., `venv` writes `prompt = 'example'`), while uv's
+    // activation scripts expect the bare value.
+    let cfg = root.join("pyvenv.cfg");
+    let mut content = fs::read_to_string(&cfg)?;
+    let prompt = PyVenvConfiguration::parse(&cfg)
+        .ok()
+        .and_then(|cfg| cfg.prompt().map(unquote).map(ToString::to_string));
+    if let Some(prompt) = &prompt {
+        content = PyVenvConfiguration::set(&content, "prompt", prompt);
+    }
+    content = PyVenvConfiguration::set(&content, "uv", &version().to_string());
+    fs::write(&cfg, content)?;
+
+    write_activation_scripts(
+        scripts,
+        root,
+        environment.interpreter(),
+        environment.relocatable(),
+        prompt.as_deref(),
+        |_| true,
+    )?;
+
+    Ok(())
+}
+
+/// Rewrite the shebangs of the entry points in the `scripts` directory that refer to an
+/// interpreter within `previous`, such that they refer to the same interpreter within `root`.
+///
+/// Files that aren't text scripts, e.g., Windows launchers, are left unchanged.
+fn relink_entry_points(scripts: &Path, previous: &Path, root: &Path) -> io::Result<()> {
+    let Some(previous) = previous.to_str() else {
+        return Ok(());
+    };
+    let Some(root) = root.to_str() else {
+        return Ok(());
+    };
+
+    for entry in fs::read_dir(scripts)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let path = entry.path();
+        let Ok(content) = fs::read_to_string(&path) else {
+            trace!("Skipping non-text entry point: {}", path.user_display());
+            continue;
+        };
+        if !content.starts_with("#!") {
+            continue;
+        }
+
+        // The interpreter is named on the first line or, for long paths, on the second line of a
+        // `/bin/sh` trampoline, i.e., `'''exec' /path/to/python "$0" "$@"`.
+        let mut lines = content.splitn(3, '\n');
+        let shebang = lines.next().unwrap_or_default();
+        let header_len = match lines.next() {
+            Some(line) if line.starts_with("'''exec'") => shebang.len() + 1 + line.len(),
+            _ => shebang.len(),
+        };
+        let (header, body) = content.split_at(header_len);
+        if !header.contains(previous) {
+            continue;
+        }
+
+        debug!("Re-linking entry point: {}", path.user_display());
+        fs::write(&path, format!("{}{body}", header.replace(previous, root)))?;
+    }
+
+    Ok(())
+}
+
+/// Strip matching single or double quotes from a `pyvenv.cfg` value.
+fn unquote(value: &str) -> &str {
+    ['\'', '"']
+        .into_iter()
+        .find_map(|quote| {
+            value
+                .strip_prefix(quote)
+                .and_then(|value| value.strip_suffix(quote))
+        })
+        .unwrap_or(value)
+}
+
 /// Prompt a confirmation that the virtual environment should be cleared.
 ///
 /// If not a TTY, returns `None`.
@@ -623,6 +824,76 @@ pub fn remove_virtualenv(location: &Path) -> Result<(), Error> {
     Ok(())
 }
 
+/// Move a staged virtual environment into place, replacing the environment at `location`.
+///
+/// The existing environment is first renamed aside and only removed once the staged environment
+/// has been moved into place, such that an interruption never leaves both environments missing.
+pub(crate) fn swap_virtualenv(staging: &Path, location: &Path) -> Result<(), Error> {
+    let backup = sibling(location, "old");
+
+    // Clear out any backup left behind by an interrupted swap.
+    if backup.exists() {
+        remove_virtualenv(&backup)?;
+    }
+
+    let rename = |from: &Path, to: &Path| {
+        uv_fs::with_retry_sync(from, to, "rename", || fs_err::rename(from, to))
+    };
+
+    // Move the existing environment aside.
+    let backed_up = match rename(location, &backup) {
+        Ok(()) => true,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => false,
+        // On Windows, an environment that is in use (e.g., by a running interpreter) can't be
+        // renamed, though its contents can be removed. Fall back to removing it in place.
+        Err(err) if cfg!(windows) && err.kind() == io::ErrorKind::PermissionDenied => {
+            debug!(
+                "Failed to move virtual environment aside ({err}); removing in place: {}",
+                location.display()
+            );
+            remove_virtualenv(location)?;
+            false
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Move the staged environment into place, restoring the existing environment on failure.
+    if let Err(err) = rename(staging, location) {
+        if backed_up {
+            if let Err(err) = rename(&backup, location) {
+                warn_user_once!(
+                    "Failed to restore virtual environment at `{}`: {err}",
+                    location.user_display()
+                );
+            }
+        }
+        return Err(err.into());
+    }
+
+    // Remove the previous environment; failing to do so is not fatal.
+    if backed_up {
+        if let Err(err) = remove_virtualenv(&backup) {
+            debug!(
+                "Failed to remove previous virtual environment at `{}`: {err}",
+                backup.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Return a hidden sibling of the virtual environment at `location`, e.g., `.venv.staging` for
+/// `.venv`.
+pub(crate) fn sibling(location: &Path, suffix: &str) -> PathBuf {
+    let name = location
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "venv".to_string());
+    let name = name.strip_prefix('.').unwrap_or(&name);
+    location.with_file_name(format!(".{name}.{suffix}"))
+}
+
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
 pub enum OnExisting {
     /// Fail if the directory already exists and is non-empty.
@@ -379,6 +379,46 @@ pub struct ToolUv {
     )]
     pub managed: Option<bool>,
 
+    /// Whether to record the Python interpreter used to lock the project in the lockfile.
+    ///
+    /// When enabled, uv records the implementation, minor version, and variant of the interpreter
+    /// used to create the lockfile, and warns when the project is used with an interpreter that
+    /// differs, as the same lockfile can produce different environments on different interpreters.
+    /// Use `--strict-python` to raise the warning as an error instead.
+    ///
+    /// The range of Python versions requested by the project is recorded alongside, i.e., the
+    /// `.python-version` file in the workspace root, narrowed to the project's `requires-python`,
+    /// and uv also warns when the project is used with an interpreter outside of the range.
+    ///
+    /// The recorded interpreter is retained when the lockfile is updated, unless all packages are
+    /// upgraded, e.g., with `uv lock --upgrade`.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            lock-interpreter = true
+        "#
+    )]
+    pub lock_interpreter: Option<bool>,
+
+    /// Whether to add the project environment to the ignore files in the workspace root.
+    ///
+    /// When enabled, uv adds an entry for the project environment, e.g., `/.venv`, to the
+    /// `.gitignore` and `.dockerignore` files in the workspace root, if they exist. Entries for
+    /// environments that no longer exist, e.g., after `UV_PROJECT_ENVIRONMENT` changes, are
+    /// removed. Only the entries added by uv are modified.
+    ///
+    /// Regardless of this setting, uv creates a `.gitignore` file within the environment itself,
+    /// which excludes it from Git, but not from, e.g., Docker build contexts.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            manage-ignore-files = true
+        "#
+    )]
+    pub manage_ignore_files: Option<bool>,
+
     /// Whether the project should be considered a Python package, or a non-package ("virtual")
     /// project.
     ///
@@ -398,6 +438,23 @@ pub struct ToolUv {
     )]
     pub package: Option<bool>,
 
+    /// The Python version to use for this workspace member, overriding the workspace's Python
+    /// version.
+    ///
+    /// Respected when running or syncing the member with `--package`, in which case it takes
+    /// precedence over any `.python-version` file, but not over an explicit `--python` request.
+    /// The version must be compatible with the member's `requires-python`, along with that of the
+    /// workspace. An existing workspace environment that doesn't satisfy the version is not
+    /// replaced, as it's shared by all members of the workspace.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            python = "3.10"
+        "#
+    )]
+    pub python: Option<String>,
+
     /// The list of `dependency-groups` to install by default.
     ///
     /// Can also be the literal `"all"` to default enable all groups.
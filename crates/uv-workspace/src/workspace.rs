@@ -478,6 +478,26 @@ impl Workspace {
             .and_then(|uv| uv.environments.as_ref())
     }
 
+    /// Returns `true` if the interpreter used to lock the workspace should be recorded.
+    pub fn lock_interpreter(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.lock_interpreter)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the project environment should be added to the workspace's ignore files.
+    pub fn manage_ignore_files(&self) -> bool {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.manage_ignore_files)
+            .unwrap_or(false)
+    }
+
     /// Returns the set of required platforms for the workspace.
     pub fn required_environments(&self) -> Option<&SupportedEnvironments> {
         self.pyproject_toml
@@ -649,24 +669,6 @@ impl Workspace {
             Some(workspace.install_path.join(path))
         }
 
-        /// Resolve the `VIRTUAL_ENV` variable, if any.
-        fn from_virtual_env_variable() -> Option<PathBuf> {
-            let value = std::env::var_os(EnvVars::VIRTUAL_ENV)?;
-
-            if value.is_empty() {
-                return None;
-            }
-
-            let path = PathBuf::from(value);
-            if path.is_absolute() {
-                return Some(path);
-            }
-
-            // Resolve the path relative to current directory.
-            // Note this differs from `UV_PROJECT_ENVIRONMENT`
-            Some(CWD.join(path))
-        }
-
         // Determine the default value
         let project_env = from_project_environment_variable(self)
             .unwrap_or_else(|| self.install_path.join(".venv"));
@@ -705,6 +707,15 @@ impl Workspace {
         project_env
     }
 
+    /// Return the active virtual environment, if `VIRTUAL_ENV` is set but does not match the
+    /// project environment, such that [`Workspace::venv`] ignores it unless `active` is `true`.
+    pub fn mismatched_virtual_env(&self) -> Option<PathBuf> {
+        let from_virtual_env = from_virtual_env_variable()?;
+        let project_env = self.venv(Some(false));
+        (!uv_fs::is_same_file_allow_missing(&from_virtual_env, &project_env).unwrap_or(false))
+            .then_some(from_virtual_env)
+    }
+
     /// The members of the workspace.
     pub fn packages(&self) -> &BTreeMap<PackageName, WorkspaceMember> {
         &self.packages
@@ -1464,6 +1475,24 @@ async fn find_workspace(
     Ok(None)
 }
 
+/// Resolve the `VIRTUAL_ENV` variable, if any.
+fn from_virtual_env_variable() -> Option<PathBuf> {
+    let value = std::env::var_os(EnvVars::VIRTUAL_ENV)?;
+
+    if value.is_empty() {
+        return None;
+    }
+
+    let path = PathBuf::from(value);
+    if path.is_absolute() {
+        return Some(path);
+    }
+
+    // Resolve the path relative to current directory.
+    // Note this differs from `UV_PROJECT_ENVIRONMENT`
+    Some(CWD.join(path))
+}
+
 /// Check if we're in the `tool.uv.workspace.excluded` of a workspace.
 fn is_excluded_from_workspace(
     project_path: &Path,
@@ -1968,6 +1997,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
@@ -2070,6 +2100,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
@@ -2285,6 +2316,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
@@ -2396,6 +2428,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
@@ -2520,6 +2553,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
@@ -2618,6 +2652,7 @@ mod tests {
                       },
                       "managed": null,
                       "package": null,
+                      "python": null,
                       "default-groups": null,
                       "dependency-groups": null,
                       "extra-build-dependencies": null,
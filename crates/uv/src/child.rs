@@ -1,14 +1,62 @@
-use tokio::process::Child;
+use std::time::Duration;
+
+use tokio::process::{Child, Command};
+use tokio::time::Instant;
 use tracing::debug;
 
+use uv_cli::SignalForwarding;
+
 use crate::commands::ExitStatus;
 
+/// How to handle signals received while waiting for a child process.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct SignalOptions {
+    /// Where to forward signals received by uv.
+    pub(crate) forwarding: SignalForwarding,
+    /// How long to wait for the child to exit after an interrupt or termination signal before
+    /// killing it.
+    pub(crate) shutdown_timeout: Option<Duration>,
+}
+
+impl SignalOptions {
+    /// Prepare the [`Command`] for the requested signal handling.
+    ///
+    /// Must be called before the child process is spawned.
+    pub(crate) fn configure(&self, command: &mut Command) {
+        #[cfg(unix)]
+        {
+            if self.forwarding == SignalForwarding::Group {
+                command.process_group(0);
+            }
+        }
+        #[cfg(not(unix))]
+        let _ = (self, command);
+    }
+
+    /// The instant at which the child should be killed, if a shutdown was requested now.
+    fn shutdown_deadline(&self) -> Option<Instant> {
+        self.shutdown_timeout
+            .map(|shutdown_timeout| Instant::now() + shutdown_timeout)
+    }
+}
+
+/// Wait until the shutdown deadline has passed, or forever if there is no deadline.
+async fn wait_for_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
 /// Wait for the child process to complete, handling signals and error codes.
 ///
 /// Note that this registers handles to ignore some signals in the parent process. This is safe as
 /// long as the command is the last thing that runs in this process; otherwise, we'd need to restore
 /// the default signal handlers after the command completes.
-pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitStatus> {
+pub(crate) async fn run_to_completion(
+    mut handle: Child,
+    options: SignalOptions,
+) -> anyhow::Result<ExitStatus> {
     // On Unix, the terminal driver will send SIGINT to the active process group when a user presses
     // `Ctrl-C`. In general, this means that uv should ignore SIGINT, allowing the child process to
     // cleanly exit instead. If uv forwarded the SIGINT immediately, the child process would receive
@@ -35,6 +83,12 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
     // a SIGTERM and does not have a SIGTERM handler, it is terminated. However, if PID 1 receives a
     // SIGTERM, it is not terminated. In this context, it is essential for uv to forward the SIGTERM
     // to the child process or the process will not be killable.
+    //
+    // With `SignalForwarding::Group`, the child is spawned in its own process group, so it never
+    // receives signals from the terminal driver and uv forwards every signal to the whole group.
+    // This ensures that grandchildren are signalled too, e.g., when uv runs a shell script under a
+    // supervisor that only signals uv. If a shutdown timeout is set, the group is sent SIGKILL if it
+    // has not exited by the time the timeout elapses after the first SIGINT or SIGTERM.
     #[cfg(unix)]
     let status = {
         use std::io::{IsTerminal, stdin};
@@ -92,6 +146,22 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
             debug!("Spawned child {child_pid} in process group {parent_pgid}");
         }
 
+        // Forward a signal to the child, or to its process group if requested. If the child is
+        // still in our process group, only signal the child, as signalling the group would signal
+        // uv too.
+        let forward = |child_pid: Pid, signal: signal::Signal| {
+            if options.forwarding == SignalForwarding::Group {
+                let child_pgid = getpgid(Some(child_pid))?;
+                if child_pgid != parent_pgid {
+                    return signal::killpg(child_pgid, signal);
+                }
+            }
+            signal::kill(child_pid, signal)
+        };
+
+        // The instant at which the child will be killed, once a shutdown has been requested.
+        let mut deadline = None;
+
         let mut sigterm_handle = handle_signal(SignalKind::terminate())?;
         let mut sigint_handle = handle_signal(SignalKind::interrupt())?;
 
@@ -141,6 +211,16 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
                 result = handle.wait() => {
                     break result;
                 },
+                () = wait_for_deadline(deadline) => {
+                    deadline = None;
+
+                    let Some(child_pid) = *ChildPid::from(&handle) else {
+                        continue;
+                    };
+
+                    debug!("Child at {child_pid} did not exit within the shutdown timeout, sending SIGKILL");
+                    let _ = forward(child_pid, signal::Signal::SIGKILL);
+                },
                 _ = sigint_handle.recv() => {
                     // See above for commentary on handling of SIGINT.
 
@@ -150,6 +230,8 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
                         continue;
                     };
 
+                    deadline = deadline.or_else(|| options.shutdown_deadline());
+
                     // Check if the child pgid has changed
                     let child_pgid = getpgid(Some(child_pid)).context("Failed to get PID of child process")?;
 
@@ -165,7 +247,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
                     // to handle that signal before hitting it with another one
                     debug!("Received SIGINT, forwarding to child at {child_pid} in 200ms");
                     tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-                    let _ = signal::kill(child_pid, signal::Signal::SIGINT);
+                    let _ = forward(child_pid, signal::Signal::SIGINT);
                 },
                 _ = sigterm_handle.recv() => {
                     // If the child has already exited, we can't send it signals
@@ -174,10 +256,12 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
                         continue;
                     };
 
+                    deadline = deadline.or_else(|| options.shutdown_deadline());
+
                     // We unconditionally forward SIGTERM to the child process; unlike SIGINT, this
                     // isn't usually handled by the terminal.
                     debug!("Received SIGTERM, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGTERM);
+                    let _ = forward(child_pid, signal::Signal::SIGTERM);
                 }
                 _ = sigusr1_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -187,7 +271,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGUSR1 to the child process.
                     debug!("Received SIGUSR1, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGUSR1);
+                    let _ = forward(child_pid, signal::Signal::SIGUSR1);
                 }
                 _ = sigusr2_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -197,7 +281,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGUSR2 to the child process.
                     debug!("Received SIGUSR2, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGUSR2);
+                    let _ = forward(child_pid, signal::Signal::SIGUSR2);
                 }
                 _ = sighup_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -207,7 +291,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGHUP to the child process.
                     debug!("Received SIGHUP, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGHUP);
+                    let _ = forward(child_pid, signal::Signal::SIGHUP);
                 }
                 _ = sigalrm_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -217,7 +301,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGALRM to the child process.
                     debug!("Received SIGALRM, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGALRM);
+                    let _ = forward(child_pid, signal::Signal::SIGALRM);
                 }
                 _ = sigquit_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -227,7 +311,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGQUIT to the child process.
                     debug!("Received SIGQUIT, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGQUIT);
+                    let _ = forward(child_pid, signal::Signal::SIGQUIT);
                 }
                 _ = sigwinch_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -237,7 +321,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGWINCH to the child process.
                     debug!("Received SIGWINCH, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGWINCH);
+                    let _ = forward(child_pid, signal::Signal::SIGWINCH);
                 }
                 _ = sigpipe_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -247,7 +331,7 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
 
                     // We unconditionally forward SIGPIPE to the child process.
                     debug!("Received SIGPIPE, forwarding to child at {child_pid}");
-                    let _ = signal::kill(child_pid, signal::Signal::SIGPIPE);
+                    let _ = forward(child_pid, signal::Signal::SIGPIPE);
                 }
                 _ = siginfo_handle.recv() => {
                     let Some(child_pid) = *ChildPid::from(&handle) else {
@@ -265,20 +349,47 @@ pub(crate) async fn run_to_completion(mut handle: Child) -> anyhow::Result<ExitS
                         target_os = "openbsd",
                         target_os = "illumos",
                     ))]
-                    let _ = signal::kill(child_pid, signal::Signal::SIGINFO);
+                    let _ = forward(child_pid, signal::Signal::SIGINFO);
                 }
             };
         }
     }?;
 
-    // On Windows, we just ignore the console CTRL_C_EVENT and assume it will always be sent to the
-    // child by the console. There's not a clear programmatic way to forward the signal anyway.
-    #[cfg(not(unix))]
+    // On Windows, we just ignore the console CTRL_C_EVENT and CTRL_BREAK_EVENT and assume they will
+    // always be sent to the child by the console. There's not a clear programmatic way to forward
+    // the signals anyway. If a shutdown timeout is set, the child is terminated if it has not
+    // exited by the time the timeout elapses after the first event.
+    #[cfg(windows)]
     let status = {
-        let _ctrl_c_handler =
-            tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
-        handle.wait().await?
-    };
+        use tokio::select;
+        use tokio::signal::windows::{ctrl_break, ctrl_c};
+
+        let mut ctrl_c_handle = ctrl_c()?;
+        let mut ctrl_break_handle = ctrl_break()?;
+
+        let mut deadline = None;
+
+        loop {
+            select! {
+                result = handle.wait() => {
+                    break result;
+                },
+                () = wait_for_deadline(deadline) => {
+                    deadline = None;
+                    debug!("Child did not exit within the shutdown timeout, terminating");
+                    let _ = handle.start_kill();
+                },
+                _ = ctrl_c_handle.recv() => {
+                    debug!("Received CTRL_C_EVENT, assuming the child received it from the console");
+                    deadline = deadline.or_else(|| options.shutdown_deadline());
+                },
+                _ = ctrl_break_handle.recv() => {
+                    debug!("Received CTRL_BREAK_EVENT, assuming the child received it from the console");
+                    deadline = deadline.or_else(|| options.shutdown_deadline());
+                },
+            }
+        }
+    }?;
 
     // Exit based on the result of the command.
     if let Some(code) = status.code() {
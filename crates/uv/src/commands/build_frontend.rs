@@ -47,7 +47,7 @@ use crate::commands::ExitStatus;
 use crate::commands::pip::operations;
 use crate::commands::project::{ProjectError, find_requires_python};
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::printer::Printer;
+use crate::printer::{Printer, Verbosity};
 use crate::settings::{NetworkSettings, ResolverSettings};
 
 #[derive(Debug, Error)]
@@ -361,7 +361,7 @@ async fn build_impl(
             wheel,
             list,
             dependency_metadata,
-            *link_mode,
+            link_mode.unwrap_or_default(),
             config_setting,
             config_settings_package,
             preview,
@@ -521,6 +521,10 @@ async fn build_package(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await?
@@ -648,15 +652,15 @@ async fn build_package(
     let subdirectory = None;
     let version_id = source.path().file_name().and_then(|name| name.to_str());
 
-    let build_output = match printer {
-        Printer::Default | Printer::NoProgress | Printer::Verbose => {
+    let build_output = match printer.verbosity() {
+        Verbosity::Default | Verbosity::NoProgress | Verbosity::Verbose => {
             if build_logs {
                 BuildOutput::Stderr
             } else {
                 BuildOutput::Quiet
             }
         }
-        Printer::Quiet | Printer::Silent => BuildOutput::Quiet,
+        Verbosity::Quiet | Verbosity::Silent => BuildOutput::Quiet,
     };
 
     let mut build_results = Vec::new();
@@ -6,11 +6,17 @@ use owo_colors::OwoColorize;
 use uv_cache::{Cache, Removal};
 use uv_fs::Simplified;
 
+use crate::commands::project::environment::{prune_ephemeral, prune_kept};
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+pub(crate) fn cache_prune(
+    ci: bool,
+    ephemeral: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -28,15 +34,26 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
 
     let mut summary = Removal::default();
 
-    // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(cache)
+    // Remove any ephemeral environments leaked by killed processes.
+    summary += prune_ephemeral(cache)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
-    // Prune the remaining cache buckets.
-    summary += cache
-        .prune(ci)
+    // Remove any ephemeral environments kept for inspection, e.g., by `uv run --keep-env-on-failure`.
+    summary += prune_kept(cache)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
+    if !ephemeral {
+        // Prune the source distribution cache, which is tightly coupled to the builder crate.
+        summary += uv_distribution::prune(cache).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+
+        // Prune the remaining cache buckets.
+        summary += cache.prune(ci).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
+
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -0,0 +1,193 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cli::{ConfigKey, GlobalArgs};
+use uv_fs::Simplified;
+use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::{
+    InstallMirrorLayers, InterpreterLayers, Layered, PythonLayers, SettingSource,
+};
+
+/// A setting's layers, rendered for display.
+struct Report {
+    /// The effective value of the setting.
+    value: Option<String>,
+    /// Each layer that provides a value, along with whether the value is used.
+    layers: Vec<(SettingSource, Option<String>, bool)>,
+}
+
+impl Report {
+    /// Render the layers of a setting, as recorded by the settings resolution.
+    fn new<T>(layered: &Layered<T>, display: impl Fn(&T) -> String) -> Self
+    where
+        T: Clone,
+        Option<T>: Combine,
+    {
+        let mut layers = layered
+            .layers()
+            .filter(|(source, value, _)| value.is_some() || **source == SettingSource::Default)
+            .map(|(source, value, used)| (source.clone(), value.map(&display), used))
+            .collect::<Vec<_>>();
+
+        // Settings without a default value are unset unless a layer provides one.
+        if !layers
+            .iter()
+            .any(|(source, ..)| *source == SettingSource::Default)
+        {
+            let used = !layers.iter().any(|(.., used)| *used);
+            layers.push((SettingSource::Default, None, used));
+        }
+
+        Self {
+            value: layered.value().as_ref().map(display),
+            layers,
+        }
+    }
+}
+
+/// Show where the value of a setting comes from.
+pub(crate) fn config_where(
+    key: ConfigKey,
+    args: &GlobalArgs,
+    environment: &EnvironmentOptions,
+    filesystem: Option<&FilesystemOptions>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Resolve the layers exactly as they're resolved when uv starts, such that the reported value
+    // is the one that's used.
+    let report = match key {
+        ConfigKey::PythonPreference => Report::new(
+            &PythonLayers::resolve(args, filesystem).python_preference,
+            value_name,
+        ),
+        ConfigKey::PythonDownloads => Report::new(
+            &PythonLayers::resolve(args, filesystem).python_downloads,
+            value_name,
+        ),
+        ConfigKey::PythonInstallMirror => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).python_install_mirror,
+            String::clone,
+        ),
+        ConfigKey::PypyInstallMirror => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).pypy_install_mirror,
+            String::clone,
+        ),
+        ConfigKey::PythonDownloadsJsonUrl => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).python_downloads_json_url,
+            String::clone,
+        ),
+        ConfigKey::PythonInstallKeyringProvider => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).python_install_keyring_provider,
+            value_name,
+        ),
+        ConfigKey::PythonInstallCaCerts => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).python_install_ca_certs,
+            |certificates| display_list(certificates),
+        ),
+        ConfigKey::PythonInstallDir => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).python_install_dir,
+            |path| path.user_display().to_string(),
+        ),
+        ConfigKey::InstallStagingDir => Report::new(
+            &InstallMirrorLayers::resolve(filesystem).install_staging_dir,
+            |path| path.user_display().to_string(),
+        ),
+        ConfigKey::PythonDiscoveryExclude => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_discovery_exclude,
+            |values| display_list(values),
+        ),
+        ConfigKey::PythonDiscoveryIncludeOnly => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_discovery_include_only,
+            |values| display_list(values),
+        ),
+        ConfigKey::PythonQueryEnv => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_query_env,
+            display_query_env,
+        ),
+        ConfigKey::PythonQueryTimeout => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_query_timeout,
+            ToString::to_string,
+        ),
+        ConfigKey::PythonQueryMemoryLimit => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_query_memory_limit,
+            ToString::to_string,
+        ),
+        ConfigKey::PythonQueryCpuLimit => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_query_cpu_limit,
+            ToString::to_string,
+        ),
+        ConfigKey::PythonQueryConcurrency => Report::new(
+            &InterpreterLayers::resolve(environment, filesystem).python_query_concurrency,
+            ToString::to_string,
+        ),
+    };
+
+    writeln!(
+        printer.stdout(),
+        "{} = {}",
+        value_name(&key).bold(),
+        display_value(report.value.as_deref()).cyan()
+    )?;
+    writeln!(printer.stdout())?;
+    writeln!(printer.stdout(), "Sources, in order of precedence:")?;
+    for (source, value, used) in &report.layers {
+        if *used {
+            writeln!(
+                printer.stdout(),
+                "  {source}: {} {}",
+                display_value(value.as_deref()).cyan(),
+                "(used)".green()
+            )?;
+        } else {
+            writeln!(
+                printer.stdout(),
+                "  {source}: {}",
+                display_value(value.as_deref()).dimmed()
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the name of a value as it's written in configuration files, e.g., `only-managed`.
+fn value_name(value: &impl ValueEnum) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn display_list(values: &[impl std::fmt::Display]) -> String {
+    format!(
+        "[{}]",
+        values.iter().map(|value| format!("\"{value}\"")).join(", ")
+    )
+}
+
+fn display_query_env(env: &BTreeMap<PathBuf, BTreeMap<String, String>>) -> String {
+    env.iter()
+        .map(|(path, vars)| {
+            format!(
+                "`{}` ({})",
+                path.user_display(),
+                vars.iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .join(", ")
+            )
+        })
+        .join(", ")
+}
+
+fn display_value(value: Option<&str>) -> &str {
+    value.unwrap_or("(none)")
+}
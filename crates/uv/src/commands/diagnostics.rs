@@ -1,18 +1,26 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock};
 
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 use version_ranges::Ranges;
 
 use uv_distribution_types::{
     DerivationChain, DerivationStep, Dist, DistErrorKind, Name, RequestedDist,
 };
-use uv_normalize::PackageName;
+use uv_fs::Simplified;
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_resolver::SentinelRange;
+use uv_workspace::Workspace;
 
 use crate::commands::pip;
+use crate::commands::project::ProjectError;
+use crate::printer::Printer;
 
 static SUGGESTIONS: LazyLock<FxHashMap<PackageName, PackageName>> = LazyLock::new(|| {
     let suggestions: Vec<(String, String)> =
@@ -71,7 +79,15 @@ impl OperationDiagnostic {
     /// Attempt to report an error with rich diagnostic context.
     ///
     /// Returns `Some` if the error was not handled.
-    pub(crate) fn report(self, err: pip::operations::Error) -> Option<pip::operations::Error> {
+    pub(crate) fn report(
+        self,
+        err: pip::operations::Error,
+        printer: Printer,
+    ) -> Option<pip::operations::Error> {
+        if printer.json_diagnostics() {
+            report_json(&err, &WorkspaceFiles::default());
+            return None;
+        }
         match err {
             pip::operations::Error::Resolve(uv_resolver::ResolveError::NoSolution(err)) => {
                 if let Some(context) = self.context {
@@ -324,6 +340,155 @@ pub(crate) fn no_solution_hint(err: Box<uv_resolver::NoSolutionError>, help: Str
     anstream::eprint!("{report:?}");
 }
 
+/// The files that declare a workspace and its members, used to locate the declarations reported
+/// with `--diagnostics json`.
+///
+/// Without a workspace, declarations are attributed to the `pyproject.toml` at the root.
+#[derive(Debug, Default)]
+pub(crate) struct WorkspaceFiles {
+    /// The root of the workspace.
+    root: Option<PathBuf>,
+    /// The `pyproject.toml` of each workspace member.
+    members: BTreeMap<PackageName, PathBuf>,
+    /// The `pyproject.toml` of the member containing the project directory, if any.
+    current: Option<PathBuf>,
+}
+
+impl WorkspaceFiles {
+    /// Collect the files of the workspace in which uv was invoked, such that diagnostics can point
+    /// at the file that made each declaration, rather than assuming the `pyproject.toml` at the
+    /// workspace root.
+    pub(crate) fn from_workspace(workspace: &Workspace, project_dir: &Path) -> Self {
+        let current = workspace
+            .packages()
+            .values()
+            .filter(|member| project_dir.starts_with(member.root()))
+            .max_by_key(|member| member.root().components().count())
+            .map(|member| member.root().join("pyproject.toml"));
+        let members = workspace
+            .packages()
+            .iter()
+            .map(|(name, member)| (name.clone(), member.root().join("pyproject.toml")))
+            .collect();
+        Self {
+            root: Some(workspace.install_path().clone()),
+            members,
+            current,
+        }
+    }
+
+    /// Display a path relative to the workspace root, if it's within the workspace.
+    pub(crate) fn relative(&self, path: &Path) -> String {
+        match self
+            .root
+            .as_deref()
+            .and_then(|root| path.strip_prefix(root).ok())
+        {
+            Some(relative) => relative.portable_display().to_string(),
+            None => path.user_display().to_string(),
+        }
+    }
+
+    /// Return the `pyproject.toml` that declares the given workspace member.
+    pub(crate) fn member_file(&self, package: &PackageName) -> String {
+        self.members
+            .get(package)
+            .map(|path| self.relative(path))
+            .unwrap_or_else(|| "pyproject.toml".to_string())
+    }
+
+    /// Return the `pyproject.toml` of the project in which uv was invoked, falling back to the
+    /// workspace root.
+    pub(crate) fn project_file(&self) -> String {
+        self.current
+            .as_deref()
+            .map(|path| self.relative(path))
+            .unwrap_or_else(|| "pyproject.toml".to_string())
+    }
+}
+
+/// The declaration that caused a diagnostic, e.g., the workspace member and dependency group that
+/// declared an incompatible `requires-python`.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct DiagnosticLocation {
+    /// The file containing the declaration, relative to the workspace root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) file: Option<String>,
+    /// The package that made the declaration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) package: Option<PackageName>,
+    /// The dependency group that made the declaration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) group: Option<GroupName>,
+    /// The extra that made the declaration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) extra: Option<ExtraName>,
+}
+
+impl DiagnosticLocation {
+    /// Create a [`DiagnosticLocation`] pointing at the given file.
+    pub(crate) fn file(file: impl Into<String>) -> Self {
+        Self {
+            file: Some(file.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A machine-readable error report, emitted with `--diagnostics json`.
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic {
+    /// The severity of the diagnostic; always `error`.
+    severity: &'static str,
+    /// A stable code identifying the kind of error, e.g., `lock-mismatch`.
+    code: &'static str,
+    /// The human-readable error message.
+    message: String,
+    /// The human-readable messages of the underlying causes, outermost first.
+    causes: Vec<String>,
+    /// The declarations that caused the error, if known.
+    locations: Vec<DiagnosticLocation>,
+}
+
+/// Report an error as a single line of JSON on stderr.
+///
+/// The diagnostic code and locations are derived from the outermost [`ProjectError`] in the
+/// chain that maps to a known code; otherwise, the generic `error` code is used.
+pub(crate) fn report_json(err: &(dyn std::error::Error + 'static), files: &WorkspaceFiles) {
+    let chain = std::iter::successors(Some(err), |err| err.source());
+
+    let project = chain.clone().find_map(|err| {
+        err.downcast_ref::<ProjectError>()
+            .filter(|err| err.code().is_some())
+    });
+
+    let mut messages =
+        chain.map(|err| anstream::adapter::strip_str(err.to_string().trim()).to_string());
+    let diagnostic = JsonDiagnostic {
+        severity: "error",
+        code: project.and_then(ProjectError::code).unwrap_or("error"),
+        message: messages.next().unwrap_or_default(),
+        causes: messages.collect(),
+        locations: project.map(|err| err.locations(files)).unwrap_or_default(),
+    };
+
+    match serde_json::to_string(&diagnostic) {
+        Ok(json) => anstream::eprintln!("{json}"),
+        Err(_) => anstream::eprintln!("{}", diagnostic.message),
+    }
+}
+
+/// Report a [`ProjectError`] that is handled by the command itself rather than propagated, e.g.,
+/// a lockfile mismatch under `--locked`.
+pub(crate) fn project_error(err: &ProjectError, printer: Printer) -> std::fmt::Result {
+    if printer.json_diagnostics() {
+        report_json(err, &WorkspaceFiles::default());
+        Ok(())
+    } else {
+        writeln!(printer.stderr(), "{}", err.to_string().bold())
+    }
+}
+
 /// Render a [`uv_resolver::NoSolutionError`] with a help message.
 pub(crate) fn native_tls_hint(err: uv_client::Error) {
     #[derive(Debug, miette::Diagnostic)]
@@ -0,0 +1,124 @@
+//! The exit codes that identify uv's own failures, with `--exit-code-map reserved`.
+//!
+//! By default, uv exits with `1` or `2` if it fails, which a caller can't distinguish from a
+//! command run with `uv run` that exits with the same code. With the reserved scheme, uv exits with
+//! a code of `200` or greater instead, identifying the kind of failure.
+
+use std::error::Error;
+
+use uv_cli::{Cli, Commands, ExitCodeMap, ProjectCommand};
+
+use crate::commands::pip;
+use crate::commands::project::ProjectError;
+
+/// The exit code for a failure in uv that doesn't match a more specific [`SetupFailure`].
+pub(crate) const RESERVED_FAILURE: u8 = 200;
+
+/// Return the exit code scheme requested for the command, i.e., with `--exit-code-map` on
+/// `uv run` or `uv sync`.
+///
+/// The scheme is read from the parsed command line, rather than the resolved settings, such that
+/// it also applies to failures that occur before the settings are resolved.
+pub(crate) fn exit_code_map(cli: &Cli) -> ExitCodeMap {
+    let map = match &*cli.command {
+        Commands::Project(command) => match &**command {
+            ProjectCommand::Run(args) => args.exit_code_map,
+            ProjectCommand::Sync(args) => args.exit_code_map,
+            _ => None,
+        },
+        _ => None,
+    };
+    map.unwrap_or_default()
+}
+
+/// The kind of failure in uv that prevented a command from running, or an environment from being
+/// synced.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum SetupFailure {
+    /// The requirements could not be resolved.
+    Resolution,
+    /// No suitable Python interpreter was found.
+    Interpreter,
+    /// The lockfile is missing or out of date, e.g., under `--locked`.
+    Lockfile,
+    /// The environment could not be prepared, e.g., as a distribution failed to build.
+    Environment,
+}
+
+impl SetupFailure {
+    /// Identify the kind of failure from the first error in the chain that maps to one.
+    pub(crate) fn classify(err: &(dyn Error + 'static)) -> Option<Self> {
+        std::iter::successors(Some(err), |err| err.source()).find_map(|err| {
+            if let Some(err) = err.downcast_ref::<ProjectError>() {
+                Self::from_project_error(err)
+            } else if let Some(err) = err.downcast_ref::<pip::operations::Error>() {
+                Some(Self::from(err))
+            } else if let Some(err) = err.downcast_ref::<uv_python::Error>() {
+                Self::from_python_error(err)
+            } else if err.is::<uv_resolver::ResolveError>() {
+                Some(Self::Resolution)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Identify the kind of failure from a [`ProjectError`], if it maps to one.
+    ///
+    /// Errors that wrap another error transparently, e.g., [`ProjectError::Python`], are matched
+    /// here, as the wrapped error is skipped in the chain of sources.
+    fn from_project_error(err: &ProjectError) -> Option<Self> {
+        match err {
+            ProjectError::LockMismatch(..)
+            | ProjectError::MissingLockfile
+            | ProjectError::UnsupportedLockVersion(..)
+            | ProjectError::UnparsableLockVersion(..)
+            | ProjectError::UvLockParse(..) => Some(Self::Lockfile),
+            ProjectError::LockedPythonIncompatibility(..)
+            | ProjectError::RequestedPythonProjectIncompatibility(..)
+            | ProjectError::DotPythonVersionProjectIncompatibility(..)
+            | ProjectError::RequiresPythonProjectIncompatibility(..)
+            | ProjectError::RequestedPythonScriptIncompatibility(..)
+            | ProjectError::DotPythonVersionScriptIncompatibility(..)
+            | ProjectError::RequiresPythonScriptIncompatibility(..)
+            | ProjectError::MissingFreethreadedPython(..) => Some(Self::Interpreter),
+            ProjectError::ResolutionOnlyEnvironment(..) => Some(Self::Environment),
+            ProjectError::Python(err) => Self::from_python_error(err),
+            ProjectError::Operation(err) => Some(Self::from(err)),
+            _ => None,
+        }
+    }
+
+    /// Identify the kind of failure from a [`uv_python::Error`], if it maps to one.
+    fn from_python_error(err: &uv_python::Error) -> Option<Self> {
+        match err {
+            uv_python::Error::MissingPython(..)
+            | uv_python::Error::Discovery(..)
+            | uv_python::Error::Download(..)
+            | uv_python::Error::ManagedPython(..)
+            | uv_python::Error::KeyError(..) => Some(Self::Interpreter),
+            _ => None,
+        }
+    }
+
+    /// Return the reserved exit code for the failure.
+    pub(crate) fn code(self) -> u8 {
+        match self {
+            Self::Resolution => 201,
+            Self::Interpreter => 202,
+            Self::Lockfile => 203,
+            Self::Environment => 204,
+        }
+    }
+}
+
+impl From<&pip::operations::Error> for SetupFailure {
+    fn from(err: &pip::operations::Error) -> Self {
+        match err {
+            pip::operations::Error::Resolve(..) | pip::operations::Error::Requirements(..) => {
+                Self::Resolution
+            }
+            _ => Self::Environment,
+        }
+    }
+}
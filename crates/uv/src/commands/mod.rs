@@ -5,12 +5,15 @@ use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
-use std::{fmt::Display, fmt::Write, process::ExitCode};
+use std::{fmt::Display, fmt::Write};
 
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use config_where::config_where;
+pub(crate) use diagnostics::{WorkspaceFiles, report_json as report_json_diagnostic};
+pub(crate) use exit_code::{SetupFailure, exit_code_map};
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -24,22 +27,37 @@ pub(crate) use pip::uninstall::pip_uninstall;
 pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::format::format;
+pub(crate) use project::fs_trace::matrix_path as fs_trace_matrix_path;
 pub(crate) use project::init::{InitKind, InitProjectKind, init};
+pub(crate) use project::kernel::Kernel;
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
-pub(crate) use project::run::{RunCommand, run};
+pub(crate) use project::run::{Repl, RunCommand, python_matrix, run, run_matrix};
+pub(crate) use project::run_history::{RunHistory, RunInvocation, history as run_history};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
 pub(crate) use project::version::{project_version, self_version};
+pub(crate) use project::warning_policy::WarningPolicy;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
+pub(crate) use python::du::du as python_du;
+pub(crate) use python::env_vars::env_vars as python_env_vars;
 pub(crate) use python::find::find as python_find;
+pub(crate) use python::find::find_failures as python_find_failures;
 pub(crate) use python::find::find_script as python_find_script;
+pub(crate) use python::inspect::inspect as python_inspect;
 pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
+pub(crate) use python::list::outdated as python_list_outdated;
+pub(crate) use python::markers::markers as python_markers;
 pub(crate) use python::pin::pin as python_pin;
+pub(crate) use python::pin_install::pin_install as python_pin_install;
+pub(crate) use python::resolve::resolve as python_resolve;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
 pub(crate) use python::update_shell::update_shell as python_update_shell;
+pub(crate) use python::upgrade::upgrade_minor as python_upgrade_minor;
+pub(crate) use python::validate_downloads::validate_downloads as python_validate_downloads;
+pub(crate) use python::which::which as python_which;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
@@ -51,6 +69,7 @@ pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
+use uv_cli::ExitCodeMap;
 use uv_configuration::Concurrency;
 use uv_distribution_types::InstalledMetadata;
 use uv_fs::{CWD, Simplified};
@@ -67,7 +86,9 @@ mod build_frontend;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod config_where;
 mod diagnostics;
+mod exit_code;
 mod help;
 pub(crate) mod pip;
 mod project;
@@ -90,17 +111,38 @@ pub(crate) enum ExitStatus {
     /// The command failed with an unexpected error.
     Error,
 
+    /// The command failed before an external command could be run, or an environment could be
+    /// synced, e.g., as the requirements could not be resolved.
+    Setup(SetupFailure),
+
     /// The command's exit status is propagated from an external command.
     External(u8),
 }
 
-impl From<ExitStatus> for ExitCode {
-    fn from(status: ExitStatus) -> Self {
-        match status {
-            ExitStatus::Success => Self::from(0),
-            ExitStatus::Failure => Self::from(1),
-            ExitStatus::Error => Self::from(2),
-            ExitStatus::External(code) => Self::from(code),
+impl ExitStatus {
+    /// Return the status for an error that was propagated to the top level.
+    ///
+    /// The kind of failure is only identified under `--exit-code-map reserved`, such that the
+    /// error otherwise exits with `2`, as before.
+    pub(crate) fn from_error(err: &(dyn std::error::Error + 'static), map: ExitCodeMap) -> Self {
+        if map == ExitCodeMap::Reserved {
+            if let Some(failure) = SetupFailure::classify(err) {
+                return Self::Setup(failure);
+            }
+        }
+        Self::Error
+    }
+
+    /// Return the exit code for the status, per the `--exit-code-map` scheme.
+    pub(crate) fn code(self, map: ExitCodeMap) -> u8 {
+        let reserved = map == ExitCodeMap::Reserved;
+        match self {
+            Self::Success => 0,
+            Self::Failure | Self::Error if reserved => exit_code::RESERVED_FAILURE,
+            Self::Setup(failure) if reserved => failure.code(),
+            Self::Failure | Self::Setup(_) => 1,
+            Self::Error => 2,
+            Self::External(code) => code,
         }
     }
 }
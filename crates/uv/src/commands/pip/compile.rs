@@ -1,10 +1,12 @@
 use std::collections::BTreeSet;
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Result, anyhow};
+use clap::ValueEnum;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
@@ -29,6 +31,7 @@ use uv_git::ResolvedRepositoryReference;
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
+use uv_python::wsl::{WslPython, WslRequest};
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
     PythonVersion, VersionRequest,
@@ -51,7 +54,7 @@ use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
-use crate::commands::pip::{operations, resolution_environment};
+use crate::commands::pip::{environment_python_platform, operations, resolution_environment};
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -101,7 +104,8 @@ pub(crate) async fn pip_compile(
     extra_build_variables: &ExtraBuildVariables,
     build_options: BuildOptions,
     mut python_version: Option<PythonVersion>,
-    python_platform: Option<TargetTriple>,
+    mut python_platform: Option<TargetTriple>,
+    wsl: Option<WslRequest>,
     universal: bool,
     exclude_newer: ExcludeNewer,
     sources: SourceStrategy,
@@ -109,6 +113,7 @@ pub(crate) async fn pip_compile(
     link_mode: LinkMode,
     mut python: Option<String>,
     system: bool,
+    python_install_dir: Option<&Path>,
     python_preference: PythonPreference,
     concurrency: Concurrency,
     quiet: bool,
@@ -163,6 +168,33 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Resolve for the Python interpreter in WSL, in place of `--python-version` and
+    // `--python-platform`.
+    if let Some(wsl) = wsl {
+        let wsl_python = WslPython::query(&wsl)?;
+        let target = wsl_python
+            .target_triples()
+            .iter()
+            .find_map(|name| TargetTriple::from_str(name, false).ok())
+            .ok_or_else(|| {
+                anyhow!(
+                    "The platform of the Python interpreter in WSL is not supported: `{}`",
+                    wsl_python.target_triples()[0]
+                )
+            })?;
+        let target_name = target
+            .to_possible_value()
+            .expect("target triples are not skipped");
+        writeln!(
+            printer.stderr(),
+            "Resolving for Python {} in WSL ({})",
+            wsl_python.python_version().cyan(),
+            target_name.get_name().cyan()
+        )?;
+        python_version = Some(wsl_python.python_version().clone());
+        python_platform = Some(target);
+    }
+
     // Respect `UV_PYTHON`
     if python.is_none() && python_version.is_none() {
         if let Ok(request) = std::env::var(EnvVars::UV_PYTHON) {
@@ -293,6 +325,7 @@ pub(crate) async fn pip_compile(
             environment_preference,
             python_preference,
             &cache,
+            python_install_dir,
             preview,
         )
     } else {
@@ -309,6 +342,7 @@ pub(crate) async fn pip_compile(
             environment_preference,
             python_preference,
             &cache,
+            python_install_dir,
             preview,
         )
     }?
@@ -320,6 +354,9 @@ pub(crate) async fn pip_compile(
         interpreter.sys_executable().user_display().cyan()
     );
 
+    // If the interpreter belongs to a resolution-only environment, target its platform by default.
+    let python_platform = python_platform.or_else(|| environment_python_platform(&interpreter));
+
     if let Some(python_version) = python_version.as_ref() {
         // If the requested version does not match the version we're using warn the user
         // _unless_ they have not specified a patch version and that is the only difference
@@ -561,7 +598,7 @@ pub(crate) async fn pip_compile(
         Ok(resolution) => resolution,
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
     };
@@ -1,5 +1,5 @@
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -22,6 +22,7 @@ pub(crate) fn pip_freeze(
     strict: bool,
     python: Option<&str>,
     system: bool,
+    python_install_dir: Option<&Path>,
     paths: Option<Vec<PathBuf>>,
     cache: &Cache,
     printer: Printer,
@@ -33,6 +34,7 @@ pub(crate) fn pip_freeze(
         EnvironmentPreference::from_system_flag(system, false),
         PythonPreference::default().with_system_flag(system),
         cache,
+        python_install_dir,
         preview,
     )?;
 
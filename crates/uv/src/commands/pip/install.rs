@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Context;
 use itertools::Itertools;
@@ -43,7 +44,9 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    environment_python_platform, operations, resolution_markers, resolution_tags,
+};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -71,7 +74,7 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     reinstall: Reinstall,
-    link_mode: LinkMode,
+    link_mode: Option<LinkMode>,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     installer_metadata: bool,
@@ -89,6 +92,7 @@ pub(crate) async fn pip_install(
     sources: SourceStrategy,
     python: Option<String>,
     system: bool,
+    python_install_dir: Option<&Path>,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
@@ -96,6 +100,7 @@ pub(crate) async fn pip_install(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    unlock: bool,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
@@ -195,6 +200,7 @@ pub(crate) async fn pip_install(
             EnvironmentPreference::from_system_flag(system, false),
             python_preference.with_system_flag(system),
             &cache,
+            python_install_dir,
             preview,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -208,6 +214,7 @@ pub(crate) async fn pip_install(
             EnvironmentPreference::from_system_flag(system, true),
             PythonPreference::default().with_system_flag(system),
             &cache,
+            python_install_dir,
             preview,
         )?;
         report_target_environment(&environment, &cache, printer)?;
@@ -266,6 +273,9 @@ pub(crate) async fn pip_install(
 
     // Determine the markers to use for the resolution.
     let interpreter = environment.interpreter();
+
+    // If the environment is resolution-only, target its platform by default.
+    let python_platform = python_platform.or_else(|| environment_python_platform(interpreter));
     let marker_env = resolution_markers(
         python_version.as_ref(),
         python_platform.as_ref(),
@@ -454,7 +464,7 @@ pub(crate) async fn pip_install(
         types_build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         &build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -560,7 +570,7 @@ pub(crate) async fn pip_install(
             Ok(graph) => Resolution::from(graph),
             Err(err) => {
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
         };
@@ -587,7 +597,7 @@ pub(crate) async fn pip_install(
         types_build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         &build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -602,6 +612,8 @@ pub(crate) async fn pip_install(
         &resolution,
         site_packages,
         modifications,
+        None,
+        unlock,
         &reinstall,
         &build_options,
         link_mode,
@@ -625,7 +637,7 @@ pub(crate) async fn pip_install(
         Ok(..) => {}
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
     }
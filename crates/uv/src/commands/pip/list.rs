@@ -1,5 +1,6 @@
 use std::cmp::max;
 use std::fmt::Write;
+use std::path::Path;
 
 use anstream::println;
 use anyhow::Result;
@@ -42,6 +43,7 @@ pub(crate) async fn pip_list(
     exclude: &[PackageName],
     format: &ListFormat,
     outdated: bool,
+    include_parent: bool,
     prerelease: PrereleaseMode,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -52,6 +54,7 @@ pub(crate) async fn pip_list(
     exclude_newer: ExcludeNewer,
     python: Option<&str>,
     system: bool,
+    python_install_dir: Option<&Path>,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -67,6 +70,7 @@ pub(crate) async fn pip_list(
         EnvironmentPreference::from_system_flag(system, false),
         PythonPreference::default().with_system_flag(system),
         cache,
+        python_install_dir,
         preview,
     )?;
 
@@ -75,9 +79,24 @@ pub(crate) async fn pip_list(
     // Build the installed index.
     let site_packages = SitePackages::from_environment(&environment)?;
 
-    // Filter if `--editable` is specified; always sort by name.
+    // If requested, build the installed index for each environment in the `extends-environment`
+    // chain, nearest first.
+    let parent_site_packages = if include_parent {
+        environment
+            .parent_environments(cache)?
+            .iter()
+            .map(SitePackages::from_environment)
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        Vec::new()
+    };
+
+    // Filter if `--editable` is specified; always sort by name. Packages in nearer environments
+    // shadow those of the same name in parent environments.
     let results = site_packages
         .iter()
+        .chain(parent_site_packages.iter().flat_map(SitePackages::iter))
+        .unique_by(|dist| dist.name().clone())
         .filter(|dist| editable.is_none() || editable == Some(dist.is_editable()))
         .filter(|dist| !exclude.contains(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
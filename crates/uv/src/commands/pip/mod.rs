@@ -1,9 +1,13 @@
 use std::borrow::Cow;
 
+use clap::ValueEnum;
+use tracing::debug;
+
 use uv_configuration::TargetTriple;
+use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::{Tags, TagsError};
 use uv_pypi_types::ResolverMarkerEnvironment;
-use uv_python::{Interpreter, PythonVersion};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonVersion};
 
 pub(crate) mod check;
 pub(crate) mod compile;
@@ -14,26 +18,60 @@ pub(crate) mod list;
 pub(crate) mod loggers;
 pub(crate) mod operations;
 pub(crate) mod show;
+pub(crate) mod size_limit;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod uninstall;
 
+/// Return the target platform of a resolution-only environment, i.e., one created with
+/// `uv venv --python-platform`, if the interpreter belongs to one.
+pub(crate) fn environment_python_platform(interpreter: &Interpreter) -> Option<TargetTriple> {
+    if !interpreter.is_virtualenv() {
+        return None;
+    }
+    let cfg = PyVenvConfiguration::parse(interpreter.sys_prefix().join("pyvenv.cfg")).ok()?;
+    let python_platform = cfg.python_platform()?;
+    match TargetTriple::from_str(python_platform, false) {
+        Ok(target) => {
+            debug!("Using target platform of resolution-only environment: `{python_platform}`");
+            Some(target)
+        }
+        Err(err) => {
+            debug!("Ignoring invalid target platform `{python_platform}` in `pyvenv.cfg`: {err}");
+            None
+        }
+    }
+}
+
 pub(crate) fn resolution_markers(
     python_version: Option<&PythonVersion>,
     python_platform: Option<&TargetTriple>,
     interpreter: &Interpreter,
 ) -> ResolverMarkerEnvironment {
     match (python_platform, python_version) {
-        (Some(python_platform), Some(python_version)) => ResolverMarkerEnvironment::from(
-            python_version.markers(&python_platform.markers(interpreter.markers())),
-        ),
+        (None, None) => interpreter.resolver_marker_environment(),
+        _ => synthesize_markers(python_version, python_platform, interpreter.markers()),
+    }
+}
+
+/// Synthesize the [`ResolverMarkerEnvironment`] for the given Python version and platform, based
+/// on the markers of a base environment.
+pub(crate) fn synthesize_markers(
+    python_version: Option<&PythonVersion>,
+    python_platform: Option<&TargetTriple>,
+    base: &MarkerEnvironment,
+) -> ResolverMarkerEnvironment {
+    match (python_platform, python_version) {
+        (Some(python_platform), Some(python_version)) => {
+            ResolverMarkerEnvironment::from(python_version.markers(&python_platform.markers(base)))
+        }
         (Some(python_platform), None) => {
-            ResolverMarkerEnvironment::from(python_platform.markers(interpreter.markers()))
+            ResolverMarkerEnvironment::from(python_platform.markers(base))
         }
         (None, Some(python_version)) => {
-            ResolverMarkerEnvironment::from(python_version.markers(interpreter.markers()))
+            ResolverMarkerEnvironment::from(python_version.markers(base))
         }
-        (None, None) => interpreter.resolver_marker_environment(),
+        (None, None) => ResolverMarkerEnvironment::from(base.clone()),
     }
 }
 
@@ -2,7 +2,7 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, anyhow};
@@ -13,8 +13,8 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, ExtrasSpecification,
-    Overrides, Reinstall, Upgrade,
+    BuildOptions, ByteSize, Concurrency, Constraints, DependencyGroups, DryRun,
+    ExtrasSpecification, Overrides, Reinstall, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
@@ -30,7 +30,7 @@ use uv_normalize::PackageName;
 use uv_pep508::{MarkerEnvironment, RequirementOrigin};
 use uv_platform_tags::Tags;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
-use uv_python::{PythonEnvironment, PythonInstallation};
+use uv_python::{PyVenvConfiguration, PythonEnvironment, PythonInstallation};
 use uv_requirements::{
     GroupsSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
     RequirementsSpecification, SourceTreeResolver,
@@ -44,6 +44,7 @@ use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger, ResolveLogger};
+use crate::commands::pip::size_limit::EnvironmentSizeLimit;
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
 use crate::commands::{ChangeEventKind, DryRunEvent, compile_bytecode};
 use crate::printer::Printer;
@@ -427,6 +428,21 @@ impl Changelog {
     }
 }
 
+/// Returns an error if the virtual environment at `root` was locked against modification with
+/// `uv venv --lock-environment`, unless `unlock` is set.
+///
+/// Used to guard every operation that installs into, removes, recreates, or rewrites the
+/// `pyvenv.cfg` of an existing environment.
+pub(crate) fn check_read_only(root: &Path, unlock: bool) -> Result<(), Error> {
+    if unlock {
+        return Ok(());
+    }
+    match PyVenvConfiguration::parse(root.join("pyvenv.cfg")) {
+        Ok(cfg) if cfg.is_read_only() => Err(Error::ReadOnlyEnvironment(root.to_path_buf())),
+        _ => Ok(()),
+    }
+}
+
 /// Install a set of requirements into the current environment.
 ///
 /// Returns a [`Changelog`] summarizing the changes made to the environment.
@@ -434,9 +450,11 @@ pub(crate) async fn install(
     resolution: &Resolution,
     site_packages: SitePackages,
     modifications: Modifications,
+    size_limit: Option<EnvironmentSizeLimit>,
+    unlock: bool,
     reinstall: &Reinstall,
     build_options: &BuildOptions,
-    link_mode: LinkMode,
+    link_mode: Option<LinkMode>,
     compile: bool,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -454,6 +472,11 @@ pub(crate) async fn install(
 ) -> Result<Changelog, Error> {
     let start = std::time::Instant::now();
 
+    // Respect the link mode recorded in the environment, unless another link mode was requested.
+    let link_mode = link_mode
+        .or_else(|| environment_link_mode(venv))
+        .unwrap_or_default();
+
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
     let plan = Planner::new(resolution)
@@ -499,19 +522,34 @@ pub(crate) async fn install(
         && !compile
     {
         logger.on_audit(resolution.len(), start, printer)?;
+        if let Some(size_limit) = size_limit {
+            size_limit.report(venv, false, printer)?;
+        }
         return Ok(Changelog::default());
     }
 
-    // Partition into two sets: those that require build isolation, and those that disable it. This
-    // is effectively a heuristic to make `--no-build-isolation` work "more often" by way of giving
-    // `--no-build-isolation` packages "access" to the rest of the environment.
-    let (isolated_phase, shared_phase) = Plan {
+    let plan = Plan {
         cached,
         remote,
         reinstalls,
         extraneous,
+    };
+
+    // Refuse to modify an environment that was locked with `uv venv --lock-environment`.
+    if !plan.is_empty() {
+        check_read_only(venv.root(), unlock)?;
+    }
+
+    // Check the projected size of the environment before modifying it.
+    if let Some(size_limit) = size_limit {
+        size_limit.check(&plan, venv)?;
     }
-    .partition(|name| build_dispatch.build_isolation().is_isolated(Some(name)));
+
+    // Partition into two sets: those that require build isolation, and those that disable it. This
+    // is effectively a heuristic to make `--no-build-isolation` work "more often" by way of giving
+    // `--no-build-isolation` packages "access" to the rest of the environment.
+    let (isolated_phase, shared_phase) =
+        plan.partition(|name| build_dispatch.build_isolation().is_isolated(Some(name)));
 
     let has_isolated_phase = !isolated_phase.is_empty();
     let has_shared_phase = !shared_phase.is_empty();
@@ -584,6 +622,10 @@ pub(crate) async fn install(
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer)?;
 
+    if let Some(size_limit) = size_limit {
+        size_limit.report(venv, true, printer)?;
+    }
+
     Ok(changelog)
 }
 
@@ -601,6 +643,17 @@ impl InstallPhase {
     }
 }
 
+/// Returns the link mode recorded in the environment's `pyvenv.cfg` with `uv venv --link-mode`,
+/// if any.
+fn environment_link_mode(venv: &PythonEnvironment) -> Option<LinkMode> {
+    if !venv.interpreter().is_virtualenv() {
+        return None;
+    }
+    let link_mode = venv.cfg().ok()?.link_mode()?;
+    debug!("Using link mode from the environment's `pyvenv.cfg`: {link_mode}");
+    Some(link_mode)
+}
+
 /// Execute a [`Plan`] to install distributions into a Python environment.
 async fn execute_plan(
     plan: Plan,
@@ -1024,4 +1077,16 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment,
+
+    #[error(
+        "The environment is projected to reach {projected}, which exceeds the `max-environment-size` of {max}"
+    )]
+    EnvironmentSizeLimit { projected: String, max: ByteSize },
+
+    #[error(
+        "The environment at `{}` is locked against modification; pass `{}` to modify it anyway",
+        _0.user_display().cyan(),
+        "--unlock".green()
+    )]
+    ReadOnlyEnvironment(PathBuf),
 }
@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use fs_err::File;
@@ -25,6 +26,7 @@ pub(crate) fn pip_show(
     strict: bool,
     python: Option<&str>,
     system: bool,
+    python_install_dir: Option<&Path>,
     files: bool,
     cache: &Cache,
     printer: Printer,
@@ -49,6 +51,7 @@ pub(crate) fn pip_show(
         EnvironmentPreference::from_system_flag(system, false),
         PythonPreference::default().with_system_flag(system),
         cache,
+        python_install_dir,
         preview,
     )?;
 
@@ -0,0 +1,191 @@
+//! Limits on the size of an environment, as configured via `max-environment-size`.
+use std::fmt::Write;
+use std::io;
+use std::path::Path;
+
+use owo_colors::OwoColorize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_configuration::ByteSize;
+use uv_distribution_types::{InstalledDist, Name, RemoteSource};
+use uv_installer::Plan;
+use uv_python::PythonEnvironment;
+use uv_settings::MaxEnvironmentSizeAction;
+use uv_warnings::warn_user;
+
+use crate::commands::human_readable_bytes;
+use crate::commands::pip::operations::Error;
+use crate::printer::Printer;
+
+/// A limit on the size of an environment.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EnvironmentSizeLimit {
+    /// The maximum size of the environment.
+    pub(crate) max: ByteSize,
+    /// The behavior when a sync would exceed the limit.
+    pub(crate) action: MaxEnvironmentSizeAction,
+    /// Whether to report the size of the environment when it's left unchanged, rather than only
+    /// after it's modified.
+    pub(crate) report_unchanged: bool,
+}
+
+impl EnvironmentSizeLimit {
+    /// Resolve the [`EnvironmentSizeLimit`] from the `max-environment-size` settings, if set.
+    pub(crate) fn from_options(
+        max: Option<ByteSize>,
+        action: Option<MaxEnvironmentSizeAction>,
+    ) -> Option<Self> {
+        Some(Self {
+            max: max?,
+            action: action.unwrap_or_default(),
+            report_unchanged: true,
+        })
+    }
+
+    /// Only report the size of the environment after it's modified, e.g., for `uv run`, which
+    /// audits the environment on every invocation.
+    #[must_use]
+    pub(crate) fn report_changes_only(self) -> Self {
+        Self {
+            report_unchanged: false,
+            ..self
+        }
+    }
+
+    /// Check the projected size of the environment after executing the [`Plan`] against the limit.
+    ///
+    /// Returns an error if the limit would be exceeded, unless the limit is configured to warn.
+    pub(crate) fn check(&self, plan: &Plan, venv: &PythonEnvironment) -> Result<(), Error> {
+        let current = directory_size(venv.root())?;
+
+        let removed = plan
+            .reinstalls
+            .iter()
+            .chain(&plan.extraneous)
+            .map(installed_size)
+            .sum::<io::Result<u64>>()?;
+
+        let cached = plan
+            .cached
+            .iter()
+            .map(|dist| directory_size(dist.path()))
+            .sum::<io::Result<u64>>()?;
+
+        // Distributions that aren't in the cache are counted by their download size, which is
+        // the best estimate available prior to downloading them.
+        let mut remote = 0;
+        let mut unknown = Vec::new();
+        for dist in &plan.remote {
+            match dist.size() {
+                Some(size) => remote += size,
+                None => unknown.push(dist.name()),
+            }
+        }
+
+        // Rather than silently counting them as empty, call out the distributions that the
+        // projection can't account for.
+        if !unknown.is_empty() {
+            unknown.sort();
+            warn_user!(
+                "The download size of {} is unknown, so the projected environment size excludes {}",
+                unknown
+                    .iter()
+                    .map(|name| format!("`{}`", name.cyan()))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                if unknown.len() == 1 { "it" } else { "them" },
+            );
+        }
+
+        let projected = current.saturating_sub(removed) + cached + remote;
+        debug!(
+            "Projected environment size: {projected} bytes (current: {current}, removed: {removed}, cached: {cached}, remote: {remote})"
+        );
+
+        if projected <= self.max.as_u64() {
+            return Ok(());
+        }
+
+        let (bytes, unit) = human_readable_bytes(projected);
+        match self.action {
+            MaxEnvironmentSizeAction::Fail => Err(Error::EnvironmentSizeLimit {
+                projected: format!("{bytes:.1}{unit}"),
+                max: self.max,
+            }),
+            MaxEnvironmentSizeAction::Warn => {
+                warn_user!(
+                    "The environment is projected to reach {}, which exceeds the `max-environment-size` of {}",
+                    format!("{bytes:.1}{unit}").cyan(),
+                    self.max.cyan(),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Report the size of the environment, relative to the limit.
+    ///
+    /// Unless `changed` is set, the report is skipped for limits that only report after the
+    /// environment is modified, to avoid walking the environment when it's merely audited.
+    pub(crate) fn report(
+        &self,
+        venv: &PythonEnvironment,
+        changed: bool,
+        printer: Printer,
+    ) -> Result<(), Error> {
+        if !changed && !self.report_unchanged {
+            return Ok(());
+        }
+        let size = directory_size(venv.root())?;
+        let (bytes, unit) = human_readable_bytes(size);
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!("Environment size: {bytes:.1}{unit} (limit: {})", self.max).dimmed()
+        )?;
+        Ok(())
+    }
+}
+
+/// Return the total size of the files in a directory.
+///
+/// Symbolic links are not followed.
+fn directory_size(dir: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in WalkDir::new(dir) {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
+/// Return the size of an installed distribution, per the files listed in its `RECORD`.
+///
+/// Distributions without a `RECORD`, e.g., legacy editables, are assumed to be empty.
+fn installed_size(dist: &InstalledDist) -> io::Result<u64> {
+    let Some(site_packages) = dist.install_path().parent() else {
+        return Ok(0);
+    };
+    let mut record = match fs_err::File::open(dist.install_path().join("RECORD")) {
+        Ok(record) => record,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err),
+    };
+    let entries = uv_install_wheel::read_record_file(&mut record).map_err(io::Error::other)?;
+
+    let mut size = 0;
+    for entry in entries {
+        size += match entry.size {
+            Some(size) => size,
+            None => match fs_err::symlink_metadata(site_packages.join(&entry.path)) {
+                Ok(metadata) => metadata.len(),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => 0,
+                Err(err) => return Err(err),
+            },
+        };
+    }
+    Ok(size)
+}
@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
@@ -41,7 +42,9 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
-use crate::commands::pip::{operations, resolution_markers, resolution_tags};
+use crate::commands::pip::{
+    environment_python_platform, operations, resolution_markers, resolution_tags,
+};
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -55,7 +58,7 @@ pub(crate) async fn pip_sync(
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     reinstall: Reinstall,
-    link_mode: LinkMode,
+    link_mode: Option<LinkMode>,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
     index_locations: IndexLocations,
@@ -78,6 +81,7 @@ pub(crate) async fn pip_sync(
     exclude_newer: ExcludeNewer,
     python: Option<String>,
     system: bool,
+    python_install_dir: Option<&Path>,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
@@ -86,6 +90,7 @@ pub(crate) async fn pip_sync(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    unlock: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -174,6 +179,7 @@ pub(crate) async fn pip_sync(
             EnvironmentPreference::from_system_flag(system, false),
             python_preference.with_system_flag(system),
             &cache,
+            python_install_dir,
             preview,
         )?;
         report_interpreter(&installation, true, printer)?;
@@ -187,6 +193,7 @@ pub(crate) async fn pip_sync(
             EnvironmentPreference::from_system_flag(system, true),
             PythonPreference::default().with_system_flag(system),
             &cache,
+            python_install_dir,
             preview,
         )?;
         report_target_environment(&environment, &cache, printer)?;
@@ -240,6 +247,9 @@ pub(crate) async fn pip_sync(
 
     let interpreter = environment.interpreter();
 
+    // If the environment is resolution-only, target its platform by default.
+    let python_platform = python_platform.or_else(|| environment_python_platform(interpreter));
+
     // Determine the Python requirement, if the user requested a specific version.
     let python_requirement = if let Some(python_version) = python_version.as_ref() {
         PythonRequirement::from_python_version(interpreter, python_version)
@@ -382,7 +392,7 @@ pub(crate) async fn pip_sync(
         types_build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         &build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -490,7 +500,7 @@ pub(crate) async fn pip_sync(
             Ok(resolution) => Resolution::from(resolution),
             Err(err) => {
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
         };
@@ -517,7 +527,7 @@ pub(crate) async fn pip_sync(
         types_build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         &build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -532,6 +542,8 @@ pub(crate) async fn pip_sync(
         &resolution,
         site_packages,
         Modifications::Exact,
+        None,
+        unlock,
         &reinstall,
         &build_options,
         link_mode,
@@ -555,7 +567,7 @@ pub(crate) async fn pip_sync(
         Ok(_) => {}
         Err(err) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
     }
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use futures::StreamExt;
@@ -50,6 +51,7 @@ pub(crate) async fn pip_tree(
     exclude_newer: ExcludeNewer,
     python: Option<&str>,
     system: bool,
+    python_install_dir: Option<&Path>,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -60,6 +62,7 @@ pub(crate) async fn pip_tree(
         EnvironmentPreference::from_system_flag(system, false),
         PythonPreference::default().with_system_flag(system),
         cache,
+        python_install_dir,
         preview,
     )?;
 
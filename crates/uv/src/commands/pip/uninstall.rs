@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
@@ -18,7 +19,7 @@ use uv_python::{EnvironmentPreference, PythonPreference};
 use uv_python::{Prefix, PythonEnvironment, Target};
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 
-use crate::commands::pip::operations::report_target_environment;
+use crate::commands::pip::operations::{self, report_target_environment};
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
 use crate::settings::NetworkSettings;
@@ -29,6 +30,7 @@ pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
     python: Option<String>,
     system: bool,
+    python_install_dir: Option<&Path>,
     break_system_packages: bool,
     target: Option<Target>,
     prefix: Option<Prefix>,
@@ -36,6 +38,7 @@ pub(crate) async fn pip_uninstall(
     keyring_provider: KeyringProviderType,
     network_settings: &NetworkSettings,
     dry_run: DryRun,
+    unlock: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -60,6 +63,7 @@ pub(crate) async fn pip_uninstall(
         EnvironmentPreference::from_system_flag(system, true),
         PythonPreference::default().with_system_flag(system),
         &cache,
+        python_install_dir,
         preview,
     )?;
 
@@ -205,6 +209,11 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
+    // Refuse to modify an environment that was locked with `uv venv --lock-environment`.
+    if !dry_run.enabled() {
+        operations::check_read_only(environment.root(), unlock)?;
+    }
+
     // Uninstall each package.
     if !dry_run.enabled() {
         for distribution in &distributions {
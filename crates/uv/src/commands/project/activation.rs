@@ -0,0 +1,145 @@
+//! Activation scripts for the project environment, exported on sync.
+//!
+//! With `--export-activate` (or the `export-activate` setting), `uv sync` writes a script for each
+//! requested shell that activates the project environment, e.g., an `.envrc` file for `direnv`.
+//! The scripts are regenerated on every sync, such that they track the project environment, e.g.,
+//! if `UV_PROJECT_ENVIRONMENT` changes.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_configuration::ActivationShell;
+use uv_fs::Simplified;
+use uv_python::PythonEnvironment;
+use uv_warnings::warn_user;
+
+/// The marker identifying an activation script generated by uv.
+///
+/// Existing files without the marker are never overwritten.
+const MARKER: &str = "# Generated by uv";
+
+/// Write the activation scripts for the given shells to the project root.
+pub(crate) fn write(
+    project_root: &Path,
+    environment: &PythonEnvironment,
+    shells: &[ActivationShell],
+) -> Result<()> {
+    let root = std::path::absolute(environment.root())?;
+    let scripts = std::path::absolute(environment.scripts())?;
+    let root = root.simplified().to_string_lossy();
+    let scripts = scripts.simplified().to_string_lossy();
+
+    for shell in shells {
+        let path = script_path(project_root, *shell);
+        let contents = render(*shell, &root, &scripts);
+
+        match fs_err::read_to_string(&path) {
+            Ok(existing) if existing == contents => {
+                debug!(
+                    "Activation script at `{}` is up-to-date",
+                    path.user_display()
+                );
+                continue;
+            }
+            Ok(existing) if !existing.starts_with(MARKER) => {
+                warn_user!(
+                    "Skipping export of the activation script to `{}`, which was not generated by uv",
+                    path.user_display()
+                );
+                continue;
+            }
+            Ok(_) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        if let Some(parent) = path.parent() {
+            if !parent.exists() {
+                fs_err::create_dir_all(parent)?;
+                // Exclude the directory from version control, as with virtual environments.
+                if parent != project_root {
+                    fs_err::write(parent.join(".gitignore"), "*")?;
+                }
+            }
+        }
+
+        debug!("Writing activation script to `{}`", path.user_display());
+        uv_fs::write_atomic_sync(&path, contents).with_context(|| {
+            format!(
+                "Failed to write activation script to `{}`",
+                path.user_display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Return the path of the activation script for the given shell.
+fn script_path(project_root: &Path, shell: ActivationShell) -> PathBuf {
+    match shell {
+        ActivationShell::Direnv => project_root.join(".envrc"),
+        ActivationShell::Sh => project_root.join(".uv").join("activate.sh"),
+        ActivationShell::Fish => project_root.join(".uv").join("activate.fish"),
+        ActivationShell::Powershell => project_root.join(".uv").join("activate.ps1"),
+    }
+}
+
+/// Render the activation script for the given shell.
+fn render(shell: ActivationShell, root: &str, scripts: &str) -> String {
+    let mut contents =
+        format!("{MARKER}; changes will be overwritten by `uv sync --export-activate`.\n");
+    match shell {
+        ActivationShell::Direnv => {
+            let root = quote_posix(root);
+            let _ = writeln!(contents, "export VIRTUAL_ENV={root}");
+            let _ = writeln!(contents, "export UV_PROJECT_ENVIRONMENT={root}");
+            let _ = writeln!(contents, "PATH_add {}", quote_posix(scripts));
+        }
+        ActivationShell::Sh => {
+            let root = quote_posix(root);
+            let _ = writeln!(contents, "export VIRTUAL_ENV={root}");
+            let _ = writeln!(contents, "export UV_PROJECT_ENVIRONMENT={root}");
+            let _ = writeln!(
+                contents,
+                "export PATH={}\"${{PATH:+:$PATH}}\"",
+                quote_posix(scripts)
+            );
+        }
+        ActivationShell::Fish => {
+            let root = quote_fish(root);
+            let _ = writeln!(contents, "set -gx VIRTUAL_ENV {root}");
+            let _ = writeln!(contents, "set -gx UV_PROJECT_ENVIRONMENT {root}");
+            let _ = writeln!(contents, "set -gx PATH {} $PATH", quote_fish(scripts));
+        }
+        ActivationShell::Powershell => {
+            let root = quote_powershell(root);
+            let _ = writeln!(contents, "$env:VIRTUAL_ENV = {root}");
+            let _ = writeln!(contents, "$env:UV_PROJECT_ENVIRONMENT = {root}");
+            let _ = writeln!(
+                contents,
+                "$env:PATH = {} + [IO.Path]::PathSeparator + $env:PATH",
+                quote_powershell(scripts)
+            );
+        }
+    }
+    contents
+}
+
+/// Quote a string for a POSIX shell, in which single-quoted strings can't contain a single quote.
+fn quote_posix(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Quote a string for `fish`, in which single-quoted strings support `\\` and `\'` escapes.
+fn quote_fish(value: &str) -> String {
+    format!("'{}'", value.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// Quote a string for PowerShell, in which single quotes are escaped by doubling them.
+fn quote_powershell(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -52,6 +52,7 @@ use crate::commands::pip::operations::Modifications;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
     PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter,
     UniversalState, default_dependency_groups, init_script_python_requirement,
@@ -69,6 +70,7 @@ pub(crate) async fn add(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    unlock: bool,
     no_install_project: bool,
     no_install_workspace: bool,
     requirements: Vec<RequirementsSource>,
@@ -224,6 +226,7 @@ pub(crate) async fn add(
         // Discover the interpreter.
         let interpreter = ScriptInterpreter::discover(
             (&script).into(),
+            project_dir,
             python.as_deref().map(PythonRequest::parse),
             &network_settings,
             python_preference,
@@ -232,6 +235,7 @@ pub(crate) async fn add(
             false,
             no_config,
             active,
+            WarningPolicy::default(),
             cache,
             printer,
             preview,
@@ -302,6 +306,7 @@ pub(crate) async fn add(
                 false,
                 no_config,
                 active,
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -323,8 +328,10 @@ pub(crate) async fn add(
                 no_sync,
                 no_config,
                 active,
+                WarningPolicy::default(),
                 cache,
                 DryRun::Disabled,
+                unlock,
                 printer,
                 preview,
             )
@@ -469,7 +476,7 @@ pub(crate) async fn add(
                 build_isolation,
                 &extra_build_requires,
                 &extra_build_variables,
-                settings.resolver.link_mode,
+                settings.resolver.link_mode.unwrap_or_default(),
                 &settings.resolver.build_options,
                 &build_hasher,
                 settings.resolver.exclude_newer.clone(),
@@ -736,6 +743,7 @@ pub(crate) async fn add(
         lock_state,
         sync_state,
         locked,
+        unlock,
         no_install_project,
         no_install_workspace,
         &defaulted_extras,
@@ -760,7 +768,7 @@ pub(crate) async fn add(
             }
             match err {
                 ProjectError::Operation(err) => diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls).with_hint(format!("If you want to add the package regardless of the failed resolution, provide the `{}` flag to skip locking and syncing.", "--frozen".green()))
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
                 err => Err(err.into()),
             }
@@ -966,6 +974,7 @@ async fn lock_and_sync(
     lock_state: UniversalState,
     sync_state: PlatformState,
     locked: bool,
+    unlock: bool,
     no_install_project: bool,
     no_install_workspace: bool,
     extras: &ExtrasSpecificationWithDefaults,
@@ -1157,10 +1166,13 @@ async fn lock_and_sync(
         InstallOptions::new(no_install_project, no_install_workspace, vec![]),
         Modifications::Sufficient,
         None,
+        unlock,
+        None,
         settings.into(),
         network_settings,
         &sync_state,
         Box::new(DefaultInstallLogger),
+        None,
         installer_metadata,
         concurrency,
         cache,
@@ -1387,11 +1399,12 @@ impl AddTarget {
                 Ok(Self::Script(script, interpreter))
             }
             Self::Project(project, venv) => {
+                let path = project.root().join("pyproject.toml");
+                let pyproject_toml = toml::from_str(content)
+                    .map_err(|err| ProjectError::PyprojectTomlParse(path.clone(), err))?;
                 let project = project
-                    .with_pyproject_toml(
-                        toml::from_str(content).map_err(ProjectError::PyprojectTomlParse)?,
-                    )
-                    .ok_or(ProjectError::PyprojectTomlUpdate)?;
+                    .with_pyproject_toml(pyproject_toml)
+                    .ok_or(ProjectError::PyprojectTomlUpdate(path))?;
                 Ok(Self::Project(project, venv))
             }
         }
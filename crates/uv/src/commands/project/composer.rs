@@ -0,0 +1,691 @@
+//! Composition of the environment in which a command is executed.
+//!
+//! A command runs against a base interpreter, optionally layered with a requirements environment
+//! (e.g., from `--with`). When layering, the two are combined in an ephemeral environment that
+//! references both site packages directories, so that modifications made by the command don't
+//! poison the cached requirements environment.
+//!
+//! The composition is shared by `uv run`, `uv tool run`, and `uv python which`, such that they
+//! agree on the `PATH` and the interpreter of the command. It's internal to this crate, as the
+//! ephemeral environments are registered in the cache for `uv cache prune --ephemeral` and the
+//! operations are recorded for `uv run --fs-trace`.
+
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use itertools::Itertools;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+use uv_cache::Cache;
+use uv_cli::OverlayMode;
+use uv_configuration::{PathEntry, PathPolicy, Preview};
+use uv_fs::{PythonExt, Simplified, create_symlink, long_path};
+use uv_python::{Interpreter, PyVenvConfiguration, PythonEnvironment};
+use uv_static::EnvVars;
+
+use crate::commands::project::environment::{EphemeralDir, EphemeralEnvironment};
+use crate::commands::project::fs_trace::{FsTrace, OperationKind};
+
+/// Layers an optional requirements environment atop a base interpreter.
+#[derive(Debug)]
+pub(crate) struct EnvironmentComposer<'a> {
+    base_interpreter: &'a Interpreter,
+    requirements_env: Option<&'a PythonEnvironment>,
+    overlay_mode: OverlayMode,
+    allow_user_site: bool,
+    fs_trace: Option<&'a FsTrace>,
+    preview: Preview,
+}
+
+impl<'a> EnvironmentComposer<'a> {
+    /// Create an [`EnvironmentComposer`] for the given base interpreter.
+    pub(crate) fn new(base_interpreter: &'a Interpreter, preview: Preview) -> Self {
+        Self {
+            base_interpreter,
+            requirements_env: None,
+            overlay_mode: OverlayMode::default(),
+            allow_user_site: false,
+            fs_trace: None,
+            preview,
+        }
+    }
+
+    /// Layer the given requirements environment atop the base interpreter.
+    #[must_use]
+    pub(crate) fn with_requirements(
+        mut self,
+        requirements_env: Option<&'a PythonEnvironment>,
+    ) -> Self {
+        self.requirements_env = requirements_env;
+        self
+    }
+
+    /// Set the strategy used to reference the site packages of the layered environments.
+    #[must_use]
+    pub(crate) fn with_overlay_mode(mut self, overlay_mode: OverlayMode) -> Self {
+        self.overlay_mode = overlay_mode;
+        self
+    }
+
+    /// Expose the user site packages directory to the command.
+    ///
+    /// Virtual environments exclude the user site packages directory. If the base environment
+    /// doesn't already include it (see `uv venv --allow-user-site`), the command runs in an
+    /// ephemeral environment that does.
+    #[must_use]
+    pub(crate) fn with_user_site(mut self, allow_user_site: bool) -> Self {
+        self.allow_user_site = allow_user_site;
+        self
+    }
+
+    /// Record the file system operations performed while composing the environment in the given
+    /// [`FsTrace`].
+    #[must_use]
+    pub(crate) fn with_fs_trace(mut self, fs_trace: Option<&'a FsTrace>) -> Self {
+        self.fs_trace = fs_trace;
+        self
+    }
+
+    /// Compose the environment.
+    ///
+    /// If a requirements environment is layered atop the base interpreter, or the user site
+    /// packages must be added to a base virtual environment, this creates an ephemeral
+    /// environment in the cache that extends both.
+    pub(crate) fn compose(self, cache: &Cache) -> anyhow::Result<ComposedEnvironment<'a>> {
+        let user_site = self.allow_user_site && !self.base_allows_user_site();
+        if self.requirements_env.is_none() && !user_site {
+            return Ok(ComposedEnvironment {
+                base_interpreter: self.base_interpreter,
+                requirements_env: None,
+                ephemeral: None,
+            });
+        }
+
+        // Run the command in an ephemeral, isolated environment. Otherwise, modifications to the
+        // "active virtual environment" would poison the cache.
+        let ephemeral_dir = EphemeralDir::new(cache)?;
+        debug!(
+            "Creating ephemeral environment at: `{}`",
+            ephemeral_dir.path().simplified_display()
+        );
+        let ephemeral_env = uv_virtualenv::create_venv(
+            ephemeral_dir.path(),
+            self.base_interpreter.clone(),
+            uv_virtualenv::Prompt::None,
+            false,
+            uv_virtualenv::OnExisting::Remove,
+            false,
+            false,
+            false,
+            self.preview,
+        )
+        .inspect(|_| {
+            if let Some(fs_trace) = self.fs_trace {
+                fs_trace.record_tree(ephemeral_dir.path());
+            }
+        })?;
+        let ephemeral_env = EphemeralEnvironment::from(ephemeral_env);
+
+        self.overlay(&ephemeral_env, self.requirements_env)?;
+
+        // Add the user site packages in a separate path file, which sorts after the overlay, such
+        // that they take the lowest precedence.
+        if user_site {
+            debug!("Adding the user site packages to the ephemeral environment");
+            ephemeral_env.set_user_site()?;
+            self.trace(
+                OperationKind::Modify,
+                ephemeral_env.sys_prefix().join("pyvenv.cfg"),
+            );
+        }
+
+        Ok(ComposedEnvironment {
+            base_interpreter: self.base_interpreter,
+            requirements_env: self.requirements_env,
+            ephemeral: Some((ephemeral_dir, PythonEnvironment::from(ephemeral_env))),
+        })
+    }
+
+    /// Compose the environment without creating the ephemeral environment, e.g., to inspect the
+    /// executables that a command would resolve to.
+    ///
+    /// The entrypoints of the ephemeral environment are copies of those in the requirements
+    /// environment, so the requirements environment takes its place.
+    pub(crate) fn layered(self) -> ComposedEnvironment<'a> {
+        ComposedEnvironment {
+            base_interpreter: self.base_interpreter,
+            requirements_env: self.requirements_env,
+            ephemeral: None,
+        }
+    }
+
+    /// Record an operation on `path`, if tracing is enabled.
+    fn trace(&self, operation: OperationKind, path: impl AsRef<Path>) {
+        if let Some(fs_trace) = self.fs_trace {
+            fs_trace.record(operation, path);
+        }
+    }
+
+    /// Record an operation on `path` that derives from `source`, if tracing is enabled.
+    fn trace_from(
+        &self,
+        operation: OperationKind,
+        path: impl AsRef<Path>,
+        source: impl AsRef<Path>,
+    ) {
+        if let Some(fs_trace) = self.fs_trace {
+            fs_trace.record_from(operation, path, source);
+        }
+    }
+
+    /// Returns `true` if the base interpreter already includes the user site packages directory.
+    ///
+    /// Only virtual environments exclude it, unless created with `uv venv --allow-user-site`.
+    fn base_allows_user_site(&self) -> bool {
+        !self.base_interpreter.is_virtualenv()
+            || PyVenvConfiguration::parse(self.base_interpreter.sys_prefix().join("pyvenv.cfg"))
+                .is_ok_and(|cfg| cfg.allows_user_site())
+    }
+
+    /// Add a path file to the ephemeral environment to enable loading from the requirements
+    /// environment and the base environment site packages, and expose their entrypoints.
+    ///
+    /// Setting `PYTHONPATH` is insufficient, as it doesn't resolve `.pth` files in the base
+    /// environment. Adding `sitecustomize.py` would be an alternative, but it can be shadowed by an
+    /// existing such module in the python installation.
+    fn overlay(
+        &self,
+        ephemeral_env: &EphemeralEnvironment,
+        requirements_env: Option<&PythonEnvironment>,
+    ) -> anyhow::Result<()> {
+        let base_interpreter = self.base_interpreter;
+        let requirements_site_packages = requirements_env
+            .map(|requirements_env| {
+                requirements_env.site_packages().next().ok_or_else(|| {
+                    anyhow!("Requirements environment has no site packages directory")
+                })
+            })
+            .transpose()?;
+        let mut base_site_packages = base_interpreter
+            .runtime_site_packages()
+            .iter()
+            .map(|path| Cow::Borrowed(path.as_path()))
+            .chain(base_interpreter.site_packages())
+            .peekable();
+        if base_site_packages.peek().is_none() {
+            return Err(anyhow!("Base environment has no site packages directory"));
+        }
+
+        let site_packages = requirements_site_packages
+            .into_iter()
+            .chain(base_site_packages)
+            .dedup()
+            .collect::<Vec<_>>();
+
+        // Clone the site packages of the `--with` requirements and of the project environment
+        // into the ephemeral environment, such that the command is isolated from any changes
+        // to those environments while it's running (e.g., a concurrent `uv sync`). Site
+        // packages outside of a virtual environment (e.g., those of a system interpreter) are
+        // always referenced in place.
+        let site_packages = match self.overlay_mode {
+            OverlayMode::Link => site_packages,
+            OverlayMode::Auto | OverlayMode::Clone => {
+                let should_clone = |path: &Path| {
+                    requirements_env.is_some_and(|env| env.site_packages().any(|site| site == path))
+                        || (base_interpreter.is_virtualenv()
+                            && path.starts_with(base_interpreter.sys_prefix()))
+                };
+                let target = ephemeral_env.sys_prefix().join("overlay");
+                match clone_site_packages(&site_packages, should_clone, &target) {
+                    Ok(cloned) => {
+                        for (source, clone) in site_packages.iter().zip(&cloned) {
+                            if source != clone {
+                                self.trace_from(OperationKind::Copy, clone, source);
+                            }
+                        }
+                        cloned
+                    }
+                    Err(err) => {
+                        if let Err(err) = fs_err::remove_dir_all(&target) {
+                            if err.kind() != std::io::ErrorKind::NotFound {
+                                debug!("Failed to remove partial overlay: {err}");
+                            }
+                        }
+                        if self.overlay_mode == OverlayMode::Clone {
+                            return Err(anyhow::Error::from(err).context(
+                                "Failed to clone site packages into the ephemeral environment; use `--overlay-mode link` if the filesystem does not support copy-on-write clones",
+                            ));
+                        }
+                        debug!(
+                            "Failed to clone site packages into the ephemeral environment, falling back to links: {err}"
+                        );
+                        site_packages
+                    }
+                }
+            }
+        };
+
+        let overlay_content = format!(
+            "import site; {}",
+            site_packages
+                .iter()
+                .inspect(|path| debug!("Adding `{}` to site packages", path.display()))
+                .map(|path| format!("site.addsitedir(\"{}\")", path.escape_for_python()))
+                .collect::<Vec<_>>()
+                .join("; ")
+        );
+
+        let overlay_path = ephemeral_env.set_overlay(overlay_content)?;
+        self.trace(OperationKind::Create, overlay_path);
+
+        // N.B. The order here matters — earlier interpreters take precedence over the
+        // later ones.
+        for interpreter in requirements_env
+            .map(PythonEnvironment::interpreter)
+            .into_iter()
+            .chain([base_interpreter])
+        {
+            // Copy each entrypoint from the base environments to the ephemeral environment,
+            // updating the Python executable target to ensure they run in the ephemeral
+            // environment.
+            for entry in fs_err::read_dir(long_path(interpreter.scripts()))? {
+                let entry = entry?;
+                if !entry.file_type()?.is_file() {
+                    continue;
+                }
+                match copy_entrypoint(
+                    &entry.path(),
+                    &ephemeral_env.scripts().join(entry.file_name()),
+                    interpreter.sys_executable(),
+                    ephemeral_env.sys_executable(),
+                    self.fs_trace,
+                ) {
+                    Ok(()) => {}
+                    // If the entrypoint already exists, skip it.
+                    Err(CopyEntrypointError::Io(err))
+                        if err.kind() == std::io::ErrorKind::AlreadyExists =>
+                    {
+                        trace!(
+                            "Skipping copy of entrypoint `{}`: already exists",
+                            &entry.path().display()
+                        );
+                    }
+                    Err(CopyEntrypointError::Io(err))
+                        if err.kind() == std::io::ErrorKind::PermissionDenied =>
+                    {
+                        trace!(
+                            "Skipping copy of entrypoint `{}`: permission denied",
+                            &entry.path().display()
+                        );
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+
+            // Link data directories from the base environment to the ephemeral environment.
+            //
+            // This is critical for Jupyter Lab, which cannot operate without the files it
+            // writes to `<prefix>/share/jupyter`.
+            //
+            // See https://github.com/jupyterlab/jupyterlab/issues/17716
+            for dir in &["etc/jupyter", "share/jupyter"] {
+                let source = interpreter.sys_prefix().join(dir);
+                if !matches!(source.try_exists(), Ok(true)) {
+                    continue;
+                }
+                if !source.is_dir() {
+                    continue;
+                }
+                let target = ephemeral_env.sys_prefix().join(dir);
+                if let Some(parent) = target.parent() {
+                    fs_err::create_dir_all(parent)?;
+                }
+                match create_symlink(&source, &target) {
+                    Ok(()) => {
+                        trace!(
+                            "Created link for {} -> {}",
+                            target.user_display(),
+                            source.user_display()
+                        );
+                        self.trace_from(OperationKind::Symlink, &target, &source);
+                    }
+                    Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        // Write the `sys.prefix` of the parent environment to the `extends-environment` key of the `pyvenv.cfg`
+        // file. This helps out static-analysis tools such as ty (see docs on
+        // `CachedEnvironment::set_parent_environment`).
+        //
+        // Note that we do this even if the parent environment is not a virtual environment.
+        // For ephemeral environments created by `uv run --with`, the parent environment's
+        // `site-packages` directory is added to `sys.path` even if the parent environment is not
+        // a virtual environment and even if `--system-site-packages` was not explicitly selected.
+        ephemeral_env.set_parent_environment(base_interpreter.sys_prefix())?;
+        self.trace(
+            OperationKind::Modify,
+            ephemeral_env.sys_prefix().join("pyvenv.cfg"),
+        );
+
+        // If `--system-site-packages` is enabled, add the system site packages to the ephemeral
+        // environment.
+        if base_interpreter.is_virtualenv()
+            && PyVenvConfiguration::parse(base_interpreter.sys_prefix().join("pyvenv.cfg"))
+                .is_ok_and(|cfg| cfg.include_system_site_packages())
+        {
+            ephemeral_env.set_system_site_packages()?;
+            self.trace(
+                OperationKind::Modify,
+                ephemeral_env.sys_prefix().join("pyvenv.cfg"),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// An environment composed by an [`EnvironmentComposer`].
+///
+/// The ephemeral environment, if any, is removed when this is dropped.
+#[derive(Debug)]
+pub(crate) struct ComposedEnvironment<'a> {
+    base_interpreter: &'a Interpreter,
+    requirements_env: Option<&'a PythonEnvironment>,
+    ephemeral: Option<(EphemeralDir, PythonEnvironment)>,
+}
+
+impl ComposedEnvironment<'_> {
+    /// Return the ephemeral environment, if the requirements were layered atop the base
+    /// interpreter.
+    pub(crate) fn ephemeral(&self) -> Option<&PythonEnvironment> {
+        self.ephemeral.as_ref().map(|(_, environment)| environment)
+    }
+
+    /// Return the directory of the ephemeral environment, if any.
+    pub(crate) fn ephemeral_dir(&self) -> Option<&EphemeralDir> {
+        self.ephemeral.as_ref().map(|(dir, _)| dir)
+    }
+
+    /// Return the directory of the ephemeral environment, if any, such that it can be kept.
+    pub(crate) fn into_ephemeral_dir(self) -> Option<EphemeralDir> {
+        self.ephemeral.map(|(dir, _)| dir)
+    }
+
+    /// Return the [`Interpreter`] with which commands should be run.
+    pub(crate) fn interpreter(&self) -> &Interpreter {
+        self.ephemeral()
+            .or(self.requirements_env)
+            .map_or(self.base_interpreter, PythonEnvironment::interpreter)
+    }
+
+    /// Return the site packages directories visible to the composed environment, in order of
+    /// precedence.
+    pub(crate) fn site_packages(&self) -> Vec<PathBuf> {
+        self.ephemeral()
+            .into_iter()
+            .chain(self.requirements_env)
+            .flat_map(PythonEnvironment::site_packages)
+            .chain(self.base_interpreter.site_packages())
+            .map(Cow::into_owned)
+            .dedup()
+            .collect()
+    }
+
+    /// Construct the `PATH` environment variable from the sources in the given policy, in order.
+    ///
+    /// By default, the scripts directories of the composed environment are prepended to the
+    /// current `PATH`. Returns `None` if the policy clears `PATH` entirely.
+    pub(crate) fn path(
+        &self,
+        policy: &PathPolicy,
+    ) -> Result<Option<OsString>, std::env::JoinPathsError> {
+        if policy.is_empty() {
+            return Ok(None);
+        }
+        let entries = self.path_entries(policy).into_iter().map(|(_, dir)| dir);
+        std::env::join_paths(entries).map(Some)
+    }
+
+    /// Return the directories of the `PATH` constructed by [`ComposedEnvironment::path`], along
+    /// with the source of each directory.
+    pub(crate) fn path_entries(&self, policy: &PathPolicy) -> Vec<(PathEntry, PathBuf)> {
+        let inherited = std::env::var_os(EnvVars::PATH);
+        policy
+            .entries()
+            .iter()
+            .flat_map(|entry| match entry {
+                PathEntry::Overlay => self
+                    .ephemeral()
+                    .map(PythonEnvironment::scripts)
+                    .into_iter()
+                    .chain(self.requirements_env.map(PythonEnvironment::scripts))
+                    .map(|dir| (PathEntry::Overlay, dir.to_path_buf()))
+                    .collect::<Vec<_>>(),
+                PathEntry::Base => std::iter::once(self.base_interpreter.scripts())
+                    .chain(
+                        // On Windows, non-virtual Python distributions put `python.exe` in the
+                        // top-level directory, rather than in the `Scripts` subdirectory.
+                        cfg!(windows)
+                            .then(|| self.base_interpreter.sys_executable().parent())
+                            .flatten(),
+                    )
+                    .map(|dir| (PathEntry::Base, dir.to_path_buf()))
+                    .collect(),
+                PathEntry::Inherit => inherited
+                    .as_ref()
+                    .iter()
+                    .flat_map(std::env::split_paths)
+                    .map(|dir| (PathEntry::Inherit, dir))
+                    .collect(),
+            })
+            .dedup_by(|(_, a), (_, b)| a == b)
+            .collect()
+    }
+}
+
+#[derive(Error, Debug)]
+enum CopyEntrypointError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[cfg(windows)]
+    #[error(transparent)]
+    Trampoline(#[from] uv_trampoline_builder::Error),
+}
+
+/// Clone the site packages directories for which `should_clone` returns `true` into `target` via
+/// copy-on-write, returning the paths to use in place of `site_packages`, in the same order.
+///
+/// Fails if the filesystem does not support copy-on-write clones, e.g., if the source and target
+/// directories are on different filesystems.
+fn clone_site_packages<'a>(
+    site_packages: &[Cow<'a, Path>],
+    should_clone: impl Fn(&Path) -> bool,
+    target: &Path,
+) -> std::io::Result<Vec<Cow<'a, Path>>> {
+    let mut cloned = Vec::with_capacity(site_packages.len());
+    for (index, path) in site_packages.iter().enumerate() {
+        if !should_clone(path) {
+            cloned.push(path.clone());
+            continue;
+        }
+        let clone = target.join(index.to_string());
+        trace!("Cloning `{}` to `{}`", path.display(), clone.display());
+        fs_err::create_dir_all(target)?;
+        clone_dir(path, &clone)?;
+        cloned.push(Cow::Owned(clone));
+    }
+    Ok(cloned)
+}
+
+/// Recursively clone the directory at `from` to `to` via copy-on-write.
+///
+/// On macOS, `clonefile` clones an entire directory in a single call. On other platforms, each
+/// file is cloned individually, as the underlying ioctls do not support directories.
+fn clone_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    if cfg!(target_os = "macos") {
+        return reflink_copy::reflink(from, to);
+    }
+    for entry in walkdir::WalkDir::new(from) {
+        let entry = entry?;
+        let path = to.join(
+            entry
+                .path()
+                .strip_prefix(from)
+                .expect("walkdir starts with root"),
+        );
+        if entry.file_type().is_dir() {
+            fs_err::create_dir_all(&path)?;
+        } else {
+            reflink_copy::reflink(entry.path(), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Create a copy of the entrypoint at `source` at `target`, if it has a Python shebang, replacing
+/// the previous Python executable with a new one.
+///
+/// This is a no-op if the target already exists.
+///
+/// Note on Windows, the entrypoints do not use shebangs and require a rewrite of the trampoline.
+#[cfg(unix)]
+fn copy_entrypoint(
+    source: &Path,
+    target: &Path,
+    previous_executable: &Path,
+    python_executable: &Path,
+    fs_trace: Option<&FsTrace>,
+) -> Result<(), CopyEntrypointError> {
+    use std::ffi::OsStr;
+    use std::io::{Seek, Write};
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::fs::PermissionsExt;
+
+    use fs_err::os::unix::fs::OpenOptionsExt;
+
+    let mut file = fs_err::File::open(long_path(source))?;
+    let mut buffer = [0u8; 2];
+    if file.read_exact(&mut buffer).is_err() {
+        // File is too small to have a shebang
+        trace!(
+            "Skipping copy of entrypoint `{}`: file is too small to contain a shebang",
+            source.user_display()
+        );
+        return Ok(());
+    }
+
+    // Check if it starts with `#!` to avoid reading binary files and such into memory
+    if &buffer != b"#!" {
+        trace!(
+            "Skipping copy of entrypoint `{}`: does not start with #!",
+            source.user_display()
+        );
+        return Ok(());
+    }
+
+    let mut contents = Vec::new();
+    file.seek(std::io::SeekFrom::Start(0))?;
+    file.read_to_end(&mut contents)?;
+
+    // The shebangs that may have been written for the previous executable. The executable may
+    // contain spaces or bytes that aren't valid UTF-8, so we compare raw bytes.
+    let previous = previous_executable.as_os_str().as_bytes();
+    let mut shebangs = vec![
+        // A relative path or relocatable shebang
+        [
+            uv_install_wheel::format_shebang("python", "posix", true).as_bytes(),
+            b"\n",
+        ]
+        .concat(),
+        // An absolute path shebang, or a `/bin/sh` trampoline for a non-simple path
+        shebang_line(previous_executable),
+        // A verbatim absolute path shebang, even if it contains spaces
+        [b"#!", previous, b"\n"].concat(),
+    ];
+    // If the previous executable ends with `python3`, check for a shebang with `python` too
+    if let Some(previous) = previous.strip_suffix(b"3") {
+        shebangs.push(shebang_line(Path::new(OsStr::from_bytes(previous))));
+        shebangs.push([b"#!", previous, b"\n"].concat());
+    }
+
+    let Some(contents) = shebangs
+        .iter()
+        .find_map(|shebang| contents.strip_prefix(shebang.as_slice()))
+    else {
+        // If it's not a Python shebang, we'll skip it
+        trace!(
+            "Skipping copy of entrypoint `{}`: does not start with expected shebang",
+            source.user_display()
+        );
+        return Ok(());
+    };
+
+    let contents = [shebang_line(python_executable).as_slice(), contents].concat();
+    let mode = fs_err::metadata(long_path(source))?.permissions().mode();
+    let mut file = fs_err::OpenOptions::new()
+        .create_new(true)
+        .write(true)
+        .mode(mode)
+        .open(long_path(target))?;
+    file.write_all(&contents)?;
+
+    trace!("Updated entrypoint at {}", target.user_display());
+    if let Some(fs_trace) = fs_trace {
+        fs_trace.record_from(OperationKind::Copy, target, source);
+    }
+
+    Ok(())
+}
+
+/// The shebang line for a Python executable, as written by the wheel installer.
+#[cfg(unix)]
+fn shebang_line(executable: &Path) -> Vec<u8> {
+    let mut shebang = uv_install_wheel::format_shebang(executable, "posix", false).into_bytes();
+    shebang.push(b'\n');
+    shebang
+}
+
+/// Create a copy of the entrypoint at `source` at `target`, if it's a Python script launcher,
+/// replacing the target Python executable with a new one.
+#[cfg(windows)]
+fn copy_entrypoint(
+    source: &Path,
+    target: &Path,
+    _previous_executable: &Path,
+    python_executable: &Path,
+    fs_trace: Option<&FsTrace>,
+) -> Result<(), CopyEntrypointError> {
+    use uv_trampoline_builder::Launcher;
+
+    let Some(launcher) = Launcher::try_from_path(&long_path(source))? else {
+        return Ok(());
+    };
+
+    let is_gui = launcher.python_path.ends_with("pythonw.exe");
+
+    let python_path = if is_gui {
+        python_executable.with_file_name("pythonw.exe")
+    } else {
+        python_executable.to_path_buf()
+    };
+
+    let launcher = launcher.with_python_path(python_path);
+    let mut file = fs_err::OpenOptions::new()
+        .create_new(true)
+        .write(true)
+        .open(long_path(target))?;
+    launcher.write_to_file(&mut file)?;
+
+    trace!("Updated entrypoint at {}", target.user_display());
+    if let Some(fs_trace) = fs_trace {
+        fs_trace.record_from(OperationKind::Copy, target, source);
+    }
+
+    Ok(())
+}
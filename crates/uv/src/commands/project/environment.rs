@@ -1,21 +1,238 @@
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
 
 use tracing::debug;
+use url::Url;
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::{resolution_markers, resolution_tags};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::run_summary::{OverlayStatus, RunSummary};
+use crate::commands::project::sync::do_sync;
 use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_environment, sync_environment,
 };
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, CacheEntry, Removal, rm_rf};
 use uv_cache_key::{cache_digest, hash_digest};
-use uv_configuration::{Concurrency, Constraints, Preview};
-use uv_distribution_types::{Name, Resolution};
-use uv_fs::PythonExt;
+use uv_configuration::{
+    Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, EditableMode,
+    ExtrasSpecification, HashCheckingMode, InstallOptions, Preview,
+};
+use uv_distribution_types::{HashGeneration, Name, Resolution};
+use uv_fs::{LockedFile, PythonExt};
+use uv_installer::SitePackages;
+use uv_normalize::DefaultExtras;
 use uv_python::{Interpreter, PythonEnvironment, canonicalize_executable};
+use uv_resolver::Installable;
+use uv_types::HashStrategy;
+use uv_workspace::WorkspaceCache;
+
+/// A temporary directory in the cache for an ephemeral environment.
+///
+/// Each directory is registered with a marker file that is locked by the owning process for as
+/// long as the directory exists. If the process is killed before it can
+/// remove the directory, the operating system releases the lock, and the directory is removed
+/// by the next cleanup pass (see [`prune_ephemeral`]).
+#[derive(Debug)]
+pub(crate) struct EphemeralDir {
+    dir: Option<tempfile::TempDir>,
+    marker: Option<(PathBuf, LockedFile)>,
+}
+
+impl EphemeralDir {
+    /// Create a new registered temporary directory in the cache.
+    pub(crate) fn new(cache: &Cache) -> io::Result<Self> {
+        // Opportunistically remove any environments leaked by killed processes, once per process.
+        static CLEANUP: Once = Once::new();
+        CLEANUP.call_once(|| match prune_ephemeral(cache) {
+            Ok(summary) if summary.num_dirs > 0 => {
+                debug!(
+                    "Removed {} leaked ephemeral environment(s)",
+                    summary.num_dirs
+                );
+            }
+            Ok(_) => {}
+            Err(err) => debug!("Failed to remove leaked ephemeral environments: {err}"),
+        });
+
+        let dir = cache.venv_dir()?;
+
+        // Register the directory. Directories without a marker are never removed by the cleanup
+        // pass, and the marker is locked before it's moved into the registry, so there's no window
+        // in which a concurrent cleanup could remove this directory.
+        let registry = ephemeral_registry(cache);
+        fs_err::create_dir_all(&registry)?;
+        let marker = registry.join(
+            dir.path()
+                .file_name()
+                .expect("Temporary directories have a file name"),
+        );
+        let lock = LockedFile::create_locked(&marker, dir.path().display())?;
+
+        Ok(Self {
+            dir: Some(dir),
+            marker: Some((marker, lock)),
+        })
+    }
+
+    /// Returns the path to the directory.
+    pub(crate) fn path(&self) -> &Path {
+        self.dir
+            .as_ref()
+            .expect("Ephemeral directory is present until dropped")
+            .path()
+    }
+
+    /// Persist the directory, such that it's no longer removed on drop or by the cleanup pass.
+    pub(crate) fn keep(mut self) -> PathBuf {
+        let path = self
+            .dir
+            .take()
+            .expect("Ephemeral directory is present until dropped")
+            .keep();
+        self.deregister();
+        path
+    }
+
+    /// Persist the directory after the command that used it failed, such that it can be
+    /// inspected, and register it for removal by `uv cache prune`.
+    ///
+    /// The directory is kept in place, rather than moved, as virtual environments embed their
+    /// absolute path, e.g., in entrypoints and activation scripts. The registration records the
+    /// command that failed.
+    pub(crate) fn keep_for_inspection(self, cache: &Cache, command: &str) -> io::Result<PathBuf> {
+        let path = self.keep();
+        let registry = kept_registry(cache);
+        fs_err::create_dir_all(&registry)?;
+        fs_err::write(
+            registry.join(
+                path.file_name()
+                    .expect("Temporary directories have a file name"),
+            ),
+            command,
+        )?;
+        debug!(
+            "Keeping ephemeral environment for inspection: {}",
+            path.display()
+        );
+        Ok(path)
+    }
+
+    /// Hand the directory over to a program that replaces the current process, e.g., with `exec`.
+    ///
+    /// The program inherits the lock on the marker, such that the cleanup pass doesn't remove the
+    /// directory while the program is running. As no process remains to remove the directory once
+    /// the program exits, the directory is leaked until the next cleanup pass.
+    #[cfg(unix)]
+    pub(crate) fn inherit_on_exec(&self) -> io::Result<()> {
+        if let Some((_, lock)) = &self.marker {
+            lock.inherit_on_exec()?;
+        }
+        Ok(())
+    }
+
+    /// Remove the marker for the directory.
+    fn deregister(&mut self) {
+        if let Some((marker, lock)) = self.marker.take() {
+            // Release the lock before removal, as locked files can't be removed on Windows.
+            drop(lock);
+            match fs_err::remove_file(&marker) {
+                Ok(()) => {}
+                // A concurrent cleanup pass may have removed the marker already.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => debug!("Failed to remove ephemeral environment marker: {err}"),
+            }
+        }
+    }
+}
+
+impl Drop for EphemeralDir {
+    fn drop(&mut self) {
+        // Remove the directory before its marker, such that a cleanup pass never observes an
+        // unregistered directory.
+        drop(self.dir.take());
+        self.deregister();
+    }
+}
+
+/// Returns the directory containing the markers for registered [`EphemeralDir`]s.
+fn ephemeral_registry(cache: &Cache) -> PathBuf {
+    cache.bucket(CacheBucket::Builds).join(".ephemeral")
+}
+
+/// Returns the directory containing the markers for [`EphemeralDir`]s kept after a failure.
+fn kept_registry(cache: &Cache) -> PathBuf {
+    cache.bucket(CacheBucket::Builds).join(".kept")
+}
+
+/// Remove any ephemeral environments that were kept for inspection after a failure, e.g., with
+/// `uv run --keep-env-on-failure`.
+pub(crate) fn prune_kept(cache: &Cache) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+
+    let entries = match fs_err::read_dir(kept_registry(cache)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let dir = cache.bucket(CacheBucket::Builds).join(entry.file_name());
+        if dir.exists() {
+            debug!("Removing kept ephemeral environment: {}", dir.display());
+            summary += rm_rf(&dir)?;
+        }
+        match fs_err::remove_file(entry.path()) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Remove any ephemeral environments whose owning process is no longer running.
+pub(crate) fn prune_ephemeral(cache: &Cache) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+
+    let entries = match fs_err::read_dir(ephemeral_registry(cache)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(summary),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let marker = entry.path();
+
+        // If the marker is locked, the owning process is still running.
+        let Some(lock) = LockedFile::try_acquire(&marker, marker.display())? else {
+            continue;
+        };
+
+        let dir = cache.bucket(CacheBucket::Builds).join(entry.file_name());
+        if dir.exists() {
+            debug!("Removing leaked ephemeral environment: {}", dir.display());
+            summary += rm_rf(&dir)?;
+        }
+
+        drop(lock);
+        match fs_err::remove_file(&marker) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(summary)
+}
 
 /// An ephemeral [`PythonEnvironment`] for running an individual command.
 #[derive(Debug)]
@@ -34,17 +251,17 @@ impl From<EphemeralEnvironment> for PythonEnvironment {
 }
 
 impl EphemeralEnvironment {
-    /// Set the ephemeral overlay for a Python environment.
+    /// Set the ephemeral overlay for a Python environment, returning the path to the overlay.
     #[allow(clippy::result_large_err)]
-    pub(crate) fn set_overlay(&self, contents: impl AsRef<[u8]>) -> Result<(), ProjectError> {
+    pub(crate) fn set_overlay(&self, contents: impl AsRef<[u8]>) -> Result<PathBuf, ProjectError> {
         let site_packages = self
             .0
             .site_packages()
             .next()
             .ok_or(ProjectError::NoSitePackages)?;
         let overlay_path = site_packages.join("_uv_ephemeral_overlay.pth");
-        fs_err::write(overlay_path, contents)?;
-        Ok(())
+        fs_err::write(&overlay_path, contents)?;
+        Ok(overlay_path)
     }
 
     /// Enable system site packages for a Python environment.
@@ -55,6 +272,13 @@ impl EphemeralEnvironment {
         Ok(())
     }
 
+    /// Expose the user site packages directory in a Python environment.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn set_user_site(&self) -> Result<(), ProjectError> {
+        uv_virtualenv::enable_user_site(&self.0)?;
+        Ok(())
+    }
+
     /// Set the `extends-environment` key in the `pyvenv.cfg` file to the given path.
     ///
     /// Ephemeral environments created by `uv run --with` extend a parent (virtual or system)
@@ -106,15 +330,26 @@ impl From<CachedEnvironment> for PythonEnvironment {
 
 impl CachedEnvironment {
     /// Get or create an [`CachedEnvironment`] based on a given set of requirements.
+    ///
+    /// If `require_hashes` is set, the hashes of each distribution are sourced from the index
+    /// during resolution and verified during installation; the resolution fails if a distribution
+    /// doesn't provide any hashes.
+    ///
+    /// If the environment is layered on top of a base environment, `base` should contain the
+    /// packages installed in the base environment, such that the cached environment is rebuilt
+    /// when they change.
     pub(crate) async fn from_spec(
         spec: EnvironmentSpecification<'_>,
         build_constraints: Constraints,
+        require_hashes: bool,
+        base: Option<&SitePackages>,
         interpreter: &Interpreter,
         settings: &ResolverInstallerSettings,
         network_settings: &NetworkSettings,
         state: &PlatformState,
         resolve: Box<dyn ResolveLogger>,
         install: Box<dyn InstallLogger>,
+        mut summary: Option<&mut RunSummary>,
         installer_metadata: bool,
         concurrency: Concurrency,
         cache: &Cache,
@@ -123,12 +358,20 @@ impl CachedEnvironment {
     ) -> Result<Self, ProjectError> {
         let interpreter = Self::base_interpreter(interpreter, cache)?;
 
+        // When requiring hashes, record the hashes provided by the index during resolution.
+        let hasher = if require_hashes {
+            HashStrategy::Generate(HashGeneration::Url)
+        } else {
+            HashStrategy::default()
+        };
+
         // Resolve the requirements with the interpreter.
         let resolution = Resolution::from(
             resolve_environment(
                 spec,
                 &interpreter,
                 build_constraints.clone(),
+                &hasher,
                 &settings.resolver,
                 network_settings,
                 state,
@@ -141,17 +384,129 @@ impl CachedEnvironment {
             .await?,
         );
 
-        // Hash the resolution by hashing the generated lockfile.
-        // TODO(charlie): If the resolution contains any mutable metadata (like a path or URL
-        // dependency), skip this step.
-        let resolution_hash = {
-            let mut distributions = resolution.distributions().collect::<Vec<_>>();
-            distributions.sort_unstable_by_key(|dist| dist.name());
-            hash_digest(&distributions)
+        let resolution_hash = resolution_digest(&resolution, require_hashes, base);
+
+        // Verify the recorded hashes during installation, failing if any are missing.
+        let hasher = if require_hashes {
+            HashStrategy::from_resolution(&resolution, HashCheckingMode::Require)?
+        } else {
+            HashStrategy::default()
         };
 
-        // Construct a hash for the environment.
-        //
+        let cache_entry = Self::cache_entry(&interpreter, &resolution_hash, cache)?;
+        if let Some(environment) = Self::find(&cache_entry, cache, summary.as_deref_mut()) {
+            return Ok(environment);
+        }
+
+        // Create the environment in the cache, then relocate it to its content-addressed location.
+        let temp_dir = EphemeralDir::new(cache)?;
+        let venv = Self::create_venv(&temp_dir, interpreter, preview)?;
+
+        sync_environment(
+            venv,
+            &resolution,
+            Modifications::Exact,
+            build_constraints,
+            &hasher,
+            settings.into(),
+            network_settings,
+            state,
+            install,
+            summary,
+            installer_metadata,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+
+        Self::persist(temp_dir, &cache_entry, cache).await
+    }
+
+    /// Get or create a [`CachedEnvironment`] with the given dependency groups installed from the
+    /// lockfile of the install target.
+    ///
+    /// Only the packages required by the groups are installed, at their locked versions, such that
+    /// the environment can be layered on top of a base environment whose packages are given by
+    /// `base`.
+    pub(crate) async fn from_lock(
+        target: InstallTarget<'_>,
+        groups: &DependencyGroupsWithDefaults,
+        editable: EditableMode,
+        base: &SitePackages,
+        interpreter: &Interpreter,
+        settings: &ResolverInstallerSettings,
+        network_settings: &NetworkSettings,
+        state: &PlatformState,
+        install: Box<dyn InstallLogger>,
+        mut summary: Option<&mut RunSummary>,
+        installer_metadata: bool,
+        concurrency: Concurrency,
+        cache: &Cache,
+        workspace_cache: WorkspaceCache,
+        printer: Printer,
+        preview: Preview,
+    ) -> Result<Self, ProjectError> {
+        let interpreter = Self::base_interpreter(interpreter, cache)?;
+        let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
+        let install_options = InstallOptions::default();
+
+        // Select the packages to install from the lockfile, to key the environment.
+        let resolution = target.to_resolution(
+            &resolution_markers(None, None, &interpreter),
+            &resolution_tags(None, None, &interpreter)?,
+            &extras,
+            groups,
+            &settings.resolver.build_options,
+            &install_options,
+        )?;
+        let resolution_hash = resolution_digest(&resolution, false, Some(base));
+
+        let cache_entry = Self::cache_entry(&interpreter, &resolution_hash, cache)?;
+        if let Some(environment) = Self::find(&cache_entry, cache, summary.as_deref_mut()) {
+            return Ok(environment);
+        }
+
+        // Create the environment in the cache, then relocate it to its content-addressed location.
+        let temp_dir = EphemeralDir::new(cache)?;
+        let venv = Self::create_venv(&temp_dir, interpreter, preview)?;
+
+        do_sync(
+            target,
+            &venv,
+            &extras,
+            groups,
+            editable,
+            install_options,
+            Modifications::Exact,
+            None,
+            false,
+            None,
+            settings.into(),
+            network_settings,
+            state,
+            install,
+            summary,
+            installer_metadata,
+            concurrency,
+            cache,
+            workspace_cache,
+            DryRun::Disabled,
+            printer,
+            preview,
+        )
+        .await?;
+
+        Self::persist(temp_dir, &cache_entry, cache).await
+    }
+
+    /// Return the content-addressed cache entry for an environment with the given resolution.
+    fn cache_entry(
+        interpreter: &Interpreter,
+        resolution_hash: &str,
+        cache: &Cache,
+    ) -> Result<CacheEntry, ProjectError> {
         // Use the canonicalized base interpreter path since that's the interpreter we performed the
         // resolution with and the interpreter the environment will be created with.
         //
@@ -167,20 +522,39 @@ impl CachedEnvironment {
         let interpreter_hash =
             cache_digest(&canonicalize_executable(interpreter.sys_executable())?);
 
-        // Search in the content-addressed cache.
-        let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
+        Ok(cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash))
+    }
 
+    /// Return the environment at the given cache entry, if it exists, recording the cache hit or
+    /// miss in the `summary`.
+    fn find(
+        cache_entry: &CacheEntry,
+        cache: &Cache,
+        summary: Option<&mut RunSummary>,
+    ) -> Option<Self> {
         if cache.refresh().is_none() {
             if let Ok(root) = cache.resolve_link(cache_entry.path()) {
                 if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
-                    return Ok(Self(environment));
+                    if let Some(summary) = summary {
+                        summary.record_overlay(OverlayStatus::Hit);
+                    }
+                    return Some(Self(environment));
                 }
             }
         }
+        if let Some(summary) = summary {
+            summary.record_overlay(OverlayStatus::Miss);
+        }
+        None
+    }
 
-        // Create the environment in the cache, then relocate it to its content-addressed location.
-        let temp_dir = cache.venv_dir()?;
-        let venv = uv_virtualenv::create_venv(
+    /// Create an empty environment in the given temporary directory.
+    fn create_venv(
+        temp_dir: &EphemeralDir,
+        interpreter: Interpreter,
+        preview: Preview,
+    ) -> Result<PythonEnvironment, ProjectError> {
+        Ok(uv_virtualenv::create_venv(
             temp_dir.path(),
             interpreter,
             uv_virtualenv::Prompt::None,
@@ -190,26 +564,15 @@ impl CachedEnvironment {
             false,
             false,
             preview,
-        )?;
-
-        sync_environment(
-            venv,
-            &resolution,
-            Modifications::Exact,
-            build_constraints,
-            settings.into(),
-            network_settings,
-            state,
-            install,
-            installer_metadata,
-            concurrency,
-            cache,
-            printer,
-            preview,
-        )
-        .await?;
+        )?)
+    }
 
-        // Now that the environment is complete, sync it to its content-addressed location.
+    /// Move the complete environment in the temporary directory to its content-addressed location.
+    async fn persist(
+        temp_dir: EphemeralDir,
+        cache_entry: &CacheEntry,
+        cache: &Cache,
+    ) -> Result<Self, ProjectError> {
         let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
         let root = cache.archive(&id);
 
@@ -246,3 +609,53 @@ impl CachedEnvironment {
         }
     }
 }
+
+/// Compute a digest of a resolution, to key the cached environment in which it's installed.
+///
+/// If the environment is layered on top of a base environment, the digest includes the packages
+/// installed in the base environment, as the environment shadows them.
+fn resolution_digest(
+    resolution: &Resolution,
+    require_hashes: bool,
+    base: Option<&SitePackages>,
+) -> String {
+    // Hash the resolution by hashing the generated lockfile.
+    // TODO(charlie): If the resolution contains any mutable metadata (like a path or URL
+    // dependency), skip this step.
+    let resolution_hash = {
+        let mut distributions = resolution.distributions().collect::<Vec<_>>();
+        distributions.sort_unstable_by_key(|dist| dist.name());
+        // Environments installed with verified hashes are cached separately, such that an
+        // environment installed without verification is never reused.
+        if require_hashes {
+            hash_digest(&(distributions, "require-hashes"))
+        } else {
+            hash_digest(&distributions)
+        }
+    };
+
+    // Key the environment on the packages installed in the base environment, if any. The
+    // environment shadows the base environment's packages when layered on top of it, so an
+    // environment built against a different set of base packages may conflict with them.
+    if let Some(base) = base {
+        hash_digest(&(resolution_hash, base_digest(base)))
+    } else {
+        resolution_hash
+    }
+}
+
+/// Compute a digest of the packages installed in a base environment.
+fn base_digest(site_packages: &SitePackages) -> String {
+    let mut packages = site_packages
+        .iter()
+        .map(|dist| {
+            (
+                dist.name(),
+                dist.version(),
+                dist.as_editable().map(Url::as_str),
+            )
+        })
+        .collect::<Vec<_>>();
+    packages.sort_unstable();
+    hash_digest(&packages)
+}
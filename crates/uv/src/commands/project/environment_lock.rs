@@ -0,0 +1,154 @@
+//! Coordination between concurrent uv processes that sync the same environment.
+//!
+//! The process that holds the environment lock records its PID in the lockfile, such that processes
+//! waiting for the lock can report which process they're waiting on. Once a sync completes, the
+//! holder records a digest of the sync in the environment, such that a process that waited for it
+//! can skip its own sync if it would be identical.
+
+use std::fmt::{Debug, Write};
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use uv_cache_key::hash_digest;
+use uv_fs::LockedFile;
+use uv_python::PythonEnvironment;
+use uv_resolver::Lock;
+
+use crate::printer::Printer;
+
+/// The name of the file in the environment that records the most recent sync.
+const SYNC_MARKER: &str = "uv-sync.json";
+
+/// The most recent sync of an environment, as recorded by the process that performed it.
+#[derive(Debug, Serialize, Deserialize)]
+struct SyncMarker {
+    /// The PID of the process that performed the sync.
+    pid: u32,
+    /// The digest of the sync, as computed by [`EnvironmentLock::digest`].
+    digest: String,
+}
+
+/// A cross-process lock on an environment, held for the duration of a sync.
+#[derive(Debug)]
+pub(crate) struct EnvironmentLock {
+    /// The underlying file lock, if it could be acquired.
+    lock: Option<LockedFile>,
+    /// The root of the environment.
+    root: PathBuf,
+    /// The PID of the process that held the lock while this process waited for it, if any.
+    waited_for: Option<u32>,
+}
+
+impl EnvironmentLock {
+    /// Acquire the lock for the environment, reporting the process that holds it, if any, while
+    /// waiting.
+    ///
+    /// Failure to acquire the lock is not fatal, as with [`PythonEnvironment::lock`].
+    pub(crate) async fn acquire(
+        environment: &PythonEnvironment,
+        printer: Printer,
+    ) -> anyhow::Result<Self> {
+        let (path, resource) = environment.lock_path();
+
+        let (lock, waited_for) = match LockedFile::try_acquire(&path, &resource) {
+            Ok(Some(lock)) => (Some(lock), None),
+            Ok(None) => {
+                // The holder records its PID in the lockfile, but the file may be unreadable
+                // while it's locked (e.g., on Windows), or held by a process that doesn't record
+                // its PID (e.g., `uv pip install`).
+                let holder = fs_err::read_to_string(&path)
+                    .ok()
+                    .and_then(|contents| contents.trim().parse::<u32>().ok());
+                if let Some(pid) = holder {
+                    writeln!(
+                        printer.stderr(),
+                        "Waiting for another uv process (PID {pid}) to finish syncing the environment..."
+                    )?;
+                } else {
+                    writeln!(
+                        printer.stderr(),
+                        "Waiting for another uv process to release the environment lock..."
+                    )?;
+                }
+                match LockedFile::acquire(&path, &resource).await {
+                    Ok(lock) => (Some(lock), holder),
+                    Err(err) => {
+                        warn!("Failed to acquire environment lock: {err}");
+                        (None, None)
+                    }
+                }
+            }
+            Err(err) => {
+                warn!("Failed to acquire environment lock: {err}");
+                (None, None)
+            }
+        };
+
+        if let Some(lock) = &lock {
+            if let Err(err) = lock.write_contents(std::process::id().to_string()) {
+                debug!("Failed to record PID in environment lock: {err}");
+            }
+        }
+
+        Ok(Self {
+            lock,
+            root: environment.root().to_path_buf(),
+            waited_for,
+        })
+    }
+
+    /// Compute a digest identifying a sync of the environment to the given lockfile, with the
+    /// given options (e.g., the extras and dependency groups to install).
+    pub(crate) fn digest(lock: &Lock, options: &impl Debug) -> Option<String> {
+        let lock = lock.to_toml().ok()?;
+        Some(hash_digest(&(lock, format!("{options:?}"))))
+    }
+
+    /// Returns `true` if the process this process waited on synced the environment with the given
+    /// digest, such that the sync can be skipped.
+    pub(crate) fn is_synced(&self, digest: &str) -> bool {
+        let Some(pid) = self.waited_for else {
+            return false;
+        };
+        let Ok(contents) = fs_err::read_to_string(self.root.join(SYNC_MARKER)) else {
+            return false;
+        };
+        let Ok(marker) = serde_json::from_str::<SyncMarker>(&contents) else {
+            return false;
+        };
+        marker.pid == pid && marker.digest == digest
+    }
+
+    /// Record a completed sync of the environment with the given digest.
+    pub(crate) fn record_sync(&self, digest: String) {
+        // Without the lock, another process could be modifying the environment concurrently.
+        if self.lock.is_none() {
+            return;
+        }
+        let marker = SyncMarker {
+            pid: std::process::id(),
+            digest,
+        };
+        let result = serde_json::to_string(&marker)
+            .map_err(io::Error::other)
+            .and_then(|contents| fs_err::write(self.root.join(SYNC_MARKER), contents));
+        if let Err(err) = result {
+            debug!("Failed to record environment sync: {err}");
+        }
+    }
+}
+
+impl Drop for EnvironmentLock {
+    fn drop(&mut self) {
+        // Clear the PID before releasing the lock, such that no waiter reports a process that no
+        // longer holds it.
+        if let Some(lock) = self.lock.take() {
+            if let Err(err) = lock.write_contents("") {
+                debug!("Failed to clear PID in environment lock: {err}");
+            }
+        }
+    }
+}
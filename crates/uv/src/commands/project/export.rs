@@ -1,5 +1,6 @@
 use std::env;
 use std::ffi::OsStr;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, anyhow};
@@ -11,18 +12,21 @@ use uv_configuration::{
     Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
     Preview,
 };
+use uv_fs::Simplified;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport};
+use uv_resolver::{Lock, PylockToml, RequirementsTxtExport, ScriptExport};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, default_dependency_groups,
     detect_conflicts,
@@ -69,6 +73,7 @@ pub(crate) async fn export(
     include_annotations: bool,
     include_header: bool,
     script: Option<Pep723Script>,
+    to_script: Option<PathBuf>,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
@@ -133,6 +138,7 @@ pub(crate) async fn export(
         Some(match &target {
             ExportTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
@@ -141,6 +147,7 @@ pub(crate) async fn export(
                 no_config,
                 false,
                 Some(false),
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -159,6 +166,7 @@ pub(crate) async fn export(
                 false,
                 no_config,
                 Some(false),
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -204,7 +212,7 @@ pub(crate) async fn export(
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
@@ -266,6 +274,12 @@ pub(crate) async fn export(
     target.validate_extras(&extras)?;
     target.validate_groups(&groups)?;
 
+    // If requested, write the pinned dependencies to the inline metadata of a script.
+    if let Some(to_script) = to_script {
+        let export = ScriptExport::from_lock(&target, &prune, &extras, &groups, &install_options)?;
+        return write_script(&to_script, &export, &lock, printer).await;
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file.as_deref());
 
@@ -356,6 +370,58 @@ pub(crate) async fn export(
     Ok(ExitStatus::Success)
 }
 
+/// Replace the `dependencies` in the inline metadata of the script at the given path with the
+/// exported requirements, creating the metadata if the script doesn't have any.
+async fn write_script(
+    path: &Path,
+    export: &ScriptExport,
+    lock: &Lock,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let script = match Pep723Script::read(path).await? {
+        Some(script) => script,
+        None => Pep723Script::init(path, lock.requires_python().specifiers()).await?,
+    };
+
+    let mut toml = PyProjectTomlMut::from_toml(&script.metadata.raw, DependencyTarget::Script)?;
+
+    // Remove the existing dependencies, such that any that are no longer required are dropped.
+    for requirement in script.metadata.dependencies.iter().flatten() {
+        toml.remove_dependency(&requirement.name)?;
+    }
+    for requirement in export.requirements() {
+        toml.add_dependency(requirement, None, false)?;
+    }
+
+    let content = toml.to_string();
+    if content == script.metadata.raw {
+        writeln!(
+            printer.stderr(),
+            "The dependencies of `{}` are up-to-date",
+            path.user_display().cyan()
+        )?;
+    } else {
+        script.write(&content)?;
+        let count = export.requirements().len();
+        writeln!(
+            printer.stderr(),
+            "Exported {} to `{}`",
+            format!(
+                "{count} {}",
+                if count == 1 {
+                    "dependency"
+                } else {
+                    "dependencies"
+                }
+            )
+            .bold(),
+            path.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd() -> String {
     let args = env::args_os()
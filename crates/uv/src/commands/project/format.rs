@@ -10,7 +10,7 @@ use uv_configuration::{Preview, PreviewFeatures};
 use uv_pep440::Version;
 use uv_warnings::warn_user;
 
-use crate::child::run_to_completion;
+use crate::child::{SignalOptions, run_to_completion};
 use crate::commands::ExitStatus;
 use crate::commands::reporters::BinaryDownloadReporter;
 use crate::printer::Printer;
@@ -66,5 +66,5 @@ pub(crate) async fn format(
     command.args(extra_args.iter());
 
     let handle = command.spawn().context("Failed to spawn `ruff format`")?;
-    run_to_completion(handle).await
+    run_to_completion(handle, SignalOptions::default()).await
 }
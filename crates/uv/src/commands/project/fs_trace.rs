@@ -0,0 +1,135 @@
+//! A trace of the file system operations performed while composing the environment for
+//! `uv run`, enabled via `uv run --fs-trace`.
+//!
+//! Each invocation creates its own [`FsTrace`], which is passed to the lockfile and environment
+//! helpers that perform the traced operations.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use uv_fs::Simplified;
+
+/// The kind of a traced file system operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum OperationKind {
+    /// A file was read, e.g., a lockfile.
+    Read,
+    /// A file or directory was created.
+    Create,
+    /// An existing file was modified.
+    Modify,
+    /// A file or directory was copied (or cloned) from `source`.
+    Copy,
+    /// A symlink to `source` was created.
+    Symlink,
+}
+
+/// A traced file system operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Operation {
+    operation: OperationKind,
+    path: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<PathBuf>,
+}
+
+/// The serialized trace, as written to the `--fs-trace` file.
+#[derive(Debug, Serialize)]
+struct Trace<'a> {
+    operations: &'a [Operation],
+}
+
+/// A recorder for the file system operations of a single `uv run` invocation.
+#[derive(Debug, Default)]
+pub(crate) struct FsTrace {
+    operations: Mutex<Vec<Operation>>,
+}
+
+impl FsTrace {
+    /// Record an operation on `path`.
+    pub(crate) fn record(&self, operation: OperationKind, path: impl AsRef<Path>) {
+        self.push(operation, path.as_ref(), None);
+    }
+
+    /// Record an operation on `path` that derives from `source`, e.g., a copy or a symlink.
+    pub(crate) fn record_from(
+        &self,
+        operation: OperationKind,
+        path: impl AsRef<Path>,
+        source: impl AsRef<Path>,
+    ) {
+        self.push(operation, path.as_ref(), Some(source.as_ref()));
+    }
+
+    /// Record the creation of every file, directory, and symlink under `root`.
+    ///
+    /// Used for environments created in a single step (e.g., via `uv_virtualenv::create_venv`),
+    /// where the individual writes are not instrumented.
+    pub(crate) fn record_tree(&self, root: impl AsRef<Path>) {
+        for entry in WalkDir::new(root.as_ref()).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    debug!("Failed to trace directory entry: {err}");
+                    continue;
+                }
+            };
+            if entry.path_is_symlink() {
+                match fs_err::read_link(entry.path()) {
+                    Ok(source) => self.push(OperationKind::Symlink, entry.path(), Some(&source)),
+                    Err(err) => debug!("Failed to trace symlink: {err}"),
+                }
+            } else {
+                self.push(OperationKind::Create, entry.path(), None);
+            }
+        }
+    }
+
+    fn push(&self, operation: OperationKind, path: &Path, source: Option<&Path>) {
+        self.operations.lock().unwrap().push(Operation {
+            operation,
+            path: path.to_path_buf(),
+            source: source.map(Path::to_path_buf),
+        });
+    }
+
+    /// Write the recorded operations to `path` as JSON.
+    pub(crate) fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let operations = self.operations.lock().unwrap();
+        debug!(
+            "Writing {} traced operation(s) to: `{}`",
+            operations.len(),
+            path.user_display()
+        );
+        let contents = serde_json::to_string_pretty(&Trace {
+            operations: &operations,
+        })?;
+        fs_err::write(path, contents + "\n")?;
+        Ok(())
+    }
+}
+
+/// Returns the path of the trace for the given Python request when running for multiple Python
+/// versions, e.g., `trace.json.3.12` for `--fs-trace trace.json --python 3.11,3.12`.
+pub(crate) fn matrix_path(path: &Path, python: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(
+        python
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '@') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>(),
+    );
+    PathBuf::from(path)
+}
@@ -0,0 +1,177 @@
+//! Entries for project environments in the workspace's ignore files.
+//!
+//! With the `manage-ignore-files` setting, uv adds the project environment to the `.gitignore` and
+//! `.dockerignore` files in the workspace root. The entries are written to a block delimited by
+//! markers, such that uv can update them, and remove them once the environment is removed, without
+//! touching the rest of the file.
+
+use std::path::{Component, Path};
+
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+/// The ignore files to update, relative to the workspace root.
+const IGNORE_FILES: &[&str] = &[".gitignore", ".dockerignore"];
+
+/// The line that starts the block of entries managed by uv.
+const START: &str = "# Project environments, managed by uv (`manage-ignore-files`)";
+
+/// The line that ends the block of entries managed by uv.
+const END: &str = "# End of project environments";
+
+/// Add the environment at `environment` to the ignore files in `workspace_root`, and remove the
+/// entries for environments that no longer exist.
+///
+/// Ignore files that don't exist are not created, and environments outside of the workspace root
+/// are not added.
+pub(crate) fn update(workspace_root: &Path, environment: &Path) -> Result<()> {
+    let entry = entry(workspace_root, environment);
+
+    for name in IGNORE_FILES {
+        let path = workspace_root.join(name);
+        let contents = match fs_err::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err.into()),
+        };
+
+        let updated = render(&contents, workspace_root, entry.as_deref());
+        if updated == contents {
+            continue;
+        }
+
+        debug!("Updating project environments in `{}`", path.user_display());
+        uv_fs::write_atomic_sync(&path, updated)
+            .with_context(|| format!("Failed to update `{}`", path.user_display()))?;
+    }
+
+    Ok(())
+}
+
+/// Return the ignore entry for the environment, e.g., `/.venv`, if it's within the workspace root.
+fn entry(workspace_root: &Path, environment: &Path) -> Option<String> {
+    let relative = environment.strip_prefix(workspace_root).ok()?;
+    let mut entry = String::new();
+    for component in relative.components() {
+        let Component::Normal(component) = component else {
+            return None;
+        };
+        entry.push('/');
+        entry.push_str(component.to_str()?);
+    }
+    (!entry.is_empty()).then_some(entry)
+}
+
+/// Render the contents of an ignore file with an up-to-date block of entries.
+///
+/// Existing entries are retained as long as the environment they refer to exists.
+fn render(contents: &str, workspace_root: &Path, entry: Option<&str>) -> String {
+    let mut before = Vec::new();
+    let mut entries = Vec::new();
+    let mut after = Vec::new();
+    let mut position = 0;
+    for line in contents.lines() {
+        match (position, line.trim_end()) {
+            (0, START) => position = 1,
+            (1, END) => position = 2,
+            (0, _) => before.push(line),
+            (1, line) => entries.push(line.to_string()),
+            (_, _) => after.push(line),
+        }
+    }
+
+    // Drop the entries for environments that were removed.
+    entries.retain(|existing| {
+        Some(existing.as_str()) == entry
+            || (existing.starts_with('/')
+                && workspace_root
+                    .join(existing.trim_start_matches('/'))
+                    .join("pyvenv.cfg")
+                    .is_file())
+    });
+    if let Some(entry) = entry {
+        if !entries.iter().any(|existing| existing == entry) {
+            entries.push(entry.to_string());
+        }
+    }
+
+    let mut lines = before;
+    if !entries.is_empty() {
+        if position == 0 && lines.last().is_some_and(|line| !line.is_empty()) {
+            lines.push("");
+        }
+        lines.push(START);
+        lines.extend(entries.iter().map(String::as_str));
+        lines.push(END);
+    }
+    lines.extend(after);
+
+    // Don't leave a trailing blank line behind after removing the block.
+    if entries.is_empty() && position != 0 {
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+    }
+
+    if lines.is_empty() {
+        return String::new();
+    }
+    let mut rendered = lines.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{entry, render};
+
+    #[test]
+    fn entries() {
+        let root = Path::new("/project");
+        assert_eq!(
+            entry(root, Path::new("/project/.venv")),
+            Some("/.venv".to_string())
+        );
+        assert_eq!(
+            entry(root, Path::new("/project/envs/dev")),
+            Some("/envs/dev".to_string())
+        );
+        assert_eq!(entry(root, Path::new("/elsewhere/.venv")), None);
+        assert_eq!(entry(root, root), None);
+    }
+
+    #[test]
+    fn render_block() {
+        let root = Path::new("/nonexistent");
+
+        // The block is appended to the file.
+        let rendered = render("__pycache__/\n", root, Some("/.venv"));
+        insta::assert_snapshot!(rendered, @r"
+        __pycache__/
+
+        # Project environments, managed by uv (`manage-ignore-files`)
+        /.venv
+        # End of project environments
+        ");
+
+        // Rendering is idempotent.
+        assert_eq!(render(&rendered, root, Some("/.venv")), rendered);
+
+        // Entries for environments that don't exist are replaced.
+        let replaced = render(&rendered, root, Some("/.venv-dev"));
+        insta::assert_snapshot!(replaced, @r"
+        __pycache__/
+
+        # Project environments, managed by uv (`manage-ignore-files`)
+        /.venv-dev
+        # End of project environments
+        ");
+
+        // Without any entries, the block is removed.
+        assert_eq!(render(&rendered, root, None), "__pycache__/\n");
+    }
+}
@@ -438,6 +438,10 @@ async fn init_project(
                         install_mirrors.python_install_mirror.as_deref(),
                         install_mirrors.pypy_install_mirror.as_deref(),
                         install_mirrors.python_downloads_json_url.as_deref(),
+                        install_mirrors.python_install_dir.as_deref(),
+                        install_mirrors.install_staging_dir.as_deref(),
+                        &install_mirrors.download_client(),
+                        install_mirrors.python_resolution.unwrap_or_default(),
                         preview,
                     )
                     .await?
@@ -466,6 +470,10 @@ async fn init_project(
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors.python_install_dir.as_deref(),
+                    install_mirrors.install_staging_dir.as_deref(),
+                    &install_mirrors.download_client(),
+                    install_mirrors.python_resolution.unwrap_or_default(),
                     preview,
                 )
                 .await?
@@ -533,6 +541,10 @@ async fn init_project(
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
+                install_mirrors.python_install_dir.as_deref(),
+                install_mirrors.install_staging_dir.as_deref(),
+                &install_mirrors.download_client(),
+                install_mirrors.python_resolution.unwrap_or_default(),
                 preview,
             )
             .await?
@@ -561,6 +573,10 @@ async fn init_project(
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors.python_install_dir.as_deref(),
+            install_mirrors.install_staging_dir.as_deref(),
+            &install_mirrors.download_client(),
+            install_mirrors.python_resolution.unwrap_or_default(),
             preview,
         )
         .await?
@@ -278,9 +278,10 @@ impl<'lock> InstallTarget<'lock> {
                 for extra in extras.explicit_names() {
                     if !known_extras.contains(extra) {
                         return match self {
-                            Self::Project { .. } => {
-                                Err(ProjectError::MissingExtraProject(extra.clone()))
-                            }
+                            Self::Project { name, .. } => Err(ProjectError::MissingExtraProject(
+                                name.clone(),
+                                extra.clone(),
+                            )),
                             _ => Err(ProjectError::MissingExtraWorkspace(extra.clone())),
                         };
                     }
@@ -341,7 +342,7 @@ impl<'lock> InstallTarget<'lock> {
                     }
                 }
             }
-            Self::Project { lock, .. } => {
+            Self::Project { name, lock, .. } => {
                 let roots = self.roots().collect::<FxHashSet<_>>();
                 let member_packages: Vec<&Package> = lock
                     .packages()
@@ -357,7 +358,10 @@ impl<'lock> InstallTarget<'lock> {
 
                 for group in groups.explicit_names() {
                     if !known_groups.contains(group) {
-                        return Err(ProjectError::MissingGroupProject(group.clone()));
+                        return Err(ProjectError::MissingGroupProject(
+                            name.clone(),
+                            group.clone(),
+                        ));
                     }
                 }
             }
@@ -0,0 +1,156 @@
+//! Jupyter kernel specs for `uv run --kernel`.
+//!
+//! Rather than pointing at a fixed interpreter, the registered kernel invokes `uv run` when it's
+//! launched, such that the project environment is synced and any `--with` requirements are layered
+//! on top of it, as they would be for any other command.
+
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt::Write;
+
+use anyhow::{Context, bail};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::RunArgs;
+use uv_fs::{CWD, Simplified};
+use uv_static::EnvVars;
+
+use crate::printer::Printer;
+
+/// A Jupyter kernel that runs in a `uv run` environment.
+#[derive(Debug, Clone)]
+pub(crate) struct Kernel {
+    /// The name of the kernel, i.e., the name of its directory in the Jupyter data directory.
+    name: String,
+    /// The arguments to `uv run` that launch the kernel.
+    args: Vec<String>,
+}
+
+impl Kernel {
+    /// The requirement that provides the kernel.
+    pub(crate) const REQUIREMENT: &'static str = "ipykernel";
+
+    /// Create a [`Kernel`] from the `uv run` arguments, if `--kernel` was provided.
+    ///
+    /// The kernel re-invokes uv with the same command-line arguments, `argv`, other than
+    /// `--kernel` itself. Since Jupyter launches kernels from the directory of the notebook, the
+    /// kernel is launched with `--directory` set to the current directory, such that relative
+    /// paths in the arguments (e.g., in `--with` or `--python`) resolve as they did when the kernel
+    /// was registered.
+    pub(crate) fn from_args(
+        args: &RunArgs,
+        argv: impl IntoIterator<Item = OsString>,
+    ) -> anyhow::Result<Option<Self>> {
+        let Some(name) = args.kernel.as_deref() else {
+            return Ok(None);
+        };
+
+        // Jupyter restricts kernel names to ASCII alphanumerics, `-`, `_`, and `.`, and treats
+        // them case-insensitively.
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            bail!(
+                "Invalid kernel name `{name}`: names may only contain ASCII letters, digits, `-`, `_`, and `.`"
+            );
+        }
+
+        let mut run = vec![
+            "--directory".to_string(),
+            CWD.simplified().display().to_string(),
+        ];
+        // Skip the executable, which is replaced with the path to uv when the kernel is
+        // registered.
+        let mut argv = argv
+            .into_iter()
+            .skip(1)
+            .map(|arg| arg.to_string_lossy().into_owned());
+        while let Some(arg) = argv.next() {
+            match arg.as_str() {
+                // Drop `--kernel`, and any `--directory`, which is superseded by the current
+                // directory, along with their values.
+                "--kernel" | "--directory" => {
+                    argv.next();
+                }
+                _ if arg.starts_with("--kernel=") || arg.starts_with("--directory=") => {}
+                _ => run.push(arg),
+            }
+        }
+        run.extend(
+            [
+                "--with",
+                Self::REQUIREMENT,
+                "--",
+                "python",
+                "-m",
+                "ipykernel_launcher",
+                "-f",
+                "{connection_file}",
+            ]
+            .map(ToString::to_string),
+        );
+
+        Ok(Some(Self {
+            name: name.to_ascii_lowercase(),
+            args: run,
+        }))
+    }
+
+    /// Write the kernel spec to the user's Jupyter data directory.
+    pub(crate) fn register(&self, printer: Printer) -> anyhow::Result<()> {
+        let Some(data_dir) = uv_dirs::jupyter_data_dir() else {
+            bail!(
+                "Unable to determine the Jupyter data directory; set `{}` to register the kernel",
+                EnvVars::JUPYTER_DATA_DIR
+            );
+        };
+        let kernel_dir = data_dir.join("kernels").join(&self.name);
+
+        let uv = std::env::current_exe().context("Failed to determine the path to uv")?;
+        let spec = KernelSpec {
+            argv: std::iter::once(uv.simplified().display().to_string())
+                .chain(self.args.iter().cloned())
+                .collect(),
+            display_name: &self.name,
+            language: "python",
+            // Progress bars would otherwise be written to the Jupyter server's logs when the
+            // kernel is launched.
+            env: BTreeMap::from([(EnvVars::UV_NO_PROGRESS, "1")]),
+            metadata: KernelMetadata { debugger: true },
+        };
+
+        fs_err::create_dir_all(&kernel_dir)?;
+        let path = kernel_dir.join("kernel.json");
+        uv_fs::write_atomic_sync(&path, serde_json::to_string_pretty(&spec)? + "\n")?;
+
+        writeln!(
+            printer.stderr(),
+            "Registered kernel `{}` at: {}",
+            self.name.cyan(),
+            path.user_display().cyan()
+        )?;
+
+        Ok(())
+    }
+}
+
+/// The contents of a `kernel.json` file.
+///
+/// See: <https://jupyter-client.readthedocs.io/en/latest/kernels.html#kernel-specs>
+#[derive(Debug, Serialize)]
+struct KernelSpec<'a> {
+    argv: Vec<String>,
+    display_name: &'a str,
+    language: &'a str,
+    env: BTreeMap<&'a str, &'a str>,
+    metadata: KernelMetadata,
+}
+
+#[derive(Debug, Serialize)]
+struct KernelMetadata {
+    /// Whether the kernel supports the Jupyter debugger, as `ipykernel` does.
+    debugger: bool,
+}
@@ -23,13 +23,17 @@ use uv_distribution_types::{
 };
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::MarkerEnvironment;
 use uv_pypi_types::{ConflictKind, Conflicts, SupportedEnvironments};
-use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    Interpreter, PYTHON_VERSION_FILENAME, PythonDownloads, PythonEnvironment, PythonPreference,
+    PythonRequest, PythonVersionFile,
+};
 use uv_requirements::ExtrasResolver;
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_resolver::{
-    FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PythonRequirement,
+    FlatIndex, InMemoryIndex, Lock, LockedInterpreter, Options, OptionsBuilder, PythonRequirement,
     ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
@@ -39,9 +43,11 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
+use crate::commands::project::fs_trace::{FsTrace, OperationKind};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
-    ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
+    ProjectError, ProjectInterpreter, ScriptInterpreter, SynthesizedPython, UniversalState,
     init_script_python_requirement, script_extra_build_requires,
 };
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
@@ -91,6 +97,7 @@ pub(crate) async fn lock(
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
     no_config: bool,
+    warnings: WarningPolicy,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -138,11 +145,12 @@ pub(crate) async fn lock(
 
     // Determine the lock mode.
     let interpreter;
+    let mut synthesized = None;
     let mode = if frozen {
         LockMode::Frozen
     } else {
         interpreter = match target {
-            LockTarget::Workspace(workspace) => ProjectInterpreter::discover(
+            LockTarget::Workspace(workspace) => match ProjectInterpreter::discover(
                 workspace,
                 project_dir,
                 // Don't enable any groups' requires-python for interpreter discovery
@@ -155,14 +163,40 @@ pub(crate) async fn lock(
                 false,
                 no_config,
                 Some(false),
+                warnings,
                 cache,
                 printer,
                 preview,
             )
-            .await?
-            .into_interpreter(),
+            .await
+            {
+                Ok(interpreter) => interpreter.into_interpreter(),
+                // If the requested Python version isn't installed and can't be downloaded, lock
+                // against markers synthesized for that version.
+                Err(ProjectError::Python(err @ uv_python::Error::MissingPython(..)))
+                    if !python_downloads.is_automatic() =>
+                {
+                    let Some(synthesized_python) = SynthesizedPython::find(
+                        workspace,
+                        &DependencyGroupsWithDefaults::none(),
+                        python.as_deref().map(PythonRequest::parse).as_ref(),
+                        python_preference,
+                        install_mirrors.python_install_dir.as_deref(),
+                        cache,
+                        preview,
+                    )?
+                    else {
+                        return Err(ProjectError::Python(err).into());
+                    };
+                    let interpreter = synthesized_python.interpreter().clone();
+                    synthesized = Some(synthesized_python);
+                    interpreter
+                }
+                Err(err) => return Err(err.into()),
+            },
             LockTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
@@ -171,6 +205,7 @@ pub(crate) async fn lock(
                 false,
                 no_config,
                 Some(false),
+                warnings,
                 cache,
                 printer,
                 preview,
@@ -192,7 +227,7 @@ pub(crate) async fn lock(
     let state = UniversalState::default();
 
     // Perform the lock operation.
-    match LockOperation::new(
+    let mut operation = LockOperation::new(
         mode,
         &settings,
         &network_settings,
@@ -203,10 +238,11 @@ pub(crate) async fn lock(
         &workspace_cache,
         printer,
         preview,
-    )
-    .execute(target)
-    .await
-    {
+    );
+    if let Some(synthesized) = &synthesized {
+        operation = operation.with_markers(synthesized.markers());
+    }
+    match operation.execute(target).await {
         Ok(lock) => {
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
@@ -235,12 +271,12 @@ pub(crate) async fn lock(
             Ok(ExitStatus::Success)
         }
         Err(err @ ProjectError::LockMismatch(..)) => {
-            writeln!(printer.stderr(), "{}", err.to_string().bold())?;
+            diagnostics::project_error(&err, printer)?;
             Ok(ExitStatus::Failure)
         }
         Err(ProjectError::Operation(err)) => {
             diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
         }
         Err(err) => Err(err.into()),
@@ -257,12 +293,17 @@ pub(super) enum LockMode<'env> {
     Locked(&'env Interpreter),
     /// Use the existing lockfile without performing a resolution.
     Frozen,
+    /// Use the existing lockfile without performing a resolution, reading a lockfile with a newer
+    /// schema version in a read-only compatibility mode.
+    FrozenCompatible,
 }
 
 /// A lock operation.
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    markers: Option<&'env MarkerEnvironment>,
+    fs_trace: Option<&'env FsTrace>,
     settings: &'env ResolverSettings,
     network_settings: &'env NetworkSettings,
     state: &'env UniversalState,
@@ -291,6 +332,8 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            markers: None,
+            fs_trace: None,
             settings,
             network_settings,
             state,
@@ -313,8 +356,30 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Resolve against the given markers, rather than those of the interpreter, e.g., to lock for
+    /// a Python version that isn't available.
+    #[must_use]
+    pub(super) fn with_markers(mut self, markers: &'env MarkerEnvironment) -> Self {
+        self.markers = Some(markers);
+        self
+    }
+
+    /// Record the reads and writes of the lockfile in the given [`FsTrace`].
+    #[must_use]
+    pub(super) fn with_fs_trace(mut self, fs_trace: Option<&'env FsTrace>) -> Self {
+        self.fs_trace = fs_trace;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
+        let fs_trace = self.fs_trace;
+        let trace = |operation| {
+            if let Some(fs_trace) = fs_trace {
+                fs_trace.record(operation, target.lock_path());
+            }
+        };
+
         match self.mode {
             LockMode::Frozen => {
                 // Read the existing lockfile, but don't attempt to lock the project.
@@ -322,6 +387,18 @@ impl<'env> LockOperation<'env> {
                     .read()
                     .await?
                     .ok_or_else(|| ProjectError::MissingLockfile)?;
+                trace(OperationKind::Read);
+                Ok(LockResult::Unchanged(existing))
+            }
+            LockMode::FrozenCompatible => {
+                // Read the existing lockfile, but don't attempt to lock the project. Since the
+                // lockfile is never written, a lockfile with a newer schema version can be used
+                // in a read-only compatibility mode.
+                let existing = target
+                    .read_compatible()
+                    .await?
+                    .ok_or_else(|| ProjectError::MissingLockfile)?;
+                trace(OperationKind::Read);
                 Ok(LockResult::Unchanged(existing))
             }
             LockMode::Locked(interpreter) => {
@@ -330,11 +407,13 @@ impl<'env> LockOperation<'env> {
                     .read()
                     .await?
                     .ok_or_else(|| ProjectError::MissingLockfile)?;
+                trace(OperationKind::Read);
 
                 // Perform the lock operation, but don't write the lockfile to disk.
                 let result = do_lock(
                     target,
                     interpreter,
+                    self.markers.unwrap_or(interpreter.markers()),
                     Some(existing),
                     self.constraints,
                     self.settings,
@@ -362,7 +441,10 @@ impl<'env> LockOperation<'env> {
             LockMode::Write(interpreter) | LockMode::DryRun(interpreter) => {
                 // Read the existing lockfile.
                 let existing = match target.read().await {
-                    Ok(Some(existing)) => Some(existing),
+                    Ok(Some(existing)) => {
+                        trace(OperationKind::Read);
+                        Some(existing)
+                    }
                     Ok(None) => None,
                     Err(ProjectError::Lock(err)) => {
                         warn_user!(
@@ -377,6 +459,7 @@ impl<'env> LockOperation<'env> {
                 let result = do_lock(
                     target,
                     interpreter,
+                    self.markers.unwrap_or(interpreter.markers()),
                     existing,
                     self.constraints,
                     self.settings,
@@ -394,7 +477,13 @@ impl<'env> LockOperation<'env> {
                 // If the lockfile changed, write it to disk.
                 if !matches!(self.mode, LockMode::DryRun(_)) {
                     if let LockResult::Changed(_, lock) = &result {
+                        let operation = if target.lock_path().is_file() {
+                            OperationKind::Modify
+                        } else {
+                            OperationKind::Create
+                        };
                         target.commit(lock).await?;
+                        trace(operation);
                     }
                 }
 
@@ -408,6 +497,7 @@ impl<'env> LockOperation<'env> {
 async fn do_lock(
     target: LockTarget<'_>,
     interpreter: &Interpreter,
+    markers: &MarkerEnvironment,
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
     settings: &ResolverSettings,
@@ -615,9 +705,16 @@ async fn do_lock(
         }
     }
 
+    // Determine the range of Python versions to record alongside the locking interpreter.
+    let requested_python = if target.lock_interpreter() {
+        Some(requested_python_range(target.install_path(), &requires_python).await?)
+    } else {
+        None
+    };
+
     // Determine the Python requirement.
     let python_requirement =
-        PythonRequirement::from_requires_python(interpreter, requires_python.clone());
+        PythonRequirement::from_marker_environment(markers, requires_python.clone());
 
     // Initialize the client.
     let client_builder = BaseClientBuilder::new()
@@ -644,7 +741,7 @@ async fn do_lock(
         .cache(cache.clone())
         .index_locations(index_locations)
         .index_strategy(*index_strategy)
-        .markers(interpreter.markers())
+        .markers(markers)
         .platform(interpreter.platform())
         .build();
 
@@ -722,7 +819,7 @@ async fn do_lock(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        *link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -784,6 +881,17 @@ async fn do_lock(
             // Print the success message after completing resolution.
             logger.on_complete(lock.len(), start, printer)?;
 
+            // Record (or remove) the locking interpreter, if the setting or the requested range
+            // of Python versions changed.
+            let recorded = requested_python.map(|range| match lock.interpreter() {
+                Some(locked) => locked.clone().with_requires_python(Some(range)),
+                None => LockedInterpreter::from_interpreter(interpreter, Some(range)),
+            });
+            if recorded.as_ref() != lock.interpreter() {
+                let updated = lock.clone().with_interpreter(recorded);
+                return Ok(LockResult::Changed(Some(lock), updated));
+            }
+
             Ok(LockResult::Unchanged(lock))
         }
 
@@ -882,7 +990,7 @@ async fn do_lock(
                 None,
                 resolver_env,
                 python_requirement,
-                interpreter.markers(),
+                markers,
                 conflicts.clone(),
                 &client,
                 &flat_index,
@@ -913,6 +1021,19 @@ async fn do_lock(
             .relative_to(target.install_path())?;
 
             let previous = existing_lock.map(ValidatedLock::into_lock);
+
+            // Retain the recorded interpreter, unless all packages are being upgraded.
+            let locked_interpreter = requested_python.map(|range| {
+                match previous
+                    .as_ref()
+                    .and_then(Lock::interpreter)
+                    .filter(|_| !upgrade.is_all())
+                {
+                    Some(locked) => locked.clone().with_requires_python(Some(range)),
+                    None => LockedInterpreter::from_interpreter(interpreter, Some(range)),
+                }
+            });
+
             let lock = Lock::from_resolution(&resolution, target.install_path())?
                 .with_manifest(manifest)
                 .with_conflicts(conflicts)
@@ -927,13 +1048,42 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
-                );
+                )
+                .with_interpreter(locked_interpreter);
 
             Ok(LockResult::Changed(previous, lock))
         }
     }
 }
 
+/// Determine the range of Python versions requested by the project, as recorded with the locking
+/// interpreter: the `.python-version` file in the workspace root, if any, narrowed to the
+/// workspace `requires-python`.
+///
+/// Unlike `--python`, the `.python-version` file is shared by everyone working on the project, so
+/// it's recorded as the range that all interpreters are expected to fall into.
+async fn requested_python_range(
+    install_path: &Path,
+    requires_python: &RequiresPython,
+) -> Result<VersionSpecifiers, ProjectError> {
+    let requested = PythonVersionFile::try_from_path(install_path.join(PYTHON_VERSION_FILENAME))
+        .await?
+        .and_then(PythonVersionFile::into_version)
+        .and_then(|request| match request {
+            PythonRequest::Version(version) | PythonRequest::ImplementationVersion(_, version) => {
+                version.as_specifiers()
+            }
+            _ => None,
+        });
+    let Some(requested) = requested else {
+        return Ok(requires_python.specifiers().clone());
+    };
+    Ok(
+        RequiresPython::intersection([requires_python.specifiers(), &requested].into_iter())
+            .map_or(requested, |range| range.specifiers().clone()),
+    )
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
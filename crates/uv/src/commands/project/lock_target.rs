@@ -6,11 +6,13 @@ use itertools::Either;
 use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
+use uv_fs::Simplified;
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_resolver::{Lock, LockVersion, VERSION};
 use uv_scripts::Pep723Script;
+use uv_warnings::warn_user;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
 use uv_workspace::{Workspace, WorkspaceMember};
 
@@ -188,6 +190,14 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns `true` if the interpreter used to lock the [`LockTarget`] should be recorded.
+    pub(crate) fn lock_interpreter(self) -> bool {
+        match self {
+            Self::Workspace(workspace) => workspace.lock_interpreter(),
+            Self::Script(_) => false,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Conflicts {
         match self {
@@ -270,10 +280,36 @@ impl<'lock> LockTarget<'lock> {
     ///
     /// Returns `Ok(None)` if the lockfile does not exist.
     pub(crate) async fn read(self) -> Result<Option<Lock>, ProjectError> {
+        self.read_impl(false).await
+    }
+
+    /// Read the lockfile from the workspace, accepting a lockfile with a newer schema version in a
+    /// read-only compatibility mode.
+    ///
+    /// If a lockfile with a newer schema version can still be parsed, i.e., the fields added in the
+    /// newer schema can be ignored, it's returned with a warning that it may not be fully
+    /// respected. The returned lockfile must not be written back to disk, which would downgrade
+    /// the schema.
+    ///
+    /// Returns `Ok(None)` if the lockfile does not exist.
+    pub(crate) async fn read_compatible(self) -> Result<Option<Lock>, ProjectError> {
+        self.read_impl(true).await
+    }
+
+    async fn read_impl(self, compatible: bool) -> Result<Option<Lock>, ProjectError> {
         match fs_err::tokio::read_to_string(self.lock_path()).await {
             Ok(encoded) => {
                 match toml::from_str::<Lock>(&encoded) {
                     Ok(lock) => {
+                        if compatible && lock.version() > VERSION {
+                            warn_user!(
+                                "The lockfile at `{}` uses a newer schema version (v{}, but only v{VERSION} is supported). Reading it in read-only compatibility mode, which ignores any fields added in the newer schema; upgrade uv for full support.",
+                                self.lock_path().user_display(),
+                                lock.version(),
+                            );
+                            return Ok(Some(lock));
+                        }
+
                         // If the lockfile uses an unsupported version, raise an error.
                         if lock.version() != VERSION {
                             return Err(ProjectError::UnsupportedLockVersion(
@@ -25,13 +25,15 @@ use uv_fs::{CWD, LockedFile, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::{DEV_DEPENDENCIES, DefaultGroups, ExtraName, GroupName, PackageName};
-use uv_pep440::{TildeVersionSpecifier, Version, VersionSpecifiers};
-use uv_pep508::MarkerTreeContents;
+use uv_pep440::{TildeVersionSpecifier, Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerEnvironment, MarkerTreeContents};
 use uv_pypi_types::{ConflictItem, ConflictKind, ConflictSet, Conflicts};
 use uv_python::{
-    EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest, satisfies_python_preference,
+    EnvironmentPreference, EnvironmentProvenance, Interpreter, InvalidEnvironmentKind,
+    PYTHON_VERSION_FILENAME, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest, PythonSource, PythonVariant, PythonVersion, PythonVersionFile,
+    Remediation, VersionFileDiscoveryOptions, VersionRequest, find_active_environment_skip,
+    satisfies_python_preference,
 };
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
@@ -50,9 +52,15 @@ use uv_workspace::pyproject::ExtraBuildDependency;
 use uv_workspace::pyproject::PyProjectToml;
 use uv_workspace::{RequiresPythonSources, Workspace, WorkspaceCache};
 
+use crate::commands::diagnostics::{DiagnosticLocation, WorkspaceFiles};
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
+use crate::commands::project::environment::EphemeralDir;
 use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::run_summary::RunSummary;
+use crate::commands::project::warning_policy::{EnvironmentWarning, WarningPolicy};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
@@ -60,19 +68,28 @@ use crate::settings::{
     InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings, ResolverSettings,
 };
 
+pub(crate) mod activation;
 pub(crate) mod add;
+pub(crate) mod composer;
 pub(crate) mod environment;
+mod environment_lock;
 pub(crate) mod export;
 pub(crate) mod format;
+pub(crate) mod fs_trace;
+mod ignore_files;
 pub(crate) mod init;
-mod install_target;
+pub(crate) mod install_target;
+pub(crate) mod kernel;
 pub(crate) mod lock;
-mod lock_target;
+pub(crate) mod lock_target;
 pub(crate) mod remove;
 pub(crate) mod run;
+pub(crate) mod run_history;
+pub(crate) mod run_summary;
 pub(crate) mod sync;
 pub(crate) mod tree;
 pub(crate) mod version;
+pub(crate) mod warning_policy;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
@@ -109,6 +126,16 @@ pub(crate) enum ProjectError {
     )]
     LockedPlatformIncompatibility(String),
 
+    #[error(
+        "`{0}` has no wheel for the requested `--python-platform` and would need to be built from source, which is not supported when syncing into a `--target` directory"
+    )]
+    CrossPlatformSourceDist(PackageName),
+
+    #[error(
+        "The lockfile requires a free-threaded Python interpreter, since `{0}` only provides free-threaded wheels"
+    )]
+    MissingFreethreadedPython(PackageName, #[source] uv_python::Error),
+
     #[error(transparent)]
     Conflict(#[from] ConflictError),
 
@@ -151,8 +178,18 @@ pub(crate) enum ProjectError {
     )]
     RequiresPythonScriptIncompatibility(Version, RequiresPython),
 
-    #[error("Group `{0}` is not defined in the project's `dependency-groups` table")]
-    MissingGroupProject(GroupName),
+    #[error(
+        "The Python request `{1}` from `{0}` is incompatible with the Python requirement of `{2}`: `{3}`"
+    )]
+    MemberPythonIncompatibility(String, String, PackageName, VersionSpecifiers),
+
+    #[error(
+        "The Python request `{1}` from `{0}` is not satisfied by the workspace environment at `{3}` (Python {4}), which is shared with the other members of the workspace"
+    )]
+    MemberPythonEnvironmentMismatch(String, String, PackageName, String, Version),
+
+    #[error("Group `{1}` is not defined in the project's `dependency-groups` table")]
+    MissingGroupProject(PackageName, GroupName),
 
     #[error("Group `{0}` is not defined in any project's `dependency-groups` table")]
     MissingGroupWorkspace(GroupName),
@@ -165,8 +202,8 @@ pub(crate) enum ProjectError {
     )]
     MissingDefaultGroup(GroupName),
 
-    #[error("Extra `{0}` is not defined in the project's `optional-dependencies` table")]
-    MissingExtraProject(ExtraName),
+    #[error("Extra `{1}` is not defined in the project's `optional-dependencies` table")]
+    MissingExtraProject(PackageName, ExtraName),
 
     #[error("Extra `{0}` is not defined in any project's `optional-dependencies` table")]
     MissingExtraWorkspace(ExtraName),
@@ -196,10 +233,10 @@ pub(crate) enum ProjectError {
     UvLockParse(#[source] toml::de::Error),
 
     #[error("Failed to parse `pyproject.toml`")]
-    PyprojectTomlParse(#[source] toml::de::Error),
+    PyprojectTomlParse(PathBuf, #[source] toml::de::Error),
 
     #[error("Failed to update `pyproject.toml`")]
-    PyprojectTomlUpdate,
+    PyprojectTomlUpdate(PathBuf),
 
     #[error("Failed to parse PEP 723 script metadata")]
     Pep723ScriptTomlParse(#[source] toml::de::Error),
@@ -210,6 +247,16 @@ pub(crate) enum ProjectError {
     #[error("Attempted to drop a temporary virtual environment while still in-use")]
     DroppedEnvironment,
 
+    #[error(
+        "The virtual environment at `{}` is a resolution-only environment for `{}` and cannot be used to install packages or run commands",
+        _0.user_display().cyan(),
+        _1
+    )]
+    ResolutionOnlyEnvironment(PathBuf, String),
+
+    #[error("{1} [{code}]", code = .0.code())]
+    EnvironmentWarning(EnvironmentWarning, String),
+
     #[error(transparent)]
     DependencyGroup(#[from] DependencyGroupError),
 
@@ -271,6 +318,170 @@ pub(crate) enum ProjectError {
     Anyhow(#[from] anyhow::Error),
 }
 
+impl ProjectError {
+    /// Return a stable, machine-readable code identifying the kind of error, as reported with
+    /// `--diagnostics json`.
+    ///
+    /// Returns `None` for errors that wrap an error from another crate.
+    pub(crate) fn code(&self) -> Option<&'static str> {
+        match self {
+            Self::LockMismatch(..) => Some("lock-mismatch"),
+            Self::MissingLockfile => Some("missing-lockfile"),
+            Self::UnsupportedLockVersion(..) | Self::UnparsableLockVersion(..) => {
+                Some("unsupported-lock-version")
+            }
+            Self::LockSerialization(..) => Some("lock-serialization"),
+            Self::LockedPythonIncompatibility(..) => Some("locked-python-incompatibility"),
+            Self::LockedPlatformIncompatibility(..) => Some("locked-platform-incompatibility"),
+            Self::CrossPlatformSourceDist(..) => Some("cross-platform-source-dist"),
+            Self::MissingFreethreadedPython(..) => Some("missing-freethreaded-python"),
+            Self::Conflict(..) => Some("conflict"),
+            Self::RequestedPythonProjectIncompatibility(..)
+            | Self::DotPythonVersionProjectIncompatibility(..)
+            | Self::RequiresPythonProjectIncompatibility(..)
+            | Self::RequestedPythonScriptIncompatibility(..)
+            | Self::DotPythonVersionScriptIncompatibility(..)
+            | Self::RequiresPythonScriptIncompatibility(..)
+            | Self::MemberPythonIncompatibility(..) => Some("requires-python-incompatibility"),
+            Self::MemberPythonEnvironmentMismatch(..) => Some("member-python-environment-mismatch"),
+            Self::MissingGroupProject(..)
+            | Self::MissingGroupWorkspace(..)
+            | Self::MissingDefaultGroup(..) => Some("missing-group"),
+            Self::MissingExtraProject(..) | Self::MissingExtraWorkspace(..) => {
+                Some("missing-extra")
+            }
+            Self::MissingGroupScript(..) | Self::MissingExtraScript(..) => {
+                Some("unsupported-script-option")
+            }
+            Self::OverlappingMarkers(..) => Some("overlapping-environments"),
+            Self::DisjointEnvironment(..) => Some("disjoint-environment"),
+            Self::DisjointRequiresPython(..) => Some("conflicting-requires-python"),
+            Self::EmptyEnvironment => Some("empty-environment"),
+            Self::InvalidProjectEnvironmentDir(..) => Some("invalid-environment-dir"),
+            Self::UvLockParse(..) => Some("invalid-lockfile"),
+            Self::PyprojectTomlParse(..) | Self::PyprojectTomlUpdate(..) => {
+                Some("invalid-pyproject-toml")
+            }
+            Self::Pep723ScriptTomlParse(..) => Some("invalid-script-metadata"),
+            Self::NoSitePackages => Some("missing-site-packages"),
+            Self::DroppedEnvironment => Some("dropped-environment"),
+            Self::ResolutionOnlyEnvironment(..) => Some("resolution-only-environment"),
+            Self::EnvironmentWarning(warning, _) => Some(warning.code()),
+            _ => None,
+        }
+    }
+
+    /// Return the declarations that caused the error, as reported with `--diagnostics json`.
+    pub(crate) fn locations(&self, files: &WorkspaceFiles) -> Vec<DiagnosticLocation> {
+        /// Map the sources of a `requires-python` conflict to the members that declared them.
+        fn requires_python_locations<'a>(
+            sources: &'a RequiresPythonSources,
+            files: &'a WorkspaceFiles,
+        ) -> impl Iterator<Item = DiagnosticLocation> + 'a {
+            sources.keys().map(|(package, group)| DiagnosticLocation {
+                file: Some(files.member_file(package)),
+                package: Some(package.clone()),
+                group: group.clone(),
+                extra: None,
+            })
+        }
+
+        match self {
+            Self::LockMismatch(..)
+            | Self::MissingLockfile
+            | Self::UnsupportedLockVersion(..)
+            | Self::UnparsableLockVersion(..)
+            | Self::LockSerialization(..)
+            | Self::LockedPythonIncompatibility(..)
+            | Self::LockedPlatformIncompatibility(..)
+            | Self::UvLockParse(..) => vec![DiagnosticLocation::file("uv.lock")],
+            Self::CrossPlatformSourceDist(package)
+            | Self::MissingFreethreadedPython(package, _) => {
+                vec![DiagnosticLocation {
+                    file: Some("uv.lock".to_string()),
+                    package: Some(package.clone()),
+                    ..DiagnosticLocation::default()
+                }]
+            }
+            Self::Conflict(err) => err
+                .conflicts
+                .iter()
+                .map(|item| DiagnosticLocation {
+                    file: Some(files.member_file(item.package())),
+                    package: Some(item.package().clone()),
+                    group: item.group().cloned(),
+                    extra: item.extra().cloned(),
+                })
+                .collect(),
+            Self::RequestedPythonProjectIncompatibility(_, _, sources, _)
+            | Self::RequiresPythonProjectIncompatibility(_, _, sources, _) => {
+                requires_python_locations(sources, files).collect()
+            }
+            Self::DotPythonVersionProjectIncompatibility(file, _, _, sources, _) => {
+                std::iter::once(DiagnosticLocation::file(file.clone()))
+                    .chain(requires_python_locations(sources, files))
+                    .collect()
+            }
+            Self::DotPythonVersionScriptIncompatibility(file, ..) => {
+                vec![DiagnosticLocation::file(file.clone())]
+            }
+            Self::MemberPythonIncompatibility(file, _, package, _)
+            | Self::MemberPythonEnvironmentMismatch(file, _, package, ..) => vec![
+                DiagnosticLocation::file(file.clone()),
+                DiagnosticLocation {
+                    file: Some(files.member_file(package)),
+                    package: Some(package.clone()),
+                    ..DiagnosticLocation::default()
+                },
+            ],
+            Self::DisjointRequiresPython(sources) => {
+                requires_python_locations(sources, files).collect()
+            }
+            Self::MissingGroupProject(package, group) => vec![DiagnosticLocation {
+                file: Some(files.member_file(package)),
+                package: Some(package.clone()),
+                group: Some(group.clone()),
+                ..DiagnosticLocation::default()
+            }],
+            Self::MissingDefaultGroup(group) => vec![DiagnosticLocation {
+                file: Some(files.project_file()),
+                group: Some(group.clone()),
+                ..DiagnosticLocation::default()
+            }],
+            Self::MissingExtraProject(package, extra) => vec![DiagnosticLocation {
+                file: Some(files.member_file(package)),
+                package: Some(package.clone()),
+                extra: Some(extra.clone()),
+                ..DiagnosticLocation::default()
+            }],
+            // Requests across the workspace, and the workspace's supported environments, are
+            // declared at the workspace root.
+            Self::MissingGroupWorkspace(group) => vec![DiagnosticLocation {
+                file: Some("pyproject.toml".to_string()),
+                group: Some(group.clone()),
+                ..DiagnosticLocation::default()
+            }],
+            Self::MissingExtraWorkspace(extra) => vec![DiagnosticLocation {
+                file: Some("pyproject.toml".to_string()),
+                extra: Some(extra.clone()),
+                ..DiagnosticLocation::default()
+            }],
+            Self::OverlappingMarkers(..)
+            | Self::DisjointEnvironment(..)
+            | Self::EmptyEnvironment => {
+                vec![DiagnosticLocation::file("pyproject.toml")]
+            }
+            Self::PyprojectTomlParse(path, _) | Self::PyprojectTomlUpdate(path) => {
+                vec![DiagnosticLocation::file(files.relative(path))]
+            }
+            Self::InvalidProjectEnvironmentDir(path, _) => {
+                vec![DiagnosticLocation::file(path.user_display().to_string())]
+            }
+            _ => vec![],
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ConflictError {
     /// The set from which the conflict was derived.
@@ -551,6 +762,38 @@ fn validate_script_requires_python(
     }
 }
 
+/// How to key the cached environment of a PEP 723 script.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ScriptEnvironmentKey {
+    /// Key the environment by the path (or URL) of the script.
+    #[default]
+    Path,
+    /// Key the environment by a hash of the inline metadata of the script, such that moved,
+    /// renamed, or identical scripts share an environment.
+    Content,
+}
+
+impl ScriptEnvironmentKey {
+    /// Read the [`ScriptEnvironmentKey`] from `UV_SCRIPT_ENVIRONMENT_KEY`.
+    fn from_env() -> Self {
+        let Some(value) = std::env::var_os(EnvVars::UV_SCRIPT_ENVIRONMENT_KEY) else {
+            return Self::default();
+        };
+        match value.to_str() {
+            Some("path") => Self::Path,
+            Some("content") => Self::Content,
+            _ => {
+                warn_user_once!(
+                    "Ignoring invalid value for `{}`: `{}` (expected `path` or `content`)",
+                    EnvVars::UV_SCRIPT_ENVIRONMENT_KEY,
+                    value.to_string_lossy()
+                );
+                Self::default()
+            }
+        }
+    }
+}
+
 /// An interpreter suitable for a PEP 723 script.
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
@@ -586,32 +829,7 @@ impl ScriptInterpreter {
         }
 
         // Determine the stable path to the script environment in the cache.
-        let cache_env = {
-            let entry = match script {
-                // For local scripts, use a hash of the path to the script.
-                Pep723ItemRef::Script(script) => {
-                    let digest = cache_digest(&script.path);
-                    if let Some(file_name) = script
-                        .path
-                        .file_stem()
-                        .and_then(|name| name.to_str())
-                        .and_then(cache_name)
-                    {
-                        format!("{file_name}-{digest}")
-                    } else {
-                        digest
-                    }
-                }
-                // For remote scripts, use a hash of the URL.
-                Pep723ItemRef::Remote(.., url) => cache_digest(url),
-                // Otherwise, use a hash of the metadata.
-                Pep723ItemRef::Stdin(metadata) => cache_digest(&metadata.raw),
-            };
-
-            cache
-                .shard(CacheBucket::Environments, entry)
-                .into_path_buf()
-        };
+        let cache_env = Self::cache_root(script, ScriptEnvironmentKey::from_env(), cache);
 
         // If `--active` is set, prefer the active virtual environment.
         if let Some(from_virtual_env) = from_virtual_env_variable() {
@@ -647,9 +865,63 @@ impl ScriptInterpreter {
         cache_env
     }
 
+    /// Return the path to the script environment in the cache, keyed according to the given
+    /// [`ScriptEnvironmentKey`].
+    fn cache_root(script: Pep723ItemRef<'_>, key: ScriptEnvironmentKey, cache: &Cache) -> PathBuf {
+        let entry = match (script, key) {
+            // For local scripts, use a hash of the path to the script.
+            (Pep723ItemRef::Script(script), ScriptEnvironmentKey::Path) => {
+                let digest = cache_digest(&script.path);
+                if let Some(file_name) = script
+                    .path
+                    .file_stem()
+                    .and_then(|name| name.to_str())
+                    .and_then(cache_name)
+                {
+                    format!("{file_name}-{digest}")
+                } else {
+                    digest
+                }
+            }
+            // For remote scripts, use a hash of the URL.
+            (Pep723ItemRef::Remote(.., url), ScriptEnvironmentKey::Path) => cache_digest(url),
+            // Otherwise, use a hash of the metadata.
+            (script, _) => cache_digest(&script.metadata().raw),
+        };
+
+        cache
+            .shard(CacheBucket::Environments, entry)
+            .into_path_buf()
+    }
+
+    /// Remove the environment that a local script would use if its environment was keyed by
+    /// path, if any.
+    ///
+    /// Once a script environment is created at its content-keyed `root`, the environment created
+    /// for the script while environments were keyed by path is no longer reachable.
+    fn remove_path_keyed(script: Pep723ItemRef<'_>, root: &Path, cache: &Cache) {
+        if !matches!(script, Pep723ItemRef::Script(..)) {
+            return;
+        }
+        // Leave the environment alone if the script is using the active virtual environment.
+        if root != Self::cache_root(script, ScriptEnvironmentKey::Content, cache) {
+            return;
+        }
+        let legacy = Self::cache_root(script, ScriptEnvironmentKey::Path, cache);
+        match remove_virtualenv(&legacy) {
+            Ok(()) => debug!(
+                "Removed path-keyed script environment at: {}",
+                legacy.user_display()
+            ),
+            Err(uv_virtualenv::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => warn!("Failed to remove path-keyed script environment: {err}"),
+        }
+    }
+
     /// Discover the interpreter to use for the current [`Pep723Item`].
     pub(crate) async fn discover(
         script: Pep723ItemRef<'_>,
+        project_dir: &Path,
         python_request: Option<PythonRequest>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
@@ -658,6 +930,7 @@ impl ScriptInterpreter {
         keep_incompatible: bool,
         no_config: bool,
         active: Option<bool>,
+        warnings: WarningPolicy,
         cache: &Cache,
         printer: Printer,
         preview: Preview,
@@ -669,7 +942,8 @@ impl ScriptInterpreter {
             source,
             python_request,
             requires_python,
-        } = ScriptPython::from_request(python_request, workspace, script, no_config).await?;
+        } = ScriptPython::from_request(python_request, workspace, script, project_dir, no_config)
+            .await?;
 
         let root = Self::root(script, active, cache);
         match PythonEnvironment::from_root(&root, cache) {
@@ -679,6 +953,7 @@ impl ScriptInterpreter {
                     EnvironmentKind::Script,
                     python_request.as_ref(),
                     python_preference,
+                    install_mirrors.python_install_dir.as_deref(),
                     requires_python
                         .as_ref()
                         .map(|(requires_python, _)| requires_python),
@@ -686,10 +961,13 @@ impl ScriptInterpreter {
                 ) {
                     Ok(()) => return Ok(Self::Environment(venv)),
                     Err(err) if keep_incompatible => {
-                        warn_user!(
-                            "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
-                            root.user_display().cyan(),
-                        );
+                        EnvironmentWarning::IncompatibleEnvironment.emit(
+                            warnings,
+                            format_args!(
+                                "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
+                                root.user_display().cyan(),
+                            ),
+                        )?;
                         return Ok(Self::Environment(venv));
                     }
                     Err(err) => {
@@ -720,6 +998,10 @@ impl ScriptInterpreter {
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors.python_install_dir.as_deref(),
+            install_mirrors.install_staging_dir.as_deref(),
+            &install_mirrors.download_client(),
+            install_mirrors.python_resolution.unwrap_or_default(),
             preview,
         )
         .await?
@@ -740,7 +1022,7 @@ impl ScriptInterpreter {
             }
             None => Ok(()),
         } {
-            warn_user!("{err}");
+            EnvironmentWarning::IncompatiblePython.emit(warnings, err)?;
         }
 
         Ok(Self::Interpreter(interpreter))
@@ -814,12 +1096,32 @@ pub(crate) enum EnvironmentIncompatibilityError {
     PythonPreference(EnvironmentKind, PythonPreference),
 }
 
+/// Record the provenance of the base interpreter in a newly created environment.
+///
+/// Failures are logged and otherwise ignored, as the environment is usable without it.
+pub(crate) fn write_provenance(
+    environment: &PythonEnvironment,
+    python_downloads_json_url: Option<&str>,
+    python_install_dir: Option<&Path>,
+) {
+    let provenance = EnvironmentProvenance::new(
+        environment.interpreter(),
+        python_downloads_json_url,
+        python_install_dir,
+        uv_version::version(),
+    );
+    if let Err(err) = provenance.write(environment.root()) {
+        warn!("Failed to record the provenance of the environment: {err}");
+    }
+}
+
 /// Whether an environment is usable for a project or script, i.e., if it matches the requirements.
 fn environment_is_usable(
     environment: &PythonEnvironment,
     kind: EnvironmentKind,
     python_request: Option<&PythonRequest>,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     requires_python: Option<&RequiresPython>,
     cache: &Cache,
 ) -> Result<(), EnvironmentIncompatibilityError> {
@@ -859,6 +1161,7 @@ fn environment_is_usable(
         PythonSource::DiscoveredEnvironment,
         environment.interpreter(),
         python_preference,
+        python_install_dir,
     ) {
         trace!(
             "The virtual environment's Python interpreter meets the Python preference: `{}`",
@@ -898,6 +1201,7 @@ impl ProjectInterpreter {
         keep_incompatible: bool,
         no_config: bool,
         active: Option<bool>,
+        warnings: WarningPolicy,
         cache: &Cache,
         printer: Printer,
         preview: Preview,
@@ -916,6 +1220,31 @@ impl ProjectInterpreter {
         )
         .await?;
 
+        // Under `--fail-fast-env`, reject a `VIRTUAL_ENV` that would otherwise be ignored.
+        if active.is_none() && warnings.fail_fast {
+            if let Some(virtual_env) = workspace.mismatched_virtual_env() {
+                EnvironmentWarning::IgnoredVirtualEnv.emit(
+                    warnings,
+                    format_args!(
+                        "`VIRTUAL_ENV={}` does not match the project environment path `{}`; use `--active` to target the active environment instead",
+                        virtual_env.user_display(),
+                        workspace.venv(Some(false)).user_display()
+                    ),
+                )?;
+            }
+        }
+
+        // If the active virtual environment is ignored, and its interpreter wouldn't satisfy the
+        // request either (such that `--active` wouldn't help), explain why.
+        if active.is_none() && workspace.mismatched_virtual_env().is_some() {
+            if let Some(skip) = python_request
+                .as_ref()
+                .and_then(|request| find_active_environment_skip(request, cache))
+            {
+                warn_user_once!("{skip}");
+            }
+        }
+
         // Read from the virtual environment first.
         let root = workspace.venv(active);
         match PythonEnvironment::from_root(&root, cache) {
@@ -925,15 +1254,25 @@ impl ProjectInterpreter {
                     EnvironmentKind::Project,
                     python_request.as_ref(),
                     python_preference,
+                    install_mirrors.python_install_dir.as_deref(),
                     requires_python.as_ref(),
                     cache,
                 ) {
-                    Ok(()) => return Ok(Self::Environment(venv)),
+                    Ok(()) => {
+                        validate_locked_interpreter(workspace, venv.interpreter(), warnings)
+                            .await?;
+                        return Ok(Self::Environment(venv));
+                    }
                     Err(err) if keep_incompatible => {
-                        warn_user!(
-                            "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
-                            root.user_display().cyan(),
-                        );
+                        EnvironmentWarning::IncompatibleEnvironment.emit(
+                            warnings,
+                            format_args!(
+                                "Using incompatible environment (`{}`) due to `--no-sync` ({err})",
+                                root.user_display().cyan(),
+                            ),
+                        )?;
+                        validate_locked_interpreter(workspace, venv.interpreter(), warnings)
+                            .await?;
                         return Ok(Self::Environment(venv));
                     }
                     Err(err) => {
@@ -1005,9 +1344,14 @@ impl ProjectInterpreter {
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors.python_install_dir.as_deref(),
+            install_mirrors.install_staging_dir.as_deref(),
+            &install_mirrors.download_client(),
+            install_mirrors.python_resolution.unwrap_or_default(),
             preview,
         )
-        .await?;
+        .await
+        .map_err(|err| source.remediate(err, project_dir))?;
 
         let managed = python.source().is_managed();
         let implementation = python.implementation();
@@ -1040,6 +1384,8 @@ impl ProjectInterpreter {
             )?;
         }
 
+        validate_locked_interpreter(workspace, &interpreter, warnings).await?;
+
         Ok(Self::Interpreter(interpreter))
     }
 
@@ -1064,6 +1410,182 @@ impl ProjectInterpreter {
     }
 }
 
+/// Compare the interpreter to the one recorded in the workspace lockfile with `lock-interpreter`,
+/// if any.
+///
+/// Warns on any difference, or errors with `--strict-python`.
+async fn validate_locked_interpreter(
+    workspace: &Workspace,
+    interpreter: &Interpreter,
+    warnings: WarningPolicy,
+) -> Result<(), ProjectError> {
+    if !workspace.lock_interpreter() {
+        return Ok(());
+    }
+
+    // Errors are surfaced when the lockfile is read for locking or syncing.
+    let lock = match LockTarget::Workspace(workspace).read().await {
+        Ok(Some(lock)) => lock,
+        Ok(None) => return Ok(()),
+        Err(err) => {
+            debug!("Failed to read lockfile to validate the interpreter: {err}");
+            return Ok(());
+        }
+    };
+    let Some(locked) = lock.interpreter() else {
+        return Ok(());
+    };
+
+    let differences = locked.differences(interpreter);
+    if differences.is_empty() {
+        return Ok(());
+    }
+
+    EnvironmentWarning::InterpreterMismatch.emit(
+        warnings,
+        format_args!(
+            "The Python interpreter at `{}` differs from the one used to lock the project (`{}`): {}",
+            interpreter.sys_executable().user_display().cyan(),
+            locked,
+            differences.join(", ")
+        ),
+    )
+}
+
+/// A requested Python version that isn't available, with markers synthesized for it from another
+/// interpreter.
+///
+/// Used to lock the project for a Python version that isn't installed and can't be downloaded,
+/// such that the lockfile is ready once the interpreter is available.
+#[derive(Debug)]
+pub(crate) struct SynthesizedPython {
+    python_version: PythonVersion,
+    interpreter: Interpreter,
+    markers: MarkerEnvironment,
+}
+
+impl SynthesizedPython {
+    /// Synthesize markers for the requested Python version, using any available interpreter as
+    /// the base.
+    ///
+    /// Returns `None` if the request isn't for a specific Python version, or if no interpreter is
+    /// available. Returns an error if the requested version is incompatible with the workspace
+    /// `requires-python`.
+    pub(crate) fn find(
+        workspace: &Workspace,
+        groups: &DependencyGroupsWithDefaults,
+        python_request: Option<&PythonRequest>,
+        python_preference: PythonPreference,
+        python_install_dir: Option<&Path>,
+        cache: &Cache,
+        preview: Preview,
+    ) -> Result<Option<Self>, ProjectError> {
+        let Some(python_version) = python_request.and_then(PythonRequest::as_python_version) else {
+            return Ok(None);
+        };
+
+        // Validate the requested version before anything is locked.
+        if let Some(requires_python) = find_requires_python(workspace, groups)? {
+            let version = python_version.python_full_version();
+            if !requires_python.contains(&version) {
+                let conflicting_requires = workspace
+                    .requires_python(groups)?
+                    .into_iter()
+                    .filter(|(.., requires)| !requires.contains(&version))
+                    .collect::<RequiresPythonSources>();
+                return Err(ProjectError::RequestedPythonProjectIncompatibility(
+                    version,
+                    requires_python,
+                    conflicting_requires,
+                    workspace.packages().len() > 1,
+                ));
+            }
+        }
+
+        let Ok(installation) = PythonInstallation::find(
+            &PythonRequest::Default,
+            EnvironmentPreference::Any,
+            python_preference,
+            cache,
+            python_install_dir,
+            preview,
+        ) else {
+            return Ok(None);
+        };
+        let interpreter = installation.into_interpreter();
+        let markers = python_version.markers(interpreter.markers());
+        debug!(
+            "Python {python_version} is not available; synthesizing markers from `{}`",
+            interpreter.sys_executable().user_display()
+        );
+
+        Ok(Some(Self {
+            python_version,
+            interpreter,
+            markers,
+        }))
+    }
+
+    /// Return the interpreter from which the markers were synthesized.
+    pub(crate) fn interpreter(&self) -> &Interpreter {
+        &self.interpreter
+    }
+
+    /// Return the synthesized markers.
+    pub(crate) fn markers(&self) -> &MarkerEnvironment {
+        &self.markers
+    }
+
+    /// Lock the workspace against the synthesized markers.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn lock(
+        &self,
+        workspace: &Workspace,
+        locked: bool,
+        settings: &ResolverSettings,
+        network_settings: &NetworkSettings,
+        state: &UniversalState,
+        logger: Box<dyn ResolveLogger>,
+        concurrency: Concurrency,
+        cache: &Cache,
+        workspace_cache: &WorkspaceCache,
+        printer: Printer,
+        preview: Preview,
+    ) -> Result<LockResult, ProjectError> {
+        let mode = if locked {
+            LockMode::Locked(&self.interpreter)
+        } else {
+            LockMode::Write(&self.interpreter)
+        };
+        LockOperation::new(
+            mode,
+            settings,
+            network_settings,
+            state,
+            logger,
+            concurrency,
+            cache,
+            workspace_cache,
+            printer,
+            preview,
+        )
+        .with_markers(&self.markers)
+        .execute(LockTarget::Workspace(workspace))
+        .await
+    }
+
+    /// A hint to install the requested Python version, once the lockfile is up-to-date for it.
+    pub(crate) fn hint(&self) -> String {
+        format!(
+            "{}{} The lockfile is up-to-date for Python {}; install it with `{}` to create the project environment",
+            "hint".bold().cyan(),
+            ":".bold(),
+            self.python_version,
+            format!("uv python install {}", self.python_version).green(),
+        )
+    }
+}
+
 /// The source of a `Requires-Python` specifier.
 #[derive(Debug, Clone)]
 pub(crate) enum RequiresPythonSource {
@@ -1083,6 +1605,26 @@ pub(crate) enum PythonRequestSource {
     RequiresPython,
 }
 
+impl PythonRequestSource {
+    /// Attach a [`Remediation`] to a missing Python error if the request was read from a version
+    /// file in a parent of the given project directory.
+    fn remediate(&self, err: uv_python::Error, project_dir: &Path) -> uv_python::Error {
+        let Self::DotPythonVersion(file) = self else {
+            return err;
+        };
+        if file.is_global() || file.path().parent() == Some(project_dir) {
+            return err;
+        }
+        let Some(request) = file.version() else {
+            return err;
+        };
+        err.with_remediation(Remediation::ParentVersionFile {
+            request: request.clone(),
+            path: file.path().to_path_buf(),
+        })
+    }
+}
+
 impl std::fmt::Display for PythonRequestSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1172,6 +1714,103 @@ impl WorkspacePython {
     }
 }
 
+/// Determine the Python request for a specific workspace member, if the member overrides the
+/// workspace interpreter via (1) `tool.uv.python` in its `pyproject.toml` or (2) a
+/// `.python-version` file in its directory.
+///
+/// Returns an error if the request is incompatible with the member's `requires-python`, or if the
+/// workspace environment exists but isn't satisfied by the request. The workspace environment is
+/// shared by all members, so it is never recreated to satisfy a single member; an explicit
+/// `--python` request is required to do so.
+pub(crate) async fn member_python_request(
+    workspace: &Workspace,
+    package: &PackageName,
+    no_config: bool,
+    active: Option<bool>,
+    cache: &Cache,
+) -> Result<Option<String>, ProjectError> {
+    let Some(member) = workspace.packages().get(package) else {
+        return Ok(None);
+    };
+
+    // The workspace root's settings already apply to the workspace as a whole.
+    if member.root() == workspace.install_path() {
+        return Ok(None);
+    }
+
+    let (source, request) = if let Some(python) = member
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.python.as_deref())
+    {
+        (
+            member.root().join("pyproject.toml"),
+            PythonRequest::parse(python),
+        )
+    } else if no_config {
+        return Ok(None);
+    } else if let Some(file) =
+        PythonVersionFile::try_from_path(member.root().join(PYTHON_VERSION_FILENAME)).await?
+    {
+        let path = file.path().to_path_buf();
+        let Some(request) = file.into_version() else {
+            return Ok(None);
+        };
+        (path, request)
+    } else {
+        return Ok(None);
+    };
+
+    // Reject a request that can't satisfy the member's `requires-python`.
+    if let (Some(version), Some(requires_python)) = (
+        request.as_python_version(),
+        member
+            .pyproject_toml()
+            .project
+            .as_ref()
+            .and_then(|project| project.requires_python.as_ref()),
+    ) {
+        let requested = VersionSpecifiers::from(if version.patch().is_some() {
+            VersionSpecifier::equals_version(version.version().clone())
+        } else {
+            VersionSpecifier::equals_star_version(version.python_version())
+        });
+        if RequiresPython::intersection([requires_python, &requested].into_iter()).is_none() {
+            return Err(ProjectError::MemberPythonIncompatibility(
+                source.user_display().to_string(),
+                request.to_canonical_string(),
+                package.clone(),
+                requires_python.clone(),
+            ));
+        }
+    }
+
+    // Reject a request that the shared workspace environment doesn't satisfy, rather than
+    // replacing the environment for the other members.
+    let root = workspace.venv(active);
+    if let Ok(environment) = PythonEnvironment::from_root(&root, cache) {
+        if !request.satisfied(environment.interpreter(), cache) {
+            return Err(ProjectError::MemberPythonEnvironmentMismatch(
+                source.user_display().to_string(),
+                request.to_canonical_string(),
+                package.clone(),
+                root.user_display().to_string(),
+                environment.interpreter().python_version().clone(),
+            ));
+        }
+    }
+
+    debug!(
+        "Using Python request `{}` for workspace member `{package}` from `{}`",
+        request.to_canonical_string(),
+        source.user_display()
+    );
+
+    Ok(Some(request.to_canonical_string()))
+}
+
 /// The resolved Python request and requirement for a [`Pep723Script`]
 #[derive(Debug, Clone)]
 pub(crate) struct ScriptPython {
@@ -1188,10 +1827,15 @@ pub(crate) struct ScriptPython {
 
 impl ScriptPython {
     /// Determine the [`ScriptPython`] for the current [`Workspace`].
+    ///
+    /// A `.python-version` file is discovered from the script's directory or, for scripts without
+    /// a path (e.g., those read from stdin), from the project directory, which is the current
+    /// directory unless overridden with `--project` or `--directory`.
     pub(crate) async fn from_request(
         python_request: Option<PythonRequest>,
         workspace: Option<&Workspace>,
         script: Pep723ItemRef<'_>,
+        project_dir: &Path,
         no_config: bool,
     ) -> Result<Self, ProjectError> {
         // First, discover a requirement from the workspace
@@ -1204,7 +1848,7 @@ impl ScriptPython {
             workspace,
             // Scripts have no groups to hang requires-python settings off of
             &DependencyGroupsWithDefaults::none(),
-            script.path().and_then(Path::parent).unwrap_or(&**CWD),
+            script.path().and_then(Path::parent).unwrap_or(project_dir),
             no_config,
         )
         .await?;
@@ -1252,18 +1896,97 @@ enum ProjectEnvironment {
     /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the project's
     /// requirements. A new environment would've been created, but `--dry-run` mode is enabled; as
     /// such, a temporary environment was created instead.
-    WouldReplace(
-        PathBuf,
-        PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
-    ),
+    WouldReplace(PathBuf, PythonEnvironment, #[allow(unused)] EphemeralDir),
     /// A new [`PythonEnvironment`] would've been created, but `--dry-run` mode is enabled; as such,
     /// a temporary environment was created instead.
-    WouldCreate(
-        PathBuf,
-        PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
-    ),
+    WouldCreate(PathBuf, PythonEnvironment, #[allow(unused)] EphemeralDir),
+}
+
+/// Determine a prompt for the project environment, in order of preference:
+///
+/// 1) The name of the project
+/// 2) The name of the directory at the root of the workspace
+/// 3) No prompt
+fn project_prompt(workspace: &Workspace) -> Option<String> {
+    workspace
+        .pyproject_toml()
+        .project
+        .as_ref()
+        .map(|p| p.name.to_string())
+        .or_else(|| {
+            workspace
+                .install_path()
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+        })
+}
+
+/// Add the project environment to the workspace's ignore files, if enabled via
+/// `manage-ignore-files`.
+///
+/// Failures are logged, rather than surfaced, as the ignore files are a convenience.
+fn update_ignore_files(workspace: &Workspace, environment: &PythonEnvironment) {
+    if !workspace.manage_ignore_files() {
+        return;
+    }
+    let root = match std::path::absolute(environment.root()) {
+        Ok(root) => root,
+        Err(err) => {
+            warn!("Failed to update ignore files: {err}");
+            return;
+        }
+    };
+    if let Err(err) = ignore_files::update(workspace.install_path(), &root) {
+        warn!("Failed to update ignore files: {err}");
+    }
+}
+
+/// Update the prompt of an existing project environment if it no longer matches the project,
+/// e.g., because the project was renamed.
+///
+/// Only environments that uv created for the project, and that already have a prompt, are
+/// updated. Failures are logged, rather than surfaced, as a stale prompt is not fatal.
+fn update_project_prompt(
+    workspace: &Workspace,
+    environment: &PythonEnvironment,
+    active: Option<bool>,
+) {
+    let Some(prompt) = project_prompt(workspace) else {
+        return;
+    };
+    if !uv_fs::is_same_file_allow_missing(environment.root(), &workspace.venv(active))
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let Ok(cfg) = environment.cfg() else {
+        return;
+    };
+    let Some(existing) = cfg.prompt() else {
+        return;
+    };
+    if !cfg.is_uv() || existing == prompt {
+        return;
+    }
+
+    debug!("Updating prompt of project environment from `{existing}` to `{prompt}`");
+    if let Err(err) = uv_virtualenv::set_prompt(environment, uv_virtualenv::Prompt::Static(prompt))
+    {
+        warn!("Failed to update prompt of project environment: {err}");
+    }
+}
+
+/// Refuse to use a resolution-only environment, i.e., one created with
+/// `uv venv --python-platform`, to install packages or run commands, as its packages may target a
+/// different platform.
+pub(crate) fn refuse_resolution_only(environment: &PythonEnvironment) -> Result<(), ProjectError> {
+    match environment.python_platform() {
+        Some(python_platform) => Err(ProjectError::ResolutionOnlyEnvironment(
+            environment.root().to_path_buf(),
+            python_platform,
+        )),
+        None => Ok(()),
+    }
 }
 
 impl ProjectEnvironment {
@@ -1279,8 +2002,10 @@ impl ProjectEnvironment {
         no_sync: bool,
         no_config: bool,
         active: Option<bool>,
+        warnings: WarningPolicy,
         cache: &Cache,
         dry_run: DryRun,
+        unlock: bool,
         printer: Printer,
         preview: Preview,
     ) -> Result<Self, ProjectError> {
@@ -1309,6 +2034,7 @@ impl ProjectEnvironment {
             no_sync,
             no_config,
             active,
+            warnings,
             cache,
             printer,
             preview,
@@ -1316,7 +2042,24 @@ impl ProjectEnvironment {
         .await?
         {
             // If we found an existing, compatible environment, use it.
-            ProjectInterpreter::Environment(environment) => Ok(Self::Existing(environment)),
+            ProjectInterpreter::Environment(environment) => {
+                refuse_resolution_only(&environment)?;
+
+                // If the project was renamed, update the prompt of its environment rather than
+                // leaving the stale name behind.
+                if !dry_run.enabled() && (unlock || !environment.is_read_only()) {
+                    update_project_prompt(workspace, &environment, active);
+                    if uv_fs::is_same_file_allow_missing(
+                        environment.root(),
+                        &workspace.venv(active),
+                    )
+                    .unwrap_or(false)
+                    {
+                        update_ignore_files(workspace, &environment);
+                    }
+                }
+                Ok(Self::Existing(environment))
+            }
 
             // Otherwise, create a virtual environment with the discovered interpreter.
             ProjectInterpreter::Interpreter(interpreter) => {
@@ -1351,28 +2094,13 @@ impl ProjectEnvironment {
                     }
                 };
 
-                // Determine a prompt for the environment, in order of preference:
-                //
-                // 1) The name of the project
-                // 2) The name of the directory at the root of the workspace
-                // 3) No prompt
-                let prompt = workspace
-                    .pyproject_toml()
-                    .project
-                    .as_ref()
-                    .map(|p| p.name.to_string())
-                    .or_else(|| {
-                        workspace
-                            .install_path()
-                            .file_name()
-                            .map(|f| f.to_string_lossy().to_string())
-                    })
+                let prompt = project_prompt(workspace)
                     .map(uv_virtualenv::Prompt::Static)
                     .unwrap_or(uv_virtualenv::Prompt::None);
 
                 // Under `--dry-run`, avoid modifying the environment.
                 if dry_run.enabled() {
-                    let temp_dir = cache.venv_dir()?;
+                    let temp_dir = EphemeralDir::new(cache)?;
                     let environment = uv_virtualenv::create_venv(
                         temp_dir.path(),
                         interpreter,
@@ -1391,20 +2119,38 @@ impl ProjectEnvironment {
                     });
                 }
 
-                // Remove the existing virtual environment if it doesn't meet the requirements.
+                // Replace the existing virtual environment if it doesn't meet the requirements. The
+                // new environment is built aside and swapped into place, so the existing
+                // environment survives if creation fails or is interrupted.
                 if replace {
-                    match remove_virtualenv(&root) {
-                        Ok(()) => {
-                            writeln!(
-                                printer.stderr(),
-                                "Removed virtual environment at: {}",
-                                root.user_display().cyan()
-                            )?;
-                        }
-                        Err(uv_virtualenv::Error::Io(err))
-                            if err.kind() == std::io::ErrorKind::NotFound => {}
-                        Err(err) => return Err(err.into()),
-                    }
+                    pip::operations::check_read_only(&root, unlock)?;
+                    let environment = uv_virtualenv::replace_venv(
+                        &root,
+                        interpreter,
+                        prompt,
+                        false,
+                        false,
+                        false,
+                        upgradeable,
+                        preview,
+                    )?;
+                    writeln!(
+                        printer.stderr(),
+                        "Removed virtual environment at: {}",
+                        root.user_display().cyan()
+                    )?;
+                    writeln!(
+                        printer.stderr(),
+                        "Creating virtual environment at: {}",
+                        root.user_display().cyan()
+                    )?;
+                    write_provenance(
+                        &environment,
+                        install_mirrors.python_downloads_json_url.as_deref(),
+                        install_mirrors.python_install_dir.as_deref(),
+                    );
+                    update_ignore_files(workspace, &environment);
+                    return Ok(Self::Replaced(environment));
                 }
 
                 writeln!(
@@ -1424,12 +2170,14 @@ impl ProjectEnvironment {
                     upgradeable,
                     preview,
                 )?;
+                write_provenance(
+                    &environment,
+                    install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors.python_install_dir.as_deref(),
+                );
+                update_ignore_files(workspace, &environment);
 
-                if replace {
-                    Ok(Self::Replaced(environment))
-                } else {
-                    Ok(Self::Created(environment))
-                }
+                Ok(Self::Created(environment))
             }
         }
     }
@@ -1485,24 +2233,17 @@ enum ScriptEnvironment {
     /// An existing [`PythonEnvironment`] was discovered, but did not satisfy the script's
     /// requirements. A new environment would've been created, but `--dry-run` mode is enabled; as
     /// such, a temporary environment was created instead.
-    WouldReplace(
-        PathBuf,
-        PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
-    ),
+    WouldReplace(PathBuf, PythonEnvironment, #[allow(unused)] EphemeralDir),
     /// A new [`PythonEnvironment`] would've been created, but `--dry-run` mode is enabled; as such,
     /// a temporary environment was created instead.
-    WouldCreate(
-        PathBuf,
-        PythonEnvironment,
-        #[allow(unused)] tempfile::TempDir,
-    ),
+    WouldCreate(PathBuf, PythonEnvironment, #[allow(unused)] EphemeralDir),
 }
 
 impl ScriptEnvironment {
     /// Initialize a virtual environment for a PEP 723 script.
     pub(crate) async fn get_or_init(
         script: Pep723ItemRef<'_>,
+        project_dir: &Path,
         python_request: Option<PythonRequest>,
         network_settings: &NetworkSettings,
         python_preference: PythonPreference,
@@ -1511,6 +2252,7 @@ impl ScriptEnvironment {
         no_sync: bool,
         no_config: bool,
         active: Option<bool>,
+        warnings: WarningPolicy,
         cache: &Cache,
         dry_run: DryRun,
         printer: Printer,
@@ -1530,6 +2272,7 @@ impl ScriptEnvironment {
 
         match ScriptInterpreter::discover(
             script,
+            project_dir,
             python_request,
             network_settings,
             python_preference,
@@ -1538,6 +2281,7 @@ impl ScriptEnvironment {
             no_sync,
             no_config,
             active,
+            warnings,
             cache,
             printer,
             preview,
@@ -1545,7 +2289,10 @@ impl ScriptEnvironment {
         .await?
         {
             // If we found an existing, compatible environment, use it.
-            ScriptInterpreter::Environment(environment) => Ok(Self::Existing(environment)),
+            ScriptInterpreter::Environment(environment) => {
+                refuse_resolution_only(&environment)?;
+                Ok(Self::Existing(environment))
+            }
 
             // Otherwise, create a virtual environment with the discovered interpreter.
             ScriptInterpreter::Interpreter(interpreter) => {
@@ -1564,7 +2311,7 @@ impl ScriptEnvironment {
 
                 // Under `--dry-run`, avoid modifying the environment.
                 if dry_run.enabled() {
-                    let temp_dir = cache.venv_dir()?;
+                    let temp_dir = EphemeralDir::new(cache)?;
                     let environment = uv_virtualenv::create_venv(
                         temp_dir.path(),
                         interpreter,
@@ -1616,6 +2363,15 @@ impl ScriptEnvironment {
                     upgradeable,
                     preview,
                 )?;
+                write_provenance(
+                    &environment,
+                    install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors.python_install_dir.as_deref(),
+                );
+
+                if ScriptEnvironmentKey::from_env() == ScriptEnvironmentKey::Content {
+                    ScriptInterpreter::remove_path_keyed(script, &root, cache);
+                }
 
                 Ok(if replaced {
                     Self::Replaced(environment)
@@ -1780,7 +2536,7 @@ pub(crate) async fn resolve_names(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        *link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -1849,6 +2605,7 @@ pub(crate) async fn resolve_environment(
     spec: EnvironmentSpecification<'_>,
     interpreter: &Interpreter,
     build_constraints: Constraints,
+    hasher: &HashStrategy,
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
     state: &PlatformState,
@@ -1940,7 +2697,6 @@ pub(crate) async fn resolve_environment(
     // optional on the downstream APIs.
     let extras = ExtrasSpecification::default();
     let groups = BTreeMap::new();
-    let hasher = HashStrategy::default();
     let build_hasher = HashStrategy::default();
 
     // When resolving from an interpreter, we assume an empty environment, so reinstalls and
@@ -1972,7 +2728,7 @@ pub(crate) async fn resolve_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(tags), hasher, build_options)
     };
 
     let workspace_cache = WorkspaceCache::default();
@@ -1998,7 +2754,7 @@ pub(crate) async fn resolve_environment(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        *link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -2020,7 +2776,7 @@ pub(crate) async fn resolve_environment(
         &groups,
         preferences,
         EmptyInstalledPackages,
-        &hasher,
+        hasher,
         &reinstall,
         &upgrade,
         Some(tags),
@@ -2046,10 +2802,12 @@ pub(crate) async fn sync_environment(
     resolution: &Resolution,
     modifications: Modifications,
     build_constraints: Constraints,
+    hasher: &HashStrategy,
     settings: InstallerSettingsRef<'_>,
     network_settings: &NetworkSettings,
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
+    summary: Option<&mut RunSummary>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -2111,7 +2869,6 @@ pub(crate) async fn sync_environment(
     // optional on the downstream APIs.
     let build_hasher = HashStrategy::default();
     let dry_run = DryRun::default();
-    let hasher = HashStrategy::default();
     let workspace_cache = WorkspaceCache::default();
 
     // Resolve the flat indexes from `--find-links`.
@@ -2120,7 +2877,7 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(entries, Some(tags), hasher, build_options)
     };
 
     // Lower the extra build dependencies, if any.
@@ -2144,7 +2901,7 @@ pub(crate) async fn sync_environment(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -2155,15 +2912,17 @@ pub(crate) async fn sync_environment(
     );
 
     // Sync the environment.
-    pip::operations::install(
+    let changelog = pip::operations::install(
         resolution,
         site_packages,
         modifications,
+        None,
+        false,
         reinstall,
         build_options,
         link_mode,
         compile_bytecode,
-        &hasher,
+        hasher,
         tags,
         &client,
         state.in_flight(),
@@ -2179,6 +2938,10 @@ pub(crate) async fn sync_environment(
     )
     .await?;
 
+    if let Some(summary) = summary {
+        summary.record_changelog(&changelog);
+    }
+
     // Notify the user of any resolution diagnostics.
     pip::operations::diagnose_resolution(resolution.diagnostics(), printer)?;
 
@@ -2379,7 +3142,7 @@ pub(crate) async fn update_environment(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        *link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -2429,6 +3192,8 @@ pub(crate) async fn update_environment(
         &resolution,
         site_packages,
         modifications,
+        None,
+        false,
         reinstall,
         build_options,
         *link_mode,
@@ -2502,6 +3267,10 @@ pub(crate) async fn init_script_python_requirement(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await?
@@ -29,6 +29,7 @@ use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     default_dependency_groups,
@@ -45,6 +46,7 @@ pub(crate) async fn remove(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    unlock: bool,
     packages: Vec<PackageName>,
     dependency_type: DependencyType,
     package: Option<PackageName>,
@@ -227,6 +229,7 @@ pub(crate) async fn remove(
                     false,
                     no_config,
                     active,
+                    WarningPolicy::default(),
                     cache,
                     printer,
                     preview,
@@ -248,8 +251,10 @@ pub(crate) async fn remove(
                     no_sync,
                     no_config,
                     active,
+                    WarningPolicy::default(),
                     cache,
                     DryRun::Disabled,
+                    unlock,
                     printer,
                     preview,
                 )
@@ -262,6 +267,7 @@ pub(crate) async fn remove(
         RemoveTarget::Script(script) => {
             let interpreter = ScriptInterpreter::discover(
                 (&script).into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
@@ -270,6 +276,7 @@ pub(crate) async fn remove(
                 no_sync,
                 no_config,
                 active,
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -318,7 +325,7 @@ pub(crate) async fn remove(
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
@@ -358,10 +365,13 @@ pub(crate) async fn remove(
         InstallOptions::default(),
         Modifications::Exact,
         None,
+        unlock,
+        None,
         (&settings).into(),
         &network_settings,
         &state,
         Box::new(DefaultInstallLogger),
+        None,
         installer_metadata,
         concurrency,
         cache,
@@ -375,7 +385,7 @@ pub(crate) async fn remove(
         Ok(()) => {}
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
@@ -432,11 +442,12 @@ impl RemoveTarget {
                 Ok(Self::Script(script))
             }
             Self::Project(project) => {
+                let path = project.root().join("pyproject.toml");
+                let pyproject_toml = toml::from_str(content)
+                    .map_err(|err| ProjectError::PyprojectTomlParse(path.clone(), err))?;
                 let project = project
-                    .with_pyproject_toml(
-                        toml::from_str(content).map_err(ProjectError::PyprojectTomlParse)?,
-                    )
-                    .ok_or(ProjectError::PyprojectTomlUpdate)?;
+                    .with_pyproject_toml(pyproject_toml)
+                    .ok_or(ProjectError::PyprojectTomlUpdate(path))?;
                 Ok(Self::Project(project))
             }
         }
@@ -1,48 +1,49 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::env::VarError;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
-use std::io::Read;
+use std::io::{Read, Seek};
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use anyhow::{Context, anyhow, bail};
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
-use thiserror::Error;
 use tokio::process::Command;
-use tracing::{debug, trace, warn};
+use tracing::{debug, warn};
 use url::Url;
 
 use uv_cache::Cache;
-use uv_cli::ExternalCommand;
-use uv_client::BaseClientBuilder;
+use uv_cli::{ExternalCommand, OverlayMode};
+use uv_client::{BaseClient, BaseClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DryRun, EditableMode, ExtrasSpecification,
-    InstallOptions, Preview,
+    InstallOptions, PathPolicy, Preview,
 };
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::Requirement;
+use uv_fs::Simplified;
 use uv_fs::which::is_executable;
-use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_normalize::{DefaultExtras, DefaultGroups, GroupName, PackageName};
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
-    PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
-    VersionFileDiscoveryOptions,
+    PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVersionFile,
+    VersionFileDiscoveryOptions, find_active_environment_skip,
 };
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{Installable, Lock, Preference};
-use uv_scripts::Pep723Item;
+use uv_scripts::{Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache, WorkspaceError};
 
-use crate::child::run_to_completion;
+use crate::child::{SignalOptions, run_to_completion};
 
 /// GitHub Gist API response structure
 #[derive(serde::Deserialize)]
@@ -58,43 +59,47 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
-use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironment};
+use crate::commands::pip::size_limit::EnvironmentSizeLimit;
+use crate::commands::project::composer::EnvironmentComposer;
+use crate::commands::project::environment::{CachedEnvironment, EphemeralDir};
+use crate::commands::project::environment_lock::EnvironmentLock;
+use crate::commands::project::fs_trace::{FsTrace, OperationKind};
 use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::kernel::Kernel;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::run_history::{RunHistory, RunInvocation, RunRecord};
+use crate::commands::project::run_summary::{Phase, RunSummary};
+use crate::commands::project::warning_policy::{EnvironmentWarning, WarningPolicy};
 use crate::commands::project::{
-    EnvironmentSpecification, PreferenceLocation, ProjectEnvironment, ProjectError,
-    ScriptEnvironment, ScriptInterpreter, UniversalState, WorkspacePython,
-    default_dependency_groups, script_extra_build_requires, script_specification,
-    update_environment, validate_project_requires_python,
+    EnvironmentSpecification, PlatformState, PreferenceLocation, ProjectEnvironment, ProjectError,
+    ScriptEnvironment, ScriptInterpreter, SynthesizedPython, UniversalState, WorkspacePython,
+    default_dependency_groups, member_python_request, refuse_resolution_only,
+    script_extra_build_requires, script_specification, update_environment,
+    validate_project_requires_python,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::{ExitStatus, diagnostics, project};
+use crate::commands::venv::activation_command;
+use crate::commands::{ExitStatus, SetupFailure, diagnostics, project};
 use crate::printer::Printer;
-use crate::settings::{NetworkSettings, ResolverInstallerSettings, ResolverSettings};
+use crate::sandbox::Sandbox;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings, ResolverSettings, RunOptions};
 
 /// Run a command.
-#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn run(
     project_dir: &Path,
     script: Option<Pep723Item>,
     command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
-    show_resolution: bool,
-    locked: bool,
-    frozen: bool,
-    active: Option<bool>,
-    no_sync: bool,
-    isolated: bool,
-    all_packages: bool,
+    options: RunOptions,
+    warnings: WarningPolicy,
     package: Option<PackageName>,
-    no_project: bool,
     no_config: bool,
     extras: ExtrasSpecification,
     groups: DependencyGroups,
-    editable: EditableMode,
-    modifications: Modifications,
+    with_requirements_from: Vec<GroupName>,
     python: Option<String>,
+    script_python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -104,11 +109,44 @@ pub(crate) async fn run(
     concurrency: Concurrency,
     cache: &Cache,
     printer: Printer,
-    env_file: Vec<PathBuf>,
-    no_env_file: bool,
     preview: Preview,
-    max_recursion_depth: u32,
+    invocation: Option<RunInvocation>,
+    kernel: Option<Kernel>,
 ) -> anyhow::Result<ExitStatus> {
+    let RunOptions {
+        show_resolution,
+        locked,
+        frozen,
+        active,
+        no_sync,
+        unlock,
+        check_only,
+        isolated,
+        all_packages,
+        no_project,
+        editable,
+        modifications,
+        size_limit,
+        env_file,
+        no_env_file,
+        max_recursion_depth,
+        signal_options,
+        sandbox,
+        path_policy,
+        overlay_mode,
+        require_hashes,
+        allow_user_site,
+        exec,
+        keep_env_on_failure,
+        fs_trace: fs_trace_path,
+        summary: print_summary,
+        summary_json,
+        buffer_output,
+    } = options;
+
+    let start = Instant::now();
+    let buffer_label = if buffer_output { python.clone() } else { None };
+
     // Check if max recursion depth was exceeded. This most commonly happens
     // for scripts with a shebang line like `#!/usr/bin/env -S uv run`, so try
     // to provide guidance for that case.
@@ -147,6 +185,14 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         }
     }
 
+    // The dependency groups are layered in place of any other requirements, e.g., from the
+    // `run.with` setting.
+    if !with_requirements_from.is_empty() && !requirements.is_empty() {
+        bail!(
+            "`--with-requirements-from` can't be combined with other requirements layered on top of the project environment (e.g., from the `run.with` setting)"
+        );
+    }
+
     // Fail early if stdin is used for multiple purposes.
     if matches!(
         command,
@@ -156,6 +202,18 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         bail!("Cannot read both requirements file and script from stdin");
     }
 
+    let fs_trace = fs_trace_path.as_ref().map(|_| FsTrace::default());
+    let mut summary = RunSummary::default();
+
+    // Under `--check-only`, require an up-to-date lockfile and check, rather than update, the
+    // environment.
+    let locked = locked || (check_only && !frozen);
+    let dry_run = if check_only {
+        DryRun::Check
+    } else {
+        DryRun::Disabled
+    };
+
     // Initialize any shared state.
     let lock_state = UniversalState::default();
     let sync_state = lock_state.fork();
@@ -205,8 +263,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     // The lockfile used for the base environment.
     let mut base_lock: Option<(Lock, PathBuf)> = None;
 
+    // The environment for the `--with-requirements-from` groups, if any.
+    let mut groups_env: Option<PythonEnvironment> = None;
+
+    // The ephemeral environments created for the command, which are removed once it exits.
+    let mut ephemeral_dirs = Vec::new();
+
     // Determine whether the command to execute is a PEP 723 script.
-    let temp_dir;
     let script_interpreter = if let Some(script) = script {
         match &script {
             Pep723Item::Script(script) => {
@@ -226,9 +289,18 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
+        // For remote scripts, read the downloaded script, such that the lockfile published
+        // alongside it (if any) is used in the same manner as that of a local script.
+        let remote_script = match (&script, command.as_ref()) {
+            (Pep723Item::Remote(..), Some(RunCommand::PythonRemote(_, file, Some(_), _))) => {
+                Pep723Script::read(file.path()).await?
+            }
+            _ => None,
+        };
+        let locked_script = script.as_script().or(remote_script.as_ref());
+
         // If a lockfile already exists, lock the script.
-        if let Some(target) = script
-            .as_script()
+        if let Some(target) = locked_script
             .map(LockTarget::from)
             .filter(|target| target.lock_path().is_file())
         {
@@ -237,6 +309,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             // Discover the interpreter for the script.
             let environment = ScriptEnvironment::get_or_init(
                 (&script).into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
@@ -245,13 +318,17 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 no_sync,
                 no_config,
                 active.map_or(Some(false), Some),
+                warnings,
                 cache,
-                DryRun::Disabled,
+                dry_run,
                 printer,
                 preview,
             )
-            .await?
-            .into_environment()?;
+            .await?;
+            if let Some(root) = environment.dry_run_target() {
+                return report_missing_environment(root, printer);
+            }
+            let environment = environment.into_environment()?;
 
             let _lock = environment
                 .lock()
@@ -263,7 +340,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             // Determine the lock mode.
             let mode = if frozen {
-                LockMode::Frozen
+                LockMode::FrozenCompatible
             } else if locked {
                 LockMode::Locked(environment.interpreter())
             } else {
@@ -271,6 +348,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             };
 
             // Generate a lockfile.
+            let lock_start = Instant::now();
             let lock = match project::lock::LockOperation::new(
                 mode,
                 &settings.resolver,
@@ -287,29 +365,36 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 printer,
                 preview,
             )
+            .with_fs_trace(fs_trace.as_ref())
             .execute(target)
             .await
             {
-                Ok(result) => result.into_lock(),
+                Ok(result) => {
+                    summary.record_lock(&result);
+                    result.into_lock()
+                }
                 Err(ProjectError::Operation(err)) => {
+                    let failure = SetupFailure::from(&err);
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
                     )
                     .with_context("script")
-                    .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                 }
                 Err(err) => return Err(err.into()),
             };
+            summary.record_phase(Phase::Lock, lock_start.elapsed());
 
             // Sync the environment.
             let target = InstallTarget::Script {
-                script: script.as_script().unwrap(),
+                script: locked_script.unwrap(),
                 lock: &lock,
             };
 
             let install_options = InstallOptions::default();
 
+            let sync_start = Instant::now();
             match project::sync::do_sync(
                 target,
                 &environment,
@@ -318,6 +403,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 editable,
                 install_options,
                 modifications,
+                size_limit,
+                unlock,
                 None,
                 (&settings).into(),
                 &network_settings,
@@ -327,11 +414,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 } else {
                     Box::new(SummaryInstallLogger)
                 },
+                Some(&mut summary),
                 installer_metadata,
                 concurrency,
                 cache,
                 workspace_cache.clone(),
-                DryRun::Disabled,
+                dry_run,
                 printer,
                 preview,
             )
@@ -339,15 +427,18 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             {
                 Ok(()) => {}
                 Err(ProjectError::Operation(err)) => {
+                    let failure = SetupFailure::from(&err);
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
                     )
                     .with_context("script")
-                    .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                 }
                 Err(err) => return Err(err.into()),
             }
+            summary.record_phase(Phase::Sync, sync_start.elapsed());
+            summary.record_interpreter_source("script environment");
 
             // Respect any locked preferences when resolving `--with` dependencies downstream.
             let install_path = target.install_path().to_path_buf();
@@ -355,18 +446,35 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             Some(environment.into_interpreter())
         } else {
+            // Remote scripts can't be locked locally, so `--frozen` requires a published lockfile.
+            if frozen {
+                if let Pep723Item::Remote(.., url) = &script {
+                    bail!(
+                        "No lockfile found for remote script `{}`, but `--frozen` was provided; publish a lockfile at `{}` or declare `tool.uv.lock-url` in the script metadata",
+                        url.cyan(),
+                        format!("{url}.lock").cyan(),
+                    );
+                }
+            }
+
             // If no lockfile is found, warn against `--locked` and `--frozen`.
             if locked {
-                warn_user!(
-                    "No lockfile found for Python script (ignoring `--locked`); run `{}` to generate a lockfile",
-                    "uv lock --script".green(),
-                );
+                EnvironmentWarning::MissingScriptLockfile.emit(
+                    warnings,
+                    format_args!(
+                        "No lockfile found for Python script (ignoring `--locked`); run `{}` to generate a lockfile",
+                        "uv lock --script".green(),
+                    ),
+                )?;
             }
             if frozen {
-                warn_user!(
-                    "No lockfile found for Python script (ignoring `--frozen`); run `{}` to generate a lockfile",
-                    "uv lock --script".green(),
-                );
+                EnvironmentWarning::MissingScriptLockfile.emit(
+                    warnings,
+                    format_args!(
+                        "No lockfile found for Python script (ignoring `--frozen`); run `{}` to generate a lockfile",
+                        "uv lock --script".green(),
+                    ),
+                )?;
             }
 
             // Install the script requirements, if necessary. Otherwise, use an isolated environment.
@@ -375,6 +483,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     script_extra_build_requires((&script).into(), &settings.resolver)?.into_inner();
                 let environment = ScriptEnvironment::get_or_init(
                     (&script).into(),
+                    project_dir,
                     python.as_deref().map(PythonRequest::parse),
                     &network_settings,
                     python_preference,
@@ -383,13 +492,17 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     no_sync,
                     no_config,
                     active.map_or(Some(false), Some),
+                    warnings,
                     cache,
-                    DryRun::Disabled,
+                    dry_run,
                     printer,
                     preview,
                 )
-                .await?
-                .into_environment()?;
+                .await?;
+                if let Some(root) = environment.dry_run_target() {
+                    return report_missing_environment(root, printer);
+                }
+                let environment = environment.into_environment()?;
 
                 let build_constraints = script
                     .metadata()
@@ -416,7 +529,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     })
                     .ok();
 
-                match update_environment(
+                let sync_start = Instant::now();
+                let update = update_environment(
                     environment,
                     spec,
                     modifications,
@@ -439,20 +553,27 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     concurrency,
                     cache,
                     workspace_cache.clone(),
-                    DryRun::Disabled,
+                    dry_run,
                     printer,
                     preview,
                 )
-                .await
-                {
-                    Ok(update) => Some(update.into_environment().into_interpreter()),
+                .await;
+                summary.record_phase(Phase::Sync, sync_start.elapsed());
+                summary.record_interpreter_source("script environment");
+
+                match update {
+                    Ok(update) => {
+                        summary.record_changelog(&update.changelog);
+                        Some(update.into_environment().into_interpreter())
+                    }
                     Err(ProjectError::Operation(err)) => {
+                        let failure = SetupFailure::from(&err);
                         return diagnostics::OperationDiagnostic::native_tls(
                             network_settings.native_tls,
                         )
                         .with_context("script")
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .report(err, printer)
+                        .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                     }
                     Err(err) => return Err(err.into()),
                 }
@@ -460,6 +581,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 // Create a virtual environment.
                 let interpreter = ScriptInterpreter::discover(
                     (&script).into(),
+                    project_dir,
                     python.as_deref().map(PythonRequest::parse),
                     &network_settings,
                     python_preference,
@@ -468,6 +590,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     no_sync,
                     no_config,
                     active.map_or(Some(false), Some),
+                    warnings,
                     cache,
                     printer,
                     preview,
@@ -475,7 +598,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 .await?
                 .into_interpreter();
 
-                temp_dir = cache.venv_dir()?;
+                let temp_dir = EphemeralDir::new(cache)?;
                 let environment = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
@@ -487,6 +610,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     false,
                     preview,
                 )?;
+                if let Some(fs_trace) = fs_trace.as_ref() {
+                    fs_trace.record_tree(temp_dir.path());
+                }
+                ephemeral_dirs.push(temp_dir);
+                summary.record_interpreter_source("ephemeral environment");
 
                 Some(environment.into_interpreter())
             }
@@ -496,7 +624,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     };
 
     // Discover and sync the base environment.
-    let temp_dir;
+    let mut history = None;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
         // If we found a PEP 723 script and the user provided a project-only setting, warn.
         if no_project {
@@ -608,9 +736,25 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             if no_sync {
                 warn_user!("`--no-sync` has no effect when used outside of a project");
             }
+            if !with_requirements_from.is_empty() {
+                warn_user!(
+                    "`--with-requirements-from` has no effect when used outside of a project"
+                );
+            }
         }
 
         if let Some(project) = project {
+            history = Some(RunHistory::for_workspace(project.workspace()));
+
+            // The project environment takes precedence over an interpreter hint in the script.
+            if python.is_none() {
+                if let Some(request) = script_python.as_deref() {
+                    warn_user!(
+                        "Ignoring the `python={request}` hint in the script, since it's run in the project environment; use `--no-project` to respect the hint"
+                    );
+                }
+            }
+
             if let Some(project_name) = project.project_name() {
                 debug!(
                     "Discovered project `{project_name}` at: {}",
@@ -628,6 +772,15 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             let groups = groups.with_defaults(default_groups);
             let extras = extras.with_defaults(default_extras);
 
+            // When running a specific member, respect any Python override for that member.
+            let python = match package.as_ref() {
+                Some(package) if python.is_none() => {
+                    member_python_request(project.workspace(), package, no_config, active, cache)
+                        .await?
+                }
+                _ => python.clone(),
+            };
+
             let venv = if isolated {
                 debug!("Creating isolated virtual environment");
 
@@ -664,6 +817,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors.python_install_dir.as_deref(),
+                    install_mirrors.install_staging_dir.as_deref(),
+                    &install_mirrors.download_client(),
+                    install_mirrors.python_resolution.unwrap_or_default(),
                     preview,
                 )
                 .await?
@@ -680,8 +837,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 }
 
                 // Create a virtual environment
-                temp_dir = cache.venv_dir()?;
-                uv_virtualenv::create_venv(
+                let temp_dir = EphemeralDir::new(cache)?;
+                let venv = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
                     uv_virtualenv::Prompt::None,
@@ -691,11 +848,17 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     false,
                     false,
                     preview,
-                )?
+                )?;
+                if let Some(fs_trace) = fs_trace.as_ref() {
+                    fs_trace.record_tree(temp_dir.path());
+                }
+                ephemeral_dirs.push(temp_dir);
+                summary.record_interpreter_source("isolated environment");
+                venv
             } else {
                 // If we're not isolating the environment, reuse the base environment for the
                 // project.
-                ProjectEnvironment::get_or_init(
+                match ProjectEnvironment::get_or_init(
                     project.workspace(),
                     &groups,
                     python.as_deref().map(PythonRequest::parse),
@@ -706,40 +869,100 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     no_sync,
                     no_config,
                     active,
+                    warnings,
                     cache,
-                    DryRun::Disabled,
+                    dry_run,
+                    unlock,
                     printer,
                     preview,
                 )
-                .await?
-                .into_environment()?
+                .await
+                {
+                    Ok(environment) => {
+                        if let Some(root) = environment.dry_run_target() {
+                            return report_missing_environment(root, printer);
+                        }
+                        summary.record_interpreter_source("project environment");
+                        environment.into_environment()?
+                    }
+                    // If the requested Python version isn't installed and can't be downloaded,
+                    // resolve against markers synthesized for that version, so the lockfile is
+                    // ready once the interpreter is available.
+                    Err(ProjectError::Python(err @ uv_python::Error::MissingPython(..)))
+                        if !python_downloads.is_automatic() && !frozen && !no_sync =>
+                    {
+                        let Some(synthesized) = SynthesizedPython::find(
+                            project.workspace(),
+                            &groups,
+                            python.as_deref().map(PythonRequest::parse).as_ref(),
+                            python_preference,
+                            install_mirrors.python_install_dir.as_deref(),
+                            cache,
+                            preview,
+                        )?
+                        else {
+                            return Err(err.into());
+                        };
+
+                        match synthesized
+                            .lock(
+                                project.workspace(),
+                                locked,
+                                &settings.resolver,
+                                &network_settings,
+                                &lock_state,
+                                Box::new(SummaryResolveLogger),
+                                concurrency,
+                                cache,
+                                &workspace_cache,
+                                printer,
+                                preview,
+                            )
+                            .await
+                        {
+                            Ok(_) => {}
+                            Err(ProjectError::Operation(err)) => {
+                                let failure = SetupFailure::from(&err);
+                                return diagnostics::OperationDiagnostic::native_tls(
+                                    network_settings.native_tls,
+                                )
+                                .report(err, printer)
+                                .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
+                            }
+                            Err(err) => return Err(err.into()),
+                        }
+
+                        return Err(anyhow!("{err}\n\n{}", synthesized.hint()));
+                    }
+                    Err(err) => return Err(err.into()),
+                }
             };
 
             if no_sync {
                 debug!("Skipping environment synchronization due to `--no-sync`");
 
                 // If we're not syncing, we should still attempt to respect the locked preferences
-                // in any `--with` requirements.
+                // in any `--with` requirements, even if the lockfile uses a newer schema.
                 if !isolated && !requirements.is_empty() {
-                    base_lock = LockTarget::from(project.workspace())
-                        .read()
+                    let target = LockTarget::from(project.workspace());
+                    base_lock = target
+                        .read_compatible()
                         .await
                         .ok()
                         .flatten()
+                        .inspect(|_| {
+                            if let Some(fs_trace) = fs_trace.as_ref() {
+                                fs_trace.record(OperationKind::Read, target.lock_path());
+                            }
+                        })
                         .map(|lock| (lock, project.workspace().install_path().to_owned()));
                 }
             } else {
-                let _lock = venv
-                    .lock()
-                    .await
-                    .inspect_err(|err| {
-                        warn!("Failed to acquire environment lock: {err}");
-                    })
-                    .ok();
+                let environment_lock = EnvironmentLock::acquire(&venv, printer).await?;
 
                 // Determine the lock mode.
                 let mode = if frozen {
-                    LockMode::Frozen
+                    LockMode::FrozenCompatible
                 } else if locked {
                     LockMode::Locked(venv.interpreter())
                 } else if isolated {
@@ -748,6 +971,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     LockMode::Write(venv.interpreter())
                 };
 
+                let lock_start = Instant::now();
                 let result = match project::lock::LockOperation::new(
                     mode,
                     &settings.resolver,
@@ -764,19 +988,23 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     printer,
                     preview,
                 )
+                .with_fs_trace(fs_trace.as_ref())
                 .execute(project.workspace().into())
                 .await
                 {
                     Ok(result) => result,
                     Err(ProjectError::Operation(err)) => {
+                        let failure = SetupFailure::from(&err);
                         return diagnostics::OperationDiagnostic::native_tls(
                             network_settings.native_tls,
                         )
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                        .report(err, printer)
+                        .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                     }
                     Err(err) => return Err(err.into()),
                 };
+                summary.record_phase(Phase::Lock, lock_start.elapsed());
+                summary.record_lock(&result);
 
                 // Identify the installation target.
                 let target = match &project {
@@ -828,42 +1056,134 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 target.validate_extras(&extras)?;
                 target.validate_groups(&groups)?;
 
-                match project::sync::do_sync(
-                    target,
-                    &venv,
-                    &extras,
-                    &groups,
-                    editable,
-                    install_options,
-                    modifications,
-                    None,
-                    (&settings).into(),
-                    &network_settings,
-                    &sync_state,
-                    if show_resolution {
-                        Box::new(DefaultInstallLogger)
-                    } else {
-                        Box::new(SummaryInstallLogger)
-                    },
-                    installer_metadata,
-                    concurrency,
-                    cache,
-                    workspace_cache.clone(),
-                    DryRun::Disabled,
-                    printer,
-                    preview,
-                )
-                .await
-                {
-                    Ok(()) => {}
-                    Err(ProjectError::Operation(err)) => {
-                        return diagnostics::OperationDiagnostic::native_tls(
-                            network_settings.native_tls,
-                        )
-                        .report(err)
-                        .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                // Only the packages required by the `--with-requirements-from` groups are layered,
+                // excluding the project itself.
+                let overlay_groups = (!with_requirements_from.is_empty()).then(|| {
+                    DependencyGroups::from_args(
+                        false,
+                        false,
+                        false,
+                        vec![],
+                        vec![],
+                        false,
+                        with_requirements_from.clone(),
+                        false,
+                    )
+                    .with_defaults(DefaultGroups::default())
+                });
+                if let Some(overlay_groups) = &overlay_groups {
+                    target.validate_groups(overlay_groups)?;
+                }
+
+                // If another process synced the environment to the same state while we waited for
+                // the lock, there's nothing left to do.
+                let digest = if dry_run.enabled() {
+                    None
+                } else {
+                    EnvironmentLock::digest(
+                        result.lock(),
+                        &(&extras, &groups, editable, &install_options, modifications),
+                    )
+                };
+                let synced = settings.reinstall.is_none()
+                    && digest
+                        .as_deref()
+                        .is_some_and(|digest| environment_lock.is_synced(digest));
+
+                let sync_start = Instant::now();
+                if synced {
+                    debug!("Skipping sync; the environment was synced by another uv process");
+                } else {
+                    match project::sync::do_sync(
+                        target,
+                        &venv,
+                        &extras,
+                        &groups,
+                        editable,
+                        install_options,
+                        modifications,
+                        size_limit,
+                        unlock,
+                        None,
+                        (&settings).into(),
+                        &network_settings,
+                        &sync_state,
+                        if show_resolution {
+                            Box::new(DefaultInstallLogger)
+                        } else {
+                            Box::new(SummaryInstallLogger)
+                        },
+                        Some(&mut summary),
+                        installer_metadata,
+                        concurrency,
+                        cache,
+                        workspace_cache.clone(),
+                        dry_run,
+                        printer,
+                        preview,
+                    )
+                    .await
+                    {
+                        Ok(()) => {}
+                        Err(ProjectError::Operation(err)) => {
+                            let failure = SetupFailure::from(&err);
+                            return diagnostics::OperationDiagnostic::native_tls(
+                                network_settings.native_tls,
+                            )
+                            .report(err, printer)
+                            .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
+                        }
+                        Err(err) => return Err(err.into()),
+                    }
+                    if let Some(digest) = digest {
+                        environment_lock.record_sync(digest);
                     }
-                    Err(err) => return Err(err.into()),
+                }
+                summary.record_phase(Phase::Sync, sync_start.elapsed());
+
+                // Install the `--with-requirements-from` groups into a cached environment, to be
+                // layered on top of the project environment.
+                if let Some(overlay_groups) = overlay_groups.filter(|_| !dry_run.enabled()) {
+                    debug!("Syncing `--with-requirements-from` groups to cached environment");
+                    let overlay_start = Instant::now();
+                    let result = CachedEnvironment::from_lock(
+                        target,
+                        &overlay_groups,
+                        editable,
+                        &SitePackages::from_environment(&venv)?,
+                        venv.interpreter(),
+                        &settings,
+                        &network_settings,
+                        &sync_state,
+                        if show_resolution {
+                            Box::new(DefaultInstallLogger)
+                        } else {
+                            Box::new(SummaryInstallLogger)
+                        },
+                        Some(&mut summary),
+                        installer_metadata,
+                        concurrency,
+                        cache,
+                        workspace_cache.clone(),
+                        printer,
+                        preview,
+                    )
+                    .await;
+                    let environment = match result {
+                        Ok(environment) => environment,
+                        Err(ProjectError::Operation(err)) => {
+                            let failure = SetupFailure::from(&err);
+                            return diagnostics::OperationDiagnostic::native_tls(
+                                network_settings.native_tls,
+                            )
+                            .with_context("`--with-requirements-from`")
+                            .report(err, printer)
+                            .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+                    groups_env = Some(PythonEnvironment::from(environment));
+                    summary.record_phase(Phase::Overlay, overlay_start.elapsed());
                 }
 
                 base_lock = Some((
@@ -886,7 +1206,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 // (1) Explicit request from user
                 let python_request = if let Some(request) = python.as_deref() {
                     Some(PythonRequest::parse(request))
-                // (2) Request from `.python-version`
+                // (2) Request from an interpreter hint in the script
+                } else if let Some(request) = script_python.as_deref() {
+                    debug!("Using Python request `{request}` from script interpreter hint");
+                    Some(PythonRequest::parse(request))
+                // (3) Request from `.python-version`
                 } else {
                     PythonVersionFile::discover(
                         &project_dir,
@@ -908,18 +1232,41 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     install_mirrors.python_install_mirror.as_deref(),
                     install_mirrors.pypy_install_mirror.as_deref(),
                     install_mirrors.python_downloads_json_url.as_deref(),
+                    install_mirrors.python_install_dir.as_deref(),
+                    install_mirrors.install_staging_dir.as_deref(),
+                    &install_mirrors.download_client(),
+                    install_mirrors.python_resolution.unwrap_or_default(),
                     preview,
                 )
                 .await?;
 
-                python.into_interpreter()
+                // If the user has an active environment that we didn't select, explain why.
+                if !matches!(
+                    python.source(),
+                    PythonSource::ActiveEnvironment | PythonSource::CondaPrefix
+                ) {
+                    if let Some(skip) = find_active_environment_skip(
+                        python_request.as_ref().unwrap_or(&PythonRequest::Default),
+                        cache,
+                    ) {
+                        warn_user!("{skip}");
+                    }
+                }
+
+                summary.record_interpreter_source(python.source());
+
+                // Without a project, the environment isn't acquired via `ProjectEnvironment`, so
+                // refuse a resolution-only environment here.
+                let environment = PythonEnvironment::from_installation(python);
+                refuse_resolution_only(&environment)?;
+                environment.into_interpreter()
             };
 
             if isolated {
                 debug!("Creating isolated virtual environment");
 
                 // If we're isolating the environment, use an ephemeral virtual environment.
-                temp_dir = cache.venv_dir()?;
+                let temp_dir = EphemeralDir::new(cache)?;
                 let venv = uv_virtualenv::create_venv(
                     temp_dir.path(),
                     interpreter,
@@ -931,6 +1278,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     false,
                     preview,
                 )?;
+                if let Some(fs_trace) = fs_trace.as_ref() {
+                    fs_trace.record_tree(temp_dir.path());
+                }
+                ephemeral_dirs.push(temp_dir);
+                summary.record_interpreter_source("isolated environment");
                 venv.into_interpreter()
             } else {
                 interpreter
@@ -944,6 +1296,17 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         base_interpreter.sys_executable().display()
     );
 
+    // Under `--check-only`, the environment is ready; stop before running the command.
+    if check_only {
+        writeln!(
+            printer.stderr(),
+            "Environment is ready to run with Python {} at: {}",
+            base_interpreter.python_version(),
+            base_interpreter.sys_executable().user_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Read the requirements.
     let spec = if requirements.is_empty() {
         None
@@ -962,54 +1325,21 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
     // If necessary, create an environment for the ephemeral requirements or command.
     let base_site_packages = SitePackages::from_interpreter(&base_interpreter)?;
+    let overlay_start = Instant::now();
     let requirements_env = match spec {
-        None => None,
-        Some(spec)
-            if can_skip_ephemeral(&spec, &base_interpreter, &base_site_packages, &settings) =>
-        {
-            None
-        }
+        None => groups_env,
         Some(spec) => {
-            debug!("Syncing `--with` requirements to cached environment");
-
-            // Read the build constraints from the lock file.
-            let build_constraints = base_lock
-                .as_ref()
-                .map(|(lock, path)| lock.build_constraints(path));
-
-            // Read the preferences.
-            let spec = EnvironmentSpecification::from(spec).with_preferences(
-                if let Some((lock, install_path)) = base_lock.as_ref() {
-                    // If we have a lockfile, use the locked versions as preferences.
-                    PreferenceLocation::Lock { lock, install_path }
-                } else {
-                    // Otherwise, extract preferences from the base environment.
-                    PreferenceLocation::Entries(
-                        base_site_packages
-                            .iter()
-                            .filter_map(Preference::from_installed)
-                            .collect::<Vec<_>>(),
-                    )
-                },
-            );
-
-            let result = CachedEnvironment::from_spec(
+            let result = requirements_environment(
                 spec,
-                build_constraints.unwrap_or_default(),
                 &base_interpreter,
+                &base_site_packages,
+                base_lock.as_ref(),
+                require_hashes,
                 &settings,
                 &network_settings,
                 &sync_state,
-                if show_resolution {
-                    Box::new(DefaultResolveLogger)
-                } else {
-                    Box::new(SummaryResolveLogger)
-                },
-                if show_resolution {
-                    Box::new(DefaultInstallLogger)
-                } else {
-                    Box::new(SummaryInstallLogger)
-                },
+                show_resolution,
+                Some(&mut summary),
                 installer_metadata,
                 concurrency,
                 cache,
@@ -1018,187 +1348,48 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             )
             .await;
 
-            let environment = match result {
-                Ok(resolution) => resolution,
+            match result {
+                Ok(environment) => environment,
                 Err(ProjectError::Operation(err)) => {
+                    let failure = SetupFailure::from(&err);
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
                     )
                     .with_context("`--with`")
-                    .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                 }
                 Err(err) => return Err(err.into()),
-            };
-
-            Some(PythonEnvironment::from(environment))
+            }
         }
     };
+    if requirements_env.is_some() {
+        summary.record_phase(Phase::Overlay, overlay_start.elapsed());
+    }
 
     // If we're layering requirements atop the project environment, run the command in an ephemeral,
-    // isolated environment. Otherwise, modifications to the "active virtual environment" would
-    // poison the cache.
-    let ephemeral_dir = requirements_env
-        .as_ref()
-        .map(|_| cache.venv_dir())
-        .transpose()?;
-
-    let ephemeral_env = ephemeral_dir
-        .as_ref()
-        .map(|dir| {
-            debug!(
-                "Creating ephemeral environment at: `{}`",
-                dir.path().simplified_display()
-            );
-
-            uv_virtualenv::create_venv(
-                dir.path(),
-                base_interpreter.clone(),
-                uv_virtualenv::Prompt::None,
-                false,
-                uv_virtualenv::OnExisting::Remove,
-                false,
-                false,
-                false,
-                preview,
-            )
-        })
-        .transpose()?
-        .map(EphemeralEnvironment::from);
-
-    // If we're running in an ephemeral environment, add a path file to enable loading from the
-    // `--with` requirements environment and the project environment site packages.
-    //
-    // Setting `PYTHONPATH` is insufficient, as it doesn't resolve `.pth` files in the base
-    // environment. Adding `sitecustomize.py` would be an alternative, but it can be shadowed by an
-    // existing such module in the python installation.
-    if let Some(ephemeral_env) = ephemeral_env.as_ref() {
-        if let Some(requirements_env) = requirements_env.as_ref() {
-            let requirements_site_packages =
-                requirements_env.site_packages().next().ok_or_else(|| {
-                    anyhow!("Requirements environment has no site packages directory")
-                })?;
-            let mut base_site_packages = base_interpreter
-                .runtime_site_packages()
-                .iter()
-                .map(|path| Cow::Borrowed(path.as_path()))
-                .chain(base_interpreter.site_packages())
-                .peekable();
-            if base_site_packages.peek().is_none() {
-                return Err(anyhow!("Base environment has no site packages directory"));
-            }
-
-            let overlay_content = format!(
-                "import site; {}",
-                std::iter::once(requirements_site_packages)
-                    .chain(base_site_packages)
-                    .dedup()
-                    .inspect(|path| debug!("Adding `{}` to site packages", path.display()))
-                    .map(|path| format!("site.addsitedir(\"{}\")", path.escape_for_python()))
-                    .collect::<Vec<_>>()
-                    .join("; ")
-            );
-
-            ephemeral_env.set_overlay(overlay_content)?;
-
-            // N.B. The order here matters — earlier interpreters take precedence over the
-            // later ones.
-            for interpreter in [requirements_env.interpreter(), &base_interpreter] {
-                // Copy each entrypoint from the base environments to the ephemeral environment,
-                // updating the Python executable target to ensure they run in the ephemeral
-                // environment.
-                for entry in fs_err::read_dir(interpreter.scripts())? {
-                    let entry = entry?;
-                    if !entry.file_type()?.is_file() {
-                        continue;
-                    }
-                    match copy_entrypoint(
-                        &entry.path(),
-                        &ephemeral_env.scripts().join(entry.file_name()),
-                        interpreter.sys_executable(),
-                        ephemeral_env.sys_executable(),
-                    ) {
-                        Ok(()) => {}
-                        // If the entrypoint already exists, skip it.
-                        Err(CopyEntrypointError::Io(err))
-                            if err.kind() == std::io::ErrorKind::AlreadyExists =>
-                        {
-                            trace!(
-                                "Skipping copy of entrypoint `{}`: already exists",
-                                &entry.path().display()
-                            );
-                        }
-                        Err(CopyEntrypointError::Io(err))
-                            if err.kind() == std::io::ErrorKind::PermissionDenied =>
-                        {
-                            trace!(
-                                "Skipping copy of entrypoint `{}`: permission denied",
-                                &entry.path().display()
-                            );
-                        }
-                        Err(err) => return Err(err.into()),
-                    }
-                }
-
-                // Link data directories from the base environment to the ephemeral environment.
-                //
-                // This is critical for Jupyter Lab, which cannot operate without the files it
-                // writes to `<prefix>/share/jupyter`.
-                //
-                // See https://github.com/jupyterlab/jupyterlab/issues/17716
-                for dir in &["etc/jupyter", "share/jupyter"] {
-                    let source = interpreter.sys_prefix().join(dir);
-                    if !matches!(source.try_exists(), Ok(true)) {
-                        continue;
-                    }
-                    if !source.is_dir() {
-                        continue;
-                    }
-                    let target = ephemeral_env.sys_prefix().join(dir);
-                    if let Some(parent) = target.parent() {
-                        fs_err::create_dir_all(parent)?;
-                    }
-                    match create_symlink(&source, &target) {
-                        Ok(()) => trace!(
-                            "Created link for {} -> {}",
-                            target.user_display(),
-                            source.user_display()
-                        ),
-                        Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {}
-                        Err(err) => return Err(err.into()),
-                    }
-                }
-            }
-
-            // Write the `sys.prefix` of the parent environment to the `extends-environment` key of the `pyvenv.cfg`
-            // file. This helps out static-analysis tools such as ty (see docs on
-            // `CachedEnvironment::set_parent_environment`).
-            //
-            // Note that we do this even if the parent environment is not a virtual environment.
-            // For ephemeral environments created by `uv run --with`, the parent environment's
-            // `site-packages` directory is added to `sys.path` even if the parent environment is not
-            // a virtual environment and even if `--system-site-packages` was not explicitly selected.
-            ephemeral_env.set_parent_environment(base_interpreter.sys_prefix())?;
-
-            // If `--system-site-packages` is enabled, add the system site packages to the ephemeral
-            // environment.
-            if base_interpreter.is_virtualenv()
-                && PyVenvConfiguration::parse(base_interpreter.sys_prefix().join("pyvenv.cfg"))
-                    .is_ok_and(|cfg| cfg.include_system_site_packages())
-            {
-                ephemeral_env.set_system_site_packages()?;
-            }
-        }
+    // isolated environment.
+    let composed = EnvironmentComposer::new(&base_interpreter, preview)
+        .with_requirements(requirements_env.as_ref())
+        .with_overlay_mode(overlay_mode)
+        .with_user_site(allow_user_site)
+        .with_fs_trace(fs_trace.as_ref())
+        .compose(cache)?;
+
+    // The environment is fully composed; write the trace of the operations that composed it.
+    if let (Some(fs_trace), Some(fs_trace_path)) = (fs_trace.as_ref(), fs_trace_path.as_deref()) {
+        fs_trace.write(fs_trace_path)?;
     }
 
-    // Cast to `PythonEnvironment`.
-    let ephemeral_env = ephemeral_env.map(PythonEnvironment::from);
-
     // Determine the Python interpreter to use for the command, if necessary.
-    let interpreter = ephemeral_env
-        .as_ref()
-        .or(requirements_env.as_ref())
-        .map_or_else(|| &base_interpreter, |env| env.interpreter());
+    let interpreter = composed.interpreter();
+
+    // Under `--kernel`, the environment is ready to launch the kernel; register it rather than
+    // running a command.
+    if let Some(kernel) = kernel {
+        kernel.register(printer)?;
+        return Ok(ExitStatus::Success);
+    }
 
     // Check if any run command is given.
     // If not, print the available scripts for the current interpreter.
@@ -1271,40 +1462,21 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         return Ok(ExitStatus::Error);
     };
 
+    // If we're running a module, verify that it can be found before spawning the command.
+    if let RunCommand::PythonModule(module, _) = &command {
+        validate_module(module, interpreter, &composed.site_packages())?;
+    }
+
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
+    process.envs(interpreter.query_env());
 
-    // Construct the `PATH` environment variable.
-    let new_path = std::env::join_paths(
-        ephemeral_env
-            .as_ref()
-            .map(PythonEnvironment::scripts)
-            .into_iter()
-            .chain(
-                requirements_env
-                    .as_ref()
-                    .map(PythonEnvironment::scripts)
-                    .into_iter(),
-            )
-            .chain(std::iter::once(base_interpreter.scripts()))
-            .chain(
-                // On Windows, non-virtual Python distributions put `python.exe` in the top-level
-                // directory, rather than in the `Scripts` subdirectory.
-                cfg!(windows)
-                    .then(|| base_interpreter.sys_executable().parent())
-                    .flatten()
-                    .into_iter(),
-            )
-            .dedup()
-            .map(PathBuf::from)
-            .chain(
-                std::env::var_os(EnvVars::PATH)
-                    .as_ref()
-                    .iter()
-                    .flat_map(std::env::split_paths),
-            ),
-    )?;
-    process.env(EnvVars::PATH, new_path);
+    // Construct the `PATH` environment variable, or clear it entirely.
+    if let Some(new_path) = composed.path(&path_policy)? {
+        process.env(EnvVars::PATH, new_path);
+    } else {
+        process.env_remove(EnvVars::PATH);
+    }
 
     // Increment recursion depth counter.
     process.env(
@@ -1317,14 +1489,455 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
     }
 
+    // Exclude the user site packages directory from ephemeral environments, unless requested, such
+    // that packages installed with `pip install --user` can't leak into the command via the base
+    // interpreter.
+    if composed.ephemeral().is_some() && !allow_user_site {
+        process.env(EnvVars::PYTHONNOUSERSITE, "1");
+    }
+
+    // Replace the uv process with the command, if requested.
+    if exec {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+
+            // The network namespace can't be created in place, as uv is multi-threaded.
+            if isolated && sandbox.network.is_off() {
+                anyhow::bail!("`--exec` is not supported with `--network off`");
+            }
+
+            // The command replaces uv, so record the invocation before it starts.
+            if let (Some(history), Some(invocation)) = (history, invocation) {
+                let record = RunRecord::new(invocation, None, start.elapsed());
+                if let Err(err) = history.record(record).await {
+                    warn!("Failed to record the invocation in the `uv run` history: {err}");
+                }
+            }
+
+            // Hand the ephemeral environments over to the command, such that a concurrent cleanup
+            // pass doesn't remove them while it's running. Once the command exits, they're
+            // removed by the next cleanup pass.
+            for dir in composed.ephemeral_dir().into_iter().chain(&ephemeral_dirs) {
+                dir.inherit_on_exec()?;
+            }
+
+            debug!(
+                "Replacing the uv process with: `{}`",
+                command.display_executable()
+            );
+            let err = process.as_std_mut().exec();
+            return Err(anyhow::Error::from(err).context(format!(
+                "Failed to exec: `{}`",
+                command.display_executable()
+            )));
+        }
+        #[cfg(not(unix))]
+        warn_user!("`--exec` is not supported on Windows; running the command as a child process");
+    }
+
     // Spawn and wait for completion
     // Standard input, output, and error streams are all inherited
     // TODO(zanieb): Throw a nicer error message if the command is not found
-    let handle = process
-        .spawn()
-        .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))?;
+    signal_options.configure(&mut process);
+    if isolated {
+        sandbox.configure(&mut process);
+    } else if sandbox.network.is_off() {
+        debug!("Ignoring `run.network` setting outside of an isolated environment");
+    }
+
+    // When the command is run under multiple Python versions concurrently, buffer its output, such
+    // that the output of each run is shown as a block once it completes.
+    let buffered = if buffer_output {
+        let stdout = tempfile::tempfile()?;
+        let stderr = tempfile::tempfile()?;
+        process.stdout(stdout.try_clone()?);
+        process.stderr(stderr.try_clone()?);
+        Some((stdout, stderr))
+    } else {
+        None
+    };
+
+    let handle = process.spawn().with_context(|| {
+        if isolated && sandbox.network.is_off() {
+            format!(
+                "Failed to spawn `{}` without network access",
+                command.display_executable()
+            )
+        } else {
+            format!("Failed to spawn: `{}`", command.display_executable())
+        }
+    })?;
+
+    let command_start = Instant::now();
+    let status = run_to_completion(handle, signal_options).await;
+    summary.record_phase(Phase::Command, command_start.elapsed());
 
-    run_to_completion(handle).await
+    if let Some((stdout, stderr)) = buffered {
+        write_buffered_output(buffer_label.as_deref(), stdout, stderr)?;
+    }
+
+    if print_summary || summary_json.is_some() {
+        summary.finish(
+            interpreter,
+            start.elapsed(),
+            print_summary,
+            summary_json.as_deref(),
+            printer,
+        )?;
+    }
+
+    if let (Some(history), Some(invocation)) = (history, invocation) {
+        let record = RunRecord::new(invocation, status.as_ref().ok().copied(), start.elapsed());
+        if let Err(err) = history.record(record).await {
+            warn!("Failed to record the invocation in the `uv run` history: {err}");
+        }
+    }
+
+    // If the command failed, keep the ephemeral environments in which it ran for inspection.
+    if keep_env_on_failure && !matches!(status, Ok(ExitStatus::Success | ExitStatus::External(0))) {
+        let root = interpreter.sys_prefix().to_path_buf();
+        let scripts = interpreter.scripts().to_path_buf();
+        let mut kept = false;
+        for dir in composed
+            .into_ephemeral_dir()
+            .into_iter()
+            .chain(ephemeral_dirs)
+        {
+            let path = dir.keep_for_inspection(cache, &command.to_string())?;
+            kept |= path == root;
+        }
+        if kept {
+            writeln!(
+                printer.stderr(),
+                "The environment in which the command failed was kept at: {}",
+                root.user_display().cyan()
+            )?;
+            if let Some(activation) = activation_command(&scripts) {
+                writeln!(printer.stderr(), "Activate with: {}", activation.green())?;
+            }
+            writeln!(
+                printer.stderr(),
+                "Remove it with: {}",
+                "uv cache prune --ephemeral".green()
+            )?;
+        }
+    }
+
+    status
+}
+
+/// Report that the environment at `root` is missing or incompatible, and so would need to be
+/// created or replaced, under `--check-only`.
+fn report_missing_environment(root: &Path, printer: Printer) -> anyhow::Result<ExitStatus> {
+    writeln!(
+        printer.stderr(),
+        "The environment at `{}` is missing or incompatible and would be recreated",
+        root.user_display().cyan(),
+    )?;
+    Ok(ExitStatus::Setup(SetupFailure::Environment))
+}
+
+/// Create a [`Command`] that runs the Python executable of the interpreter.
+///
+/// If a non-native slice of a macOS universal binary was selected, the executable is run as that
+/// slice via `arch`.
+fn python_command(interpreter: &Interpreter) -> Command {
+    let mut process = arch_command(interpreter, interpreter.sys_executable());
+    process.envs(interpreter.query_env());
+    process
+}
+
+/// Create a [`Command`] that runs the given program, e.g., an entrypoint of the environment, as
+/// the selected slice of a macOS universal binary, if any.
+fn arch_command(interpreter: &Interpreter, program: &Path) -> Command {
+    let (program, args) = interpreter.arch_command_for(program);
+    let mut process = Command::new(program);
+    process.args(args);
+    process
+}
+
+/// `.pth` files whose `import` lines configure the interpreter, rather than install import hooks
+/// that provide modules.
+const CONFIGURATION_PTH: &[&str] = &["_virtualenv.pth", "distutils-precedence.pth"];
+
+/// Verify that the top-level package of a module passed to `uv run -m` can be imported by the
+/// interpreter.
+///
+/// The module is resolved on disk, without spawning the interpreter, against the working
+/// directory, `PYTHONPATH`, the standard library, and the given `site-packages` directories along
+/// with the paths added by their `.pth` files (e.g., for editable installs). Where the search path
+/// can't be determined on disk, e.g., if a `.pth` file installs an import hook or the environment
+/// includes the system or user site packages, the check is skipped and Python reports any errors.
+///
+/// If the module can't be found, returns an error with suggestions derived from the modules and
+/// distributions in the given `site-packages` directories.
+fn validate_module(
+    module: &OsStr,
+    interpreter: &Interpreter,
+    site_packages: &[PathBuf],
+) -> anyhow::Result<()> {
+    // Defer to Python for anything that isn't a valid module name.
+    let Some(module) = module.to_str() else {
+        return Ok(());
+    };
+    let Some(top_level) = module.split('.').next().filter(|name| !name.is_empty()) else {
+        return Ok(());
+    };
+
+    // Defer to Python if packages outside the environment are visible to the interpreter.
+    let isolated = interpreter.is_virtualenv()
+        && PyVenvConfiguration::parse(interpreter.sys_prefix().join("pyvenv.cfg"))
+            .is_ok_and(|cfg| !cfg.include_system_site_packages() && !cfg.allows_user_site());
+    if !isolated {
+        return Ok(());
+    }
+
+    let mut search_path = vec![std::env::current_dir()?];
+    search_path.extend(
+        std::env::var_os(EnvVars::PYTHONPATH)
+            .iter()
+            .flat_map(std::env::split_paths),
+    );
+    search_path.push(interpreter.stdlib().to_path_buf());
+    search_path.push(interpreter.stdlib().join("lib-dynload"));
+    search_path.push(interpreter.sys_base_prefix().join("DLLs"));
+    for dir in site_packages {
+        search_path.push(dir.clone());
+        let Some(entries) = pth_entries(dir) else {
+            return Ok(());
+        };
+        search_path.extend(entries);
+    }
+
+    // Zip archives, e.g., eggs, can't be searched on disk.
+    if search_path.iter().any(|path| path.is_file()) {
+        return Ok(());
+    }
+
+    if search_path
+        .iter()
+        .any(|dir| provides_module(dir, top_level))
+    {
+        return Ok(());
+    }
+
+    let suggestions = module_suggestions(top_level, site_packages);
+    let mut message = format!(
+        "Module `{}` was not found in the environment",
+        module.cyan()
+    );
+    if !suggestions.is_empty() {
+        let suggestions = suggestions
+            .iter()
+            .map(|suggestion| format!("`{}`", suggestion.cyan()))
+            .join(", ");
+        write!(
+            message,
+            "\n\n{}{} Did you mean {suggestions}?",
+            "hint".bold().cyan(),
+            ":".bold()
+        )?;
+    }
+    Err(anyhow!(message))
+}
+
+/// Return the directories added to the search path by the `.pth` files in a `site-packages`
+/// directory.
+///
+/// Returns `None` if a `.pth` file installs an import hook, as the modules it provides can't be
+/// determined on disk.
+fn pth_entries(site_packages: &Path) -> Option<Vec<PathBuf>> {
+    let mut entries = Vec::new();
+    for entry in fs_err::read_dir(site_packages)
+        .into_iter()
+        .flatten()
+        .flatten()
+    {
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "pth") {
+            continue;
+        }
+        let Ok(contents) = fs_err::read_to_string(&path) else {
+            continue;
+        };
+        for line in contents.lines().map(str::trim_end) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with("import ") || line.starts_with("import\t") {
+                if CONFIGURATION_PTH
+                    .iter()
+                    .any(|name| path.file_name().is_some_and(|file_name| file_name == *name))
+                {
+                    continue;
+                }
+                return None;
+            }
+            entries.push(site_packages.join(line));
+        }
+    }
+    Some(entries)
+}
+
+/// Returns `true` if the directory contains the given top-level module, as a package (including
+/// namespace packages), a source or bytecode module, or an extension module.
+fn provides_module(dir: &Path, name: &str) -> bool {
+    if dir.join(name).is_dir()
+        || dir.join(format!("{name}.py")).is_file()
+        || dir.join(format!("{name}.pyc")).is_file()
+    {
+        return true;
+    }
+
+    // Extension modules may carry a platform tag, e.g., `name.cpython-312-x86_64-linux-gnu.so`.
+    fs_err::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| {
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else {
+                return false;
+            };
+            file_name
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .is_some_and(|rest| {
+                    rest == "so" || rest == "pyd" || rest.ends_with(".so") || rest.ends_with(".pyd")
+                })
+        })
+}
+
+/// Find importable modules in the given `site-packages` directories that plausibly match the
+/// requested top-level module name.
+///
+/// A module matches if its name is equal to the request after normalizing case and separators,
+/// or if it's provided by an installed distribution whose name matches the request (e.g., `yaml`
+/// for `PyYAML`).
+fn module_suggestions(top_level: &str, site_packages: &[PathBuf]) -> BTreeSet<String> {
+    fn normalize(name: &str) -> String {
+        name.to_lowercase().replace(['-', '.'], "_")
+    }
+
+    let requested = normalize(top_level);
+    let mut suggestions = BTreeSet::new();
+    for entry in site_packages
+        .iter()
+        .filter_map(|dir| fs_err::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+    {
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if let Some(dist_info) = name.strip_suffix(".dist-info") {
+            let distribution = dist_info
+                .split_once('-')
+                .map_or(dist_info, |(distribution, _)| distribution);
+            if normalize(distribution) != requested {
+                continue;
+            }
+            let Ok(contents) = fs_err::read_to_string(path.join("top_level.txt")) else {
+                continue;
+            };
+            suggestions.extend(
+                contents
+                    .lines()
+                    .map(str::trim)
+                    .filter(|module| !module.is_empty() && !module.starts_with('_'))
+                    .map(ToString::to_string),
+            );
+        } else {
+            let (candidate, is_module) = match name.strip_suffix(".py") {
+                Some(stem) => (stem, true),
+                None => (name, path.is_dir()),
+            };
+            if is_module && candidate != top_level && normalize(candidate) == requested {
+                suggestions.insert(candidate.to_string());
+            }
+        }
+    }
+    suggestions.remove(top_level);
+    suggestions
+}
+
+/// Create the environment for the `--with` requirements, to be layered atop the base
+/// interpreter.
+///
+/// Returns `None` if the base environment already satisfies the requirements.
+pub(crate) async fn requirements_environment(
+    spec: RequirementsSpecification,
+    base_interpreter: &Interpreter,
+    base_site_packages: &SitePackages,
+    base_lock: Option<&(Lock, PathBuf)>,
+    require_hashes: bool,
+    settings: &ResolverInstallerSettings,
+    network_settings: &NetworkSettings,
+    state: &PlatformState,
+    show_resolution: bool,
+    summary: Option<&mut RunSummary>,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<Option<PythonEnvironment>, ProjectError> {
+    if can_skip_ephemeral(&spec, base_interpreter, base_site_packages, settings) {
+        return Ok(None);
+    }
+
+    debug!("Syncing `--with` requirements to cached environment");
+
+    // Read the build constraints from the lock file.
+    let build_constraints = base_lock.map(|(lock, path)| lock.build_constraints(path));
+
+    // Read the preferences.
+    let spec = EnvironmentSpecification::from(spec).with_preferences(
+        if let Some((lock, install_path)) = base_lock {
+            // If we have a lockfile, use the locked versions as preferences.
+            PreferenceLocation::Lock { lock, install_path }
+        } else {
+            // Otherwise, extract preferences from the base environment.
+            PreferenceLocation::Entries(
+                base_site_packages
+                    .iter()
+                    .filter_map(Preference::from_installed)
+                    .collect::<Vec<_>>(),
+            )
+        },
+    );
+
+    let environment = CachedEnvironment::from_spec(
+        spec,
+        build_constraints.unwrap_or_default(),
+        require_hashes,
+        Some(base_site_packages),
+        base_interpreter,
+        settings,
+        network_settings,
+        state,
+        if show_resolution {
+            Box::new(DefaultResolveLogger)
+        } else {
+            Box::new(SummaryResolveLogger)
+        },
+        if show_resolution {
+            Box::new(DefaultInstallLogger)
+        } else {
+            Box::new(SummaryInstallLogger)
+        },
+        summary,
+        installer_metadata,
+        concurrency,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    Ok(Some(PythonEnvironment::from(environment)))
 }
 
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
@@ -1416,14 +2029,56 @@ pub(crate) enum RunCommand {
     PythonStdin(Vec<u8>, Vec<OsString>),
     /// Execute a `pythonw` script provided via `stdin`.
     PythonGuiStdin(Vec<u8>, Vec<OsString>),
-    /// Execute a Python script provided via a remote URL.
-    PythonRemote(DisplaySafeUrl, tempfile::NamedTempFile, Vec<OsString>),
+    /// Execute a Python script provided via a remote URL, along with the lockfile published
+    /// alongside it, if any.
+    PythonRemote(
+        DisplaySafeUrl,
+        tempfile::NamedTempFile,
+        Option<tempfile::TempPath>,
+        Vec<OsString>,
+    ),
     /// Execute an external command.
     External(OsString, Vec<OsString>),
     /// Execute an empty command (in practice, `python` with no arguments).
     Empty,
 }
 
+/// An enhanced REPL that can be launched by `uv run`, e.g., with `uv run --ipython`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Repl {
+    /// [IPython](https://ipython.org/).
+    IPython,
+    /// [ptpython](https://github.com/prompt-toolkit/ptpython).
+    PtPython,
+}
+
+impl Repl {
+    /// The requirement that provides the REPL.
+    pub(crate) fn requirement(self) -> &'static str {
+        match self {
+            Self::IPython => "ipython",
+            Self::PtPython => "ptpython",
+        }
+    }
+
+    /// The command that launches the REPL.
+    pub(crate) fn command(self) -> RunCommand {
+        match self {
+            Self::IPython => RunCommand::External(OsString::from("ipython"), vec![]),
+            Self::PtPython => RunCommand::External(OsString::from("ptpython"), vec![]),
+        }
+    }
+}
+
+impl std::fmt::Display for Repl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IPython => write!(f, "ipython"),
+            Self::PtPython => write!(f, "ptpython"),
+        }
+    }
+}
+
 impl RunCommand {
     /// Return the name of the target executable, for display purposes.
     fn display_executable(&self) -> Cow<'_, str> {
@@ -1460,7 +2115,7 @@ impl RunCommand {
     fn as_command(&self, interpreter: &Interpreter) -> Command {
         match self {
             Self::Python(args) => {
-                let mut process = Command::new(interpreter.sys_executable());
+                let mut process = python_command(interpreter);
                 process.args(args);
                 process
             }
@@ -1470,31 +2125,31 @@ impl RunCommand {
 
                 // If the target is an installed, executable script — prefer that
                 if uv_fs::which::is_executable(&entrypoint) {
-                    let mut process = Command::new(entrypoint);
+                    let mut process = arch_command(interpreter, &entrypoint);
                     process.args(args);
                     process
                 // Otherwise, invoke `python <module>`
                 } else {
-                    let mut process = Command::new(interpreter.sys_executable());
+                    let mut process = python_command(interpreter);
                     process.arg(path);
                     process.args(args);
                     process
                 }
             }
             Self::PythonScript(target, args) | Self::PythonZipapp(target, args) => {
-                let mut process = Command::new(interpreter.sys_executable());
+                let mut process = python_command(interpreter);
                 process.arg(target);
                 process.args(args);
                 process
             }
-            Self::PythonRemote(.., target, args) => {
-                let mut process = Command::new(interpreter.sys_executable());
+            Self::PythonRemote(_, target, _, args) => {
+                let mut process = python_command(interpreter);
                 process.arg(target.path());
                 process.args(args);
                 process
             }
             Self::PythonModule(module, args) => {
-                let mut process = Command::new(interpreter.sys_executable());
+                let mut process = python_command(interpreter);
                 process.arg("-m");
                 process.arg(module);
                 process.args(args);
@@ -1520,7 +2175,7 @@ impl RunCommand {
                 process
             }
             Self::PythonStdin(script, args) => {
-                let mut process = Command::new(interpreter.sys_executable());
+                let mut process = python_command(interpreter);
                 process.arg("-c");
 
                 #[cfg(unix)]
@@ -1574,12 +2229,51 @@ impl RunCommand {
                 let mut process = if cfg!(windows) {
                     WindowsRunnable::from_script_path(interpreter.scripts(), executable).into()
                 } else {
-                    Command::new(executable)
+                    arch_command(interpreter, Path::new(executable))
                 };
                 process.args(args);
                 process
             }
-            Self::Empty => Command::new(interpreter.sys_executable()),
+            Self::Empty => python_command(interpreter),
+        }
+    }
+}
+
+impl RunCommand {
+    /// Clone the [`RunCommand`], if possible.
+    ///
+    /// Remote scripts are backed by a temporary file and cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        match self {
+            Self::Python(args) => Some(Self::Python(args.clone())),
+            Self::PythonScript(target, args) => {
+                Some(Self::PythonScript(target.clone(), args.clone()))
+            }
+            Self::PythonModule(module, args) => {
+                Some(Self::PythonModule(module.clone(), args.clone()))
+            }
+            Self::PythonGuiScript(target, args) => {
+                Some(Self::PythonGuiScript(target.clone(), args.clone()))
+            }
+            Self::PythonPackage(target, path, args) => Some(Self::PythonPackage(
+                target.clone(),
+                path.clone(),
+                args.clone(),
+            )),
+            Self::PythonZipapp(target, args) => {
+                Some(Self::PythonZipapp(target.clone(), args.clone()))
+            }
+            Self::PythonStdin(source, args) => {
+                Some(Self::PythonStdin(source.clone(), args.clone()))
+            }
+            Self::PythonGuiStdin(source, args) => {
+                Some(Self::PythonGuiStdin(source.clone(), args.clone()))
+            }
+            Self::PythonRemote(..) => None,
+            Self::External(executable, args) => {
+                Some(Self::External(executable.clone(), args.clone()))
+            }
+            Self::Empty => Some(Self::Empty),
         }
     }
 }
@@ -1706,8 +2400,89 @@ async fn resolve_gist_url(
     Ok(url)
 }
 
+/// Fetch the lockfile for a remote script, if one is published, writing it adjacent to the
+/// downloaded script at `path` (i.e., where `uv lock --script` would write it).
+///
+/// The lockfile is read from the `tool.uv.lock-url` declared in the script metadata, or from the
+/// script URL with a `.lock` suffix (e.g., `https://example.com/script.py.lock`).
+///
+/// If the lockfile can't be fetched, e.g., due to a network error, the script is run without it,
+/// unless the lockfile is `required` (i.e., with `--locked` or `--frozen`).
+async fn fetch_remote_lock(
+    client: &BaseClient,
+    url: &DisplaySafeUrl,
+    path: &Path,
+    required: bool,
+) -> anyhow::Result<Option<tempfile::TempPath>> {
+    let lock_url = match Pep723Metadata::read(path).await {
+        Ok(metadata) => metadata
+            .and_then(|metadata| metadata.tool)
+            .and_then(|tool| tool.uv)
+            .and_then(|uv| uv.lock_url),
+        Err(err) => {
+            debug!("Failed to read inline script metadata from remote script: {err}");
+            None
+        }
+    };
+    let lock_url = if let Some(lock_url) = lock_url {
+        lock_url
+    } else {
+        let mut lock_url = url.clone();
+        lock_url.set_path(&format!("{}.lock", url.path()));
+        lock_url
+    };
+
+    debug!("Checking for remote script lockfile at: `{lock_url}`");
+    let contents = async {
+        let response = client
+            .for_host(&lock_url)
+            .get(Url::from(lock_url.clone()))
+            .send()
+            .await?;
+        // A client error indicates that no lockfile is published; any other failure is an error.
+        if response.status().is_client_error() {
+            debug!(
+                "No lockfile found for remote script (status: {})",
+                response.status()
+            );
+            return Ok(None);
+        }
+        let contents = response.error_for_status()?.bytes().await?;
+        Ok::<_, anyhow::Error>(Some(contents))
+    }
+    .await;
+    let contents = match contents {
+        Ok(Some(contents)) => contents,
+        Ok(None) => return Ok(None),
+        Err(err) if required => {
+            return Err(err.context(format!(
+                "Failed to fetch the lockfile for the remote script from `{lock_url}`"
+            )));
+        }
+        Err(err) => {
+            debug!("Failed to fetch remote script lockfile: {err:#}");
+            warn_user!(
+                "Failed to fetch the lockfile for the remote script from `{}`; running without a lockfile",
+                lock_url.cyan()
+            );
+            return Ok(None);
+        }
+    };
+
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".lock");
+    let lock_path = path.with_file_name(file_name);
+    fs_err::tokio::write(&lock_path, contents).await?;
+    debug!("Downloaded remote script lockfile from: `{lock_url}`");
+
+    Ok(Some(tempfile::TempPath::from_path(lock_path)))
+}
+
 impl RunCommand {
     /// Determine the [`RunCommand`] for a given set of arguments.
+    ///
+    /// If `require_lock` is set, i.e., with `--locked` or `--frozen`, failing to fetch the lockfile
+    /// published alongside a remote script is an error.
     #[allow(clippy::fn_params_excessive_bools)]
     pub(crate) async fn from_args(
         command: &ExternalCommand,
@@ -1715,6 +2490,7 @@ impl RunCommand {
         module: bool,
         script: bool,
         gui_script: bool,
+        require_lock: bool,
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
         let Some(target) = target else {
@@ -1780,7 +2556,9 @@ impl RunCommand {
                     writer.write_all(&chunk?)?;
                 }
 
-                return Ok(Self::PythonRemote(url, file, args.to_vec()));
+                let lock = fetch_remote_lock(&client, &url, file.path(), require_lock).await?;
+
+                return Ok(Self::PythonRemote(url, file, lock, args.to_vec()));
             }
         }
 
@@ -1827,6 +2605,131 @@ impl RunCommand {
     }
 }
 
+/// Split a `--python` value into multiple Python requests, e.g., `3.10,3.11,3.12`.
+///
+/// Returns `None` if the value is a single request. Version specifiers that contain commas, like
+/// `>=3.10,<3.12`, are treated as a single request.
+pub(crate) fn python_matrix(python: &str) -> Option<Vec<String>> {
+    if !python.contains(',') {
+        return None;
+    }
+    let requests = python.split(',').map(str::trim).collect::<Vec<_>>();
+    if requests
+        .iter()
+        .any(|request| request.is_empty() || request.starts_with(['<', '>', '=', '!', '~']))
+    {
+        return None;
+    }
+    Some(requests.into_iter().map(ToString::to_string).collect())
+}
+
+/// Run a command once for each Python request in a matrix, then report the outcome of each run.
+///
+/// The runs are performed sequentially unless `parallel` is set. The returned status is the
+/// status of the first failing run, or success if all runs succeeded.
+pub(crate) async fn run_matrix<F, Fut>(
+    pythons: Vec<String>,
+    parallel: bool,
+    printer: Printer,
+    run: F,
+) -> anyhow::Result<ExitStatus>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = anyhow::Result<ExitStatus>>,
+{
+    let results = if parallel {
+        futures::future::join_all(pythons.iter().cloned().map(&run)).await
+    } else {
+        let mut results = Vec::with_capacity(pythons.len());
+        for python in &pythons {
+            writeln!(
+                printer.stderr(),
+                "{} {}",
+                "Running with Python".bold(),
+                python.cyan()
+            )?;
+            results.push(run(python.clone()).await);
+        }
+        results
+    };
+
+    writeln!(printer.stderr())?;
+    let mut status = ExitStatus::Success;
+    for (python, result) in pythons.iter().zip(results) {
+        match result {
+            Ok(ExitStatus::Success) => {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    python.cyan(),
+                    "success".green()
+                )?;
+            }
+            Ok(result) => {
+                let code = result.code();
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {} (exit code {code})",
+                    python.cyan(),
+                    "failed".red()
+                )?;
+                if matches!(status, ExitStatus::Success) {
+                    status = result;
+                }
+            }
+            Err(err) => {
+                writeln!(printer.stderr(), "  {}: {}", python.cyan(), "error".red())?;
+                for cause in err.chain() {
+                    writeln!(
+                        printer.stderr(),
+                        "    {}: {}",
+                        "Caused by".red().bold(),
+                        cause.to_string().trim()
+                    )?;
+                }
+                if matches!(status, ExitStatus::Success) {
+                    status = ExitStatus::Error;
+                }
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Write the buffered output of a command run under one of multiple Python versions, preceded by
+/// a header, without interleaving it with the output of the other runs.
+fn write_buffered_output(
+    python: Option<&str>,
+    stdout: impl Read + Seek,
+    stderr: impl Read + Seek,
+) -> anyhow::Result<()> {
+    use std::io::Write as _;
+
+    let stdout_buffer = read_buffer(stdout)?;
+    let stderr_buffer = read_buffer(stderr)?;
+
+    let mut out = anstream::stdout().lock();
+    let mut err = anstream::stderr().lock();
+    if let Some(python) = python {
+        writeln!(err, "{} {}", "Output with Python".bold(), python.cyan())?;
+        err.flush()?;
+    }
+    out.write_all(&stdout_buffer)?;
+    out.flush()?;
+    err.write_all(&stderr_buffer)?;
+    err.flush()?;
+    Ok(())
+}
+
+/// Read the full contents of a buffer that the child process wrote to.
+fn read_buffer(mut buffer: impl Read + Seek) -> std::io::Result<Vec<u8>> {
+    let mut contents = Vec::new();
+    buffer.rewind()?;
+    buffer.read_to_end(&mut contents)?;
+    Ok(contents)
+}
+
 /// Returns `true` if the target is a ZIP archive containing a `__main__.py` file.
 fn is_python_zipapp(target: &Path) -> bool {
     if let Ok(file) = fs_err::File::open(target) {
@@ -1858,140 +2761,101 @@ fn read_recursion_depth_from_environment_variable() -> anyhow::Result<u32> {
         .with_context(|| format!("invalid value for {}", EnvVars::UV_RUN_RECURSION_DEPTH))
 }
 
-#[derive(Error, Debug)]
-enum CopyEntrypointError {
-    #[error(transparent)]
-    Io(#[from] std::io::Error),
-    #[cfg(windows)]
-    #[error(transparent)]
-    Trampoline(#[from] uv_trampoline_builder::Error),
-}
-
-/// Create a copy of the entrypoint at `source` at `target`, if it has a Python shebang, replacing
-/// the previous Python executable with a new one.
-///
-/// This is a no-op if the target already exists.
-///
-/// Note on Windows, the entrypoints do not use shebangs and require a rewrite of the trampoline.
-#[cfg(unix)]
-fn copy_entrypoint(
-    source: &Path,
-    target: &Path,
-    previous_executable: &Path,
-    python_executable: &Path,
-) -> Result<(), CopyEntrypointError> {
-    use std::io::{Seek, Write};
-    use std::os::unix::fs::PermissionsExt;
-
-    use fs_err::os::unix::fs::OpenOptionsExt;
-
-    let mut file = fs_err::File::open(source)?;
-    let mut buffer = [0u8; 2];
-    if file.read_exact(&mut buffer).is_err() {
-        // File is too small to have a shebang
-        trace!(
-            "Skipping copy of entrypoint `{}`: file is too small to contain a shebang",
-            source.user_display()
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write as _};
+
+    use uv_cli::DiagnosticsFormat;
+
+    use super::*;
+    use crate::printer::Verbosity;
+
+    #[test]
+    fn test_python_matrix() {
+        assert_eq!(python_matrix("3.12"), None);
+        assert_eq!(
+            python_matrix("3.10,3.11,3.12"),
+            Some(vec![
+                "3.10".to_string(),
+                "3.11".to_string(),
+                "3.12".to_string()
+            ])
         );
-        return Ok(());
+        assert_eq!(
+            python_matrix("3.11, pypy@3.10"),
+            Some(vec!["3.11".to_string(), "pypy@3.10".to_string()])
+        );
+        assert_eq!(python_matrix(">=3.10,<3.12"), None);
+        assert_eq!(python_matrix("3.11,<3.12"), None);
+        assert_eq!(python_matrix("3.11,"), None);
+        assert_eq!(python_matrix(",3.11"), None);
     }
 
-    // Check if it starts with `#!` to avoid reading binary files and such into memory
-    if &buffer != b"#!" {
-        trace!(
-            "Skipping copy of entrypoint `{}`: does not start with #!",
-            source.user_display()
-        );
-        return Ok(());
+    #[tokio::test]
+    async fn test_run_matrix_success() -> anyhow::Result<()> {
+        let pythons = vec!["3.11".to_string(), "3.12".to_string()];
+        for parallel in [false, true] {
+            let status = run_matrix(
+                pythons.clone(),
+                parallel,
+                Printer::new(Verbosity::Silent, DiagnosticsFormat::Text),
+                |_| async { Ok(ExitStatus::Success) },
+            )
+            .await?;
+            assert!(matches!(status, ExitStatus::Success));
+        }
+        Ok(())
     }
 
-    let mut contents = String::new();
-    file.seek(std::io::SeekFrom::Start(0))?;
-    match file.read_to_string(&mut contents) {
-        Ok(_) => {}
-        Err(err) if err.kind() == std::io::ErrorKind::InvalidData => {
-            // If the file is not valid UTF-8, we skip it in case it was a binary file with `#!` at
-            // the start (which seems pretty niche, but being defensive here seems safe)
-            trace!(
-                "Skipping copy of entrypoint `{}`: is not valid UTF-8",
-                source.user_display()
-            );
-            return Ok(());
+    #[tokio::test]
+    async fn test_run_matrix_first_failure() -> anyhow::Result<()> {
+        let pythons = vec!["3.10".to_string(), "3.11".to_string(), "3.12".to_string()];
+        for parallel in [false, true] {
+            let status = run_matrix(
+                pythons.clone(),
+                parallel,
+                Printer::new(Verbosity::Silent, DiagnosticsFormat::Text),
+                |python| async move {
+                    match python.as_str() {
+                        "3.10" => Ok(ExitStatus::Success),
+                        "3.11" => Ok(ExitStatus::Failure),
+                        _ => Err(anyhow::anyhow!("No interpreter found")),
+                    }
+                },
+            )
+            .await?;
+            assert!(matches!(status, ExitStatus::Failure));
         }
-        Err(err) => return Err(err.into()),
+        Ok(())
     }
 
-    let Some(contents) = contents
-        // Check for a relative path or relocatable shebang
-        .strip_prefix(
-            r#"#!/bin/sh
-'''exec' "$(dirname -- "$(realpath -- "$0")")"/'python' "$0" "$@"
-' '''
-"#,
+    #[tokio::test]
+    async fn test_run_matrix_error() -> anyhow::Result<()> {
+        let pythons = vec!["3.11".to_string(), "3.12".to_string()];
+        let status = run_matrix(
+            pythons,
+            true,
+            Printer::new(Verbosity::Silent, DiagnosticsFormat::Text),
+            |python| async move {
+                if python == "3.11" {
+                    Err(anyhow::anyhow!("No interpreter found"))
+                } else {
+                    Ok(ExitStatus::Failure)
+                }
+            },
         )
-        // Or, an absolute path shebang
-        .or_else(|| contents.strip_prefix(&format!("#!{}\n", previous_executable.display())))
-        // If the previous executable ends with `python3`, check for a shebang with `python` too
-        .or_else(|| {
-            previous_executable
-                .to_str()
-                .and_then(|path| path.strip_suffix("3"))
-                .and_then(|path| contents.strip_prefix(&format!("#!{path}\n")))
-        })
-    else {
-        // If it's not a Python shebang, we'll skip it
-        trace!(
-            "Skipping copy of entrypoint `{}`: does not start with expected shebang",
-            source.user_display()
-        );
-        return Ok(());
-    };
-
-    let contents = format!("#!{}\n{}", python_executable.display(), contents);
-    let mode = fs_err::metadata(source)?.permissions().mode();
-    let mut file = fs_err::OpenOptions::new()
-        .create_new(true)
-        .write(true)
-        .mode(mode)
-        .open(target)?;
-    file.write_all(contents.as_bytes())?;
-
-    trace!("Updated entrypoint at {}", target.user_display());
-
-    Ok(())
-}
-
-/// Create a copy of the entrypoint at `source` at `target`, if it's a Python script launcher,
-/// replacing the target Python executable with a new one.
-#[cfg(windows)]
-fn copy_entrypoint(
-    source: &Path,
-    target: &Path,
-    _previous_executable: &Path,
-    python_executable: &Path,
-) -> Result<(), CopyEntrypointError> {
-    use uv_trampoline_builder::Launcher;
-
-    let Some(launcher) = Launcher::try_from_path(source)? else {
-        return Ok(());
-    };
-
-    let is_gui = launcher.python_path.ends_with("pythonw.exe");
-
-    let python_path = if is_gui {
-        python_executable.with_file_name("pythonw.exe")
-    } else {
-        python_executable.to_path_buf()
-    };
-
-    let launcher = launcher.with_python_path(python_path);
-    let mut file = fs_err::OpenOptions::new()
-        .create_new(true)
-        .write(true)
-        .open(target)?;
-    launcher.write_to_file(&mut file)?;
-
-    trace!("Updated entrypoint at {}", target.user_display());
+        .await?;
+        assert!(matches!(status, ExitStatus::Error));
+        Ok(())
+    }
 
-    Ok(())
+    #[test]
+    fn test_read_buffer() -> anyhow::Result<()> {
+        // The buffer is read from the start, regardless of the current position.
+        let mut buffer = Cursor::new(Vec::new());
+        buffer.write_all(b"hello\n")?;
+        assert_eq!(read_buffer(&mut buffer)?, b"hello\n");
+        assert_eq!(read_buffer(&mut buffer)?, b"hello\n");
+        Ok(())
+    }
 }
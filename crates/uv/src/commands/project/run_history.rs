@@ -0,0 +1,280 @@
+//! A per-project history of `uv run` invocations, used by `uv run --last` and `uv run --history`.
+//!
+//! The history is stored in the project environment, such that it's local to the project and
+//! ignored by version control, and is cleared when the environment is recreated.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::bail;
+use itertools::Itertools;
+use jiff::Timestamp;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use uv_cli::{ExternalCommand, Maybe, RunArgs, comma::CommaSeparatedRequirements};
+use uv_fs::{LockedFile, Simplified};
+use uv_shell::escape_posix_for_single_quotes;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+
+/// The name of the history file in the project environment.
+const HISTORY_FILE: &str = "uv-run-history.json";
+
+/// The name of the file used to lock the history against concurrent writers.
+const HISTORY_LOCK_FILE: &str = "uv-run-history.lock";
+
+/// The maximum number of invocations to retain.
+const MAX_ENTRIES: usize = 50;
+
+/// The arguments of a `uv run` invocation that are replayed by `uv run --last`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RunInvocation {
+    /// The command, including its arguments.
+    command: Vec<String>,
+    /// The `--python` request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    python: Option<String>,
+    /// The `--with` requirements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    with: Vec<String>,
+    /// The `--with-editable` requirements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    with_editable: Vec<String>,
+    /// The `--with-requirements` files.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    with_requirements: Vec<PathBuf>,
+}
+
+impl RunInvocation {
+    /// Capture the invocation from the command-line arguments, if a command was provided.
+    ///
+    /// Requirements from `tool.uv.run.with` aren't included, since they're read from the
+    /// configuration again when the invocation is replayed.
+    pub(crate) fn from_args(args: &RunArgs) -> Option<Self> {
+        let command = args.command.as_ref()?;
+        Some(Self {
+            command: command
+                .iter()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect(),
+            python: args.python.clone().and_then(Maybe::into_option),
+            with: args.with.iter().cloned().flatten().collect(),
+            with_editable: args.with_editable.iter().cloned().flatten().collect(),
+            with_requirements: args
+                .with_requirements
+                .iter()
+                .cloned()
+                .filter_map(Maybe::into_option)
+                .collect(),
+        })
+    }
+
+    /// Replace the command and `--with` requirements of the arguments with those of the
+    /// invocation, along with the `--python` request, if the invocation included one.
+    pub(crate) fn apply(self, args: &mut RunArgs) {
+        args.command = Some(ExternalCommand::Cmd(
+            self.command.into_iter().map(Into::into).collect(),
+        ));
+        if let Some(python) = self.python {
+            args.python = Some(Maybe::Some(python));
+        }
+        args.with = self
+            .with
+            .into_iter()
+            .map(CommaSeparatedRequirements::from)
+            .collect();
+        args.with_editable = self
+            .with_editable
+            .into_iter()
+            .map(CommaSeparatedRequirements::from)
+            .collect();
+        args.with_requirements = self
+            .with_requirements
+            .into_iter()
+            .map(Maybe::Some)
+            .collect();
+    }
+}
+
+impl std::fmt::Display for RunInvocation {
+    /// Display the invocation as the equivalent `uv run` arguments, quoted for a POSIX shell.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(python) = &self.python {
+            write!(f, "--python {} ", quote(python))?;
+        }
+        for requirement in &self.with {
+            write!(f, "--with {} ", quote(requirement))?;
+        }
+        for requirement in &self.with_editable {
+            write!(f, "--with-editable {} ", quote(requirement))?;
+        }
+        for path in &self.with_requirements {
+            write!(
+                f,
+                "--with-requirements {} ",
+                quote(&path.user_display().to_string())
+            )?;
+        }
+        write!(f, "{}", self.command.iter().map(|arg| quote(arg)).join(" "))
+    }
+}
+
+/// Quote an argument for a POSIX shell, if necessary, like Python's `shlex.quote`.
+fn quote(arg: &str) -> Cow<'_, str> {
+    if !arg.is_empty()
+        && arg.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, '@' | '%' | '+' | '=' | ':' | ',' | '.' | '/' | '_' | '-')
+        })
+    {
+        Cow::Borrowed(arg)
+    } else {
+        Cow::Owned(format!("'{}'", escape_posix_for_single_quotes(arg)))
+    }
+}
+
+/// A recorded `uv run` invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RunRecord {
+    #[serde(flatten)]
+    invocation: RunInvocation,
+    /// The exit code of the command, or `None` if it couldn't be determined.
+    exit_code: Option<i32>,
+    /// The duration of the invocation, in seconds, including locking and syncing.
+    duration: f64,
+    /// When the invocation finished.
+    timestamp: Timestamp,
+}
+
+impl RunRecord {
+    pub(crate) fn new(
+        invocation: RunInvocation,
+        status: Option<ExitStatus>,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            invocation,
+            exit_code: status.map(|status| i32::from(status.code())),
+            duration: duration.as_secs_f64(),
+            timestamp: Timestamp::now(),
+        }
+    }
+}
+
+/// The history of `uv run` invocations in a project.
+#[derive(Debug, Clone)]
+pub(crate) struct RunHistory {
+    path: PathBuf,
+}
+
+impl RunHistory {
+    /// The history for a workspace, stored in its project environment.
+    pub(crate) fn for_workspace(workspace: &Workspace) -> Self {
+        Self {
+            path: workspace.venv(Some(false)).join(HISTORY_FILE),
+        }
+    }
+
+    /// Discover the history for the project in the given directory.
+    pub(crate) async fn discover(
+        project_dir: &Path,
+        workspace_cache: &WorkspaceCache,
+    ) -> anyhow::Result<Self> {
+        let Ok(workspace) =
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), workspace_cache).await
+        else {
+            bail!("The `uv run` history is only available in a project");
+        };
+        Ok(Self::for_workspace(&workspace))
+    }
+
+    /// Read the recorded invocations, from oldest to newest.
+    pub(crate) fn read(&self) -> io::Result<Vec<RunRecord>> {
+        let contents = match fs_err::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        match serde_json::from_slice(&contents) {
+            Ok(records) => Ok(records),
+            Err(err) => {
+                debug!(
+                    "Ignoring invalid `uv run` history at `{}`: {err}",
+                    self.path.user_display()
+                );
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// Return the most recent invocation, if any.
+    pub(crate) fn last(&self) -> io::Result<Option<RunInvocation>> {
+        Ok(self.read()?.pop().map(|record| record.invocation))
+    }
+
+    /// Record an invocation, discarding the oldest invocations beyond the limit.
+    ///
+    /// The history is locked while it's updated, such that concurrent invocations don't overwrite
+    /// each other's records. If the project environment doesn't exist, e.g., with `--isolated`,
+    /// nothing is recorded.
+    pub(crate) async fn record(&self, record: RunRecord) -> io::Result<()> {
+        let Some(parent) = self.path.parent().filter(|parent| parent.is_dir()) else {
+            return Ok(());
+        };
+        let _lock =
+            LockedFile::acquire(parent.join(HISTORY_LOCK_FILE), self.path.user_display()).await?;
+
+        let mut records = self.read()?;
+        records.push(record);
+        if records.len() > MAX_ENTRIES {
+            records.drain(..records.len() - MAX_ENTRIES);
+        }
+        let contents = serde_json::to_vec(&records).map_err(io::Error::other)?;
+        uv_fs::write_atomic_sync(&self.path, contents)
+    }
+}
+
+/// Show the recorded `uv run` invocations in the project, from oldest to newest.
+pub(crate) async fn history(
+    project_dir: &Path,
+    workspace_cache: &WorkspaceCache,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    let records = RunHistory::discover(project_dir, workspace_cache)
+        .await?
+        .read()?;
+
+    if records.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No `uv run` invocations have been recorded"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for record in records {
+        let exit_code = record
+            .exit_code
+            .map_or_else(|| "unknown".to_string(), |code| code.to_string());
+        let status = format!(
+            "{}, exit code {exit_code}, {}",
+            record.timestamp.strftime("%Y-%m-%d %H:%M:%S UTC"),
+            elapsed(Duration::from_secs_f64(record.duration))
+        );
+        writeln!(
+            printer.stdout(),
+            "{} {}",
+            format!("uv run {}", record.invocation).bold(),
+            format!("({status})").dimmed()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,238 @@
+//! A report of the work performed to compose the environment for `uv run`, enabled via
+//! `uv run --summary` or `uv run --summary-json`.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_python::Interpreter;
+
+use crate::commands::elapsed;
+use crate::commands::pip::operations::Changelog;
+use crate::commands::project::lock::LockResult;
+use crate::printer::Printer;
+
+/// Whether the lockfile was reused or updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum LockfileStatus {
+    /// The existing lockfile was up-to-date.
+    Reused,
+    /// The lockfile was created or updated.
+    Updated,
+}
+
+/// Whether the environment for `--with` requirements was found in the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum OverlayStatus {
+    /// The environment was found in the cache.
+    Hit,
+    /// The environment was created.
+    Miss,
+}
+
+/// A phase of `uv run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Phase {
+    /// Locking the project or script.
+    Lock,
+    /// Syncing the project or script environment.
+    Sync,
+    /// Creating or reusing the environment for `--with` requirements.
+    Overlay,
+    /// Running the command.
+    Command,
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lock => write!(f, "lock"),
+            Self::Sync => write!(f, "sync"),
+            Self::Overlay => write!(f, "overlay"),
+            Self::Command => write!(f, "command"),
+        }
+    }
+}
+
+/// The interpreter used to run the command.
+#[derive(Debug, Clone, Serialize)]
+struct InterpreterSummary {
+    path: PathBuf,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+/// The wall time spent in a [`Phase`].
+#[derive(Debug, Clone, Serialize)]
+struct PhaseSummary {
+    phase: Phase,
+    seconds: f64,
+}
+
+/// A report of the work performed by a single `uv run` invocation.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct RunSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lockfile: Option<LockfileStatus>,
+    installed: usize,
+    uninstalled: usize,
+    reinstalled: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interpreter: Option<InterpreterSummary>,
+    #[serde(skip)]
+    interpreter_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overlay: Option<OverlayStatus>,
+    phases: Vec<PhaseSummary>,
+    seconds: f64,
+}
+
+impl RunSummary {
+    /// Record the outcome of locking the project or script.
+    pub(crate) fn record_lock(&mut self, result: &LockResult) {
+        let status = match result {
+            LockResult::Unchanged(..) => LockfileStatus::Reused,
+            LockResult::Changed(..) => LockfileStatus::Updated,
+        };
+        // If any lock was updated, report the lockfile as updated.
+        if self.lockfile != Some(LockfileStatus::Updated) {
+            self.lockfile = Some(status);
+        }
+    }
+
+    /// Record the changes made to an environment.
+    pub(crate) fn record_changelog(&mut self, changelog: &Changelog) {
+        self.installed += changelog.installed.len();
+        self.uninstalled += changelog.uninstalled.len();
+        self.reinstalled += changelog.reinstalled.len();
+    }
+
+    /// Record where the base interpreter came from, e.g., the project environment.
+    pub(crate) fn record_interpreter_source(&mut self, source: impl std::fmt::Display) {
+        self.interpreter_source = Some(source.to_string());
+    }
+
+    /// Record whether the environment for `--with` requirements was found in the cache.
+    pub(crate) fn record_overlay(&mut self, status: OverlayStatus) {
+        self.overlay = Some(status);
+    }
+
+    /// Record the wall time spent in a phase.
+    ///
+    /// Repeated phases are accumulated.
+    pub(crate) fn record_phase(&mut self, phase: Phase, duration: Duration) {
+        if let Some(existing) = self
+            .phases
+            .iter_mut()
+            .find(|existing| existing.phase == phase)
+        {
+            existing.seconds += duration.as_secs_f64();
+        } else {
+            self.phases.push(PhaseSummary {
+                phase,
+                seconds: duration.as_secs_f64(),
+            });
+        }
+    }
+
+    /// Complete the summary with the interpreter used to run the command and the total wall time,
+    /// then print it to stderr and/or write it to `json` as JSON.
+    pub(crate) fn finish(
+        mut self,
+        interpreter: &Interpreter,
+        total: Duration,
+        print: bool,
+        json: Option<&Path>,
+        printer: Printer,
+    ) -> anyhow::Result<()> {
+        self.interpreter = Some(InterpreterSummary {
+            path: interpreter.sys_executable().to_path_buf(),
+            version: interpreter.python_version().to_string(),
+            source: self.interpreter_source.take(),
+        });
+        self.seconds = total.as_secs_f64();
+
+        if let Some(path) = json {
+            debug!("Writing run summary to: `{}`", path.user_display());
+            let contents = serde_json::to_string_pretty(&self)?;
+            fs_err::write(path, contents + "\n")?;
+        }
+
+        if print {
+            write_summary(&self, printer)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_summary(summary: &RunSummary, printer: Printer) -> std::fmt::Result {
+    let mut stderr = printer.stderr();
+    writeln!(stderr, "{}", "Summary:".bold())?;
+    if let Some(interpreter) = summary.interpreter.as_ref() {
+        write!(
+            stderr,
+            "  Interpreter: Python {} at `{}`",
+            interpreter.version,
+            interpreter.path.user_display().cyan()
+        )?;
+        if let Some(source) = interpreter.source.as_ref() {
+            write!(stderr, " ({source})")?;
+        }
+        writeln!(stderr)?;
+    }
+    if let Some(lockfile) = summary.lockfile {
+        let status = match lockfile {
+            LockfileStatus::Reused => "reused",
+            LockfileStatus::Updated => "updated",
+        };
+        writeln!(stderr, "  Lockfile: {status}")?;
+    }
+    writeln!(
+        stderr,
+        "  Packages: {} installed, {} uninstalled, {} reinstalled",
+        summary.installed, summary.uninstalled, summary.reinstalled
+    )?;
+    if let Some(overlay) = summary.overlay {
+        let status = match overlay {
+            OverlayStatus::Hit => "cache hit",
+            OverlayStatus::Miss => "cache miss",
+        };
+        writeln!(stderr, "  Overlay: {status}")?;
+    }
+    let phases = summary
+        .phases
+        .iter()
+        .map(|phase| {
+            format!(
+                "{} {}",
+                phase.phase,
+                elapsed(Duration::from_secs_f64(phase.seconds))
+            )
+        })
+        .collect::<Vec<_>>();
+    let total = format!(
+        "{} total",
+        elapsed(Duration::from_secs_f64(summary.seconds))
+    );
+    if phases.is_empty() {
+        writeln!(stderr, "  Time: {}", total.dimmed())?;
+    } else {
+        writeln!(
+            stderr,
+            "  Time: {} {}",
+            phases.join(", "),
+            format!("({total})").dimmed()
+        )?;
+    }
+    Ok(())
+}
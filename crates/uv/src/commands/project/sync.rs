@@ -3,30 +3,38 @@ use std::ops::Deref;
 use std::path::Path;
 use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use serde::Serialize;
-use tracing::warn;
+use tracing::debug;
 use uv_cache::Cache;
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    Preview, PreviewFeatures, TargetTriple, Upgrade,
+    ActivationShell, BuildOptions, Concurrency, Constraints, DependencyGroups,
+    DependencyGroupsWithDefaults, DryRun, EditableMode, ExtrasSpecification,
+    ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions, Preview, PreviewFeatures,
+    TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, InstalledDist, Name, Requirement, Resolution, ResolvedDist,
+    SourceDist,
 };
 use uv_fs::{PortablePathBuf, Simplified};
+use uv_install_wheel::{RecordMismatch, RecordMismatchKind};
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_pep440::Version;
 use uv_pep508::{MarkerTree, VersionOrUrl};
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_python::{
+    PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, PythonVariant, Target,
+    VersionRequest,
+};
 use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
@@ -38,16 +46,21 @@ use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace,
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_markers;
+use crate::commands::pip::size_limit::EnvironmentSizeLimit;
 use crate::commands::pip::{operations, resolution_tags};
+use crate::commands::project::environment_lock::EnvironmentLock;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::run_summary::RunSummary;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
-    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
-    default_dependency_groups, detect_conflicts, script_extra_build_requires, script_specification,
+    PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptEnvironment,
+    SynthesizedPython, UniversalState, WorkspacePython, activation, default_dependency_groups,
+    detect_conflicts, member_python_request, script_extra_build_requires, script_specification,
     update_environment,
 };
-use crate::commands::{ExitStatus, diagnostics};
+use crate::commands::{ExitStatus, SetupFailure, diagnostics};
 use crate::printer::Printer;
 use crate::settings::{
     InstallerSettingsRef, NetworkSettings, ResolverInstallerSettings, ResolverSettings,
@@ -68,8 +81,13 @@ pub(crate) async fn sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    size_limit: Option<EnvironmentSizeLimit>,
+    unlock: bool,
+    warnings: WarningPolicy,
     python: Option<String>,
     python_platform: Option<TargetTriple>,
+    target_dir: Option<Target>,
+    verify: bool,
     install_mirrors: PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -83,6 +101,7 @@ pub(crate) async fn sync(
     printer: Printer,
     preview: Preview,
     output_format: SyncFormat,
+    export_activate: Vec<ActivationShell>,
 ) -> Result<ExitStatus> {
     if preview.is_enabled(PreviewFeatures::JSON_OUTPUT) && matches!(output_format, SyncFormat::Json)
     {
@@ -145,30 +164,155 @@ pub(crate) async fn sync(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
+    // When syncing a specific member, respect any Python override for that member.
+    let python = match (&target, package.as_ref()) {
+        (SyncTarget::Project(project), Some(package)) if python.is_none() => {
+            member_python_request(project.workspace(), package, no_config, active, cache).await?
+        }
+        _ => python,
+    };
+
+    // If the existing lockfile can only be installed on a free-threaded interpreter, require one.
+    let freethreaded = match &target {
+        SyncTarget::Project(project) => {
+            freethreaded_request(
+                project.workspace(),
+                python.as_deref().map(PythonRequest::parse),
+                &groups,
+                project_dir,
+                no_config,
+            )
+            .await?
+        }
+        SyncTarget::Script(..) => None,
+    };
+
     // Discover or create the virtual environment.
-    let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
-            ProjectEnvironment::get_or_init(
+    let environment = match (&target, target_dir) {
+        // With `--target`, install into the directory using the project's interpreter, without
+        // creating or modifying the project environment.
+        (SyncTarget::Project(project), Some(target_dir)) => {
+            let interpreter = ProjectInterpreter::discover(
                 project.workspace(),
+                project_dir,
                 &groups,
-                python.as_deref().map(PythonRequest::parse),
-                &install_mirrors,
+                match &freethreaded {
+                    Some((_, request)) => Some(request.clone()),
+                    None => python.as_deref().map(PythonRequest::parse),
+                },
                 &network_settings,
                 python_preference,
                 python_downloads,
+                &install_mirrors,
                 false,
                 no_config,
-                active,
+                None,
+                warnings,
                 cache,
-                dry_run,
                 printer,
                 preview,
             )
-            .await?,
-        ),
-        SyncTarget::Script(script) => SyncEnvironment::Script(
+            .await?
+            .into_interpreter();
+            debug!(
+                "Using `--target` directory at {}",
+                target_dir.root().user_display()
+            );
+            SyncEnvironment::Target(
+                PythonEnvironment::from_interpreter(interpreter).with_target(target_dir)?,
+            )
+        }
+        (SyncTarget::Project(project), None) => match ProjectEnvironment::get_or_init(
+            project.workspace(),
+            &groups,
+            match &freethreaded {
+                Some((_, request)) => Some(request.clone()),
+                None => python.as_deref().map(PythonRequest::parse),
+            },
+            &install_mirrors,
+            &network_settings,
+            python_preference,
+            python_downloads,
+            false,
+            no_config,
+            active,
+            warnings,
+            cache,
+            dry_run,
+            unlock,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(environment) => SyncEnvironment::Project(environment),
+            Err(ProjectError::Python(err @ uv_python::Error::MissingPython(..))) => {
+                if let Some((package, _)) = freethreaded {
+                    return Err(ProjectError::MissingFreethreadedPython(package, err).into());
+                }
+
+                // If the requested Python version isn't installed and can't be downloaded,
+                // resolve against markers synthesized for that version, so the lockfile is ready
+                // once the interpreter is available.
+                if python_downloads.is_automatic() || frozen || dry_run.enabled() {
+                    return Err(ProjectError::Python(err).into());
+                }
+                let Some(synthesized) = SynthesizedPython::find(
+                    project.workspace(),
+                    &groups,
+                    python.as_deref().map(PythonRequest::parse).as_ref(),
+                    python_preference,
+                    install_mirrors.python_install_dir.as_deref(),
+                    cache,
+                    preview,
+                )?
+                else {
+                    return Err(ProjectError::Python(err).into());
+                };
+
+                match synthesized
+                    .lock(
+                        project.workspace(),
+                        locked,
+                        &settings.resolver,
+                        &network_settings,
+                        &UniversalState::default(),
+                        Box::new(DefaultResolveLogger),
+                        concurrency,
+                        cache,
+                        &workspace_cache,
+                        printer,
+                        preview,
+                    )
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(ProjectError::Operation(err)) => {
+                        let failure = SetupFailure::from(&err);
+                        return diagnostics::OperationDiagnostic::native_tls(
+                            network_settings.native_tls,
+                        )
+                        .report(err, printer)
+                        .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
+                    }
+                    Err(ProjectError::LockMismatch(prev, cur)) => {
+                        diagnostics::project_error(
+                            &ProjectError::LockMismatch(prev, cur),
+                            printer,
+                        )?;
+                        return Ok(ExitStatus::Setup(SetupFailure::Lockfile));
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+
+                return Err(anyhow!("{err}\n\n{}", synthesized.hint()));
+            }
+            Err(err) => return Err(err.into()),
+        },
+        (SyncTarget::Script(script), _) => SyncEnvironment::Script(
             ScriptEnvironment::get_or_init(
                 script.into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 &network_settings,
                 python_preference,
@@ -177,6 +321,7 @@ pub(crate) async fn sync(
                 false,
                 no_config,
                 active,
+                warnings,
                 cache,
                 dry_run,
                 printer,
@@ -186,13 +331,7 @@ pub(crate) async fn sync(
         ),
     };
 
-    let _lock = environment
-        .lock()
-        .await
-        .inspect_err(|err| {
-            warn!("Failed to acquire environment lock: {err}");
-        })
-        .ok();
+    let environment_lock = EnvironmentLock::acquire(&environment, printer).await?;
 
     let sync_report = SyncReport {
         dry_run: dry_run.enabled(),
@@ -206,6 +345,22 @@ pub(crate) async fn sync(
         writeln!(printer.stderr(), "{message}")?;
     }
 
+    // Under `--verify`, there's nothing to compare against if the environment doesn't exist or
+    // would need to be replaced.
+    if let Some(root) = environment.dry_run_target().filter(|_| verify) {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "The environment at `{}` is missing or incompatible; run `{}` to create it",
+                root.user_display(),
+                "uv sync".green(),
+            )
+            .bold()
+        )?;
+        return Ok(ExitStatus::Setup(SetupFailure::Environment));
+    }
+
     // Special-case: we're syncing a script that doesn't have an associated lockfile. In that case,
     // we don't create a lockfile, so the resolve-and-install semantics are different.
     if let SyncTarget::Script(script) = &target {
@@ -278,6 +433,7 @@ pub(crate) async fn sync(
                         script: Some(ScriptReport::from(script)),
                         sync: sync_report,
                         lock: None,
+                        verify: None,
                         dry_run: dry_run.enabled(),
                     };
                     if let Some(output) = report.format(output_format) {
@@ -287,11 +443,12 @@ pub(crate) async fn sync(
                 }
                 // TODO(zanieb): We should respect `--output-format json` for the error case
                 Err(ProjectError::Operation(err)) => {
+                    let failure = SetupFailure::from(&err);
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
                     )
-                    .report(err)
-                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
                 }
                 Err(err) => return Err(err.into()),
             }
@@ -301,8 +458,9 @@ pub(crate) async fn sync(
     // Initialize any shared state.
     let state = UniversalState::default();
 
-    // Determine the lock mode.
-    let mode = if frozen {
+    // Determine the lock mode. Under `--verify`, the lockfile is used as-is, unless `--locked` is
+    // provided.
+    let mode = if frozen || (verify && !locked) {
         LockMode::Frozen
     } else if locked {
         LockMode::Locked(environment.interpreter())
@@ -334,9 +492,10 @@ pub(crate) async fn sync(
     {
         Ok(result) => Outcome::Success(result),
         Err(ProjectError::Operation(err)) => {
+            let failure = SetupFailure::from(&err);
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .report(err, printer)
+                .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
         }
         Err(ProjectError::LockMismatch(prev, cur)) => {
             if dry_run.enabled() {
@@ -344,12 +503,8 @@ pub(crate) async fn sync(
                 // sync operation, but exit with a non-zero status.
                 Outcome::LockMismatch(prev, cur)
             } else {
-                writeln!(
-                    printer.stderr(),
-                    "{}",
-                    ProjectError::LockMismatch(prev, cur).to_string().bold()
-                )?;
-                return Ok(ExitStatus::Failure);
+                diagnostics::project_error(&ProjectError::LockMismatch(prev, cur), printer)?;
+                return Ok(ExitStatus::Setup(SetupFailure::Lockfile));
             }
         }
         Err(err) => return Err(err.into()),
@@ -360,6 +515,24 @@ pub(crate) async fn sync(
         writeln!(printer.stderr(), "{message}")?;
     }
 
+    // Under `--verify`, compare the environment against the lockfile rather than syncing it.
+    let verify_report = if verify {
+        let verify_target =
+            identify_installation_target(&target, outcome.lock(), all_packages, package.as_ref());
+        Some(do_verify(
+            verify_target,
+            &environment,
+            &extras,
+            &groups,
+            editable,
+            &install_options,
+            python_platform.as_ref(),
+            &settings.resolver.build_options,
+        )?)
+    } else {
+        None
+    };
+
     let report = Report {
         schema: SchemaReport::default(),
         target: TargetName::from(&target),
@@ -367,6 +540,7 @@ pub(crate) async fn sync(
         script: target.script().map(ScriptReport::from),
         sync: sync_report,
         lock: Some(lock_report),
+        verify: verify_report,
         dry_run: dry_run.enabled(),
     };
 
@@ -374,12 +548,38 @@ pub(crate) async fn sync(
         writeln!(printer.stdout_important(), "{output}")?;
     }
 
+    if let Some(verify_report) = &report.verify {
+        if let Some(message) = verify_report.format(output_format) {
+            writeln!(printer.stderr(), "{message}")?;
+        }
+        if let Outcome::LockMismatch(prev, cur) = outcome {
+            diagnostics::project_error(&ProjectError::LockMismatch(prev, cur), printer)?;
+            return Ok(ExitStatus::Setup(SetupFailure::Lockfile));
+        }
+        return Ok(if verify_report.is_empty() {
+            ExitStatus::Success
+        } else {
+            ExitStatus::Failure
+        });
+    }
+
     // Identify the installation target.
     let sync_target =
         identify_installation_target(&target, outcome.lock(), all_packages, package.as_ref());
 
     let state = state.fork();
 
+    // Compute the digest to record once the sync completes, such that concurrent `uv run`
+    // invocations waiting on the environment lock can skip an identical sync.
+    let digest = if dry_run.enabled() || python_platform.is_some() {
+        None
+    } else {
+        EnvironmentLock::digest(
+            outcome.lock(),
+            &(&extras, &groups, editable, &install_options, modifications),
+        )
+    };
+
     // Perform the sync operation.
     match do_sync(
         sync_target,
@@ -389,11 +589,14 @@ pub(crate) async fn sync(
         editable,
         install_options,
         modifications,
+        size_limit,
+        unlock,
         python_platform.as_ref(),
         (&settings).into(),
         &network_settings,
         &state,
         Box::new(DefaultInstallLogger),
+        None,
         installer_metadata,
         concurrency,
         cache,
@@ -406,22 +609,36 @@ pub(crate) async fn sync(
     {
         Ok(()) => {}
         Err(ProjectError::Operation(err)) => {
+            let failure = SetupFailure::from(&err);
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
-                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+                .report(err, printer)
+                .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
     }
 
+    if let Some(digest) = digest {
+        environment_lock.record_sync(digest);
+    }
+
+    // Export the activation scripts for the project environment, if requested.
+    if !export_activate.is_empty() && !dry_run.enabled() {
+        if let (SyncTarget::Project(project), SyncEnvironment::Project(environment)) =
+            (&target, &environment)
+        {
+            activation::write(
+                project.workspace().install_path(),
+                environment,
+                &export_activate,
+            )?;
+        }
+    }
+
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur) => {
-            writeln!(
-                printer.stderr(),
-                "{}",
-                ProjectError::LockMismatch(prev, cur).to_string().bold()
-            )?;
-            Ok(ExitStatus::Failure)
+            diagnostics::project_error(&ProjectError::LockMismatch(prev, cur), printer)?;
+            Ok(ExitStatus::Setup(SetupFailure::Lockfile))
         }
     }
 }
@@ -499,6 +716,50 @@ fn identify_installation_target<'a>(
     }
 }
 
+/// If the existing lockfile contains a package that can only be installed on a free-threaded
+/// interpreter, return that package along with a free-threaded variant of the workspace's Python
+/// request.
+async fn freethreaded_request(
+    workspace: &Workspace,
+    python_request: Option<PythonRequest>,
+    groups: &DependencyGroupsWithDefaults,
+    project_dir: &Path,
+    no_config: bool,
+) -> Result<Option<(PackageName, PythonRequest)>, ProjectError> {
+    // Errors reading the lockfile are surfaced later, when the lockfile is validated.
+    let Ok(Some(lock)) = LockTarget::from(workspace).read().await else {
+        return Ok(None);
+    };
+    let Some(package) = lock.freethreaded_package() else {
+        return Ok(None);
+    };
+
+    let WorkspacePython { python_request, .. } = WorkspacePython::from_request(
+        python_request,
+        Some(workspace),
+        groups,
+        project_dir,
+        no_config,
+    )
+    .await?;
+    let python_request = python_request.unwrap_or_else(|| {
+        PythonRequest::Version(VersionRequest::Range(
+            lock.requires_python().specifiers().clone(),
+            PythonVariant::Default,
+        ))
+    });
+    let Some(python_request) = python_request.into_freethreaded() else {
+        return Ok(None);
+    };
+
+    debug!(
+        "Requiring a free-threaded interpreter, since `{}` only provides free-threaded wheels",
+        package.name()
+    );
+
+    Ok(Some((package.name().clone(), python_request)))
+}
+
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
 enum SyncTarget {
@@ -530,6 +791,8 @@ enum SyncEnvironment {
     Project(ProjectEnvironment),
     /// A Python environment for a script.
     Script(ScriptEnvironment),
+    /// A `--target` directory, using the interpreter of a project.
+    Target(PythonEnvironment),
 }
 
 impl SyncEnvironment {
@@ -537,6 +800,7 @@ impl SyncEnvironment {
         match self {
             Self::Project(env) => env.dry_run_target(),
             Self::Script(env) => env.dry_run_target(),
+            Self::Target(_) => None,
         }
     }
 }
@@ -548,13 +812,14 @@ impl Deref for SyncEnvironment {
         match self {
             Self::Project(environment) => environment,
             Self::Script(environment) => environment,
+            Self::Target(environment) => environment,
         }
     }
 }
 
 /// Sync a lockfile with an environment.
 #[allow(clippy::fn_params_excessive_bools)]
-pub(super) async fn do_sync(
+pub(crate) async fn do_sync(
     target: InstallTarget<'_>,
     venv: &PythonEnvironment,
     extras: &ExtrasSpecificationWithDefaults,
@@ -562,11 +827,14 @@ pub(super) async fn do_sync(
     editable: EditableMode,
     install_options: InstallOptions,
     modifications: Modifications,
+    size_limit: Option<EnvironmentSizeLimit>,
+    unlock: bool,
     python_platform: Option<&TargetTriple>,
     settings: InstallerSettingsRef<'_>,
     network_settings: &NetworkSettings,
     state: &PlatformState,
     logger: Box<dyn InstallLogger>,
+    summary: Option<&mut RunSummary>,
     installer_metadata: bool,
     concurrency: Concurrency,
     cache: &Cache,
@@ -713,6 +981,12 @@ pub(super) async fn do_sync(
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // When populating a `--target` directory for another platform, any source distribution would
+    // be built for the current platform, so reject them rather than install incompatible builds.
+    if python_platform.is_some() && venv.interpreter().is_target() {
+        reject_source_dists(&resolution)?;
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -774,7 +1048,7 @@ pub(super) async fn do_sync(
         build_isolation,
         &extra_build_requires,
         extra_build_variables,
-        link_mode,
+        link_mode.unwrap_or_default(),
         build_options,
         &build_hasher,
         exclude_newer.clone(),
@@ -787,10 +1061,12 @@ pub(super) async fn do_sync(
     let site_packages = SitePackages::from_environment(venv)?;
 
     // Sync the environment.
-    operations::install(
+    let changelog = operations::install(
         &resolution,
         site_packages,
         modifications,
+        size_limit,
+        unlock,
         reinstall,
         build_options,
         link_mode,
@@ -811,9 +1087,135 @@ pub(super) async fn do_sync(
     )
     .await?;
 
+    if let Some(summary) = summary {
+        summary.record_changelog(&changelog);
+    }
+
     Ok(())
 }
 
+/// Compare an environment against a lockfile, without modifying either.
+///
+/// Packages are compared by name and version, and the files of each installed wheel are hashed and
+/// compared against its `RECORD`.
+#[allow(clippy::too_many_arguments)]
+fn do_verify(
+    target: InstallTarget<'_>,
+    venv: &PythonEnvironment,
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    editable: EditableMode,
+    install_options: &InstallOptions,
+    python_platform: Option<&TargetTriple>,
+    build_options: &BuildOptions,
+) -> Result<VerifyReport, ProjectError> {
+    // Validate that the Python version is supported by the lockfile.
+    if !target
+        .lock()
+        .requires_python()
+        .contains(venv.interpreter().python_version())
+    {
+        return Err(ProjectError::LockedPythonIncompatibility(
+            venv.interpreter().python_version().clone(),
+            target.lock().requires_python().clone(),
+        ));
+    }
+
+    // Validate that the set of requested extras and development groups are compatible.
+    detect_conflicts(&target, extras, groups)?;
+
+    // Validate that the set of requested extras and development groups are defined in the lockfile.
+    target.validate_extras(extras)?;
+    target.validate_groups(groups)?;
+
+    // Read the lockfile, as in `do_sync`.
+    let marker_env = resolution_markers(None, python_platform, venv.interpreter());
+    let tags = resolution_tags(None, python_platform, venv.interpreter())?;
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        extras,
+        groups,
+        build_options,
+        install_options,
+    )?;
+    let resolution = apply_no_virtual_project(resolution);
+    let resolution = apply_editable_mode(resolution, editable);
+
+    let site_packages = SitePackages::from_environment(venv)?;
+
+    let mut report = VerifyReport::default();
+    let mut locked = FxHashSet::default();
+    for dist in resolution.distributions() {
+        let name = dist.name();
+        locked.insert(name);
+        report.checked += 1;
+
+        let installed = site_packages.get_packages(name);
+        if installed.is_empty() {
+            report.missing.push(DriftedPackage {
+                name: name.clone(),
+                locked: dist.version().cloned(),
+                installed: None,
+                files: Vec::new(),
+            });
+            continue;
+        }
+
+        for installed in installed {
+            // A version mismatch takes precedence over any modified files.
+            if let Some(version) = dist
+                .version()
+                .filter(|version| *version != installed.version())
+            {
+                report.modified.push(DriftedPackage {
+                    name: name.clone(),
+                    locked: Some(version.clone()),
+                    installed: Some(installed.version().clone()),
+                    files: Vec::new(),
+                });
+                continue;
+            }
+
+            // Only wheels have a `RECORD` to verify against.
+            let files = match installed {
+                InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+                    uv_install_wheel::verify_wheel(installed.install_path())
+                        .with_context(|| format!("Failed to verify `{name}`"))?
+                }
+                InstalledDist::EggInfoFile(_)
+                | InstalledDist::EggInfoDirectory(_)
+                | InstalledDist::LegacyEditable(_) => continue,
+            };
+            if !files.is_empty() {
+                report.modified.push(DriftedPackage {
+                    name: name.clone(),
+                    locked: dist.version().cloned(),
+                    installed: Some(installed.version().clone()),
+                    files,
+                });
+            }
+        }
+    }
+
+    for installed in site_packages.iter() {
+        if !locked.contains(installed.name()) {
+            report.extraneous.push(DriftedPackage {
+                name: installed.name().clone(),
+                locked: None,
+                installed: Some(installed.version().clone()),
+                files: Vec::new(),
+            });
+        }
+    }
+
+    report.missing.sort_by(|a, b| a.name.cmp(&b.name));
+    report.extraneous.sort_by(|a, b| a.name.cmp(&b.name));
+    report.modified.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(report)
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -833,6 +1235,30 @@ fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     })
 }
 
+/// Reject any distribution that would be built from a remote or archived source distribution.
+///
+/// Local source trees (e.g., workspace members) are allowed, since they're under the user's
+/// control.
+fn reject_source_dists(resolution: &Resolution) -> Result<(), ProjectError> {
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        match dist.as_ref() {
+            Dist::Source(
+                SourceDist::Registry(_)
+                | SourceDist::DirectUrl(_)
+                | SourceDist::Git(_)
+                | SourceDist::Path(_),
+            ) => {
+                return Err(ProjectError::CrossPlatformSourceDist(dist.name().clone()));
+            }
+            Dist::Source(SourceDist::Directory(_)) | Dist::Built(_) => {}
+        }
+    }
+    Ok(())
+}
+
 /// If necessary, convert any editable requirements to non-editable.
 fn apply_editable_mode(resolution: Resolution, editable: EditableMode) -> Resolution {
     match editable {
@@ -1003,6 +1429,9 @@ struct Report {
     sync: SyncReport,
     /// The report for the lock operation.
     lock: Option<LockReport>,
+    /// The report for the verification of the environment, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verify: Option<VerifyReport>,
     /// Whether this is a dry run.
     dry_run: bool,
 }
@@ -1051,6 +1480,7 @@ impl From<&SyncEnvironment> for SyncAction {
             SyncEnvironment::Script(ScriptEnvironment::WouldCreate(..)) => Self::Create,
             SyncEnvironment::Script(ScriptEnvironment::WouldReplace(..)) => Self::Replace,
             SyncEnvironment::Script(ScriptEnvironment::Replaced(..)) => Self::Update,
+            SyncEnvironment::Target(..) => Self::Update,
         }
     }
 }
@@ -1161,6 +1591,8 @@ impl From<&SyncEnvironment> for EnvironmentReport {
         // run invocations and want to report the path we _would_ use.
         if let Some(path) = env.dry_run_target() {
             report.with_path(path.into())
+        } else if let Some(target) = env.interpreter().target() {
+            report.with_path(target.root().into())
         } else {
             report
         }
@@ -1251,7 +1683,7 @@ impl From<(&LockTarget<'_>, &LockMode<'_>, &Outcome)> for LockReport {
                     match result {
                         LockResult::Unchanged(..) => match mode {
                             // When `--frozen` is used, we don't check the lockfile
-                            LockMode::Frozen => LockAction::Use,
+                            LockMode::Frozen | LockMode::FrozenCompatible => LockAction::Use,
                             LockMode::DryRun(_) | LockMode::Locked(_) | LockMode::Write(_) => {
                                 LockAction::Check
                             }
@@ -1297,6 +1729,125 @@ impl LockReport {
     }
 }
 
+/// The report for a `--verify` operation.
+#[derive(Debug, Default, Serialize)]
+struct VerifyReport {
+    /// The number of packages in the lockfile that were compared against the environment.
+    checked: usize,
+    /// Packages in the lockfile that are not installed.
+    missing: Vec<DriftedPackage>,
+    /// Installed packages that are not in the lockfile.
+    extraneous: Vec<DriftedPackage>,
+    /// Installed packages that differ from the lockfile, or were modified after installation.
+    modified: Vec<DriftedPackage>,
+}
+
+/// A package that differs between the lockfile and the environment.
+#[derive(Debug, Serialize)]
+struct DriftedPackage {
+    /// The name of the package.
+    name: PackageName,
+    /// The version of the package in the lockfile, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<Version>,
+    /// The installed version of the package, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed: Option<Version>,
+    /// The installed files that don't match the package's `RECORD`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    files: Vec<RecordMismatch>,
+}
+
+impl VerifyReport {
+    /// Returns `true` if the environment matches the lockfile.
+    fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extraneous.is_empty() && self.modified.is_empty()
+    }
+
+    fn format(&self, output_format: SyncFormat) -> Option<String> {
+        match output_format {
+            SyncFormat::Json => None,
+            SyncFormat::Text => Some(self.to_human_readable_string()),
+        }
+    }
+
+    fn to_human_readable_string(&self) -> String {
+        let Self {
+            checked,
+            missing,
+            extraneous,
+            modified,
+        } = self;
+
+        if self.is_empty() {
+            let s = if *checked == 1 { "" } else { "s" };
+            return format!(
+                "Verified {} against the lockfile",
+                format!("{checked} package{s}").bold()
+            )
+            .dimmed()
+            .to_string();
+        }
+
+        let drifted = missing.len() + extraneous.len() + modified.len();
+        let (s, differ) = if drifted == 1 {
+            ("", "differs")
+        } else {
+            ("s", "differ")
+        };
+        let mut lines = vec![
+            format!("Found {drifted} package{s} that {differ} from the lockfile:")
+                .bold()
+                .to_string(),
+        ];
+        for package in missing {
+            lines.push(format!(
+                " {} {}{} (missing)",
+                "-".red(),
+                package.name.bold(),
+                format_version(package.locked.as_ref()).dimmed()
+            ));
+        }
+        for package in extraneous {
+            lines.push(format!(
+                " {} {}{} (extraneous)",
+                "+".green(),
+                package.name.bold(),
+                format_version(package.installed.as_ref()).dimmed()
+            ));
+        }
+        for package in modified {
+            let reason = match (&package.locked, &package.installed) {
+                (Some(locked), Some(installed)) if locked != installed => {
+                    format!("locked: {locked}")
+                }
+                _ => package
+                    .files
+                    .iter()
+                    .map(|file| match file.kind {
+                        RecordMismatchKind::Missing => format!("`{}` is missing", file.path),
+                        RecordMismatchKind::Modified => format!("`{}` was modified", file.path),
+                    })
+                    .join(", "),
+            };
+            lines.push(format!(
+                " {} {}{} ({reason})",
+                "~".yellow(),
+                package.name.bold(),
+                format_version(package.installed.as_ref()).dimmed()
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Format an optional version as a `==` specifier suffix.
+fn format_version(version: Option<&Version>) -> String {
+    version
+        .map(|version| format!("=={version}"))
+        .unwrap_or_default()
+}
+
 impl Report {
     fn format(&self, output_format: SyncFormat) -> Option<String> {
         match output_format {
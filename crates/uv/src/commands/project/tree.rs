@@ -22,6 +22,7 @@ use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::resolution_markers;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState, default_dependency_groups,
 };
@@ -87,6 +88,7 @@ pub(crate) async fn tree(
         Some(match target {
             LockTarget::Script(script) => ScriptInterpreter::discover(
                 script.into(),
+                project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 network_settings,
                 python_preference,
@@ -95,6 +97,7 @@ pub(crate) async fn tree(
                 false,
                 no_config,
                 Some(false),
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -113,6 +116,7 @@ pub(crate) async fn tree(
                 false,
                 no_config,
                 Some(false),
+                WarningPolicy::default(),
                 cache,
                 printer,
                 preview,
@@ -156,7 +160,7 @@ pub(crate) async fn tree(
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
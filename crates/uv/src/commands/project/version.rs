@@ -31,6 +31,7 @@ use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
+use crate::commands::project::warning_policy::WarningPolicy;
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, UniversalState, default_dependency_groups,
 };
@@ -65,6 +66,7 @@ pub(crate) async fn project_version(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    unlock: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
@@ -299,6 +301,7 @@ pub(crate) async fn project_version(
             frozen,
             active,
             no_sync,
+            unlock,
             python,
             install_mirrors,
             &settings,
@@ -389,9 +392,11 @@ fn update_project(
     fs_err::write(pyproject_path, &content)?;
 
     // Update the `pyproject.toml` in-memory.
+    let pyproject_toml = toml::from_str(&content)
+        .map_err(|err| ProjectError::PyprojectTomlParse(pyproject_path.to_path_buf(), err))?;
     let project = project
-        .with_pyproject_toml(toml::from_str(&content).map_err(ProjectError::PyprojectTomlParse)?)
-        .ok_or(ProjectError::PyprojectTomlUpdate)?;
+        .with_pyproject_toml(pyproject_toml)
+        .ok_or_else(|| ProjectError::PyprojectTomlUpdate(pyproject_path.to_path_buf()))?;
 
     Ok(project)
 }
@@ -429,6 +434,7 @@ async fn print_frozen_version(
         false,
         no_config,
         active,
+        WarningPolicy::default(),
         cache,
         printer,
         preview,
@@ -460,7 +466,7 @@ async fn print_frozen_version(
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
@@ -498,6 +504,7 @@ async fn lock_and_sync(
     frozen: bool,
     active: Option<bool>,
     no_sync: bool,
+    unlock: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverInstallerSettings,
@@ -538,6 +545,7 @@ async fn lock_and_sync(
             false,
             no_config,
             active,
+            WarningPolicy::default(),
             cache,
             printer,
             preview,
@@ -559,8 +567,10 @@ async fn lock_and_sync(
             no_sync,
             no_config,
             active,
+            WarningPolicy::default(),
             cache,
             DryRun::Disabled,
+            unlock,
             printer,
             preview,
         )
@@ -600,7 +610,7 @@ async fn lock_and_sync(
         Ok(result) => result.into_lock(),
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
@@ -642,10 +652,13 @@ async fn lock_and_sync(
         install_options,
         Modifications::Sufficient,
         None,
+        unlock,
+        None,
         settings.into(),
         &network_settings,
         &state,
         Box::new(DefaultInstallLogger),
+        None,
         installer_metadata,
         concurrency,
         cache,
@@ -659,7 +672,7 @@ async fn lock_and_sync(
         Ok(()) => {}
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(err) => return Err(err.into()),
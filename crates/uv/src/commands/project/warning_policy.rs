@@ -0,0 +1,67 @@
+//! The policy for warnings about the environment in which a project or tool command runs.
+//!
+//! By default, uv falls back to a usable environment with a warning, e.g., when `VIRTUAL_ENV` is
+//! ignored or an incompatible environment is kept due to `--no-sync`. With `--fail-fast-env` (or
+//! the `fail-fast-env` setting), a curated set of these warnings are raised as errors instead,
+//! such that the fallback can't go unnoticed, e.g., in continuous integration.
+
+use std::fmt::Display;
+
+use uv_warnings::warn_user;
+
+use crate::commands::project::ProjectError;
+
+/// Which warnings about the environment are raised as errors.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct WarningPolicy {
+    /// Raise all environment warnings as errors, i.e., `--fail-fast-env`.
+    pub(crate) fail_fast: bool,
+    /// Raise mismatches with the interpreter recorded in the lockfile as errors, i.e.,
+    /// `--strict-python`.
+    pub(crate) strict_python: bool,
+}
+
+/// A warning about the environment in which a project or tool command runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EnvironmentWarning {
+    /// `VIRTUAL_ENV` is set, but doesn't match the project environment, and is ignored.
+    IgnoredVirtualEnv,
+    /// An existing environment is incompatible with the project, but is used due to `--no-sync`.
+    IncompatibleEnvironment,
+    /// A script has no lockfile, so `--locked` or `--frozen` is ignored.
+    MissingScriptLockfile,
+    /// The Python interpreter doesn't satisfy the script's `requires-python`, but is used anyway.
+    IncompatiblePython,
+    /// The Python interpreter differs from the one recorded in the lockfile.
+    InterpreterMismatch,
+    /// An installed tool has an invalid receipt, and its environment is removed.
+    InvalidToolReceipt,
+}
+
+impl EnvironmentWarning {
+    /// Return a stable, machine-readable code identifying the warning, as reported when it's
+    /// raised as an error.
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Self::IgnoredVirtualEnv => "ignored-virtual-env",
+            Self::IncompatibleEnvironment => "incompatible-environment",
+            Self::MissingScriptLockfile => "missing-script-lockfile",
+            Self::IncompatiblePython => "incompatible-python",
+            Self::InterpreterMismatch => "interpreter-mismatch",
+            Self::InvalidToolReceipt => "invalid-tool-receipt",
+        }
+    }
+
+    /// Warn the user, or return an error if the policy raises the warning as an error.
+    pub(crate) fn emit(
+        self,
+        policy: WarningPolicy,
+        message: impl Display,
+    ) -> Result<(), ProjectError> {
+        if policy.fail_fast || (self == Self::InterpreterMismatch && policy.strict_python) {
+            return Err(ProjectError::EnvironmentWarning(self, message.to_string()));
+        }
+        warn_user!("{message}");
+        Ok(())
+    }
+}
@@ -335,11 +335,13 @@ fn prompt_username_and_password() -> Result<(Option<String>, Option<String>)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::printer::Verbosity;
 
     use std::str::FromStr;
 
     use insta::assert_snapshot;
 
+    use uv_cli::DiagnosticsFormat;
     use uv_redacted::DisplaySafeUrl;
 
     async fn get_credentials(
@@ -357,7 +359,7 @@ mod tests {
             &client,
             None,
             Prompt::Disabled,
-            Printer::Quiet,
+            Printer::new(Verbosity::Quiet, DiagnosticsFormat::Text),
         )
         .await
     }
@@ -0,0 +1,125 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::PythonDuFormat;
+use uv_fs::Simplified;
+use uv_python::PythonRequest;
+use uv_python::managed::{DiskUsage, ManagedPythonInstallations, disk_usage};
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    key: String,
+    path: String,
+    total_bytes: u64,
+    shared_bytes: u64,
+    reclaimable_bytes: u64,
+}
+
+/// Show the disk space used by the managed Python installations.
+pub(crate) fn du(
+    targets: Vec<String>,
+    output_format: PythonDuFormat,
+    python_install_dir: Option<&Path>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installations =
+        ManagedPythonInstallations::from_settings(python_install_dir.map(Path::to_path_buf))
+            .context("Failed to initialize toolchain settings")?;
+    let requests = targets
+        .iter()
+        .map(|target| PythonRequest::parse(target))
+        .collect::<Vec<_>>();
+
+    let installed = installations
+        .find_all()?
+        .filter(|installation| {
+            requests.is_empty()
+                || requests
+                    .iter()
+                    .any(|request| installation.satisfies(request))
+        })
+        .collect::<Vec<_>>();
+    let usages = disk_usage(&installed)?;
+
+    if output_format == PythonDuFormat::Json {
+        let data = usages
+            .iter()
+            .map(|(installation, usage)| PrintData {
+                key: installation.key().to_string(),
+                path: installation.path().user_display().to_string(),
+                total_bytes: usage.total,
+                shared_bytes: usage.shared,
+                reclaimable_bytes: usage.reclaimable(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if usages.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No managed Python installations found in {}",
+            installations.root().simplified_display().cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let width = usages
+        .iter()
+        .map(|(installation, _)| installation.key().to_string().len())
+        .max()
+        .unwrap_or_default();
+
+    let mut sum = DiskUsage::default();
+    for (installation, usage) in &usages {
+        sum.total += usage.total;
+        sum.shared += usage.shared;
+        writeln!(
+            printer.stdout(),
+            "{:width$}    {}",
+            installation.key().to_string(),
+            format_usage(usage),
+        )?;
+    }
+
+    if usages.len() > 1 {
+        writeln!(
+            printer.stdout(),
+            "{:width$}    {}",
+            "total".bold(),
+            format_usage(&sum),
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a [`DiskUsage`], e.g., `45.2MiB (3.1MiB shared)`.
+fn format_usage(usage: &DiskUsage) -> String {
+    if usage.shared > 0 {
+        format!(
+            "{} ({} shared)",
+            format_bytes(usage.total).green(),
+            format_bytes(usage.shared)
+        )
+    } else {
+        format_bytes(usage.total).green().to_string()
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::PythonEnvVarsFormat;
+use uv_configuration::Preview;
+use uv_fs::Simplified;
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonInstallation, PythonPreference, PythonRequest,
+};
+use uv_shell::Shell;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    name: &'static str,
+    path: String,
+}
+
+/// Show the environment variables needed to build against a Python installation.
+pub(crate) fn env_vars(
+    request: Option<String>,
+    output_format: PythonEnvVarsFormat,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let request = request
+        .as_deref()
+        .map(PythonRequest::parse)
+        .unwrap_or_default();
+
+    // Use the base installation, rather than any virtual environment.
+    let installation = PythonInstallation::find(
+        &request,
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        cache,
+        python_install_dir,
+        preview,
+    )?;
+    let variables = search_paths(installation.interpreter());
+
+    match output_format {
+        PythonEnvVarsFormat::Json => {
+            let data = variables
+                .iter()
+                .map(|(name, path)| PrintData {
+                    name,
+                    path: path.simplified_display().to_string(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        PythonEnvVarsFormat::Shell => {
+            let shell = Shell::from_env().unwrap_or(if cfg!(windows) {
+                Shell::Powershell
+            } else {
+                Shell::Bash
+            });
+            for (name, path) in &variables {
+                let Some(command) = shell.prepend_search_path(name, path) else {
+                    return Err(anyhow::anyhow!(
+                        "Setting environment variables is not supported in {shell}; use `--output-format json` instead"
+                    ));
+                };
+                writeln!(printer.stdout(), "{command}")?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the search path variables pointing at the headers and libraries of the interpreter.
+///
+/// The include and library directories are read from the interpreter's `sysconfig` data, which is
+/// patched to match the install location for uv-managed installations. Directories that do not
+/// exist are omitted.
+fn search_paths(interpreter: &Interpreter) -> Vec<(&'static str, PathBuf)> {
+    let prefix = interpreter.sys_base_prefix();
+    let variables = if cfg!(windows) {
+        vec![
+            ("INCLUDE", interpreter.include().to_path_buf()),
+            ("LIB", prefix.join("libs")),
+        ]
+    } else {
+        let libdir = interpreter
+            .libdir()
+            .map_or_else(|| prefix.join("lib"), Path::to_path_buf);
+        vec![
+            ("CPATH", interpreter.include().to_path_buf()),
+            ("LIBRARY_PATH", libdir.clone()),
+            ("PKG_CONFIG_PATH", libdir.join("pkgconfig")),
+        ]
+    };
+    variables
+        .into_iter()
+        .filter(|(_, path)| path.is_dir())
+        .collect()
+}
@@ -1,35 +1,57 @@
 use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::fmt::Write;
 use std::path::Path;
 
 use uv_cache::Cache;
+use uv_cli::PythonFindFormat;
 use uv_configuration::{DependencyGroupsWithDefaults, Preview};
 use uv_fs::Simplified;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    Quarantine, QueryFailure, find_all_python_installations,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
 
 use crate::commands::{
-    ExitStatus,
-    project::{ScriptInterpreter, WorkspacePython, validate_project_requires_python},
+    ExitStatus, elapsed,
+    project::{
+        ScriptInterpreter, WorkspacePython, validate_project_requires_python,
+        warning_policy::WarningPolicy,
+    },
 };
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{NetworkSettings, resolve_install_mirrors};
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    key: String,
+    version: String,
+    implementation: String,
+    variant: String,
+    source: String,
+    path: String,
+}
 
 /// Find a Python interpreter.
-#[allow(clippy::fn_params_excessive_bools)]
+#[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
 pub(crate) async fn find(
     project_dir: &Path,
     request: Option<String>,
     show_version: bool,
+    base: bool,
+    all: bool,
+    output_format: PythonFindFormat,
     no_project: bool,
     no_config: bool,
     system: bool,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -73,11 +95,103 @@ pub(crate) async fn find(
     )
     .await?;
 
+    let python_request = python_request.unwrap_or_default();
+
+    if all {
+        let installations = find_all_python_installations(
+            &python_request,
+            environment_preference,
+            python_preference,
+            cache,
+            python_install_dir,
+            preview,
+        )?;
+
+        // If nothing matched, fall back to single-result discovery to report a helpful error.
+        if installations.is_empty() {
+            PythonInstallation::find(
+                &python_request,
+                environment_preference,
+                python_preference,
+                cache,
+                python_install_dir,
+                preview,
+            )?;
+        }
+
+        // Warn if the first (selected) Python version is incompatible with the current workspace
+        if let (Some(requires_python), Some(python)) = (requires_python, installations.first()) {
+            if let Err(err) = validate_project_requires_python(
+                python.interpreter(),
+                project.as_ref().map(VirtualProject::workspace),
+                &groups,
+                &requires_python,
+                &source,
+            ) {
+                warn_user!("{err}");
+            }
+        }
+
+        let data = installations
+            .iter()
+            .map(|installation| -> Result<_> {
+                Ok(PrintData {
+                    key: installation.key().to_string(),
+                    version: installation.python_version().to_string(),
+                    implementation: installation.implementation().to_string(),
+                    variant: installation.interpreter().variant().to_string(),
+                    source: installation.source().to_string(),
+                    path: std::path::absolute(installation.interpreter().sys_executable())?
+                        .simplified_display()
+                        .to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        match output_format {
+            PythonFindFormat::Json => {
+                writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+            }
+            PythonFindFormat::Table => {
+                let version_width = data
+                    .iter()
+                    .fold("VERSION".len(), |acc, row| acc.max(row.version.len()));
+                let variant_width = data
+                    .iter()
+                    .fold("VARIANT".len(), |acc, row| acc.max(row.variant.len()));
+                let source_width = data
+                    .iter()
+                    .fold("SOURCE".len(), |acc, row| acc.max(row.source.len()));
+                writeln!(
+                    printer.stdout(),
+                    "{:version_width$}    {:variant_width$}    {:source_width$}    {}",
+                    "VERSION",
+                    "VARIANT",
+                    "SOURCE",
+                    "PATH",
+                )?;
+                for row in &data {
+                    writeln!(
+                        printer.stdout(),
+                        "{:version_width$}    {:variant_width$}    {:source_width$}    {}",
+                        row.version,
+                        row.variant,
+                        row.source,
+                        row.path.cyan(),
+                    )?;
+                }
+            }
+        }
+
+        return Ok(ExitStatus::Success);
+    }
+
     let python = PythonInstallation::find(
-        &python_request.unwrap_or_default(),
+        &python_request,
         environment_preference,
         python_preference,
         cache,
+        python_install_dir,
         preview,
     )?;
 
@@ -97,18 +211,93 @@ pub(crate) async fn find(
         }
     }
 
+    let interpreter = if base {
+        python.interpreter().resolved_base(cache)?
+    } else {
+        python.into_interpreter()
+    };
+
     if show_version {
+        writeln!(printer.stdout(), "{}", interpreter.python_version())?;
+    } else {
         writeln!(
             printer.stdout(),
             "{}",
-            python.interpreter().python_version()
+            std::path::absolute(interpreter.sys_executable())?.simplified_display()
         )?;
-    } else {
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Show the quarantined Python interpreters and the recorded failures to query them.
+pub(crate) fn find_failures(cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+    let quarantined = Quarantine::read_all(cache)?;
+    let failures = QueryFailure::read_all(cache)?;
+
+    for (index, quarantine) in quarantined.iter().enumerate() {
+        if index > 0 {
+            writeln!(printer.stdout())?;
+        }
         writeln!(
             printer.stdout(),
-            "{}",
-            std::path::absolute(python.interpreter().sys_executable())?.simplified_display()
+            "{} (skipped for {} after {} consecutive failed queries)",
+            quarantine.path.simplified_display().cyan(),
+            elapsed(quarantine.remaining().unwrap_or_default()),
+            quarantine.failures
+        )?;
+        writeln!(printer.stdout(), "  reason: {}", quarantine.reason)?;
+    }
+
+    if failures.is_empty() {
+        if !quarantined.is_empty() {
+            return Ok(ExitStatus::Success);
+        }
+        writeln!(
+            printer.stderr(),
+            "No failed interpreter queries were recorded"
+        )?;
+        if !QueryFailure::enabled() {
+            writeln!(
+                printer.stderr(),
+                "\n{}{} Set `{}` to record the output of failed interpreter queries",
+                "hint".bold().cyan(),
+                ":".bold(),
+                format!("{}=1", EnvVars::UV_PYTHON_RECORD_QUERY_FAILURES).green(),
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    for (index, (record, failure)) in failures.iter().enumerate() {
+        if index > 0 || !quarantined.is_empty() {
+            writeln!(printer.stdout())?;
+        }
+        writeln!(
+            printer.stdout(),
+            "{} ({})",
+            failure.path.simplified_display().cyan(),
+            if failure.count == 1 {
+                "failed once".to_string()
+            } else {
+                format!("failed {} times", failure.count)
+            }
         )?;
+        writeln!(printer.stdout(), "  reason: {}", failure.reason)?;
+        writeln!(
+            printer.stdout(),
+            "  record: {}",
+            record.simplified_display()
+        )?;
+        for (name, output) in [("[stdout]", &failure.stdout), ("[stderr]", &failure.stderr)] {
+            if output.trim().is_empty() {
+                continue;
+            }
+            writeln!(printer.stdout(), "  {}", name.red())?;
+            for line in output.lines() {
+                writeln!(printer.stdout(), "    {line}")?;
+            }
+        }
     }
 
     Ok(ExitStatus::Success)
@@ -116,10 +305,13 @@ pub(crate) async fn find(
 
 pub(crate) async fn find_script(
     script: Pep723ItemRef<'_>,
+    project_dir: &Path,
     show_version: bool,
+    base: bool,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    python_install_dir: Option<&Path>,
     no_config: bool,
     cache: &Cache,
     printer: Printer,
@@ -127,14 +319,19 @@ pub(crate) async fn find_script(
 ) -> Result<ExitStatus> {
     let interpreter = match ScriptInterpreter::discover(
         script,
+        project_dir,
         None,
         network_settings,
         python_preference,
         python_downloads,
-        &PythonInstallMirrors::default(),
+        &PythonInstallMirrors {
+            python_install_dir: python_install_dir.map(Path::to_path_buf),
+            ..resolve_install_mirrors(None)
+        },
         false,
         no_config,
         Some(false),
+        WarningPolicy::default(),
         cache,
         printer,
         preview,
@@ -149,6 +346,12 @@ pub(crate) async fn find_script(
         Ok(ScriptInterpreter::Environment(environment)) => environment.into_interpreter(),
     };
 
+    let interpreter = if base {
+        interpreter.resolved_base(cache)?
+    } else {
+        interpreter
+    };
+
     if show_version {
         writeln!(printer.stdout(), "{}", interpreter.python_version())?;
     } else {
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::PythonInspectFormat;
+use uv_configuration::Preview;
+use uv_fs::Simplified;
+use uv_python::features::{PythonFeature, PythonFeatures};
+use uv_python::{
+    EnvironmentPreference, PythonInstallation, PythonPreference, PythonRequest, QueryProbe,
+};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    key: String,
+    version: String,
+    implementation: String,
+    path: String,
+    libc: String,
+    soabi: Option<String>,
+    ext_suffix: Option<String>,
+    features: Vec<String>,
+    missing_features: Vec<String>,
+}
+
+/// Show details about a Python interpreter, including its optional standard library features.
+pub(crate) fn inspect(
+    request: Option<String>,
+    system: bool,
+    output_format: PythonInspectFormat,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let environment_preference = if system {
+        EnvironmentPreference::OnlySystem
+    } else {
+        EnvironmentPreference::Any
+    };
+
+    let request = request
+        .as_deref()
+        .map(PythonRequest::parse)
+        .unwrap_or_default();
+
+    // The optional features are only reported with the corresponding probe.
+    let cache = QueryProbe::with_features(cache);
+    let python = PythonInstallation::find(
+        &request,
+        environment_preference,
+        python_preference,
+        &cache,
+        python_install_dir,
+        preview,
+    )?;
+    let interpreter = python.interpreter();
+    let path = std::path::absolute(interpreter.sys_executable())?
+        .simplified_display()
+        .to_string();
+    let libc = interpreter
+        .abi_libc()
+        .unwrap_or_else(|| interpreter.libc())
+        .to_string();
+    let features = interpreter.features().unwrap_or_default();
+    let missing = PythonFeatures::only(PythonFeature::ALL).difference(features);
+
+    if output_format == PythonInspectFormat::Json {
+        let data = PrintData {
+            key: python.key().to_string(),
+            version: python.python_version().to_string(),
+            implementation: python.implementation().to_string(),
+            path,
+            libc,
+            soabi: interpreter.soabi().map(ToString::to_string),
+            ext_suffix: interpreter.ext_suffix().map(ToString::to_string),
+            features: features.iter().map(|feature| feature.to_string()).collect(),
+            missing_features: missing.iter().map(|feature| feature.to_string()).collect(),
+        };
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+    } else {
+        writeln!(printer.stdout(), "{}", python.key().cyan())?;
+        writeln!(printer.stdout(), "path: {path}")?;
+        writeln!(printer.stdout(), "libc: {libc}")?;
+        writeln!(
+            printer.stdout(),
+            "soabi: {}",
+            interpreter.soabi().unwrap_or("unknown")
+        )?;
+        writeln!(
+            printer.stdout(),
+            "ext_suffix: {}",
+            interpreter.ext_suffix().unwrap_or("unknown")
+        )?;
+        for feature in PythonFeature::ALL {
+            if features.contains(feature) {
+                writeln!(printer.stdout(), "{feature}: {}", "available".green())?;
+            } else {
+                writeln!(printer.stdout(), "{feature}: {}", "missing".red())?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
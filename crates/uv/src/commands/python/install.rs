@@ -3,40 +3,49 @@ use std::collections::BTreeMap;
 use std::fmt::Write;
 use std::io::ErrorKind;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::str::FromStr;
 
-use anyhow::{Error, Result};
+use anyhow::{Context, Error, Result};
 use futures::StreamExt;
-use futures::stream::FuturesUnordered;
 use indexmap::IndexSet;
 use itertools::{Either, Itertools};
 use owo_colors::{AnsiColors, OwoColorize};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
-use uv_configuration::{Preview, PreviewFeatures};
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, Preview, PreviewFeatures};
 use uv_fs::Simplified;
 use uv_platform::{Arch, Libc};
+use uv_python::components::PythonComponents;
 use uv_python::downloads::{
-    self, ArchRequest, DownloadResult, ManagedPythonDownload, PythonDownloadRequest,
+    self, ArchRequest, DownloadRateLimit, DownloadResult, ManagedPythonDownload, PythonChannel,
+    PythonDownloadClientSettings, PythonDownloadRequest,
 };
 use uv_python::managed::{
     ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
     create_link_to_executable, python_executable_dir,
 };
 use uv_python::{
-    PythonDownloads, PythonInstallationKey, PythonInstallationMinorVersionKey, PythonRequest,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionFilePreference, VersionRequest,
+    DownloadRate, PyVenvConfiguration, PythonDownloads, PythonEnvironment, PythonInstallationKey,
+    PythonInstallationMinorVersionKey, PythonRequest, PythonVersionFile,
+    VersionFileDiscoveryOptions, VersionFilePreference, VersionRequest,
 };
+use uv_settings::PythonInstallHookFailure;
 use uv_shell::Shell;
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
 use uv_trampoline_builder::{Launcher, LauncherKind};
 use uv_warnings::{warn_user, write_error_chain};
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
 
 use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, elapsed};
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{NetworkSettings, PythonInstallHookSettings};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct InstallRequest {
@@ -45,11 +54,16 @@ struct InstallRequest {
     /// A download request corresponding to the `request` with platform information filled
     download_request: PythonDownloadRequest,
     /// A download that satisfies the request
-    download: &'static ManagedPythonDownload,
+    download: ManagedPythonDownload,
 }
 
 impl InstallRequest {
-    fn new(request: PythonRequest, python_downloads_json_url: Option<&str>) -> Result<Self> {
+    fn new(
+        request: PythonRequest,
+        channel: Option<PythonChannel>,
+        components: Option<PythonComponents>,
+        python_downloads_json_url: Option<&str>,
+    ) -> Result<Self> {
         // Make sure the request is a valid download request and fill platform information
         let download_request = PythonDownloadRequest::from_request(&request)
             .ok_or_else(|| {
@@ -60,6 +74,21 @@ impl InstallRequest {
             })?
             .fill()?;
 
+        // Restrict the download to the requested release channel
+        let download_request = if let Some(channel) = channel {
+            download_request.with_channel(channel)
+        } else {
+            download_request
+        };
+
+        // Select the optional components to install, e.g., with `--with-tk`, which take
+        // precedence over any components in the request itself, e.g., `3.12+tk`. `pip` is always
+        // kept, such that deselecting the other components doesn't break `python -m pip`
+        let download_request = match components.or(download_request.components()) {
+            Some(components) => download_request.with_components(components.with_pip()),
+            None => download_request,
+        };
+
         // Find a matching download
         let download =
             match ManagedPythonDownload::from_request(&download_request, python_downloads_json_url)
@@ -75,9 +104,24 @@ impl InstallRequest {
                         "uv does not yet provide musl Python distributions on aarch64."
                     ));
                 }
+                Err(err @ downloads::Error::NoDownloadFound(_)) => {
+                    if let Some(channel) = channel {
+                        return Err(anyhow::anyhow!(
+                            "No download found for request `{}` in the `{channel}` channel",
+                            request.to_canonical_string(),
+                        ));
+                    }
+                    return Err(err.into());
+                }
                 Err(err) => return Err(err.into()),
             };
 
+        // Remove the components that weren't selected after the download is extracted
+        let download = match download_request.components() {
+            Some(components) if !components.is_all() => download.with_components(components),
+            _ => download.clone(),
+        };
+
         Ok(Self {
             request,
             download_request,
@@ -112,6 +156,8 @@ struct Changelog {
     installed: FxHashSet<PythonInstallationKey>,
     uninstalled: FxHashSet<PythonInstallationKey>,
     installed_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>>,
+    /// The previous targets of the executables that were replaced, e.g., on an upgrade.
+    replaced_executables: FxHashMap<PathBuf, PathBuf>,
 }
 
 impl Changelog {
@@ -147,6 +193,7 @@ enum InstallErrorKind {
     Bin,
     #[cfg_attr(not(windows), allow(dead_code))]
     Registry,
+    Hook,
 }
 
 /// Download and install Python versions.
@@ -154,6 +201,7 @@ enum InstallErrorKind {
 pub(crate) async fn install(
     project_dir: &Path,
     install_dir: Option<PathBuf>,
+    install_staging_dir: Option<PathBuf>,
     targets: Vec<String>,
     reinstall: bool,
     upgrade: bool,
@@ -163,10 +211,20 @@ pub(crate) async fn install(
     python_install_mirror: Option<String>,
     pypy_install_mirror: Option<String>,
     python_downloads_json_url: Option<String>,
+    download_client: PythonDownloadClientSettings,
     network_settings: NetworkSettings,
     default: bool,
+    channel: Option<PythonChannel>,
+    components: Option<PythonComponents>,
+    hook: Option<PythonInstallHookSettings>,
+    only_download: bool,
+    from_cache: bool,
+    atomic: bool,
+    download_rate_limit: Option<DownloadRate>,
     python_downloads: PythonDownloads,
+    concurrency: Concurrency,
     no_config: bool,
+    cache: &Cache,
     preview: Preview,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -195,7 +253,9 @@ pub(crate) async fn install(
     }
 
     // Read the existing installations, lock the directory for the duration
-    let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
+    let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?
+        .with_staging_dir(install_staging_dir)
+        .init()?;
     let installations_dir = installations.root();
     let scratch_dir = installations.scratch();
     let _lock = installations.lock().await?;
@@ -215,6 +275,14 @@ pub(crate) async fn install(
                 let request = VersionRequest::major_minor_request_from_key(installation.key());
                 if let Ok(request) = InstallRequest::new(
                     PythonRequest::Version(request),
+                    channel.or_else(|| {
+                        recorded_channel(
+                            &existing_installations,
+                            installation.key().major(),
+                            installation.key().minor(),
+                        )
+                    }),
+                    components,
                     python_downloads_json_url.as_deref(),
                 ) {
                     minor_version_requests.insert(request);
@@ -249,14 +317,39 @@ pub(crate) async fn install(
                 }]
             })
             .into_iter()
-            .map(|request| InstallRequest::new(request, python_downloads_json_url.as_deref()))
+            .map(|request| {
+                InstallRequest::new(
+                    request,
+                    channel,
+                    components,
+                    python_downloads_json_url.as_deref(),
+                )
+            })
             .collect::<Result<Vec<_>>>()?
         }
     } else {
         targets
             .iter()
             .map(|target| PythonRequest::parse(target.as_str()))
-            .map(|request| InstallRequest::new(request, python_downloads_json_url.as_deref()))
+            .map(|request| {
+                // Upgrades follow the release channel of the existing installations, unless
+                // another channel is requested
+                let channel = match &request {
+                    PythonRequest::Version(VersionRequest::MajorMinor(major, minor, _))
+                        if upgrade =>
+                    {
+                        channel
+                            .or_else(|| recorded_channel(&existing_installations, *major, *minor))
+                    }
+                    _ => channel,
+                };
+                InstallRequest::new(
+                    request,
+                    channel,
+                    components,
+                    python_downloads_json_url.as_deref(),
+                )
+            })
             .collect::<Result<Vec<_>>>()?
     };
 
@@ -295,6 +388,38 @@ pub(crate) async fn install(
         return Ok(ExitStatus::Failure);
     }
 
+    // When staging installs through the Python cache, the cache directory must be configured.
+    if only_download || from_cache {
+        let Some(python_builds_dir) = downloads::python_cache_dir() else {
+            anyhow::bail!(
+                "`{}` requires `{}` to be set",
+                if only_download {
+                    "--only-download"
+                } else {
+                    "--from-cache"
+                },
+                EnvVars::UV_PYTHON_CACHE_DIR
+            );
+        };
+
+        if only_download {
+            return download_only(
+                &requests,
+                &python_builds_dir,
+                python_install_mirror.as_deref(),
+                pypy_install_mirror.as_deref(),
+                &download_client,
+                python_downloads,
+                &network_settings,
+                concurrency,
+                download_rate_limit,
+                start,
+                printer,
+            )
+            .await;
+        }
+    }
+
     // Find requests that are already satisfied
     let mut changelog = Changelog::default();
     let (satisfied, unsatisfied): (Vec<_>, Vec<_>) = if reinstall {
@@ -321,6 +446,8 @@ pub(crate) async fn install(
                     // Construct an install request matching the existing installation
                     match InstallRequest::new(
                         PythonRequest::Key(installation.into()),
+                        channel,
+                        components,
                         python_downloads_json_url.as_deref(),
                     ) {
                         Ok(request) => {
@@ -397,23 +524,29 @@ pub(crate) async fn install(
                 request.cyan(),
             );
         })
-        .map(|request| request.download)
+        .map(|request| &request.download)
         // Ensure we only download each version once
         .unique_by(|download| download.key())
         .collect::<Vec<_>>();
 
-    // Download and unpack the Python versions concurrently
-    let client = uv_client::BaseClientBuilder::new()
+    // Download and unpack the Python versions concurrently, sharing a single client and limiting the
+    // number of concurrent downloads and their combined bandwidth
+    //
+    // With `--from-cache`, the archives are read from the cache, and missing archives are an error.
+    let client_builder = uv_client::BaseClientBuilder::new()
         .retries_from_env()?
-        .connectivity(network_settings.connectivity)
+        .connectivity(if from_cache {
+            Connectivity::Offline
+        } else {
+            network_settings.connectivity
+        })
         .native_tls(network_settings.native_tls)
-        .allow_insecure_host(network_settings.allow_insecure_host.clone())
-        .build();
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let client = download_client.apply(client_builder).build();
+    let rate_limit = download_rate_limit.map(DownloadRateLimit::new);
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
-    let mut tasks = FuturesUnordered::new();
-
-    for download in &downloads {
-        tasks.push(async {
+    let mut tasks = futures::stream::iter(&downloads)
+        .map(async |download| {
             (
                 *download,
                 download
@@ -424,26 +557,33 @@ pub(crate) async fn install(
                         reinstall,
                         python_install_mirror.as_deref(),
                         pypy_install_mirror.as_deref(),
+                        rate_limit.as_ref(),
                         Some(&reporter),
                     )
                     .await,
             )
-        });
-    }
+        })
+        .buffer_unordered(concurrency.downloads);
 
     let mut errors = vec![];
     let mut downloaded = Vec::with_capacity(downloads.len());
+    // The installations created by this invocation, i.e., excluding those installed concurrently
+    // by another process, which `--atomic` must not remove.
+    let mut created = Vec::with_capacity(downloads.len());
     let mut requests_by_new_installation = BTreeMap::new();
     while let Some((download, result)) = tasks.next().await {
         match result {
             Ok(download_result) => {
-                let path = match download_result {
+                let (path, fetched) = match download_result {
                     // We should only encounter already-available during concurrent installs
-                    DownloadResult::AlreadyAvailable(path) => path,
-                    DownloadResult::Fetched(path) => path,
+                    DownloadResult::AlreadyAvailable(path) => (path, false),
+                    DownloadResult::Fetched(path) => (path, true),
                 };
 
                 let installation = ManagedPythonInstallation::new(path, download);
+                if fetched {
+                    created.push(installation.clone());
+                }
                 changelog.installed.insert(installation.key().clone());
                 for request in &requests {
                     // Take note of which installations satisfied which requests
@@ -469,6 +609,21 @@ pub(crate) async fn install(
         }
     }
 
+    // With `--atomic`, remove the installations from this invocation if any of them failed, before
+    // creating any executables or registry entries.
+    if atomic && !errors.is_empty() {
+        return rollback(
+            created,
+            &existing_installations,
+            &changelog,
+            errors,
+            downloads.len(),
+            preview,
+            printer,
+        )
+        .await;
+    }
+
     let bin_dir = if matches!(bin, Some(false)) {
         None
     } else {
@@ -480,11 +635,19 @@ pub(crate) async fn install(
     // Ensure that the installations are _complete_ for both downloaded installations and existing
     // installations that match the request
     for installation in &installations {
-        installation.ensure_externally_managed()?;
-        installation.ensure_sysconfig_patched()?;
-        installation.ensure_canonical_executables()?;
-        if let Err(e) = installation.ensure_dylib_patched() {
-            e.warn_user(installation);
+        if let Err(err) =
+            ensure_complete(installation).and_then(|()| record_channel(installation, &requests))
+        {
+            // With `--atomic`, the failure is reported with the rollback below.
+            if !atomic {
+                return Err(err);
+            }
+            errors.push((
+                InstallErrorKind::DownloadUnpack,
+                installation.key().clone(),
+                err,
+            ));
+            continue;
         }
 
         let upgradeable = (default || is_default_install)
@@ -526,6 +689,31 @@ pub(crate) async fn install(
         }
     }
 
+    // Run the post-install hook for any installations that were (re)installed, unless they'll be
+    // rolled back
+    if let Some(hook) = hook.as_ref().filter(|_| !atomic || errors.is_empty()) {
+        for installation in &downloaded {
+            if let Err(err) = run_install_hook(hook, installation).await {
+                errors.push((InstallErrorKind::Hook, installation.key().clone(), err));
+            }
+        }
+    }
+
+    // With `--atomic`, any failure, including one that is otherwise only a warning, undoes this
+    // invocation.
+    if atomic && !errors.is_empty() {
+        return rollback(
+            created,
+            &existing_installations,
+            &changelog,
+            errors,
+            downloads.len(),
+            preview,
+            printer,
+        )
+        .await;
+    }
+
     let minor_versions =
         PythonInstallationMinorVersionKey::highest_installations_by_minor_version_key(
             installations
@@ -534,13 +722,48 @@ pub(crate) async fn install(
                 .chain(existing_installations.iter()),
         );
 
+    let mut upgraded_links = Vec::new();
     for installation in minor_versions.values() {
-        if upgrade {
+        let result = if upgrade {
             // During an upgrade, update existing symlinks but avoid
             // creating new ones.
-            installation.update_minor_version_link(preview)?;
+            installation.update_minor_version_link(preview)
         } else {
-            installation.ensure_minor_version_link(preview)?;
+            installation.ensure_minor_version_link(preview)
+        };
+        if let Err(err) = result {
+            if atomic {
+                errors.push((
+                    InstallErrorKind::DownloadUnpack,
+                    installation.key().clone(),
+                    err.into(),
+                ));
+                return rollback(
+                    created,
+                    &existing_installations,
+                    &changelog,
+                    errors,
+                    downloads.len(),
+                    preview,
+                    printer,
+                )
+                .await;
+            }
+            return Err(err.into());
+        }
+
+        if upgrade {
+            // Track the links that now point to a new installation, to refresh the environments
+            // that use them.
+            if changelog.installed.contains(installation.key()) {
+                if let Some(minor_version_link) =
+                    PythonMinorVersionLink::from_installation(installation, preview)
+                {
+                    if minor_version_link.exists() {
+                        upgraded_links.push(minor_version_link);
+                    }
+                }
+            }
         }
     }
 
@@ -676,6 +899,8 @@ pub(crate) async fn install(
         if let Some(bin_dir) = bin_dir.as_ref() {
             warn_if_not_on_path(bin_dir);
         }
+
+        refresh_dependent_environments(&upgraded_links, project_dir, cache, printer).await?;
     }
 
     if !errors.is_empty() {
@@ -685,6 +910,9 @@ pub(crate) async fn install(
             InstallErrorKind::Bin => bin.is_none(),
             InstallErrorKind::Registry => registry.is_none(),
             InstallErrorKind::DownloadUnpack => false,
+            InstallErrorKind::Hook => hook
+                .as_ref()
+                .is_none_or(|hook| hook.on_failure == PythonInstallHookFailure::Warn),
         });
 
         for (kind, key, err) in errors
@@ -731,6 +959,20 @@ pub(crate) async fn install(
                         color,
                     )?;
                 }
+                InstallErrorKind::Hook => {
+                    let (level, color) = match hook.as_ref().map(|hook| hook.on_failure) {
+                        Some(PythonInstallHookFailure::Fail) => ("error", AnsiColors::Red),
+                        _ => ("warning", AnsiColors::Yellow),
+                    };
+
+                    write_error_chain(
+                        err.context(format!("Failed to run post-install hook for {key}"))
+                            .as_ref(),
+                        printer.stderr(),
+                        level,
+                        color,
+                    )?;
+                }
             }
         }
 
@@ -742,6 +984,539 @@ pub(crate) async fn install(
     Ok(ExitStatus::Success)
 }
 
+/// Refresh the virtual environments whose base interpreter is in one of the given minor version
+/// links, i.e., environments that were transparently upgraded to a new patch version.
+///
+/// Each environment's interpreter is re-queried, which refreshes its cached metadata, and the
+/// version in its `pyvenv.cfg` is updated to match. Otherwise, the stale version would be reported
+/// as a mismatch until the environment is recreated.
+///
+/// uv doesn't track the environments created with an installation, so only the active virtual
+/// environment, the project environment, and tool environments are considered.
+async fn refresh_dependent_environments(
+    links: &[PythonMinorVersionLink],
+    project_dir: &Path,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    if links.is_empty() {
+        return Ok(());
+    }
+
+    let mut candidates = IndexSet::new();
+    if let Some(venv) = std::env::var_os(EnvVars::VIRTUAL_ENV).filter(|venv| !venv.is_empty()) {
+        candidates.insert(PathBuf::from(venv));
+    }
+    // Respect `UV_PROJECT_ENVIRONMENT`, or fall back to the `.venv` that `uv venv` would create
+    // outside of a project.
+    let workspace_cache = WorkspaceCache::default();
+    match VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+        .await
+    {
+        Ok(project) => {
+            candidates.insert(project.workspace().venv(Some(false)));
+        }
+        Err(err) => {
+            debug!("Failed to discover project: {err}");
+            candidates.insert(project_dir.join(".venv"));
+        }
+    }
+    match InstalledTools::from_settings() {
+        Ok(tools) => match uv_fs::directories(tools.root()) {
+            Ok(directories) => candidates.extend(directories),
+            Err(err) => debug!("Failed to read tool environments: {err}"),
+        },
+        Err(err) => debug!("Failed to find tool environments: {err}"),
+    }
+
+    // Deduplicate environments referenced via different paths.
+    let candidates = candidates
+        .into_iter()
+        .filter_map(|root| {
+            fs_err::canonicalize(&root)
+                .ok()
+                .map(|canonical| (canonical, root))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let mut refreshed = Vec::new();
+    for root in candidates.into_values() {
+        let Ok(cfg) = PyVenvConfiguration::parse(root.join("pyvenv.cfg")) else {
+            continue;
+        };
+        let Some(home) = cfg.home() else {
+            continue;
+        };
+        if !links
+            .iter()
+            .any(|link| home.starts_with(&link.symlink_directory))
+        {
+            continue;
+        }
+
+        debug!(
+            "Refreshing environment using an upgraded Python installation: `{}`",
+            root.user_display()
+        );
+        let environment = match PythonEnvironment::from_root(&root, cache) {
+            Ok(environment) => environment,
+            Err(err) => {
+                warn_user!(
+                    "Failed to refresh the environment at `{}`: {err}",
+                    root.user_display()
+                );
+                continue;
+            }
+        };
+        match environment.update_pyvenv_version() {
+            Ok(Some(previous)) => refreshed.push((
+                root,
+                previous,
+                environment.interpreter().python_version().clone(),
+            )),
+            Ok(None) => {}
+            Err(err) => {
+                warn_user!(
+                    "Failed to refresh the environment at `{}`: {err}",
+                    root.user_display()
+                );
+            }
+        }
+    }
+
+    if refreshed.is_empty() {
+        return Ok(());
+    }
+
+    // Ex) "Refreshed 2 environments using the upgraded Python version"
+    let environments = if refreshed.len() == 1 {
+        "1 environment".to_string()
+    } else {
+        format!("{} environments", refreshed.len())
+    };
+    writeln!(
+        printer.stderr(),
+        "Refreshed {} using the upgraded Python {}",
+        environments.bold(),
+        if links.len() == 1 {
+            "version"
+        } else {
+            "versions"
+        },
+    )?;
+    for (root, previous, current) in refreshed {
+        writeln!(
+            printer.stderr(),
+            " {} {} ({previous} -> {current})",
+            "~".yellow(),
+            root.user_display().bold(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Download the archives for the requested Python versions into the cache directory, without
+/// installing them.
+#[allow(clippy::too_many_arguments)]
+async fn download_only(
+    requests: &[InstallRequest],
+    python_builds_dir: &Path,
+    python_install_mirror: Option<&str>,
+    pypy_install_mirror: Option<&str>,
+    download_client: &PythonDownloadClientSettings,
+    python_downloads: PythonDownloads,
+    network_settings: &NetworkSettings,
+    concurrency: Concurrency,
+    download_rate_limit: Option<DownloadRate>,
+    start: std::time::Instant,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Check if Python downloads are banned
+    if matches!(python_downloads, PythonDownloads::Never) {
+        writeln!(
+            printer.stderr(),
+            "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit installs.",
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let downloads = requests
+        .iter()
+        .map(|request| &request.download)
+        // Ensure we only download each version once
+        .unique_by(|download| download.key())
+        .collect::<Vec<_>>();
+
+    // Download the Python versions concurrently, limiting the number of concurrent downloads and
+    // their combined bandwidth
+    let rate_limit = download_rate_limit.map(DownloadRateLimit::new);
+    let client_builder = uv_client::BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let client = download_client.apply(client_builder).build();
+    let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
+    let mut tasks = futures::stream::iter(&downloads)
+        .map(async |download| {
+            (
+                *download,
+                download
+                    .download_to_cache(
+                        &client,
+                        python_builds_dir,
+                        python_install_mirror,
+                        pypy_install_mirror,
+                        rate_limit.as_ref(),
+                        Some(&reporter),
+                    )
+                    .await,
+            )
+        })
+        .buffer_unordered(concurrency.downloads);
+
+    let mut fetched = BTreeMap::new();
+    let mut errors = vec![];
+    while let Some((download, result)) = tasks.next().await {
+        match result {
+            Ok(DownloadResult::Fetched(path)) => {
+                fetched.insert(download.key().clone(), path);
+            }
+            Ok(DownloadResult::AlreadyAvailable(path)) => {
+                debug!(
+                    "Found cached archive for `{}` at: {}",
+                    download.key().green(),
+                    path.user_display()
+                );
+            }
+            Err(err) => {
+                errors.push((download.key().clone(), err));
+            }
+        }
+    }
+
+    if fetched.is_empty() && errors.is_empty() {
+        if downloads.len() > 1 {
+            writeln!(
+                printer.stderr(),
+                "All requested versions already downloaded"
+            )?;
+        } else {
+            writeln!(printer.stderr(), "Python is already downloaded")?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    if !fetched.is_empty() {
+        if fetched.len() == 1 {
+            let (key, _) = fetched.iter().next().unwrap();
+            // Ex) "Downloaded Python 3.9.7 in 1.68s"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Downloaded {} {}",
+                    format!("Python {}", key.version()).bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        } else {
+            // Ex) "Downloaded 2 versions in 1.68s"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Downloaded {} {}",
+                    format!("{} versions", fetched.len()).bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        }
+
+        for key in fetched.keys() {
+            writeln!(printer.stderr(), " {} {}", "+".green(), key.bold())?;
+        }
+    }
+
+    if !errors.is_empty() {
+        for (key, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            write_error_chain(
+                anyhow::Error::new(err)
+                    .context(format!("Failed to download {key}"))
+                    .as_ref(),
+                printer.stderr(),
+                "error",
+                AnsiColors::Red,
+            )?;
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Undo an `--atomic` install in which something failed, and report the failures.
+///
+/// Removes the executables and registry entries created by this invocation, restoring any
+/// executables they replaced, along with the installations it created. Installations that another
+/// process installed concurrently are kept.
+async fn rollback(
+    created: Vec<ManagedPythonInstallation>,
+    existing_installations: &[ManagedPythonInstallation],
+    changelog: &Changelog,
+    errors: Vec<(InstallErrorKind, PythonInstallationKey, Error)>,
+    total: usize,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Remove the executables, restoring those that were replaced.
+    for executable in changelog.installed_executables.values().flatten() {
+        debug!(
+            "Removing executable at `{}` due to `--atomic`",
+            executable.simplified_display()
+        );
+        match fs_err::remove_file(executable) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => {
+                warn_user!(
+                    "Failed to remove executable at `{}` after a failed install: {err}",
+                    executable.simplified_display()
+                );
+                continue;
+            }
+        }
+        if let Some(previous) = changelog.replaced_executables.get(executable) {
+            if let Err(err) = create_link_to_executable(executable, previous) {
+                warn_user!(
+                    "Failed to restore executable at `{}` after a failed install: {err}",
+                    executable.simplified_display()
+                );
+            }
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        let mut errors = Vec::new();
+        uv_python::windows_registry::remove_registry_entry(&created, false, &mut errors);
+        for (key, err) in errors {
+            warn_user!("Failed to remove registry entry for `{key}` after a failed install: {err}");
+        }
+    }
+
+    let mut removed = Vec::with_capacity(created.len());
+    for installation in &created {
+        debug!(
+            "Removing `{}` due to `--atomic`",
+            installation.key().green()
+        );
+        match fs_err::tokio::remove_dir_all(installation.path()).await {
+            Ok(()) => removed.push(installation.key().clone()),
+            Err(err) => warn_user!(
+                "Failed to remove `{}` after a failed install: {err}",
+                installation.key().green()
+            ),
+        }
+    }
+
+    // Point the minor version links back at the remaining installations, or remove them if there
+    // are none.
+    let remaining_minor_versions =
+        PythonInstallationMinorVersionKey::highest_installations_by_minor_version_key(
+            existing_installations
+                .iter()
+                .filter(|installation| installation.path().is_dir()),
+        );
+    for installation in &created {
+        let result = if let Some(remaining) =
+            remaining_minor_versions.get(installation.minor_version_key())
+        {
+            remaining
+                .update_minor_version_link(preview)
+                .map_err(Error::from)
+        } else if let Some(minor_version_link) =
+            PythonMinorVersionLink::from_installation(installation, preview)
+                .filter(PythonMinorVersionLink::exists)
+        {
+            if cfg!(windows) {
+                fs_err::remove_dir(minor_version_link.symlink_directory.as_path())
+            } else {
+                fs_err::remove_file(minor_version_link.symlink_directory.as_path())
+            }
+            .map_err(Error::from)
+        } else {
+            Ok(())
+        };
+        if let Err(err) = result {
+            warn_user!(
+                "Failed to restore the minor version link for `{}` after a failed install: {err}",
+                installation.key().green()
+            );
+        }
+    }
+
+    let failed = errors
+        .iter()
+        .map(|(_, key, _)| key)
+        .collect::<FxHashSet<_>>()
+        .len();
+    for (kind, key, err) in errors
+        .into_iter()
+        .sorted_unstable_by(|(_, key_a, _), (_, key_b, _)| key_a.cmp(key_b))
+    {
+        let context = match kind {
+            InstallErrorKind::DownloadUnpack => format!("Failed to install {key}"),
+            InstallErrorKind::Bin => format!("Failed to install executable for {key}"),
+            InstallErrorKind::Registry => format!("Failed to create registry entry for {key}"),
+            InstallErrorKind::Hook => format!("Failed to run post-install hook for {key}"),
+        };
+        write_error_chain(
+            err.context(context).as_ref(),
+            printer.stderr(),
+            "error",
+            AnsiColors::Red,
+        )?;
+    }
+
+    // Ex) "Failed to install 1 of 4 versions; rolled back 3 versions"
+    writeln!(
+        printer.stderr(),
+        "Failed to install {failed} of {}; rolled back {}",
+        if total == 1 {
+            "1 version".to_string()
+        } else {
+            format!("{total} versions")
+        },
+        if removed.len() == 1 {
+            "1 version".to_string()
+        } else {
+            format!("{} versions", removed.len())
+        }
+    )?;
+    for key in removed.iter().sorted() {
+        writeln!(printer.stderr(), " {} {}", "-".red(), key.bold())?;
+    }
+
+    Ok(ExitStatus::Failure)
+}
+
+/// Ensure that an installation is complete, e.g., after it was unpacked by an older version of uv.
+fn ensure_complete(installation: &ManagedPythonInstallation) -> Result<()> {
+    installation.ensure_externally_managed()?;
+    installation.ensure_sysconfig_patched()?;
+    installation.ensure_canonical_executables()?;
+    if let Err(e) = installation.ensure_dylib_patched() {
+        e.warn_user(installation);
+    }
+    Ok(())
+}
+
+/// Record the release channel of the requests satisfied by an installation, such that upgrades of
+/// the installation follow the channel.
+fn record_channel(
+    installation: &ManagedPythonInstallation,
+    requests: &[InstallRequest],
+) -> Result<()> {
+    let Some(channel) = requests
+        .iter()
+        .filter(|request| request.matches_installation(installation))
+        .find_map(|request| request.download_request.channel())
+    else {
+        return Ok(());
+    };
+    installation.set_channel(channel)?;
+    Ok(())
+}
+
+/// Return the release channel recorded for the installations of a minor version, if any.
+///
+/// If the installations were installed from different channels, the least stable channel is
+/// returned, as it includes the releases of the others.
+fn recorded_channel(
+    installations: &[ManagedPythonInstallation],
+    major: u8,
+    minor: u8,
+) -> Option<PythonChannel> {
+    installations
+        .iter()
+        .filter(|installation| {
+            installation.key().major() == major && installation.key().minor() == minor
+        })
+        .filter_map(ManagedPythonInstallation::channel)
+        .min()
+}
+
+/// Run the post-install hook for a managed Python installation.
+///
+/// The hook's output is forwarded to stderr, and the hook is terminated if it does not complete
+/// within the configured timeout.
+async fn run_install_hook(
+    hook: &PythonInstallHookSettings,
+    installation: &ManagedPythonInstallation,
+) -> Result<()> {
+    let Some((program, args)) = hook.command.split_first() else {
+        return Ok(());
+    };
+    let executable = installation.executable(false);
+
+    // Python scripts are executed with the newly installed interpreter.
+    let mut command = if Path::new(program)
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("py"))
+    {
+        let mut command = tokio::process::Command::new(&executable);
+        command.arg(program);
+        command
+    } else {
+        tokio::process::Command::new(program)
+    };
+    command
+        .args(args)
+        .env(EnvVars::UV_PYTHON_INSTALL_PATH, installation.path())
+        .env(
+            EnvVars::UV_PYTHON_INSTALL_KEY,
+            installation.key().to_string(),
+        )
+        .env(EnvVars::UV_PYTHON_INSTALL_EXECUTABLE, &executable)
+        .stdin(Stdio::null())
+        .stdout(std::io::stderr())
+        .kill_on_drop(true);
+
+    debug!(
+        "Running post-install hook for {}: `{}`",
+        installation.key(),
+        hook.command.join(" ")
+    );
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Failed to spawn hook command `{program}`"))?;
+
+    let status = match tokio::time::timeout(hook.timeout, child.wait()).await {
+        Ok(status) => {
+            status.with_context(|| format!("Failed to wait for hook command `{program}`"))?
+        }
+        Err(_) => {
+            child.kill().await?;
+            anyhow::bail!(
+                "Hook command `{program}` timed out after {}s",
+                hook.timeout.as_secs()
+            );
+        }
+    };
+    if !status.success() {
+        anyhow::bail!("Hook command `{program}` failed with {status}");
+    }
+
+    Ok(())
+}
+
 /// Link the binaries of a managed Python installation to the bin directory.
 ///
 /// This function is fallible, but errors are pushed to `errors` instead of being thrown.
@@ -929,7 +1704,13 @@ fn create_bin_links(
                     }
                 }
 
-                // Replace the existing link
+                // Replace the existing link, recording its target to restore it on rollback
+                if let Ok(previous) = fs_err::read_link(&to) {
+                    changelog
+                        .replaced_executables
+                        .entry(target.clone())
+                        .or_insert(previous);
+                }
                 if let Err(err) = fs_err::remove_file(&to) {
                     errors.push((
                         InstallErrorKind::Bin,
@@ -1,6 +1,7 @@
 use serde::Serialize;
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use uv_cli::PythonListFormat;
 use uv_configuration::Preview;
 use uv_pep440::Version;
@@ -8,13 +9,16 @@ use uv_pep440::Version;
 use anyhow::Result;
 use itertools::Either;
 use owo_colors::OwoColorize;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
 use uv_cache::Cache;
 use uv_fs::Simplified;
-use uv_python::downloads::PythonDownloadRequest;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
+use uv_python::managed::ManagedPythonInstallations;
 use uv_python::{
-    DiscoveryError, EnvironmentPreference, PythonDownloads, PythonInstallation, PythonNotFound,
-    PythonPreference, PythonRequest, PythonSource, find_python_installations,
+    DiscoveryError, EnvironmentPreference, InterpreterIdentity, PythonDownloads,
+    PythonInstallation, PythonInstallationKey, PythonInstallationMinorVersionKey, PythonNotFound,
+    PythonPreference, PythonRequest, PythonSource, VersionRequest, find_python_installations,
 };
 
 use crate::commands::ExitStatus;
@@ -48,6 +52,18 @@ struct PrintData {
     implementation: String,
     arch: String,
     libc: String,
+    protected: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct OutdatedPrintData {
+    key: String,
+    version: Version,
+    path: String,
+    latest_key: String,
+    latest_version: Version,
 }
 
 /// List available Python installations.
@@ -61,6 +77,7 @@ pub(crate) async fn list(
     show_urls: bool,
     output_format: PythonListFormat,
     python_downloads_json_url: Option<String>,
+    python_install_dir: Option<&Path>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     cache: &Cache,
@@ -120,14 +137,17 @@ pub(crate) async fn list(
         }
     }
 
+    let installed_request = request.as_ref().unwrap_or(&PythonRequest::Any);
+    let installed_cache = installed_request.query_cache(cache);
     let installed =
         match kinds {
             PythonListKinds::Installed | PythonListKinds::Default => {
                 Some(find_python_installations(
-                request.as_ref().unwrap_or(&PythonRequest::Any),
+                installed_request,
                 EnvironmentPreference::OnlySystem,
                 python_preference,
-                cache,
+                &installed_cache,
+                python_install_dir,
                 preview,
             )
             // Raise discovery errors if critical
@@ -145,6 +165,9 @@ pub(crate) async fn list(
             PythonListKinds::Downloads => None,
         };
 
+    // The identity of each installed interpreter, to group the paths that are aliases of the same
+    // interpreter, e.g., symbolic links or hard links to the same executable.
+    let mut identities = FxHashMap::<_, InterpreterIdentity>::default();
     if let Some(installed) = installed {
         for installation in installed {
             let kind = if matches!(installation.source(), PythonSource::Managed) {
@@ -152,24 +175,35 @@ pub(crate) async fn list(
             } else {
                 Kind::System
             };
-            output.insert((
-                installation.key(),
-                kind,
-                Either::Left(installation.interpreter().real_executable().to_path_buf()),
-            ));
+            let path = installation.interpreter().real_executable().to_path_buf();
+            if let Ok(identity) = installation.interpreter().identity() {
+                identities.insert(path.clone(), identity);
+            }
+            output.insert((installation.key(), kind, Either::Left(path)));
         }
     }
 
     let mut seen_minor = FxHashSet::default();
     let mut seen_patch = FxHashSet::default();
     let mut seen_paths = FxHashSet::default();
-    let mut include = Vec::new();
+    let mut seen_identities = FxHashMap::default();
+    let mut include: Vec<(_, _, _, Vec<&PathBuf>)> = Vec::new();
     for (key, kind, uri) in output.iter().rev() {
         // Do not show the same path more than once
         if let Either::Left(path) = uri {
             if !seen_paths.insert(path) {
                 continue;
             }
+
+            // Show aliases of an interpreter under the first path at which it was found
+            if let Some(identity) = identities.remove(path) {
+                if let Some(&index) = seen_identities.get(&identity) {
+                    let (.., aliases) = &mut include[index];
+                    aliases.push(path);
+                    continue;
+                }
+                seen_identities.insert(identity, include.len());
+            }
         }
 
         // Only show the latest patch version for each download unless all were requested.
@@ -210,14 +244,31 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, kind, uri, Vec::new()));
     }
 
+    // Determine which of the managed installations are protected from uninstallation.
+    let protected = if include
+        .iter()
+        .any(|(_, kind, ..)| matches!(kind, Kind::Managed))
+    {
+        let mut protected = BTreeSet::new();
+        for installations in ManagedPythonInstallations::discoverable(python_install_dir)? {
+            protected.extend(installations.protected()?);
+        }
+        protected
+    } else {
+        BTreeSet::new()
+    };
+    let is_protected = |key: &PythonInstallationKey, kind: &Kind| {
+        matches!(kind, Kind::Managed) && protected.contains(key)
+    };
+
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, kind, uri, aliases)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -255,6 +306,11 @@ pub(crate) async fn list(
                         os: key.os().to_string(),
                         variant: key.variant().to_string(),
                         libc: key.libc().to_string(),
+                        protected: is_protected(key, kind),
+                        aliases: aliases
+                            .iter()
+                            .map(|alias| alias.user_display().to_string())
+                            .collect(),
                     })
                 })
                 .collect::<Result<Vec<_>>>()?;
@@ -264,9 +320,14 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, kind, uri, aliases) in include {
+                let suffix = if is_protected(key, kind) {
+                    format!(" {}", "(protected)".dimmed())
+                } else {
+                    String::new()
+                };
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
@@ -274,17 +335,26 @@ pub(crate) async fn list(
                         if is_symlink {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {} -> {}",
+                                "{key:width$}    {} -> {}{suffix}",
                                 path.user_display().cyan(),
                                 path.read_link()?.user_display().cyan()
                             )?;
                         } else {
                             writeln!(
                                 printer.stdout(),
-                                "{key:width$}    {}",
+                                "{key:width$}    {}{suffix}",
                                 path.user_display().cyan()
                             )?;
                         }
+                        for alias in aliases {
+                            writeln!(
+                                printer.stdout(),
+                                "{:width$}    {} {}",
+                                "",
+                                alias.user_display().cyan(),
+                                "(alias)".dimmed()
+                            )?;
+                        }
                     }
                     Either::Right(url) => {
                         if show_urls {
@@ -304,3 +374,102 @@ pub(crate) async fn list(
 
     Ok(ExitStatus::Success)
 }
+
+/// List managed Python installations with a newer patch release available.
+pub(crate) fn outdated(
+    request: Option<String>,
+    output_format: PythonListFormat,
+    exit_code: bool,
+    python_downloads_json_url: Option<String>,
+    python_install_dir: Option<&Path>,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let request = request.as_deref().map(PythonRequest::parse);
+
+    let mut installed = Vec::new();
+    for installations in ManagedPythonInstallations::discoverable(python_install_dir)? {
+        installed.extend(
+            installations
+                .find_matching_current_platform()?
+                .filter(|installation| {
+                    request
+                        .as_ref()
+                        .is_none_or(|request| installation.satisfies(request))
+                }),
+        );
+    }
+
+    // Compare the latest installed patch version of each minor version against the latest
+    // available download for the same minor version.
+    let mut outdated = Vec::new();
+    for installation in
+        PythonInstallationMinorVersionKey::highest_installations_by_minor_version_key(&installed)
+            .into_values()
+    {
+        let download_request = PythonDownloadRequest::from(&installation).with_version(
+            VersionRequest::major_minor_request_from_key(installation.key()),
+        );
+        let download = match ManagedPythonDownload::from_request(
+            &download_request,
+            python_downloads_json_url.as_deref(),
+        ) {
+            Ok(download) => download,
+            Err(uv_python::downloads::Error::NoDownloadFound(_)) => {
+                debug!(
+                    "No download found for installed version `{}`",
+                    installation.key()
+                );
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if download.key().version().version() > installation.key().version().version() {
+            outdated.push((installation, download));
+        }
+    }
+
+    match output_format {
+        PythonListFormat::Json => {
+            let data = outdated
+                .iter()
+                .map(|(installation, download)| OutdatedPrintData {
+                    key: installation.key().to_string(),
+                    version: installation.key().version().version().clone(),
+                    path: installation.executable(false).user_display().to_string(),
+                    latest_key: download.key().to_string(),
+                    latest_version: download.key().version().version().clone(),
+                })
+                .collect::<Vec<_>>();
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        }
+        PythonListFormat::Text => {
+            if outdated.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "All managed Python installations are up-to-date"
+                )?;
+            }
+
+            // Compute the width of the first column.
+            let width = outdated.iter().fold(0usize, |acc, (installation, _)| {
+                acc.max(installation.key().to_string().len())
+            });
+
+            for (installation, download) in &outdated {
+                writeln!(
+                    printer.stdout(),
+                    "{:width$}    {} -> {}",
+                    installation.key().to_string(),
+                    installation.key().version().to_string().red(),
+                    download.key().version().to_string().green(),
+                )?;
+            }
+        }
+    }
+
+    if exit_code && !outdated.is_empty() {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
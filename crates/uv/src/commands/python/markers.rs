@@ -0,0 +1,119 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_cli::PythonMarkersFormat;
+use uv_configuration::{Preview, TargetTriple};
+use uv_python::{
+    EnvironmentPreference, ImplementationName, PythonInstallation, PythonPreference, PythonRequest,
+    PythonVariant, PythonVersion, VersionRequest,
+};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::synthesize_markers;
+use crate::printer::Printer;
+
+/// Show the environment markers for a Python interpreter, with optional overrides.
+pub(crate) fn markers(
+    python: Option<&str>,
+    python_version: Option<&PythonVersion>,
+    python_platform: Option<&TargetTriple>,
+    implementation: Option<ImplementationName>,
+    system: bool,
+    output_format: PythonMarkersFormat,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let environment_preference = if system {
+        EnvironmentPreference::OnlySystem
+    } else {
+        EnvironmentPreference::Any
+    };
+
+    // The discovered interpreter provides any markers that aren't overridden.
+    let request = python.map(PythonRequest::parse).unwrap_or_default();
+    let mut installation = PythonInstallation::find(
+        &request,
+        environment_preference,
+        python_preference,
+        cache,
+        python_install_dir,
+        preview,
+    )?;
+
+    // The `implementation_version` of an alternative implementation (e.g., the PyPy version)
+    // can't be inferred from the base interpreter, so use an interpreter of the requested
+    // implementation as the base instead.
+    if let Some(implementation) = implementation {
+        let base = installation.interpreter().markers();
+        if implementation.markers(base).implementation_name() != base.implementation_name() {
+            let request = match python_version {
+                Some(python_version) => PythonRequest::ImplementationVersion(
+                    implementation,
+                    VersionRequest::MajorMinor(
+                        python_version.major(),
+                        python_version.minor(),
+                        PythonVariant::Default,
+                    ),
+                ),
+                None => PythonRequest::Implementation(implementation),
+            };
+            installation = PythonInstallation::find(
+                &request,
+                environment_preference,
+                python_preference,
+                cache,
+                python_install_dir,
+                preview,
+            )?;
+        }
+    }
+    let interpreter = installation.interpreter();
+
+    let base = match implementation {
+        Some(implementation) => implementation.markers(interpreter.markers()),
+        None => interpreter.markers().clone(),
+    };
+    let markers = synthesize_markers(python_version, python_platform, &base);
+
+    if output_format == PythonMarkersFormat::Json {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&*markers)?)?;
+    } else {
+        for (key, value) in [
+            (
+                "implementation_name",
+                markers.implementation_name().to_string(),
+            ),
+            (
+                "implementation_version",
+                markers.implementation_version().to_string(),
+            ),
+            ("os_name", markers.os_name().to_string()),
+            ("platform_machine", markers.platform_machine().to_string()),
+            (
+                "platform_python_implementation",
+                markers.platform_python_implementation().to_string(),
+            ),
+            ("platform_release", markers.platform_release().to_string()),
+            ("platform_system", markers.platform_system().to_string()),
+            ("platform_version", markers.platform_version().to_string()),
+            (
+                "python_full_version",
+                markers.python_full_version().to_string(),
+            ),
+            ("python_version", markers.python_version().to_string()),
+            ("sys_platform", markers.sys_platform().to_string()),
+        ] {
+            // Quote the values, as some markers, e.g., `platform_release`, may be empty.
+            writeln!(printer.stdout(), "{}: \"{value}\"", key.cyan())?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -1,10 +1,19 @@
 pub(crate) mod dir;
+pub(crate) mod du;
+pub(crate) mod env_vars;
 pub(crate) mod find;
+pub(crate) mod inspect;
 pub(crate) mod install;
 pub(crate) mod list;
+pub(crate) mod markers;
 pub(crate) mod pin;
+pub(crate) mod pin_install;
+pub(crate) mod resolve;
 pub(crate) mod uninstall;
 pub(crate) mod update_shell;
+pub(crate) mod upgrade;
+pub(crate) mod validate_downloads;
+pub(crate) mod which;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub(super) enum ChangeEventKind {
@@ -100,6 +100,7 @@ pub(crate) async fn pin(
                         pin,
                         virtual_project,
                         python_preference,
+                        install_mirrors.python_install_dir.as_deref(),
                         cache,
                         preview,
                     );
@@ -133,6 +134,10 @@ pub(crate) async fn pin(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await
@@ -207,12 +212,19 @@ pub(crate) async fn pin(
             // TODO(zanieb): We should find a nice way to surface that as an error
             bail!("Failed to determine directory for global Python pin");
         };
-        new.with_versions(vec![request])
+        new
     } else {
         PythonVersionFile::new(project_dir.join(PYTHON_VERSION_FILENAME))
-            .with_versions(vec![request])
     };
 
+    // If we're updating an existing version file, preserve its comments and formatting.
+    let new = existing
+        .as_ref()
+        .filter(|existing| existing.path() == new.path())
+        .cloned()
+        .unwrap_or(new)
+        .with_versions(vec![request]);
+
     new.write().await?;
 
     // If we updated an existing version file to a new version
@@ -269,6 +281,7 @@ fn warn_if_existing_pin_incompatible_with_project(
     pin: &PythonRequest,
     virtual_project: &VirtualProject,
     python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
     cache: &Cache,
     preview: Preview,
 ) {
@@ -295,6 +308,7 @@ fn warn_if_existing_pin_incompatible_with_project(
         EnvironmentPreference::OnlySystem,
         python_preference,
         cache,
+        python_install_dir,
         preview,
     ) {
         Ok(python) => {
@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_python::downloads::PythonDownloadRequest;
+use uv_python::managed::ManagedPythonInstallations;
+use uv_python::{PythonInstallationKey, PythonRequest};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Protect managed Python installations from uninstallation, or lift the protection.
+pub(crate) async fn pin_install(
+    install_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    remove: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
+
+    let _lock = installations.lock().await?;
+
+    let mut protected = installations.protected()?;
+
+    // When lifting the protection, also consider protected keys for which the installation no
+    // longer exists.
+    let candidates = installations
+        .find_all()?
+        .map(|installation| installation.key().clone())
+        .chain(if remove {
+            protected.iter().cloned().collect()
+        } else {
+            vec![]
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut changed = BTreeSet::<PythonInstallationKey>::new();
+    let mut unchanged = BTreeSet::<PythonInstallationKey>::new();
+    let mut failed = false;
+    for target in targets.into_iter().collect::<BTreeSet<_>>() {
+        let request = PythonRequest::parse(&target);
+        let download_request = PythonDownloadRequest::from_request(&request)
+            .ok_or_else(|| anyhow::anyhow!("Cannot protect managed Python for request: {request}"))?
+            // Always include pre-releases, as in uninstalls.
+            .with_prereleases(true);
+
+        let mut found = false;
+        for key in candidates
+            .iter()
+            .filter(|key| download_request.satisfied_by_key(key))
+        {
+            found = true;
+            let updated = if remove {
+                protected.remove(key)
+            } else {
+                protected.insert(key.clone())
+            };
+            if updated {
+                changed.insert(key.clone());
+            } else {
+                unchanged.insert(key.clone());
+            }
+        }
+
+        if !found {
+            writeln!(
+                printer.stderr(),
+                "No existing installations found for: {}",
+                request.cyan()
+            )?;
+            failed = true;
+        }
+    }
+
+    installations.set_protected(&protected)?;
+
+    for key in &unchanged {
+        if remove {
+            writeln!(printer.stderr(), "`{}` is not protected", key.cyan())?;
+        } else {
+            writeln!(printer.stderr(), "`{}` is already protected", key.cyan())?;
+        }
+    }
+    for key in &changed {
+        if remove {
+            writeln!(printer.stderr(), "Removed protection from {}", key.bold())?;
+        } else {
+            writeln!(printer.stderr(), "Protected {}", key.bold())?;
+        }
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::PythonResolveFormat;
+use uv_client::BaseClientBuilder;
+use uv_platform::Platform;
+use uv_python::PythonRequest;
+use uv_python::downloads::{ManagedPythonDownload, PythonDownloadRequest};
+use uv_settings::PythonInstallMirrors;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    key: String,
+    version: String,
+    implementation: String,
+    os: String,
+    arch: String,
+    libc: String,
+    url: String,
+    size: Option<u64>,
+    sha256: Option<String>,
+}
+
+/// Show the Python download that would be used for a request, without downloading it.
+pub(crate) async fn resolve(
+    request: Option<String>,
+    platform: Option<String>,
+    output_format: PythonResolveFormat,
+    install_mirrors: PythonInstallMirrors,
+    network_settings: NetworkSettings,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let request = request
+        .as_deref()
+        .map(PythonRequest::parse)
+        .unwrap_or_default();
+
+    let Some(mut download_request) = PythonDownloadRequest::from_request(&request) else {
+        anyhow::bail!(
+            "`{}` cannot be satisfied by a Python download",
+            request.to_canonical_string()
+        );
+    };
+
+    if let Some(platform) = platform {
+        let platform = Platform::from_str(&platform)
+            .with_context(|| format!("Invalid platform `{platform}`"))?;
+        download_request = download_request
+            .with_os(platform.os)
+            .with_arch(platform.arch)
+            .with_libc(platform.libc);
+    }
+
+    // Select the download as `PythonInstallation::find_or_download` would.
+    let download = ManagedPythonDownload::from_request_with_resolution(
+        &download_request.fill()?,
+        install_mirrors.python_resolution.unwrap_or_default(),
+        install_mirrors.python_downloads_json_url.as_deref(),
+    )?;
+
+    let python_install_mirror = install_mirrors.python_install_mirror.as_deref();
+    let pypy_install_mirror = install_mirrors.pypy_install_mirror.as_deref();
+    let url = download.download_url(python_install_mirror, pypy_install_mirror)?;
+
+    let client_builder = BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let client = install_mirrors
+        .download_client()
+        .apply(client_builder)
+        .build();
+    let size = download
+        .archive_size(&client, python_install_mirror, pypy_install_mirror)
+        .await?;
+
+    let key = download.key();
+    if output_format == PythonResolveFormat::Json {
+        let data = PrintData {
+            key: key.to_string(),
+            version: key.version().to_string(),
+            implementation: key.implementation().to_string(),
+            os: key.os().to_string(),
+            arch: key.arch().to_string(),
+            libc: key.libc().to_string(),
+            url: url.to_string(),
+            size,
+            sha256: download.sha256().map(ToString::to_string),
+        };
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+    } else {
+        writeln!(printer.stdout(), "{}", key.cyan())?;
+        writeln!(printer.stdout(), "url: {url}")?;
+        if let Some(size) = size {
+            let (bytes, unit) = human_readable_bytes(size);
+            writeln!(printer.stdout(), "size: {size} ({bytes:.1}{unit})")?;
+        }
+        if let Some(sha256) = download.sha256() {
+            writeln!(printer.stdout(), "sha256: {sha256}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
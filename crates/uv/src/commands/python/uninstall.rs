@@ -29,6 +29,7 @@ pub(crate) async fn uninstall(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     all: bool,
+    force: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -37,7 +38,7 @@ pub(crate) async fn uninstall(
     let _lock = installations.lock().await?;
 
     // Perform the uninstallation.
-    do_uninstall(&installations, targets, all, printer, preview).await?;
+    do_uninstall(&installations, targets, all, force, printer, preview).await?;
 
     // Clean up any empty directories.
     if uv_fs::directories(installations.root())?.all(|path| uv_fs::is_temporary(&path)) {
@@ -65,6 +66,7 @@ async fn do_uninstall(
     installations: &ManagedPythonInstallations,
     targets: Vec<String>,
     all: bool,
+    force: bool,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -132,6 +134,27 @@ async fn do_uninstall(
         }
     }
 
+    // Skip any protected installations, unless forced.
+    let mut protected = installations.protected()?;
+    if !force {
+        let skipped = matching_installations
+            .iter()
+            .filter(|installation| protected.contains(installation.key()))
+            .cloned()
+            .collect::<Vec<_>>();
+        for installation in &skipped {
+            writeln!(
+                printer.stderr(),
+                "Skipping protected installation: {} (use `--force` to uninstall)",
+                installation.key().cyan()
+            )?;
+            matching_installations.remove(installation);
+        }
+        if !skipped.is_empty() && matching_installations.is_empty() {
+            return Ok(ExitStatus::Success);
+        }
+    }
+
     if matching_installations.is_empty() {
         writeln!(
             printer.stderr(),
@@ -144,14 +167,21 @@ async fn do_uninstall(
     // and the registry removal.
     let mut errors = vec![];
     #[cfg(windows)]
-    {
-        uv_python::windows_registry::remove_registry_entry(
+    let unregistered = {
+        // Clearing the entire registry key would also drop the entries of the installations that
+        // are kept, e.g., protected installations, so only do so if every installation is removed.
+        let all = all
+            && installed_installations
+                .iter()
+                .all(|installation| matching_installations.contains(installation));
+        let unregistered = uv_python::windows_registry::remove_registry_entry(
             &matching_installations,
             all,
             &mut errors,
         );
         uv_python::windows_registry::remove_orphan_registry_entries(&installed_installations);
-    }
+        unregistered
+    };
 
     // Find and remove all relevant Python executables
     let mut uninstalled_executables: FxHashMap<PythonInstallationKey, FxHashSet<PathBuf>> =
@@ -220,6 +250,24 @@ async fn do_uninstall(
         }
     }
 
+    // Forget the protection of any forcibly uninstalled installations.
+    if uninstalled.iter().any(|key| protected.contains(key)) {
+        protected.retain(|key| !uninstalled.contains(key));
+        installations.set_protected(&protected)?;
+    }
+
+    // If an installation could not be removed, restore its registry entry, so that the registry
+    // stays consistent with the installations on disk.
+    #[cfg(windows)]
+    for installation in unregistered
+        .into_iter()
+        .filter(|installation| !uninstalled.contains(installation.key()))
+    {
+        if let Err(err) = uv_python::windows_registry::create_registry_entry(installation) {
+            errors.push((installation.key().clone(), err.into()));
+        }
+    }
+
     // Read all existing managed installations and find the highest installed patch
     // for each installed minor version. Ensure the minor version link directory
     // is still valid.
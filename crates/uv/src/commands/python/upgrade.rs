@@ -0,0 +1,519 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_configuration::{Concurrency, DependencyGroupsWithDefaults, Preview, PreviewFeatures};
+use uv_distribution_types::RequiresPython;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::downloads::{
+    ManagedPythonDownload, PythonDownloadClientSettings, PythonDownloadRequest,
+};
+use uv_python::managed::ManagedPythonInstallations;
+use uv_python::{
+    ImplementationName, LenientImplementationName, PyVenvConfiguration, PythonChannel,
+    PythonDownloads, PythonRequest, PythonVersion, PythonVersionFile, VersionFileDiscoveryOptions,
+    VersionRequest,
+};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::project::find_requires_python;
+use crate::commands::python::install::install;
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, PythonInstallHookSettings};
+
+/// A step required to migrate a project environment to a new Python minor version.
+#[derive(Debug)]
+enum Migration {
+    /// The environment can be recreated with the new minor version as-is.
+    Rebase,
+    /// The Python version file pins the old minor version, and must be updated before the
+    /// environment can be recreated.
+    UpdatePin(PythonVersionFile),
+    /// The global Python version file pins the old minor version; it's not updated
+    /// automatically, as it applies beyond the project.
+    GlobalPin(PathBuf),
+    /// The project's `requires-python` excludes the new minor version.
+    RequiresPython(RequiresPython),
+    /// A tool environment, which is reinstalled with `uv tool upgrade --python`.
+    Tool(PackageName),
+    /// A virtual environment outside the project, e.g., the active environment, which is rebased
+    /// with `uv venv --rebase`.
+    Environment,
+}
+
+impl Migration {
+    /// Returns `true` if the migration can be applied automatically.
+    fn is_automatic(&self) -> bool {
+        matches!(self, Self::Rebase | Self::UpdatePin(_))
+    }
+
+    /// Returns `true` if the migration must be resolved manually before the plan can be applied.
+    fn is_blocking(&self) -> bool {
+        matches!(self, Self::GlobalPin(_) | Self::RequiresPython(_))
+    }
+}
+
+/// Upgrade to the latest Python minor version, and migrate the project environment.
+pub(crate) async fn upgrade_minor(
+    project_dir: &Path,
+    install_dir: Option<PathBuf>,
+    install_staging_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    apply: bool,
+    python_install_mirror: Option<String>,
+    pypy_install_mirror: Option<String>,
+    python_downloads_json_url: Option<String>,
+    download_client: PythonDownloadClientSettings,
+    network_settings: NetworkSettings,
+    channel: Option<PythonChannel>,
+    hook: Option<PythonInstallHookSettings>,
+    python_downloads: PythonDownloads,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::PYTHON_UPGRADE) {
+        warn_user!(
+            "`uv python upgrade` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning",
+            PreviewFeatures::PYTHON_UPGRADE
+        );
+    }
+
+    let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
+
+    // Determine the minor versions to upgrade from.
+    let sources = if targets.is_empty() {
+        installations
+            .find_all()?
+            .filter(|installation| {
+                matches!(
+                    &*installation.implementation(),
+                    LenientImplementationName::Known(ImplementationName::CPython)
+                )
+            })
+            .map(|installation| (installation.key().major(), installation.key().minor()))
+            .collect::<BTreeSet<_>>()
+    } else {
+        targets
+            .iter()
+            .map(|target| match PythonRequest::parse(target) {
+                PythonRequest::Version(VersionRequest::MajorMinor(major, minor, _)) => {
+                    Ok((major, minor))
+                }
+                _ => Err(anyhow::anyhow!(
+                    "`uv python upgrade --minor` only accepts minor versions, but found `{target}`"
+                )),
+            })
+            .collect::<Result<BTreeSet<_>>>()?
+    };
+
+    // Determine the latest available minor version.
+    let request = PythonDownloadRequest::from_request(&PythonRequest::Default)
+        .expect("The default request is a valid download request")
+        .fill()?;
+    let request = if let Some(channel) = channel {
+        request.with_channel(channel)
+    } else {
+        request
+    };
+    let latest =
+        ManagedPythonDownload::from_request(&request, python_downloads_json_url.as_deref())?;
+    let target = (latest.key().major(), latest.key().minor());
+    let target_version = PythonVersion::from_str(&format!("{}.{}", target.0, target.1))
+        .map_err(anyhow::Error::msg)?;
+
+    let sources = sources
+        .into_iter()
+        .filter(|source| *source < target)
+        .collect::<BTreeSet<_>>();
+    if sources.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "There are no installed versions older than Python {target_version}"
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let installed = installations
+        .find_all()?
+        .any(|installation| (installation.key().major(), installation.key().minor()) == target);
+
+    // Plan the migration of the dependent environments, if any.
+    let plans = plan(
+        project_dir,
+        &installations,
+        &sources,
+        &target_version,
+        no_config,
+    )
+    .await?;
+
+    writeln!(
+        printer.stderr(),
+        "Migration plan to Python {}:",
+        target_version.cyan()
+    )?;
+    if !installed {
+        writeln!(
+            printer.stderr(),
+            " {} Python {target_version}: install",
+            "+".green()
+        )?;
+    }
+    if plans.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No environments depend on Python {}",
+            sources
+                .iter()
+                .map(|(major, minor)| format!("{major}.{minor}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+    }
+    for (dependent, migration) in &plans {
+        let environment = &dependent.environment;
+        match migration {
+            Migration::Rebase => writeln!(
+                printer.stderr(),
+                " {} {} ({}): recreate the environment",
+                "~".yellow(),
+                environment.user_display().bold(),
+                dependent.from
+            )?,
+            Migration::UpdatePin(file) => writeln!(
+                printer.stderr(),
+                " {} {} ({}): update `{}` and recreate the environment",
+                "~".yellow(),
+                environment.user_display().bold(),
+                dependent.from,
+                file.path().user_display()
+            )?,
+            Migration::GlobalPin(path) => writeln!(
+                printer.stderr(),
+                " {} {} ({}): the global pin at `{}` excludes Python {target_version}; update it with `{}`",
+                "!".red(),
+                environment.user_display().bold(),
+                dependent.from,
+                path.user_display(),
+                format!("uv python pin --global {target_version}").green()
+            )?,
+            Migration::RequiresPython(requires_python) => writeln!(
+                printer.stderr(),
+                " {} {} ({}): `requires-python` ({}) excludes Python {target_version}; update it in `pyproject.toml`",
+                "!".red(),
+                environment.user_display().bold(),
+                dependent.from,
+                requires_python.specifiers()
+            )?,
+            Migration::Tool(name) => writeln!(
+                printer.stderr(),
+                " {} {} ({}): reinstall the tool with `{}`",
+                "-".dimmed(),
+                environment.user_display().bold(),
+                dependent.from,
+                format!("uv tool upgrade {name} --python {target_version}").green()
+            )?,
+            Migration::Environment => writeln!(
+                printer.stderr(),
+                " {} {} ({}): rebase the environment with `{}`",
+                "-".dimmed(),
+                environment.user_display().bold(),
+                dependent.from,
+                format!(
+                    "uv venv {} --rebase {target_version}",
+                    environment.user_display()
+                )
+                .green()
+            )?,
+        }
+    }
+
+    if plans.iter().any(|(_, migration)| migration.is_blocking()) {
+        return Ok(ExitStatus::Failure);
+    }
+
+    if !apply {
+        writeln!(
+            printer.stderr(),
+            "Run `{}` to apply the plan",
+            "uv python upgrade --minor --apply".green()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Install the new minor version.
+    let status = install(
+        project_dir,
+        install_dir,
+        install_staging_dir,
+        vec![target_version.to_string()],
+        false,
+        false,
+        None,
+        None,
+        false,
+        python_install_mirror,
+        pypy_install_mirror,
+        python_downloads_json_url,
+        download_client,
+        network_settings,
+        false,
+        channel,
+        None,
+        hook,
+        false,
+        false,
+        false,
+        None,
+        python_downloads,
+        concurrency,
+        no_config,
+        cache,
+        preview,
+        printer,
+    )
+    .await?;
+    if !matches!(status, ExitStatus::Success) {
+        return Ok(status);
+    }
+
+    // Apply the automatic migrations; the others were reported above.
+    for (dependent, migration) in plans {
+        if !migration.is_automatic() {
+            continue;
+        }
+        let environment = &dependent.environment;
+
+        if let Migration::UpdatePin(file) = migration {
+            let path = file.path().to_path_buf();
+            let versions = file
+                .into_versions()
+                .into_iter()
+                .map(|request| {
+                    if pin_allows(&request, &target_version) {
+                        request
+                    } else {
+                        PythonRequest::parse(&target_version.to_string())
+                    }
+                })
+                .fold(Vec::new(), |mut versions, request| {
+                    if !versions.contains(&request) {
+                        versions.push(request);
+                    }
+                    versions
+                });
+            PythonVersionFile::new(path.clone())
+                .with_versions(versions)
+                .write()
+                .await?;
+            writeln!(
+                printer.stderr(),
+                "Updated `{}` to `{}`",
+                path.user_display().cyan(),
+                target_version
+            )?;
+        }
+
+        if environment.is_dir() {
+            debug!("Removing environment at: {}", environment.user_display());
+            fs_err::remove_dir_all(environment)?;
+        }
+        writeln!(
+            printer.stderr(),
+            "Removed environment at `{}`; run `{}` to recreate it with Python {target_version}",
+            environment.user_display().cyan(),
+            "uv sync".green()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The project environment to migrate.
+#[derive(Debug)]
+struct Dependent {
+    /// The path to the environment.
+    environment: PathBuf,
+    /// The Python version the environment, or its pin, currently uses.
+    from: String,
+}
+
+/// Determine the migrations required for the environments that depend on any of the `sources`
+/// minor versions, i.e., the project environment, the active virtual environment, and tool
+/// environments.
+async fn plan(
+    project_dir: &Path,
+    installations: &ManagedPythonInstallations,
+    sources: &BTreeSet<(u8, u8)>,
+    target: &PythonVersion,
+    no_config: bool,
+) -> Result<Vec<(Dependent, Migration)>> {
+    let mut plans = Vec::new();
+    if let Some(plan) = plan_project(project_dir, installations, sources, target, no_config).await?
+    {
+        plans.push(plan);
+    }
+
+    // The active virtual environment, unless it's the project environment.
+    if let Some(environment) = std::env::var_os(EnvVars::VIRTUAL_ENV).map(PathBuf::from) {
+        let planned = plans.iter().any(|(dependent, _)| {
+            uv_fs::is_same_file_allow_missing(&dependent.environment, &environment).unwrap_or(false)
+        });
+        if !planned {
+            if let Some(version) = managed_version(&environment, installations, sources) {
+                plans.push((
+                    Dependent {
+                        environment,
+                        from: format!("Python {}", version.without_patch()),
+                    },
+                    Migration::Environment,
+                ));
+            }
+        }
+    }
+
+    // Tool environments.
+    let installed_tools = InstalledTools::from_settings()?;
+    for (name, _) in installed_tools.tools().unwrap_or_default() {
+        let environment = installed_tools.tool_dir(&name);
+        if let Some(version) = managed_version(&environment, installations, sources) {
+            plans.push((
+                Dependent {
+                    environment,
+                    from: format!("Python {}", version.without_patch()),
+                },
+                Migration::Tool(name),
+            ));
+        }
+    }
+
+    Ok(plans)
+}
+
+/// Returns the version of the managed Python installation that the virtual environment at the
+/// given path was created with, if it's one of the `sources` minor versions.
+fn managed_version(
+    environment: &Path,
+    installations: &ManagedPythonInstallations,
+    sources: &BTreeSet<(u8, u8)>,
+) -> Option<PythonVersion> {
+    PyVenvConfiguration::parse(environment.join("pyvenv.cfg"))
+        .ok()
+        .filter(|cfg| {
+            cfg.home().is_some_and(|home| {
+                home.starts_with(installations.root())
+                    || dunce::canonicalize(home).is_ok_and(|home| {
+                        dunce::canonicalize(installations.root())
+                            .is_ok_and(|root| home.starts_with(root))
+                    })
+            })
+        })
+        .and_then(|cfg| cfg.version().cloned())
+        .filter(|version| sources.contains(&(version.major(), version.minor())))
+}
+
+/// Determine the migration required for the project in the given directory, if it depends on any
+/// of the `sources` minor versions.
+async fn plan_project(
+    project_dir: &Path,
+    installations: &ManagedPythonInstallations,
+    sources: &BTreeSet<(u8, u8)>,
+    target: &PythonVersion,
+    no_config: bool,
+) -> Result<Option<(Dependent, Migration)>> {
+    let workspace_cache = WorkspaceCache::default();
+    let project =
+        match VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await
+        {
+            Ok(project) => project,
+            Err(err) => {
+                debug!("Failed to discover project: {err}");
+                return Ok(None);
+            }
+        };
+    let workspace = project.workspace();
+    let environment = workspace.venv(None);
+
+    // Determine whether the existing environment uses a managed installation of a source version.
+    let installed = managed_version(&environment, installations, sources);
+
+    // Determine whether the project pins a source version.
+    let pin = PythonVersionFile::discover(
+        workspace.install_path(),
+        &VersionFileDiscoveryOptions::default().with_no_config(no_config),
+    )
+    .await?;
+    let pinned = pin.as_ref().and_then(|file| {
+        file.versions()
+            .find(|request| !pin_allows(request, target))
+            .and_then(|request| {
+                sources
+                    .iter()
+                    .find(|(major, minor)| {
+                        PythonVersion::from_str(&format!("{major}.{minor}"))
+                            .is_ok_and(|version| pin_allows(request, &version))
+                    })
+                    .map(|_| request.to_canonical_string())
+            })
+    });
+
+    let from = match (&installed, &pinned) {
+        (Some(version), _) => format!("Python {}", version.without_patch()),
+        (None, Some(request)) => format!("pinned to {request}"),
+        (None, None) => return Ok(None),
+    };
+    let dependent = Dependent { environment, from };
+
+    // The project's `requires-python` takes precedence, as it must change before anything else.
+    if let Some(requires_python) =
+        find_requires_python(workspace, &DependencyGroupsWithDefaults::none())?
+    {
+        let version = Version::new([u64::from(target.major()), u64::from(target.minor())]);
+        if !requires_python.contains(&version) {
+            return Ok(Some((
+                dependent,
+                Migration::RequiresPython(requires_python),
+            )));
+        }
+    }
+
+    if pinned.is_some() {
+        if let Some(file) = pin {
+            if file.is_global() {
+                return Ok(Some((
+                    dependent,
+                    Migration::GlobalPin(file.path().to_path_buf()),
+                )));
+            }
+            return Ok(Some((dependent, Migration::UpdatePin(file))));
+        }
+    }
+
+    Ok(Some((dependent, Migration::Rebase)))
+}
+
+/// Returns `true` if a pinned request admits the given Python version.
+fn pin_allows(request: &PythonRequest, version: &PythonVersion) -> bool {
+    match request {
+        PythonRequest::Version(request) | PythonRequest::ImplementationVersion(_, request) => {
+            request.matches_version(version)
+        }
+        _ => true,
+    }
+}
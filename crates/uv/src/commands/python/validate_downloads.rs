@@ -0,0 +1,209 @@
+use std::fmt::Write;
+use std::time::Instant;
+
+use anyhow::Result;
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cli::PythonValidateDownloadsFormat;
+use uv_client::BaseClientBuilder;
+use uv_configuration::Concurrency;
+use uv_python::PythonRequest;
+use uv_python::downloads::{DownloadValidation, ManagedPythonDownload, PythonDownloadRequest};
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+
+use crate::commands::{ExitStatus, elapsed};
+use crate::printer::Printer;
+use crate::settings::NetworkSettings;
+
+#[derive(Debug, Default, Serialize)]
+struct Report {
+    checked: usize,
+    missing: Vec<Missing>,
+    corrupted: Vec<Corrupted>,
+    failed: Vec<Failed>,
+}
+
+#[derive(Debug, Serialize)]
+struct Missing {
+    key: String,
+    url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Corrupted {
+    key: String,
+    url: String,
+    expected_sha256: String,
+    actual_sha256: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Failed {
+    key: String,
+    url: Option<String>,
+    error: String,
+}
+
+/// Check that the Python downloads in the download manifest are available from their source.
+pub(crate) async fn validate_downloads(
+    targets: Vec<String>,
+    full: bool,
+    output_format: PythonValidateDownloadsFormat,
+    install_mirrors: PythonInstallMirrors,
+    network_settings: NetworkSettings,
+    concurrency: Concurrency,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+
+    let requests = targets
+        .iter()
+        .map(|target| {
+            let request = PythonRequest::parse(target);
+            PythonDownloadRequest::from_request(&request).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "`{}` cannot be satisfied by a Python download",
+                    request.to_canonical_string()
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut downloads =
+        ManagedPythonDownload::iter_all(install_mirrors.python_downloads_json_url.as_deref())?
+            .filter(|download| {
+                requests.is_empty()
+                    || requests
+                        .iter()
+                        .any(|request| request.satisfied_by_download(download))
+            })
+            .collect::<Vec<_>>();
+    downloads.sort_by(|a, b| a.key().cmp(b.key()));
+
+    if downloads.is_empty() {
+        warn_user!("No Python downloads in the manifest match the request");
+        return Ok(ExitStatus::Success);
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .retries_from_env()?
+        .connectivity(network_settings.connectivity)
+        .native_tls(network_settings.native_tls)
+        .allow_insecure_host(network_settings.allow_insecure_host.clone());
+    let client = install_mirrors
+        .download_client()
+        .apply(client_builder)
+        .build();
+
+    let python_install_mirror = install_mirrors.python_install_mirror.as_deref();
+    let pypy_install_mirror = install_mirrors.pypy_install_mirror.as_deref();
+
+    let mut results = futures::stream::iter(&downloads)
+        .map(|download| {
+            let client = &client;
+            async move {
+                let url = download
+                    .download_url(python_install_mirror, pypy_install_mirror)
+                    .ok();
+                let result = download
+                    .validate(client, python_install_mirror, pypy_install_mirror, full)
+                    .await;
+                (download, url, result)
+            }
+        })
+        .buffered(concurrency.downloads);
+
+    let mut report = Report::default();
+    while let Some((download, url, result)) = results.next().await {
+        report.checked += 1;
+        let key = download.key().to_string();
+        match result {
+            Ok(DownloadValidation::Available { .. } | DownloadValidation::Verified { .. }) => {}
+            Ok(DownloadValidation::Missing) => report.missing.push(Missing {
+                key,
+                url: url.map(|url| url.to_string()).unwrap_or_default(),
+            }),
+            Ok(DownloadValidation::Corrupted { expected, actual }) => {
+                report.corrupted.push(Corrupted {
+                    key,
+                    url: url.map(|url| url.to_string()).unwrap_or_default(),
+                    expected_sha256: expected,
+                    actual_sha256: actual,
+                });
+            }
+            Err(err) => report.failed.push(Failed {
+                key,
+                url: url.map(|url| url.to_string()),
+                error: anyhow::Error::from(err)
+                    .chain()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(": "),
+            }),
+        }
+    }
+
+    let problems = report.missing.len() + report.corrupted.len() + report.failed.len();
+
+    if output_format == PythonValidateDownloadsFormat::Json {
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&report)?)?;
+    } else {
+        for missing in &report.missing {
+            writeln!(
+                printer.stdout(),
+                "{} {} ({})",
+                "missing:".red().bold(),
+                missing.key.cyan(),
+                missing.url
+            )?;
+        }
+        for corrupted in &report.corrupted {
+            writeln!(
+                printer.stdout(),
+                "{} {} ({})\n  expected sha256: {}\n  actual sha256:   {}",
+                "corrupted:".red().bold(),
+                corrupted.key.cyan(),
+                corrupted.url,
+                corrupted.expected_sha256,
+                corrupted.actual_sha256
+            )?;
+        }
+        for failed in &report.failed {
+            writeln!(
+                printer.stdout(),
+                "{} {}: {}",
+                "failed:".red().bold(),
+                failed.key.cyan(),
+                failed.error
+            )?;
+        }
+
+        let s = if report.checked == 1 { "" } else { "s" };
+        let summary = format!(
+            "Checked {} Python download{s} {}",
+            report.checked,
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        );
+        if problems == 0 {
+            writeln!(printer.stderr(), "{}", summary.bold())?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "{}: {} missing, {} corrupted, {} failed",
+                summary.bold(),
+                report.missing.len(),
+                report.corrupted.len(),
+                report.failed.len()
+            )?;
+        }
+    }
+
+    if problems == 0 {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
@@ -0,0 +1,383 @@
+use std::fmt::{self, Write};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::PythonWhichFormat;
+use uv_client::BaseClientBuilder;
+use uv_configuration::{Concurrency, PathEntry, PathPolicy, Preview};
+use uv_fs::Simplified;
+use uv_install_wheel::ShebangExecutable;
+use uv_installer::SitePackages;
+use uv_python::{
+    EnvironmentPreference, Interpreter, PythonEnvironment, PythonPreference, PythonRequest,
+};
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_static::EnvVars;
+use uv_trampoline_builder::Launcher;
+use uv_warnings::warn_user_once;
+use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
+
+use crate::commands::project::composer::EnvironmentComposer;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::run::requirements_environment;
+use crate::commands::project::{PlatformState, ProjectError};
+use crate::commands::{ExitStatus, SetupFailure, diagnostics};
+use crate::printer::Printer;
+use crate::settings::{NetworkSettings, ResolverInstallerSettings};
+
+/// Where an executable was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Source {
+    /// The scripts directory of an environment layered atop the base environment.
+    Overlay,
+    /// The scripts directory of the base environment.
+    Base,
+    /// The inherited `PATH`.
+    Inherit,
+    /// The command is a path, rather than a name to search for.
+    Path,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overlay => f.write_str("overlay"),
+            Self::Base => f.write_str("base"),
+            Self::Inherit => f.write_str("inherit"),
+            Self::Path => f.write_str("path"),
+        }
+    }
+}
+
+/// The kind of environment a Python interpreter belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum EnvironmentKind {
+    /// The project environment.
+    Project,
+    /// An ephemeral environment layered atop the base environment, e.g., by `uv run --with`.
+    Ephemeral,
+    /// Any other virtual environment.
+    Virtual,
+    /// A Python installation managed by uv.
+    Managed,
+    /// A Python installation that's marked as externally managed, e.g., by the system package
+    /// manager.
+    ExternallyManaged,
+    /// Any other Python installation.
+    System,
+}
+
+impl fmt::Display for EnvironmentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Project => f.write_str("project environment"),
+            Self::Ephemeral => f.write_str("ephemeral environment"),
+            Self::Virtual => f.write_str("virtual environment"),
+            Self::Managed => f.write_str("managed Python installation"),
+            Self::ExternallyManaged => f.write_str("externally managed Python installation"),
+            Self::System => f.write_str("system Python installation"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PrintData {
+    path: String,
+    source: Source,
+    interpreter: Option<String>,
+    environment: Option<EnvironmentKind>,
+    prefix: Option<String>,
+}
+
+/// Show the executable that `uv run` would invoke for a command, along with its interpreter.
+pub(crate) async fn which(
+    project_dir: &Path,
+    command: &str,
+    requirements: &[RequirementsSource],
+    no_project: bool,
+    path_policy: &PathPolicy,
+    output_format: PythonWhichFormat,
+    settings: &ResolverInstallerSettings,
+    network_settings: &NetworkSettings,
+    python_preference: PythonPreference,
+    python_install_dir: Option<&Path>,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let workspace_cache = WorkspaceCache::default();
+    let project = if no_project {
+        None
+    } else {
+        match VirtualProject::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+            .await
+        {
+            Ok(project) => Some(project),
+            Err(WorkspaceError::MissingProject(_)) => None,
+            Err(WorkspaceError::MissingPyprojectToml) => None,
+            Err(WorkspaceError::NonWorkspace(_)) => None,
+            Err(err) => {
+                warn_user_once!("{err}");
+                None
+            }
+        }
+    };
+
+    // Determine the base environment, as `uv run` would, without creating or syncing it.
+    let project_environment = project
+        .as_ref()
+        .map(|project| project.workspace().venv(Some(false)));
+    let base = if let Some(root) = &project_environment {
+        if !root.join("pyvenv.cfg").is_file() {
+            bail!(
+                "No project environment found at `{}`; run `{}` to create it",
+                root.user_display().cyan(),
+                "uv sync".green()
+            );
+        }
+        PythonEnvironment::from_root(root, cache)?.into_interpreter()
+    } else {
+        PythonEnvironment::find(
+            &PythonRequest::default(),
+            EnvironmentPreference::Any,
+            python_preference,
+            cache,
+            python_install_dir,
+            preview,
+        )?
+        .into_interpreter()
+    };
+
+    let overlay = if requirements.is_empty() {
+        // Within `uv run --with`, the active environment is an ephemeral environment that extends
+        // the base environment.
+        std::env::var_os(EnvVars::VIRTUAL_ENV)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+            .filter(|root| {
+                !uv_fs::is_same_file_allow_missing(root, base.sys_prefix()).unwrap_or(false)
+            })
+            .and_then(|root| PythonEnvironment::from_root(root, cache).ok())
+            .filter(|environment| {
+                environment
+                    .parent_environments(cache)
+                    .is_ok_and(|parents| parents.iter().any(|parent| parent.uses(&base)))
+            })
+    } else {
+        // Otherwise, layer the requirements atop the base environment, as `uv run --with` would,
+        // respecting the locked versions as preferences, as with `uv run --no-sync`.
+        let client_builder = BaseClientBuilder::new()
+            .retries_from_env()?
+            .connectivity(network_settings.connectivity)
+            .native_tls(network_settings.native_tls)
+            .allow_insecure_host(network_settings.allow_insecure_host.clone());
+        let spec =
+            RequirementsSpecification::from_simple_sources(requirements, &client_builder).await?;
+        let base_lock = if let Some(project) = &project {
+            LockTarget::from(project.workspace())
+                .read_compatible()
+                .await
+                .ok()
+                .flatten()
+                .map(|lock| (lock, project.workspace().install_path().to_owned()))
+        } else {
+            None
+        };
+
+        let result = requirements_environment(
+            spec,
+            &base,
+            &SitePackages::from_interpreter(&base)?,
+            base_lock.as_ref(),
+            false,
+            settings,
+            network_settings,
+            &PlatformState::default(),
+            false,
+            None,
+            installer_metadata,
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await;
+
+        match result {
+            Ok(environment) => environment,
+            Err(ProjectError::Operation(err)) => {
+                let failure = SetupFailure::from(&err);
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .with_context("`--with`")
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Setup(failure)), |err| Err(err.into()));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+
+    // Search the `PATH` that `uv run` would construct.
+    let search_path = EnvironmentComposer::new(&base, preview)
+        .with_requirements(overlay.as_ref())
+        .layered()
+        .path_entries(path_policy);
+
+    let (path, source) = find_executable(command, &search_path)?;
+
+    // Determine the interpreter the executable runs with, if it's a Python script.
+    let interpreter = script_interpreter(&path, &search_path);
+    let queried = interpreter
+        .as_deref()
+        .and_then(|interpreter| Interpreter::query(interpreter, cache).ok());
+    let environment = queried.as_ref().map(|queried| {
+        classify(
+            queried,
+            project_environment.as_deref(),
+            overlay.as_ref(),
+            python_install_dir,
+        )
+    });
+
+    if output_format == PythonWhichFormat::Json {
+        let data = PrintData {
+            path: path.simplified_display().to_string(),
+            source,
+            interpreter: interpreter
+                .as_ref()
+                .map(|interpreter| interpreter.simplified_display().to_string()),
+            environment,
+            prefix: queried
+                .as_ref()
+                .map(|queried| queried.sys_prefix().simplified_display().to_string()),
+        };
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&data)?)?;
+        return Ok(ExitStatus::Success);
+    }
+
+    writeln!(
+        printer.stdout(),
+        "path: {}",
+        path.simplified_display().cyan()
+    )?;
+    writeln!(printer.stdout(), "source: {source}")?;
+    match &interpreter {
+        Some(interpreter) => writeln!(
+            printer.stdout(),
+            "interpreter: {}",
+            interpreter.simplified_display()
+        )?,
+        None => writeln!(printer.stdout(), "interpreter: {}", "none".dimmed())?,
+    }
+    match (environment, &queried) {
+        (Some(environment), Some(queried)) => writeln!(
+            printer.stdout(),
+            "environment: {environment} (`{}`)",
+            queried.sys_prefix().simplified_display()
+        )?,
+        _ => writeln!(printer.stdout(), "environment: {}", "unknown".dimmed())?,
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Search the given `PATH` entries for an executable.
+fn search(name: &str, search_path: &[(PathEntry, PathBuf)]) -> Option<(PathBuf, Source)> {
+    search_path.iter().find_map(|(entry, dir)| {
+        let path = which::which_in_global(name, Some(dir))
+            .ok()
+            .and_then(|mut paths| paths.next())?;
+        let source = match entry {
+            PathEntry::Overlay => Source::Overlay,
+            PathEntry::Base => Source::Base,
+            PathEntry::Inherit => Source::Inherit,
+        };
+        Some((path, source))
+    })
+}
+
+/// Find the executable for a command, returning its path and where it was found.
+fn find_executable(
+    command: &str,
+    search_path: &[(PathEntry, PathBuf)],
+) -> Result<(PathBuf, Source)> {
+    // As with `uv run`, a command that includes a directory isn't searched for.
+    if Path::new(command).components().count() > 1 {
+        let path = std::path::absolute(command)?;
+        if !uv_fs::which::is_executable(&path) {
+            bail!("`{}` is not an executable", path.user_display().cyan());
+        }
+        return Ok((path, Source::Path));
+    }
+
+    let Some(found) = search(command, search_path) else {
+        bail!(
+            "Failed to find `{}` in the environment or on the `PATH`",
+            command.cyan()
+        )
+    };
+    Ok(found)
+}
+
+/// Return the Python interpreter that the executable at `path` runs with, if it's a Python
+/// script, as determined by its shebang or, on Windows, its launcher.
+fn script_interpreter(path: &Path, search_path: &[(PathEntry, PathBuf)]) -> Option<PathBuf> {
+    if let Ok(Some(launcher)) = Launcher::try_from_path(path) {
+        return Some(launcher.python_path);
+    }
+
+    // Only the first lines are needed, and the executable may be a large binary.
+    let mut contents = Vec::new();
+    fs_err::File::open(path)
+        .ok()?
+        .take(4096)
+        .read_to_end(&mut contents)
+        .ok()?;
+
+    match uv_install_wheel::parse_shebang(&String::from_utf8_lossy(&contents))? {
+        ShebangExecutable::Path(interpreter) => Some(interpreter),
+        ShebangExecutable::Relative(interpreter) => {
+            let script = fs_err::canonicalize(path).ok()?;
+            Some(script.parent()?.join(interpreter))
+        }
+        // For `/usr/bin/env python3`, the interpreter is resolved from the `PATH` that `uv run`
+        // would set.
+        ShebangExecutable::Env(name) => search(&name, search_path).map(|(path, _)| path),
+    }
+}
+
+/// Classify the environment that a Python interpreter belongs to.
+fn classify(
+    interpreter: &Interpreter,
+    project_environment: Option<&Path>,
+    overlay: Option<&PythonEnvironment>,
+    python_install_dir: Option<&Path>,
+) -> EnvironmentKind {
+    let prefix = interpreter.sys_prefix();
+    if project_environment
+        .is_some_and(|root| uv_fs::is_same_file_allow_missing(root, prefix).unwrap_or(false))
+    {
+        return EnvironmentKind::Project;
+    }
+    if overlay.is_some_and(|overlay| overlay.uses(interpreter)) {
+        return EnvironmentKind::Ephemeral;
+    }
+    if interpreter.is_virtualenv() {
+        return EnvironmentKind::Virtual;
+    }
+    if interpreter.is_managed(python_install_dir) {
+        return EnvironmentKind::Managed;
+    }
+    if interpreter.is_externally_managed().is_some() {
+        return EnvironmentKind::ExternallyManaged;
+    }
+    EnvironmentKind::System
+}
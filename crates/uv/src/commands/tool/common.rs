@@ -148,6 +148,10 @@ pub(crate) async fn refine_interpreter(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await?
@@ -22,12 +22,13 @@ use uv_python::{
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
-use uv_warnings::warn_user;
+use uv_types::HashStrategy;
 use uv_workspace::WorkspaceCache;
 
 use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::{self, Modifications};
+use crate::commands::project::warning_policy::{EnvironmentWarning, WarningPolicy};
 use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_environment, resolve_names,
     sync_environment, update_environment,
@@ -54,6 +55,7 @@ pub(crate) async fn install(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    warnings: WarningPolicy,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     network_settings: NetworkSettings,
@@ -88,6 +90,10 @@ pub(crate) async fn install(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await?
@@ -337,10 +343,13 @@ pub(crate) async fn install(
                 // If the tool is not installed properly, remove the environment and continue.
                 match installed_tools.remove_environment(package_name) {
                     Ok(()) => {
-                        warn_user!(
-                            "Removed existing `{}` with invalid receipt",
-                            package_name.cyan()
-                        );
+                        EnvironmentWarning::InvalidToolReceipt.emit(
+                            warnings,
+                            format_args!(
+                                "Removed existing `{}` with invalid receipt",
+                                package_name.cyan()
+                            ),
+                        )?;
                     }
                     Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {}
                     Err(err) => {
@@ -458,7 +467,7 @@ pub(crate) async fn install(
             Ok(update) => update.into_environment(),
             Err(ProjectError::Operation(err)) => {
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
             Err(err) => return Err(err.into()),
@@ -480,6 +489,7 @@ pub(crate) async fn install(
             spec.clone(),
             &interpreter,
             Constraints::from_requirements(build_constraints.iter().cloned()),
+            &HashStrategy::default(),
             &settings.resolver,
             &network_settings,
             &state,
@@ -520,7 +530,7 @@ pub(crate) async fn install(
                         return diagnostics::OperationDiagnostic::native_tls(
                             network_settings.native_tls,
                         )
-                        .report(err)
+                        .report(err, printer)
                         .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
                     };
 
@@ -534,6 +544,7 @@ pub(crate) async fn install(
                         spec,
                         &interpreter,
                         Constraints::from_requirements(build_constraints.iter().cloned()),
+                        &HashStrategy::default(),
                         &settings.resolver,
                         &network_settings,
                         &state,
@@ -550,7 +561,7 @@ pub(crate) async fn install(
                             return diagnostics::OperationDiagnostic::native_tls(
                                 network_settings.native_tls,
                             )
-                            .report(err)
+                            .report(err, printer)
                             .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
                         }
                         Err(err) => return Err(err.into()),
@@ -574,10 +585,12 @@ pub(crate) async fn install(
             &resolution.into(),
             Modifications::Exact,
             Constraints::from_requirements(build_constraints.iter().cloned()),
+            &HashStrategy::default(),
             (&settings).into(),
             &network_settings,
             &state,
             Box::new(DefaultInstallLogger),
+            None,
             installer_metadata,
             concurrency,
             &cache,
@@ -593,7 +606,7 @@ pub(crate) async fn install(
             Ok(environment) => environment,
             Err(ProjectError::Operation(err)) => {
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
             Err(err) => return Err(err.into()),
@@ -42,12 +42,13 @@ use uv_warnings::warn_user;
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
-use crate::child::run_to_completion;
+use crate::child::{SignalOptions, run_to_completion};
 use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations;
+use crate::commands::project::composer::EnvironmentComposer;
 use crate::commands::project::{
     EnvironmentSpecification, PlatformState, ProjectError, resolve_names,
 };
@@ -296,13 +297,13 @@ pub(crate) async fn run(
                         format!("uvx {rest}").green()
                     ))
                     .with_context("tool")
-                    .report(err)
+                    .report(err, printer)
                     .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
             }
 
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .with_context("tool")
-                .report(err)
+                .report(err, printer)
                 .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
         Err(ProjectError::Requirements(err)) => {
@@ -359,15 +360,10 @@ pub(crate) async fn run(
     process.args(args);
 
     // Construct the `PATH` environment variable.
-    let new_path = std::env::join_paths(
-        std::iter::once(environment.scripts().to_path_buf()).chain(
-            std::env::var_os(EnvVars::PATH)
-                .as_ref()
-                .iter()
-                .flat_map(std::env::split_paths),
-        ),
-    )
-    .context("Failed to build new PATH variable")?;
+    let new_path = EnvironmentComposer::new(environment.interpreter(), preview)
+        .compose(&cache)?
+        .path()
+        .context("Failed to build new PATH variable")?;
     process.env(EnvVars::PATH, new_path);
 
     // Spawn and wait for completion
@@ -397,7 +393,7 @@ pub(crate) async fn run(
     }
     .with_context(|| format!("Failed to spawn: `{executable}`"))?;
 
-    run_to_completion(handle).await
+    run_to_completion(handle, SignalOptions::default()).await
 }
 
 /// Return the entry points for the specified package.
@@ -748,6 +744,10 @@ async fn get_or_create_environment(
         install_mirrors.python_install_mirror.as_deref(),
         install_mirrors.pypy_install_mirror.as_deref(),
         install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+        install_mirrors.install_staging_dir.as_deref(),
+        &install_mirrors.download_client(),
+        install_mirrors.python_resolution.unwrap_or_default(),
         preview,
     )
     .await?
@@ -1018,6 +1018,8 @@ async fn get_or_create_environment(
     let result = CachedEnvironment::from_spec(
         spec.clone(),
         build_constraints.clone(),
+        false,
+        None,
         &interpreter,
         settings,
         network_settings,
@@ -1032,6 +1034,7 @@ async fn get_or_create_environment(
         } else {
             Box::new(SummaryInstallLogger)
         },
+        None,
         installer_metadata,
         concurrency,
         cache,
@@ -1077,6 +1080,8 @@ async fn get_or_create_environment(
                 CachedEnvironment::from_spec(
                     spec,
                     build_constraints,
+                    false,
+                    None,
                     &interpreter,
                     settings,
                     network_settings,
@@ -1091,6 +1096,7 @@ async fn get_or_create_environment(
                     } else {
                         Box::new(SummaryInstallLogger)
                     },
+                    None,
                     installer_metadata,
                     concurrency,
                     cache,
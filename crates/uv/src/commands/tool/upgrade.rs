@@ -19,6 +19,7 @@ use uv_python::{
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
+use uv_types::HashStrategy;
 use uv_warnings::write_error_chain;
 use uv_workspace::WorkspaceCache;
 
@@ -102,6 +103,10 @@ pub(crate) async fn upgrade(
                 install_mirrors.python_install_mirror.as_deref(),
                 install_mirrors.pypy_install_mirror.as_deref(),
                 install_mirrors.python_downloads_json_url.as_deref(),
+                install_mirrors.python_install_dir.as_deref(),
+                install_mirrors.install_staging_dir.as_deref(),
+                &install_mirrors.download_client(),
+                install_mirrors.python_resolution.unwrap_or_default(),
                 preview,
             )
             .await?
@@ -296,6 +301,7 @@ async fn upgrade_tool(
             spec.into(),
             interpreter,
             build_constraints.clone(),
+            &HashStrategy::default(),
             &settings.resolver,
             network_settings,
             &state,
@@ -314,10 +320,12 @@ async fn upgrade_tool(
             &resolution.into(),
             Modifications::Exact,
             build_constraints,
+            &HashStrategy::default(),
             (&settings).into(),
             network_settings,
             &state,
             Box::new(DefaultInstallLogger),
+            None,
             installer_metadata,
             concurrency,
             cache,
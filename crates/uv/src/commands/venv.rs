@@ -1,30 +1,37 @@
 use std::fmt::Write;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::vec;
 
 use anyhow::Result;
+use clap::ValueEnum;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use thiserror::Error;
+use tracing::debug;
 
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, Constraints, DependencyGroups, IndexStrategy, KeyringProviderType,
-    NoBinary, NoBuild, Preview, PreviewFeatures, SourceStrategy,
+    BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, EditableMode,
+    ExtrasSpecification, IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild,
+    Preview, PreviewFeatures, SourceStrategy, TargetTriple,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildRequires, Index, IndexLocations,
-    PackageConfigSettings, Requirement,
+    ConfigSettings, DependencyMetadata, ExtraBuildRequires, Index, IndexLocations, InstalledDist,
+    Name, PackageConfigSettings, Requirement,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_normalize::DefaultGroups;
+use uv_installer::SitePackages;
+use uv_normalize::{DefaultExtras, DefaultGroups};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, EnvironmentProvenance, PythonDownloads, PythonEnvironment,
+    PythonInstallation, PythonPreference, PythonRequest,
 };
-use uv_resolver::{ExcludeNewer, FlatIndex};
+use uv_resolver::{ExcludeNewer, FlatIndex, Lock, Package};
 use uv_settings::PythonInstallMirrors;
 use uv_shell::{Shell, shlex_posix, shlex_windows};
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
@@ -32,13 +39,21 @@ use uv_virtualenv::OnExisting;
 use uv_warnings::warn_user;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceCache, WorkspaceError};
 
-use crate::commands::ExitStatus;
 use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
-use crate::commands::pip::operations::{Changelog, report_interpreter};
-use crate::commands::project::{WorkspacePython, validate_project_requires_python};
+use crate::commands::pip::operations::{
+    Changelog, Modifications, check_read_only, report_interpreter,
+};
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::sync::do_sync;
+use crate::commands::project::{
+    PlatformState, ProjectError, WorkspacePython, validate_project_requires_python,
+    write_provenance,
+};
 use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
-use crate::settings::NetworkSettings;
+use crate::settings::{InstallerSettingsRef, NetworkSettings};
 
 use super::project::default_dependency_groups;
 
@@ -50,11 +65,35 @@ enum VenvError {
     #[error("Failed to install seed packages into virtual environment")]
     Seed(#[source] AnyErrorBuild),
 
+    #[error("Failed to reinstall packages into the rebased virtual environment")]
+    Rebase(#[source] AnyErrorBuild),
+
     #[error("Failed to extract interpreter tags for installing seed packages")]
     Tags(#[source] uv_platform_tags::TagsError),
 
     #[error("Failed to resolve `--find-links` entry")]
     FlatIndex(#[source] uv_client::FlatIndexError),
+
+    #[error("Failed to update the prompt of the virtual environment")]
+    Prompt(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to mark the virtual environment as resolution-only")]
+    Platform(#[source] uv_python::Error),
+
+    #[error("Failed to record the link mode of the virtual environment")]
+    LinkMode(#[source] uv_python::Error),
+
+    #[error("Failed to include the user site packages in the virtual environment")]
+    UserSite(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to adopt the virtual environment")]
+    Adopt(#[source] uv_virtualenv::Error),
+
+    #[error("Failed to update the lock of the virtual environment")]
+    ReadOnly(#[source] uv_python::Error),
+
+    #[error("Failed to update access to the system site packages of the virtual environment")]
+    SystemSitePackages(#[source] uv_python::Error),
 }
 
 /// Create a virtual environment.
@@ -67,6 +106,7 @@ pub(crate) async fn venv(
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     link_mode: LinkMode,
+    environment_link_mode: Option<LinkMode>,
     index_locations: &IndexLocations,
     index_strategy: IndexStrategy,
     dependency_metadata: DependencyMetadata,
@@ -74,15 +114,26 @@ pub(crate) async fn venv(
     network_settings: &NetworkSettings,
     prompt: uv_virtualenv::Prompt,
     system_site_packages: bool,
+    allow_user_site: bool,
     seed: bool,
     on_existing: OnExisting,
     exclude_newer: ExcludeNewer,
     concurrency: Concurrency,
     no_config: bool,
     no_project: bool,
+    show: bool,
+    rebase: bool,
+    sync_settings: InstallerSettingsRef<'_>,
+    installer_metadata: bool,
+    rename_prompt: Option<uv_virtualenv::Prompt>,
+    adopt: Option<PathBuf>,
+    lock_environment: Option<bool>,
+    unlock: bool,
+    set_system_site_packages: Option<bool>,
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    python_platform: Option<TargetTriple>,
     preview: Preview,
 ) -> Result<ExitStatus> {
     let workspace_cache = WorkspaceCache::default();
@@ -125,6 +176,44 @@ pub(crate) async fn venv(
             .unwrap_or(PathBuf::from(".venv")),
     );
 
+    if show {
+        return show_provenance(&path, printer);
+    }
+
+    if let Some(read_only) = lock_environment {
+        return lock_environment_in_place(&path, read_only, cache, printer);
+    }
+
+    // Every other operation recreates an existing environment or rewrites its `pyvenv.cfg`.
+    check_read_only(&path, unlock)?;
+
+    if let Some(prompt) = rename_prompt {
+        return rename_environment_prompt(&path, prompt, cache, printer);
+    }
+
+    if let Some(source) = adopt {
+        check_read_only(&source, unlock)?;
+        return adopt_environment(
+            &source,
+            &path,
+            install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors.python_install_dir.as_deref(),
+            cache,
+            printer,
+        );
+    }
+
+    if let Some(enabled) = set_system_site_packages {
+        return set_system_site_packages_in_place(&path, enabled, cache, printer);
+    }
+
+    if rebase && !path.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            path.user_display().cyan()
+        ));
+    }
+
     // TODO(zanieb): We don't use [`BaseClientBuilder::retries_from_env`] here because it's a pain
     // to map into a miette diagnostic. We should just remove miette diagnostics here, we're not
     // using them elsewhere.
@@ -168,6 +257,10 @@ pub(crate) async fn venv(
             install_mirrors.python_install_mirror.as_deref(),
             install_mirrors.pypy_install_mirror.as_deref(),
             install_mirrors.python_downloads_json_url.as_deref(),
+            install_mirrors.python_install_dir.as_deref(),
+            install_mirrors.install_staging_dir.as_deref(),
+            &install_mirrors.download_client(),
+            install_mirrors.python_resolution.unwrap_or_default(),
             preview,
         )
         .await?;
@@ -193,12 +286,48 @@ pub(crate) async fn venv(
         }
     }
 
-    writeln!(
-        printer.stderr(),
-        "Creating virtual environment {}at: {}",
-        if seed { "with seed packages " } else { "" },
-        path.user_display().cyan()
-    )?;
+    // When rebasing, determine the packages to reinstall and move the existing environment aside.
+    // The project environment is synced from the lockfile, if there is one, such that any drift
+    // in the existing environment isn't carried over.
+    let (rebased, lock, backup) = if rebase {
+        let lock = match &project {
+            Some(project)
+                if uv_fs::is_same_file_allow_missing(
+                    &path,
+                    &project.workspace().venv(Some(false)),
+                )
+                .unwrap_or(false) =>
+            {
+                LockTarget::from(project.workspace()).read().await?
+            }
+            _ => None,
+        };
+        let rebased = if let Some(lock) = &lock {
+            warn_unlocked_packages(&path, lock, cache)?;
+            vec![]
+        } else {
+            rebase_requirements(&path, cache)?
+        };
+        let backup = RebaseBackup::new(&path)?;
+        (rebased, lock, Some(backup))
+    } else {
+        (vec![], None, None)
+    };
+
+    if rebase {
+        writeln!(
+            printer.stderr(),
+            "Rebasing virtual environment at: {}",
+            path.user_display().cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Creating virtual environment {}at: {}",
+            if seed { "with seed packages " } else { "" },
+            path.user_display().cyan()
+        )?;
+    }
 
     let upgradeable = preview.is_enabled(PreviewFeatures::PYTHON_UPGRADE)
         && python_request
@@ -218,12 +347,45 @@ pub(crate) async fn venv(
         preview,
     )
     .map_err(VenvError::Creation)?;
+    write_provenance(
+        &venv,
+        install_mirrors.python_downloads_json_url.as_deref(),
+        install_mirrors.python_install_dir.as_deref(),
+    );
+
+    // Mark the environment as resolution-only for the target platform.
+    if let Some(python_platform) = python_platform {
+        let python_platform = python_platform
+            .to_possible_value()
+            .expect("target triples are not skipped");
+        venv.set_pyvenv_cfg("uv-python-platform", python_platform.get_name())
+            .map_err(VenvError::Platform)?;
+    }
+
+    // Record the link mode, such that subsequent installations into the environment use it.
+    if let Some(link_mode) = environment_link_mode {
+        venv.set_pyvenv_cfg("uv-link-mode", &link_mode.to_string())
+            .map_err(VenvError::LinkMode)?;
+    }
 
-    // Install seed packages.
-    if seed {
+    // Expose the user site packages directory, if requested.
+    if allow_user_site {
+        uv_virtualenv::enable_user_site(&venv).map_err(VenvError::UserSite)?;
+    }
+
+    // Install seed packages and, when rebasing, the packages from the previous environment.
+    if seed || !rebased.is_empty() {
         // Extract the interpreter.
         let interpreter = venv.interpreter();
 
+        // Seed packages are never built from source, but the packages from the previous
+        // environment may have been.
+        let build_options = if rebased.is_empty() {
+            BuildOptions::new(NoBinary::None, NoBuild::All)
+        } else {
+            BuildOptions::default()
+        };
+
         // Add all authenticated sources to the cache.
         index_locations.cache_index_credentials();
 
@@ -246,12 +408,7 @@ pub(crate) async fn venv(
                 .fetch_all(index_locations.flat_indexes().map(Index::url))
                 .await
                 .map_err(VenvError::FlatIndex)?;
-            FlatIndex::from_entries(
-                entries,
-                Some(tags),
-                &HashStrategy::None,
-                &BuildOptions::new(NoBinary::None, NoBuild::All),
-            )
+            FlatIndex::from_entries(entries, Some(tags), &HashStrategy::None, &build_options)
         };
 
         // Initialize any shared state.
@@ -265,8 +422,6 @@ pub(crate) async fn venv(
         let config_settings_package = PackageConfigSettings::default();
         let sources = SourceStrategy::Disabled;
 
-        // Do not allow builds
-        let build_options = BuildOptions::new(NoBinary::None, NoBuild::All);
         let extra_build_requires = ExtraBuildRequires::default();
         let extra_build_variables = uv_distribution_types::ExtraBuildVariables::default();
         // Prep the build context.
@@ -296,7 +451,9 @@ pub(crate) async fn venv(
         );
 
         // Resolve the seed packages.
-        let requirements = if interpreter.python_tuple() >= (3, 12) {
+        let mut requirements = if !seed {
+            vec![]
+        } else if interpreter.python_tuple() >= (3, 12) {
             vec![Requirement::from(
                 uv_pep508::Requirement::from_str("pip").unwrap(),
             )]
@@ -308,6 +465,7 @@ pub(crate) async fn venv(
                 Requirement::from(uv_pep508::Requirement::from_str("wheel").unwrap()),
             ]
         };
+        requirements.extend(rebased);
 
         let build_stack = BuildStack::default();
 
@@ -315,47 +473,505 @@ pub(crate) async fn venv(
         //
         // Since the virtual environment is empty, and the set of requirements is trivial (no
         // constraints, no editables, etc.), we can use the build dispatch APIs directly.
+        let install_error = |err: anyhow::Error| {
+            if rebase {
+                VenvError::Rebase(err.into())
+            } else {
+                VenvError::Seed(err.into())
+            }
+        };
         let resolution = build_dispatch
             .resolve(&requirements, &build_stack)
             .await
-            .map_err(|err| VenvError::Seed(err.into()))?;
+            .map_err(|err| install_error(err.into()))?;
         let installed = build_dispatch
             .install(&resolution, &venv, &build_stack)
             .await
-            .map_err(|err| VenvError::Seed(err.into()))?;
+            .map_err(|err| install_error(err.into()))?;
 
         let changelog = Changelog::from_installed(installed);
         DefaultInstallLogger.on_complete(&changelog, printer)?;
     }
 
+    // When rebasing the project environment, sync it from the lockfile.
+    if let (Some(lock), Some(project)) = (&lock, &project) {
+        let target = match project {
+            VirtualProject::Project(project) => InstallTarget::Project {
+                workspace: project.workspace(),
+                name: project.project_name(),
+                lock,
+            },
+            VirtualProject::NonProject(workspace) => {
+                InstallTarget::NonProjectWorkspace { workspace, lock }
+            }
+        };
+        let extras = ExtrasSpecification::default().with_defaults(DefaultExtras::default());
+
+        match do_sync(
+            target,
+            &venv,
+            &extras,
+            &groups,
+            EditableMode::Editable,
+            InstallOptions::default(),
+            Modifications::Sufficient,
+            None,
+            false,
+            None,
+            sync_settings,
+            network_settings,
+            &PlatformState::default(),
+            Box::new(DefaultInstallLogger),
+            None,
+            installer_metadata,
+            concurrency,
+            cache,
+            workspace_cache,
+            DryRun::Disabled,
+            printer,
+            preview,
+        )
+        .await
+        {
+            Ok(()) => {}
+            Err(ProjectError::Operation(err)) => {
+                return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                    .report(err, printer)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    // The environment was rebased successfully; discard the previous environment.
+    if let Some(backup) = backup {
+        backup.commit();
+    }
+
     // Determine the appropriate activation command.
-    let activation = match Shell::from_env() {
+    let activation = activation_command(venv.scripts());
+    if let Some(act) = activation {
+        writeln!(printer.stderr(), "Activate with: {}", act.green())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Return the command to activate the virtual environment with the given `scripts` directory in
+/// the current shell, if the shell can be determined.
+pub(crate) fn activation_command(scripts: &Path) -> Option<String> {
+    match Shell::from_env() {
         None => None,
-        Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => Some(format!(
-            "source {}",
-            shlex_posix(venv.scripts().join("activate"))
-        )),
+        Some(Shell::Bash | Shell::Zsh | Shell::Ksh) => {
+            Some(format!("source {}", shlex_posix(scripts.join("activate"))))
+        }
         Some(Shell::Fish) => Some(format!(
             "source {}",
-            shlex_posix(venv.scripts().join("activate.fish"))
+            shlex_posix(scripts.join("activate.fish"))
         )),
         Some(Shell::Nushell) => Some(format!(
             "overlay use {}",
-            shlex_posix(venv.scripts().join("activate.nu"))
+            shlex_posix(scripts.join("activate.nu"))
         )),
         Some(Shell::Csh) => Some(format!(
             "source {}",
-            shlex_posix(venv.scripts().join("activate.csh"))
-        )),
-        Some(Shell::Powershell) => Some(shlex_windows(
-            venv.scripts().join("activate"),
-            Shell::Powershell,
+            shlex_posix(scripts.join("activate.csh"))
         )),
-        Some(Shell::Cmd) => Some(shlex_windows(venv.scripts().join("activate"), Shell::Cmd)),
+        Some(Shell::Powershell) => Some(shlex_windows(scripts.join("activate"), Shell::Powershell)),
+        Some(Shell::Cmd) => Some(shlex_windows(scripts.join("activate"), Shell::Cmd)),
+    }
+}
+
+/// Collect the packages installed in the virtual environment at `path` as requirements, such that
+/// they can be reinstalled once the environment is rebased onto a new interpreter.
+///
+/// Editable installations are skipped with a warning, since they're typically managed by a
+/// project (and restored by `uv sync`).
+fn rebase_requirements(path: &Path, cache: &Cache) -> Result<Vec<Requirement>> {
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    let mut requirements = Vec::new();
+    for dist in site_packages.iter() {
+        let requirement = match dist {
+            InstalledDist::Url(dist) if dist.editable => {
+                warn_user!(
+                    "Skipping editable installation of `{}`; it will not be reinstalled into the rebased environment",
+                    dist.name
+                );
+                continue;
+            }
+            InstalledDist::LegacyEditable(dist) => {
+                warn_user!(
+                    "Skipping editable installation of `{}`; it will not be reinstalled into the rebased environment",
+                    dist.name
+                );
+                continue;
+            }
+            InstalledDist::Url(dist) => format!("{} @ {}", dist.name, dist.url.as_str()),
+            InstalledDist::Registry(..)
+            | InstalledDist::EggInfoFile(..)
+            | InstalledDist::EggInfoDirectory(..) => {
+                format!("{}=={}", dist.name(), dist.version())
+            }
+        };
+        requirements.push(Requirement::from(uv_pep508::Requirement::from_str(
+            &requirement,
+        )?));
+    }
+    Ok(requirements)
+}
+
+/// Warn about the packages installed in the virtual environment at `path` that aren't in the
+/// lockfile, since they won't be reinstalled when the environment is synced from the lockfile.
+fn warn_unlocked_packages(path: &Path, lock: &Lock, cache: &Cache) -> Result<()> {
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    let locked = lock
+        .packages()
+        .iter()
+        .map(Package::name)
+        .collect::<FxHashSet<_>>();
+    for dist in site_packages.iter() {
+        if !locked.contains(dist.name()) {
+            warn_user!(
+                "`{}` is not in the lockfile; it will not be reinstalled into the rebased environment",
+                dist.name()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// An existing virtual environment that was moved aside while it's rebased onto a new interpreter.
+///
+/// Unless the rebase is committed, the existing environment is restored to its original path when
+/// dropped, e.g., if the new environment could not be created.
+struct RebaseBackup {
+    path: PathBuf,
+    backup: Option<tempfile::TempDir>,
+}
+
+impl RebaseBackup {
+    /// Move the virtual environment at `path` into a temporary sibling directory.
+    fn new(path: &Path) -> io::Result<Self> {
+        let parent = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        let backup = tempfile::Builder::new()
+            .prefix(".uv-rebase-")
+            .tempdir_in(parent)?;
+        fs_err::rename(path, backup.path().join("venv"))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            backup: Some(backup),
+        })
+    }
+
+    /// Discard the previous environment.
+    fn commit(mut self) {
+        self.backup.take();
+    }
+}
+
+impl Drop for RebaseBackup {
+    fn drop(&mut self) {
+        let Some(backup) = self.backup.take() else {
+            return;
+        };
+        match fs_err::remove_dir_all(&self.path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => debug!("Failed to remove partially rebased environment: {err}"),
+        }
+        if let Err(err) = fs_err::rename(backup.path().join("venv"), &self.path) {
+            let backup = backup.keep();
+            warn_user!(
+                "Failed to restore the previous virtual environment to `{}` ({err}); it was preserved at: `{}`",
+                self.path.user_display(),
+                backup.join("venv").user_display()
+            );
+        }
+    }
+}
+
+/// Update the prompt of the virtual environment at the given path, for `uv venv --rename-prompt`.
+fn rename_environment_prompt(
+    path: &Path,
+    prompt: uv_virtualenv::Prompt,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            path.user_display().cyan()
+        ));
+    }
+
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    uv_virtualenv::set_prompt(&environment, prompt).map_err(VenvError::Prompt)?;
+
+    let prompt = environment.cfg()?.prompt().map(ToString::to_string);
+    match prompt {
+        Some(prompt) => writeln!(
+            printer.stderr(),
+            "Updated prompt of virtual environment at `{}` to `{}`",
+            path.user_display().cyan(),
+            prompt.green()
+        )?,
+        None => writeln!(
+            printer.stderr(),
+            "Removed prompt of virtual environment at `{}`",
+            path.user_display().cyan()
+        )?,
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Lock or unlock the virtual environment at `path` against modification, for
+/// `uv venv --lock-environment` and `uv venv --unlock-environment`.
+fn lock_environment_in_place(
+    path: &Path,
+    read_only: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            path.user_display().cyan()
+        ));
+    }
+
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    environment
+        .set_pyvenv_cfg("uv-read-only", if read_only { "true" } else { "false" })
+        .map_err(VenvError::ReadOnly)?;
+
+    if read_only {
+        writeln!(
+            printer.stderr(),
+            "Locked virtual environment at `{}` against modification",
+            path.user_display().cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Unlocked virtual environment at `{}`",
+            path.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Update whether the virtual environment at `path` has access to the system site packages, for
+/// `uv venv --set-system-site-packages`.
+fn set_system_site_packages_in_place(
+    path: &Path,
+    enabled: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            path.user_display().cyan()
+        ));
+    }
+
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    let previous = environment.cfg()?.include_system_site_packages();
+    environment
+        .set_pyvenv_cfg(
+            "include-system-site-packages",
+            if enabled { "true" } else { "false" },
+        )
+        .map_err(VenvError::SystemSitePackages)?;
+
+    // Check that the interpreter picks up the change, e.g., rather than ignoring `pyvenv.cfg`.
+    match includes_system_site_packages(&environment) {
+        Some(included) if included != enabled => {
+            environment
+                .set_pyvenv_cfg(
+                    "include-system-site-packages",
+                    if previous { "true" } else { "false" },
+                )
+                .map_err(VenvError::SystemSitePackages)?;
+            return Err(anyhow::anyhow!(
+                "The interpreter of the virtual environment at `{}` {} the system site packages after updating `{}`; the previous value was restored",
+                path.user_display().cyan(),
+                if included { "includes" } else { "excludes" },
+                "pyvenv.cfg".cyan()
+            ));
+        }
+        Some(_) => {}
+        None => debug!(
+            "Unable to verify access to the system site packages in `{}`",
+            path.user_display()
+        ),
+    }
+
+    if enabled {
+        writeln!(
+            printer.stderr(),
+            "Enabled access to the system site packages in virtual environment at `{}`",
+            path.user_display().cyan()
+        )?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Disabled access to the system site packages in virtual environment at `{}`",
+            path.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the system site packages directories are on the `sys.path` of the
+/// environment's interpreter, or `None` if it can't be determined, e.g., if the base interpreter
+/// doesn't have a site packages directory.
+fn includes_system_site_packages(environment: &PythonEnvironment) -> Option<bool> {
+    let status = std::process::Command::new(environment.python_executable())
+        .arg("-c")
+        .arg(indoc::indoc! {r"
+            import os, site, sys
+            def normalize(path):
+                return os.path.normcase(os.path.abspath(path))
+            prefixes = list({sys.base_prefix, sys.base_exec_prefix})
+            system = [
+                normalize(path)
+                for path in site.getsitepackages(prefixes)
+                if os.path.isdir(path)
+            ]
+            if sys.prefix == sys.base_prefix or not system:
+                sys.exit(2)
+            path = {normalize(path) for path in sys.path}
+            sys.exit(0 if any(entry in path for entry in system) else 1)
+        "})
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .inspect_err(|err| debug!("Failed to query `sys.path`: {err}"))
+        .ok()?;
+    match status.code() {
+        Some(0) => Some(true),
+        Some(1) => Some(false),
+        _ => None,
+    }
+}
+
+/// Adopt the virtual environment at `source` as the virtual environment at `path`, for
+/// `uv venv --adopt`.
+fn adopt_environment(
+    source: &Path,
+    path: &Path,
+    python_downloads_json_url: Option<&str>,
+    python_install_dir: Option<&Path>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !source.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            source.user_display().cyan()
+        ));
+    }
+
+    // Entry points refer to the interpreter by its absolute path, as the environment was created.
+    let source = std::path::absolute(source)?;
+
+    // Move the environment into place, unless it's already there.
+    let previous = if path.exists() {
+        if fs_err::canonicalize(&source)? != fs_err::canonicalize(path)? {
+            return Err(anyhow::anyhow!(
+                "Cannot adopt the virtual environment at `{}`, as a file or directory already exists at `{}`",
+                source.user_display().cyan(),
+                path.user_display().cyan()
+            ));
+        }
+        None
+    } else {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs_err::create_dir_all(parent)?;
+        }
+        match fs_err::rename(&source, path) {
+            Ok(()) => {}
+            // Copying the environment would resolve its symlinks, e.g., to the base interpreter.
+            Err(err) if err.kind() == io::ErrorKind::CrossesDevices => {
+                return Err(anyhow::anyhow!(
+                    "Cannot move the virtual environment at `{}` to `{}`, as they're on different filesystems",
+                    source.user_display().cyan(),
+                    path.user_display().cyan()
+                ));
+            }
+            Err(err) => return Err(err.into()),
+        }
+        Some(source)
     };
-    if let Some(act) = activation {
-        writeln!(printer.stderr(), "Activate with: {}", act.green())?;
+
+    let environment = PythonEnvironment::from_root(path, cache)?;
+    uv_virtualenv::adopt_venv(&environment, previous.as_deref()).map_err(VenvError::Adopt)?;
+    write_provenance(&environment, python_downloads_json_url, python_install_dir);
+
+    writeln!(
+        printer.stderr(),
+        "Adopted virtual environment at: {}",
+        path.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Show the provenance of the base interpreter of the virtual environment at `path`.
+fn show_provenance(path: &Path, printer: Printer) -> Result<ExitStatus> {
+    if !path.join("pyvenv.cfg").is_file() {
+        return Err(anyhow::anyhow!(
+            "No virtual environment found at `{}`",
+            path.user_display().cyan()
+        ));
     }
 
+    let Some(provenance) = EnvironmentProvenance::read(path)? else {
+        return Err(anyhow::anyhow!(
+            "No provenance is recorded for the virtual environment at `{}`; it may have been created by another tool or an older version of uv",
+            path.user_display().cyan()
+        ));
+    };
+
+    let interpreter = &provenance.interpreter;
+    writeln!(
+        printer.stdout(),
+        "Interpreter: {} ({})",
+        interpreter.key.cyan(),
+        interpreter.source
+    )?;
+    writeln!(
+        printer.stdout(),
+        "Executable: {}",
+        interpreter.executable.simplified_display()
+    )?;
+    if let Some(url) = &interpreter.url {
+        writeln!(printer.stdout(), "URL: {url}")?;
+    }
+    if let Some(sha256) = &interpreter.sha256 {
+        writeln!(printer.stdout(), "SHA256: {sha256}")?;
+    }
+    writeln!(
+        printer.stdout(),
+        "Created: {} by uv {}",
+        provenance.created,
+        provenance.uv_version
+    )?;
+
     Ok(ExitStatus::Success)
 }
@@ -12,7 +12,8 @@ use std::sync::atomic::Ordering;
 use anstream::eprintln;
 use anyhow::{Context, Result, bail};
 use clap::error::{ContextKind, ContextValue};
-use clap::{CommandFactory, Parser};
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches};
 use futures::FutureExt;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
@@ -24,11 +25,12 @@ use uv_cache_info::Timestamp;
 #[cfg(feature = "self-update")]
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
-    BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands, PipCommand, PipNamespace,
-    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
-    ToolNamespace, TopLevelArgs, compat::CompatArgs,
+    BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands, ConfigCommand,
+    ConfigNamespace, DiagnosticsFormat, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
+    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
+    compat::CompatArgs,
 };
-use uv_configuration::min_stack_size;
+use uv_configuration::{RequiredVersion, min_stack_size};
 use uv_fs::{CWD, Simplified};
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
@@ -37,30 +39,39 @@ use uv_pypi_types::{ParsedDirectoryUrl, ParsedUrl};
 use uv_python::PythonRequest;
 use uv_requirements::{GroupsSpecification, RequirementsSource};
 use uv_requirements_txt::RequirementsTxtRequirement;
-use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script};
+use uv_scripts::{Pep723Error, Pep723Item, Pep723Metadata, Pep723Script, ScriptPythonHint};
 use uv_settings::{Combine, EnvironmentOptions, FilesystemOptions, Options};
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
 
-use crate::commands::{ExitStatus, RunCommand, ScriptPath, ToolRunCommand};
-use crate::printer::Printer;
+use crate::commands::{
+    ExitStatus, RunCommand, RunHistory, RunInvocation, ScriptPath, ToolRunCommand, WarningPolicy,
+    WorkspaceFiles,
+};
+use crate::printer::{Printer, Verbosity};
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, GlobalSettings, InterpreterLayers, PipCheckSettings, PipCompileSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PublishSettings,
 };
 
 pub(crate) mod child;
 pub(crate) mod commands;
 pub(crate) mod logging;
 pub(crate) mod printer;
+pub(crate) mod sandbox;
 pub(crate) mod settings;
 #[cfg(windows)]
 mod windows_exception;
 
+/// Run the command.
+///
+/// With `--diagnostics json`, the files of the workspace discovered while loading configuration
+/// are stored in `workspace_files`, such that an error can be reported against the declarations
+/// that caused it.
 #[instrument(skip_all)]
-async fn run(mut cli: Cli) -> Result<ExitStatus> {
+async fn run(mut cli: Cli, workspace_files: &mut WorkspaceFiles) -> Result<ExitStatus> {
     // Enable flag to pick up warnings generated by workspace loading.
     if cli.top_level.global_args.quiet == 0 {
         uv_warnings::enable();
@@ -147,6 +158,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     } else if let Ok(workspace) =
         Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache).await
     {
+        if cli.top_level.global_args.diagnostics == Some(DiagnosticsFormat::Json) {
+            *workspace_files = WorkspaceFiles::from_workspace(&workspace, &project_dir);
+        }
         let project = FilesystemOptions::find(workspace.install_path())?;
         let system = FilesystemOptions::system()?;
         let user = FilesystemOptions::user()?;
@@ -158,6 +172,24 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         project.combine(user).combine(system)
     };
 
+    // With `uv run --last`, replay the most recent invocation in the project.
+    if let Commands::Project(command) = &mut *cli.command {
+        if let ProjectCommand::Run(args) = &mut **command {
+            if args.last {
+                if args.command.is_some() {
+                    bail!("`--last` cannot be used with a command");
+                }
+                let Some(invocation) = RunHistory::discover(&project_dir, &workspace_cache)
+                    .await?
+                    .last()?
+                else {
+                    bail!("No previous `uv run` invocation was found in the project");
+                };
+                invocation.apply(args);
+            }
+        }
+    }
+
     // Parse the external command, if necessary.
     let run_command = if let Commands::Project(command) = &mut *cli.command {
         if let ProjectCommand::Run(uv_cli::RunArgs {
@@ -165,6 +197,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             module,
             script,
             gui_script,
+            locked,
+            frozen,
             ..
         }) = &mut **command
         {
@@ -176,6 +210,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     *module,
                     *script,
                     *gui_script,
+                    *locked || *frozen,
                 )
                 .await?,
             )
@@ -198,7 +233,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     Err(Pep723Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => None,
                     Err(err) => return Err(err.into()),
                 },
-                Some(RunCommand::PythonRemote(url, script, _)) => {
+                Some(RunCommand::PythonRemote(url, script, ..)) => {
                     match Pep723Metadata::read(&script).await {
                         Ok(Some(metadata)) => Some(Pep723Item::Remote(metadata, url.clone())),
                         Ok(None) => None,
@@ -293,14 +328,22 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         None
     };
 
+    // Capture the required version declared by the workspace (or user configuration) before the
+    // script metadata is merged in, such that a script can't relax the project's requirement.
+    let workspace_required_version = filesystem
+        .as_ref()
+        .and_then(|filesystem| filesystem.globals.required_version.clone());
+
     // If the target is a PEP 723 script, merge the metadata into the filesystem metadata.
     let filesystem = script
         .as_ref()
-        .map(Pep723Item::metadata)
-        .and_then(|metadata| metadata.tool.as_ref())
-        .and_then(|tool| tool.uv.as_ref())
-        .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
-        .map(FilesystemOptions::from)
+        .and_then(|script| {
+            let uv = script.metadata().tool.as_ref()?.uv.as_ref()?;
+            Some(FilesystemOptions::from_script(
+                script.path(),
+                Options::simple(uv.globals.clone(), uv.top_level.clone()),
+            ))
+        })
         .combine(filesystem);
 
     // Load environment variables not handled by Clap
@@ -312,42 +355,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Resolve the cache settings.
     let cache_settings = CacheSettings::resolve(*cli.top_level.cache_args, filesystem.as_ref());
 
-    // Enforce the required version.
+    // Enforce the required version, from both the script metadata and the workspace.
     if let Some(required_version) = globals.required_version.as_ref() {
-        let package_version = uv_pep440::Version::from_str(uv_version::version())?;
-        if !required_version.contains(&package_version) {
-            #[cfg(feature = "self-update")]
-            let hint = {
-                // If the required version range includes a lower bound that's higher than
-                // the current version, suggest `uv self update`.
-                let ranges = release_specifiers_to_ranges(required_version.specifiers().clone());
-
-                if let Some(singleton) = ranges.as_singleton() {
-                    // E.g., `==1.0.0`
-                    format!(
-                        ". Update `uv` by running `{}`.",
-                        format!("uv self update {singleton}").green()
-                    )
-                } else if ranges
-                    .bounding_range()
-                    .iter()
-                    .any(|(lowest, _highest)| match lowest {
-                        Bound::Included(version) => **version > package_version,
-                        Bound::Excluded(version) => **version > package_version,
-                        Bound::Unbounded => false,
-                    })
-                {
-                    // E.g., `>=1.0.0`
-                    format!(". Update `uv` by running `{}`.", "uv self update".cyan())
-                } else {
-                    String::new()
-                }
-            };
-            #[cfg(not(feature = "self-update"))]
-            let hint = "";
-            return Err(anyhow::anyhow!(
-                "Required uv version `{required_version}` does not match the running version `{package_version}`{hint}",
-            ));
+        let source = script.as_ref().and_then(|script| {
+            script
+                .metadata()
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.globals.required_version.as_ref())
+                .map(|_| match script.path() {
+                    Some(path) => format!("`{}`", path.user_display()),
+                    None => "the script metadata".to_string(),
+                })
+        });
+        enforce_required_version(required_version, source.as_deref())?;
+    }
+    if let Some(required_version) = workspace_required_version.as_ref() {
+        if globals.required_version.as_ref() != Some(required_version) {
+            enforce_required_version(required_version, None)?;
         }
     }
 
@@ -368,17 +394,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     )?;
 
     // Configure the `Printer`, which controls user-facing output in the CLI.
-    let printer = if globals.quiet == 1 {
-        Printer::Quiet
+    let verbosity = if globals.quiet == 1 {
+        Verbosity::Quiet
     } else if globals.quiet > 1 {
-        Printer::Silent
+        Verbosity::Silent
     } else if globals.verbose > 0 {
-        Printer::Verbose
+        Verbosity::Verbose
     } else if globals.no_progress {
-        Printer::NoProgress
+        Verbosity::NoProgress
     } else {
-        Printer::Default
+        Verbosity::Default
     };
+    let printer = Printer::new(verbosity, globals.diagnostics);
 
     // Configure the `warn!` macros, which control user-facing warnings in the CLI.
     if globals.quiet > 0 {
@@ -426,8 +453,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(globals, false);
     show_settings!(cache_settings, false);
 
-    // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    // Configure the cache, along with the settings that affect how Python interpreters are
+    // discovered and queried, e.g., the filter that skips interpreters before they're queried,
+    // the environment variables for Python executables that can't start without them, and the
+    // limits on the query subprocesses (where the environment takes precedence).
+    let interpreter_settings =
+        InterpreterLayers::resolve(&environment, filesystem.as_ref()).settings();
+    // Validate the filter eagerly, rather than on the first discovery.
+    uv_python::PythonDiscoveryFilter::from_settings(&interpreter_settings)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_interpreter_settings(interpreter_settings);
 
     match *cli.command {
         Commands::Help(args) => commands::help(
@@ -520,13 +555,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.wsl,
                 args.settings.universal,
                 args.settings.exclude_newer,
                 args.settings.sources,
                 args.settings.annotation_style,
-                args.settings.link_mode,
+                args.settings.link_mode.unwrap_or_default(),
                 args.settings.python,
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 globals.python_preference,
                 globals.concurrency,
                 globals.quiet > 0,
@@ -602,6 +639,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 args.settings.python,
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
@@ -610,6 +648,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.unlock,
                 printer,
                 globals.preview,
             )
@@ -747,6 +786,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.sources,
                 args.settings.python,
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
@@ -754,6 +794,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.unlock,
                 printer,
                 globals.preview,
             )
@@ -783,6 +824,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &sources,
                 args.settings.python,
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 args.settings.break_system_packages,
                 args.settings.target,
                 args.settings.prefix,
@@ -790,6 +832,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 args.dry_run,
+                args.unlock,
                 printer,
                 globals.preview,
             )
@@ -810,6 +853,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 args.paths,
                 &cache,
                 printer,
@@ -833,6 +877,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &args.exclude,
                 &args.format,
                 args.outdated,
+                args.include_parent,
                 args.settings.prerelease,
                 args.settings.index_locations,
                 args.settings.index_strategy,
@@ -843,6 +888,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 &cache,
                 printer,
                 globals.preview,
@@ -864,6 +910,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.strict,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 args.files,
                 &cache,
                 printer,
@@ -897,6 +944,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 args.settings.python.as_deref(),
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 &cache,
                 printer,
                 globals.preview,
@@ -916,6 +964,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::pip_check(
                 args.settings.python.as_deref(),
                 args.settings.system,
+                args.settings.install_mirrors.python_install_dir.as_deref(),
                 &cache,
                 printer,
                 globals.preview,
@@ -932,7 +981,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(args.ci, args.ephemeral, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -940,6 +989,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Config(ConfigNamespace {
+            command: ConfigCommand::Where(args),
+        }) => commands::config_where(
+            args.key,
+            &cli.top_level.global_args,
+            &environment,
+            filesystem.as_ref(),
+            printer,
+        ),
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -1020,8 +1078,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 }
             });
 
-            let python_request: Option<PythonRequest> =
-                args.settings.python.as_deref().map(PythonRequest::parse);
+            // When rebasing, the requested interpreter takes the place of `--python`.
+            let python_request: Option<PythonRequest> = args
+                .rebase
+                .as_deref()
+                .or(args.settings.python.as_deref())
+                .map(PythonRequest::parse);
 
             let on_existing = uv_virtualenv::OnExisting::from_args(args.allow_existing, args.clear);
 
@@ -1029,26 +1091,39 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &project_dir,
                 args.path,
                 python_request,
-                args.settings.install_mirrors,
+                args.settings.install_mirrors.clone(),
                 globals.python_preference,
                 globals.python_downloads,
-                args.settings.link_mode,
+                args.settings.link_mode.unwrap_or_default(),
+                args.environment_link_mode,
                 &args.settings.index_locations,
                 args.settings.index_strategy,
-                args.settings.dependency_metadata,
+                args.settings.dependency_metadata.clone(),
                 args.settings.keyring_provider,
                 &globals.network_settings,
                 uv_virtualenv::Prompt::from_args(prompt),
                 args.system_site_packages,
+                args.allow_user_site,
                 args.seed,
                 on_existing,
-                args.settings.exclude_newer,
+                args.settings.exclude_newer.clone(),
                 globals.concurrency,
                 cli.top_level.no_config,
                 args.no_project,
+                args.show,
+                args.rebase.is_some(),
+                (&args.settings).into(),
+                globals.installer_metadata,
+                args.rename_prompt
+                    .map(|prompt| uv_virtualenv::Prompt::from_args(Some(prompt))),
+                args.adopt,
+                args.lock_environment,
+                args.unlock,
+                args.set_system_site_packages,
                 &cache,
                 printer,
                 args.relocatable,
+                args.settings.python_platform,
                 globals.preview,
             )
             .await
@@ -1286,6 +1361,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python,
                 args.install_mirrors,
                 args.force,
+                WarningPolicy {
+                    fail_fast: args.fail_fast_env,
+                    ..WarningPolicy::default()
+                },
                 args.options,
                 args.settings,
                 globals.network_settings,
@@ -1378,6 +1457,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonListSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            if args.outdated {
+                return commands::python_list_outdated(
+                    args.request,
+                    args.output_format,
+                    args.exit_code,
+                    args.python_downloads_json_url,
+                    args.python_install_dir.as_deref(),
+                    printer,
+                );
+            }
+
             // Initialize the cache.
             let cache = cache.init()?;
 
@@ -1390,6 +1480,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.show_urls,
                 args.output_format,
                 args.python_downloads_json_url,
+                args.python_install_dir.as_deref(),
                 globals.python_preference,
                 globals.python_downloads,
                 &cache,
@@ -1404,12 +1495,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::PythonInstallSettings::resolve(args, filesystem, environment);
             show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             // TODO(john): If we later want to support `--upgrade`, we need to replace this.
             let upgrade = false;
 
             commands::python_install(
                 &project_dir,
                 args.install_dir,
+                args.install_staging_dir,
                 args.targets,
                 args.reinstall,
                 upgrade,
@@ -1419,10 +1515,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.python_downloads_json_url,
+                args.download_client,
                 globals.network_settings,
                 args.default,
+                args.channel,
+                args.components,
+                args.hook,
+                args.only_download,
+                args.from_cache,
+                args.atomic,
+                args.download_rate_limit,
                 globals.python_downloads,
+                globals.concurrency,
                 cli.top_level.no_config,
+                &cache,
                 globals.preview,
                 printer,
             )
@@ -1434,11 +1540,40 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
             show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            if args.minor {
+                return commands::python_upgrade_minor(
+                    &project_dir,
+                    args.install_dir,
+                    args.install_staging_dir,
+                    args.targets,
+                    args.apply,
+                    args.python_install_mirror,
+                    args.pypy_install_mirror,
+                    args.python_downloads_json_url,
+                    args.download_client,
+                    globals.network_settings,
+                    args.channel,
+                    args.hook,
+                    globals.python_downloads,
+                    globals.concurrency,
+                    cli.top_level.no_config,
+                    &cache,
+                    globals.preview,
+                    printer,
+                )
+                .await;
+            }
+
             let upgrade = true;
 
             commands::python_install(
                 &project_dir,
                 args.install_dir,
+                args.install_staging_dir,
                 args.targets,
                 args.reinstall,
                 upgrade,
@@ -1448,10 +1583,20 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python_install_mirror,
                 args.pypy_install_mirror,
                 args.python_downloads_json_url,
+                args.download_client,
                 globals.network_settings,
                 args.default,
+                args.channel,
+                None,
+                args.hook,
+                false,
+                false,
+                false,
+                None,
                 globals.python_downloads,
+                globals.concurrency,
                 cli.top_level.no_config,
+                &cache,
                 globals.preview,
                 printer,
             )
@@ -1468,11 +1613,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.install_dir,
                 args.targets,
                 args.all,
+                args.force,
                 printer,
                 globals.preview,
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::PinInstall(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonPinInstallSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_pin_install(args.install_dir, args.targets, args.remove, printer).await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
         }) => {
@@ -1482,13 +1637,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // Initialize the cache.
             let cache = cache.init()?;
 
-            if let Some(Pep723Item::Script(script)) = script {
+            if args.show_failures {
+                commands::python_find_failures(&cache, printer)
+            } else if let Some(Pep723Item::Script(script)) = script {
                 commands::python_find_script(
                     (&script).into(),
+                    &project_dir,
                     args.show_version,
+                    args.base,
                     &globals.network_settings,
                     globals.python_preference,
                     globals.python_downloads,
+                    args.python_install_dir.as_deref(),
                     cli.top_level.no_config,
                     &cache,
                     printer,
@@ -1500,10 +1660,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     &project_dir,
                     args.request,
                     args.show_version,
+                    args.base,
+                    args.all,
+                    args.output_format,
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
                     globals.python_preference,
+                    args.python_install_dir.as_deref(),
                     &cache,
                     printer,
                     globals.preview,
@@ -1511,6 +1675,121 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .await
             }
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Inspect(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonInspectSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_inspect(
+                args.request,
+                args.system,
+                args.output_format,
+                globals.python_preference,
+                args.python_install_dir.as_deref(),
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Markers(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonMarkersSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_markers(
+                args.python.as_deref(),
+                args.python_version.as_ref(),
+                args.python_platform.as_ref(),
+                args.implementation,
+                args.system,
+                args.output_format,
+                globals.python_preference,
+                args.python_install_dir.as_deref(),
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Which(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonWhichSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            let requirements = args
+                .with
+                .iter()
+                .map(|package| RequirementsSource::from_with_package_argument(package))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            commands::python_which(
+                &project_dir,
+                &args.command,
+                &requirements,
+                args.no_project,
+                &args.path_policy,
+                args.output_format,
+                &args.settings,
+                &globals.network_settings,
+                globals.python_preference,
+                args.python_install_dir.as_deref(),
+                globals.installer_metadata,
+                globals.concurrency,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Resolve(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonResolveSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_resolve(
+                args.request,
+                args.platform,
+                args.output_format,
+                args.install_mirrors,
+                globals.network_settings,
+                printer,
+            )
+            .await
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::ValidateDownloads(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonValidateDownloadsSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_validate_downloads(
+                args.targets,
+                args.full,
+                args.output_format,
+                args.install_mirrors,
+                globals.network_settings,
+                globals.concurrency,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Pin(args),
         }) => {
@@ -1544,9 +1823,43 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonDirSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            commands::python_dir(args.bin)?;
+            commands::python_dir(args.bin, args.python_install_dir.as_deref())?;
             Ok(ExitStatus::Success)
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Du(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonDuSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_du(
+                args.targets,
+                args.output_format,
+                args.python_install_dir.as_deref(),
+                printer,
+            )
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::EnvVars(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonEnvVarsSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_env_vars(
+                args.request,
+                args.output_format,
+                globals.python_preference,
+                args.python_install_dir.as_deref(),
+                &cache,
+                printer,
+                globals.preview,
+            )
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::UpdateShell,
         }) => {
@@ -1729,10 +2042,38 @@ async fn run_project(
             .await
         }
         ProjectCommand::Run(args) => {
+            if args.history {
+                return commands::run_history(project_dir, &WorkspaceCache::default(), printer)
+                    .await;
+            }
+
+            // Record the invocation in the project's history.
+            let invocation = RunInvocation::from_args(&args);
+            if args.last {
+                if let Some(invocation) = invocation.as_ref() {
+                    writeln!(
+                        printer.stderr(),
+                        "Re-running `{}`",
+                        format!("uv run {invocation}").cyan()
+                    )?;
+                }
+            }
+
+            let explicit_script = args.script;
+            let kernel = commands::Kernel::from_args(&args, std::env::args_os())?;
+
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::RunSettings::resolve(args, filesystem);
             show_settings!(args);
 
+            // Unless the history is disabled, in which case the invocation isn't recorded.
+            let invocation = invocation.filter(|_| args.history);
+
+            let warnings = WarningPolicy {
+                fail_fast: args.fail_fast_env,
+                strict_python: args.strict_python,
+            };
+
             // Initialize the cache.
             let cache = cache.init()?.with_refresh(
                 args.refresh
@@ -1740,6 +2081,40 @@ async fn run_project(
                     .combine(Refresh::from(args.settings.resolver.upgrade.clone())),
             );
 
+            // Launch the requested REPL, which is provided by the `--with` requirements.
+            let command = if let Some(repl) = args.repl {
+                if command
+                    .as_ref()
+                    .is_some_and(|command| !matches!(command, RunCommand::Empty))
+                {
+                    bail!("`--{repl}` cannot be used with a command");
+                }
+                Some(repl.command())
+            } else if kernel.is_some() {
+                if command
+                    .as_ref()
+                    .is_some_and(|command| !matches!(command, RunCommand::Empty))
+                {
+                    bail!("`--kernel` cannot be used with a command");
+                }
+                None
+            } else {
+                command
+            };
+
+            // If a plain script is run with `--script`, respect any interpreter hint it contains
+            // (e.g., `# uv: python=3.11`).
+            let script_python = match command.as_ref() {
+                Some(RunCommand::PythonScript(path, _)) if explicit_script && script.is_none() => {
+                    match ScriptPythonHint::read(path).await {
+                        Ok(hint) => hint.map(|hint| hint.as_str().to_string()),
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+                        Err(err) => return Err(err.into()),
+                    }
+                }
+                _ => None,
+            };
+
             let mut requirements = Vec::with_capacity(
                 args.with.len() + args.with_editable.len() + args.with_requirements.len(),
             );
@@ -1756,26 +2131,104 @@ async fn run_project(
                     .collect::<Result<Vec<_>, _>>()?,
             );
 
+            let options = settings::RunOptions {
+                show_resolution: args.options.show_resolution || globals.verbose > 0,
+                ..args.options
+            };
+
+            // If multiple Python versions were requested, run the command once for each version,
+            // in an isolated environment.
+            if let Some(pythons) = args.python.as_deref().and_then(commands::python_matrix) {
+                if script.is_some() {
+                    bail!("Running a script with multiple Python versions is not supported");
+                }
+                if options.summary || options.summary_json.is_some() {
+                    bail!("`--summary` is not supported with multiple Python versions");
+                }
+                if options.check_only {
+                    bail!("`--check-only` is not supported with multiple Python versions");
+                }
+                if options.exec {
+                    bail!("`--exec` is not supported with multiple Python versions");
+                }
+                if kernel.is_some() {
+                    bail!("`--kernel` is not supported with multiple Python versions");
+                }
+                if command
+                    .as_ref()
+                    .is_some_and(|command| command.try_clone().is_none())
+                {
+                    bail!("Running a remote script with multiple Python versions is not supported");
+                }
+
+                let options = settings::RunOptions {
+                    check_only: false,
+                    isolated: true,
+                    exec: false,
+                    summary: false,
+                    summary_json: None,
+                    buffer_output: args.parallel,
+                    ..options
+                };
+                return Box::pin(commands::run_matrix(
+                    pythons,
+                    args.parallel,
+                    printer,
+                    |python| {
+                        // Write a separate trace for each Python version.
+                        let options = settings::RunOptions {
+                            fs_trace: options
+                                .fs_trace
+                                .as_deref()
+                                .map(|path| commands::fs_trace_matrix_path(path, &python)),
+                            ..options.clone()
+                        };
+                        Box::pin(commands::run(
+                            project_dir,
+                            None,
+                            command.as_ref().and_then(RunCommand::try_clone),
+                            requirements.clone(),
+                            options,
+                            warnings,
+                            args.package.clone(),
+                            no_config,
+                            args.extras.clone(),
+                            args.groups.clone(),
+                            args.with_requirements_from.clone(),
+                            Some(python),
+                            None,
+                            args.install_mirrors.clone(),
+                            args.settings.clone(),
+                            globals.network_settings.clone(),
+                            globals.python_preference,
+                            globals.python_downloads,
+                            globals.installer_metadata,
+                            globals.concurrency,
+                            &cache,
+                            printer,
+                            globals.preview,
+                            None,
+                            None,
+                        ))
+                    },
+                ))
+                .await;
+            }
+
             Box::pin(commands::run(
                 project_dir,
                 script,
                 command,
                 requirements,
-                args.show_resolution || globals.verbose > 0,
-                args.locked,
-                args.frozen,
-                args.active,
-                args.no_sync,
-                args.isolated,
-                args.all_packages,
+                options,
+                warnings,
                 args.package,
-                args.no_project,
                 no_config,
                 args.extras,
                 args.groups,
-                args.editable,
-                args.modifications,
+                args.with_requirements_from,
                 args.python,
+                script_python,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
@@ -1785,10 +2238,9 @@ async fn run_project(
                 globals.concurrency,
                 &cache,
                 printer,
-                args.env_file,
-                args.no_env_file,
                 globals.preview,
-                args.max_recursion_depth,
+                invocation,
+                kernel,
             ))
             .await
         }
@@ -1824,8 +2276,16 @@ async fn run_project(
                 args.editable,
                 args.install_options,
                 args.modifications,
+                args.size_limit,
+                args.unlock,
+                WarningPolicy {
+                    fail_fast: args.fail_fast_env,
+                    strict_python: args.strict_python,
+                },
                 args.python,
                 args.python_platform,
+                args.target,
+                args.verify,
                 args.install_mirrors,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1839,6 +2299,7 @@ async fn run_project(
                 printer,
                 globals.preview,
                 args.output_format,
+                args.export_activate,
             ))
             .await
         }
@@ -1880,6 +2341,10 @@ async fn run_project(
                 globals.python_downloads,
                 globals.concurrency,
                 no_config,
+                WarningPolicy {
+                    fail_fast: args.fail_fast_env,
+                    ..WarningPolicy::default()
+                },
                 &cache,
                 printer,
                 globals.preview,
@@ -1976,6 +2441,7 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.unlock,
                 args.no_install_project,
                 args.no_install_workspace,
                 requirements,
@@ -2033,6 +2499,7 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.unlock,
                 args.packages,
                 args.dependency_type,
                 args.package,
@@ -2077,6 +2544,7 @@ async fn run_project(
                 args.frozen,
                 args.active,
                 args.no_sync,
+                args.unlock,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -2168,6 +2636,7 @@ async fn run_project(
                 args.include_annotations,
                 args.include_header,
                 script,
+                args.to_script,
                 args.python,
                 args.install_mirrors,
                 args.settings,
@@ -2207,6 +2676,80 @@ async fn run_project(
     }
 }
 
+/// Error if the running uv does not satisfy the given [`RequiredVersion`].
+///
+/// The `source`, if provided, is included in the error message to identify where the requirement
+/// was declared (e.g., a PEP 723 script).
+fn enforce_required_version(
+    required_version: &RequiredVersion,
+    source: Option<&str>,
+) -> Result<()> {
+    let package_version = uv_pep440::Version::from_str(uv_version::version())?;
+    if required_version.contains(&package_version) {
+        return Ok(());
+    }
+
+    #[cfg(feature = "self-update")]
+    let hint = {
+        // If the required version range includes a lower bound that's higher than
+        // the current version, suggest `uv self update`.
+        let ranges = release_specifiers_to_ranges(required_version.specifiers().clone());
+
+        if let Some(singleton) = ranges.as_singleton() {
+            // E.g., `==1.0.0`
+            format!(
+                ". Update `uv` by running `{}`.",
+                format!("uv self update {singleton}").green()
+            )
+        } else if ranges
+            .bounding_range()
+            .iter()
+            .any(|(lowest, _highest)| match lowest {
+                Bound::Included(version) => **version > package_version,
+                Bound::Excluded(version) => **version > package_version,
+                Bound::Unbounded => false,
+            })
+        {
+            // E.g., `>=1.0.0`
+            format!(". Update `uv` by running `{}`.", "uv self update".cyan())
+        } else {
+            String::new()
+        }
+    };
+    #[cfg(not(feature = "self-update"))]
+    let hint = "";
+
+    if let Some(source) = source {
+        Err(anyhow::anyhow!(
+            "Required uv version `{required_version}` (from {source}) does not match the running version `{package_version}`{hint}",
+        ))
+    } else {
+        Err(anyhow::anyhow!(
+            "Required uv version `{required_version}` does not match the running version `{package_version}`{hint}",
+        ))
+    }
+}
+
+/// Parse the command-line arguments, recording which global options were read from their
+/// environment variable rather than passed on the command line.
+fn parse_cli<I, T>(args: I) -> Result<Cli, clap::Error>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let matches = Cli::command().try_get_matches_from(args)?;
+    let mut cli = Cli::from_arg_matches(&matches).map_err(|err| err.format(&mut Cli::command()))?;
+    // Global options are propagated to the top-level matches, along with their source.
+    cli.top_level.global_args.env_sourced = Cli::command()
+        .get_arguments()
+        .filter(|arg| arg.is_global_set())
+        .map(|arg| arg.get_id().as_str())
+        .filter(|id| matches.value_source(id) == Some(ValueSource::EnvVariable))
+        .map(ToString::to_string)
+        .collect();
+    Ok(cli)
+}
+
 /// The main entry point for a uv invocation.
 ///
 /// # Usage
@@ -2243,7 +2786,7 @@ where
 
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
-    let cli = match Cli::try_parse_from(args) {
+    let cli = match parse_cli(args) {
         Ok(cli) => cli,
         Err(mut err) => {
             if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
@@ -2292,6 +2835,11 @@ where
         }
     };
 
+    // Determine the exit code scheme and error format before the command line is moved into the
+    // runtime.
+    let exit_code_map = commands::exit_code_map(&cli);
+    let diagnostics = cli.top_level.global_args.diagnostics.unwrap_or_default();
+
     // See `min_stack_size` doc comment about `main2`
     let min_stack_size = min_stack_size();
     let main2 = move || {
@@ -2301,16 +2849,17 @@ where
             .build()
             .expect("Failed building the Runtime");
         // Box the large main future to avoid stack overflows.
-        let result = runtime.block_on(Box::pin(run(cli)));
+        let mut workspace_files = WorkspaceFiles::default();
+        let result = runtime.block_on(Box::pin(run(cli, &mut workspace_files)));
         // Avoid waiting for pending tasks to complete.
         //
         // The resolver may have kicked off HTTP requests during resolution that
         // turned out to be unnecessary. Waiting for those to complete can cause
         // the CLI to hang before exiting.
         runtime.shutdown_background();
-        result
+        (result, workspace_files)
     };
-    let result = std::thread::Builder::new()
+    let (result, workspace_files) = std::thread::Builder::new()
         .name("main2".to_owned())
         .stack_size(min_stack_size)
         .spawn(main2)
@@ -2319,9 +2868,15 @@ where
         .expect("Tokio executor failed, was there a panic?");
 
     match result {
-        Ok(code) => code.into(),
+        Ok(status) => ExitCode::from(status.code(exit_code_map)),
         Err(err) => {
             trace!("Error trace: {err:?}");
+            if diagnostics == DiagnosticsFormat::Json {
+                commands::report_json_diagnostic(err.as_ref(), &workspace_files);
+                return ExitCode::from(
+                    ExitStatus::from_error(err.as_ref(), exit_code_map).code(exit_code_map),
+                );
+            }
             let mut causes = err.chain();
             eprintln!(
                 "{}: {}",
@@ -2331,7 +2886,7 @@ where
             for err in causes {
                 eprintln!("  {}: {}", "Caused by".red().bold(), err.to_string().trim());
             }
-            ExitStatus::Error.into()
+            ExitCode::from(ExitStatus::from_error(err.as_ref(), exit_code_map).code(exit_code_map))
         }
     }
 }
@@ -1,8 +1,18 @@
 use anstream::{eprint, print};
 use indicatif::ProgressDrawTarget;
 
+use uv_cli::DiagnosticsFormat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Printer {
+    /// The amount of user-facing output to display.
+    verbosity: Verbosity,
+    /// The format in which errors are reported.
+    diagnostics: DiagnosticsFormat,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Printer {
+pub(crate) enum Verbosity {
     /// A printer that suppresses all output.
     Silent,
     /// A printer that suppresses most output, but preserves "important" stdout.
@@ -16,50 +26,68 @@ pub(crate) enum Printer {
 }
 
 impl Printer {
+    /// Create a [`Printer`] with the given verbosity, reporting errors in the given format.
+    pub(crate) fn new(verbosity: Verbosity, diagnostics: DiagnosticsFormat) -> Self {
+        Self {
+            verbosity,
+            diagnostics,
+        }
+    }
+
+    /// Return the amount of user-facing output to display.
+    pub(crate) fn verbosity(self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// Returns `true` if errors should be reported as JSON, i.e., with `--diagnostics json`.
+    pub(crate) fn json_diagnostics(self) -> bool {
+        self.diagnostics == DiagnosticsFormat::Json
+    }
+
     /// Return the [`ProgressDrawTarget`] for this printer.
     pub(crate) fn target(self) -> ProgressDrawTarget {
-        match self {
-            Self::Silent => ProgressDrawTarget::hidden(),
-            Self::Quiet => ProgressDrawTarget::hidden(),
-            Self::Default => ProgressDrawTarget::stderr(),
+        match self.verbosity {
+            Verbosity::Silent => ProgressDrawTarget::hidden(),
+            Verbosity::Quiet => ProgressDrawTarget::hidden(),
+            Verbosity::Default => ProgressDrawTarget::stderr(),
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
-            Self::Verbose => ProgressDrawTarget::hidden(),
-            Self::NoProgress => ProgressDrawTarget::hidden(),
+            Verbosity::Verbose => ProgressDrawTarget::hidden(),
+            Verbosity::NoProgress => ProgressDrawTarget::hidden(),
         }
     }
 
     /// Return the [`Stdout`] for this printer.
     #[allow(dead_code, reason = "to be adopted incrementally")]
     pub(crate) fn stdout_important(self) -> Stdout {
-        match self {
-            Self::Silent => Stdout::Disabled,
-            Self::Quiet => Stdout::Enabled,
-            Self::Default => Stdout::Enabled,
-            Self::Verbose => Stdout::Enabled,
-            Self::NoProgress => Stdout::Enabled,
+        match self.verbosity {
+            Verbosity::Silent => Stdout::Disabled,
+            Verbosity::Quiet => Stdout::Enabled,
+            Verbosity::Default => Stdout::Enabled,
+            Verbosity::Verbose => Stdout::Enabled,
+            Verbosity::NoProgress => Stdout::Enabled,
         }
     }
 
     /// Return the [`Stdout`] for this printer.
     pub(crate) fn stdout(self) -> Stdout {
-        match self {
-            Self::Silent => Stdout::Disabled,
-            Self::Quiet => Stdout::Disabled,
-            Self::Default => Stdout::Enabled,
-            Self::Verbose => Stdout::Enabled,
-            Self::NoProgress => Stdout::Enabled,
+        match self.verbosity {
+            Verbosity::Silent => Stdout::Disabled,
+            Verbosity::Quiet => Stdout::Disabled,
+            Verbosity::Default => Stdout::Enabled,
+            Verbosity::Verbose => Stdout::Enabled,
+            Verbosity::NoProgress => Stdout::Enabled,
         }
     }
 
     /// Return the [`Stderr`] for this printer.
     pub(crate) fn stderr(self) -> Stderr {
-        match self {
-            Self::Silent => Stderr::Disabled,
-            Self::Quiet => Stderr::Disabled,
-            Self::Default => Stderr::Enabled,
-            Self::Verbose => Stderr::Enabled,
-            Self::NoProgress => Stderr::Enabled,
+        match self.verbosity {
+            Verbosity::Silent => Stderr::Disabled,
+            Verbosity::Quiet => Stderr::Disabled,
+            Verbosity::Default => Stderr::Enabled,
+            Verbosity::Verbose => Stderr::Enabled,
+            Verbosity::NoProgress => Stderr::Enabled,
         }
     }
 }
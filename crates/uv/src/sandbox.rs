@@ -0,0 +1,154 @@
+//! Restrictions on the commands run by uv, e.g., with `uv run --isolated --network off`.
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_configuration::NetworkAccess;
+use uv_static::EnvVars;
+
+/// Restrictions applied to a command run by uv.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Sandbox {
+    /// Whether the command may access the network.
+    pub(crate) network: NetworkAccess,
+}
+
+impl Sandbox {
+    /// Prepare the [`Command`] for the requested restrictions.
+    ///
+    /// Must be called before the child process is spawned.
+    pub(crate) fn configure(&self, command: &mut Command) {
+        if self.network.is_off() {
+            // Nested uv invocations would fail to reach the network regardless; avoid trying.
+            command.env(EnvVars::UV_OFFLINE, "1");
+            disable_network(command);
+        }
+    }
+}
+
+/// Run the command in a new network namespace, which has no network interfaces other than a
+/// loopback interface.
+///
+/// The loopback interface of a new network namespace starts out down; it's brought up before the
+/// command is executed, such that the command can still serve and connect to `localhost`.
+///
+/// Creating a network namespace requires `CAP_SYS_ADMIN`. Unless uv is running as root, the
+/// network namespace is created within a new user namespace, in which the current user and group
+/// are mapped to themselves. Unprivileged user namespaces are permitted on most distributions; if
+/// they're not, the command fails to spawn rather than running with network access.
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code)]
+fn disable_network(command: &mut Command) {
+    use nix::fcntl::{OFlag, open};
+    use nix::sched::{CloneFlags, unshare};
+    use nix::sys::stat::Mode;
+    use nix::unistd::{Gid, Uid, write};
+
+    let uid = Uid::effective();
+    let gid = Gid::effective();
+    let privileged = uid.is_root();
+
+    // Format the ID maps ahead of time, since the closure runs after `fork`.
+    let uid_map = format!("{uid} {uid} 1");
+    let gid_map = format!("{gid} {gid} 1");
+
+    debug!(
+        "Running command in a new network namespace{}",
+        if privileged {
+            ""
+        } else {
+            " (within a new user namespace)"
+        }
+    );
+
+    // SAFETY: `unshare`, `open`, `write`, `socket`, and `ioctl` are async-signal-safe, and the
+    // closure does not allocate.
+    unsafe {
+        command.pre_exec(move || {
+            if privileged {
+                unshare(CloneFlags::CLONE_NEWNET)?;
+            } else {
+                unshare(CloneFlags::CLONE_NEWUSER | CloneFlags::CLONE_NEWNET)?;
+                // Writing the group map requires disabling `setgroups` first.
+                for (path, contents) in [
+                    ("/proc/self/setgroups", b"deny".as_slice()),
+                    ("/proc/self/uid_map", uid_map.as_bytes()),
+                    ("/proc/self/gid_map", gid_map.as_bytes()),
+                ] {
+                    let fd = open(path, OFlag::O_WRONLY | OFlag::O_CLOEXEC, Mode::empty())?;
+                    write(&fd, contents)?;
+                }
+            }
+            enable_loopback()?;
+            Ok(())
+        });
+    }
+}
+
+/// Bring up the loopback interface of the current network namespace.
+///
+/// Runs between `fork` and `exec`, so it must not allocate.
+#[cfg(target_os = "linux")]
+#[allow(unsafe_code, clippy::cast_possible_truncation)]
+fn enable_loopback() -> nix::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    use nix::errno::Errno;
+    use nix::libc;
+    use nix::sys::socket::{AddressFamily, SockFlag, SockType, socket};
+
+    let socket = socket(
+        AddressFamily::Inet,
+        SockType::Datagram,
+        SockFlag::SOCK_CLOEXEC,
+        None,
+    )?;
+
+    // SAFETY: `ifreq` is a plain C struct, for which all zeroes is a valid value.
+    let mut request: libc::ifreq = unsafe { std::mem::zeroed() };
+    for (byte, name) in request.ifr_name.iter_mut().zip(b"lo") {
+        *byte = libc::c_char::from_ne_bytes([*name]);
+    }
+
+    // SAFETY: `request` is a valid `ifreq` for the interface, which outlives both calls, and the
+    // flags are only read after `SIOCGIFFLAGS` has initialized them.
+    unsafe {
+        Errno::result(libc::ioctl(
+            socket.as_raw_fd(),
+            libc::SIOCGIFFLAGS as _,
+            &raw mut request,
+        ))?;
+        request.ifr_ifru.ifru_flags |= libc::IFF_UP as libc::c_short;
+        Errno::result(libc::ioctl(
+            socket.as_raw_fd(),
+            libc::SIOCSIFFLAGS as _,
+            &raw const request,
+        ))?;
+    }
+    Ok(())
+}
+
+/// Disable network access for the command on a best-effort basis, by pointing the standard proxy
+/// environment variables at an unreachable address.
+///
+/// This is advisory rather than isolation: unlike a network namespace, it's only effective for
+/// programs that respect the proxy environment variables, e.g., `urllib` and `requests`, and not
+/// for programs that open connections directly.
+#[cfg(not(target_os = "linux"))]
+fn disable_network(command: &mut Command) {
+    /// The discard port on the loopback interface, which refuses connections.
+    const UNREACHABLE_PROXY: &str = "http://127.0.0.1:9";
+
+    uv_warnings::warn_user_once!(
+        "Network access can only be fully disabled on Linux; on this platform, proxy environment variables are pointed at an unreachable address, which is advisory and may be ignored by the command"
+    );
+    debug!("Routing network access through an unreachable proxy");
+
+    for key in [
+        EnvVars::HTTP_PROXY,
+        EnvVars::HTTPS_PROXY,
+        EnvVars::ALL_PROXY,
+    ] {
+        command.env(key, UNREACHABLE_PROXY);
+        command.env(key.to_ascii_lowercase(), UNREACHABLE_PROXY);
+    }
+}
@@ -1,49 +1,63 @@
+use std::collections::BTreeMap;
 use std::env::VarError;
 use std::num::NonZeroUsize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, InterpreterSettings, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
+    AddArgs, ColorChoice, DiagnosticsFormat, ExternalCommand, GlobalArgs, InitArgs, ListFormat,
+    LockArgs, Maybe, OverlayMode, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs,
+    PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs,
+    PythonFindFormat, PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs,
+    PythonPinInstallArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs,
+    SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
+    TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
 };
 use uv_cli::{
-    AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
+    AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs, PythonDuArgs,
+    PythonDuFormat, PythonEnvVarsArgs, PythonEnvVarsFormat, PythonInspectArgs, PythonInspectFormat,
+    PythonMarkersArgs, PythonMarkersFormat, PythonResolveArgs, PythonResolveFormat,
+    PythonValidateDownloadsArgs, PythonValidateDownloadsFormat, PythonWhichArgs, PythonWhichFormat,
     ResolverInstallerArgs, ToolUpgradeArgs,
     options::{flag, resolver_installer_options, resolver_options},
 };
 use uv_client::Connectivity;
 use uv_configuration::{
-    BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, Preview, ProjectBuildBackend, Reinstall,
-    RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade,
-    VersionControlSystem,
+    ActivationShell, BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun,
+    EditableMode, ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy,
+    InstallOptions, KeyringProviderType, NoBinary, NoBuild, PathPolicy, Preview,
+    ProjectBuildBackend, Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost,
+    TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
     ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl,
     PackageConfigSettings, Requirement,
 };
+use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
-use uv_normalize::{ExtraName, PackageName, PipGroupName};
+use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, RequirementOrigin};
 use uv_pypi_types::SupportedEnvironments;
-use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
+use uv_python::components::{PythonComponent, PythonComponents};
+use uv_python::downloads::PythonDownloadClientSettings;
+use uv_python::wsl::WslRequest;
+use uv_python::{
+    DownloadRate, HostCertificate, ImplementationName, Prefix, PythonChannel, PythonDownloads,
+    PythonPreference, PythonVersion, Target,
+};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
     PrereleaseMode, ResolutionMode,
 };
 use uv_settings::{
-    Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
-    PythonInstallMirrors, ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
+    Combine, ConfigSourceKind, EnvironmentOptions, FilesystemOptions, Options, PipOptions,
+    PublishOptions, PythonInstallHookFailure, PythonInstallHookOptions, PythonInstallMirrors,
+    PythonQueryLimitsOptions, ResolverInstallerOptions, ResolverInstallerSchema, ResolverOptions,
 };
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -51,8 +65,11 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
-use crate::commands::ToolRunCommand;
+use crate::child::SignalOptions;
+use crate::commands::pip::size_limit::EnvironmentSizeLimit;
 use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
+use crate::commands::{Kernel, Repl, ToolRunCommand};
+use crate::sandbox::Sandbox;
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
@@ -72,13 +89,14 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
     pub(crate) installer_metadata: bool,
+    pub(crate) diagnostics: DiagnosticsFormat,
 }
 
 impl GlobalSettings {
     /// Resolve the [`GlobalSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(args: &GlobalArgs, workspace: Option<&FilesystemOptions>) -> Self {
         let network_settings = NetworkSettings::resolve(args, workspace);
-        let python_preference = resolve_python_preference(args, workspace);
+        let python_layers = PythonLayers::resolve(args, workspace);
         Self {
             required_version: workspace
                 .and_then(|workspace| workspace.globals.required_version.clone()),
@@ -128,36 +146,428 @@ impl GlobalSettings {
                 args.no_preview,
                 &args.preview_features,
             ),
-            python_preference,
-            python_downloads: flag(
-                args.allow_python_downloads,
-                args.no_python_downloads,
-                "python-downloads",
-            )
-            .map(PythonDownloads::from)
-            .combine(env(env::UV_PYTHON_DOWNLOADS))
-            .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
-            .unwrap_or_default(),
+            python_preference: python_layers.python_preference.value().unwrap_or_default(),
+            python_downloads: python_layers.python_downloads.value().unwrap_or_default(),
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
             installer_metadata: !args.no_installer_metadata,
+            diagnostics: args.diagnostics.unwrap_or_default(),
+        }
+    }
+}
+
+/// A layer of configuration that can provide the value of a setting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SettingSource {
+    /// A command-line option, e.g., `--managed-python`.
+    CommandLine(&'static str),
+    /// An environment variable, e.g., `UV_PYTHON_DOWNLOADS`.
+    EnvVar(&'static str),
+    /// A configuration file, e.g., the project's `pyproject.toml`.
+    File(ConfigSourceKind, Option<PathBuf>),
+    /// The default value.
+    Default,
+}
+
+impl std::fmt::Display for SettingSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CommandLine(option) => write!(f, "command-line option `{option}`"),
+            Self::EnvVar(var) => write!(f, "environment variable `{var}`"),
+            Self::File(kind, Some(path)) => {
+                write!(f, "{kind} configuration `{}`", path.user_display())
+            }
+            Self::File(kind, None) => write!(f, "{kind} configuration"),
+            Self::Default => f.write_str("default"),
         }
     }
 }
 
-fn resolve_python_preference(
-    args: &GlobalArgs,
-    workspace: Option<&FilesystemOptions>,
-) -> PythonPreference {
-    if args.managed_python {
-        PythonPreference::OnlyManaged
-    } else if args.no_managed_python {
-        PythonPreference::OnlySystem
-    } else {
-        args.python_preference
-            .combine(workspace.and_then(|workspace| workspace.globals.python_preference))
+/// The value of a setting as provided by each layer of configuration, in order of precedence.
+///
+/// The effective value is derived from the layers, such that it always agrees with the layers
+/// that are reported as used.
+#[derive(Debug, Clone)]
+pub(crate) struct Layered<T> {
+    layers: Vec<(SettingSource, Option<T>)>,
+    /// Whether the values of all layers are merged, rather than the first value being used.
+    merged: bool,
+}
+
+impl<T> Layered<T>
+where
+    T: Clone,
+    Option<T>: Combine,
+{
+    /// A setting for which the first layer that provides a value is used.
+    fn first() -> Self {
+        Self {
+            layers: Vec::new(),
+            merged: false,
+        }
+    }
+
+    /// A setting for which the values of all layers are merged, e.g., arrays.
+    fn merged() -> Self {
+        Self {
+            layers: Vec::new(),
+            merged: true,
+        }
+    }
+
+    /// Add a layer for a global command-line option, attributed to the option's environment
+    /// variable if `clap` read the value from there.
+    fn arg(
+        mut self,
+        args: &GlobalArgs,
+        id: &str,
+        option: &'static str,
+        var: Option<&'static str>,
+        value: Option<T>,
+    ) -> Self {
+        let source = match var {
+            Some(var) if args.is_env_sourced(id) => SettingSource::EnvVar(var),
+            _ => SettingSource::CommandLine(option),
+        };
+        self.layers.push((source, value));
+        self
+    }
+
+    /// Add a layer for an environment variable.
+    fn env(mut self, var: &'static str, value: Option<T>) -> Self {
+        self.layers.push((SettingSource::EnvVar(var), value));
+        self
+    }
+
+    /// Add a layer for each configuration file, in order of precedence.
+    fn files(
+        mut self,
+        filesystem: Option<&FilesystemOptions>,
+        value: impl Fn(&Options) -> Option<T>,
+    ) -> Self {
+        for source in filesystem
+            .map(FilesystemOptions::sources)
             .unwrap_or_default()
+        {
+            self.layers.push((
+                SettingSource::File(source.kind(), source.path().map(Path::to_path_buf)),
+                value(source.options()),
+            ));
+        }
+        self
+    }
+
+    /// Add a layer for the default value.
+    fn or_default(mut self, value: T) -> Self {
+        self.layers.push((SettingSource::Default, Some(value)));
+        self
+    }
+
+    /// Return the effective value of the setting.
+    pub(crate) fn value(&self) -> Option<T> {
+        self.layers
+            .iter()
+            .fold(None, |value, (_, layer)| value.combine(layer.clone()))
+    }
+
+    /// Return the effective value of the setting, if it was provided by a configuration file.
+    pub(crate) fn file_value(&self) -> Option<T> {
+        match self.layers.iter().find(|(_, value)| value.is_some()) {
+            Some((SettingSource::File(..), _)) => self.value(),
+            _ => None,
+        }
+    }
+
+    /// Return each layer, along with the value it provides and whether that value is used.
+    pub(crate) fn layers(&self) -> impl Iterator<Item = (&SettingSource, Option<&T>, bool)> {
+        let first = self.layers.iter().position(|(_, value)| value.is_some());
+        self.layers
+            .iter()
+            .enumerate()
+            .map(move |(index, (source, value))| {
+                let used = value.is_some() && (self.merged || Some(index) == first);
+                (source, value.as_ref(), used)
+            })
+    }
+}
+
+/// The layers of the global settings that determine which Python interpreters are used.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonLayers {
+    pub(crate) python_preference: Layered<PythonPreference>,
+    pub(crate) python_downloads: Layered<PythonDownloads>,
+}
+
+impl PythonLayers {
+    /// Resolve the [`PythonLayers`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: &GlobalArgs, workspace: Option<&FilesystemOptions>) -> Self {
+        let python_preference = Layered::first()
+            .arg(
+                args,
+                "managed_python",
+                "--managed-python",
+                Some(EnvVars::UV_MANAGED_PYTHON),
+                args.managed_python.then_some(PythonPreference::OnlyManaged),
+            )
+            .arg(
+                args,
+                "no_managed_python",
+                "--no-managed-python",
+                Some(EnvVars::UV_NO_MANAGED_PYTHON),
+                args.no_managed_python
+                    .then_some(PythonPreference::OnlySystem),
+            )
+            .arg(
+                args,
+                "python_preference",
+                "--python-preference",
+                Some(EnvVars::UV_PYTHON_PREFERENCE),
+                args.python_preference,
+            )
+            .files(workspace, |options| options.globals.python_preference)
+            .or_default(PythonPreference::default());
+
+        let downloads = flag(
+            args.allow_python_downloads,
+            args.no_python_downloads,
+            "python-downloads",
+        );
+        let python_downloads = Layered::first()
+            .arg(
+                args,
+                "allow_python_downloads",
+                "--allow-python-downloads",
+                None,
+                (downloads == Some(true)).then(|| PythonDownloads::from(true)),
+            )
+            .arg(
+                args,
+                "no_python_downloads",
+                "--no-python-downloads",
+                None,
+                (downloads == Some(false)).then(|| PythonDownloads::from(false)),
+            )
+            .env(EnvVars::UV_PYTHON_DOWNLOADS, env(env::UV_PYTHON_DOWNLOADS))
+            .files(workspace, |options| options.globals.python_downloads)
+            .or_default(PythonDownloads::default());
+
+        Self {
+            python_preference,
+            python_downloads,
+        }
+    }
+}
+
+/// The layers of the mirrors used to download managed Python installations.
+///
+/// The environment variables take precedence over the configuration files.
+#[derive(Debug, Clone)]
+pub(crate) struct InstallMirrorLayers {
+    pub(crate) python_install_mirror: Layered<String>,
+    pub(crate) pypy_install_mirror: Layered<String>,
+    pub(crate) python_downloads_json_url: Layered<String>,
+    pub(crate) python_install_keyring_provider: Layered<KeyringProviderType>,
+    pub(crate) python_install_ca_certs: Layered<Vec<HostCertificate>>,
+    pub(crate) python_install_dir: Layered<PathBuf>,
+    pub(crate) install_staging_dir: Layered<PathBuf>,
+}
+
+impl InstallMirrorLayers {
+    /// Resolve the [`InstallMirrorLayers`] from the environment and filesystem configuration.
+    pub(crate) fn resolve(filesystem: Option<&FilesystemOptions>) -> Self {
+        let layered = |var: &'static str, field: fn(&PythonInstallMirrors) -> Option<String>| {
+            Layered::first()
+                .env(var, std::env::var(var).ok())
+                .files(filesystem, |options| field(&options.install_mirrors))
+        };
+        Self {
+            python_install_mirror: layered(EnvVars::UV_PYTHON_INSTALL_MIRROR, |mirrors| {
+                mirrors.python_install_mirror.clone()
+            }),
+            pypy_install_mirror: layered(EnvVars::UV_PYPY_INSTALL_MIRROR, |mirrors| {
+                mirrors.pypy_install_mirror.clone()
+            }),
+            python_downloads_json_url: layered(EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL, |mirrors| {
+                mirrors.python_downloads_json_url.clone()
+            }),
+            python_install_keyring_provider: Layered::first()
+                .env(
+                    EnvVars::UV_PYTHON_INSTALL_KEYRING_PROVIDER,
+                    parse_env_var(EnvVars::UV_PYTHON_INSTALL_KEYRING_PROVIDER, |value| {
+                        <KeyringProviderType as clap::ValueEnum>::from_str(value.trim(), true)
+                    }),
+                )
+                .files(filesystem, |options| {
+                    options.install_mirrors.python_install_keyring_provider
+                }),
+            python_install_ca_certs: Layered::merged()
+                .env(
+                    EnvVars::UV_PYTHON_INSTALL_CA_CERTS,
+                    parse_env_var(EnvVars::UV_PYTHON_INSTALL_CA_CERTS, |value| {
+                        value
+                            .split_whitespace()
+                            .map(HostCertificate::from_str)
+                            .collect()
+                    }),
+                )
+                .files(filesystem, |options| {
+                    options.install_mirrors.python_install_ca_certs.clone()
+                }),
+            python_install_dir: Layered::first()
+                .env(
+                    EnvVars::UV_PYTHON_INSTALL_DIR,
+                    std::env::var_os(EnvVars::UV_PYTHON_INSTALL_DIR)
+                        .filter(|value| !value.is_empty())
+                        .map(PathBuf::from),
+                )
+                .files(filesystem, |options| {
+                    options.install_mirrors.python_install_dir.clone()
+                }),
+            install_staging_dir: Layered::first()
+                .env(
+                    EnvVars::UV_PYTHON_INSTALL_STAGING_DIR,
+                    std::env::var_os(EnvVars::UV_PYTHON_INSTALL_STAGING_DIR)
+                        .filter(|value| !value.is_empty())
+                        .map(PathBuf::from),
+                )
+                .files(filesystem, |options| {
+                    options.install_mirrors.install_staging_dir.clone()
+                }),
+        }
+    }
+}
+
+/// Read an environment variable that isn't parsed by `clap`, ignoring invalid values with a
+/// warning.
+fn parse_env_var<T>(var: &'static str, parse: impl Fn(&str) -> Result<T, String>) -> Option<T> {
+    let value = std::env::var(var)
+        .ok()
+        .filter(|value| !value.trim().is_empty())?;
+    match parse(&value) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            warn_user_once!("Ignoring invalid value for `{var}`: {err}");
+            None
+        }
+    }
+}
+
+/// Resolve the [`PythonInstallMirrors`] from the environment and filesystem configuration.
+pub(crate) fn resolve_install_mirrors(
+    filesystem: Option<&FilesystemOptions>,
+) -> PythonInstallMirrors {
+    let layers = InstallMirrorLayers::resolve(filesystem);
+    PythonInstallMirrors {
+        python_install_mirror: layers.python_install_mirror.value(),
+        pypy_install_mirror: layers.pypy_install_mirror.value(),
+        python_downloads_json_url: layers.python_downloads_json_url.value(),
+        python_install_keyring_provider: layers.python_install_keyring_provider.value(),
+        python_install_ca_certs: layers.python_install_ca_certs.value(),
+        python_resolution: filesystem
+            .and_then(|filesystem| filesystem.install_mirrors.python_resolution),
+        // `UV_PYTHON_INSTALL_DIR` replaces the user-level directory, which is searched regardless,
+        // so only a project-level directory needs to be carried.
+        python_install_dir: layers.python_install_dir.file_value(),
+        install_staging_dir: layers.install_staging_dir.value(),
+    }
+}
+
+/// The layers of the settings that affect how Python interpreters are discovered and queried.
+#[derive(Debug, Clone)]
+pub(crate) struct InterpreterLayers {
+    pub(crate) python_discovery_exclude: Layered<Vec<String>>,
+    pub(crate) python_discovery_include_only: Layered<Vec<String>>,
+    pub(crate) python_query_env: Layered<BTreeMap<PathBuf, BTreeMap<String, String>>>,
+    pub(crate) python_query_timeout: Layered<u64>,
+    pub(crate) python_query_memory_limit: Layered<u64>,
+    pub(crate) python_query_cpu_limit: Layered<u64>,
+    pub(crate) python_query_concurrency: Layered<NonZeroUsize>,
+}
+
+impl InterpreterLayers {
+    /// Resolve the [`InterpreterLayers`] from the environment and filesystem configuration.
+    ///
+    /// The environment variables take precedence over the configuration files.
+    pub(crate) fn resolve(
+        environment: &EnvironmentOptions,
+        filesystem: Option<&FilesystemOptions>,
+    ) -> Self {
+        let limits = &environment.python_query_limits;
+        let limit = |var: &'static str,
+                     value: Option<u64>,
+                     field: fn(&PythonQueryLimitsOptions) -> Option<u64>| {
+            Layered::first()
+                .env(var, value)
+                .files(filesystem, |options| {
+                    options.python_query_limits.as_ref().and_then(field)
+                })
+        };
+        Self {
+            python_discovery_exclude: Layered::merged().files(filesystem, |options| {
+                options
+                    .python_discovery
+                    .as_ref()
+                    .and_then(|discovery| discovery.exclude.clone())
+            }),
+            python_discovery_include_only: Layered::merged().files(filesystem, |options| {
+                options
+                    .python_discovery
+                    .as_ref()
+                    .and_then(|discovery| discovery.include_only.clone())
+            }),
+            python_query_env: Layered::merged()
+                .files(filesystem, |options| options.python_query_env.clone()),
+            python_query_timeout: limit(
+                EnvVars::UV_PYTHON_QUERY_TIMEOUT,
+                limits.timeout,
+                |limits| limits.timeout,
+            ),
+            python_query_memory_limit: limit(
+                EnvVars::UV_PYTHON_QUERY_MEMORY_LIMIT,
+                limits.memory_limit,
+                |limits| limits.memory_limit,
+            ),
+            python_query_cpu_limit: limit(
+                EnvVars::UV_PYTHON_QUERY_CPU_LIMIT,
+                limits.cpu_limit,
+                |limits| limits.cpu_limit,
+            ),
+            python_query_concurrency: Layered::first()
+                .env(EnvVars::UV_PYTHON_QUERY_CONCURRENCY, limits.concurrency)
+                .files(filesystem, |options| {
+                    options
+                        .python_query_limits
+                        .as_ref()
+                        .and_then(|limits| limits.concurrency)
+                }),
+        }
+    }
+
+    /// Build the [`InterpreterSettings`] from the layers.
+    pub(crate) fn settings(&self) -> InterpreterSettings {
+        let settings = InterpreterSettings::default()
+            .with_discovery_filter(
+                self.python_discovery_exclude.value().unwrap_or_default(),
+                self.python_discovery_include_only.value(),
+            )
+            .with_query_env(self.python_query_env.value().unwrap_or_default())
+            .with_query_timeout(
+                self.python_query_timeout
+                    .value()
+                    .filter(|timeout| *timeout > 0)
+                    .map(Duration::from_secs),
+            )
+            .with_query_resource_limits(
+                self.python_query_memory_limit
+                    .value()
+                    .map(|mebibytes| mebibytes.saturating_mul(1024 * 1024)),
+                self.python_query_cpu_limit.value(),
+            );
+        match self.python_query_concurrency.value() {
+            Some(concurrency) => settings.with_query_concurrency(concurrency),
+            None => settings,
+        }
     }
 }
 
@@ -291,9 +701,7 @@ impl InitSettings {
         )
         .unwrap_or(kind.packaged_by_default());
 
-        let install_mirrors = filesystem
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         let no_description = no_description || (bare && description.is_none());
 
@@ -320,29 +728,58 @@ impl InitSettings {
 /// The resolved settings to use for a `run` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct RunSettings {
-    pub(crate) locked: bool,
-    pub(crate) frozen: bool,
+    pub(crate) options: RunOptions,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: DependencyGroups,
-    pub(crate) editable: EditableMode,
-    pub(crate) modifications: Modifications,
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
-    pub(crate) isolated: bool,
-    pub(crate) show_resolution: bool,
-    pub(crate) all_packages: bool,
+    pub(crate) with_requirements_from: Vec<GroupName>,
+    pub(crate) repl: Option<Repl>,
     pub(crate) package: Option<PackageName>,
-    pub(crate) no_project: bool,
-    pub(crate) active: Option<bool>,
-    pub(crate) no_sync: bool,
+    pub(crate) fail_fast_env: bool,
+    pub(crate) strict_python: bool,
     pub(crate) python: Option<String>,
+    pub(crate) parallel: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
+    pub(crate) history: bool,
+}
+
+/// The options that control how `uv run` prepares the environment and runs the command.
+#[derive(Debug, Clone)]
+pub(crate) struct RunOptions {
+    pub(crate) show_resolution: bool,
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) active: Option<bool>,
+    pub(crate) no_sync: bool,
+    pub(crate) unlock: bool,
+    pub(crate) check_only: bool,
+    pub(crate) isolated: bool,
+    pub(crate) all_packages: bool,
+    pub(crate) no_project: bool,
+    pub(crate) editable: EditableMode,
+    pub(crate) modifications: Modifications,
+    pub(crate) size_limit: Option<EnvironmentSizeLimit>,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
     pub(crate) max_recursion_depth: u32,
+    pub(crate) signal_options: SignalOptions,
+    pub(crate) sandbox: Sandbox,
+    pub(crate) path_policy: PathPolicy,
+    pub(crate) overlay_mode: OverlayMode,
+    pub(crate) require_hashes: bool,
+    pub(crate) allow_user_site: bool,
+    pub(crate) exec: bool,
+    pub(crate) keep_env_on_failure: bool,
+    pub(crate) fs_trace: Option<PathBuf>,
+    pub(crate) summary: bool,
+    pub(crate) summary_json: Option<PathBuf>,
+    /// Whether to buffer the command's output, e.g., when running for multiple Python versions
+    /// in parallel.
+    pub(crate) buffer_output: bool,
 }
 
 impl RunSettings {
@@ -374,14 +811,38 @@ impl RunSettings {
             exact,
             script: _,
             gui_script: _,
+            ipython,
+            ptpython,
+            kernel,
             command: _,
             with,
             with_editable,
             with_requirements,
+            with_requirements_from,
+            overlay_mode,
+            require_hashes,
+            no_require_hashes,
+            allow_user_site,
+            exec,
+            keep_env_on_failure,
+            fs_trace,
+            summary,
+            summary_json,
+            last: _,
+            history: _,
+            no_history,
             isolated,
+            network,
+            path_policy,
             active,
             no_active,
             no_sync,
+            unlock,
+            check_only,
+            fail_fast_env,
+            no_fail_fast_env,
+            strict_python,
+            exit_code_map: _,
             locked,
             frozen,
             installer,
@@ -391,20 +852,126 @@ impl RunSettings {
             package,
             no_project,
             python,
+            parallel,
             show_resolution,
+            signal_forwarding,
+            shutdown_timeout,
             env_file,
             no_env_file,
             max_recursion_depth,
         } = args;
 
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+
+        let fail_fast_env = flag(fail_fast_env, no_fail_fast_env, "fail-fast-env")
+            .or_else(|| filesystem.as_ref().and_then(|fs| fs.fail_fast_env))
+            .unwrap_or(false);
+
+        let repl = if ipython {
+            Some(Repl::IPython)
+        } else if ptpython {
+            Some(Repl::PtPython)
+        } else {
+            None
+        };
+
+        // As `uv run` audits the environment on every invocation, only report its size when the
+        // environment is modified.
+        let size_limit = filesystem.as_ref().and_then(|fs| {
+            EnvironmentSizeLimit::from_options(
+                fs.max_environment_size,
+                fs.max_environment_size_action,
+            )
+            .map(EnvironmentSizeLimit::report_changes_only)
+        });
+
+        let network = network
+            .or_else(|| {
+                filesystem
+                    .as_ref()
+                    .and_then(|fs| fs.run.as_ref())
+                    .and_then(|run| run.network)
+            })
+            .unwrap_or_default();
+
+        let path_policy = path_policy
+            .or_else(|| {
+                filesystem
+                    .as_ref()
+                    .and_then(|fs| fs.run.as_ref())
+                    .and_then(|run| run.path_policy.clone())
+            })
             .unwrap_or_default();
 
+        let history = !no_history
+            && filesystem
+                .as_ref()
+                .and_then(|fs| fs.run.as_ref())
+                .and_then(|run| run.history)
+                .unwrap_or(true);
+
+        // Include the `tool.uv.run.with` requirements ahead of any `--with` requirements, skipping
+        // duplicates so that repeating a configured requirement on the command line is a no-op.
+        // The requested REPL or kernel, if any, is provided by the same overlay.
+        let with = filesystem
+            .as_ref()
+            .and_then(|fs| fs.run.as_ref())
+            .and_then(|run| run.with.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .chain(
+                with.into_iter()
+                    .flat_map(CommaSeparatedRequirements::into_iter),
+            )
+            .chain(repl.map(|repl| repl.requirement().to_string()))
+            .chain(kernel.map(|_| Kernel::REQUIREMENT.to_string()))
+            .fold(Vec::new(), |mut with, requirement| {
+                if !with.contains(&requirement) {
+                    with.push(requirement);
+                }
+                with
+            });
+
         Self {
-            locked,
-            frozen,
+            options: RunOptions {
+                show_resolution,
+                locked,
+                frozen,
+                active: flag(active, no_active, "active"),
+                no_sync,
+                unlock,
+                check_only,
+                isolated,
+                all_packages,
+                no_project,
+                editable: EditableMode::from_args(no_editable),
+                modifications: if flag(exact, inexact, "inexact").unwrap_or(false) {
+                    Modifications::Exact
+                } else {
+                    Modifications::Sufficient
+                },
+                size_limit,
+                env_file,
+                no_env_file,
+                max_recursion_depth: max_recursion_depth
+                    .unwrap_or(Self::DEFAULT_MAX_RECURSION_DEPTH),
+                signal_options: SignalOptions {
+                    forwarding: signal_forwarding,
+                    shutdown_timeout: shutdown_timeout.map(Duration::from_secs),
+                },
+                sandbox: Sandbox { network },
+                path_policy,
+                overlay_mode,
+                require_hashes: flag(require_hashes, no_require_hashes, "require-hashes")
+                    .unwrap_or_default(),
+                allow_user_site,
+                exec,
+                keep_env_on_failure,
+                fs_trace,
+                summary,
+                summary_json,
+                buffer_output: false,
+            },
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -424,16 +991,7 @@ impl RunSettings {
                 only_group,
                 all_groups,
             ),
-            editable: EditableMode::from_args(no_editable),
-            modifications: if flag(exact, inexact, "inexact").unwrap_or(false) {
-                Modifications::Exact
-            } else {
-                Modifications::Sufficient
-            },
-            with: with
-                .into_iter()
-                .flat_map(CommaSeparatedRequirements::into_iter)
-                .collect(),
+            with,
             with_editable: with_editable
                 .into_iter()
                 .flat_map(CommaSeparatedRequirements::into_iter)
@@ -442,23 +1000,20 @@ impl RunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
-            isolated,
-            show_resolution,
-            all_packages,
+            with_requirements_from,
+            repl,
             package,
-            no_project,
-            no_sync,
-            active: flag(active, no_active, "active"),
+            fail_fast_env,
+            strict_python,
             python: python.and_then(Maybe::into_option),
+            parallel,
+            install_mirrors,
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
                 resolver_installer_options(installer, build),
                 filesystem,
             ),
-            env_file,
-            no_env_file,
-            install_mirrors,
-            max_recursion_depth: max_recursion_depth.unwrap_or(Self::DEFAULT_MAX_RECURSION_DEPTH),
+            history,
         }
     }
 }
@@ -548,10 +1103,7 @@ impl ToolRunSettings {
                     .unwrap_or_default(),
             ));
 
-        let install_mirrors = filesystem
-            .map(FilesystemOptions::into_options)
-            .map(|options| options.install_mirrors)
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         let settings = ResolverInstallerSettings::from(options.clone());
 
@@ -612,6 +1164,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) fail_fast_env: bool,
     pub(crate) editable: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
@@ -633,11 +1186,17 @@ impl ToolInstallSettings {
             build_constraints,
             installer,
             force,
+            fail_fast_env,
+            no_fail_fast_env,
             build,
             refresh,
             python,
         } = args;
 
+        let fail_fast_env = flag(fail_fast_env, no_fail_fast_env, "fail-fast-env")
+            .or_else(|| filesystem.as_ref().and_then(|fs| fs.fail_fast_env))
+            .unwrap_or(false);
+
         let options =
             resolver_installer_options(installer, build).combine(ResolverInstallerOptions::from(
                 filesystem
@@ -647,10 +1206,7 @@ impl ToolInstallSettings {
                     .unwrap_or_default(),
             ));
 
-        let install_mirrors = filesystem
-            .map(FilesystemOptions::into_options)
-            .map(|options| options.install_mirrors)
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         let settings = ResolverInstallerSettings::from(options.clone());
 
@@ -687,6 +1243,7 @@ impl ToolInstallSettings {
                 .collect(),
             python: python.and_then(Maybe::into_option),
             force,
+            fail_fast_env,
             editable,
             refresh: Refresh::from(refresh),
             options,
@@ -775,11 +1332,8 @@ impl ToolUpgradeSettings {
         };
 
         let args = resolver_installer_options(installer, build);
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
         let filesystem = filesystem.map(FilesystemOptions::into_options);
-        let install_mirrors = filesystem
-            .clone()
-            .map(|options| options.install_mirrors)
-            .unwrap_or_default();
         let top_level = ResolverInstallerOptions::from(
             filesystem
                 .map(|options| options.top_level)
@@ -881,7 +1435,10 @@ pub(crate) struct PythonListSettings {
     pub(crate) all_versions: bool,
     pub(crate) show_urls: bool,
     pub(crate) output_format: PythonListFormat,
+    pub(crate) outdated: bool,
+    pub(crate) exit_code: bool,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) python_install_dir: Option<PathBuf>,
 }
 
 impl PythonListSettings {
@@ -897,14 +1454,16 @@ impl PythonListSettings {
             only_downloads,
             show_urls,
             output_format,
+            outdated,
+            exit_code,
             python_downloads_json_url: python_downloads_json_url_arg,
         } = args;
 
-        let options = filesystem.map(FilesystemOptions::into_options);
-        let python_downloads_json_url_option = match options {
-            Some(options) => options.install_mirrors.python_downloads_json_url,
-            None => None,
-        };
+        let PythonInstallMirrors {
+            python_downloads_json_url: python_downloads_json_url_option,
+            python_install_dir,
+            ..
+        } = resolve_install_mirrors(filesystem.as_ref());
 
         let python_downloads_json_url =
             python_downloads_json_url_arg.or(python_downloads_json_url_option);
@@ -925,7 +1484,10 @@ impl PythonListSettings {
             all_versions,
             show_urls,
             output_format,
+            outdated,
+            exit_code,
             python_downloads_json_url,
+            python_install_dir,
         }
     }
 }
@@ -934,15 +1496,281 @@ impl PythonListSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PythonDirSettings {
     pub(crate) bin: bool,
+    pub(crate) python_install_dir: Option<PathBuf>,
 }
 
 impl PythonDirSettings {
     /// Resolve the [`PythonDirSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: PythonDirArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: PythonDirArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PythonDirArgs { bin } = args;
 
-        Self { bin }
+        Self {
+            bin,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python du` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonDuSettings {
+    pub(crate) targets: Vec<String>,
+    pub(crate) output_format: PythonDuFormat,
+    pub(crate) python_install_dir: Option<PathBuf>,
+}
+
+impl PythonDuSettings {
+    /// Resolve the [`PythonDuSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonDuArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonDuArgs {
+            targets,
+            output_format,
+        } = args;
+
+        Self {
+            targets,
+            output_format,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python env-vars` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonEnvVarsSettings {
+    pub(crate) request: Option<String>,
+    pub(crate) output_format: PythonEnvVarsFormat,
+    pub(crate) python_install_dir: Option<PathBuf>,
+}
+
+impl PythonEnvVarsSettings {
+    /// Resolve the [`PythonEnvVarsSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonEnvVarsArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonEnvVarsArgs {
+            request,
+            output_format,
+        } = args;
+
+        Self {
+            request,
+            output_format,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python inspect` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonInspectSettings {
+    pub(crate) request: Option<String>,
+    pub(crate) system: bool,
+    pub(crate) output_format: PythonInspectFormat,
+    pub(crate) python_install_dir: Option<PathBuf>,
+}
+
+impl PythonInspectSettings {
+    /// Resolve the [`PythonInspectSettings`] from the CLI and workspace configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonInspectArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonInspectArgs {
+            request,
+            system,
+            no_system,
+            output_format,
+        } = args;
+
+        Self {
+            request,
+            system: flag(system, no_system, "system").unwrap_or_default(),
+            output_format,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python markers` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonMarkersSettings {
+    pub(crate) python: Option<String>,
+    pub(crate) python_version: Option<PythonVersion>,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) implementation: Option<ImplementationName>,
+    pub(crate) system: bool,
+    pub(crate) output_format: PythonMarkersFormat,
+    pub(crate) python_install_dir: Option<PathBuf>,
+}
+
+impl PythonMarkersSettings {
+    /// Resolve the [`PythonMarkersSettings`] from the CLI and workspace configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonMarkersArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonMarkersArgs {
+            python,
+            python_version,
+            python_platform,
+            implementation,
+            system,
+            no_system,
+            output_format,
+        } = args;
+
+        Self {
+            python: python.and_then(Maybe::into_option),
+            python_version,
+            python_platform,
+            implementation,
+            system: flag(system, no_system, "system").unwrap_or_default(),
+            output_format,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python which` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonWhichSettings {
+    pub(crate) command: String,
+    pub(crate) with: Vec<String>,
+    pub(crate) no_project: bool,
+    pub(crate) path_policy: PathPolicy,
+    pub(crate) output_format: PythonWhichFormat,
+    pub(crate) python_install_dir: Option<PathBuf>,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl PythonWhichSettings {
+    /// Resolve the [`PythonWhichSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: PythonWhichArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonWhichArgs {
+            command,
+            with,
+            no_project,
+            path_policy,
+            output_format,
+        } = args;
+
+        let path_policy = path_policy
+            .or_else(|| {
+                filesystem
+                    .as_ref()
+                    .and_then(|fs| fs.run.as_ref())
+                    .and_then(|run| run.path_policy.clone())
+            })
+            .unwrap_or_default();
+
+        Self {
+            command,
+            with: with
+                .into_iter()
+                .flat_map(CommaSeparatedRequirements::into_iter)
+                .collect(),
+            no_project,
+            path_policy,
+            output_format,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
+            settings: ResolverInstallerSettings::combine(
+                ResolverInstallerOptions::default(),
+                filesystem,
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `python resolve` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonResolveSettings {
+    pub(crate) request: Option<String>,
+    pub(crate) platform: Option<String>,
+    pub(crate) output_format: PythonResolveFormat,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl PythonResolveSettings {
+    /// Resolve the [`PythonResolveSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonResolveArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonResolveArgs {
+            request,
+            platform,
+            output_format,
+            mirror,
+            pypy_mirror,
+            python_downloads_json_url,
+            download_client,
+        } = args;
+
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+
+        Self {
+            request,
+            platform,
+            output_format,
+            install_mirrors: PythonInstallMirrors {
+                python_install_mirror: mirror.or(install_mirrors.python_install_mirror),
+                pypy_install_mirror: pypy_mirror.or(install_mirrors.pypy_install_mirror),
+                python_downloads_json_url: python_downloads_json_url
+                    .or(install_mirrors.python_downloads_json_url),
+                python_install_keyring_provider: download_client
+                    .python_install_keyring_provider
+                    .or(install_mirrors.python_install_keyring_provider),
+                python_install_ca_certs: download_client
+                    .python_install_ca_cert
+                    .or(install_mirrors.python_install_ca_certs),
+                ..install_mirrors
+            },
+        }
+    }
+}
+
+/// The resolved settings to use for a `python validate-downloads` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonValidateDownloadsSettings {
+    pub(crate) targets: Vec<String>,
+    pub(crate) full: bool,
+    pub(crate) output_format: PythonValidateDownloadsFormat,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+}
+
+impl PythonValidateDownloadsSettings {
+    /// Resolve the [`PythonValidateDownloadsSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: PythonValidateDownloadsArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let PythonValidateDownloadsArgs {
+            targets,
+            full,
+            output_format,
+            mirror,
+            pypy_mirror,
+            python_downloads_json_url,
+            download_client,
+        } = args;
+
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+
+        Self {
+            targets,
+            full,
+            output_format,
+            install_mirrors: PythonInstallMirrors {
+                python_install_mirror: mirror.or(install_mirrors.python_install_mirror),
+                pypy_install_mirror: pypy_mirror.or(install_mirrors.pypy_install_mirror),
+                python_downloads_json_url: python_downloads_json_url
+                    .or(install_mirrors.python_downloads_json_url),
+                python_install_keyring_provider: download_client
+                    .python_install_keyring_provider
+                    .or(install_mirrors.python_install_keyring_provider),
+                python_install_ca_certs: download_client
+                    .python_install_ca_cert
+                    .or(install_mirrors.python_install_ca_certs),
+                ..install_mirrors
+            },
+        }
     }
 }
 
@@ -950,6 +1778,7 @@ impl PythonDirSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct PythonInstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) install_staging_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) reinstall: bool,
     pub(crate) force: bool,
@@ -958,7 +1787,15 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) python_install_mirror: Option<String>,
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) download_client: PythonDownloadClientSettings,
     pub(crate) default: bool,
+    pub(crate) channel: Option<PythonChannel>,
+    pub(crate) components: Option<PythonComponents>,
+    pub(crate) hook: Option<PythonInstallHookSettings>,
+    pub(crate) only_download: bool,
+    pub(crate) from_cache: bool,
+    pub(crate) atomic: bool,
+    pub(crate) download_rate_limit: Option<DownloadRate>,
 }
 
 impl PythonInstallSettings {
@@ -969,19 +1806,24 @@ impl PythonInstallSettings {
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
-        let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
-            Some(options) => (
-                options.install_mirrors.python_install_mirror,
-                options.install_mirrors.pypy_install_mirror,
-                options.install_mirrors.python_downloads_json_url,
-            ),
-            None => (None, None, None),
-        };
-        let python_mirror = args.mirror.or(python_mirror);
-        let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
-        let python_downloads_json_url =
-            args.python_downloads_json_url.or(python_downloads_json_url);
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+        let hook = filesystem
+            .map(FilesystemOptions::into_options)
+            .and_then(|options| options.python_install_hook);
+        let python_mirror = args.mirror.or(install_mirrors.python_install_mirror);
+        let pypy_mirror = args.pypy_mirror.or(install_mirrors.pypy_install_mirror);
+        let python_downloads_json_url = args
+            .python_downloads_json_url
+            .or(install_mirrors.python_downloads_json_url);
+        let download_client = PythonDownloadClientSettings::new(
+            args.download_client
+                .python_install_keyring_provider
+                .or(install_mirrors.python_install_keyring_provider),
+            args.download_client
+                .python_install_ca_cert
+                .or(install_mirrors.python_install_ca_certs)
+                .unwrap_or_default(),
+        );
 
         let PythonInstallArgs {
             install_dir,
@@ -995,11 +1837,32 @@ impl PythonInstallSettings {
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
+            download_client: _,
             default,
+            channel,
+            with_pip,
+            with_tk,
+            with_tests,
+            only_download,
+            from_cache,
+            atomic,
+            download_rate_limit,
         } = args;
 
+        // If any components are selected, the others are omitted.
+        let components = [
+            (with_pip, PythonComponent::Pip),
+            (with_tk, PythonComponent::Tk),
+            (with_tests, PythonComponent::Tests),
+        ]
+        .into_iter()
+        .filter_map(|(selected, component)| selected.then_some(component))
+        .collect::<Vec<_>>();
+        let components = (!components.is_empty()).then(|| PythonComponents::only(components));
+
         Self {
-            install_dir,
+            install_dir: install_dir.or(install_mirrors.python_install_dir),
+            install_staging_dir: install_mirrors.install_staging_dir,
             targets,
             reinstall,
             force,
@@ -1009,16 +1872,54 @@ impl PythonInstallSettings {
             python_install_mirror: python_mirror,
             pypy_install_mirror: pypy_mirror,
             python_downloads_json_url,
+            download_client,
             default,
+            channel,
+            components,
+            hook: PythonInstallHookSettings::resolve(hook),
+            only_download,
+            from_cache,
+            atomic,
+            download_rate_limit,
         }
     }
 }
 
+/// The resolved settings for the hook to execute after a managed Python installation is installed.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonInstallHookSettings {
+    pub(crate) command: Vec<String>,
+    pub(crate) timeout: Duration,
+    pub(crate) on_failure: PythonInstallHookFailure,
+}
+
+impl PythonInstallHookSettings {
+    /// The default time to wait for the hook to complete.
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+
+    /// Resolve the [`PythonInstallHookSettings`] from the filesystem configuration, if a hook
+    /// command is configured.
+    fn resolve(options: Option<PythonInstallHookOptions>) -> Option<Self> {
+        let PythonInstallHookOptions {
+            command,
+            timeout,
+            on_failure,
+        } = options?;
+        let command = command.filter(|command| !command.is_empty())?;
+        Some(Self {
+            command,
+            timeout: timeout.map_or(Self::DEFAULT_TIMEOUT, Duration::from_secs),
+            on_failure: on_failure.unwrap_or_default(),
+        })
+    }
+}
+
 /// The resolved settings to use for a `python upgrade` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct PythonUpgradeSettings {
     pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) install_staging_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) force: bool,
     pub(crate) registry: Option<bool>,
@@ -1026,27 +1927,37 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) pypy_install_mirror: Option<String>,
     pub(crate) reinstall: bool,
     pub(crate) python_downloads_json_url: Option<String>,
+    pub(crate) download_client: PythonDownloadClientSettings,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) channel: Option<PythonChannel>,
+    pub(crate) hook: Option<PythonInstallHookSettings>,
+    pub(crate) minor: bool,
+    pub(crate) apply: bool,
 }
 
 impl PythonUpgradeSettings {
     /// Resolve the [`PythonUpgradeSettings`] from the CLI and filesystem configuration.
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(args: PythonUpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
-        let options = filesystem.map(FilesystemOptions::into_options);
-        let (python_mirror, pypy_mirror, python_downloads_json_url) = match options {
-            Some(options) => (
-                options.install_mirrors.python_install_mirror,
-                options.install_mirrors.pypy_install_mirror,
-                options.install_mirrors.python_downloads_json_url,
-            ),
-            None => (None, None, None),
-        };
-        let python_mirror = args.mirror.or(python_mirror);
-        let pypy_mirror = args.pypy_mirror.or(pypy_mirror);
-        let python_downloads_json_url =
-            args.python_downloads_json_url.or(python_downloads_json_url);
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+        let hook = filesystem
+            .map(FilesystemOptions::into_options)
+            .and_then(|options| options.python_install_hook);
+        let python_mirror = args.mirror.or(install_mirrors.python_install_mirror);
+        let pypy_mirror = args.pypy_mirror.or(install_mirrors.pypy_install_mirror);
+        let python_downloads_json_url = args
+            .python_downloads_json_url
+            .or(install_mirrors.python_downloads_json_url);
+        let download_client = PythonDownloadClientSettings::new(
+            args.download_client
+                .python_install_keyring_provider
+                .or(install_mirrors.python_install_keyring_provider),
+            args.download_client
+                .python_install_ca_cert
+                .or(install_mirrors.python_install_ca_certs)
+                .unwrap_or_default(),
+        );
         let force = false;
         let default = false;
         let bin = None;
@@ -1059,10 +1970,15 @@ impl PythonUpgradeSettings {
             pypy_mirror: _,
             reinstall,
             python_downloads_json_url: _,
+            download_client: _,
+            channel,
+            minor,
+            apply,
         } = args;
 
         Self {
-            install_dir,
+            install_dir: install_dir.or(install_mirrors.python_install_dir),
+            install_staging_dir: install_mirrors.install_staging_dir,
             targets,
             force,
             registry,
@@ -1070,8 +1986,13 @@ impl PythonUpgradeSettings {
             pypy_install_mirror: pypy_mirror,
             reinstall,
             python_downloads_json_url,
+            download_client,
             default,
             bin,
+            channel,
+            hook: PythonInstallHookSettings::resolve(hook),
+            minor,
+            apply,
         }
     }
 }
@@ -1082,6 +2003,7 @@ pub(crate) struct PythonUninstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) all: bool,
+    pub(crate) force: bool,
 }
 
 impl PythonUninstallSettings {
@@ -1089,18 +2011,51 @@ impl PythonUninstallSettings {
     #[allow(clippy::needless_pass_by_value)]
     pub(crate) fn resolve(
         args: PythonUninstallArgs,
-        _filesystem: Option<FilesystemOptions>,
+        filesystem: Option<FilesystemOptions>,
     ) -> Self {
+        let python_install_dir = resolve_install_mirrors(filesystem.as_ref()).python_install_dir;
         let PythonUninstallArgs {
             install_dir,
             targets,
             all,
+            force,
         } = args;
 
         Self {
-            install_dir,
+            install_dir: install_dir.or(python_install_dir),
             targets,
             all,
+            force,
+        }
+    }
+}
+
+/// The resolved settings to use for a `python pin-install` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonPinInstallSettings {
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) targets: Vec<String>,
+    pub(crate) remove: bool,
+}
+
+impl PythonPinInstallSettings {
+    /// Resolve the [`PythonPinInstallSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(
+        args: PythonPinInstallArgs,
+        filesystem: Option<FilesystemOptions>,
+    ) -> Self {
+        let python_install_dir = resolve_install_mirrors(filesystem.as_ref()).python_install_dir;
+        let PythonPinInstallArgs {
+            install_dir,
+            targets,
+            remove,
+        } = args;
+
+        Self {
+            install_dir: install_dir.or(python_install_dir),
+            targets,
+            remove,
         }
     }
 }
@@ -1110,28 +2065,42 @@ impl PythonUninstallSettings {
 pub(crate) struct PythonFindSettings {
     pub(crate) request: Option<String>,
     pub(crate) show_version: bool,
+    pub(crate) base: bool,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
+    pub(crate) all: bool,
+    pub(crate) output_format: PythonFindFormat,
+    pub(crate) show_failures: bool,
+    pub(crate) python_install_dir: Option<PathBuf>,
 }
 
 impl PythonFindSettings {
     /// Resolve the [`PythonFindSettings`] from the CLI and workspace configuration.
     #[allow(clippy::needless_pass_by_value)]
-    pub(crate) fn resolve(args: PythonFindArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+    pub(crate) fn resolve(args: PythonFindArgs, filesystem: Option<FilesystemOptions>) -> Self {
         let PythonFindArgs {
             request,
             show_version,
+            base,
             no_project,
             system,
             no_system,
             script: _,
+            all,
+            output_format,
+            show_failures,
         } = args;
 
         Self {
             request,
             show_version,
+            base,
             no_project,
             system: flag(system, no_system, "system").unwrap_or_default(),
+            all,
+            output_format,
+            show_failures,
+            python_install_dir: resolve_install_mirrors(filesystem.as_ref()).python_install_dir,
         }
     }
 }
@@ -1160,9 +2129,7 @@ impl PythonPinSettings {
             rm,
         } = args;
 
-        let install_mirrors = filesystem
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         Self {
             request,
@@ -1182,6 +2149,9 @@ pub(crate) struct SyncSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) unlock: bool,
+    pub(crate) strict_python: bool,
+    pub(crate) fail_fast_env: bool,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
     pub(crate) extras: ExtrasSpecification,
@@ -1189,14 +2159,18 @@ pub(crate) struct SyncSettings {
     pub(crate) editable: EditableMode,
     pub(crate) install_options: InstallOptions,
     pub(crate) modifications: Modifications,
+    pub(crate) size_limit: Option<EnvironmentSizeLimit>,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) target: Option<Target>,
+    pub(crate) verify: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) output_format: SyncFormat,
+    pub(crate) export_activate: Vec<ActivationShell>,
 }
 
 impl SyncSettings {
@@ -1227,6 +2201,11 @@ impl SyncSettings {
             active,
             no_active,
             dry_run,
+            unlock,
+            strict_python,
+            fail_fast_env,
+            no_fail_fast_env,
+            exit_code_map: _,
             installer,
             build,
             refresh,
@@ -1235,14 +2214,36 @@ impl SyncSettings {
             script,
             python,
             python_platform,
+            target,
             check,
             no_check,
+            verify,
             output_format,
+            export_activate,
         } = args;
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+
+        let mut export_activate = if export_activate.is_empty() {
+            filesystem
+                .as_ref()
+                .and_then(|fs| fs.export_activate.clone())
+                .unwrap_or_default()
+        } else {
+            export_activate
+        };
+        export_activate.sort_unstable();
+        export_activate.dedup();
+
+        let fail_fast_env = flag(fail_fast_env, no_fail_fast_env, "fail-fast-env")
+            .or_else(|| filesystem.as_ref().and_then(|fs| fs.fail_fast_env))
+            .unwrap_or(false);
+
+        let size_limit = filesystem.as_ref().and_then(|fs| {
+            EnvironmentSizeLimit::from_options(
+                fs.max_environment_size,
+                fs.max_environment_size_action,
+            )
+        });
 
         let settings = ResolverInstallerSettings::combine(
             resolver_installer_options(installer, build),
@@ -1250,7 +2251,7 @@ impl SyncSettings {
         );
 
         let check = flag(check, no_check, "check").unwrap_or_default();
-        let dry_run = if check {
+        let dry_run = if check || verify {
             DryRun::Check
         } else {
             DryRun::from_args(dry_run)
@@ -1261,6 +2262,9 @@ impl SyncSettings {
             locked,
             frozen,
             dry_run,
+            unlock,
+            strict_python,
+            fail_fast_env,
             script,
             active: flag(active, no_active, "active"),
             extras: ExtrasSpecification::from_args(
@@ -1293,13 +2297,17 @@ impl SyncSettings {
             } else {
                 Modifications::Sufficient
             },
+            size_limit,
             all_packages,
             package,
             python: python.and_then(Maybe::into_option),
             python_platform,
+            target: target.map(Target::from),
+            verify,
             refresh: Refresh::from(refresh),
             settings,
             install_mirrors,
+            export_activate,
         }
     }
 }
@@ -1312,6 +2320,7 @@ pub(crate) struct LockSettings {
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) fail_fast_env: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1327,22 +2336,26 @@ impl LockSettings {
             check_exists,
             dry_run,
             script,
+            fail_fast_env,
+            no_fail_fast_env,
             resolver,
             build,
             refresh,
             python,
         } = args;
 
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
+
+        let fail_fast_env = flag(fail_fast_env, no_fail_fast_env, "fail-fast-env")
+            .or_else(|| filesystem.as_ref().and_then(|fs| fs.fail_fast_env))
+            .unwrap_or(false);
 
         Self {
             locked: check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
             script,
+            fail_fast_env,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1359,6 +2372,7 @@ pub(crate) struct AddSettings {
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) unlock: bool,
     pub(crate) packages: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
@@ -1404,6 +2418,7 @@ impl AddSettings {
             tag,
             branch,
             no_sync,
+            unlock,
             locked,
             frozen,
             active,
@@ -1490,10 +2505,7 @@ impl AddSettings {
             }
         }
 
-        let install_mirrors = filesystem
-            .as_ref()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         let bounds = bounds.or(filesystem.as_ref().and_then(|fs| fs.add.add_bounds));
 
@@ -1502,6 +2514,7 @@ impl AddSettings {
             frozen,
             active: flag(active, no_active, "active"),
             no_sync,
+            unlock,
             packages,
             requirements,
             constraints: constraints
@@ -1542,6 +2555,7 @@ pub(crate) struct RemoveSettings {
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) unlock: bool,
     pub(crate) packages: Vec<PackageName>,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
@@ -1562,6 +2576,7 @@ impl RemoveSettings {
             packages,
             group,
             no_sync,
+            unlock,
             locked,
             frozen,
             active,
@@ -1584,10 +2599,7 @@ impl RemoveSettings {
             DependencyType::Production
         };
 
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         let packages = packages
             .into_iter()
@@ -1599,6 +2611,7 @@ impl RemoveSettings {
             frozen,
             active: flag(active, no_active, "active"),
             no_sync,
+            unlock,
             packages,
             dependency_type,
             package,
@@ -1627,6 +2640,7 @@ pub(crate) struct VersionSettings {
     pub(crate) frozen: bool,
     pub(crate) active: Option<bool>,
     pub(crate) no_sync: bool,
+    pub(crate) unlock: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
@@ -1645,6 +2659,7 @@ impl VersionSettings {
             output_format,
             dry_run,
             no_sync,
+            unlock,
             locked,
             frozen,
             active,
@@ -1656,10 +2671,7 @@ impl VersionSettings {
             python,
         } = args;
 
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         Self {
             value,
@@ -1671,6 +2683,7 @@ impl VersionSettings {
             frozen,
             active: flag(active, no_active, "active"),
             no_sync,
+            unlock,
             package,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -1728,10 +2741,7 @@ impl TreeSettings {
             python_platform,
             python,
         } = args;
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         Self {
             groups: DependencyGroups::from_args(
@@ -1782,6 +2792,7 @@ pub(crate) struct ExportSettings {
     pub(crate) include_annotations: bool,
     pub(crate) include_header: bool,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) to_script: Option<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -1826,12 +2837,10 @@ impl ExportSettings {
             build,
             refresh,
             script,
+            to_script,
             python,
         } = args;
-        let install_mirrors = filesystem
-            .clone()
-            .map(|fs| fs.install_mirrors.clone())
-            .unwrap_or_default();
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         Self {
             format,
@@ -1870,6 +2879,7 @@ impl ExportSettings {
             include_annotations: flag(annotate, no_annotate, "annotate").unwrap_or(true),
             include_header: flag(header, no_header, "header").unwrap_or(true),
             script,
+            to_script,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1918,6 +2928,7 @@ pub(crate) struct PipCompileSettings {
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) wsl: Option<WslRequest>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1963,6 +2974,8 @@ impl PipCompileSettings {
             python_platform,
             universal,
             no_universal,
+            wsl,
+            wsl_distribution,
             no_emit_package,
             emit_index_url,
             no_emit_index_url,
@@ -2045,6 +3058,9 @@ impl PipCompileSettings {
             overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            wsl: wsl.then(|| WslRequest {
+                distribution: wsl_distribution,
+            }),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2102,6 +3118,7 @@ pub(crate) struct PipSyncSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) unlock: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2141,6 +3158,7 @@ impl PipSyncSettings {
             strict,
             no_strict,
             dry_run,
+            unlock,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -2156,6 +3174,7 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            unlock,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2203,6 +3222,7 @@ pub(crate) struct PipInstallSettings {
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) unlock: bool,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
@@ -2251,6 +3271,7 @@ impl PipInstallSettings {
             strict,
             no_strict,
             dry_run,
+            unlock,
             torch_backend,
             compat_args: _,
         } = args;
@@ -2314,6 +3335,7 @@ impl PipInstallSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            unlock,
             constraints_from_workspace,
             overrides_from_workspace,
             build_constraints_from_workspace,
@@ -2361,6 +3383,7 @@ pub(crate) struct PipUninstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) unlock: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2379,6 +3402,7 @@ impl PipUninstallSettings {
             target,
             prefix,
             dry_run,
+            unlock,
             compat_args: _,
         } = args;
 
@@ -2386,6 +3410,7 @@ impl PipUninstallSettings {
             package,
             requirements,
             dry_run: DryRun::from_args(dry_run),
+            unlock,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2451,6 +3476,7 @@ pub(crate) struct PipListSettings {
     pub(crate) exclude: Vec<PackageName>,
     pub(crate) format: ListFormat,
     pub(crate) outdated: bool,
+    pub(crate) include_parent: bool,
     pub(crate) settings: PipSettings,
 }
 
@@ -2466,6 +3492,7 @@ impl PipListSettings {
             no_outdated,
             strict,
             no_strict,
+            include_parent,
             fetch,
             python,
             system,
@@ -2478,6 +3505,7 @@ impl PipListSettings {
             exclude,
             format,
             outdated: flag(outdated, no_outdated, "outdated").unwrap_or(false),
+            include_parent,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2651,10 +3679,7 @@ impl BuildSettings {
             resolver,
         } = args;
 
-        let install_mirrors = match &filesystem {
-            Some(fs) => fs.install_mirrors.clone(),
-            None => PythonInstallMirrors::default(),
-        };
+        let install_mirrors = resolve_install_mirrors(filesystem.as_ref());
 
         Self {
             src,
@@ -2691,8 +3716,17 @@ pub(crate) struct VenvSettings {
     pub(crate) path: Option<PathBuf>,
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
+    pub(crate) allow_user_site: bool,
     pub(crate) relocatable: bool,
     pub(crate) no_project: bool,
+    pub(crate) show: bool,
+    pub(crate) rebase: Option<String>,
+    pub(crate) rename_prompt: Option<String>,
+    pub(crate) adopt: Option<PathBuf>,
+    pub(crate) lock_environment: Option<bool>,
+    pub(crate) unlock: bool,
+    pub(crate) set_system_site_packages: Option<bool>,
+    pub(crate) environment_link_mode: Option<LinkMode>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2710,12 +3744,22 @@ impl VenvSettings {
             path,
             prompt,
             system_site_packages,
+            allow_user_site,
             relocatable,
+            python_platform,
             index_args,
             index_strategy,
             keyring_provider,
             exclude_newer,
             no_project,
+            show,
+            rebase,
+            rename_prompt,
+            adopt,
+            lock_environment,
+            unlock_environment,
+            unlock,
+            set_system_site_packages,
             link_mode,
             refresh,
             compat_args: _,
@@ -2729,8 +3773,17 @@ impl VenvSettings {
             path,
             prompt,
             system_site_packages,
+            allow_user_site,
             no_project,
+            show,
+            rebase,
+            rename_prompt,
+            adopt,
+            lock_environment: (lock_environment || unlock_environment).then_some(lock_environment),
+            unlock,
+            set_system_site_packages,
             relocatable,
+            environment_link_mode: link_mode,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2742,6 +3795,7 @@ impl VenvSettings {
                     exclude_newer_package: exclude_newer_package
                         .map(ExcludeNewerPackage::from_iter),
                     link_mode,
+                    python_platform,
                     ..PipOptions::from(index_args)
                 },
                 filesystem,
@@ -2766,7 +3820,7 @@ pub(crate) struct InstallerSettingsRef<'a> {
     pub(crate) extra_build_dependencies: &'a ExtraBuildDependencies,
     pub(crate) extra_build_variables: &'a ExtraBuildVariables,
     pub(crate) exclude_newer: &'a ExcludeNewer,
-    pub(crate) link_mode: LinkMode,
+    pub(crate) link_mode: Option<LinkMode>,
     pub(crate) compile_bytecode: bool,
     pub(crate) reinstall: &'a Reinstall,
     pub(crate) build_options: &'a BuildOptions,
@@ -2788,7 +3842,7 @@ pub(crate) struct ResolverSettings {
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
-    pub(crate) link_mode: LinkMode,
+    pub(crate) link_mode: Option<LinkMode>,
     pub(crate) build_isolation: BuildIsolation,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
@@ -2848,7 +3902,7 @@ impl From<ResolverOptions> for ResolverSettings {
             extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
             extra_build_variables: value.extra_build_variables.unwrap_or_default(),
             exclude_newer: value.exclude_newer,
-            link_mode: value.link_mode.unwrap_or_default(),
+            link_mode: value.link_mode,
             sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
             upgrade: value.upgrade.unwrap_or_default(),
             build_options: BuildOptions::new(
@@ -2933,7 +3987,7 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 index_locations,
                 index_strategy: value.index_strategy.unwrap_or_default(),
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
-                link_mode: value.link_mode.unwrap_or_default(),
+                link_mode: value.link_mode,
                 build_isolation: value.build_isolation.unwrap_or_default(),
                 extra_build_dependencies: value.extra_build_dependencies.unwrap_or_default(),
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
@@ -2997,7 +4051,7 @@ pub(crate) struct PipSettings {
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
     pub(crate) annotation_style: AnnotationStyle,
-    pub(crate) link_mode: LinkMode,
+    pub(crate) link_mode: Option<LinkMode>,
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
@@ -3008,6 +4062,7 @@ pub(crate) struct PipSettings {
 impl PipSettings {
     /// Resolve the [`PipSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(args: PipOptions, filesystem: Option<FilesystemOptions>) -> Self {
+        let python_install_dir = resolve_install_mirrors(filesystem.as_ref()).python_install_dir;
         let Options {
             top_level,
             pip,
@@ -3290,7 +4345,7 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
-            link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
+            link_mode: args.link_mode.combine(link_mode),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
                 args.verify_hashes.combine(verify_hashes),
@@ -3352,7 +4407,10 @@ impl PipSettings {
                     top_level_no_build_package.unwrap_or_default(),
                 )),
             ),
-            install_mirrors,
+            install_mirrors: PythonInstallMirrors {
+                python_install_dir,
+                ..install_mirrors
+            },
         }
     }
 }
@@ -3379,6 +4437,28 @@ impl<'a> From<&'a ResolverInstallerSettings> for InstallerSettingsRef<'a> {
     }
 }
 
+impl<'a> From<&'a PipSettings> for InstallerSettingsRef<'a> {
+    fn from(settings: &'a PipSettings) -> Self {
+        Self {
+            index_locations: &settings.index_locations,
+            index_strategy: settings.index_strategy,
+            keyring_provider: settings.keyring_provider,
+            dependency_metadata: &settings.dependency_metadata,
+            config_setting: &settings.config_setting,
+            config_settings_package: &settings.config_settings_package,
+            build_isolation: &settings.build_isolation,
+            extra_build_dependencies: &settings.extra_build_dependencies,
+            extra_build_variables: &settings.extra_build_variables,
+            exclude_newer: &settings.exclude_newer,
+            link_mode: settings.link_mode,
+            compile_bytecode: settings.compile_bytecode,
+            reinstall: &settings.reinstall,
+            build_options: &settings.build_options,
+            sources: settings.sources,
+        }
+    }
+}
+
 /// The resolved settings to use for an invocation of the `uv publish` CLI.
 #[derive(Debug, Clone)]
 pub(crate) struct PublishSettings {
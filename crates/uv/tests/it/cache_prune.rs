@@ -83,6 +83,45 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
+/// `cache prune --ephemeral` should remove ephemeral environments leaked by killed processes.
+#[test]
+fn prune_ephemeral() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Simulate an ephemeral environment whose owning process was killed, i.e., its marker is
+    // present but unlocked.
+    let builds = context.cache_dir.child("builds-v0");
+    builds.child(".tmpLeaked").child("pyvenv.cfg").touch()?;
+    builds
+        .child(".ephemeral")
+        .child(".tmpLeaked")
+        .write_str("4294967295")?;
+
+    // A directory without a marker (e.g., a source distribution build) should be retained.
+    builds.child(".tmpBuild").child("setup.py").touch()?;
+
+    uv_snapshot!(context.filters(), context.prune().arg("--ephemeral"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed 1 file
+    ");
+
+    builds
+        .child(".tmpLeaked")
+        .assert(predicates::path::missing());
+    builds
+        .child(".ephemeral")
+        .child(".tmpLeaked")
+        .assert(predicates::path::missing());
+    builds.child(".tmpBuild").assert(predicates::path::exists());
+
+    Ok(())
+}
+
 /// `cache prune` should remove all cached environments from the cache.
 #[test]
 fn prune_cached_env() {
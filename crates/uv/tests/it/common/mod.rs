@@ -389,7 +389,7 @@ impl TestContext {
     )?                      # (we allow the patch version to be missing entirely, e.g., in a request)
     (?:(?:a|b|rc)[0-9]+)?   # Pre-release version component, e.g., `a6` or `rc2`
     (?:[td])?               # A short variant, such as `t` (for freethreaded) or `d` (for debug)
-    (?:\+[a-z]+)?           # A long variant, such as `+free-threaded`
+    (?:\+[a-z]+)*           # A long variant, such as `+free-threaded`, or components, e.g., `+pip+tk`
   )
   -
   [a-z0-9]+                 # Operating system (e.g., 'macos')
@@ -1064,6 +1064,66 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python inspect` command with options shared across scenarios.
+    pub fn python_inspect(&self) -> Command {
+        let mut command = Self::new_command();
+        command
+            .arg("python")
+            .arg("inspect")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python env-vars` command with options shared across scenarios.
+    pub fn python_env_vars(&self) -> Command {
+        let mut command = Self::new_command();
+        command
+            .arg("python")
+            .arg("env-vars")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python markers` command with options shared across scenarios.
+    pub fn python_markers(&self) -> Command {
+        let mut command = Self::new_command();
+        command
+            .arg("python")
+            .arg("markers")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python which` command with options shared across scenarios.
+    pub fn python_which(&self) -> Command {
+        let mut command = Self::new_command();
+        command
+            .arg("python")
+            .arg("which")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python resolve` command with options shared across scenarios.
+    pub fn python_resolve(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("python").arg("resolve");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv python validate-downloads` command with options shared across scenarios.
+    pub fn python_validate_downloads(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("python").arg("validate-downloads");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv python install` command with options shared across scenarios.
     pub fn python_install(&self) -> Command {
         let mut command = Self::new_command();
@@ -1080,6 +1140,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python pin-install` command with options shared across scenarios.
+    pub fn python_pin_install(&self) -> Command {
+        let mut command = Self::new_command();
+        self.add_shared_options(&mut command, true);
+        command.arg("python").arg("pin-install");
+        command
+    }
+
     /// Create a `uv python upgrade` command with options shared across scenarios.
     pub fn python_upgrade(&self) -> Command {
         let mut command = Self::new_command();
@@ -1104,6 +1172,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv config where` command with options shared across scenarios.
+    pub fn config_where(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("config").arg("where");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
+    /// Create a `uv python du` command with options shared across scenarios.
+    pub fn python_du(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("python").arg("du");
+        self.add_shared_options(&mut command, true);
+        command
+    }
+
     /// Create a `uv run` command with options shared across scenarios.
     pub fn run(&self) -> Command {
         let mut command = Self::new_command();
@@ -1565,6 +1649,7 @@ pub fn python_installations_for_versions(
                 EnvironmentPreference::OnlySystem,
                 PythonPreference::Managed,
                 &cache,
+                None,
                 Preview::default(),
             ) {
                 python.into_interpreter().sys_executable().to_owned()
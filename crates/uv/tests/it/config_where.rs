@@ -0,0 +1,413 @@
+use anyhow::Result;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{TestContext, uv_snapshot};
+
+#[test]
+fn config_where_python_preference() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [tool.uv]
+        python-preference = "system"
+    "#})?;
+
+    // The project configuration takes precedence over the default.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .env_remove(EnvVars::UV_PYTHON_PREFERENCE), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = system
+
+    Sources, in order of precedence:
+      project configuration `pyproject.toml`: system (used)
+      default: managed
+
+    ----- stderr -----
+    ");
+
+    // The environment variable takes precedence over the project configuration.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .env(EnvVars::UV_PYTHON_PREFERENCE, "only-system"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = only-system
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_PREFERENCE`: only-system (used)
+      project configuration `pyproject.toml`: system
+      default: managed
+
+    ----- stderr -----
+    ");
+
+    // The command-line takes precedence over everything else.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .arg("--managed-python")
+        .env_remove(EnvVars::UV_PYTHON_PREFERENCE), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = only-managed
+
+    Sources, in order of precedence:
+      command-line option `--managed-python`: only-managed (used)
+      project configuration `pyproject.toml`: system
+      default: managed
+
+    ----- stderr -----
+    ");
+
+    // With `--no-config`, the project configuration is ignored.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .arg("--no-config")
+        .env_remove(EnvVars::UV_PYTHON_PREFERENCE), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = managed
+
+    Sources, in order of precedence:
+      default: managed (used)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_python_downloads() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        python-downloads = "manual"
+        python-install-mirror = "https://example.com/python"
+    "#})?;
+
+    // The test context sets `UV_PYTHON_DOWNLOADS=never`, which takes precedence over `uv.toml`.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-downloads"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-downloads = never
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_DOWNLOADS`: never (used)
+      project configuration `uv.toml`: manual
+      default: automatic
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-mirror")
+        .env_remove(EnvVars::UV_PYTHON_INSTALL_MIRROR), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-mirror = https://example.com/python
+
+    Sources, in order of precedence:
+      project configuration `uv.toml`: https://example.com/python (used)
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_managed_python_env() {
+    let context = TestContext::new_with_versions(&[]);
+
+    // A flag read from its environment variable is attributed to the environment variable.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .env_remove(EnvVars::UV_PYTHON_PREFERENCE)
+        .env(EnvVars::UV_MANAGED_PYTHON, "1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = only-managed
+
+    Sources, in order of precedence:
+      environment variable `UV_MANAGED_PYTHON`: only-managed (used)
+      default: managed
+
+    ----- stderr -----
+    ");
+
+    // Unless it's also passed on the command line.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-preference")
+        .arg("--managed-python")
+        .env_remove(EnvVars::UV_PYTHON_PREFERENCE)
+        .env(EnvVars::UV_MANAGED_PYTHON, "1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-preference = only-managed
+
+    Sources, in order of precedence:
+      command-line option `--managed-python`: only-managed (used)
+      default: managed
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+fn config_where_python_query_limits() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        [python-query-limits]
+        timeout = 10
+        concurrency = 2
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-query-limits.timeout")
+        .env(EnvVars::UV_PYTHON_QUERY_TIMEOUT, "30"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-query-limits.timeout = 30
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_QUERY_TIMEOUT`: 30 (used)
+      project configuration `uv.toml`: 10
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-query-limits.concurrency"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-query-limits.concurrency = 2
+
+    Sources, in order of precedence:
+      project configuration `uv.toml`: 2 (used)
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-query-limits.memory-limit"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-query-limits.memory-limit = (none)
+
+    Sources, in order of precedence:
+      default: (none) (used)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_python_install_dir() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [tool.uv]
+        python-install-dir = "python"
+    "#})?;
+
+    // `UV_PYTHON_INSTALL_DIR` takes precedence over the project-level directory.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-dir")
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, "managed"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-dir = managed
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_INSTALL_DIR`: managed (used)
+      project configuration `pyproject.toml`: python
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-dir")
+        .env_remove(EnvVars::UV_PYTHON_INSTALL_DIR), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-dir = python
+
+    Sources, in order of precedence:
+      project configuration `pyproject.toml`: python (used)
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_install_staging_dir() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        install-staging-dir = "staging"
+    "#})?;
+
+    // `UV_PYTHON_INSTALL_STAGING_DIR` takes precedence over the configuration file.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("install-staging-dir")
+        .env(EnvVars::UV_PYTHON_INSTALL_STAGING_DIR, "override"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    install-staging-dir = override
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_INSTALL_STAGING_DIR`: override (used)
+      project configuration `uv.toml`: staging
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_python_install_keyring_provider() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        keyring-provider = "disabled"
+        python-install-keyring-provider = "subprocess"
+    "#})?;
+
+    // The keyring provider for Python downloads is independent of `keyring-provider`.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-keyring-provider"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-keyring-provider = subprocess
+
+    Sources, in order of precedence:
+      project configuration `uv.toml`: subprocess (used)
+      default: (none)
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn config_where_python_install_ca_certs() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        python-install-ca-certs = ["python.mirror.example.com=certs/mirror.pem"]
+    "#})?;
+
+    // The certificates from `UV_PYTHON_INSTALL_CA_CERTS` and the configuration file are merged,
+    // and relative paths are resolved against the directory containing the configuration file.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-ca-certs")
+        .env(EnvVars::UV_PYTHON_INSTALL_CA_CERTS, "proxy.example.com:8443=/etc/ssl/proxy.pem"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-ca-certs = ["proxy.example.com:8443=/etc/ssl/proxy.pem", "python.mirror.example.com=[TEMP_DIR]/certs/mirror.pem"]
+
+    Sources, in order of precedence:
+      environment variable `UV_PYTHON_INSTALL_CA_CERTS`: ["proxy.example.com:8443=/etc/ssl/proxy.pem"] (used)
+      project configuration `uv.toml`: ["python.mirror.example.com=[TEMP_DIR]/certs/mirror.pem"] (used)
+      default: (none)
+
+    ----- stderr -----
+    "#);
+
+    // Invalid entries in the environment variable are ignored with a warning.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-install-ca-certs")
+        .env(EnvVars::UV_PYTHON_INSTALL_CA_CERTS, "/etc/ssl/proxy.pem"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-install-ca-certs = ["python.mirror.example.com=[TEMP_DIR]/certs/mirror.pem"]
+
+    Sources, in order of precedence:
+      project configuration `uv.toml`: ["python.mirror.example.com=[TEMP_DIR]/certs/mirror.pem"] (used)
+      default: (none)
+
+    ----- stderr -----
+    warning: Ignoring invalid value for `UV_PYTHON_INSTALL_CA_CERTS`: expected `<host>=<path>`, found `/etc/ssl/proxy.pem`
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn config_where_python_discovery_exclude() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    let config = context.temp_dir.child("config.toml");
+    config.write_str(indoc! {r#"
+        [python-discovery]
+        exclude = ["/opt/broken/*"]
+    "#})?;
+
+    // The exclusions of every configuration file are merged.
+    uv_snapshot!(context.filters(), context.config_where()
+        .arg("--key").arg("python-discovery.exclude")
+        .arg("--config-file").arg(config.path()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    python-discovery.exclude = ["/opt/broken/*"]
+
+    Sources, in order of precedence:
+      `--config-file` configuration `config.toml`: ["/opt/broken/*"] (used)
+      default: (none)
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
@@ -585,7 +585,7 @@ fn requirements_txt_dependency_conflicting_markers() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
             resolution-markers = [
                 "sys_platform == 'darwin'",
@@ -1508,7 +1508,7 @@ fn requirements_txt_non_project_fork() -> Result<()> {
             insta::assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
             resolution-markers = [
                 "sys_platform == 'win32'",
@@ -2480,7 +2480,7 @@ fn requirements_txt_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
         resolution-markers = [
             "sys_platform == 'win32'",
@@ -2599,7 +2599,7 @@ fn requirements_txt_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
         resolution-markers = [
             "sys_platform == 'win32'",
@@ -4467,3 +4467,105 @@ fn no_editable_env_var() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn export_to_script() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    context.lock().assert().success();
+
+    // Stale dependencies are replaced with the locked packages; the project itself is omitted.
+    let script = context.temp_dir.child("app.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = ["requests"]
+        # ///
+
+        import anyio
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.export().arg("--to-script").arg("app.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Exported 3 dependencies to `app.py`
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("app.py"), @r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = [
+        #     "anyio==3.7.0",
+        #     "idna==3.6",
+        #     "sniffio==1.3.1",
+        # ]
+        # ///
+
+        import anyio
+        "#
+        );
+    });
+
+    // Exporting again is a no-op.
+    uv_snapshot!(context.filters(), context.export().arg("--to-script").arg("app.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The dependencies of `app.py` are up-to-date
+    ");
+
+    // A script without inline metadata is initialized from the lockfile's `requires-python`.
+    let script = context.temp_dir.child("bare.py");
+    script.write_str("import anyio\n")?;
+
+    context
+        .export()
+        .arg("--to-script")
+        .arg("bare.py")
+        .assert()
+        .success();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            context.read("bare.py"), @r#"
+        # /// script
+        # requires-python = ">=3.12"
+        # dependencies = [
+        #     "anyio==3.7.0",
+        #     "idna==3.6",
+        #     "sniffio==1.3.1",
+        # ]
+        # ///
+        import anyio
+        "#
+        );
+    });
+
+    Ok(())
+}
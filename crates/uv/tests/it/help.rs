@@ -33,6 +33,7 @@ fn help() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -62,6 +63,8 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -114,6 +117,7 @@ fn help_flag() {
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Inspect uv's configuration
       self     Manage the uv executable
       help     Display documentation for a command
 
@@ -142,6 +146,8 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -193,6 +199,7 @@ fn help_short_flag() {
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
       cache    Manage uv's cache
+      config   Inspect uv's configuration
       self     Manage the uv executable
       help     Display documentation for a command
 
@@ -221,6 +228,8 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -397,6 +406,17 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors.
+              
+              With `json`, errors are written to stderr as a single line of JSON, including a stable
+              diagnostic code and, where available, the declarations that caused the error (e.g., the
+              package and dependency group). This option is intended for editors and other tools.
+
+              Possible values:
+              - text: Report errors in a human-readable format
+              - json: Report errors as JSON, with a stable diagnostic code for each error
+
           --no-progress
               Hide all progress outputs.
               
@@ -664,6 +684,17 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors.
+              
+              With `json`, errors are written to stderr as a single line of JSON, including a stable
+              diagnostic code and, where available, the declarations that caused the error (e.g., the
+              package and dependency group). This option is intended for editors and other tools.
+
+              Possible values:
+              - text: Report errors in a human-readable format
+              - json: Report errors as JSON, with a stable diagnostic code for each error
+
           --no-progress
               Hide all progress outputs.
               
@@ -765,6 +796,8 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -846,6 +879,8 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -891,6 +926,7 @@ fn help_unknown_subcommand() {
         build
         publish
         cache
+        config
         self
         generate-shell-completion
     ");
@@ -919,6 +955,7 @@ fn help_unknown_subcommand() {
         build
         publish
         cache
+        config
         self
         generate-shell-completion
     ");
@@ -976,6 +1013,7 @@ fn help_with_global_option() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1005,6 +1043,8 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1098,6 +1138,7 @@ fn help_with_no_pager() {
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
       cache                      Manage uv's cache
+      config                     Inspect uv's configuration
       self                       Manage the uv executable
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
@@ -1127,6 +1168,8 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --diagnostics <DIAGNOSTICS>
+              The format in which to report errors [possible values: text, json]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -17,6 +17,8 @@ mod cache_clean;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+mod config_where;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -90,9 +92,13 @@ mod python_install;
 #[cfg(feature = "python")]
 mod python_pin;
 
+mod python_resolve;
+
 #[cfg(feature = "python-managed")]
 mod python_upgrade;
 
+mod python_validate_downloads;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod run;
 
@@ -7699,6 +7699,28 @@ fn no_strip_extras() -> Result<()> {
     Ok(())
 }
 
+/// Resolving for the Python interpreter in WSL is only supported on Windows.
+#[test]
+#[cfg(not(windows))]
+fn compile_wsl_unsupported() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--wsl"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Resolving for a Python interpreter in WSL is only supported on Windows
+    ");
+
+    Ok(())
+}
+
 /// Resolve a package with `--no-strip-markers`.
 #[test]
 fn no_strip_markers() -> Result<()> {
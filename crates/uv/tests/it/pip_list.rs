@@ -1,4 +1,5 @@
 use anyhow::Result;
+use assert_cmd::prelude::*;
 use assert_fs::fixture::ChildPath;
 use assert_fs::fixture::FileWriteStr;
 use assert_fs::fixture::PathChild;
@@ -788,3 +789,68 @@ fn list_ignores_quiet_flag_format_freeze() {
     "###
     );
 }
+
+#[test]
+#[cfg(feature = "pypi")]
+fn list_include_parent() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Install packages into the parent environment.
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("markupsafe==2.1.3")
+        .assert()
+        .success();
+
+    // Create a child environment that extends the parent, with a package that shadows one from
+    // the parent.
+    context.venv().arg("child").assert().success();
+    let pyvenv_cfg = context.temp_dir.child("child").child("pyvenv.cfg");
+    let content = fs_err::read_to_string(&pyvenv_cfg)?;
+    pyvenv_cfg.write_str(&format!(
+        "{content}extends-environment = {}\n",
+        context.venv.display()
+    ))?;
+    context
+        .pip_install()
+        .arg("--python")
+        .arg("child")
+        .arg("markupsafe==2.1.5")
+        .assert()
+        .success();
+
+    // By default, only the child's packages are listed.
+    uv_snapshot!(context.filters(), context.pip_list()
+        .arg("--python")
+        .arg("child")
+        .arg("--format=freeze"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.5
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: child
+    "
+    );
+
+    // With `--include-parent`, the parent's packages are included, but shadowed by the child's.
+    uv_snapshot!(context.filters(), context.pip_list()
+        .arg("--python")
+        .arg("child")
+        .arg("--include-parent")
+        .arg("--format=freeze"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+    markupsafe==2.1.5
+
+    ----- stderr -----
+    Using Python 3.12.[X] environment at: child
+    "
+    );
+
+    Ok(())
+}
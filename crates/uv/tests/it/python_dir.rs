@@ -1,4 +1,5 @@
-use assert_fs::fixture::PathChild;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::indoc;
 
 use uv_static::EnvVars;
 
@@ -19,3 +20,83 @@ fn python_dir() {
     ----- stderr -----
     "###);
 }
+
+#[test]
+fn python_dir_project() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let python_dir = context.temp_dir.child("python");
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv]
+        python-install-dir = ".uv/pythons"
+    "#})?;
+
+    // Within the project, the project-level directory is used.
+    uv_snapshot!(context.filters(), context.python_dir()
+    .arg("--project").arg("project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/project/.uv/pythons
+
+    ----- stderr -----
+    ");
+
+    // `UV_PYTHON_INSTALL_DIR` takes precedence over the project-level directory.
+    uv_snapshot!(context.filters(), context.python_dir()
+    .arg("--project").arg("project")
+    .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/python
+
+    ----- stderr -----
+    ");
+
+    // Outside of the project, the user-level directory is used.
+    uv_snapshot!(context.filters(), context.python_dir()
+    .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/python
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn python_dir_user_configuration() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+
+    let xdg = context.temp_dir.child("xdg");
+    xdg.child("uv").child("uv.toml").write_str(indoc! {r#"
+        python-install-dir = "pythons"
+    "#})?;
+
+    let python_dir = context.temp_dir.child("python");
+
+    // The setting is ignored in user-level configuration.
+    uv_snapshot!(context.filters(), context.python_dir()
+    .env(EnvVars::XDG_CONFIG_HOME, xdg.as_os_str())
+    .env(EnvVars::UV_PYTHON_INSTALL_DIR, python_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/python
+
+    ----- stderr -----
+    warning: Ignoring `python-install-dir` in `[TEMP_DIR]/xdg/uv/uv.toml`: it is only supported in project-level configuration (use `UV_PYTHON_INSTALL_DIR` to change the user-level directory)
+    ");
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
+use assert_cmd::prelude::*;
 use assert_fs::prelude::{FileTouch, PathChild};
 use assert_fs::{fixture::FileWriteStr, prelude::PathCreateDir};
 use indoc::indoc;
+use predicates::prelude::predicate;
 
 use uv_platform::{Arch, Os};
 use uv_static::EnvVars;
@@ -139,6 +141,34 @@ fn python_find() {
     "###);
 }
 
+#[test]
+fn python_find_all() {
+    let context: TestContext =
+        TestContext::new_with_versions(&["3.11", "3.12"]).with_filtered_python_sources();
+
+    // All matching interpreters are shown, in the order they would be selected
+    uv_snapshot!(context.filters(), context.python_find().arg("--all").arg(">=3.11"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    VERSION    VARIANT    SOURCE         PATH
+    3.11.11    default    search path    [PYTHON-3.11]
+    3.12.9     default    search path    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // No matching interpreters
+    uv_snapshot!(context.filters(), context.python_find().arg("--all").arg("3.13"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.13 in [PYTHON SOURCES]
+    ");
+}
+
 #[test]
 fn python_find_pin() {
     let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
@@ -599,6 +629,46 @@ fn python_find_venv() {
     }
 }
 
+/// With `UV_VENV_REFUSE_LINKS`, an active virtual environment behind a symbolic link is ignored.
+#[test]
+#[cfg(unix)]
+fn python_find_venv_refuse_links() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let link = context.temp_dir.child("link");
+    fs_err::os::unix::fs::symlink(&context.venv, &link)?;
+
+    // By default, the environment is used through the link.
+    uv_snapshot!(context.filters(), context.python_find().env(EnvVars::VIRTUAL_ENV, link.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/link/[BIN]/[PYTHON]
+
+    ----- stderr -----
+    ");
+
+    // If links are refused, `VIRTUAL_ENV` is ignored, and the environment in the working
+    // directory is used instead.
+    uv_snapshot!(context.filters(), context.python_find().env(EnvVars::VIRTUAL_ENV, link.as_os_str()).env(EnvVars::UV_VENV_REFUSE_LINKS, "1"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/[BIN]/[PYTHON]
+
+    ----- stderr -----
+    warning: Ignoring `VIRTUAL_ENV`: Refusing to use virtual environment `[TEMP_DIR]/link`: `[TEMP_DIR]/link` is a symbolic link, junction, or other reparse point (`UV_VENV_REFUSE_LINKS` is set)
+    ");
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[test]
 fn python_find_unsupported_version() {
@@ -799,6 +869,442 @@ fn python_find_managed() {
     ");
 }
 
+#[test]
+fn python_find_env_vars() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    // Environment variables in version files are expanded.
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("${UV_TEST_PYTHON_VERSION}")
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find().env("UV_TEST_PYTHON_VERSION", "3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // Unset variables are an error.
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to read Python version file `.python-version`: Environment variable `UV_TEST_PYTHON_VERSION` is not set; use `$${UV_TEST_PYTHON_VERSION}` to include `${UV_TEST_PYTHON_VERSION}` literally
+    ");
+
+    fs_err::remove_file(context.temp_dir.child(".python-version")).unwrap();
+
+    // As are unset variables in mirror settings.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(r#"python-install-mirror = "${UV_TEST_MIRROR}/python""#)
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to expand environment variables in: `uv.toml`
+      Caused by: Environment variable `UV_TEST_MIRROR` is not set; use `$${UV_TEST_MIRROR}` to include `${UV_TEST_MIRROR}` literally
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().env("UV_TEST_MIRROR", "https://mirror.example.com"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+}
+
+/// `--base` resolves virtual environments, including chained ones, to the base interpreter.
+#[cfg(unix)]
+#[test]
+fn python_find_base() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Create a virtual environment, and a second virtual environment from the first.
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+    let chained = context.temp_dir.child("chained");
+    std::process::Command::new(venv_bin_path(&context.venv).join("python"))
+        .arg("-m")
+        .arg("venv")
+        .arg("--without-pip")
+        .arg(chained.path())
+        .assert()
+        .success();
+
+    // Without `--base`, the virtual environment is shown.
+    uv_snapshot!(context.filters(), context.python_find().env(EnvVars::VIRTUAL_ENV, chained.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/chained/bin/python
+
+    ----- stderr -----
+    ");
+
+    // With `--base`, the interpreter is outside of both virtual environments.
+    let output = context
+        .python_find()
+        .arg("--base")
+        .env(EnvVars::VIRTUAL_ENV, chained.path())
+        .output()?;
+    assert!(output.status.success());
+    let base = String::from_utf8(output.stdout)?;
+    assert!(
+        !base
+            .trim()
+            .starts_with(&*context.temp_dir.path().to_string_lossy())
+    );
+
+    let output = std::process::Command::new(base.trim())
+        .arg("-c")
+        .arg("import sys; print(sys.prefix == sys.base_prefix)")
+        .output()?;
+    assert_eq!(String::from_utf8(output.stdout)?.trim(), "True");
+
+    // The version of the base interpreter can be shown, too.
+    uv_snapshot!(context.filters(), context.python_find().arg("--base").arg("--show-version").env(EnvVars::VIRTUAL_ENV, chained.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12.[X]
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn python_inspect() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"])
+        .with_filtered_python_symlinks()
+        .with_filtered_python_keys();
+
+    // The ABI varies by platform.
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"libc: .*", "libc: [LIBC]"),
+            (r"soabi: .*", "soabi: [SOABI]"),
+            (r"ext_suffix: .*", "ext_suffix: [EXT_SUFFIX]"),
+            (r#""libc":"[^"]*""#, r#""libc":"[LIBC]""#),
+            (r#""soabi":(null|"[^"]*")"#, r#""soabi":"[SOABI]""#),
+            (
+                r#""ext_suffix":(null|"[^"]*")"#,
+                r#""ext_suffix":"[EXT_SUFFIX]""#,
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    // The test interpreters are python-build-standalone distributions, which include all of the
+    // optional features.
+    uv_snapshot!(filters, context.python_inspect().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.[X]-[PLATFORM]
+    path: [PYTHON-3.12]
+    libc: [LIBC]
+    soabi: [SOABI]
+    ext_suffix: [EXT_SUFFIX]
+    ssl: available
+    sqlite3: available
+    lzma: available
+    bz2: available
+    zlib: available
+    ctypes: available
+    tkinter: available
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(filters, context.python_inspect().arg("3.12").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"key":"cpython-3.12.[X]-[PLATFORM]","version":"3.12.[X]","implementation":"cpython","path":"[PYTHON-3.12]","libc":"[LIBC]","soabi":"[SOABI]","ext_suffix":"[EXT_SUFFIX]","features":["ssl","sqlite3","lzma","bz2","zlib","ctypes","tkinter"],"missing_features":[]}
+
+    ----- stderr -----
+    "#);
+
+    // Requests with features are satisfied by interpreters that include them.
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12+ssl+sqlite3"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+}
+
+#[test]
+#[cfg(unix)]
+fn python_env_vars() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // The search paths are read from the interpreter's `sysconfig` data.
+    let output = context
+        .python_command()
+        .arg("-c")
+        .arg("import sysconfig; print(sysconfig.get_path('include')); print(sysconfig.get_config_var('LIBDIR'))")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let (include, libdir) = stdout.trim().split_once('\n').unwrap();
+
+    let output = context
+        .python_env_vars()
+        .arg("3.12")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+    let variables: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)?;
+    let variables = variables
+        .iter()
+        .map(|variable| {
+            (
+                variable["name"].as_str().unwrap(),
+                fs_err::canonicalize(variable["path"].as_str().unwrap()).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        variables,
+        [
+            ("CPATH", fs_err::canonicalize(include)?),
+            ("LIBRARY_PATH", fs_err::canonicalize(libdir)?),
+            (
+                "PKG_CONFIG_PATH",
+                fs_err::canonicalize(std::path::Path::new(libdir).join("pkgconfig"))?
+            ),
+        ]
+    );
+
+    // The shell commands prepend each entry to the existing value.
+    let output = context
+        .python_env_vars()
+        .arg("3.12")
+        .env(EnvVars::BASH_VERSION, "5.2")
+        .env_remove(EnvVars::NU_VERSION)
+        .env_remove(EnvVars::FISH_VERSION)
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.starts_with("export LIBRARY_PATH=\"")
+                && line.ends_with("${LIBRARY_PATH:+:$LIBRARY_PATH}\"")),
+        "{stdout}"
+    );
+
+    // Shells without a syntax for prepending to a variable are rejected.
+    uv_snapshot!(context.filters(), context.python_env_vars().arg("3.12").env(EnvVars::NU_VERSION, "0.100.0"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Setting environment variables is not supported in Nushell; use `--output-format json` instead
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn python_markers() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Synthesize the markers for Python 3.11 on ARM Linux. The platform markers are taken from the
+    // target, and the `implementation_version` follows the Python version for CPython.
+    uv_snapshot!(context.filters(), context
+        .python_markers()
+        .arg("--platform")
+        .arg("linux-aarch64")
+        .arg("--python-version")
+        .arg("3.11"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    implementation_name: "cpython"
+    implementation_version: "3.11"
+    os_name: "posix"
+    platform_machine: "aarch64"
+    platform_python_implementation: "CPython"
+    platform_release: ""
+    platform_system: "Linux"
+    platform_version: ""
+    python_full_version: "3.11"
+    python_version: "3.11"
+    sys_platform: "linux"
+
+    ----- stderr -----
+    "#);
+
+    // Target triples are accepted too, and `--python` selects the base interpreter.
+    uv_snapshot!(context.filters(), context
+        .python_markers()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--python-platform")
+        .arg("aarch64-unknown-linux-gnu")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"implementation_name":"cpython","implementation_version":"3.11","os_name":"posix","platform_machine":"aarch64","platform_python_implementation":"CPython","platform_release":"","platform_system":"Linux","platform_version":"","python_full_version":"3.11","python_version":"3.11","sys_platform":"linux"}
+
+    ----- stderr -----
+    "#);
+
+    // The PyPy version can't be inferred from a CPython interpreter, so a PyPy interpreter is
+    // required as the base.
+    uv_snapshot!(context.filters(), context
+        .python_markers()
+        .arg("--platform")
+        .arg("linux-aarch64")
+        .arg("--python-version")
+        .arg("3.11")
+        .arg("--implementation")
+        .arg("pypy"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for PyPy 3.11 in [PYTHON SOURCES]
+    ");
+
+    // Unknown platforms are rejected.
+    uv_snapshot!(context.filters(), context
+        .python_markers()
+        .arg("--platform")
+        .arg("solaris-x86_64"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'solaris-x86_64' for '--python-platform <PYTHON_PLATFORM>': `solaris-x86_64` is not a target triple or a platform in the form `<os>-<arch>[-<libc>]`
+
+    For more information, try '--help'.
+    ");
+}
+
+#[test]
+#[cfg(unix)]
+fn python_which() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Add a script to the environment that runs with the environment's interpreter.
+    let script = context.venv.join("bin").join("example");
+    fs_err::write(
+        &script,
+        format!(
+            "#!{}\nprint('example')\n",
+            context.venv.join("bin").join("python3").display()
+        ),
+    )?;
+    fs_err::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.python_which().arg("example"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    path: [VENV]/bin/example
+    source: base
+    interpreter: [VENV]/bin/python3
+    environment: virtual environment (`[VENV]/`)
+
+    ----- stderr -----
+    ");
+
+    // Executables that aren't found are reported.
+    uv_snapshot!(context.filters(), context.python_which().arg("missing"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to find `missing` in the environment or on the `PATH`
+    ");
+
+    Ok(())
+}
+
+/// With `--with`, the requirements are layered atop the base environment, as in `uv run --with`.
+#[test]
+#[cfg(unix)]
+fn python_which_with() {
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\[CACHE_DIR\]/[^\s`]+/bin/", "[CACHE_DIR]/[ENV]/bin/"),
+            (r"\[CACHE_DIR\]/[^\s`]+`", "[CACHE_DIR]/[ENV]`"),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.python_which().arg("--with").arg("black==24.2.0").arg("black"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    path: [CACHE_DIR]/[ENV]/bin/black
+    source: overlay
+    interpreter: [CACHE_DIR]/[ENV]/bin/python
+    environment: ephemeral environment (`[CACHE_DIR]/[ENV]`)
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    ");
+
+    // Without `--with`, the command isn't found.
+    uv_snapshot!(filters, context.python_which().arg("black"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to find `black` in the environment or on the `PATH`
+    ");
+}
+
 /// See: <https://github.com/astral-sh/uv/issues/11825>
 ///
 /// This test will not succeed on macOS if using a Homebrew provided interpreter. The interpreter
@@ -1072,3 +1578,269 @@ fn python_find_path() {
     error: No interpreter found at path `foobar`
     ");
 }
+
+#[test]
+fn python_find_discovery_filter() -> anyhow::Result<()> {
+    let context: TestContext =
+        TestContext::new_with_versions(&["3.11", "3.12"]).with_filtered_python_sources();
+
+    // Exclude the Python 3.11 interpreter from discovery.
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        [python-discovery]
+        exclude = ["**/cpython-3.11*/**"]
+    "#})?;
+
+    // The first interpreter on the path is skipped.
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().arg("3.11"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.11 in [PYTHON SOURCES]
+    ");
+
+    // An interpreter requested by path is still allowed.
+    let python = context
+        .python_versions
+        .iter()
+        .find(|(version, _)| version.minor() == 11)
+        .map(|(_, executable)| executable.clone())
+        .unwrap();
+    context.python_find().arg(&python).assert().success();
+
+    // Only allow interpreters matching the `include-only` patterns.
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        [python-discovery]
+        include-only = ["**/cpython-3.11*/**"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in [PYTHON SOURCES]
+    ");
+
+    // Invalid patterns are rejected.
+    context.temp_dir.child("uv.toml").write_str(indoc! {r#"
+        [python-discovery]
+        exclude = ["[python"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.python_find(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Invalid pattern in `python-discovery.exclude`: `[python`
+      Caused by: error parsing glob '[python': unclosed character class; missing ']'
+    ");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn python_find_query_timeout() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Create an interpreter that is slow to start.
+    let python = context.temp_dir.child("slow").child("python");
+    python.write_str(&format!(
+        indoc! {r#"
+            #!/bin/sh
+            sleep 3
+            exec "{}" "$@"
+        "#},
+        context.python_versions[0].1.display()
+    ))?;
+    fs_err::set_permissions(python.path(), std::fs::Permissions::from_mode(0o755))?;
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r"
+        [python-query-limits]
+        timeout = 1
+    "})?;
+
+    // The query is terminated after the configured timeout.
+    context
+        .python_find()
+        .arg(python.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("did not complete within 1s"));
+
+    // The environment variable takes precedence over the configuration file.
+    context
+        .python_find()
+        .arg(python.path())
+        .env(EnvVars::UV_PYTHON_QUERY_TIMEOUT, "0")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn python_find_query_env() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Create an interpreter that can't start without an environment variable.
+    let python = context.temp_dir.child("custom").child("python");
+    python.write_str(&format!(
+        indoc! {r#"
+            #!/bin/sh
+            if [ "$CUSTOM_PYTHON_READY" != "1" ]; then
+                echo "missing CUSTOM_PYTHON_READY" >&2
+                exit 1
+            fi
+            exec "{}" "$@"
+        "#},
+        context.python_versions[0].1.display()
+    ))?;
+    fs_err::set_permissions(python.path(), std::fs::Permissions::from_mode(0o755))?;
+
+    // Without the variable, the query fails.
+    context.python_find().arg(python.path()).assert().failure();
+
+    context.temp_dir.child("uv.toml").write_str(&format!(
+        indoc! {r#"
+            [python-query-env."{}"]
+            CUSTOM_PYTHON_READY = "1"
+        "#},
+        python.path().display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.python_find().arg(python.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // The variable is also applied when the interpreter is invoked.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg(python.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import os; print(os.environ['CUSTOM_PYTHON_READY'])"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// pyenv shims are resolved with `pyenv which`, and the resolution is cached until the version
+/// selection changes.
+#[test]
+#[cfg(unix)]
+fn python_find_pyenv_shim() -> anyhow::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context: TestContext = TestContext::new_with_versions(&["3.12"]);
+
+    // Create a fake `pyenv` that records each invocation.
+    let root = context.temp_dir.child("pyenv");
+    let log = context.temp_dir.child("pyenv.log");
+    let pyenv = root.child("libexec").child("pyenv");
+    pyenv.write_str(&format!(
+        indoc! {r#"
+            #!/bin/sh
+            echo "$@" >> "{}"
+            if [ "$1" = "which" ] && [ "$2" = "python" ]; then
+                echo "{}"
+                exit 0
+            fi
+            exit 1
+        "#},
+        log.path().display(),
+        context.python_versions[0].1.display()
+    ))?;
+    fs_err::set_permissions(pyenv.path(), std::fs::Permissions::from_mode(0o755))?;
+
+    // Create a shim that delegates to the fake `pyenv`.
+    let shims = root.child("shims");
+    let shim = shims.child("python");
+    shim.write_str(&format!(
+        indoc! {r#"
+            #!/usr/bin/env bash
+            set -e
+            [ -n "$PYENV_DEBUG" ] && set -x
+
+            program="${{0##*/}}"
+
+            export PYENV_ROOT="{}"
+            exec "{}" exec "$program" "$@"
+        "#},
+        root.path().display(),
+        pyenv.path().display()
+    ))?;
+    fs_err::set_permissions(shim.path(), std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), context.python_find().env(EnvVars::UV_TEST_PYTHON_PATH, shims.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+    assert_eq!(fs_err::read_to_string(log.path())?, "which python\n");
+
+    // The resolution is cached across invocations.
+    context
+        .python_find()
+        .env(EnvVars::UV_TEST_PYTHON_PATH, shims.path())
+        .assert()
+        .success();
+    assert_eq!(fs_err::read_to_string(log.path())?, "which python\n");
+
+    // Changing the selected version invalidates the cached resolution.
+    context
+        .python_find()
+        .env(EnvVars::UV_TEST_PYTHON_PATH, shims.path())
+        .env(EnvVars::PYENV_VERSION, "3.12")
+        .assert()
+        .success();
+    assert_eq!(
+        fs_err::read_to_string(log.path())?,
+        "which python\nwhich python\n"
+    );
+
+    Ok(())
+}
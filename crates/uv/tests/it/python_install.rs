@@ -138,6 +138,60 @@ fn python_install() {
     bin_python.assert(predicate::path::missing());
 }
 
+#[test]
+fn python_du() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_python_download_cache()
+        .with_filter((
+            r"\d+(\.\d+)?(B|KiB|MiB|GiB)".to_string(),
+            "[SIZE]".to_string(),
+        ));
+
+    // Nothing is installed yet
+    uv_snapshot!(context.filters(), context.python_du(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No managed Python installations found in [TEMP_DIR]/managed
+    ");
+
+    uv_snapshot!(context.filters(), context.python_du().arg("--output-format").arg("json"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    ");
+
+    context.python_install().arg("3.13").assert().success();
+
+    uv_snapshot!(context.filters(), context.python_du(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.13.7-[PLATFORM]    [SIZE]
+
+    ----- stderr -----
+    ");
+
+    // Requests that match no installation report nothing
+    uv_snapshot!(context.filters(), context.python_du().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No managed Python installations found in [TEMP_DIR]/managed
+    ");
+}
+
 #[test]
 fn python_reinstall() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -1189,6 +1243,73 @@ fn python_install_freethreaded() {
     ");
 }
 
+/// Optional components that aren't selected are removed from the installation, but `pip` is always
+/// kept.
+#[test]
+#[cfg(unix)]
+fn python_install_components() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--with-tk"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     + cpython-3.12.11+pip+tk-[PLATFORM] (python3.12)
+    ");
+
+    let installation = fs_err::read_dir(context.temp_dir.child("managed"))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("cpython-3.12.11+pip+tk-"))
+        })
+        .expect("the installation should exist");
+    let stdlib = installation.join("lib").join("python3.12");
+
+    // The selected components are kept, along with `pip`.
+    assert!(stdlib.join("tkinter").is_dir());
+    assert!(stdlib.join("site-packages").join("pip").is_dir());
+
+    // The test suite is removed.
+    assert!(!stdlib.join("test").exists());
+
+    // Add name filtering for the `find` tests.
+    let context = context
+        .with_filtered_python_install_bin()
+        .with_filtered_python_names();
+
+    // The installation satisfies requests for the components it includes...
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12+pip+tk"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/managed/cpython-3.12.11+pip+tk-[PLATFORM]/[INSTALL-BIN]/[PYTHON]
+
+    ----- stderr -----
+    ");
+
+    // ...but not those it doesn't.
+    uv_snapshot!(context.filters(), context.python_find().arg("3.12+tests").env(EnvVars::UV_PYTHON_DOWNLOADS, "never"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12+tests in [PYTHON SOURCES]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_install_invalid_request() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -2150,6 +2271,58 @@ fn python_install_314() {
     ");
 }
 
+/// The release channel is recorded with the installation, and followed by upgrades.
+#[test]
+fn python_install_channel() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs()
+        .with_filtered_exe_suffix()
+        .with_python_download_cache();
+
+    // Read the channel recorded for the installation of the given version.
+    let channel = |version: &str| -> anyhow::Result<String> {
+        let prefix = format!("cpython-{version}-");
+        let installation = fs_err::read_dir(context.temp_dir.child("managed"))?
+            .filter_map(Result::ok)
+            .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+            .expect("the version is installed");
+        Ok(fs_err::read_to_string(
+            installation.path().join("uv-channel"),
+        )?)
+    };
+
+    // The stable channel never includes pre-releases.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.14").arg("--channel").arg("stable"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No download found for request `3.14` in the `stable` channel
+    ");
+
+    context
+        .python_install()
+        .arg("3.14.0b4")
+        .arg("--channel")
+        .arg("beta")
+        .assert()
+        .success();
+    assert_eq!(channel("3.14.0b4")?, "beta\n");
+
+    // The upgrade follows the recorded channel, which is recorded for the new installation, too.
+    context
+        .python_upgrade()
+        .arg("--preview")
+        .arg("3.14")
+        .assert()
+        .success();
+    assert_eq!(channel("3.14.0rc2")?, "beta\n");
+
+    Ok(())
+}
+
 /// A duplicate of [`python_install`] with an isolated `UV_PYTHON_CACHE_DIR`.
 ///
 /// See also, [`python_install_no_cache`].
@@ -2246,6 +2419,262 @@ fn python_install_cached() {
     ");
 }
 
+/// Download Python into an isolated `UV_PYTHON_CACHE_DIR`, then install it from the cache.
+#[test]
+fn python_install_only_download() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    // Skip this test if the developer has set `UV_PYTHON_CACHE_DIR` locally since it's slow
+    if env::var_os(EnvVars::UV_PYTHON_CACHE_DIR).is_some() && env::var_os(EnvVars::CI).is_none() {
+        debug!("Skipping test because `UV_PYTHON_CACHE_DIR` is set");
+        return;
+    }
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let python_cache = context.temp_dir.child("python-cache");
+
+    // The cache directory is required
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("--only-download")
+        .arg("3.13"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--only-download` requires `UV_PYTHON_CACHE_DIR` to be set
+    ");
+
+    // Download the archive, without installing it
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("--only-download")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Downloaded Python 3.13.7 in [TIME]
+     + cpython-3.13.7-[PLATFORM]
+    ");
+
+    let bin_python = context
+        .bin_dir
+        .child(format!("python3.13{}", std::env::consts::EXE_SUFFIX));
+    bin_python.assert(predicate::path::missing());
+
+    // Should be a no-op when already downloaded
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("--only-download")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Python is already downloaded
+    ");
+
+    // Install from the cache
+    uv_snapshot!(context.filters(), context
+        .python_install()
+        .arg("--from-cache")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.7 in [TIME]
+     + cpython-3.13.7-[PLATFORM] (python3.13)
+    ");
+    bin_python.assert(predicate::path::exists());
+
+    // 3.12 isn't cached, so it can't be installed from the cache
+    context
+        .python_install()
+        .arg("--from-cache")
+        .arg("3.12")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "An offline Python installation was requested",
+        ));
+
+    // A corrupted archive is rejected
+    for entry in fs_err::read_dir(&python_cache).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_file() {
+            fs_err::write(path, "corrupted").unwrap();
+        }
+    }
+    context
+        .python_install()
+        .arg("--from-cache")
+        .arg("--reinstall")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "does not match the expected checksum",
+        ));
+}
+
+/// With `--atomic`, a failure to install any version removes the other versions again.
+#[test]
+fn python_install_atomic() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    // Skip this test if the developer has set `UV_PYTHON_CACHE_DIR` locally since it's slow
+    if env::var_os(EnvVars::UV_PYTHON_CACHE_DIR).is_some() && env::var_os(EnvVars::CI).is_none() {
+        debug!("Skipping test because `UV_PYTHON_CACHE_DIR` is set");
+        return;
+    }
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let python_cache = context.temp_dir.child("python-cache");
+
+    // Only download 3.13 into the cache
+    context
+        .python_install()
+        .arg("--only-download")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .success();
+
+    // 3.12 isn't cached, so the installation of 3.13 is rolled back
+    context
+        .python_install()
+        .arg("--from-cache")
+        .arg("--atomic")
+        .arg("3.12")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Failed to install 1 of 2 versions; rolled back 1 version",
+        ));
+
+    context
+        .bin_dir
+        .child(format!("python3.13{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+
+    context
+        .python_list()
+        .arg("--only-installed")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    // A failure to create an executable also rolls back the installation, and leaves the
+    // conflicting executable in place
+    let executable = context
+        .bin_dir
+        .child(format!("python3.13{}", std::env::consts::EXE_SUFFIX));
+    executable.write_str("unmanaged").unwrap();
+
+    context
+        .python_install()
+        .arg("--from-cache")
+        .arg("--atomic")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to install executable for"))
+        .stderr(predicate::str::contains(
+            "Failed to install 1 of 1 version; rolled back 1 version",
+        ));
+
+    executable.assert("unmanaged");
+
+    context
+        .python_list()
+        .arg("--only-installed")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    // Without the conflict, the installation succeeds
+    fs_err::remove_file(executable.path()).unwrap();
+
+    context
+        .python_install()
+        .arg("--from-cache")
+        .arg("--atomic")
+        .arg("3.13")
+        .env(EnvVars::UV_PYTHON_CACHE_DIR, python_cache.as_ref())
+        .assert()
+        .success();
+
+    executable.assert(predicate::path::exists());
+
+    // `--atomic` can't be combined with `--reinstall`
+    context
+        .python_install()
+        .arg("--atomic")
+        .arg("--reinstall")
+        .arg("3.13")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the argument '--atomic' cannot be used with '--reinstall'",
+        ));
+}
+
+/// The download rate limit must be a positive number of bytes per second.
+#[test]
+fn python_install_download_rate_limit_invalid() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--download-rate-limit").arg("0").arg("3.13"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '0' for '--download-rate-limit <RATE>': expected a positive number of bytes per second, optionally followed by `K`, `M`, or `G`, e.g., `10M`; found `0`
+
+    For more information, try '--help'.
+    ");
+}
+
+/// Per-host certificate authorities for Python downloads must be provided as `<host>=<path>`.
+#[test]
+fn python_install_ca_cert_invalid() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--python-install-ca-cert").arg("/etc/ssl/mirror.pem").arg("3.13"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '/etc/ssl/mirror.pem' for '--python-install-ca-cert <PYTHON_INSTALL_CA_CERT>': expected `<host>=<path>`, found `/etc/ssl/mirror.pem`
+
+    For more information, try '--help'.
+    ");
+}
+
 /// Duplicate of [`python_install`] with the cache directory disabled.
 #[test]
 fn python_install_no_cache() {
@@ -3158,3 +3587,224 @@ fn python_install_pyodide() {
     ----- stderr -----
     ");
 }
+
+#[test]
+fn python_uninstall_protected() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.11").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 versions in [TIME]
+     + cpython-3.11.13-[PLATFORM] (python3.11)
+     + cpython-3.12.11-[PLATFORM] (python3.12)
+    ");
+
+    // Protect one of the installations.
+    uv_snapshot!(context.filters(), context.python_pin_install().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Protected cpython-3.12.11-[PLATFORM]
+    ");
+
+    // The protected installation should be skipped when uninstalling all versions.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Skipping protected installation: cpython-3.12.11-[PLATFORM] (use `--force` to uninstall)
+    Uninstalled Python 3.11.13 in [TIME]
+     - cpython-3.11.13-[PLATFORM] (python3.11)
+    ");
+
+    // Unless forced.
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all").arg("--force"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled Python 3.12.11 in [TIME]
+     - cpython-3.12.11-[PLATFORM] (python3.12)
+    ");
+}
+
+#[test]
+fn python_install_hook() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context
+        .temp_dir
+        .child("hook.py")
+        .write_str(indoc! { r#"
+            import os
+            import sys
+
+            with open("hook.txt", "w") as f:
+                f.write(os.environ["UV_PYTHON_INSTALL_KEY"] + "\n")
+                f.write(" ".join(sys.argv[1:]) + "\n")
+                f.write(str(sys.executable == os.environ["UV_PYTHON_INSTALL_EXECUTABLE"]) + "\n")
+            "#
+        })
+        .unwrap();
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc! { r#"
+            [python-install-hook]
+            command = ["hook.py", "--register"]
+            "#
+        })
+        .unwrap();
+
+    // The hook should be executed with the newly installed interpreter.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     + cpython-3.12.11-[PLATFORM] (python3.12)
+    ");
+
+    let hook = fs_err::read_to_string(context.temp_dir.child("hook.txt")).unwrap();
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(hook, @r"
+        cpython-3.12.11-[PLATFORM]
+        --register
+        True
+        ");
+    });
+
+    // The hook should not be executed for existing installations.
+    fs_err::remove_file(context.temp_dir.child("hook.txt")).unwrap();
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+    context
+        .temp_dir
+        .child("hook.txt")
+        .assert(predicate::path::missing());
+
+    // A failing hook should only warn by default.
+    context
+        .temp_dir
+        .child("hook.py")
+        .write_str("raise SystemExit(1)")
+        .unwrap();
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--reinstall"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     ~ cpython-3.12.11-[PLATFORM] (python3.12)
+    warning: Failed to run post-install hook for cpython-3.12.11-[PLATFORM]
+      Caused by: Hook command `hook.py` failed with exit status: 1
+    ");
+
+    // Unless the hook is configured to fail the installation.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc! { r#"
+            [python-install-hook]
+            command = ["hook.py"]
+            on-failure = "fail"
+            "#
+        })
+        .unwrap();
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("--reinstall"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     ~ cpython-3.12.11-[PLATFORM] (python3.12)
+    error: Failed to run post-install hook for cpython-3.12.11-[PLATFORM]
+      Caused by: Hook command `hook.py` failed with exit status: 1
+    ");
+}
+
+/// Python distributions are extracted into the `install-staging-dir`, if set.
+#[test]
+fn python_install_staging_dir() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc! { r#"
+            install-staging-dir = "staging"
+            "#
+        })
+        .unwrap();
+
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.11 in [TIME]
+     + cpython-3.12.11-[PLATFORM] (python3.12)
+    ");
+
+    // The distribution is extracted into the staging directory, relative to the configuration
+    // file, rather than the scratch directory within the installation directory.
+    context
+        .temp_dir
+        .child("staging")
+        .assert(predicate::path::is_dir());
+    context
+        .temp_dir
+        .child("managed")
+        .child(".temp")
+        .assert(predicate::path::missing());
+
+    // `UV_PYTHON_INSTALL_STAGING_DIR` takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.python_install().arg("3.11")
+        .env(EnvVars::UV_PYTHON_INSTALL_STAGING_DIR, context.temp_dir.child("override").as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.11.13 in [TIME]
+     + cpython-3.11.13-[PLATFORM] (python3.11)
+    ");
+
+    context
+        .temp_dir
+        .child("override")
+        .assert(predicate::path::is_dir());
+}
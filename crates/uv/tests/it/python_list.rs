@@ -353,6 +353,43 @@ fn python_list_duplicate_path_entries() {
     }
 }
 
+#[cfg(unix)]
+#[test]
+fn python_list_aliases() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"])
+        .with_filtered_python_symlinks()
+        .with_filtered_python_keys()
+        .with_collapsed_whitespace();
+
+    // Link to the Python 3.12 executable from another directory on the `PATH`
+    let aliases = context.temp_dir.join("aliases");
+    fs_err::create_dir_all(&aliases).unwrap();
+    let python = context
+        .python_versions
+        .iter()
+        .find(|(version, _)| version.minor() == 12)
+        .map(|(_, executable)| executable.clone())
+        .unwrap();
+    fs_err::os::unix::fs::symlink(&python, aliases.join("python3")).unwrap();
+
+    let path = std::env::join_paths(
+        std::env::split_paths(&context.python_path()).chain(std::iter::once(aliases)),
+    )
+    .unwrap();
+
+    // The link is shown as an alias of the interpreter, rather than as a separate interpreter
+    uv_snapshot!(context.filters(), context.python_list().env(EnvVars::UV_TEST_PYTHON_PATH, &path), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.[X]-[PLATFORM] [PYTHON-3.12]
+     [TEMP_DIR]/aliases/python3 (alias)
+    cpython-3.11.[X]-[PLATFORM] [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn python_list_downloads() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
@@ -478,3 +515,57 @@ fn python_list_downloads_installed() {
     ----- stderr -----
     ");
 }
+
+#[test]
+#[cfg(feature = "python-managed")]
+fn python_list_outdated() {
+    use assert_cmd::assert::OutputAssertExt;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_managed_python_dirs();
+
+    // Install an old patch version alongside the latest patch version of another minor version.
+    context.python_install().arg("3.10.17").assert().success();
+    context.python_install().arg("3.11").assert().success();
+
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.10.17-[PLATFORM]    3.10.17 -> 3.10.18
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"key":"cpython-3.10.17-[PLATFORM]","version":"3.10.17","path":"[TEMP_DIR]/managed/cpython-3.10.17-[PLATFORM]/bin/python3.10","latest_key":"cpython-3.10.18-[PLATFORM]","latest_version":"3.10.18"}]
+
+    ----- stderr -----
+    "#);
+
+    // With `--exit-code`, outdated installations are treated as a failure.
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated").arg("--exit-code"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    cpython-3.10.17-[PLATFORM]    3.10.17 -> 3.10.18
+
+    ----- stderr -----
+    ");
+
+    // Once the latest patch version is installed, nothing is outdated.
+    context.python_install().arg("3.10").assert().success();
+
+    uv_snapshot!(context.filters(), context.python_list().arg("--outdated").arg("--exit-code"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All managed Python installations are up-to-date
+    ");
+}
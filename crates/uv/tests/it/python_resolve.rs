@@ -0,0 +1,104 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use url::Url;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Write a Python downloads JSON with a single distribution, backed by a local archive.
+fn write_python_downloads_json(context: &TestContext) -> Result<assert_fs::fixture::ChildPath> {
+    let archive = context
+        .temp_dir
+        .child("cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz");
+    archive.write_binary(&[0; 2048])?;
+
+    let python_downloads_json = context.temp_dir.child("python-downloads.json");
+    let downloads = serde_json::json!({
+        "cpython-3.12.0-linux-x86_64-gnu": {
+            "arch": {
+                "family": "x86_64",
+                "variant": null
+            },
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "name": "cpython",
+            "os": "linux",
+            "patch": 0,
+            "prerelease": "",
+            "sha256": "0000000000000000000000000000000000000000000000000000000000000000",
+            "url": Url::from_file_path(archive.path()).unwrap(),
+            "variant": null
+        }
+    });
+    python_downloads_json.write_str(&serde_json::to_string(&downloads)?)?;
+    Ok(python_downloads_json)
+}
+
+#[test]
+fn python_resolve() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let python_downloads_json = write_python_downloads_json(&context)?;
+
+    uv_snapshot!(context.filters(), context
+        .python_resolve()
+        .arg("3.12")
+        .arg("--platform")
+        .arg("linux-x86_64-gnu")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.0-linux-x86_64-gnu
+    url: file://[TEMP_DIR]/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz
+    size: 2048 (2.0KiB)
+    sha256: 0000000000000000000000000000000000000000000000000000000000000000
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .python_resolve()
+        .arg("cpython-3.12-linux-x86_64-gnu")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"key":"cpython-3.12.0-linux-x86_64-gnu","version":"3.12.0","implementation":"cpython","os":"linux","arch":"x86_64","libc":"gnu","url":"file://[TEMP_DIR]/cpython-3.12.0-x86_64-unknown-linux-gnu-install_only.tar.gz","size":2048,"sha256":"0000000000000000000000000000000000000000000000000000000000000000"}
+
+    ----- stderr -----
+    "#);
+
+    // No download is available for the platform.
+    uv_snapshot!(context.filters(), context
+        .python_resolve()
+        .arg("3.12")
+        .arg("--platform")
+        .arg("macos-aarch64-none")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No download found for request: cpython-3.12-macos-aarch64-none
+    ");
+
+    // Requests for a path can't be satisfied by a download.
+    uv_snapshot!(context.filters(), context
+        .python_resolve()
+        .arg("/usr/bin/python3"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `/usr/bin/python3` cannot be satisfied by a Python download
+    ");
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use crate::common::{TestContext, uv_snapshot};
 use anyhow::Result;
-use assert_fs::fixture::FileTouch;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::{FileTouch, FileWriteStr};
 use assert_fs::prelude::PathChild;
+use indoc::indoc;
 
 use uv_static::EnvVars;
 
@@ -207,8 +209,18 @@ fn python_upgrade_transparent_from_venv() {
     ----- stderr -----
     Installed Python 3.10.18 in [TIME]
      + cpython-3.10.18-[PLATFORM] (python3.10)
+    Refreshed 1 environment using the upgraded Python version
+     ~ .venv (3.10.17 -> 3.10.18)
     ");
 
+    // The version in the first virtual environment's `pyvenv.cfg` should be refreshed, while the
+    // second virtual environment isn't known to uv
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.child("pyvenv.cfg")).unwrap();
+    assert!(pyvenv_cfg.contains("version_info = 3.10.18"));
+    let pyvenv_cfg =
+        fs_err::read_to_string(context.temp_dir.child(second_venv).child("pyvenv.cfg")).unwrap();
+    assert!(pyvenv_cfg.contains("version_info = 3.10.17"));
+
     // First virtual environment should reflect upgraded patch
     uv_snapshot!(context.filters(), context.run().arg("python").arg("--version"), @r"
     success: true
@@ -233,6 +245,58 @@ fn python_upgrade_transparent_from_venv() {
     );
 }
 
+/// The project environment is refreshed, even if it's relocated with `UV_PROJECT_ENVIRONMENT`.
+#[test]
+fn python_upgrade_refreshes_project_environment() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.10"
+        dependencies = []
+        "#
+        })?;
+
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("3.10.17")
+        .assert()
+        .success();
+    context
+        .sync()
+        .arg("--python")
+        .arg("3.10")
+        .env(EnvVars::UV_PROJECT_ENVIRONMENT, "env")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10").env(EnvVars::UV_PROJECT_ENVIRONMENT, "env"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.18 in [TIME]
+     + cpython-3.10.18-[PLATFORM] (python3.10)
+    Refreshed 1 environment using the upgraded Python version
+     ~ env (3.10.17 -> 3.10.18)
+    ");
+
+    let pyvenv_cfg = fs_err::read_to_string(context.temp_dir.child("env").child("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("version_info = 3.10.18"));
+
+    Ok(())
+}
+
 // Installing Python in preview mode should not prevent virtual environments
 // from transparently upgrading.
 #[test]
@@ -284,6 +348,8 @@ fn python_upgrade_transparent_from_venv_preview() {
     ----- stderr -----
     Installed Python 3.10.18 in [TIME]
      + cpython-3.10.18-[PLATFORM] (python3.10)
+    Refreshed 1 environment using the upgraded Python version
+     ~ .venv (3.10.17 -> 3.10.18)
     ");
 
     // Virtual environment should reflect upgraded patch
@@ -503,6 +569,8 @@ fn python_transparent_upgrade_venv_venv() {
     ----- stderr -----
     Installed Python 3.10.18 in [TIME]
      + cpython-3.10.18-[PLATFORM] (python3.10)
+    Refreshed 1 environment using the upgraded Python version
+     ~ .venv (3.10.17 -> 3.10.18)
     ");
 
     // Should have transparently upgraded in second virtual environment
@@ -656,6 +724,8 @@ fn python_upgrade_transparent_from_venv_module_in_venv() {
     ----- stderr -----
     Installed Python 3.10.18 in [TIME]
      + cpython-3.10.18-[PLATFORM] (python3.10)
+    Refreshed 1 environment using the upgraded Python version
+     ~ .venv (3.10.17 -> 3.10.18)
     "
     );
 
@@ -712,3 +782,157 @@ fn python_upgrade_force_install() -> Result<()> {
 
     Ok(())
 }
+
+/// Upgrade across minor versions with `--minor`, migrating the project environment.
+#[test]
+fn python_upgrade_minor() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    let mut filters = context.filters().clone();
+    filters.push((r"3.13.\d+", "3.13.[X]"));
+
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.12.8"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.8 in [TIME]
+     + cpython-3.12.8-[PLATFORM] (python3.12)
+    ");
+
+    // Only minor versions are accepted.
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("--minor").arg("3.12.8"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv python upgrade --minor` only accepts minor versions, but found `3.12.8`
+    ");
+
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12\n")?;
+    context.venv().assert().success();
+
+    // The pin must be updated before the environment can be recreated.
+    uv_snapshot!(filters, context.python_upgrade().arg("--preview").arg("--minor"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migration plan to Python 3.13:
+     + Python 3.13: install
+     ~ .venv (Python 3.12): update `.python-version` and recreate the environment
+    Run `uv python upgrade --minor --apply` to apply the plan
+    ");
+
+    uv_snapshot!(filters, context.python_upgrade().arg("--preview").arg("--minor").arg("--apply"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migration plan to Python 3.13:
+     + Python 3.13: install
+     ~ .venv (Python 3.12): update `.python-version` and recreate the environment
+    Installed Python 3.13.[X] in [TIME]
+     + cpython-3.13.[X]-[PLATFORM] (python3.13)
+    Updated `.python-version` to `3.13`
+    Removed environment at `.venv`; run `uv sync` to recreate it with Python 3.13
+    ");
+
+    assert_eq!(context.read(".python-version"), "3.13\n");
+    assert!(!context.temp_dir.child(".venv").exists());
+
+    // A `requires-python` that excludes the new minor version must be updated manually.
+    context.temp_dir.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12,<3.13"
+        dependencies = []
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12\n")?;
+
+    uv_snapshot!(filters, context.python_upgrade().arg("--preview").arg("--minor"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Migration plan to Python 3.13:
+     ! .venv (pinned to 3.12): `requires-python` (>=3.12, <3.13) excludes Python 3.13; update it in `pyproject.toml`
+    ");
+
+    Ok(())
+}
+
+/// Environments outside the project that depend on an older minor version are included in the
+/// plan, with the command to migrate them.
+#[test]
+fn python_upgrade_minor_active_environment() {
+    use predicates::prelude::*;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    context
+        .python_install()
+        .arg("--preview")
+        .arg("3.12.8")
+        .assert()
+        .success();
+    context
+        .venv()
+        .arg("other")
+        .arg("--python")
+        .arg("3.12.8")
+        .assert()
+        .success();
+
+    // Nothing is installed or modified until the plan is applied.
+    uv_snapshot!(context.filters(), context.python_upgrade()
+        .arg("--preview")
+        .arg("--minor")
+        .env(EnvVars::VIRTUAL_ENV, "other"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migration plan to Python 3.13:
+     + Python 3.13: install
+     - other (Python 3.12): rebase the environment with `uv venv other --rebase 3.13`
+    Run `uv python upgrade --minor --apply` to apply the plan
+    ");
+
+    context
+        .python_list()
+        .arg("--only-installed")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("3.13").not());
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use url::Url;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// The SHA-256 checksum of an archive of 2048 zero bytes.
+const ARCHIVE_SHA256: &str = "e5a00aa9991ac8a5ee3109844d84a55583bd20572ad3ffcd42792f3c36b183ad";
+
+/// Write a Python downloads JSON with an available, a missing, and a corrupted distribution, backed
+/// by local archives.
+fn write_python_downloads_json(context: &TestContext) -> Result<assert_fs::fixture::ChildPath> {
+    let download = |patch: u8, sha256: &str| -> Result<serde_json::Value> {
+        let archive = context.temp_dir.child(format!(
+            "cpython-3.12.{patch}-x86_64-unknown-linux-gnu-install_only.tar.gz"
+        ));
+        Ok(serde_json::json!({
+            "arch": {
+                "family": "x86_64",
+                "variant": null
+            },
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "name": "cpython",
+            "os": "linux",
+            "patch": patch,
+            "prerelease": "",
+            "sha256": sha256,
+            "url": Url::from_file_path(archive.path()).unwrap(),
+            "variant": null
+        }))
+    };
+
+    // The archives for `3.12.0` and `3.12.2` exist, but the latter has the wrong checksum.
+    for patch in [0, 2] {
+        context
+            .temp_dir
+            .child(format!(
+                "cpython-3.12.{patch}-x86_64-unknown-linux-gnu-install_only.tar.gz"
+            ))
+            .write_binary(&[0; 2048])?;
+    }
+
+    let python_downloads_json = context.temp_dir.child("python-downloads.json");
+    let downloads = serde_json::json!({
+        "cpython-3.12.0-linux-x86_64-gnu": download(0, ARCHIVE_SHA256)?,
+        "cpython-3.12.1-linux-x86_64-gnu": download(1, ARCHIVE_SHA256)?,
+        "cpython-3.12.2-linux-x86_64-gnu": download(2, &"0".repeat(64))?,
+    });
+    python_downloads_json.write_str(&serde_json::to_string(&downloads)?)?;
+    Ok(python_downloads_json)
+}
+
+#[test]
+fn python_validate_downloads() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+    let python_downloads_json = write_python_downloads_json(&context)?;
+
+    // By default, only the existence of each archive is checked.
+    uv_snapshot!(context.filters(), context
+        .python_validate_downloads()
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    missing: cpython-3.12.1-linux-x86_64-gnu (file://[TEMP_DIR]/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz)
+
+    ----- stderr -----
+    Checked 3 Python downloads in [TIME]: 1 missing, 0 corrupted, 0 failed
+    ");
+
+    // With `--full`, the checksum of each archive is verified.
+    uv_snapshot!(context.filters(), context
+        .python_validate_downloads()
+        .arg("--full")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    missing: cpython-3.12.1-linux-x86_64-gnu (file://[TEMP_DIR]/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz)
+    corrupted: cpython-3.12.2-linux-x86_64-gnu (file://[TEMP_DIR]/cpython-3.12.2-x86_64-unknown-linux-gnu-install_only.tar.gz)
+      expected sha256: 0000000000000000000000000000000000000000000000000000000000000000
+      actual sha256:   e5a00aa9991ac8a5ee3109844d84a55583bd20572ad3ffcd42792f3c36b183ad
+
+    ----- stderr -----
+    Checked 3 Python downloads in [TIME]: 1 missing, 1 corrupted, 0 failed
+    ");
+
+    uv_snapshot!(context.filters(), context
+        .python_validate_downloads()
+        .arg("--full")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {"checked":3,"missing":[{"key":"cpython-3.12.1-linux-x86_64-gnu","url":"file://[TEMP_DIR]/cpython-3.12.1-x86_64-unknown-linux-gnu-install_only.tar.gz"}],"corrupted":[{"key":"cpython-3.12.2-linux-x86_64-gnu","url":"file://[TEMP_DIR]/cpython-3.12.2-x86_64-unknown-linux-gnu-install_only.tar.gz","expected_sha256":"0000000000000000000000000000000000000000000000000000000000000000","actual_sha256":"e5a00aa9991ac8a5ee3109844d84a55583bd20572ad3ffcd42792f3c36b183ad"}],"failed":[]}
+
+    ----- stderr -----
+    "#);
+
+    // Only the downloads matching the request are checked.
+    uv_snapshot!(context.filters(), context
+        .python_validate_downloads()
+        .arg("3.12.0")
+        .arg("--full")
+        .arg("--python-downloads-json-url")
+        .arg(python_downloads_json.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 Python download in [TIME]
+    ");
+
+    Ok(())
+}
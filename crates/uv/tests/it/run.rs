@@ -10,6 +10,8 @@ use std::path::Path;
 use uv_fs::copy_dir_all;
 use uv_python::PYTHON_VERSION_FILENAME;
 use uv_static::EnvVars;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use crate::common::{TestContext, uv_snapshot};
 
@@ -473,6 +475,63 @@ fn run_pep723_script() -> Result<()> {
     Ok(())
 }
 
+/// With `UV_SCRIPT_ENVIRONMENT_KEY=content`, script environments should be keyed by the inline
+/// metadata, such that renamed scripts reuse their environment.
+#[test]
+fn run_pep723_script_content_key() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+       "#
+    };
+    context.temp_dir.child("main.py").write_str(script)?;
+
+    uv_snapshot!(context.filters(), context.run().arg("main.py").env(EnvVars::UV_SCRIPT_ENVIRONMENT_KEY, "content"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // A renamed copy of the script should reuse the same environment.
+    context.temp_dir.child("renamed.py").write_str(script)?;
+
+    uv_snapshot!(context.filters(), context.run().arg("renamed.py").env(EnvVars::UV_SCRIPT_ENVIRONMENT_KEY, "content"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // Keyed by path, the renamed script gets its own environment.
+    uv_snapshot!(context.filters(), context.run().arg("renamed.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_pep723_script_requires_python() -> Result<()> {
     let context = TestContext::new_with_versions(&["3.9", "3.11"]);
@@ -576,6 +635,156 @@ fn run_pythonw_script() -> Result<()> {
     Ok(())
 }
 
+/// Run a plain script with an interpreter hint comment.
+#[test]
+fn run_script_python_hint() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r"
+        # uv: python=3.11
+        import sys
+
+        print(f'{sys.version_info.major}.{sys.version_info.minor}')
+       "
+    })?;
+
+    // The hint is respected with `--script`.
+    uv_snapshot!(context.filters(), context.run().arg("--script").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    // An explicit `--python` takes precedence.
+    uv_snapshot!(context.filters(), context.run().arg("--python").arg("3.12").arg("--script").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    "###);
+
+    // Inside a project, the project environment takes precedence.
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        })?;
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--script").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12
+
+    ----- stderr -----
+    warning: Ignoring the `python=3.11` hint in the script, since it's run in the project environment; use `--no-project` to respect the hint
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    // Unless the project is ignored.
+    uv_snapshot!(context.filters(), context.run().arg("--no-project").arg("--script").arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Enforce the `required-version` declared in a PEP 723 script, in addition to the project's.
+#[test]
+fn run_pep723_script_required_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"running version `\d+\.\d+\.\d+(-(alpha|beta|rc)\.\d+)?(\+\d+)?`",
+            "running version `[VERSION]`",
+        )])
+        .collect::<Vec<_>>();
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = []
+        #
+        # [tool.uv]
+        # required-version = "<0.1"
+        # ///
+
+        print("Hello, world!")
+       "#
+    })?;
+
+    // The script's requirement is enforced, and the error names the script.
+    uv_snapshot!(filters, context.run().arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Required uv version `<0.1` (from `main.py`) does not match the running version `[VERSION]`
+    "###);
+
+    // A script can't relax the requirement declared by the surrounding project.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv]
+        required-version = "<0.1"
+       "#
+    })?;
+
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = []
+        #
+        # [tool.uv]
+        # required-version = ">=0.1"
+        # ///
+
+        print("Hello, world!")
+       "#
+    })?;
+
+    uv_snapshot!(filters, context.run().arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Required uv version `<0.1` does not match the running version `[VERSION]`
+    "###);
+
+    Ok(())
+}
+
 /// Run a PEP 723-compatible script with `tool.uv` metadata.
 #[test]
 #[cfg(feature = "git")]
@@ -888,7 +1097,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -997,7 +1206,7 @@ fn run_pep723_script_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -1210,63 +1419,1629 @@ fn run_with() -> Result<()> {
     ----- stdout -----
     1.2.0
 
-    ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Audited 2 packages in [TIME]
-    Resolved 1 package in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + sniffio==1.2.0
-    ");
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.2.0
+    ");
+
+    // If we request a dependency that isn't in the base environment, we should still respect any
+    // other dependencies. In this case, `sniffio==1.3.0` is not the latest-compatible version, but
+    // we should use it anyway.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("anyio").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.0
+    ");
+
+    // Even if we run with` --no-sync`.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("anyio==4.2.0").arg("--no-sync").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.2.0
+     + idna==3.6
+     + sniffio==1.3.0
+    ");
+
+    // If the dependencies can't be resolved, we should reference `--with`.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("add").arg("main.py"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+      × No solution found when resolving `--with` dependencies:
+      ╰─▶ Because there are no versions of add and you require add, we can conclude that your requirements are unsatisfiable.
+    "###);
+
+    Ok(())
+}
+
+/// Requirements from `tool.uv.run.with` should be layered on top of the project environment, along
+/// with any `--with` requirements.
+#[test]
+fn run_with_config() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv.run]
+        with = ["iniconfig"]
+        "#
+    })?;
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r"
+        import iniconfig
+        import sniffio
+
+        print(sniffio.__version__)
+       "
+    })?;
+
+    // The configured requirements should be installed in a layer.
+    uv_snapshot!(context.filters(), context.run().arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.0
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Repeating a configured requirement on the command line should reuse the same layer.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("iniconfig").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Resolved 1 package in [TIME]
+    ");
+
+    // Requirements from the command line should be merged with the configured requirements.
+    uv_snapshot!(context.filters(), context.run().arg("--with").arg("anyio").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Resolved 4 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.0
+    ");
+
+    // The configured requirements should be ignored with `--no-config`.
+    uv_snapshot!(context.filters(), context.run().arg("--no-config").arg("main.py"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 2 packages in [TIME]
+    Traceback (most recent call last):
+      File "[TEMP_DIR]/main.py", line 1, in <module>
+        import iniconfig
+    ModuleNotFoundError: No module named 'iniconfig'
+    "#);
+
+    Ok(())
+}
+
+/// `--fs-trace` should record the operations that composed the environment.
+#[test]
+fn run_with_fs_trace() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    context.lock().assert().success();
+
+    let trace = context.temp_dir.child("trace.json");
+    context
+        .run()
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--overlay-mode")
+        .arg("link")
+        .arg("--fs-trace")
+        .arg(trace.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    let trace: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(trace.path())?)?;
+    let operations = trace["operations"].as_array().unwrap();
+    let has = |operation: &str, suffix: &str| {
+        operations.iter().any(|entry| {
+            entry["operation"] == operation
+                && entry["path"]
+                    .as_str()
+                    .is_some_and(|path| path.ends_with(suffix))
+        })
+    };
+
+    // The lockfile is read.
+    assert!(has("read", "uv.lock"));
+    // The ephemeral environment is created and extended with an overlay.
+    assert!(has("create", "pyvenv.cfg"));
+    assert!(has("create", "_uv_ephemeral_overlay.pth"));
+    assert!(has("modify", "pyvenv.cfg"));
+
+    Ok(())
+}
+
+/// Run a command with `--with iniconfig` in the given `--overlay-mode`, printing whether the
+/// `--with` requirements and the project's dependencies were cloned into the ephemeral environment.
+fn run_overlay_mode(context: &TestContext, mode: Option<&str>) -> Result<std::process::Output> {
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    let mut command = context.run();
+    command.arg("--with").arg("iniconfig");
+    if let Some(mode) = mode {
+        command.arg("--overlay-mode").arg(mode);
+    }
+    Ok(command
+        .arg("python")
+        .arg("-c")
+        .arg("import sys, iniconfig, sniffio; print(iniconfig.__file__.startswith(sys.prefix), sniffio.__file__.startswith(sys.prefix))")
+        .output()?)
+}
+
+/// By default, and with `--overlay-mode link`, the site packages should be referenced in place.
+#[test]
+fn run_overlay_mode_link() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    for mode in [None, Some("link")] {
+        let output = run_overlay_mode(&context, mode)?;
+        assert!(output.status.success(), "{output:?}");
+        assert_eq!(String::from_utf8(output.stdout)?, "False False\n");
+    }
+
+    Ok(())
+}
+
+/// With `--overlay-mode clone`, the site packages should be cloned into the ephemeral environment,
+/// or the command should fail if the filesystem doesn't support copy-on-write clones.
+#[test]
+fn run_overlay_mode_clone() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = run_overlay_mode(&context, Some("clone"))?;
+    if output.status.success() {
+        assert_eq!(String::from_utf8(output.stdout)?, "True True\n");
+    } else {
+        assert!(
+            String::from_utf8(output.stderr)?
+                .contains("Failed to clone site packages into the ephemeral environment"),
+        );
+    }
+
+    Ok(())
+}
+
+/// With `--overlay-mode auto`, the site packages should be cloned into the ephemeral environment
+/// if the filesystem supports copy-on-write clones, and referenced in place otherwise.
+#[test]
+fn run_overlay_mode_auto() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Whether the filesystem supports clones is determined by `--overlay-mode clone`.
+    let supported = run_overlay_mode(&context, Some("clone"))?.status.success();
+
+    let output = run_overlay_mode(&context, Some("auto"))?;
+    assert!(output.status.success(), "{output:?}");
+    if supported {
+        assert_eq!(String::from_utf8(output.stdout)?, "True True\n");
+    } else {
+        assert_eq!(String::from_utf8(output.stdout)?, "False False\n");
+    }
+
+    Ok(())
+}
+
+/// `--require-hashes` should verify the `--with` requirements against the hashes provided by the
+/// index.
+#[test]
+fn run_with_require_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let anyio_local = context.temp_dir.child("src").child("anyio_local");
+    copy_dir_all(
+        context.workspace_root.join("scripts/packages/anyio_local"),
+        &anyio_local,
+    )?;
+
+    // The index provides hashes for `iniconfig`, so the requirement is installed.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig==2.0.0")
+        .arg("--require-hashes")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    // Local directories don't provide hashes, so the requirement is rejected.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("./src/anyio_local")
+        .arg("--require-hashes")
+        .arg("python")
+        .arg("-c")
+        .arg("import anyio"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: In `--require-hashes` mode, all requirements must have a hash, but none were provided for: anyio
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn run_with_summary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    let summary = context.temp_dir.child("summary.json");
+    let read_summary = || -> Result<serde_json::Value> {
+        Ok(serde_json::from_str(&fs_err::read_to_string(
+            summary.path(),
+        )?)?)
+    };
+    let phases = |summary: &serde_json::Value| -> Vec<String> {
+        summary["phases"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|phase| phase["phase"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // The first run creates the lockfile and installs the dependencies.
+    context
+        .run()
+        .arg("--summary-json")
+        .arg(summary.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import sniffio")
+        .assert()
+        .success();
+
+    let report = read_summary()?;
+    assert_eq!(report["lockfile"], "updated");
+    assert_eq!(report["installed"], 1);
+    assert_eq!(report["uninstalled"], 0);
+    assert_eq!(report["interpreter"]["source"], "project environment");
+    assert!(report.get("overlay").is_none());
+    assert_eq!(phases(&report), ["lock", "sync", "command"]);
+
+    // The second run reuses the lockfile and the environment, and layers `--with` requirements.
+    context
+        .run()
+        .arg("--summary-json")
+        .arg(summary.path())
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    let report = read_summary()?;
+    assert_eq!(report["lockfile"], "reused");
+    assert_eq!(report["installed"], 1);
+    assert_eq!(report["overlay"], "miss");
+    assert_eq!(phases(&report), ["lock", "sync", "overlay", "command"]);
+
+    // The summary can also be printed.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--summary")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    Resolved 1 package in [TIME]
+    Summary:
+      Interpreter: Python 3.12.[X] at `[CACHE_DIR]/builds-v0/[TMP]/python` (project environment)
+      Lockfile: reused
+      Packages: 0 installed, 0 uninstalled, 0 reinstalled
+      Overlay: cache hit
+      Time: lock [TIME], sync [TIME], overlay [TIME], command [TIME] ([TIME] total)
+    ");
+
+    Ok(())
+}
+
+/// The user site packages directory should be excluded from ephemeral environments, unless
+/// `--allow-user-site` is provided.
+#[test]
+fn run_allow_user_site() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script =
+        "import site, sys; print(sys.flags.no_user_site, site.getusersitepackages() in sys.path)";
+
+    // The virtual environment excludes the user site packages, but the variable is left unset.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    0 False
+
+    ----- stderr -----
+    ");
+
+    // In the ephemeral environment, the user site packages are disabled.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1 False
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Unless requested, in which case they're added to the ephemeral environment.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--allow-user-site")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    0 True
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    ");
+
+    // Without `--with`, the virtual environment is extended by an ephemeral environment that
+    // includes the user site packages.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--allow-user-site")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    0 True
+
+    ----- stderr -----
+    ");
+
+    // A virtual environment created with `--allow-user-site` includes them directly.
+    context
+        .venv()
+        .arg("--clear")
+        .arg("--allow-user-site")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--allow-user-site")
+        .arg("python")
+        .arg("-c")
+        .arg("import site, sys; print(sys.prefix); print(site.getusersitepackages() in sys.path)"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/
+    True
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// `uv run` refuses to sync a project environment that was locked with
+/// `uv venv --lock-environment`, unless `--unlock` is provided.
+#[test]
+fn run_locked_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    context.venv().arg("--lock-environment").assert().success();
+
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "is locked against modification; pass `--unlock` to modify it anyway",
+        ));
+
+    context
+        .run()
+        .arg("--unlock")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+/// If the active virtual environment is ignored as its interpreter doesn't satisfy the request,
+/// `uv run` should explain why, both in and outside of a project.
+#[test]
+fn run_active_environment_skip_reason() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .venv()
+        .arg("active")
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    context
+        .run()
+        .env(EnvVars::VIRTUAL_ENV, "active")
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.12")
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .success()
+        .stderr(contains(
+            "Ignoring the active virtual environment at `active`: Python 3.11",
+        ))
+        .stderr(contains("does not satisfy the request for Python 3.12"));
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context
+        .run()
+        .env(EnvVars::VIRTUAL_ENV, "active")
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .success()
+        .stderr(contains(
+            "Ignoring the active virtual environment at `active`: Python 3.11",
+        ))
+        .stderr(contains("does not satisfy the request for Python >=3.12"));
+
+    Ok(())
+}
+
+/// `uv run` should run the command once per Python version in a matrix, and report the outcome
+/// of each run.
+#[test]
+fn run_python_matrix() {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.12,3.11")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print(f'{sys.version_info[0]}.{sys.version_info[1]}')")
+        .assert()
+        .success()
+        .stdout(contains("3.12\n3.11\n"))
+        .stderr(contains("Running with Python 3.12"))
+        .stderr(contains("Running with Python 3.11"))
+        .stderr(contains("3.12: success"))
+        .stderr(contains("3.11: success"));
+
+    // A failure under any version is reflected in the exit code.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.12,3.11")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; sys.exit(sys.version_info[1] == 11)")
+        .assert()
+        .failure()
+        .stderr(contains("3.12: success"))
+        .stderr(contains("3.11: failed (exit code 1)"));
+}
+
+/// With `--parallel`, the output of each run should be buffered and shown as a block, preceded by
+/// the Python version it was run with.
+#[test]
+fn run_python_matrix_parallel() {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let output = context
+        .run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.12,3.11")
+        .arg("--parallel")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; [print(sys.version_info[1]) for _ in range(100)]")
+        .output()
+        .expect("failed to run `uv run`");
+    assert!(output.status.success());
+
+    // The output of each run is not interleaved with the output of the other.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 200);
+    assert!(lines[..100].iter().all(|line| *line == lines[0]));
+    assert!(lines[100..].iter().all(|line| *line == lines[100]));
+    assert_ne!(lines[0], lines[100]);
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Output with Python 3.12"));
+    assert!(stderr.contains("Output with Python 3.11"));
+    assert!(!stderr.contains("Running with Python"));
+}
+
+/// With `--fs-trace`, a separate trace should be written for each Python version in a matrix.
+#[test]
+fn run_python_matrix_fs_trace() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let trace = context.temp_dir.child("trace.json");
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--python")
+        .arg("3.12,3.11")
+        .arg("--parallel")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--fs-trace")
+        .arg(trace.path())
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .success();
+
+    trace.assert(predicate::path::missing());
+
+    let created = |python: &str| -> Result<Vec<String>> {
+        let path = context.temp_dir.child(format!("trace.json.{python}"));
+        let trace: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(path)?)?;
+        Ok(trace["operations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry["operation"] == "create")
+            .filter_map(|entry| entry["path"].as_str().map(ToString::to_string))
+            .collect())
+    };
+
+    // Each trace records the ephemeral environment of its own run, and only that environment.
+    let py312 = created("3.12")?;
+    let py311 = created("3.11")?;
+    assert!(
+        py312
+            .iter()
+            .any(|path| path.ends_with("_uv_ephemeral_overlay.pth"))
+    );
+    assert!(
+        py311
+            .iter()
+            .any(|path| path.ends_with("_uv_ephemeral_overlay.pth"))
+    );
+    assert!(py312.iter().all(|path| !py311.contains(path)));
+
+    Ok(())
+}
+
+/// If the requested Python version isn't available, `uv run` should lock against markers
+/// synthesized for it, and fail with a hint to install it.
+#[test]
+fn run_unavailable_python_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    // The requested version is validated against `requires-python` before locking.
+    context
+        .run()
+        .arg("--python")
+        .arg("3.10")
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "incompatible with the project's Python requirement: `>=3.11`",
+        ));
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    context
+        .run()
+        .arg("--python")
+        .arg("3.11")
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .failure()
+        .stderr(contains(
+            "The lockfile is up-to-date for Python 3.11; install it with `uv python install 3.11`",
+        ));
+    assert!(context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
+
+/// With `--exec`, the command should replace the uv process, rather than run as its child.
+#[cfg(unix)]
+#[test]
+fn run_exec() {
+    let context = TestContext::new("3.12");
+
+    // The test harness spawned uv, so it's the parent of the command if uv was replaced.
+    let script = "import os; print(os.getppid() == int(os.environ['UV_TEST_PARENT_PID']))";
+
+    uv_snapshot!(context.filters(), context.run()
+        .env("UV_TEST_PARENT_PID", std::process::id().to_string())
+        .arg("--no-project")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    False
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .env("UV_TEST_PARENT_PID", std::process::id().to_string())
+        .arg("--no-project")
+        .arg("--exec")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    ");
+
+    // The exit code is the command's own.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--exec")
+        .arg("python")
+        .arg("-c")
+        .arg("raise SystemExit(3)"), @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // A summary can't be printed once uv is replaced.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--exec")
+        .arg("--summary")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exec' cannot be used with '--summary'
+
+    Usage: uv run --exec [COMMAND]
+
+    For more information, try '--help'.
+    ");
+}
+
+/// `--exec` replaces uv with the command, so options that need uv to outlive the command, or that
+/// are handled by uv after the command exits, are rejected.
+#[test]
+fn run_exec_conflicts() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--exec")
+        .arg("--keep-env-on-failure")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exec' cannot be used with '--keep-env-on-failure'
+
+    Usage: uv run --exec [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--exec")
+        .arg("--exit-code-map")
+        .arg("reserved")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exec' cannot be used with '--exit-code-map <EXIT_CODE_MAP>'
+
+    Usage: uv run --exec [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--exec")
+        .arg("--shutdown-timeout")
+        .arg("5")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exec' cannot be used with '--shutdown-timeout <SECONDS>'
+
+    Usage: uv run --exec [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--exec")
+        .arg("--fs-trace")
+        .arg("trace.json")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--exec' cannot be used with '--fs-trace <PATH>'
+
+    Usage: uv run --exec [COMMAND]
+
+    For more information, try '--help'.
+    ");
+}
+
+/// With `--exec`, the ephemeral environment should survive a concurrent `uv run` for as long as
+/// the command is running, and be removed by the next cleanup pass once it exits.
+#[cfg(unix)]
+#[test]
+fn run_exec_concurrent_cleanup() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let context = TestContext::new("3.12");
+
+    // Print the ephemeral environment, then wait for the test to close stdin.
+    let mut child = context
+        .run()
+        .arg("--no-project")
+        .arg("--exec")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; print(sys.prefix, flush=True); sys.stdin.read()")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+    let mut prefix = String::new();
+    stdout.read_line(&mut prefix)?;
+    let prefix = Path::new(prefix.trim()).to_path_buf();
+    assert!(prefix.is_dir());
+
+    // A concurrent `uv run` with an ephemeral environment runs the cleanup pass.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("print('done')")
+        .assert()
+        .success();
+    assert!(prefix.is_dir());
+
+    // Once the command exits, the environment is removed by the next cleanup pass.
+    drop(child.stdin.take());
+    assert!(child.wait()?.success());
+    assert!(prefix.is_dir());
+
+    context.prune().arg("--ephemeral").assert().success();
+    assert!(!prefix.exists());
+
+    Ok(())
+}
+
+/// With `--signal-forwarding group`, signals received by uv are forwarded to every process in the
+/// command's process group, including processes spawned by the command.
+#[cfg(unix)]
+#[test]
+fn run_signal_forwarding_group() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let context = TestContext::new("3.12");
+
+    // The command spawns a process of its own; both report the SIGTERM they receive.
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! { r#"
+        import signal
+        import subprocess
+        import sys
+
+        signal.signal(signal.SIGTERM, lambda *_: print("child: SIGTERM", flush=True))
+        grandchild = subprocess.Popen([sys.executable, "-c", """
+        import signal, sys, time
+        signal.signal(signal.SIGTERM, lambda *_: (print("grandchild: SIGTERM", flush=True), sys.exit(0)))
+        print("ready", flush=True)
+        time.sleep(60)
+        """])
+        grandchild.wait()
+        "#
+    })?;
+
+    let mut child = context
+        .run()
+        .arg("--no-project")
+        .arg("--signal-forwarding")
+        .arg("group")
+        .arg("main.py")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+    let mut line = String::new();
+    stdout.read_line(&mut line)?;
+    assert_eq!(line, "ready\n");
+
+    kill(Pid::from_raw(i32::try_from(child.id())?), Signal::SIGTERM)?;
+    assert!(child.wait()?.success());
+
+    let mut lines = stdout.lines().collect::<Result<Vec<_>, _>>()?;
+    lines.sort();
+    assert_eq!(lines, ["child: SIGTERM", "grandchild: SIGTERM"]);
+
+    Ok(())
+}
+
+/// With `--shutdown-timeout`, a command that doesn't exit after a termination signal is killed.
+#[cfg(unix)]
+#[test]
+fn run_shutdown_timeout() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+    use std::time::{Duration, Instant};
+
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let context = TestContext::new("3.12");
+
+    let mut child = context
+        .run()
+        .arg("--no-project")
+        .arg("--shutdown-timeout")
+        .arg("1")
+        .arg("python")
+        .arg("-c")
+        .arg("import signal, time; signal.signal(signal.SIGTERM, signal.SIG_IGN); print('ready', flush=True); time.sleep(60)")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    let mut stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+    let mut line = String::new();
+    stdout.read_line(&mut line)?;
+    assert_eq!(line, "ready\n");
+
+    let start = Instant::now();
+    kill(Pid::from_raw(i32::try_from(child.id())?), Signal::SIGTERM)?;
+
+    // The command is killed with SIGKILL, which is reported as 128 + 9.
+    assert_eq!(child.wait()?.code(), Some(137));
+    assert!(start.elapsed() < Duration::from_secs(30));
+
+    Ok(())
+}
+
+/// With `--keep-env-on-failure`, the ephemeral environment is kept if the command fails, until
+/// the cache is pruned.
+#[test]
+fn run_keep_env_on_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // The environment is removed if the command succeeds.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--isolated")
+        .arg("--keep-env-on-failure")
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .success()
+        .stderr("");
+
+    let output = context
+        .run()
+        .arg("--no-project")
+        .arg("--isolated")
+        .arg("--keep-env-on-failure")
+        .arg("python")
+        .arg("-c")
+        .arg("raise SystemExit(3)")
+        .assert()
+        .code(3)
+        .stderr(contains(
+            "The environment in which the command failed was kept at:",
+        ))
+        .stderr(contains("Remove it with: uv cache prune --ephemeral"))
+        .get_output()
+        .clone();
+
+    let stderr = String::from_utf8(output.stderr)?;
+    let kept = stderr
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("The environment in which the command failed was kept at: ")
+        })
+        .expect("the kept environment is reported");
+    let kept = context.temp_dir.join(kept.trim());
+    assert!(kept.join("pyvenv.cfg").is_file());
+
+    // Pruning the cache removes the environment.
+    context.prune().arg("--ephemeral").assert().success();
+    assert!(!kept.exists());
+
+    Ok(())
+}
+
+/// A `uv run` waiting on the environment lock reports the process that holds it.
+#[cfg(unix)]
+#[test]
+fn run_wait_for_environment_lock() -> Result<()> {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // Hold the environment lock, as a concurrent sync would.
+    let lock = uv_fs::LockedFile::acquire_blocking(context.venv.join(".lock"), "test")?;
+    lock.write_contents("12345")?;
+
+    let mut child = context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("print('done')")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Wait for uv to report that it's waiting on the lock, then release it.
+    let mut stderr = BufReader::new(child.stderr.take().expect("stderr is piped"));
+    let mut line = String::new();
+    while stderr.read_line(&mut line)? > 0 && !line.contains("Waiting") {
+        line.clear();
+    }
+    assert_eq!(
+        line.trim(),
+        "Waiting for another uv process (PID 12345) to finish syncing the environment..."
+    );
+    drop(lock);
+
+    let output = child.wait_with_output()?;
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout)?, "done\n");
+
+    Ok(())
+}
+
+/// The cached `--with` environment should be rebuilt when the project environment changes.
+#[test]
+fn run_with_base_environment_change() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    let summary = context.temp_dir.child("summary.json");
+    let overlay = || -> Result<serde_json::Value> {
+        context
+            .run()
+            .arg("--summary-json")
+            .arg(summary.path())
+            .arg("--with")
+            .arg("iniconfig")
+            .arg("python")
+            .arg("-c")
+            .arg("import iniconfig")
+            .assert()
+            .success();
+        let report: serde_json::Value =
+            serde_json::from_str(&fs_err::read_to_string(summary.path())?)?;
+        Ok(report["overlay"].clone())
+    };
+
+    // The first run builds the cached environment, and the second reuses it.
+    assert_eq!(overlay()?, "miss");
+    assert_eq!(overlay()?, "hit");
+
+    // Changing the packages in the project environment invalidates the cached environment.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.1"]
+        "#
+    })?;
+    assert_eq!(overlay()?, "miss");
+    assert_eq!(overlay()?, "hit");
+
+    Ok(())
+}
+
+#[test]
+fn run_last() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+        "#
+    })?;
+
+    // Nothing has been recorded yet.
+    uv_snapshot!(context.filters(), context.run().arg("--last"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No previous `uv run` invocation was found in the project
+    ");
+
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; sys.exit(3)")
+        .assert()
+        .code(3);
+
+    context
+        .run()
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig; print('hello')")
+        .assert()
+        .success();
+
+    // The most recent invocation is replayed, including its `--with` requirements.
+    uv_snapshot!(context.filters(), context.run().arg("--last"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Re-running `uv run --with iniconfig python -c 'import iniconfig; print('"'"'hello'"'"')'`
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Resolved 1 package in [TIME]
+    ");
+
+    // A command can't be combined with `--last`.
+    uv_snapshot!(context.filters(), context.run().arg("--last").arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--last` cannot be used with a command
+    ");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"\(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2} UTC, exit code (\d+), [^)]+\)",
+            "([TIMESTAMP], exit code $1, [TIME])",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.run().arg("--history"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    uv run python -c 'import sys; sys.exit(3)' ([TIMESTAMP], exit code 3, [TIME])
+    uv run --with iniconfig python -c 'import iniconfig; print('"'"'hello'"'"')' ([TIMESTAMP], exit code 0, [TIME])
+    uv run --with iniconfig python -c 'import iniconfig; print('"'"'hello'"'"')' ([TIMESTAMP], exit code 0, [TIME])
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Invocations aren't recorded with `--no-history`, `UV_NO_RUN_HISTORY`, or `run.history = false`.
+#[test]
+fn run_no_history() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+        "#
+    })?;
+
+    context
+        .run()
+        .arg("--no-history")
+        .arg("python")
+        .arg("-c")
+        .arg("print('secret')")
+        .assert()
+        .success();
+
+    context
+        .run()
+        .env(EnvVars::UV_NO_RUN_HISTORY, "1")
+        .arg("python")
+        .arg("-c")
+        .arg("print('secret')")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--history"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No `uv run` invocations have been recorded
+    ");
+
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+
+        [tool.uv.run]
+        history = false
+        "#
+    })?;
+
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("print('secret')")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--last"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No previous `uv run` invocation was found in the project
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn run_with_repl() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    let input = context.temp_dir.child("input.py");
+    input.write_str("import sniffio; print(sniffio.__name__)\n")?;
+
+    // The REPL is layered on top of the project environment.
+    context
+        .run()
+        .arg("--ipython")
+        .stdin(std::fs::File::open(&input)?)
+        .assert()
+        .success()
+        .stdout(contains("sniffio"));
+
+    // The REPL is not added to the project's dependencies.
+    assert!(!fs_err::read_to_string(&pyproject_toml)?.contains("ipython"));
+    assert!(!fs_err::read_to_string(context.temp_dir.child("uv.lock"))?.contains("ipython"));
+
+    // A REPL cannot be combined with a command.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--ipython")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--ipython` cannot be used with a command
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--ipython")
+        .arg("--ptpython"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--ipython' cannot be used with '--ptpython'
+
+    Usage: uv run --ipython [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn run_kernel() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+        "#
+    })?;
+
+    context.temp_dir.child(".env").write_str("FOO=bar\n")?;
+
+    let jupyter_data_dir = context.temp_dir.child("jupyter");
+
+    // The kernel spec re-invokes `uv run` with the same arguments, from the current directory.
+    context
+        .run()
+        .arg("--kernel")
+        .arg("demo")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--env-file")
+        .arg(".env")
+        .env(EnvVars::JUPYTER_DATA_DIR, jupyter_data_dir.as_os_str())
+        .assert()
+        .success();
+
+    let kernel_json = fs_err::read_to_string(
+        jupyter_data_dir
+            .child("kernels")
+            .child("demo")
+            .child("kernel.json"),
+    )?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(kernel_json, @r#"
+        {
+          "argv": [
+            "[UV]",
+            "--directory",
+            "[TEMP_DIR]/",
+            "run",
+            "--cache-dir",
+            "[CACHE_DIR]/",
+            "--with",
+            "iniconfig",
+            "--env-file",
+            ".env",
+            "--with",
+            "ipykernel",
+            "--",
+            "python",
+            "-m",
+            "ipykernel_launcher",
+            "-f",
+            "{connection_file}"
+          ],
+          "display_name": "demo",
+          "language": "python",
+          "env": {
+            "UV_NO_PROGRESS": "1"
+          },
+          "metadata": {
+            "debugger": true
+          }
+        }
+        "#);
+    });
+
+    // `ipykernel` is not added to the project's dependencies.
+    assert!(!fs_err::read_to_string(&pyproject_toml)?.contains("ipykernel"));
 
-    // If we request a dependency that isn't in the base environment, we should still respect any
-    // other dependencies. In this case, `sniffio==1.3.0` is not the latest-compatible version, but
-    // we should use it anyway.
-    uv_snapshot!(context.filters(), context.run().arg("--with").arg("anyio").arg("main.py"), @r"
-    success: true
-    exit_code: 0
-    ----- stdout -----
-    1.3.0
+    // With `--directory`, the kernel is launched from the target directory.
+    let subdirectory = context.temp_dir.child("subdirectory");
+    subdirectory.create_dir_all()?;
+    context
+        .run()
+        .arg("--directory")
+        .arg("..")
+        .arg("--kernel=demo")
+        .current_dir(&subdirectory)
+        .env(EnvVars::JUPYTER_DATA_DIR, jupyter_data_dir.as_os_str())
+        .assert()
+        .success();
 
-    ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Audited 2 packages in [TIME]
-    Resolved 3 packages in [TIME]
-    Prepared 2 packages in [TIME]
-    Installed 3 packages in [TIME]
-     + anyio==4.3.0
-     + idna==3.6
-     + sniffio==1.3.0
-    ");
+    let kernel_json: serde_json::Value = serde_json::from_str(&fs_err::read_to_string(
+        jupyter_data_dir
+            .child("kernels")
+            .child("demo")
+            .child("kernel.json"),
+    )?)?;
+    let argv = kernel_json["argv"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|arg| arg.as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(argv[1], "--directory");
+    assert_eq!(
+        fs_err::canonicalize(argv[2])?,
+        fs_err::canonicalize(context.temp_dir.path())?
+    );
+    assert!(!argv.contains(&"..") && !argv.contains(&"--kernel=demo"));
 
-    // Even if we run with` --no-sync`.
-    uv_snapshot!(context.filters(), context.run().arg("--with").arg("anyio==4.2.0").arg("--no-sync").arg("main.py"), @r"
-    success: true
-    exit_code: 0
+    // A kernel cannot be combined with a command.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--kernel")
+        .arg("demo")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
     ----- stdout -----
-    1.3.0
 
     ----- stderr -----
-    Resolved 3 packages in [TIME]
-    Prepared 1 package in [TIME]
-    Installed 3 packages in [TIME]
-     + anyio==4.2.0
-     + idna==3.6
-     + sniffio==1.3.0
+    error: `--kernel` cannot be used with a command
     ");
 
-    // If the dependencies can't be resolved, we should reference `--with`.
-    uv_snapshot!(context.filters(), context.run().arg("--with").arg("add").arg("main.py"), @r###"
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--kernel")
+        .arg("demo kernel"), @r"
     success: false
-    exit_code: 1
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 2 packages in [TIME]
-    Audited 2 packages in [TIME]
-      × No solution found when resolving `--with` dependencies:
-      ╰─▶ Because there are no versions of add and you require add, we can conclude that your requirements are unsatisfiable.
-    "###);
+    error: Invalid kernel name `demo kernel`: names may only contain ASCII letters, digits, `-`, `_`, and `.`
+    ");
 
     Ok(())
 }
@@ -2190,7 +3965,7 @@ fn run_locked() -> Result<()> {
         assert_snapshot!(
             existing, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2314,6 +4089,91 @@ fn run_locked() -> Result<()> {
     Ok(())
 }
 
+/// Check that the environment is ready to run, with `--check-only`, without running the command.
+#[test]
+fn run_check_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"at: .*python.*", "at: [PYTHON]")])
+        .collect::<Vec<_>>();
+
+    // Checking should error, if no lockfile is present.
+    uv_snapshot!(filters, context.run().arg("--check-only").arg("python").arg("--version"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    context.lock().assert().success();
+
+    // Checking should report the changes required to sync the environment.
+    uv_snapshot!(filters, context.run().arg("--check-only").arg("python").arg("--version"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + iniconfig==2.0.0
+    The environment is outdated; run `uv sync` to update the environment
+    "###);
+
+    context.sync().assert().success();
+
+    // Once synced, the environment is ready, and the command is not run.
+    uv_snapshot!(filters, context.run().arg("--check-only").arg("python").arg("--version"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Environment is ready to run with Python 3.12.[X] at: [PYTHON]
+    "###);
+
+    // Checking should error if the lockfile is outdated.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+        "#,
+    )?;
+
+    uv_snapshot!(filters, context.run().arg("--check-only").arg("python").arg("--version"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_frozen() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2379,6 +4239,74 @@ fn run_frozen() -> Result<()> {
     Ok(())
 }
 
+/// With `--frozen`, a lockfile with a newer schema version is read in a read-only compatibility
+/// mode, ignoring the fields added in the newer schema.
+#[test]
+fn run_frozen_newer_lock_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Bump the schema version, and add a field that this version of uv doesn't recognize.
+    let lock =
+        context
+            .read("uv.lock")
+            .replacen("version = 1\n", "version = 2\nnew-field = true\n", 1);
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("python").arg("-c").arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The lockfile at `uv.lock` uses a newer schema version (v2, but only v1 is supported). Reading it in read-only compatibility mode, which ignores any fields added in the newer schema; upgrade uv for full support.
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The locked versions are also used as preferences for `--with` requirements.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--with").arg("sniffio").arg("python").arg("-c").arg("import sniffio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The lockfile at `uv.lock` uses a newer schema version (v2, but only v1 is supported). Reading it in read-only compatibility mode, which ignores any fields added in the newer schema; upgrade uv for full support.
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    ");
+
+    // Commands that may write the lockfile still require a supported schema version.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile at `uv.lock` uses an unsupported schema version (v2, but only v1 is supported). Downgrade to a compatible uv version, or remove the `uv.lock` prior to running `uv lock` or `uv sync`.
+    ");
+
+    // The lockfile is left unchanged.
+    assert_eq!(context.read("uv.lock"), lock);
+
+    Ok(())
+}
+
 #[test]
 fn run_no_sync() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2900,32 +4828,175 @@ fn run_from_directory() -> Result<()> {
     success: true
     exit_code: 0
     ----- stdout -----
-    3.10.[X]
+    3.10.[X]
+
+    ----- stderr -----
+    warning: `VIRTUAL_ENV=.venv` does not match the project environment path `[PROJECT_VENV]/` and will be ignored; use `--active` to target the active environment instead
+    Using CPython 3.10.[X] interpreter at: [PYTHON-3.10]
+    Creating virtual environment at: [PROJECT_VENV]/
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/project)
+    "###);
+
+    fs_err::remove_dir_all(context.temp_dir.join("project").join(".venv"))?;
+    uv_snapshot!(filters.clone(), context.run().arg("--directory").arg("project").arg("main"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.10.[X]
+
+    ----- stderr -----
+    warning: `VIRTUAL_ENV=[VENV]/` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
+    Using CPython 3.10.[X] interpreter at: [PYTHON-3.10]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/project)
+    "###);
+
+    Ok(())
+}
+
+/// `uv run --directory` anchors project, `.python-version`, and `--env-file` discovery to the given
+/// directory, including for workspace members and for projects nested within a workspace.
+#[test]
+fn run_from_directory_nested_workspace() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! { r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+        exclude = ["packages/standalone"]
+        "#
+        })?;
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.12")?;
+    context.temp_dir.child(".env").write_str("FOO=root")?;
+
+    // A workspace member, which shares the workspace environment.
+    let child = context.temp_dir.child("packages").child("child");
+    child.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#
+    })?;
+    child.child(".env").write_str("FOO=child")?;
+
+    // A standalone project nested within the workspace directory, with its own environment.
+    let standalone = context.temp_dir.child("packages").child("standalone");
+    standalone.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "standalone"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+        "#
+    })?;
+    standalone
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+    standalone.child(".env").write_str("FOO=standalone")?;
+    standalone.child("script.py").write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = []
+        # ///
+        import sys
+
+        print(f"{sys.version_info.major}.{sys.version_info.minor}")
+        "#
+    })?;
+
+    let script = "import os, sys; print(f'{sys.version_info.major}.{sys.version_info.minor}', os.environ.get('FOO'))";
+
+    // The member runs in the workspace environment, with the `.env` file of the member.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--directory")
+        .arg("packages/child")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12 child
 
     ----- stderr -----
-    warning: `VIRTUAL_ENV=.venv` does not match the project environment path `[PROJECT_VENV]/` and will be ignored; use `--active` to target the active environment instead
-    Using CPython 3.10.[X] interpreter at: [PYTHON-3.10]
-    Creating virtual environment at: [PROJECT_VENV]/
-    Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + foo==1.0.0 (from file://[TEMP_DIR]/project)
-    "###);
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
 
-    fs_err::remove_dir_all(context.temp_dir.join("project").join(".venv"))?;
-    uv_snapshot!(filters.clone(), context.run().arg("--directory").arg("project").arg("main"), @r###"
+    // The nested project runs in its own environment, with its own `.python-version` file.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--directory")
+        .arg("packages/standalone")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
     success: true
     exit_code: 0
     ----- stdout -----
-    3.10.[X]
+    3.11 standalone
 
     ----- stderr -----
     warning: `VIRTUAL_ENV=[VENV]/` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
-    Using CPython 3.10.[X] interpreter at: [PYTHON-3.10]
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
     Creating virtual environment at: .venv
     Resolved 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + foo==1.0.0 (from file://[TEMP_DIR]/project)
-    "###);
+    Audited in [TIME]
+    ");
+
+    // A script in the nested project respects the `.python-version` file next to it.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--directory")
+        .arg("packages/standalone")
+        .arg("script.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11
+
+    ----- stderr -----
+    ");
+
+    // With `--project`, the project is discovered from the given directory, but relative paths
+    // are resolved from the current directory.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--project")
+        .arg("packages/child")
+        .arg("--env-file")
+        .arg(".env")
+        .arg("python")
+        .arg("-c")
+        .arg(script), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.12 root
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    ");
 
     Ok(())
 }
@@ -3187,6 +5258,72 @@ fn run_stdin() -> Result<()> {
     Ok(())
 }
 
+/// For a script read from stdin, the `.python-version` file is discovered from the project
+/// directory, as set by `--project` or `--directory`, rather than the current directory.
+#[test]
+fn run_stdin_script_project_directory() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.10", "3.11"]);
+
+    context
+        .temp_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.11")?;
+
+    let project_dir = context.temp_dir.child("project");
+    project_dir
+        .child(PYTHON_VERSION_FILENAME)
+        .write_str("3.10")?;
+    project_dir.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.10"
+        dependencies = []
+        "#
+    })?;
+
+    let test_script = context.temp_dir.child("script");
+    test_script.write_str(indoc! { r#"
+        # /// script
+        # requires-python = ">=3.10"
+        # dependencies = []
+        # ///
+        import platform
+        print(platform.python_version())
+       "#
+    })?;
+
+    // By default, the `.python-version` file in the current directory is used.
+    uv_snapshot!(context.filters(), context.run().arg("-").stdin(std::fs::File::open(&test_script)?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.11.[X]
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--project").arg("project").arg("-").stdin(std::fs::File::open(&test_script)?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.10.[X]
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("--directory").arg("project").arg("-").stdin(std::fs::File::open(&test_script)?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    3.10.[X]
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn run_package() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3297,6 +5434,112 @@ fn run_module() {
 
     ----- stderr -----
     "#);
+
+    // Missing modules are reported before spawning the interpreter.
+    uv_snapshot!(context.filters(), context.run().arg("-m").arg("does_not_exist.main"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Module `does_not_exist.main` was not found in the environment
+    ");
+}
+
+/// Run a module from a project installed in editable mode, which is only importable via the path
+/// added by a `.pth` file.
+#[test]
+fn run_module_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#
+    })?;
+    let package = context.temp_dir.child("src").child("foo");
+    package.child("__init__.py").touch()?;
+    package
+        .child("__main__.py")
+        .write_str("print('Hello from foo')")?;
+
+    uv_snapshot!(context.filters(), context.run().arg("-m").arg("foo"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from foo
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+    ");
+
+    Ok(())
+}
+
+/// Run a module from a namespace package, i.e., a package without an `__init__.py`.
+#[test]
+fn run_module_namespace_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+
+        [tool.hatch.build.targets.wheel]
+        packages = ["src/ns"]
+        "#
+    })?;
+    let package = context.temp_dir.child("src").child("ns").child("tool");
+    package.child("__init__.py").touch()?;
+    package
+        .child("__main__.py")
+        .write_str("print('Hello from ns.tool')")?;
+
+    uv_snapshot!(context.filters(), context.run().arg("-m").arg("ns.tool"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from ns.tool
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // A missing top-level package is still reported before spawning the interpreter.
+    uv_snapshot!(context.filters(), context.run().arg("-m").arg("missing_ns.tool"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited 1 package in [TIME]
+    error: Module `missing_ns.tool` was not found in the environment
+    ");
+
+    Ok(())
 }
 
 #[test]
@@ -4409,6 +6652,140 @@ fn run_remote_pep723_script() {
     "###);
 }
 
+#[tokio::test]
+async fn run_remote_pep723_script_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = indoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # ///
+
+        import iniconfig
+
+        print("Hello, world!")
+       "#
+    };
+
+    // Lock the script locally, to publish the lockfile alongside it.
+    context.temp_dir.child("main.py").write_str(script)?;
+    context
+        .lock()
+        .arg("--script")
+        .arg("main.py")
+        .assert()
+        .success();
+    let lock = context.read("main.py.lock");
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/main.py"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(script))
+        .mount(&server)
+        .await;
+
+    // Without a published lockfile, `--frozen` should fail.
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg(format!("{}/main.py", server.uri())), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No lockfile found for remote script `http://[LOCALHOST]/main.py`, but `--frozen` was provided; publish a lockfile at `http://[LOCALHOST]/main.py.lock` or declare `tool.uv.lock-url` in the script metadata
+    ");
+
+    Mock::given(method("GET"))
+        .and(path("/main.py.lock"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(lock))
+        .mount(&server)
+        .await;
+
+    // With a published lockfile, the script should be installed from the lockfile.
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg(format!("{}/main.py", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
+/// If the lockfile published alongside a remote script can't be fetched, e.g., because its host
+/// is unreachable, the script is run without it, unless `--locked` or `--frozen` is provided.
+#[tokio::test]
+async fn run_remote_pep723_script_lock_unreachable() -> Result<()> {
+    use indoc::formatdoc;
+
+    let context = TestContext::new("3.12");
+
+    // Start a server to determine a free address, then shut it down, such that requests to the
+    // address fail with a connection error.
+    let unreachable = MockServer::start().await;
+    let lock_url = format!("{}/main.py.lock", unreachable.uri());
+    drop(unreachable);
+
+    let script = formatdoc! { r#"
+        # /// script
+        # requires-python = ">=3.11"
+        # dependencies = [
+        #   "iniconfig",
+        # ]
+        # [tool.uv]
+        # lock-url = "{lock_url}"
+        # ///
+
+        import iniconfig
+
+        print("Hello, world!")
+       "#
+    };
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/main.py"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(script))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.run().arg(format!("{}/main.py", server.uri())), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, world!
+
+    ----- stderr -----
+    warning: Failed to fetch the lockfile for the remote script from `http://[LOCALHOST]/main.py.lock`; running without a lockfile
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // With `--locked` or `--frozen`, the lockfile is required.
+    for flag in ["--locked", "--frozen"] {
+        context
+            .run()
+            .arg(flag)
+            .arg(format!("{}/main.py", server.uri()))
+            .assert()
+            .failure()
+            .stderr(contains(
+                "error: Failed to fetch the lockfile for the remote script from `http://",
+            ));
+    }
+
+    Ok(())
+}
+
 #[cfg(unix)] // A URL could be a valid filepath on Unix but not on Windows
 #[test]
 fn run_url_like_with_local_file_priority() -> Result<()> {
@@ -5752,7 +8129,7 @@ fn run_pep723_script_with_constraints_lock() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -5913,6 +8290,202 @@ fn run_no_sync_incompatible_python() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_fail_fast_env() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.9"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // Using an incompatible environment due to `--no-sync` is an error.
+    uv_snapshot!(context.filters(), context.run().arg("--fail-fast-env").arg("--no-sync").arg("--python").arg("3.9").arg("python").arg("--version"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Using incompatible environment (`.venv`) due to `--no-sync` (The project environment's Python version does not satisfy the request: `Python 3.9`) [incompatible-environment]
+    ");
+
+    // As is a `VIRTUAL_ENV` that would be ignored.
+    uv_snapshot!(context.filters(), context.run().arg("--fail-fast-env").arg("python").arg("--version").env(EnvVars::VIRTUAL_ENV, context.temp_dir.child("other").as_os_str()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `VIRTUAL_ENV=other` does not match the project environment path `.venv`; use `--active` to target the active environment instead [ignored-virtual-env]
+    ");
+
+    // Unless the active environment is explicitly requested, or ignored.
+    context
+        .run()
+        .arg("--fail-fast-env")
+        .arg("--no-active")
+        .arg("python")
+        .arg("--version")
+        .env(
+            EnvVars::VIRTUAL_ENV,
+            context.temp_dir.child("other").as_os_str(),
+        )
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn run_exit_code_map() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    // A missing lockfile is distinguished from a failing command.
+    uv_snapshot!(context.filters(), context.run().arg("--exit-code-map").arg("reserved").arg("--locked").arg("python").arg("--version"), @r"
+    success: false
+    exit_code: 203
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    ");
+
+    // As is a missing interpreter.
+    context
+        .run()
+        .arg("--exit-code-map")
+        .arg("reserved")
+        .arg("--python")
+        .arg("3.11")
+        .arg("python")
+        .arg("--version")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
+        .assert()
+        .code(202);
+
+    // The exit code of the command is propagated as-is.
+    context
+        .run()
+        .arg("--exit-code-map")
+        .arg("reserved")
+        .arg("python")
+        .arg("-c")
+        .arg("import sys; sys.exit(3)")
+        .assert()
+        .code(3);
+
+    // By default, uv's failures exit with `2`.
+    context
+        .run()
+        .arg("--locked")
+        .arg("--python")
+        .arg("3.11")
+        .arg("python")
+        .arg("--version")
+        .env(EnvVars::UV_PYTHON_DOWNLOADS, "never")
+        .assert()
+        .code(2);
+
+    // The scheme also applies to failures before the settings are resolved, e.g., an invalid
+    // `pyproject.toml`.
+    pyproject_toml.write_str("[project")?;
+    context
+        .run()
+        .arg("--exit-code-map")
+        .arg("reserved")
+        .arg("python")
+        .arg("--version")
+        .assert()
+        .code(200);
+
+    Ok(())
+}
+
+#[test]
+fn run_with_requirements_from() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["sniffio==1.3.0"]
+
+        [dependency-groups]
+        debug = ["iniconfig"]
+        "#
+    })?;
+
+    // The group is installed from the lockfile into an environment layered on top of the project
+    // environment.
+    uv_snapshot!(context.filters(), context.run().arg("--with-requirements-from").arg("debug").arg("python").arg("-c").arg("import iniconfig, sniffio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.0
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // The layered environment is reused.
+    uv_snapshot!(context.filters(), context.run().arg("--with-requirements-from").arg("debug").arg("python").arg("-c").arg("import iniconfig, sniffio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    // The project environment doesn't include the group.
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("import iniconfig")
+        .assert()
+        .failure();
+
+    // Unknown groups are rejected.
+    uv_snapshot!(context.filters(), context.run().arg("--with-requirements-from").arg("lint").arg("python").arg("-c").arg("import sniffio"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: Group `lint` is not defined in the project's `dependency-groups` table
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_python_preference_no_project() {
     let context =
@@ -5958,3 +8531,164 @@ fn run_python_preference_no_project() {
     ----- stderr -----
     ");
 }
+
+/// Control the sources of the `PATH` entries of the command, as requested on the command line or via
+/// the `run.path-policy` setting.
+#[test]
+#[cfg(unix)]
+fn run_path_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+    context.sync().assert().success();
+
+    let path = "import os; print(os.environ.get('PATH'))";
+
+    // Only the project's scripts are exposed.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--path-policy")
+        .arg("base")
+        .arg("python")
+        .arg("-c")
+        .arg(path), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    // `PATH` is cleared entirely.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--path-policy")
+        .arg("none")
+        .arg("python")
+        .arg("-c")
+        .arg(path), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--path-policy")
+        .arg("base,base")
+        .arg("python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'base,base' for '--path-policy <SOURCES>': `base` was provided more than once
+
+    For more information, try '--help'.
+    ");
+
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.run]
+        path-policy = ["base"]
+        "#
+    })?;
+
+    // The setting applies to every command.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("python")
+        .arg("-c")
+        .arg(path), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [VENV]/bin
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Run an isolated command without network access, as requested on the command line or via the
+/// `run.network` setting.
+#[test]
+#[cfg(target_os = "linux")]
+fn run_isolated_network_off() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let interfaces = "import socket; print(sorted(name for _, name in socket.if_nameindex()))";
+
+    // The command is run in a network namespace with only a loopback interface.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--isolated")
+        .arg("--network")
+        .arg("off")
+        .arg("python")
+        .arg("-c")
+        .arg(interfaces), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['lo']
+
+    ----- stderr -----
+    ");
+
+    // `--network` requires `--isolated`.
+    context
+        .run()
+        .arg("--no-project")
+        .arg("--network")
+        .arg("off")
+        .arg("python")
+        .arg("-c")
+        .arg(interfaces)
+        .assert()
+        .failure()
+        .stderr(contains("--isolated"));
+
+    context.temp_dir.child("uv.toml").write_str(indoc! { r#"
+        [run]
+        network = "off"
+    "#
+    })?;
+
+    // The setting applies to isolated commands.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-project")
+        .arg("--isolated")
+        .arg("python")
+        .arg("-c")
+        .arg(interfaces), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ['lo']
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
@@ -82,6 +82,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -103,6 +104,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -156,6 +158,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -233,7 +240,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -281,6 +288,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -302,6 +310,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -355,6 +364,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -432,7 +446,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -481,6 +495,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -502,6 +517,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -555,6 +571,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -632,7 +653,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -713,6 +734,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -734,6 +756,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -787,6 +810,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -864,7 +892,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -914,6 +942,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -935,6 +964,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -954,6 +984,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1031,7 +1066,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -1091,6 +1126,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -1112,6 +1148,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1165,6 +1202,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1244,7 +1286,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -1317,6 +1359,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -1338,6 +1381,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1424,6 +1468,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1501,7 +1550,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -1551,6 +1600,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -1572,6 +1622,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1693,6 +1744,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1770,7 +1826,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -1843,6 +1899,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -1864,6 +1921,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1917,6 +1975,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -1994,7 +2057,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -2066,6 +2129,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -2087,6 +2151,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2106,6 +2171,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2183,7 +2253,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -2248,6 +2318,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -2269,6 +2340,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2355,6 +2427,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2432,7 +2509,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -2480,6 +2557,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -2501,6 +2579,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2587,6 +2666,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2664,7 +2748,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -2735,6 +2819,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -2756,6 +2841,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2775,6 +2861,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -2852,7 +2943,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -2907,6 +2998,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -2928,6 +3020,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2947,6 +3040,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3024,7 +3122,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -3079,6 +3177,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -3100,6 +3199,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3119,6 +3219,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3196,7 +3301,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -3253,6 +3358,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -3274,6 +3380,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3293,6 +3400,11 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3370,7 +3482,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -3446,6 +3558,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -3534,7 +3647,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -3551,11 +3664,17 @@ fn resolve_tool() -> anyhow::Result<()> {
             reinstall: None,
         },
         force: false,
+        fail_fast_env: false,
         editable: false,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
     }
 
@@ -3630,6 +3749,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -3651,6 +3771,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3670,6 +3791,11 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3747,7 +3873,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -3836,6 +3962,7 @@ fn resolve_both() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -3857,6 +3984,7 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3910,6 +4038,11 @@ fn resolve_both() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -3987,7 +4120,7 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -4080,6 +4213,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -4101,6 +4235,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4154,6 +4289,11 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4231,7 +4371,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -4403,6 +4543,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -4424,6 +4565,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4477,6 +4619,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4554,7 +4701,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -4597,7 +4744,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `python-install-keyring-provider`, `python-install-ca-certs`, `python-resolution`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `run`, `python-install-hook`, `python-discovery`, `python-install-dir`, `install-staging-dir`, `python-query-env`, `python-query-limits`, `max-environment-size`, `max-environment-size-action`, `export-activate`, `fail-fast-env`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `lock-interpreter`, `manage-ignore-files`, `package`, `python`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4702,6 +4849,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -4723,6 +4871,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4742,6 +4891,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4819,7 +4973,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -4877,6 +5031,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -4898,6 +5053,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4917,6 +5073,11 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -4994,7 +5155,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -5071,6 +5232,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -5092,6 +5254,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5111,6 +5274,11 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5188,7 +5356,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -5257,6 +5425,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -5278,6 +5447,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5366,6 +5536,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5443,7 +5618,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -5491,6 +5666,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -5512,6 +5688,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5600,6 +5777,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5677,7 +5859,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -5731,6 +5913,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -5752,6 +5935,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5840,6 +6024,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -5917,7 +6106,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -5966,6 +6155,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -5987,6 +6177,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6075,6 +6266,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6152,7 +6348,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -6208,6 +6404,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -6229,6 +6426,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6317,6 +6515,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6394,7 +6597,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -6443,6 +6646,7 @@ fn index_priority() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -6464,6 +6668,7 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6552,6 +6757,11 @@ fn index_priority() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6629,7 +6839,7 @@ fn index_priority() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -6691,6 +6901,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -6708,6 +6919,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6731,6 +6943,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6808,7 +7025,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -6856,6 +7073,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -6873,6 +7091,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -6896,6 +7115,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -6973,7 +7197,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
@@ -7019,6 +7243,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7036,6 +7261,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7059,6 +7285,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7136,7 +7367,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -7184,6 +7415,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7201,6 +7433,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7224,6 +7457,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7301,7 +7539,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
@@ -7347,6 +7585,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7364,6 +7603,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7387,6 +7627,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7464,7 +7709,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
@@ -7511,6 +7756,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7528,6 +7774,7 @@ fn verify_hashes() -> anyhow::Result<()> {
         overrides: [],
         build_constraints: [],
         dry_run: Disabled,
+        unlock: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
@@ -7551,6 +7798,11 @@ fn verify_hashes() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -7628,7 +7880,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -7690,6 +7942,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7707,12 +7960,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -7751,7 +8010,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -7802,6 +8061,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7819,12 +8079,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -7863,7 +8129,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -7914,6 +8180,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -7931,12 +8198,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -7975,7 +8248,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -8026,6 +8299,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8043,12 +8317,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -8087,7 +8367,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -8138,6 +8418,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8155,12 +8436,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -8199,7 +8486,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -8252,6 +8539,7 @@ fn preview_features() {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8269,12 +8557,18 @@ fn preview_features() {
         frozen: false,
         active: None,
         no_sync: false,
+        unlock: false,
         package: None,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -8313,7 +8607,7 @@ fn preview_features() {
                 },
                 index_strategy: FirstIndex,
                 keyring_provider: Disabled,
-                link_mode: Clone,
+                link_mode: None,
                 build_isolation: Isolate,
                 extra_build_dependencies: ExtraBuildDependencies(
                     {},
@@ -8385,6 +8679,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8406,6 +8701,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8425,6 +8721,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -8502,7 +8803,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -8558,6 +8859,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8579,6 +8881,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8598,6 +8901,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -8675,7 +8983,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -8754,6 +9062,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8775,6 +9084,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8794,6 +9104,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -8871,7 +9186,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -8925,6 +9240,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -8946,6 +9262,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -8965,6 +9282,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -9042,7 +9364,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -9090,6 +9412,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9111,6 +9434,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9130,6 +9454,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -9207,7 +9536,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -9256,6 +9585,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9277,6 +9607,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9296,6 +9627,11 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -9373,7 +9709,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -9487,6 +9823,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9499,11 +9836,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -9541,7 +9884,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9604,6 +9947,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9616,11 +9960,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -9658,7 +10008,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9744,6 +10094,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9756,11 +10107,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -9798,7 +10155,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9859,6 +10216,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9871,11 +10229,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -9913,7 +10277,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -9964,6 +10328,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -9976,11 +10341,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -10018,7 +10389,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -10070,6 +10441,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -10082,11 +10454,17 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
         frozen: false,
         dry_run: Disabled,
         script: None,
+        fail_fast_env: false,
         python: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
             python_downloads_json_url: None,
+            python_install_keyring_provider: None,
+            python_install_ca_certs: None,
+            python_resolution: None,
+            python_install_dir: None,
+            install_staging_dir: None,
         },
         refresh: None(
             Timestamp(
@@ -10124,7 +10502,7 @@ fn upgrade_project_cli_config_interaction() -> anyhow::Result<()> {
             },
             index_strategy: FirstIndex,
             keyring_provider: Disabled,
-            link_mode: Clone,
+            link_mode: None,
             build_isolation: Isolate,
             extra_build_dependencies: ExtraBuildDependencies(
                 {},
@@ -10240,6 +10618,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -10261,6 +10640,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10280,6 +10660,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -10357,7 +10742,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -10408,6 +10793,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         python_downloads: Automatic,
         no_progress: false,
         installer_metadata: true,
+        diagnostics: Text,
     }
     CacheSettings {
         no_cache: false,
@@ -10429,6 +10815,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        wsl: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10448,6 +10835,11 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 python_install_mirror: None,
                 pypy_install_mirror: None,
                 python_downloads_json_url: None,
+                python_install_keyring_provider: None,
+                python_install_ca_certs: None,
+                python_resolution: None,
+                python_install_dir: None,
+                install_staging_dir: None,
             },
             system: false,
             extras: ExtrasSpecification(
@@ -10531,7 +10923,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_marker_expression: false,
             emit_index_annotation: false,
             annotation_style: Split,
-            link_mode: Clone,
+            link_mode: None,
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: Some(
@@ -268,6 +268,98 @@ fn package() -> Result<()> {
     Ok(())
 }
 
+/// Sync a workspace member that overrides the workspace Python version with `tool.uv.python`.
+#[test]
+fn package_member_python() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]).with_filtered_python_names();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "root"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    fs_err::create_dir_all(&child)?;
+
+    let child_pyproject_toml = child.child("pyproject.toml");
+    child_pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig>=1"]
+
+        [tool.uv]
+        python = "3.11"
+        "#,
+    )?;
+
+    // The workspace environment is shared with the other members, so it shouldn't be replaced to
+    // satisfy the member's Python version.
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The Python request `3.11` from `child/pyproject.toml` is not satisfied by the workspace environment at `.venv` (Python 3.12.[X]), which is shared with the other members of the workspace
+    ");
+
+    assert!(context.venv.child("pyvenv.cfg").path().is_file());
+
+    // Without an existing workspace environment, syncing the member should use its Python version.
+    fs_err::remove_dir_all(context.venv.path())?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtual environment at: .venv
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // A Python version that's incompatible with the member's `requires-python` should error.
+    child_pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1"]
+
+        [tool.uv]
+        python = "3.11"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--package").arg("child"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The Python request `3.11` from `child/pyproject.toml` is incompatible with the Python requirement of `child`: `>=3.12`
+    ");
+
+    Ok(())
+}
+
 /// Test json output
 #[test]
 fn sync_json() -> Result<()> {
@@ -2800,7 +2892,7 @@ fn sync_relative_wheel() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -3452,6 +3544,117 @@ fn sync_dev_group() -> Result<()> {
     Ok(())
 }
 
+/// Report project errors as JSON with `--diagnostics json`.
+#[test]
+fn sync_diagnostics_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        foo = []
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Requesting a non-existent group should report the group.
+    uv_snapshot!(context.filters(), context.sync().arg("--group").arg("baz").arg("--diagnostics").arg("json"), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    {"severity":"error","code":"missing-group","message":"Group `baz` is not defined in the project's `dependency-groups` table","causes":[],"locations":[{"file":"pyproject.toml","package":"project","group":"baz"}]}
+    "#);
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        foo = []
+        "#,
+    )?;
+
+    // A lockfile mismatch is handled by the command, but should still be reported as JSON.
+    uv_snapshot!(context.filters(), context.sync().arg("--locked").arg("--diagnostics").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    {"severity":"error","code":"lock-mismatch","message":"The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.","causes":[],"locations":[{"file":"uv.lock"}]}
+    "#);
+
+    Ok(())
+}
+
+/// Report the `pyproject.toml` of the workspace member that made the declaration with
+/// `--diagnostics json`.
+#[test]
+fn sync_diagnostics_json_workspace_member() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#})?;
+
+    let child = context.temp_dir.child("packages").child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        foo = []
+        "#})?;
+
+    context.lock().assert().success();
+
+    // The missing group is reported against the member's `pyproject.toml`, relative to the
+    // workspace root, even when uv is invoked from within the member.
+    uv_snapshot!(context.filters(), context.sync().arg("--group").arg("baz").arg("--diagnostics").arg("json").current_dir(&child), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    {"severity":"error","code":"missing-group","message":"Group `baz` is not defined in the project's `dependency-groups` table","causes":[],"locations":[{"file":"packages/child/pyproject.toml","package":"child","group":"baz"}]}
+    "#);
+
+    Ok(())
+}
+
 #[test]
 fn sync_non_existent_group() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4355,7 +4558,7 @@ fn sync_group_legacy_non_project_member() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -4466,7 +4669,7 @@ fn sync_group_self() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5126,7 +5329,7 @@ fn no_install_workspace() -> Result<()> {
     ");
 
     // Remove the virtual environment.
-    fs_err::remove_dir_all(&context.venv)?;
+    fs_err::remove_dir_all(context.venv.path())?;
 
     // We don't require the `pyproject.toml` for non-root members, if `--frozen` is provided.
     fs_err::remove_file(child.join("pyproject.toml"))?;
@@ -5657,7 +5860,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5717,7 +5920,7 @@ fn convert_to_virtual() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5786,7 +5989,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -5851,7 +6054,7 @@ fn convert_to_package() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -7746,7 +7949,7 @@ fn sync_dynamic_extra() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -8243,6 +8446,108 @@ fn sync_no_sources_missing_member() -> Result<()> {
     Ok(())
 }
 
+/// Renaming the project updates the prompt of the existing project environment.
+#[test]
+fn sync_rename_project_prompt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.sync().assert().success();
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("prompt = foo\n"));
+
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "bar"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // The environment is reused, rather than recreated, but its prompt is updated.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("prompt = bar\n"));
+    assert!(!pyvenv_cfg.contains("prompt = foo"));
+
+    Ok(())
+}
+
+/// Replacing the project environment builds it aside and swaps it into place, discarding any
+/// staging directory left behind by an interrupted replacement.
+#[test]
+fn sync_replace_environment_staged() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context
+        .sync()
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    // Simulate a replacement that was interrupted after staging began.
+    let staging = context.temp_dir.child(".venv.staging");
+    staging.child("pyvenv.cfg").touch()?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Neither the staging directory nor the previous environment should remain.
+    staging.assert(predicate::path::missing());
+    context
+        .temp_dir
+        .child(".venv.old")
+        .assert(predicate::path::missing());
+
+    // The activation scripts should refer to the final location.
+    #[cfg(unix)]
+    {
+        let activate = fs_err::read_to_string(context.temp_dir.join(".venv/bin/activate"))?;
+        assert!(!activate.contains(".venv.staging"));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn sync_python_version() -> Result<()> {
     let context: TestContext = TestContext::new_with_versions(&["3.10", "3.11", "3.12"]);
@@ -8403,7 +8708,7 @@ fn sync_explicit() -> Result<()> {
     ");
 
     // Clear the environment.
-    fs_err::remove_dir_all(&context.venv)?;
+    fs_err::remove_dir_all(context.venv.path())?;
 
     // The package should be drawn from the cache.
     uv_snapshot!(context.filters(), context.sync(), @r"
@@ -9169,7 +9474,7 @@ fn sync_stale_egg_info() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9276,7 +9581,7 @@ fn sync_git_repeated_member_static_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9370,7 +9675,7 @@ fn sync_git_repeated_member_dynamic_metadata() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9488,7 +9793,7 @@ fn sync_git_repeated_member_backwards_path() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9670,7 +9975,7 @@ fn sync_git_path_dependency() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.13"
 
             [options]
@@ -9778,7 +10083,7 @@ fn sync_build_tag() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -10446,7 +10751,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -10551,7 +10856,7 @@ fn sync_locked_script() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.11"
 
         [options]
@@ -11317,7 +11622,7 @@ fn locked_version_coherence() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -11421,7 +11726,7 @@ fn sync_build_constraints() -> Result<()> {
             assert_snapshot!(
                 lock, @r#"
             version = 1
-            revision = 3
+            revision = 4
             requires-python = ">=3.12"
 
             [options]
@@ -11452,7 +11757,7 @@ fn sync_build_constraints() -> Result<()> {
     );
 
     fs_err::remove_dir_all(&context.cache_dir)?;
-    fs_err::remove_dir_all(&context.venv)?;
+    fs_err::remove_dir_all(context.venv.path())?;
 
     // We should also be able to read from the lockfile.
     uv_snapshot!(context.filters(), context.sync().arg("--locked"), @r"
@@ -12166,11 +12471,10 @@ fn sync_python_platform() -> Result<()> {
     Ok(())
 }
 
-/// See: <https://github.com/astral-sh/uv/issues/11648>
+/// Sync into a `--target` directory for another platform, without creating a project environment.
 #[test]
-#[cfg(not(windows))]
-fn conflicting_editable() -> Result<()> {
-    let context = TestContext::new("3.12");
+fn sync_target_python_platform() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
@@ -12179,7 +12483,193 @@ fn conflicting_editable() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = []
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--target").arg("target")
+        .arg("--python-platform").arg("x86_64-manylinux_2_17"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    context
+        .temp_dir
+        .child("target")
+        .child("iniconfig")
+        .assert(predicate::path::is_dir());
+    context.venv.assert(predicate::path::missing());
+
+    // A package without a wheel would be built for the current platform, so it's rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["source-distribution==0.0.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--target").arg("target")
+        .arg("--python-platform").arg("x86_64-manylinux_2_17"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `source-distribution` has no wheel for the requested `--python-platform` and would need to be built from source, which is not supported when syncing into a `--target` directory
+    ");
+
+    Ok(())
+}
+
+/// Verify the environment against the lockfile with `--verify`.
+#[test]
+fn sync_verify() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Nothing is installed yet.
+    uv_snapshot!(context.filters(), context.sync().arg("--verify"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Found 1 package that differs from the lockfile:
+     - iniconfig==2.0.0 (missing)
+    ");
+
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--verify"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Verified 1 package against the lockfile
+    ");
+
+    // Modify an installed file.
+    context
+        .site_packages()
+        .join("iniconfig")
+        .join("__init__.py")
+        .write_str("")?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--verify"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Would use project environment at: .venv
+    Found 1 package that differs from the lockfile:
+     ~ iniconfig==2.0.0 (`iniconfig/__init__.py` was modified)
+    ");
+
+    // Remove the dependency from the lockfile, such that the installed package is extraneous.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.sync().arg("--verify").arg("--output-format").arg("json"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "target": "project",
+      "project": {
+        "path": "[TEMP_DIR]/",
+        "workspace": {
+          "path": "[TEMP_DIR]/"
+        }
+      },
+      "sync": {
+        "environment": {
+          "path": "[VENV]/",
+          "python": {
+            "path": "[VENV]/[BIN]/[PYTHON]",
+            "version": "3.12.[X]",
+            "implementation": "cpython"
+          }
+        },
+        "action": "check"
+      },
+      "lock": {
+        "path": "[TEMP_DIR]/uv.lock",
+        "action": "use"
+      },
+      "verify": {
+        "checked": 0,
+        "missing": [],
+        "extraneous": [
+          {
+            "name": "iniconfig",
+            "installed": "2.0.0"
+          }
+        ],
+        "modified": []
+      },
+      "dry_run": true
+    }
+
+    ----- stderr -----
+    "#);
+
+    Ok(())
+}
+
+/// See: <https://github.com/astral-sh/uv/issues/11648>
+#[test]
+#[cfg(not(windows))]
+fn conflicting_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
         [dependency-groups]
         foo = [
             "child",
@@ -12244,7 +12734,7 @@ fn conflicting_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -12410,7 +12900,7 @@ fn undeclared_editable() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
         conflicts = [[
             { package = "project", group = "bar" },
@@ -12699,7 +13189,7 @@ fn sync_config_settings_package() -> Result<()> {
     assert!(finder.exists());
 
     // Remove the virtual environment.
-    fs_err::remove_dir_all(&context.venv)?;
+    fs_err::remove_dir_all(context.venv.path())?;
 
     // Install the `dependency` with `editable_mode=compat` scoped to the package.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -12740,7 +13230,7 @@ fn sync_config_settings_package() -> Result<()> {
     assert!(!finder.exists());
 
     // Remove the virtual environment.
-    fs_err::remove_dir_all(&context.venv)?;
+    fs_err::remove_dir_all(context.venv.path())?;
 
     // Install the `dependency` with `editable_mode=compat` scoped to another package.
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -13487,3 +13977,675 @@ fn sync_extra_build_dependencies_cache() -> Result<()> {
 
     Ok(())
 }
+
+/// A resolution-only environment, i.e., one created with `uv venv --python-platform`, can't be
+/// used as the project environment.
+#[test]
+fn sync_resolution_only_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The virtual environment at `.venv` is a resolution-only environment for `x86_64-pc-windows-msvc` and cannot be used to install packages or run commands
+    ");
+
+    // Nothing was installed into the environment.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Enforce the `max-environment-size` of the project environment.
+#[test]
+fn sync_max_environment_size() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-01T00:00Z");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain(vec![(r"\d+\.\d[KMGT]?i?B", "[SIZE]")])
+        .collect::<Vec<_>>();
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        max-environment-size = "1kB"
+    "#})?;
+
+    // The projected size exceeds the limit, so the sync should fail before installing anything.
+    uv_snapshot!(filters, context.sync(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    error: The environment is projected to reach [SIZE], which exceeds the `max-environment-size` of 1kB
+    ");
+
+    context.assert_command("import iniconfig").failure();
+
+    // With `max-environment-size-action = "warn"`, the sync should proceed.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        max-environment-size = "1kB"
+        max-environment-size-action = "warn"
+    "#})?;
+
+    uv_snapshot!(filters, context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    warning: The environment is projected to reach [SIZE], which exceeds the `max-environment-size` of 1kB
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Environment size: [SIZE] (limit: 1kB)
+    ");
+
+    // Within the limit, the size of the environment should be reported.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        max-environment-size = "1GB"
+    "#})?;
+
+    uv_snapshot!(filters, context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    Environment size: [SIZE] (limit: 1GB)
+    ");
+
+    // `uv run` only reports the size of the environment if it was modified.
+    uv_snapshot!(filters, context.run().arg("python").arg("-c").arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Export activation scripts for the project environment with `--export-activate`.
+#[test]
+#[cfg(unix)]
+fn sync_export_activate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--export-activate").arg("--export-activate").arg("fish"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(context.read(".envrc"), @r"
+        # Generated by uv; changes will be overwritten by `uv sync --export-activate`.
+        export VIRTUAL_ENV='[VENV]/'
+        export UV_PROJECT_ENVIRONMENT='[VENV]/'
+        PATH_add '[VENV]/bin'
+        ");
+        assert_snapshot!(context.read(".uv/activate.fish"), @r"
+        # Generated by uv; changes will be overwritten by `uv sync --export-activate`.
+        set -gx VIRTUAL_ENV '[VENV]/'
+        set -gx UV_PROJECT_ENVIRONMENT '[VENV]/'
+        set -gx PATH '[VENV]/bin' $PATH
+        ");
+    });
+
+    // The `export-activate` setting should be respected, too.
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        export-activate = ["sh"]
+    "#})?;
+
+    context.sync().assert().success();
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(context.read(".uv/activate.sh"), @r#"
+        # Generated by uv; changes will be overwritten by `uv sync --export-activate`.
+        export VIRTUAL_ENV='[VENV]/'
+        export UV_PROJECT_ENVIRONMENT='[VENV]/'
+        export PATH='[VENV]/bin'"${PATH:+:$PATH}"
+        "#);
+    });
+
+    // An `.envrc` that wasn't generated by uv should be left untouched.
+    context
+        .temp_dir
+        .child(".envrc")
+        .write_str("layout python\n")?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--export-activate"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    warning: Skipping export of the activation script to `.envrc`, which was not generated by uv
+    ");
+
+    assert_eq!(context.read(".envrc"), "layout python\n");
+
+    Ok(())
+}
+
+/// Record the interpreter used to lock the project, and warn when syncing with another.
+#[test]
+fn sync_lock_interpreter() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv]
+        lock-interpreter = true
+        "#,
+    )?;
+
+    context
+        .lock()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.11"
+
+        [interpreter]
+        implementation = "cpython"
+        version = "3.12"
+        requires-python = ">=3.11"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        "#
+        );
+    });
+
+    // Syncing with a different minor version warns.
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.11"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    warning: The Python interpreter at `[PYTHON-3.11]` differs from the one used to lock the project (`cpython-3.12`): Python version `3.11` (locked with `3.12`)
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    // The recorded interpreter is retained when relocking.
+    assert!(context.read("uv.lock").contains("version = \"3.12\""));
+
+    // With `--strict-python`, the difference is an error.
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.11").arg("--strict-python"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The Python interpreter at `[VENV]/[BIN]/[PYTHON]` differs from the one used to lock the project (`cpython-3.12`): Python version `3.11` (locked with `3.12`) [interpreter-mismatch]
+    ");
+
+    Ok(())
+}
+
+/// Record the range of Python versions requested via `.python-version` with the locking
+/// interpreter, and warn when syncing with an interpreter outside of the range.
+#[test]
+fn sync_lock_interpreter_requested_range() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = []
+
+        [tool.uv]
+        lock-interpreter = true
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str("3.12")?;
+
+    context.lock().assert().success();
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 4
+        requires-python = ">=3.11"
+
+        [interpreter]
+        implementation = "cpython"
+        version = "3.12"
+        requires-python = "==3.12.*"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        "#
+        );
+    });
+
+    // Narrowing the requested range updates the recorded range, but retains the interpreter.
+    context
+        .temp_dir
+        .child(".python-version")
+        .write_str(">=3.12.99")?;
+
+    context
+        .lock()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    assert!(
+        context
+            .read("uv.lock")
+            .contains("requires-python = \">=3.12.99\"")
+    );
+
+    // Syncing with an interpreter outside of the range warns, even though the minor version
+    // matches.
+    uv_snapshot!(context.filters(), context.sync().arg("--python").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: The Python interpreter at `[PYTHON-3.12]` differs from the one used to lock the project (`cpython-3.12`): Python version `3.12.[X]` (locked with `>=3.12.99`)
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// With `manage-ignore-files`, the project environment is added to the workspace's ignore files.
+#[test]
+fn sync_manage_ignore_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        manage-ignore-files = true
+        "#,
+    )?;
+    context.temp_dir.child(".gitignore").write_str("*.pyc\n")?;
+    context.temp_dir.child(".dockerignore").write_str("")?;
+
+    context.sync().assert().success();
+
+    assert_snapshot!(context.read(".gitignore"), @r"
+    *.pyc
+
+    # Project environments, managed by uv (`manage-ignore-files`)
+    /.venv
+    # End of project environments
+    ");
+    assert_snapshot!(context.read(".dockerignore"), @r"
+    # Project environments, managed by uv (`manage-ignore-files`)
+    /.venv
+    # End of project environments
+    ");
+
+    // Once the environment is removed, its entry is replaced by the new environment's.
+    fs_err::remove_dir_all(context.temp_dir.child(".venv"))?;
+    context
+        .sync()
+        .env(EnvVars::UV_PROJECT_ENVIRONMENT, ".venv-dev")
+        .assert()
+        .success();
+
+    assert_snapshot!(context.read(".gitignore"), @r"
+    *.pyc
+
+    # Project environments, managed by uv (`manage-ignore-files`)
+    /.venv-dev
+    # End of project environments
+    ");
+
+    Ok(())
+}
+
+/// If the requested Python version isn't available, lock against markers synthesized for it, and
+/// fail with a hint to install it.
+#[test]
+fn sync_unavailable_python_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    // The requested version is validated against `requires-python` before locking.
+    context
+        .sync()
+        .arg("--python")
+        .arg("3.10")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "incompatible with the project's Python requirement: `>=3.11`",
+        ));
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::missing());
+
+    context
+        .sync()
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "The lockfile is up-to-date for Python 3.11; install it with `uv python install 3.11`",
+        ));
+    context
+        .temp_dir
+        .child("uv.lock")
+        .assert(predicate::path::exists());
+
+    Ok(())
+}
+
+/// If the active virtual environment is ignored, and its interpreter doesn't satisfy the project's
+/// Python requirement, explain why.
+#[test]
+fn sync_active_environment_skip_reason() -> Result<()> {
+    use predicates::prelude::PredicateBooleanExt;
+
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context
+        .venv()
+        .arg("active")
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    context
+        .sync()
+        .env(EnvVars::VIRTUAL_ENV, "active")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Ignoring the active virtual environment at `active`: Python 3.11",
+        ))
+        .stderr(predicate::str::contains(
+            "does not satisfy the request for Python >=3.12",
+        ));
+
+    // No reason is given if the interpreter would satisfy the request.
+    context
+        .venv()
+        .arg("active")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--clear")
+        .assert()
+        .success();
+
+    context
+        .sync()
+        .env(EnvVars::VIRTUAL_ENV, "active")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Ignoring the active virtual environment").not());
+
+    Ok(())
+}
+
+/// A project environment that was locked with `uv venv --lock-environment` isn't recreated, e.g.,
+/// to satisfy the project's `requires-python`, unless `--unlock` is provided.
+#[test]
+fn sync_locked_environment_recreate() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+    })?;
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.11")
+        .arg("--no-project")
+        .assert()
+        .success();
+    context
+        .venv()
+        .arg("--lock-environment")
+        .arg("--no-project")
+        .assert()
+        .success();
+
+    context
+        .sync()
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "is locked against modification; pass `--unlock` to modify it anyway",
+        ));
+
+    context
+        .sync()
+        .arg("--unlock")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Removed virtual environment at: .venv",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn sync_fail_fast_env_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        fail-fast-env = true
+        "#
+    })?;
+
+    // A `VIRTUAL_ENV` that would be ignored is an error.
+    uv_snapshot!(context.filters(), context.sync().env(EnvVars::VIRTUAL_ENV, context.temp_dir.child("other").as_os_str()), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `VIRTUAL_ENV=other` does not match the project environment path `.venv`; use `--active` to target the active environment instead [ignored-virtual-env]
+    ");
+
+    // `uv lock` doesn't use the project environment, so the `VIRTUAL_ENV` is irrelevant.
+    context
+        .lock()
+        .env(
+            EnvVars::VIRTUAL_ENV,
+            context.temp_dir.child("other").as_os_str(),
+        )
+        .assert()
+        .success();
+
+    // The setting can be overridden on the command line.
+    context
+        .sync()
+        .arg("--no-fail-fast-env")
+        .env(
+            EnvVars::VIRTUAL_ENV,
+            context.temp_dir.child("other").as_os_str(),
+        )
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "does not match the project environment path",
+        ));
+
+    Ok(())
+}
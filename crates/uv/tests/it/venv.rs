@@ -3,7 +3,7 @@ use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
 use indoc::indoc;
 use predicates::prelude::*;
-use uv_python::{PYTHON_VERSION_FILENAME, PYTHON_VERSIONS_FILENAME};
+use uv_python::{PYTHON_VERSION_FILENAME, PYTHON_VERSIONS_FILENAME, PyVenvConfiguration};
 use uv_static::EnvVars;
 
 #[cfg(unix)]
@@ -801,6 +801,537 @@ fn seed() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+#[cfg(feature = "pypi")]
+fn rebase() {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Rebase the environment onto Python 3.12, preserving the installed packages.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rebase")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Rebasing virtual environment at: .venv
+     + iniconfig==2.0.0
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg("import sys, iniconfig; print(sys.version_info[:2])"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    (3, 12)
+
+    ----- stderr -----
+    "
+    );
+
+    // No leftovers from the previous environment remain next to it.
+    assert!(
+        fs_err::read_dir(context.temp_dir.path())
+            .unwrap()
+            .all(|entry| !entry
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with(".uv-rebase-"))
+    );
+}
+
+/// The project environment is rebased from the lockfile, rather than from the packages installed
+/// in the existing environment.
+#[test]
+#[cfg(feature = "pypi")]
+fn rebase_project() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12", "3.11"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    context
+        .sync()
+        .arg("--python")
+        .arg("3.11")
+        .assert()
+        .success();
+
+    // Install a package that isn't in the lockfile.
+    context
+        .pip_install()
+        .arg("typing-extensions==4.10.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rebase")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    warning: `typing-extensions` is not in the lockfile; it will not be reinstalled into the rebased environment
+    Rebasing virtual environment at: .venv
+    Prepared 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg("import sys, iniconfig; print(sys.version_info[:2])"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    (3, 12)
+
+    ----- stderr -----
+    "
+    );
+
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import typing_extensions")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[test]
+fn rebase_missing() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rebase")
+        .arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No virtual environment found at `.venv`
+    "
+    );
+}
+
+#[test]
+fn rename_prompt() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context.venv().arg("--prompt").arg("foo").assert().success();
+
+    // Update the prompt in place.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rename-prompt")
+        .arg("bar"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated prompt of virtual environment at `.venv` to `bar`
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("prompt = bar\n"));
+    assert!(!pyvenv_cfg.contains("prompt = foo"));
+
+    // The activation scripts are rewritten, too.
+    #[cfg(unix)]
+    {
+        let activate = fs_err::read_to_string(context.venv.join("bin").join("activate"))?;
+        assert!(activate.contains("bar"));
+        assert!(!activate.contains("foo"));
+    }
+
+    // The environment is still usable.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import sys")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn rename_prompt_missing() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rename-prompt")
+        .arg("bar"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No virtual environment found at `.venv`
+    "
+    );
+}
+
+#[test]
+fn lock_environment() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context.venv().assert().success();
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--lock-environment"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locked virtual environment at `.venv` against modification
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("uv-read-only = true\n"));
+
+    // Installing into the locked environment fails.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The environment at `.venv` is locked against modification; pass `--unlock` to modify it anyway
+    "
+    );
+
+    context.assert_command("import iniconfig").failure();
+
+    // Unless `--unlock` is provided.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--unlock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // Uninstalling is refused, too.
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment at `.venv` is locked against modification; pass `--unlock` to modify it anyway
+    "
+    );
+
+    // Recreating the environment is refused.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--clear"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment at `.venv` is locked against modification; pass `--unlock` to modify it anyway
+    "
+    );
+
+    // As is rewriting its `pyvenv.cfg`.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--rename-prompt")
+        .arg("bar"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment at `.venv` is locked against modification; pass `--unlock` to modify it anyway
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--set-system-site-packages")
+        .arg("true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment at `.venv` is locked against modification; pass `--unlock` to modify it anyway
+    "
+    );
+
+    context.assert_command("import iniconfig").success();
+
+    // Once unlocked, the environment can be modified again.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--unlock-environment"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Unlocked virtual environment at `.venv`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_uninstall()
+        .arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lock_environment_clear_unlock() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context.venv().assert().success();
+    context.venv().arg("--lock-environment").assert().success();
+
+    // `--unlock` allows the environment to be recreated; the new environment isn't locked.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--clear")
+        .arg("--unlock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(!pyvenv_cfg.contains("uv-read-only"));
+
+    Ok(())
+}
+
+#[test]
+fn set_system_site_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("include-system-site-packages = false\n"));
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--set-system-site-packages")
+        .arg("true"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Enabled access to the system site packages in virtual environment at `.venv`
+    "
+    );
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("include-system-site-packages = true\n"));
+
+    let cfg = PyVenvConfiguration::parse(context.venv.join("pyvenv.cfg"))?;
+    assert!(cfg.include_system_site_packages());
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--set-system-site-packages")
+        .arg("false"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Disabled access to the system site packages in virtual environment at `.venv`
+    "
+    );
+
+    let cfg = PyVenvConfiguration::parse(context.venv.join("pyvenv.cfg"))?;
+    assert!(!cfg.include_system_site_packages());
+
+    // The environment must exist.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("missing")
+        .arg("--set-system-site-packages")
+        .arg("true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No virtual environment found at `missing`
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn create_venv_allow_user_site() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--allow-user-site")
+        .arg("--python")
+        .arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Activate with: source .venv/[BIN]/activate
+    "
+    );
+
+    let cfg = PyVenvConfiguration::parse(context.venv.join("pyvenv.cfg"))?;
+    assert!(cfg.allows_user_site());
+
+    // The user site packages are on the module search path, unless disabled at runtime.
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-c")
+        .arg("import site, sys; print(site.getusersitepackages() in sys.path)"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.python_command()
+        .arg("-s")
+        .arg("-c")
+        .arg("import site, sys; print(site.getusersitepackages() in sys.path)"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    False
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn adopt_venv() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // Create an environment with another tool, with an entry point that refers to it.
+    let foreign = context.temp_dir.child("foreign");
+    std::process::Command::new(&context.python_versions[0].1)
+        .arg("-m")
+        .arg("venv")
+        .arg("--without-pip")
+        .arg("--prompt")
+        .arg("legacy")
+        .arg(foreign.path())
+        .assert()
+        .success();
+    foreign.child("bin").child("example").write_str(&format!(
+        "#!{}\nprint('example')\n",
+        foreign.path().join("bin").join("python").display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg("--adopt")
+        .arg("foreign"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Adopted virtual environment at: .venv
+    "
+    );
+
+    // The environment is moved into place, with its metadata recorded in uv's format.
+    foreign.assert(predicates::path::missing());
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("uv = "));
+    assert!(pyvenv_cfg.contains("prompt = legacy\n"));
+    assert!(context.venv.join("uv-environment.toml").is_file());
+
+    // The entry point refers to the interpreter at the new location.
+    let example = fs_err::read_to_string(context.venv.join("bin").join("example"))?;
+    let shebang = example.lines().next().unwrap();
+    assert!(shebang.ends_with("/.venv/bin/python"));
+    assert!(!shebang.contains("foreign"));
+
+    // The environment is still usable.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import sys")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "pypi")]
 fn seed_older_python_version() {
@@ -902,6 +1433,61 @@ fn create_venv_python_patch() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+fn show_provenance() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    context
+        .venv
+        .child("uv-environment.toml")
+        .assert(predicates::path::is_file());
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"cpython-3\.12\.\d+-\S+", "[KEY]"),
+            (r"Executable: .*", "Executable: [EXECUTABLE]"),
+            (r"Created: .* by uv .*", "Created: [TIME] by uv [VERSION]"),
+        ])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.venv().arg("--show"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Interpreter: [KEY] (system)
+    Executable: [EXECUTABLE]
+    Created: [TIME] by uv [VERSION]
+
+    ----- stderr -----
+    "
+    );
+
+    // Environments created by other tools have no provenance.
+    fs_err::remove_file(context.venv.child("uv-environment.toml"))?;
+
+    uv_snapshot!(context.filters(), context.venv().arg("--show"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No provenance is recorded for the virtual environment at `.venv`; it may have been created by another tool or an older version of uv
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn file_exists() -> Result<()> {
     let context = TestContext::new_with_versions(&["3.12"]);
@@ -1142,6 +1728,39 @@ fn verify_pyvenv_cfg() {
     pyvenv_cfg.assert(predicates::str::contains("relocatable").not());
 }
 
+#[test]
+fn verify_pyvenv_cfg_python_platform() {
+    let context = TestContext::new("3.12");
+
+    // Create a resolution-only virtual environment at `.venv`.
+    context
+        .venv()
+        .arg(context.venv.as_os_str())
+        .arg("--clear")
+        .arg("--python")
+        .arg("3.12")
+        .arg("--python-platform")
+        .arg("x86_64-pc-windows-msvc")
+        .assert()
+        .success();
+
+    // The target platform is recorded.
+    let pyvenv_cfg = context.venv.child("pyvenv.cfg");
+    pyvenv_cfg.assert(predicates::str::contains(
+        "uv-python-platform = x86_64-pc-windows-msvc",
+    ));
+
+    // Commands can't be run in the environment.
+    context
+        .run()
+        .arg("python")
+        .arg("-c")
+        .arg("pass")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("resolution-only environment"));
+}
+
 #[test]
 fn verify_pyvenv_cfg_relocatable() {
     let context = TestContext::new("3.12");
@@ -1572,3 +2191,113 @@ fn create_venv_nested_symlink_preservation() -> Result<()> {
 
     Ok(())
 }
+
+/// The link mode is recorded in the environment, and used for subsequent installations into it.
+#[test]
+#[cfg(unix)]
+fn create_venv_link_mode() -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("uv-link-mode = copy\n"));
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // The files are copied from the cache, rather than hard linked.
+    let metadata = fs_err::metadata(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("__init__.py"),
+    )?;
+    assert_eq!(metadata.nlink(), 1);
+
+    Ok(())
+}
+
+/// Create a virtual environment in a directory that exceeds `MAX_PATH`, without relying on long
+/// paths being enabled in the registry.
+#[test]
+#[cfg(windows)]
+fn create_venv_long_path() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    let root = context
+        .temp_dir
+        .child("a".repeat(100))
+        .child("b".repeat(100))
+        .child("c".repeat(100));
+    fs_err::create_dir_all(&root)?;
+    assert!(root.as_os_str().len() > 260);
+
+    let venv = root.child(".venv");
+    context
+        .venv()
+        .arg(venv.as_os_str())
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    venv.child("pyvenv.cfg").assert(predicates::path::is_file());
+
+    // The extended-length prefix is only used for filesystem operations, and isn't embedded in the
+    // environment.
+    let activate = fs_err::read_to_string(venv.child("Scripts").child("activate.bat"))?;
+    assert!(!activate.contains(r"\\?\"));
+
+    Ok(())
+}
+
+/// A link mode requested explicitly takes precedence over the one recorded in the environment,
+/// even if it's the default link mode.
+#[test]
+#[cfg(target_os = "linux")]
+fn create_venv_link_mode_override() -> Result<()> {
+    use std::os::unix::fs::MetadataExt;
+
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    context
+        .pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    // The files are hard linked from the cache, rather than copied.
+    let metadata = fs_err::metadata(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("__init__.py"),
+    )?;
+    assert!(metadata.nlink() > 1);
+
+    Ok(())
+}
@@ -1741,6 +1741,11 @@ fn self_version_json() -> Result<()> {
                 r#"commits_since_last_tag": .*"#,
                 r#"commits_since_last_tag": [COUNT]"#,
             ),
+            (r#"lock_version": \d+"#, r#"lock_version": [LOCK_VERSION]"#),
+            (
+                r#"lock_revision": \d+"#,
+                r#"lock_revision": [LOCK_REVISION]"#,
+            ),
         ])
         .collect::<Vec<_>>();
 
@@ -1759,6 +1764,10 @@ fn self_version_json() -> Result<()> {
           "commit_date": "[DATE]",
           "last_tag": "[TAG]",
           "commits_since_last_tag": [COUNT]
+        },
+        "capabilities": {
+          "lock_version": [LOCK_VERSION],
+          "lock_revision": [LOCK_REVISION]
         }
       }
 
@@ -1773,7 +1782,11 @@ fn self_version_json() -> Result<()> {
       {
         "package_name": "uv",
         "version": "[VERSION]",
-        "commit_info": null
+        "commit_info": null,
+        "capabilities": {
+          "lock_version": [LOCK_VERSION],
+          "lock_revision": [LOCK_REVISION]
+        }
       }
 
       ----- stderr -----
@@ -1962,7 +1975,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2022,7 +2035,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2149,7 +2162,7 @@ fn version_set_workspace() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2295,7 +2308,7 @@ fn version_set_evil_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]
@@ -2381,7 +2394,7 @@ fn version_set_evil_constraints() -> Result<()> {
         assert_snapshot!(
             lock, @r#"
         version = 1
-        revision = 3
+        revision = 4
         requires-python = ">=3.12"
 
         [options]